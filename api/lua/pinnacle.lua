@@ -85,6 +85,99 @@ function pinnacle.backend()
     end
 end
 
+---Information about Pinnacle's renderer.
+---@class pinnacle.RendererInfo
+---@field renderer string The `GL_RENDERER` string.
+---@field vendor string The `GL_VENDOR` string.
+---@field version string The `GL_VERSION` string.
+---@field gpu_path string? The device path of the gpu that is rendering, if known. On a tty this is the primary gpu.
+---@field dmabuf_formats { fourcc: integer, modifier: integer }[] The dmabuf formats the renderer can import.
+---@field hardware_accel_error string? Why hardware acceleration failed to initialize, if it did. If set, clients can only share buffers through shared memory.
+
+---Gets information about the renderer.
+---
+---Useful for bug reports and triaging rendering issues.
+---
+---#### Example
+---```lua
+---local info = Pinnacle.renderer_info()
+---print("Rendering with " .. info.renderer .. " from " .. info.vendor)
+---```
+---
+---@return pinnacle.RendererInfo
+function pinnacle.renderer_info()
+    local response, err = client:pinnacle_v1_PinnacleService_RendererInfo({})
+
+    if err then
+        log.error(err)
+    end
+
+    response = response or {}
+
+    ---@type { fourcc: integer, modifier: integer }[]
+    local dmabuf_formats = {}
+    for _, format in ipairs(response.dmabuf_formats or {}) do
+        table.insert(dmabuf_formats, {
+            fourcc = format.fourcc or 0,
+            modifier = format.modifier or 0,
+        })
+    end
+
+    ---@type pinnacle.RendererInfo
+    local info = {
+        renderer = response.renderer or "",
+        vendor = response.vendor or "",
+        version = response.version or "",
+        gpu_path = response.gpu_path,
+        dmabuf_formats = dmabuf_formats,
+        hardware_accel_error = response.hardware_accel_error,
+    }
+
+    return info
+end
+
+---Internal counters of Pinnacle, for diagnosing leaks and load.
+---@class pinnacle.Metrics
+---@field windows integer The number of open windows.
+---@field outputs integer The number of connected outputs.
+---@field frames_rendered integer The number of frames rendered and submitted across all outputs.
+---@field frames_skipped integer The number of render attempts across all outputs that didn't happen or failed.
+---@field active_grpc_streams integer The number of gRPC streams that are currently open.
+---@field config_restarts integer The number of times the config was restarted.
+---@field memory_usage integer? The resident memory usage of Pinnacle in bytes, if known.
+
+---Gets Pinnacle's internal counters.
+---
+---#### Example
+---```lua
+---local metrics = Pinnacle.metrics()
+---print(metrics.windows .. " windows open")
+---```
+---
+---@return pinnacle.Metrics
+function pinnacle.metrics()
+    local response, err = client:pinnacle_v1_PinnacleService_Metrics({})
+
+    if err then
+        log.error(err)
+    end
+
+    response = response or {}
+
+    ---@type pinnacle.Metrics
+    local metrics = {
+        windows = response.windows or 0,
+        outputs = response.outputs or 0,
+        frames_rendered = response.frames_rendered or 0,
+        frames_skipped = response.frames_skipped or 0,
+        active_grpc_streams = response.active_grpc_streams or 0,
+        config_restarts = response.config_restarts or 0,
+        memory_usage = response.memory_usage,
+    }
+
+    return metrics
+end
+
 ---Sets whether or not xwayland clients should scale themselves.
 ---
 ---If `true`, xwayland clients will be told they are on an output with a larger or smaller size than
@@ -107,6 +200,31 @@ function pinnacle.set_xwayland_self_scaling(should_self_scale)
     end
 end
 
+---Sets whether window tags and floating geometries are restored after a config reload.
+---
+---Before the config reloads, every window's tags are saved by name along with its
+---floating geometry. Once the new config adds tags with those names, windows are put
+---back on them. Windows that closed during the reload are matched to another window
+---with the same app id and title.
+---
+---This is reset on reload, so the new config has to set it again to keep it on.
+---
+---#### Example
+---```lua
+---Pinnacle.set_preserve_layout_on_reload(true)
+---```
+---
+---@param preserve boolean
+function pinnacle.set_preserve_layout_on_reload(preserve)
+    local _, err = client:pinnacle_v1_PinnacleService_SetPreserveLayoutOnReload({
+        preserve = preserve,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Sets an error message that is held by the compositor until it is retrieved.
 ---
 ---@param error string
@@ -134,6 +252,51 @@ function pinnacle.take_last_error()
     return error and error.error
 end
 
+---@class pinnacle.PinnacleSignal Signals related to the compositor and session.
+---@field prepare_for_sleep fun(sleeping: boolean)? The system is about to sleep (`true`) or has just resumed (`false`). When systemd-logind is available, sleep is delayed until the callback returns.
+---@field output_layout_changed fun()? Outputs were moved, resized, enabled, disabled, connected, or disconnected. Fires once per batch of changes.
+
+local signal_name_to_SignalName = {
+    prepare_for_sleep = "PinnaclePrepareForSleep",
+    output_layout_changed = "PinnacleOutputLayoutChanged",
+}
+
+---Connects to a Pinnacle signal.
+---
+---`signals` is a table containing the signal(s) you want to connect to along with
+---a corresponding callback that will be called when the signal is signalled.
+---
+---This function returns a table of signal handles with each handle stored at the same key used
+---to connect to the signal. See `SignalHandles` for more information.
+---
+---#### Example
+---```lua
+---Pinnacle.connect_signal({
+---    prepare_for_sleep = function(sleeping)
+---        if sleeping then
+---            Process.spawn("playerctl", "pause")
+---        end
+---    end
+---})
+---```
+---@param signals pinnacle.PinnacleSignal The signal you want to connect to
+---
+---@return pinnacle.signal.SignalHandles signal_handles Handles to every signal you connected to wrapped in a table, with keys being the same as the connected signal.
+---
+---@see pinnacle.signal.SignalHandles.disconnect_all - To disconnect from these signals
+function pinnacle.connect_signal(signals)
+    ---@diagnostic disable-next-line: invisible
+    local handles = require("pinnacle.signal").handles.new()
+
+    for signal, callback in pairs(signals) do
+        local handle =
+            require("pinnacle.signal").add_callback(signal_name_to_SignalName[signal], callback)
+        handles[signal] = handle
+    end
+
+    return handles
+end
+
 ---Initializes the protobuf backend and connects to Pinnacle's gRPC socket.
 ---
 ---If the Snowcap Lua API is installed and Snowcap is running, this will also setup Snowcap and
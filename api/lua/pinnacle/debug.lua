@@ -3,7 +3,9 @@
 -- file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 local client = require("pinnacle.grpc.client").client
+local log = require("pinnacle.log")
 local util_v1 = require("pinnacle.grpc.defs").pinnacle.util.v1
+local window_v1 = require("pinnacle.grpc.defs").pinnacle.window.v1
 
 ---Debugging utilities.
 ---
@@ -78,6 +80,29 @@ function debug.toggle_cursor_plane_scanout()
     })
 end
 
+---Sets direct scanout.
+---
+---When disabled, everything is composited and nothing, including the cursor,
+---is scanned out on a plane directly. This is useful for telling driver scanout
+---bugs apart from compositor rendering bugs.
+---
+---This only has an effect when running in a tty.
+---
+---@param set boolean
+function debug.set_direct_scanout(set)
+    local _, err = client:pinnacle_debug_v1_DebugService_SetDirectScanout({
+        set_or_toggle = set and util_v1.SetOrToggle.SET_OR_TOGGLE_SET
+            or util_v1.SetOrToggle.SET_OR_TOGGLE_UNSET,
+    })
+end
+
+---Toggles direct scanout.
+function debug.toggle_direct_scanout()
+    local _, err = client:pinnacle_debug_v1_DebugService_SetDirectScanout({
+        set_or_toggle = util_v1.SetOrToggle.SET_OR_TOGGLE_TOGGLE,
+    })
+end
+
 ---Enables or disables process spawning setting up pipes to give fds to the config.
 ---
 ---@param set boolean
@@ -95,4 +120,160 @@ function debug.toggle_process_piping()
     })
 end
 
+---Enables or disables opaque region culling.
+---
+---When enabled, elements completely hidden behind the opaque regions of elements above them,
+---like windows behind an opaque window, aren't drawn. Surfaces that don't declare
+---an opaque region never hide anything.
+---
+---This is disabled by default, as rendering already skips drawing behind opaque regions.
+---Use `set_opaque_region_visualization` to see what clients have declared as opaque.
+---
+---@param set boolean
+function debug.set_opaque_region_culling(set)
+    local _, err = client:pinnacle_debug_v1_DebugService_SetOpaqueRegionCulling({
+        set_or_toggle = set and util_v1.SetOrToggle.SET_OR_TOGGLE_SET
+            or util_v1.SetOrToggle.SET_OR_TOGGLE_UNSET,
+    })
+end
+
+---Toggles opaque region culling.
+function debug.toggle_opaque_region_culling()
+    local _, err = client:pinnacle_debug_v1_DebugService_SetOpaqueRegionCulling({
+        set_or_toggle = util_v1.SetOrToggle.SET_OR_TOGGLE_TOGGLE,
+    })
+end
+
+---@class pinnacle.debug.StateDump
+---@field outputs pinnacle.debug.OutputDump[]
+---@field windows pinnacle.debug.WindowDump[] All mapped windows.
+---@field focused_output_name string?
+---@field focused_window_id integer? The id of the window with keyboard focus.
+
+---@class pinnacle.debug.OutputDump
+---@field name string
+---@field geometry { x: integer, y: integer, width: integer, height: integer }? `nil` if the output isn't mapped.
+---@field current_mode pinnacle.output.Mode?
+---@field modes pinnacle.output.Mode[]
+---@field scale number
+---@field enabled boolean
+---@field tags { id: integer, name: string, active: boolean }[]
+
+---@class pinnacle.debug.WindowDump
+---@field id integer
+---@field app_id string
+---@field title string
+---@field tag_ids integer[]
+---@field layout_mode pinnacle.layout.LayoutMode|"spilled"
+---@field geometry { x: integer, y: integer, width: integer, height: integer }? `nil` if the window isn't mapped.
+
+local layout_modes = {
+    [window_v1.LayoutMode.LAYOUT_MODE_TILED] = "tiled",
+    [window_v1.LayoutMode.LAYOUT_MODE_FLOATING] = "floating",
+    [window_v1.LayoutMode.LAYOUT_MODE_FULLSCREEN] = "fullscreen",
+    [window_v1.LayoutMode.LAYOUT_MODE_MAXIMIZED] = "maximized",
+    [window_v1.LayoutMode.LAYOUT_MODE_SPILLED] = "spilled",
+}
+
+local function from_rect(rect)
+    if not rect then
+        return nil
+    end
+
+    return {
+        x = rect.loc and rect.loc.x or 0,
+        y = rect.loc and rect.loc.y or 0,
+        width = rect.size and rect.size.width or 0,
+        height = rect.size and rect.size.height or 0,
+    }
+end
+
+local function from_mode(mode)
+    if not mode then
+        return nil
+    end
+
+    ---@type pinnacle.output.Mode
+    local ret = {
+        width = mode.size and mode.size.width or 0,
+        height = mode.size and mode.size.height or 0,
+        refresh_rate_mhz = mode.refresh_rate_mhz or 0,
+    }
+
+    return ret
+end
+
+---Gets a snapshot of outputs, tags, windows, and focus.
+---
+---Everything is gathered at once, so the snapshot is consistent.
+---The snapshot is made of plain tables, so it can be printed or
+---serialized for bug reports.
+---
+---#### Example
+---```lua
+---local dump = Debug.dump_state()
+---for _, win in ipairs(dump.windows) do
+---    print(win.app_id, win.title)
+---end
+---```
+---
+---@return pinnacle.debug.StateDump?
+function debug.dump_state()
+    local response, err = client:pinnacle_debug_v1_DebugService_DumpState({})
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    assert(response)
+
+    ---@type pinnacle.debug.StateDump
+    local dump = {
+        outputs = {},
+        windows = {},
+        focused_output_name = response.focused_output_name,
+        focused_window_id = response.focused_window_id,
+    }
+
+    for _, output in ipairs(response.outputs or {}) do
+        local modes = {}
+        for _, mode in ipairs(output.modes or {}) do
+            table.insert(modes, from_mode(mode))
+        end
+
+        local tags = {}
+        for _, tag in ipairs(output.tags or {}) do
+            table.insert(tags, {
+                id = tag.tag_id or 0,
+                name = tag.name or "",
+                active = tag.active or false,
+            })
+        end
+
+        table.insert(dump.outputs, {
+            name = output.name or "",
+            geometry = from_rect(output.geometry),
+            current_mode = from_mode(output.current_mode),
+            modes = modes,
+            scale = output.scale or 1.0,
+            enabled = output.enabled or false,
+            tags = tags,
+        })
+    end
+
+    for _, window in ipairs(response.windows or {}) do
+        table.insert(dump.windows, {
+            id = window.window_id or 0,
+            app_id = window.app_id or "",
+            title = window.title or "",
+            tag_ids = window.tag_ids or {},
+            layout_mode = layout_modes[window.layout_mode] or "tiled",
+            geometry = from_rect(window.geometry),
+        })
+    end
+
+    return dump
+end
+
 return debug
@@ -508,6 +508,113 @@ local pinnacle_util_v1_Dir = {
     DIR_DOWN = 4,
 }
 
+---@enum pinnacle.output.v1.Transform
+local pinnacle_output_v1_Transform = {
+    TRANSFORM_UNSPECIFIED = 0,
+    TRANSFORM_NORMAL = 1,
+    TRANSFORM_90 = 2,
+    TRANSFORM_180 = 3,
+    TRANSFORM_270 = 4,
+    TRANSFORM_FLIPPED = 5,
+    TRANSFORM_FLIPPED_90 = 6,
+    TRANSFORM_FLIPPED_180 = 7,
+    TRANSFORM_FLIPPED_270 = 8,
+}
+
+---@enum pinnacle.output.v1.LayoutMode
+local pinnacle_output_v1_LayoutMode = {
+    LAYOUT_MODE_UNSPECIFIED = 0,
+    LAYOUT_MODE_TILING = 1,
+    LAYOUT_MODE_FLOATING = 2,
+}
+
+---@enum pinnacle.output.v1.ColorFilter
+local pinnacle_output_v1_ColorFilter = {
+    COLOR_FILTER_UNSPECIFIED = 0,
+    COLOR_FILTER_NONE = 1,
+    COLOR_FILTER_GRAYSCALE = 2,
+    COLOR_FILTER_PROTANOPIA = 3,
+    COLOR_FILTER_DEUTERANOPIA = 4,
+    COLOR_FILTER_TRITANOPIA = 5,
+    COLOR_FILTER_INVERT = 6,
+}
+
+---@enum pinnacle.output.v1.Vrr
+local pinnacle_output_v1_Vrr = {
+    VRR_UNSPECIFIED = 0,
+    VRR_OFF = 1,
+    VRR_ALWAYS_ON = 2,
+    VRR_ON_DEMAND = 3,
+}
+
+---@enum pinnacle.output.v1.ContentType
+local pinnacle_output_v1_ContentType = {
+    CONTENT_TYPE_UNSPECIFIED = 0,
+    CONTENT_TYPE_NONE = 1,
+    CONTENT_TYPE_PHOTO = 2,
+    CONTENT_TYPE_VIDEO = 3,
+    CONTENT_TYPE_GAME = 4,
+}
+
+---@enum pinnacle.output.v1.LegacyScaleMode
+local pinnacle_output_v1_LegacyScaleMode = {
+    LEGACY_SCALE_MODE_UNSPECIFIED = 0,
+    LEGACY_SCALE_MODE_CEIL = 1,
+    LEGACY_SCALE_MODE_FLOOR = 2,
+    LEGACY_SCALE_MODE_ROUND = 3,
+    LEGACY_SCALE_MODE_PREFER = 4,
+}
+
+---@enum pinnacle.window.v1.LayoutMode
+local pinnacle_window_v1_LayoutMode = {
+    LAYOUT_MODE_UNSPECIFIED = 0,
+    LAYOUT_MODE_TILED = 1,
+    LAYOUT_MODE_FLOATING = 2,
+    LAYOUT_MODE_FULLSCREEN = 3,
+    LAYOUT_MODE_MAXIMIZED = 4,
+    LAYOUT_MODE_SPILLED = 5,
+}
+
+---@enum pinnacle.window.v1.DecorationMode
+local pinnacle_window_v1_DecorationMode = {
+    DECORATION_MODE_UNSPECIFIED = 0,
+    DECORATION_MODE_CLIENT_SIDE = 1,
+    DECORATION_MODE_SERVER_SIDE = 2,
+}
+
+---@enum pinnacle.window.v1.InsertPosition
+local pinnacle_window_v1_InsertPosition = {
+    INSERT_POSITION_UNSPECIFIED = 0,
+    INSERT_POSITION_START = 1,
+    INSERT_POSITION_END = 2,
+    INSERT_POSITION_AFTER_FOCUSED = 3,
+}
+
+---@enum pinnacle.window.v1.DecorationPolicy
+local pinnacle_window_v1_DecorationPolicy = {
+    DECORATION_POLICY_UNSPECIFIED = 0,
+    DECORATION_POLICY_PREFER_SERVER_SIDE = 1,
+    DECORATION_POLICY_PREFER_CLIENT_SIDE = 2,
+    DECORATION_POLICY_FORCE_SERVER_SIDE = 3,
+    DECORATION_POLICY_FORCE_CLIENT_SIDE = 4,
+}
+
+---@enum pinnacle.window.v1.OffscreenPolicy
+local pinnacle_window_v1_OffscreenPolicy = {
+    OFFSCREEN_POLICY_UNSPECIFIED = 0,
+    OFFSCREEN_POLICY_ALLOW = 1,
+    OFFSCREEN_POLICY_KEEP_VISIBLE = 2,
+    OFFSCREEN_POLICY_CLAMP = 3,
+}
+
+---@enum pinnacle.window.v1.CloseBehavior
+local pinnacle_window_v1_CloseBehavior = {
+    CLOSE_BEHAVIOR_UNSPECIFIED = 0,
+    CLOSE_BEHAVIOR_CLOSE = 1,
+    CLOSE_BEHAVIOR_MINIMIZE = 2,
+    CLOSE_BEHAVIOR_SCRATCHPAD = 3,
+}
+
 ---@enum pinnacle.input.v1.Modifier
 local pinnacle_input_v1_Modifier = {
     MODIFIER_UNSPECIFIED = 0,
@@ -526,6 +633,23 @@ local pinnacle_input_v1_Edge = {
     EDGE_RELEASE = 2,
 }
 
+---@enum pinnacle.input.v1.Gesture
+local pinnacle_input_v1_Gesture = {
+    GESTURE_UNSPECIFIED = 0,
+    GESTURE_SWIPE_UP = 1,
+    GESTURE_SWIPE_DOWN = 2,
+    GESTURE_SWIPE_LEFT = 3,
+    GESTURE_SWIPE_RIGHT = 4,
+    GESTURE_PINCH = 5,
+}
+
+---@enum pinnacle.input.v1.RelativeMotionPolicy
+local pinnacle_input_v1_RelativeMotionPolicy = {
+    RELATIVE_MOTION_POLICY_UNSPECIFIED = 0,
+    RELATIVE_MOTION_POLICY_ACCELERATED = 1,
+    RELATIVE_MOTION_POLICY_UNACCELERATED = 2,
+}
+
 ---@enum pinnacle.input.v1.ClickMethod
 local pinnacle_input_v1_ClickMethod = {
     CLICK_METHOD_UNSPECIFIED = 0,
@@ -583,27 +707,6 @@ local pinnacle_layout_v1_FlexDir = {
     FLEX_DIR_COLUMN = 2,
 }
 
----@enum pinnacle.output.v1.Transform
-local pinnacle_output_v1_Transform = {
-    TRANSFORM_UNSPECIFIED = 0,
-    TRANSFORM_NORMAL = 1,
-    TRANSFORM_90 = 2,
-    TRANSFORM_180 = 3,
-    TRANSFORM_270 = 4,
-    TRANSFORM_FLIPPED = 5,
-    TRANSFORM_FLIPPED_90 = 6,
-    TRANSFORM_FLIPPED_180 = 7,
-    TRANSFORM_FLIPPED_270 = 8,
-}
-
----@enum pinnacle.output.v1.Vrr
-local pinnacle_output_v1_Vrr = {
-    VRR_UNSPECIFIED = 0,
-    VRR_OFF = 1,
-    VRR_ALWAYS_ON = 2,
-    VRR_ON_DEMAND = 3,
-}
-
 ---@enum pinnacle.render.v1.Filter
 local pinnacle_render_v1_Filter = {
     FILTER_UNSPECIFIED = 0,
@@ -611,6 +714,29 @@ local pinnacle_render_v1_Filter = {
     FILTER_NEAREST_NEIGHBOR = 2,
 }
 
+---@enum pinnacle.render.v1.RenderResult
+local pinnacle_render_v1_RenderResult = {
+    RENDER_RESULT_UNSPECIFIED = 0,
+    RENDER_RESULT_SUBMITTED = 1,
+    RENDER_RESULT_NO_DAMAGE = 2,
+    RENDER_RESULT_SKIPPED = 3,
+}
+
+---@enum pinnacle.render.v1.FrameCallbackPolicy
+local pinnacle_render_v1_FrameCallbackPolicy = {
+    FRAME_CALLBACK_POLICY_UNSPECIFIED = 0,
+    FRAME_CALLBACK_POLICY_THROTTLE = 1,
+    FRAME_CALLBACK_POLICY_ALWAYS = 2,
+    FRAME_CALLBACK_POLICY_NEVER = 3,
+}
+
+---@enum pinnacle.render.v1.CursorMode
+local pinnacle_render_v1_CursorMode = {
+    CURSOR_MODE_UNSPECIFIED = 0,
+    CURSOR_MODE_PLANE = 1,
+    CURSOR_MODE_SOFTWARE = 2,
+}
+
 ---@enum pinnacle.signal.v1.StreamControl
 local pinnacle_signal_v1_StreamControl = {
     STREAM_CONTROL_UNSPECIFIED = 0,
@@ -625,23 +751,6 @@ local pinnacle_v1_Backend = {
     BACKEND_TTY = 2,
 }
 
----@enum pinnacle.window.v1.LayoutMode
-local pinnacle_window_v1_LayoutMode = {
-    LAYOUT_MODE_UNSPECIFIED = 0,
-    LAYOUT_MODE_TILED = 1,
-    LAYOUT_MODE_FLOATING = 2,
-    LAYOUT_MODE_FULLSCREEN = 3,
-    LAYOUT_MODE_MAXIMIZED = 4,
-    LAYOUT_MODE_SPILLED = 5,
-}
-
----@enum pinnacle.window.v1.DecorationMode
-local pinnacle_window_v1_DecorationMode = {
-    DECORATION_MODE_UNSPECIFIED = 0,
-    DECORATION_MODE_CLIENT_SIDE = 1,
-    DECORATION_MODE_SERVER_SIDE = 2,
-}
-
 
 ---@alias google.protobuf.Empty nil
 
@@ -657,267 +766,137 @@ local pinnacle_window_v1_DecorationMode = {
 ---@field loc pinnacle.util.v1.Point?
 ---@field size pinnacle.util.v1.Size?
 
----@class pinnacle.debug.v1.SetDamageVisualizationRequest
----@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+---@class pinnacle.output.v1.SetLocRequest
+---@field output_name string?
+---@field x integer?
+---@field y integer?
 
----@class pinnacle.debug.v1.SetOpaqueRegionVisualizationRequest
----@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+---@class pinnacle.output.v1.SetModeRequest
+---@field output_name string?
+---@field size pinnacle.util.v1.Size?
+---@field refresh_rate_mhz integer?
+---@field custom boolean?
 
----@class pinnacle.debug.v1.SetCursorPlaneScanoutRequest
----@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+---@class pinnacle.output.v1.Modeline
+---@field clock number?
+---@field hdisplay integer?
+---@field hsync_start integer?
+---@field hsync_end integer?
+---@field htotal integer?
+---@field vdisplay integer?
+---@field vsync_start integer?
+---@field vsync_end integer?
+---@field vtotal integer?
+---@field hsync boolean?
+---@field vsync boolean?
 
----@class pinnacle.debug.v1.SetProcessPipingRequest
----@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+---@class pinnacle.output.v1.TryModeRequest
+---@field output_name string?
+---@field size pinnacle.util.v1.Size?
+---@field refresh_rate_mhz integer?
+---@field revert_after_ms integer?
 
----@class pinnacle.input.v1.Bind
----@field mods pinnacle.input.v1.Modifier[]?
----@field ignore_mods pinnacle.input.v1.Modifier[]?
----@field layer_name string?
----@field properties pinnacle.input.v1.BindProperties?
----@field key pinnacle.input.v1.Keybind?
----@field mouse pinnacle.input.v1.Mousebind?
+---@class pinnacle.output.v1.ConfirmModeRequest
 
----@class pinnacle.input.v1.BindRequest
----@field bind pinnacle.input.v1.Bind?
+---@class pinnacle.output.v1.SetModelineRequest
+---@field output_name string?
+---@field modeline pinnacle.output.v1.Modeline?
 
----@class pinnacle.input.v1.BindResponse
----@field bind_id integer?
+---@class pinnacle.output.v1.SetScaleRequest
+---@field output_name string?
+---@field scale number?
+---@field abs_or_rel pinnacle.util.v1.AbsOrRel?
 
----@class pinnacle.input.v1.BindProperties
----@field group string?
----@field description string?
----@field quit boolean?
----@field reload_config boolean?
----@field allow_when_locked boolean?
+---@class pinnacle.output.v1.SetTransformRequest
+---@field output_name string?
+---@field transform pinnacle.output.v1.Transform?
 
----@class pinnacle.input.v1.SetBindPropertiesRequest
----@field bind_id integer?
----@field properties pinnacle.input.v1.BindProperties?
+---@class pinnacle.output.v1.SetPoweredRequest
+---@field output_name string?
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
 
----@class pinnacle.input.v1.Keybind
----@field key_code integer?
----@field xkb_name string?
+---@class pinnacle.output.v1.SetBlankedRequest
+---@field output_name string?
+---@field blanked boolean?
 
----@class pinnacle.input.v1.KeybindStreamRequest
----@field bind_id integer?
+---@class pinnacle.output.v1.SetLayoutModeRequest
+---@field output_name string?
+---@field layout_mode pinnacle.output.v1.LayoutMode?
 
----@class pinnacle.input.v1.KeybindStreamResponse
----@field edge pinnacle.input.v1.Edge?
+---@class pinnacle.output.v1.SetColorFilterRequest
+---@field output_name string?
+---@field color_filter pinnacle.output.v1.ColorFilter?
 
----@class pinnacle.input.v1.KeybindOnPressRequest
----@field bind_id integer?
+---@class pinnacle.output.v1.SetSupersampleFactorRequest
+---@field output_name string?
+---@field factor number?
 
----@class pinnacle.input.v1.Mousebind
----@field button integer?
+---@class pinnacle.output.v1.SetVrrRequest
+---@field output_name string?
+---@field vrr pinnacle.output.v1.Vrr?
 
----@class pinnacle.input.v1.MousebindStreamRequest
----@field bind_id integer?
+---@class pinnacle.output.v1.SetVrrResponse
 
----@class pinnacle.input.v1.MousebindStreamResponse
----@field edge pinnacle.input.v1.Edge?
+---@class pinnacle.output.v1.VrrDemandMatcher
+---@field app_id string?
+---@field content_types pinnacle.output.v1.ContentType[]?
+---@field fullscreen boolean?
 
----@class pinnacle.input.v1.MousebindOnPressRequest
----@field bind_id integer?
+---@class pinnacle.output.v1.SetVrrDemandMatcherRequest
+---@field matcher pinnacle.output.v1.VrrDemandMatcher?
 
----@class pinnacle.input.v1.GetBindInfosRequest
+---@class pinnacle.output.v1.SetAutoScaleRequest
+---@field auto_scale boolean?
 
----@class pinnacle.input.v1.GetBindInfosResponse
----@field bind_infos pinnacle.input.v1.BindInfo[]?
+---@class pinnacle.output.v1.SetLegacyScaleModeRequest
+---@field mode pinnacle.output.v1.LegacyScaleMode?
+---@field preferred_scale integer?
 
----@class pinnacle.input.v1.BindInfo
----@field bind_id integer?
----@field bind pinnacle.input.v1.Bind?
+---@class pinnacle.output.v1.SetDynamicTagsRequest
+---@field dynamic_tags boolean?
 
----@class pinnacle.input.v1.GetBindLayerStackRequest
+---@class pinnacle.output.v1.SetDpmsTimeoutRequest
+---@field output_name string?
+---@field timeout_ms integer?
 
----@class pinnacle.input.v1.GetBindLayerStackResponse
----@field layer_names string[]?
+---@class pinnacle.output.v1.SetTransitionAnimationRequest
+---@field enabled boolean?
 
----@class pinnacle.input.v1.EnterBindLayerRequest
----@field layer_name string?
+---@class pinnacle.output.v1.SetOcclusionCullingRequest
+---@field enabled boolean?
 
----@class pinnacle.input.v1.SetXkbConfigRequest
----@field rules string?
----@field variant string?
----@field layout string?
----@field model string?
----@field options string?
+---@class pinnacle.output.v1.SetRespectExclusiveZonesRequest
+---@field respect boolean?
 
----@class pinnacle.input.v1.SetRepeatRateRequest
----@field rate integer?
----@field delay integer?
+---@class pinnacle.output.v1.SetExclusiveZoneIgnoredNamespacesRequest
+---@field namespaces string[]?
 
----@class pinnacle.input.v1.SetXkbKeymapRequest
----@field keymap string?
+---@class pinnacle.output.v1.SetAutoResolveOverlapsRequest
+---@field auto_resolve boolean?
 
----@class pinnacle.input.v1.SwitchXkbLayoutRequest
----@field next google.protobuf.Empty?
----@field prev google.protobuf.Empty?
----@field index integer?
+---@class pinnacle.output.v1.SetGameModeRequest
+---@field output_name string?
+---@field enabled boolean?
 
----@class pinnacle.input.v1.SetXcursorRequest
----@field theme string?
----@field size integer?
+---@class pinnacle.output.v1.SetGammaFromFileRequest
+---@field output_name string?
+---@field path string?
 
----@class pinnacle.input.v1.CalibrationMatrix
----@field matrix number[]?
+---@class pinnacle.output.v1.AddCustomModeRequest
+---@field output_name string?
+---@field size pinnacle.util.v1.Size?
+---@field refresh_rate_mhz integer?
+---@field reduced_blanking boolean?
 
----@class pinnacle.input.v1.GetDevicesRequest
+---@class pinnacle.output.v1.FocusRequest
+---@field output_name string?
 
----@class pinnacle.input.v1.GetDevicesResponse
----@field device_sysnames string[]?
+---@class pinnacle.output.v1.FocusResponse
 
----@class pinnacle.input.v1.GetDeviceCapabilitiesRequest
----@field device_sysname string?
+---@class pinnacle.output.v1.GetRequest
 
----@class pinnacle.input.v1.GetDeviceCapabilitiesResponse
----@field keyboard boolean?
----@field pointer boolean?
----@field touch boolean?
----@field tablet_tool boolean?
----@field tablet_pad boolean?
----@field gesture boolean?
----@field switch boolean?
-
----@class pinnacle.input.v1.GetDeviceInfoRequest
----@field device_sysname string?
-
----@class pinnacle.input.v1.GetDeviceInfoResponse
----@field name string?
----@field product_id integer?
----@field vendor_id integer?
-
----@class pinnacle.input.v1.GetDeviceTypeRequest
----@field device_sysname string?
-
----@class pinnacle.input.v1.GetDeviceTypeResponse
----@field device_type pinnacle.input.v1.DeviceType?
-
----@class pinnacle.input.v1.SetDeviceLibinputSettingRequest
----@field device_sysname string?
----@field accel_profile pinnacle.input.v1.AccelProfile?
----@field accel_speed number?
----@field calibration_matrix pinnacle.input.v1.CalibrationMatrix?
----@field click_method pinnacle.input.v1.ClickMethod?
----@field disable_while_typing boolean?
----@field left_handed boolean?
----@field middle_emulation boolean?
----@field rotation_angle integer?
----@field scroll_button integer?
----@field scroll_button_lock boolean?
----@field scroll_method pinnacle.input.v1.ScrollMethod?
----@field natural_scroll boolean?
----@field tap_button_map pinnacle.input.v1.TapButtonMap?
----@field tap_drag boolean?
----@field tap_drag_lock boolean?
----@field tap boolean?
----@field send_events_mode pinnacle.input.v1.SendEventsMode?
-
----@class pinnacle.input.v1.SetDeviceMapTargetRequest
----@field device_sysname string?
----@field region pinnacle.util.v1.Rect?
----@field output_name string?
-
----@class pinnacle.layout.v1.Gaps
----@field left number?
----@field right number?
----@field top number?
----@field bottom number?
-
----@class pinnacle.layout.v1.LayoutNode
----@field label string?
----@field traversal_index integer?
----@field traversal_overrides pinnacle.layout.v1.LayoutNode.TraversalOverridesEntry[]?
----@field style pinnacle.layout.v1.NodeStyle?
----@field children pinnacle.layout.v1.LayoutNode[]?
-
----@class pinnacle.layout.v1.LayoutNode.TraversalOverridesEntry
----@field key integer?
----@field value pinnacle.layout.v1.TraversalOverrides?
-
----@class pinnacle.layout.v1.TraversalOverrides
----@field overrides integer[]?
-
----@class pinnacle.layout.v1.NodeStyle
----@field flex_dir pinnacle.layout.v1.FlexDir?
----@field size_proportion number?
----@field gaps pinnacle.layout.v1.Gaps?
-
----@class pinnacle.layout.v1.LayoutRequest
----@field tree_response pinnacle.layout.v1.LayoutRequest.TreeResponse?
----@field force_layout pinnacle.layout.v1.LayoutRequest.ForceLayout?
-
----@class pinnacle.layout.v1.LayoutRequest.TreeResponse
----@field request_id integer?
----@field tree_id integer?
----@field root_node pinnacle.layout.v1.LayoutNode?
----@field output_name string?
-
----@class pinnacle.layout.v1.LayoutRequest.ForceLayout
----@field output_name string?
-
----@class pinnacle.layout.v1.LayoutResponse
----@field request_id integer?
----@field output_name string?
----@field window_count integer?
----@field tag_ids integer[]?
-
----@class pinnacle.output.v1.SetLocRequest
----@field output_name string?
----@field x integer?
----@field y integer?
-
----@class pinnacle.output.v1.SetModeRequest
----@field output_name string?
----@field size pinnacle.util.v1.Size?
----@field refresh_rate_mhz integer?
----@field custom boolean?
-
----@class pinnacle.output.v1.Modeline
----@field clock number?
----@field hdisplay integer?
----@field hsync_start integer?
----@field hsync_end integer?
----@field htotal integer?
----@field vdisplay integer?
----@field vsync_start integer?
----@field vsync_end integer?
----@field vtotal integer?
----@field hsync boolean?
----@field vsync boolean?
-
----@class pinnacle.output.v1.SetModelineRequest
----@field output_name string?
----@field modeline pinnacle.output.v1.Modeline?
-
----@class pinnacle.output.v1.SetScaleRequest
----@field output_name string?
----@field scale number?
----@field abs_or_rel pinnacle.util.v1.AbsOrRel?
-
----@class pinnacle.output.v1.SetTransformRequest
----@field output_name string?
----@field transform pinnacle.output.v1.Transform?
-
----@class pinnacle.output.v1.SetPoweredRequest
----@field output_name string?
----@field set_or_toggle pinnacle.util.v1.SetOrToggle?
-
----@class pinnacle.output.v1.SetVrrRequest
----@field output_name string?
----@field vrr pinnacle.output.v1.Vrr?
-
----@class pinnacle.output.v1.SetVrrResponse
-
----@class pinnacle.output.v1.FocusRequest
----@field output_name string?
-
----@class pinnacle.output.v1.FocusResponse
-
----@class pinnacle.output.v1.GetRequest
-
----@class pinnacle.output.v1.GetResponse
----@field output_names string[]?
+---@class pinnacle.output.v1.GetResponse
+---@field output_names string[]?
 
 ---@class pinnacle.output.v1.GetInfoRequest
 ---@field output_name string?
@@ -927,6 +906,12 @@ local pinnacle_window_v1_DecorationMode = {
 ---@field model string?
 ---@field serial string?
 
+---@class pinnacle.output.v1.SetIdentityOverrideRequest
+---@field output_name string?
+---@field make string?
+---@field model string?
+---@field serial string?
+
 ---@class pinnacle.output.v1.GetLocRequest
 ---@field output_name string?
 
@@ -957,6 +942,13 @@ local pinnacle_window_v1_DecorationMode = {
 ---@field preferred_mode pinnacle.output.v1.Mode?
 ---@field modes pinnacle.output.v1.Mode[]?
 
+---@class pinnacle.output.v1.GetPresentTimingRequest
+---@field output_name string?
+
+---@class pinnacle.output.v1.GetPresentTimingResponse
+---@field refresh_rate_mhz integer?
+---@field ms_since_last_presentation integer?
+
 ---@class pinnacle.output.v1.GetFocusedRequest
 ---@field output_name string?
 
@@ -993,6 +985,12 @@ local pinnacle_window_v1_DecorationMode = {
 ---@class pinnacle.output.v1.GetPoweredResponse
 ---@field powered boolean?
 
+---@class pinnacle.output.v1.GetBlankedRequest
+---@field output_name string?
+
+---@class pinnacle.output.v1.GetBlankedResponse
+---@field blanked boolean?
+
 ---@class pinnacle.output.v1.GetFocusStackWindowIdsRequest
 ---@field output_name string?
 
@@ -1006,254 +1004,106 @@ local pinnacle_window_v1_DecorationMode = {
 ---@class pinnacle.output.v1.GetOutputsInDirResponse
 ---@field output_names string[]?
 
----@class pinnacle.process.v1.SpawnRequest
----@field cmd string[]?
----@field unique boolean?
----@field once boolean?
----@field shell_cmd string[]?
----@field envs pinnacle.process.v1.SpawnRequest.EnvsEntry[]?
----@field pipe_stdin boolean?
----@field pipe_stdout boolean?
----@field pipe_stderr boolean?
+---@class pinnacle.output.v1.FocusInDirRequest
+---@field dir pinnacle.util.v1.Dir?
 
----@class pinnacle.process.v1.SpawnRequest.EnvsEntry
----@field key string?
----@field value string?
+---@class pinnacle.output.v1.ViewTagsRequest
+---@field output_name string?
+---@field tag_names string[]?
+---@field keep_active boolean?
 
----@class pinnacle.process.v1.SpawnData
----@field pid integer?
----@field fd_socket_path string?
----@field has_stdin boolean?
----@field has_stdout boolean?
----@field has_stderr boolean?
+---@class pinnacle.window.v1.GetRequest
 
----@class pinnacle.process.v1.SpawnResponse
----@field spawn_data pinnacle.process.v1.SpawnData?
+---@class pinnacle.window.v1.GetResponse
+---@field window_ids integer[]?
 
----@class pinnacle.process.v1.WaitOnSpawnRequest
----@field pid integer?
+---@class pinnacle.window.v1.GetAppIdRequest
+---@field window_id integer?
 
----@class pinnacle.process.v1.WaitOnSpawnResponse
----@field exit_code integer?
----@field exit_msg string?
+---@class pinnacle.window.v1.GetAppIdResponse
+---@field app_id string?
 
----@class pinnacle.process.v1.SetEnvRequest
----@field key string?
----@field value string?
+---@class pinnacle.window.v1.GetTitleRequest
+---@field window_id integer?
 
----@class pinnacle.render.v1.SetUpscaleFilterRequest
----@field filter pinnacle.render.v1.Filter?
+---@class pinnacle.window.v1.GetTitleResponse
+---@field title string?
 
----@class pinnacle.render.v1.SetDownscaleFilterRequest
----@field filter pinnacle.render.v1.Filter?
+---@class pinnacle.window.v1.GetInstanceRequest
+---@field window_id integer?
 
----@class pinnacle.signal.v1.OutputConnectRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.window.v1.GetInstanceResponse
+---@field instance string?
 
----@class pinnacle.signal.v1.OutputConnectResponse
----@field output_name string?
+---@class pinnacle.window.v1.GetRoleRequest
+---@field window_id integer?
 
----@class pinnacle.signal.v1.OutputDisconnectRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.window.v1.GetRoleResponse
+---@field role string?
 
----@class pinnacle.signal.v1.OutputDisconnectResponse
----@field output_name string?
+---@class pinnacle.window.v1.GetLocRequest
+---@field window_id integer?
 
----@class pinnacle.signal.v1.OutputResizeRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.window.v1.GetLocResponse
+---@field loc pinnacle.util.v1.Point?
 
----@class pinnacle.signal.v1.OutputResizeResponse
----@field output_name string?
----@field logical_width integer?
----@field logical_height integer?
+---@class pinnacle.window.v1.GetSizeRequest
+---@field window_id integer?
 
----@class pinnacle.signal.v1.OutputMoveRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.window.v1.GetSizeResponse
+---@field size pinnacle.util.v1.Size?
 
----@class pinnacle.signal.v1.OutputMoveResponse
----@field output_name string?
----@field x integer?
----@field y integer?
+---@class pinnacle.window.v1.GetFocusedRequest
+---@field window_id integer?
 
----@class pinnacle.signal.v1.OutputPointerEnterRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.window.v1.GetFocusedResponse
+---@field focused boolean?
 
----@class pinnacle.signal.v1.OutputPointerEnterResponse
----@field output_name string?
+---@class pinnacle.window.v1.GetUrgentRequest
+---@field window_id integer?
 
----@class pinnacle.signal.v1.OutputPointerLeaveRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.window.v1.GetUrgentResponse
+---@field urgent boolean?
 
----@class pinnacle.signal.v1.OutputPointerLeaveResponse
----@field output_name string?
+---@class pinnacle.window.v1.GetLayoutModeRequest
+---@field window_id integer?
 
----@class pinnacle.signal.v1.OutputFocusedRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.window.v1.GetLayoutModeResponse
+---@field layout_mode pinnacle.window.v1.LayoutMode?
 
----@class pinnacle.signal.v1.OutputFocusedResponse
----@field output_name string?
+---@class pinnacle.window.v1.GetTagIdsRequest
+---@field window_id integer?
 
----@class pinnacle.signal.v1.WindowPointerEnterRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.window.v1.GetTagIdsResponse
+---@field tag_ids integer[]?
 
----@class pinnacle.signal.v1.WindowPointerEnterResponse
----@field window_id integer?
-
----@class pinnacle.signal.v1.WindowPointerLeaveRequest
----@field control pinnacle.signal.v1.StreamControl?
-
----@class pinnacle.signal.v1.WindowPointerLeaveResponse
----@field window_id integer?
-
----@class pinnacle.signal.v1.WindowFocusedRequest
----@field control pinnacle.signal.v1.StreamControl?
-
----@class pinnacle.signal.v1.WindowFocusedResponse
+---@class pinnacle.window.v1.GetWindowsInDirRequest
 ---@field window_id integer?
+---@field dir pinnacle.util.v1.Dir?
 
----@class pinnacle.signal.v1.WindowTitleChangedRequest
----@field control pinnacle.signal.v1.StreamControl?
+---@class pinnacle.window.v1.GetWindowsInDirResponse
+---@field window_ids integer[]?
 
----@class pinnacle.signal.v1.WindowTitleChangedResponse
+---@class pinnacle.window.v1.GetForeignToplevelListIdentifierRequest
 ---@field window_id integer?
----@field title string?
-
----@class pinnacle.signal.v1.TagActiveRequest
----@field control pinnacle.signal.v1.StreamControl?
-
----@class pinnacle.signal.v1.TagActiveResponse
----@field tag_id integer?
----@field active boolean?
-
----@class pinnacle.signal.v1.InputDeviceAddedRequest
----@field control pinnacle.signal.v1.StreamControl?
-
----@class pinnacle.signal.v1.InputDeviceAddedResponse
----@field device_sysname string?
-
----@class pinnacle.tag.v1.GetRequest
-
----@class pinnacle.tag.v1.GetResponse
----@field tag_ids integer[]?
-
----@class pinnacle.tag.v1.AddRequest
----@field output_name string?
----@field tag_names string[]?
-
----@class pinnacle.tag.v1.AddResponse
----@field tag_ids integer[]?
-
----@class pinnacle.tag.v1.RemoveRequest
----@field tag_ids integer[]?
 
----@class pinnacle.tag.v1.GetActiveRequest
----@field tag_id integer?
-
----@class pinnacle.tag.v1.GetActiveResponse
----@field active boolean?
-
----@class pinnacle.tag.v1.GetNameRequest
----@field tag_id integer?
-
----@class pinnacle.tag.v1.GetNameResponse
----@field name string?
-
----@class pinnacle.tag.v1.GetOutputNameRequest
----@field tag_id integer?
+---@class pinnacle.window.v1.GetForeignToplevelListIdentifierResponse
+---@field identifier string?
 
----@class pinnacle.tag.v1.GetOutputNameResponse
+---@class pinnacle.window.v1.GetAllWithPropsRequest
 ---@field output_name string?
 
----@class pinnacle.tag.v1.SetActiveRequest
----@field tag_id integer?
----@field set_or_toggle pinnacle.util.v1.SetOrToggle?
-
----@class pinnacle.tag.v1.SwitchToRequest
----@field tag_id integer?
-
----@class pinnacle.v1.QuitRequest
-
----@class pinnacle.v1.ReloadConfigRequest
-
----@class pinnacle.v1.KeepaliveRequest
-
----@class pinnacle.v1.KeepaliveResponse
-
----@class pinnacle.v1.BackendRequest
-
----@class pinnacle.v1.BackendResponse
----@field backend pinnacle.v1.Backend?
-
----@class pinnacle.v1.SetXwaylandClientSelfScaleRequest
----@field self_scale boolean?
-
----@class pinnacle.v1.SetLastErrorRequest
----@field error string?
-
----@class pinnacle.v1.TakeLastErrorRequest
-
----@class pinnacle.v1.TakeLastErrorResponse
----@field error string?
-
----@class pinnacle.window.v1.GetRequest
-
----@class pinnacle.window.v1.GetResponse
----@field window_ids integer[]?
-
----@class pinnacle.window.v1.GetAppIdRequest
----@field window_id integer?
-
----@class pinnacle.window.v1.GetAppIdResponse
----@field app_id string?
-
----@class pinnacle.window.v1.GetTitleRequest
+---@class pinnacle.window.v1.WindowProps
 ---@field window_id integer?
-
----@class pinnacle.window.v1.GetTitleResponse
 ---@field title string?
-
----@class pinnacle.window.v1.GetLocRequest
----@field window_id integer?
-
----@class pinnacle.window.v1.GetLocResponse
+---@field app_id string?
+---@field tag_ids integer[]?
 ---@field loc pinnacle.util.v1.Point?
-
----@class pinnacle.window.v1.GetSizeRequest
----@field window_id integer?
-
----@class pinnacle.window.v1.GetSizeResponse
 ---@field size pinnacle.util.v1.Size?
-
----@class pinnacle.window.v1.GetFocusedRequest
----@field window_id integer?
-
----@class pinnacle.window.v1.GetFocusedResponse
----@field focused boolean?
-
----@class pinnacle.window.v1.GetLayoutModeRequest
----@field window_id integer?
-
----@class pinnacle.window.v1.GetLayoutModeResponse
 ---@field layout_mode pinnacle.window.v1.LayoutMode?
 
----@class pinnacle.window.v1.GetTagIdsRequest
----@field window_id integer?
-
----@class pinnacle.window.v1.GetTagIdsResponse
----@field tag_ids integer[]?
-
----@class pinnacle.window.v1.GetWindowsInDirRequest
----@field window_id integer?
----@field dir pinnacle.util.v1.Dir?
-
----@class pinnacle.window.v1.GetWindowsInDirResponse
----@field window_ids integer[]?
-
----@class pinnacle.window.v1.GetForeignToplevelListIdentifierRequest
----@field window_id integer?
-
----@class pinnacle.window.v1.GetForeignToplevelListIdentifierResponse
----@field identifier string?
+---@class pinnacle.window.v1.GetAllWithPropsResponse
+---@field windows pinnacle.window.v1.WindowProps[]?
 
 ---@class pinnacle.window.v1.CloseRequest
 ---@field window_id integer?
@@ -1265,6 +1115,13 @@ local pinnacle_window_v1_DecorationMode = {
 ---@field w integer?
 ---@field h integer?
 
+---@class pinnacle.window.v1.FloatWithGeometryRequest
+---@field window_id integer?
+---@field x integer?
+---@field y integer?
+---@field w integer?
+---@field h integer?
+
 ---@class pinnacle.window.v1.ResizeTileRequest
 ---@field window_id integer?
 ---@field left integer?
@@ -1295,6 +1152,7 @@ local pinnacle_window_v1_DecorationMode = {
 ---@class pinnacle.window.v1.MoveToTagRequest
 ---@field window_id integer?
 ---@field tag_id integer?
+---@field insert_position pinnacle.window.v1.InsertPosition?
 
 ---@class pinnacle.window.v1.SetTagRequest
 ---@field window_id integer?
@@ -1307,6 +1165,9 @@ local pinnacle_window_v1_DecorationMode = {
 
 ---@class pinnacle.window.v1.SetTagsResponse
 
+---@class pinnacle.window.v1.SetTagsBulkRequest
+---@field windows pinnacle.window.v1.SetTagsRequest[]?
+
 ---@class pinnacle.window.v1.VrrDemand
 ---@field fullscreen boolean?
 
@@ -1342,6 +1203,100 @@ local pinnacle_window_v1_DecorationMode = {
 
 ---@class pinnacle.window.v1.SwapResponse
 
+---@class pinnacle.window.v1.SetMinimizeRestoreOnTagRequest
+---@field restore boolean?
+
+---@class pinnacle.window.v1.SetUrgentRequest
+---@field window_id integer?
+---@field urgent boolean?
+
+---@class pinnacle.window.v1.SetAppIdOverrideRequest
+---@field window_id integer?
+---@field app_id string?
+
+---@class pinnacle.window.v1.SetWarpCursorOnFocusRequest
+---@field window_id integer?
+---@field warp boolean?
+
+---@class pinnacle.window.v1.SetGrabAllInputRequest
+---@field window_id integer?
+---@field grab_all_input boolean?
+
+---@class pinnacle.window.v1.SetInactiveOpacityRequest
+---@field opacity number?
+
+---@class pinnacle.window.v1.SetDecorationPolicyRequest
+---@field policy pinnacle.window.v1.DecorationPolicy?
+
+---@class pinnacle.window.v1.SetOffscreenPolicyRequest
+---@field policy pinnacle.window.v1.OffscreenPolicy?
+---@field min_visible integer?
+
+---@class pinnacle.window.v1.SetBackgroundFpsCapRequest
+---@field fps_cap integer?
+
+---@class pinnacle.window.v1.SetFloatingAppIdsRequest
+---@field app_ids string[]?
+
+---@class pinnacle.window.v1.SetFloatingTitlesRequest
+---@field titles string[]?
+
+---@class pinnacle.window.v1.WindowMatcher
+---@field app_id string?
+---@field title string?
+
+---@class pinnacle.window.v1.AddGeometryRuleRequest
+---@field matcher pinnacle.window.v1.WindowMatcher?
+---@field geometry pinnacle.util.v1.Rect?
+
+---@class pinnacle.window.v1.SetPreferredScaleRequest
+---@field window_id integer?
+---@field scale number?
+
+---@class pinnacle.window.v1.SetAspectRatioRequest
+---@field window_id integer?
+---@field width integer?
+---@field height integer?
+
+---@class pinnacle.window.v1.SetCloseBehaviorRequest
+---@field window_id integer?
+---@field behavior pinnacle.window.v1.CloseBehavior?
+---@field scratchpad_name string?
+
+---@class pinnacle.window.v1.ToScratchpadRequest
+---@field window_id integer?
+---@field name string?
+
+---@class pinnacle.window.v1.ToggleScratchpadRequest
+---@field name string?
+
+---@class pinnacle.window.v1.CreateGroupRequest
+---@field name string?
+
+---@class pinnacle.window.v1.RemoveGroupRequest
+---@field name string?
+
+---@class pinnacle.window.v1.AddToGroupRequest
+---@field window_id integer?
+---@field group_name string?
+
+---@class pinnacle.window.v1.RemoveFromGroupRequest
+---@field window_id integer?
+---@field group_name string?
+
+---@class pinnacle.window.v1.GetGroupWindowsRequest
+---@field name string?
+
+---@class pinnacle.window.v1.GetGroupWindowsResponse
+---@field window_ids integer[]?
+
+---@class pinnacle.window.v1.FocusInGroupRequest
+---@field backward boolean?
+
+---@class pinnacle.window.v1.AddToGroupsOfRequest
+---@field window_id integer?
+---@field target_id integer?
+
 ---@class pinnacle.window.v1.WindowRuleRequest
 ---@field finished pinnacle.window.v1.WindowRuleRequest.Finished?
 
@@ -1355,86 +1310,718 @@ local pinnacle_window_v1_DecorationMode = {
 ---@field request_id integer?
 ---@field window_id integer?
 
-local google = {}
-google.protobuf = {}
-google.protobuf.Empty = {}
-local pinnacle = {}
-pinnacle.util = {}
-pinnacle.util.v1 = {}
-pinnacle.util.v1.Point = {}
-pinnacle.util.v1.Size = {}
-pinnacle.util.v1.Rect = {}
-pinnacle.debug = {}
-pinnacle.debug.v1 = {}
-pinnacle.debug.v1.SetDamageVisualizationRequest = {}
-pinnacle.debug.v1.SetOpaqueRegionVisualizationRequest = {}
-pinnacle.debug.v1.SetCursorPlaneScanoutRequest = {}
-pinnacle.debug.v1.SetProcessPipingRequest = {}
-pinnacle.input = {}
-pinnacle.input.v1 = {}
-pinnacle.input.v1.Bind = {}
-pinnacle.input.v1.BindRequest = {}
-pinnacle.input.v1.BindResponse = {}
-pinnacle.input.v1.BindProperties = {}
-pinnacle.input.v1.SetBindPropertiesRequest = {}
-pinnacle.input.v1.Keybind = {}
-pinnacle.input.v1.KeybindStreamRequest = {}
-pinnacle.input.v1.KeybindStreamResponse = {}
-pinnacle.input.v1.KeybindOnPressRequest = {}
-pinnacle.input.v1.Mousebind = {}
-pinnacle.input.v1.MousebindStreamRequest = {}
-pinnacle.input.v1.MousebindStreamResponse = {}
-pinnacle.input.v1.MousebindOnPressRequest = {}
-pinnacle.input.v1.GetBindInfosRequest = {}
-pinnacle.input.v1.GetBindInfosResponse = {}
-pinnacle.input.v1.BindInfo = {}
-pinnacle.input.v1.GetBindLayerStackRequest = {}
-pinnacle.input.v1.GetBindLayerStackResponse = {}
-pinnacle.input.v1.EnterBindLayerRequest = {}
-pinnacle.input.v1.SetXkbConfigRequest = {}
-pinnacle.input.v1.SetRepeatRateRequest = {}
-pinnacle.input.v1.SetXkbKeymapRequest = {}
-pinnacle.input.v1.SwitchXkbLayoutRequest = {}
-pinnacle.input.v1.SetXcursorRequest = {}
-pinnacle.input.v1.CalibrationMatrix = {}
-pinnacle.input.v1.GetDevicesRequest = {}
-pinnacle.input.v1.GetDevicesResponse = {}
-pinnacle.input.v1.GetDeviceCapabilitiesRequest = {}
-pinnacle.input.v1.GetDeviceCapabilitiesResponse = {}
-pinnacle.input.v1.GetDeviceInfoRequest = {}
-pinnacle.input.v1.GetDeviceInfoResponse = {}
-pinnacle.input.v1.GetDeviceTypeRequest = {}
-pinnacle.input.v1.GetDeviceTypeResponse = {}
-pinnacle.input.v1.SetDeviceLibinputSettingRequest = {}
-pinnacle.input.v1.SetDeviceMapTargetRequest = {}
-pinnacle.layout = {}
-pinnacle.layout.v1 = {}
-pinnacle.layout.v1.Gaps = {}
-pinnacle.layout.v1.LayoutNode = {}
-pinnacle.layout.v1.LayoutNode.TraversalOverridesEntry = {}
-pinnacle.layout.v1.TraversalOverrides = {}
-pinnacle.layout.v1.NodeStyle = {}
-pinnacle.layout.v1.LayoutRequest = {}
-pinnacle.layout.v1.LayoutRequest.TreeResponse = {}
-pinnacle.layout.v1.LayoutRequest.ForceLayout = {}
-pinnacle.layout.v1.LayoutResponse = {}
+---@class pinnacle.debug.v1.SetDamageVisualizationRequest
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.debug.v1.SetOpaqueRegionVisualizationRequest
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.debug.v1.SetCursorPlaneScanoutRequest
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.debug.v1.SetDirectScanoutRequest
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.debug.v1.SetProcessPipingRequest
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.debug.v1.SetOpaqueRegionCullingRequest
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.debug.v1.DumpStateRequest
+
+---@class pinnacle.debug.v1.TagDump
+---@field tag_id integer?
+---@field name string?
+---@field active boolean?
+
+---@class pinnacle.debug.v1.OutputDump
+---@field name string?
+---@field geometry pinnacle.util.v1.Rect?
+---@field current_mode pinnacle.output.v1.Mode?
+---@field modes pinnacle.output.v1.Mode[]?
+---@field scale number?
+---@field enabled boolean?
+---@field tags pinnacle.debug.v1.TagDump[]?
+
+---@class pinnacle.debug.v1.WindowDump
+---@field window_id integer?
+---@field app_id string?
+---@field title string?
+---@field tag_ids integer[]?
+---@field layout_mode pinnacle.window.v1.LayoutMode?
+---@field geometry pinnacle.util.v1.Rect?
+
+---@class pinnacle.debug.v1.DumpStateResponse
+---@field outputs pinnacle.debug.v1.OutputDump[]?
+---@field windows pinnacle.debug.v1.WindowDump[]?
+---@field focused_output_name string?
+---@field focused_window_id integer?
+
+---@class pinnacle.input.v1.Bind
+---@field mods pinnacle.input.v1.Modifier[]?
+---@field ignore_mods pinnacle.input.v1.Modifier[]?
+---@field layer_name string?
+---@field properties pinnacle.input.v1.BindProperties?
+---@field key pinnacle.input.v1.Keybind?
+---@field mouse pinnacle.input.v1.Mousebind?
+
+---@class pinnacle.input.v1.BindRequest
+---@field bind pinnacle.input.v1.Bind?
+
+---@class pinnacle.input.v1.BindResponse
+---@field bind_id integer?
+
+---@class pinnacle.input.v1.BindProperties
+---@field group string?
+---@field description string?
+---@field quit boolean?
+---@field reload_config boolean?
+---@field allow_when_locked boolean?
+---@field repeat boolean?
+
+---@class pinnacle.input.v1.SetBindPropertiesRequest
+---@field bind_id integer?
+---@field properties pinnacle.input.v1.BindProperties?
+
+---@class pinnacle.input.v1.Keybind
+---@field key_code integer?
+---@field xkb_name string?
+
+---@class pinnacle.input.v1.KeybindStreamRequest
+---@field bind_id integer?
+
+---@class pinnacle.input.v1.KeybindStreamResponse
+---@field edge pinnacle.input.v1.Edge?
+
+---@class pinnacle.input.v1.KeybindOnPressRequest
+---@field bind_id integer?
+
+---@class pinnacle.input.v1.Mousebind
+---@field button integer?
+
+---@class pinnacle.input.v1.MousebindStreamRequest
+---@field bind_id integer?
+
+---@class pinnacle.input.v1.MousebindStreamResponse
+---@field edge pinnacle.input.v1.Edge?
+---@field double_click boolean?
+
+---@class pinnacle.input.v1.MousebindOnPressRequest
+---@field bind_id integer?
+
+---@class pinnacle.input.v1.ButtonMappingKeybind
+---@field mods pinnacle.input.v1.Modifier[]?
+---@field key pinnacle.input.v1.Keybind?
+
+---@class pinnacle.input.v1.ButtonMapping
+---@field from_button integer?
+---@field button integer?
+---@field keybind pinnacle.input.v1.ButtonMappingKeybind?
+
+---@class pinnacle.input.v1.SetMouseButtonMapRequest
+---@field mappings pinnacle.input.v1.ButtonMapping[]?
+
+---@class pinnacle.input.v1.SetMiddleClickPasteRequest
+---@field enabled boolean?
+
+---@class pinnacle.input.v1.GestureBindStreamRequest
+---@field fingers integer?
+---@field gesture pinnacle.input.v1.Gesture?
+
+---@class pinnacle.input.v1.GestureBindStreamResponse
+---@field scale number?
+
+---@class pinnacle.input.v1.SetGrabAllInputEscapeRequest
+---@field mods pinnacle.input.v1.Modifier[]?
+---@field key pinnacle.input.v1.Keybind?
+
+---@class pinnacle.input.v1.SetRelativeMotionScaleRequest
+---@field scale number?
+
+---@class pinnacle.input.v1.SetRelativeMotionPolicyRequest
+---@field policy pinnacle.input.v1.RelativeMotionPolicy?
+
+---@class pinnacle.input.v1.SetResizeEdgeThresholdRequest
+---@field threshold integer?
+
+---@class pinnacle.input.v1.SetPointerDefaultPositionRequest
+---@field enabled boolean?
+
+---@class pinnacle.input.v1.SetWarpOnOutputFocusRequest
+---@field enabled boolean?
+
+---@class pinnacle.input.v1.SetDoubleClickTimeRequest
+---@field time_ms integer?
+
+---@class pinnacle.input.v1.ResetPointerPositionRequest
+
+---@class pinnacle.input.v1.ConfinePointerToOutputRequest
+---@field output_name string?
+
+---@class pinnacle.input.v1.GetBindInfosRequest
+
+---@class pinnacle.input.v1.GetBindInfosResponse
+---@field bind_infos pinnacle.input.v1.BindInfo[]?
+
+---@class pinnacle.input.v1.BindInfo
+---@field bind_id integer?
+---@field bind pinnacle.input.v1.Bind?
+
+---@class pinnacle.input.v1.GetBindLayerStackRequest
+
+---@class pinnacle.input.v1.GetBindLayerStackResponse
+---@field layer_names string[]?
+
+---@class pinnacle.input.v1.EnterBindLayerRequest
+---@field layer_name string?
+
+---@class pinnacle.input.v1.ExitBindLayerRequest
+
+---@class pinnacle.input.v1.SetBindLayerPassthroughRequest
+---@field layer_name string?
+---@field passthrough boolean?
+
+---@class pinnacle.input.v1.SetXkbConfigRequest
+---@field rules string?
+---@field variant string?
+---@field layout string?
+---@field model string?
+---@field options string?
+
+---@class pinnacle.input.v1.SetRepeatRateRequest
+---@field rate integer?
+---@field delay integer?
+
+---@class pinnacle.input.v1.SetXkbKeymapRequest
+---@field keymap string?
+
+---@class pinnacle.input.v1.SwitchXkbLayoutRequest
+---@field next google.protobuf.Empty?
+---@field prev google.protobuf.Empty?
+---@field index integer?
+
+---@class pinnacle.input.v1.SetStickyKeysRequest
+---@field enabled boolean?
+
+---@class pinnacle.input.v1.SetNumlockRequest
+---@field enabled boolean?
+
+---@class pinnacle.input.v1.SetComposeEnabledRequest
+---@field enabled boolean?
+
+---@class pinnacle.input.v1.SetXcursorRequest
+---@field theme string?
+---@field size integer?
+
+---@class pinnacle.input.v1.SetCursorVisibleRequest
+---@field visible boolean?
+
+---@class pinnacle.input.v1.SetCursorAutohideRequest
+---@field hide_after_ms integer?
+
+---@class pinnacle.input.v1.TypeStringRequest
+---@field text string?
+
+---@class pinnacle.input.v1.SendComboRequest
+---@field keys pinnacle.input.v1.Keybind[]?
+
+---@class pinnacle.input.v1.SetIdleDimRequest
+---@field dim_after_ms integer?
+---@field dim_level number?
+
+---@class pinnacle.input.v1.GetPointerFocusRequest
+
+---@class pinnacle.input.v1.GetPointerFocusResponse
+---@field window_id integer?
+---@field layer pinnacle.input.v1.LayerSurfaceInfo?
+
+---@class pinnacle.input.v1.LayerSurfaceInfo
+---@field namespace string?
+---@field output_name string?
+
+---@class pinnacle.input.v1.GetKeyboardFocusRequest
+
+---@class pinnacle.input.v1.GetKeyboardFocusResponse
+---@field window_id integer?
+
+---@class pinnacle.input.v1.GetIdleInhibitedRequest
+
+---@class pinnacle.input.v1.GetIdleInhibitedResponse
+---@field inhibited boolean?
+
+---@class pinnacle.input.v1.CalibrationMatrix
+---@field matrix number[]?
+
+---@class pinnacle.input.v1.GetDevicesRequest
+
+---@class pinnacle.input.v1.GetDevicesResponse
+---@field device_sysnames string[]?
+
+---@class pinnacle.input.v1.GetDeviceCapabilitiesRequest
+---@field device_sysname string?
+
+---@class pinnacle.input.v1.GetDeviceCapabilitiesResponse
+---@field keyboard boolean?
+---@field pointer boolean?
+---@field touch boolean?
+---@field tablet_tool boolean?
+---@field tablet_pad boolean?
+---@field gesture boolean?
+---@field switch boolean?
+
+---@class pinnacle.input.v1.GetDeviceInfoRequest
+---@field device_sysname string?
+
+---@class pinnacle.input.v1.GetDeviceInfoResponse
+---@field name string?
+---@field product_id integer?
+---@field vendor_id integer?
+
+---@class pinnacle.input.v1.GetDeviceTypeRequest
+---@field device_sysname string?
+
+---@class pinnacle.input.v1.GetDeviceTypeResponse
+---@field device_type pinnacle.input.v1.DeviceType?
+
+---@class pinnacle.input.v1.SetDeviceLibinputSettingRequest
+---@field device_sysname string?
+---@field accel_profile pinnacle.input.v1.AccelProfile?
+---@field accel_speed number?
+---@field calibration_matrix pinnacle.input.v1.CalibrationMatrix?
+---@field click_method pinnacle.input.v1.ClickMethod?
+---@field disable_while_typing boolean?
+---@field left_handed boolean?
+---@field middle_emulation boolean?
+---@field rotation_angle integer?
+---@field scroll_button integer?
+---@field scroll_button_lock boolean?
+---@field scroll_method pinnacle.input.v1.ScrollMethod?
+---@field natural_scroll boolean?
+---@field tap_button_map pinnacle.input.v1.TapButtonMap?
+---@field tap_drag boolean?
+---@field tap_drag_lock boolean?
+---@field tap boolean?
+---@field send_events_mode pinnacle.input.v1.SendEventsMode?
+
+---@class pinnacle.input.v1.SetDeviceMapTargetRequest
+---@field device_sysname string?
+---@field region pinnacle.util.v1.Rect?
+---@field output_name string?
+
+---@class pinnacle.layout.v1.Gaps
+---@field left number?
+---@field right number?
+---@field top number?
+---@field bottom number?
+
+---@class pinnacle.layout.v1.LayoutNode
+---@field label string?
+---@field traversal_index integer?
+---@field traversal_overrides pinnacle.layout.v1.LayoutNode.TraversalOverridesEntry[]?
+---@field style pinnacle.layout.v1.NodeStyle?
+---@field children pinnacle.layout.v1.LayoutNode[]?
+
+---@class pinnacle.layout.v1.LayoutNode.TraversalOverridesEntry
+---@field key integer?
+---@field value pinnacle.layout.v1.TraversalOverrides?
+
+---@class pinnacle.layout.v1.TraversalOverrides
+---@field overrides integer[]?
+
+---@class pinnacle.layout.v1.NodeStyle
+---@field flex_dir pinnacle.layout.v1.FlexDir?
+---@field size_proportion number?
+---@field gaps pinnacle.layout.v1.Gaps?
+
+---@class pinnacle.layout.v1.LayoutRequest
+---@field tree_response pinnacle.layout.v1.LayoutRequest.TreeResponse?
+---@field force_layout pinnacle.layout.v1.LayoutRequest.ForceLayout?
+
+---@class pinnacle.layout.v1.LayoutRequest.TreeResponse
+---@field request_id integer?
+---@field tree_id integer?
+---@field root_node pinnacle.layout.v1.LayoutNode?
+---@field output_name string?
+
+---@class pinnacle.layout.v1.LayoutRequest.ForceLayout
+---@field output_name string?
+
+---@class pinnacle.layout.v1.LayoutResponse
+---@field request_id integer?
+---@field output_name string?
+---@field window_count integer?
+---@field tag_ids integer[]?
+
+---@class pinnacle.process.v1.SpawnRequest
+---@field cmd string[]?
+---@field unique boolean?
+---@field once boolean?
+---@field shell_cmd string[]?
+---@field envs pinnacle.process.v1.SpawnRequest.EnvsEntry[]?
+---@field pipe_stdin boolean?
+---@field pipe_stdout boolean?
+---@field pipe_stderr boolean?
+
+---@class pinnacle.process.v1.SpawnRequest.EnvsEntry
+---@field key string?
+---@field value string?
+
+---@class pinnacle.process.v1.SpawnData
+---@field pid integer?
+---@field fd_socket_path string?
+---@field has_stdin boolean?
+---@field has_stdout boolean?
+---@field has_stderr boolean?
+
+---@class pinnacle.process.v1.SpawnResponse
+---@field spawn_data pinnacle.process.v1.SpawnData?
+
+---@class pinnacle.process.v1.WaitOnSpawnRequest
+---@field pid integer?
+
+---@class pinnacle.process.v1.WaitOnSpawnResponse
+---@field exit_code integer?
+---@field exit_msg string?
+
+---@class pinnacle.process.v1.SetEnvRequest
+---@field key string?
+---@field value string?
+
+---@class pinnacle.process.v1.ReapRequest
+
+---@class pinnacle.process.v1.ReapResponse
+---@field pids integer[]?
+
+---@class pinnacle.process.v1.ChildStatus
+---@field pid integer?
+---@field alive boolean?
+
+---@class pinnacle.process.v1.GetChildrenRequest
+
+---@class pinnacle.process.v1.GetChildrenResponse
+---@field children pinnacle.process.v1.ChildStatus[]?
+
+---@class pinnacle.render.v1.SetUpscaleFilterRequest
+---@field filter pinnacle.render.v1.Filter?
+
+---@class pinnacle.render.v1.SetDownscaleFilterRequest
+---@field filter pinnacle.render.v1.Filter?
+
+---@class pinnacle.render.v1.ScreenshotWindowRequest
+---@field window_id integer?
+---@field path string?
+
+---@class pinnacle.render.v1.ScreenshotWindowResponse
+---@field width integer?
+---@field height integer?
+---@field rgba string?
+
+---@class pinnacle.render.v1.OutputThumbnailRequest
+---@field output_name string?
+---@field max_width integer?
+---@field max_height integer?
+
+---@class pinnacle.render.v1.OutputThumbnailResponse
+---@field width integer?
+---@field height integer?
+---@field rgba string?
+
+---@class pinnacle.render.v1.GetRenderStateRequest
+---@field output_name string?
+
+---@class pinnacle.render.v1.GetRenderStateResponse
+---@field last_result pinnacle.render.v1.RenderResult?
+---@field ms_since_last_presentation integer?
+---@field render_scheduled boolean?
+
+---@class pinnacle.render.v1.SetAnimationFpsCapRequest
+---@field fps_cap integer?
+
+---@class pinnacle.render.v1.SetFrameCallbackPolicyRequest
+---@field policy pinnacle.render.v1.FrameCallbackPolicy?
+---@field throttle_interval_ms integer?
+
+---@class pinnacle.render.v1.SetCursorModeRequest
+---@field mode pinnacle.render.v1.CursorMode?
+
+---@class pinnacle.render.v1.SetOverviewRequest
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.signal.v1.OutputConnectRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputConnectResponse
+---@field output_name string?
+
+---@class pinnacle.signal.v1.OutputDisconnectRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputDisconnectResponse
+---@field output_name string?
+
+---@class pinnacle.signal.v1.OutputResizeRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputResizeResponse
+---@field output_name string?
+---@field logical_width integer?
+---@field logical_height integer?
+
+---@class pinnacle.signal.v1.OutputMoveRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputMoveResponse
+---@field output_name string?
+---@field x integer?
+---@field y integer?
+
+---@class pinnacle.signal.v1.OutputPointerEnterRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputPointerEnterResponse
+---@field output_name string?
+
+---@class pinnacle.signal.v1.OutputPointerLeaveRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputPointerLeaveResponse
+---@field output_name string?
+
+---@class pinnacle.signal.v1.OutputFocusedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.OutputFocusedResponse
+---@field output_name string?
+
+---@class pinnacle.signal.v1.WindowPointerEnterRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowPointerEnterResponse
+---@field window_id integer?
+
+---@class pinnacle.signal.v1.WindowPointerLeaveRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowPointerLeaveResponse
+---@field window_id integer?
+
+---@class pinnacle.signal.v1.WindowFocusedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowFocusedResponse
+---@field window_id integer?
+
+---@class pinnacle.signal.v1.WindowTitleChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowTitleChangedResponse
+---@field window_id integer?
+---@field title string?
+
+---@class pinnacle.signal.v1.WindowUrgentChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowUrgentChangedResponse
+---@field window_id integer?
+---@field urgent boolean?
+
+---@class pinnacle.signal.v1.WindowLayoutModeChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowLayoutModeChangedResponse
+---@field window_id integer?
+---@field layout_mode pinnacle.window.v1.LayoutMode?
+
+---@class pinnacle.signal.v1.WindowGeometryChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.WindowGeometryChangedResponse
+---@field window_id integer?
+---@field geometry pinnacle.util.v1.Rect?
+
+---@class pinnacle.signal.v1.TagActiveRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.TagActiveResponse
+---@field tag_id integer?
+---@field active boolean?
+
+---@class pinnacle.signal.v1.TagWindowCountChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.TagWindowCountChangedResponse
+---@field tag_id integer?
+---@field count integer?
+
+---@class pinnacle.signal.v1.InputDeviceAddedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.InputDeviceAddedResponse
+---@field device_sysname string?
+
+---@class pinnacle.signal.v1.PinnaclePrepareForSleepRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.PinnaclePrepareForSleepResponse
+---@field sleeping boolean?
+
+---@class pinnacle.signal.v1.PinnacleOutputLayoutChangedRequest
+---@field control pinnacle.signal.v1.StreamControl?
+
+---@class pinnacle.signal.v1.PinnacleOutputLayoutChangedResponse
+
+---@class pinnacle.tag.v1.GetRequest
+
+---@class pinnacle.tag.v1.GetResponse
+---@field tag_ids integer[]?
+
+---@class pinnacle.tag.v1.AddRequest
+---@field output_name string?
+---@field tag_names string[]?
+
+---@class pinnacle.tag.v1.AddResponse
+---@field tag_ids integer[]?
+
+---@class pinnacle.tag.v1.RemoveRequest
+---@field tag_ids integer[]?
+
+---@class pinnacle.tag.v1.GetActiveRequest
+---@field tag_id integer?
+
+---@class pinnacle.tag.v1.GetActiveResponse
+---@field active boolean?
+
+---@class pinnacle.tag.v1.GetNameRequest
+---@field tag_id integer?
+
+---@class pinnacle.tag.v1.GetNameResponse
+---@field name string?
+
+---@class pinnacle.tag.v1.GetOutputNameRequest
+---@field tag_id integer?
+
+---@class pinnacle.tag.v1.GetOutputNameResponse
+---@field output_name string?
+
+---@class pinnacle.tag.v1.SetActiveRequest
+---@field tag_id integer?
+---@field set_or_toggle pinnacle.util.v1.SetOrToggle?
+
+---@class pinnacle.tag.v1.SwitchToRequest
+---@field tag_id integer?
+
+---@class pinnacle.tag.v1.MoveToOutputRequest
+---@field tag_id integer?
+---@field output_name string?
+
+---@class pinnacle.tag.v1.SetOutputRequest
+---@field tag_id integer?
+---@field output_name string?
+
+---@class pinnacle.tag.v1.SwapRequest
+---@field tag_id integer?
+---@field other_tag_id integer?
+
+---@class pinnacle.v1.QuitRequest
+
+---@class pinnacle.v1.ReloadConfigRequest
+
+---@class pinnacle.v1.KeepaliveRequest
+
+---@class pinnacle.v1.KeepaliveResponse
+
+---@class pinnacle.v1.BackendRequest
+
+---@class pinnacle.v1.BackendResponse
+---@field backend pinnacle.v1.Backend?
+
+---@class pinnacle.v1.DmabufFormat
+---@field fourcc integer?
+---@field modifier integer?
+
+---@class pinnacle.v1.RendererInfoRequest
+
+---@class pinnacle.v1.RendererInfoResponse
+---@field renderer string?
+---@field vendor string?
+---@field version string?
+---@field gpu_path string?
+---@field dmabuf_formats pinnacle.v1.DmabufFormat[]?
+---@field hardware_accel_error string?
+
+---@class pinnacle.v1.SetXwaylandClientSelfScaleRequest
+---@field self_scale boolean?
+
+---@class pinnacle.v1.SetPreserveLayoutOnReloadRequest
+---@field preserve boolean?
+
+---@class pinnacle.v1.SetLastErrorRequest
+---@field error string?
+
+---@class pinnacle.v1.TakeLastErrorRequest
+
+---@class pinnacle.v1.TakeLastErrorResponse
+---@field error string?
+
+---@class pinnacle.v1.MetricsRequest
+
+---@class pinnacle.v1.MetricsResponse
+---@field windows integer?
+---@field outputs integer?
+---@field frames_rendered integer?
+---@field frames_skipped integer?
+---@field active_grpc_streams integer?
+---@field config_restarts integer?
+---@field memory_usage integer?
+
+local google = {}
+google.protobuf = {}
+google.protobuf.Empty = {}
+local pinnacle = {}
+pinnacle.util = {}
+pinnacle.util.v1 = {}
+pinnacle.util.v1.Point = {}
+pinnacle.util.v1.Size = {}
+pinnacle.util.v1.Rect = {}
 pinnacle.output = {}
 pinnacle.output.v1 = {}
 pinnacle.output.v1.SetLocRequest = {}
 pinnacle.output.v1.SetModeRequest = {}
 pinnacle.output.v1.Modeline = {}
+pinnacle.output.v1.TryModeRequest = {}
+pinnacle.output.v1.ConfirmModeRequest = {}
 pinnacle.output.v1.SetModelineRequest = {}
 pinnacle.output.v1.SetScaleRequest = {}
 pinnacle.output.v1.SetTransformRequest = {}
 pinnacle.output.v1.SetPoweredRequest = {}
+pinnacle.output.v1.SetBlankedRequest = {}
+pinnacle.output.v1.SetLayoutModeRequest = {}
+pinnacle.output.v1.SetColorFilterRequest = {}
+pinnacle.output.v1.SetSupersampleFactorRequest = {}
 pinnacle.output.v1.SetVrrRequest = {}
 pinnacle.output.v1.SetVrrResponse = {}
+pinnacle.output.v1.VrrDemandMatcher = {}
+pinnacle.output.v1.SetVrrDemandMatcherRequest = {}
+pinnacle.output.v1.SetAutoScaleRequest = {}
+pinnacle.output.v1.SetLegacyScaleModeRequest = {}
+pinnacle.output.v1.SetDynamicTagsRequest = {}
+pinnacle.output.v1.SetDpmsTimeoutRequest = {}
+pinnacle.output.v1.SetTransitionAnimationRequest = {}
+pinnacle.output.v1.SetOcclusionCullingRequest = {}
+pinnacle.output.v1.SetRespectExclusiveZonesRequest = {}
+pinnacle.output.v1.SetExclusiveZoneIgnoredNamespacesRequest = {}
+pinnacle.output.v1.SetAutoResolveOverlapsRequest = {}
+pinnacle.output.v1.SetGameModeRequest = {}
+pinnacle.output.v1.SetGammaFromFileRequest = {}
+pinnacle.output.v1.AddCustomModeRequest = {}
 pinnacle.output.v1.FocusRequest = {}
 pinnacle.output.v1.FocusResponse = {}
 pinnacle.output.v1.GetRequest = {}
 pinnacle.output.v1.GetResponse = {}
 pinnacle.output.v1.GetInfoRequest = {}
 pinnacle.output.v1.GetInfoResponse = {}
+pinnacle.output.v1.SetIdentityOverrideRequest = {}
 pinnacle.output.v1.GetLocRequest = {}
 pinnacle.output.v1.GetLocResponse = {}
 pinnacle.output.v1.GetLogicalSizeRequest = {}
@@ -1444,6 +2031,8 @@ pinnacle.output.v1.GetPhysicalSizeResponse = {}
 pinnacle.output.v1.Mode = {}
 pinnacle.output.v1.GetModesRequest = {}
 pinnacle.output.v1.GetModesResponse = {}
+pinnacle.output.v1.GetPresentTimingRequest = {}
+pinnacle.output.v1.GetPresentTimingResponse = {}
 pinnacle.output.v1.GetFocusedRequest = {}
 pinnacle.output.v1.GetFocusedResponse = {}
 pinnacle.output.v1.GetTagIdsRequest = {}
@@ -1456,10 +2045,194 @@ pinnacle.output.v1.GetEnabledRequest = {}
 pinnacle.output.v1.GetEnabledResponse = {}
 pinnacle.output.v1.GetPoweredRequest = {}
 pinnacle.output.v1.GetPoweredResponse = {}
+pinnacle.output.v1.GetBlankedRequest = {}
+pinnacle.output.v1.GetBlankedResponse = {}
 pinnacle.output.v1.GetFocusStackWindowIdsRequest = {}
 pinnacle.output.v1.GetFocusStackWindowIdsResponse = {}
 pinnacle.output.v1.GetOutputsInDirRequest = {}
 pinnacle.output.v1.GetOutputsInDirResponse = {}
+pinnacle.output.v1.FocusInDirRequest = {}
+pinnacle.output.v1.ViewTagsRequest = {}
+pinnacle.window = {}
+pinnacle.window.v1 = {}
+pinnacle.window.v1.GetRequest = {}
+pinnacle.window.v1.GetResponse = {}
+pinnacle.window.v1.GetAppIdRequest = {}
+pinnacle.window.v1.GetAppIdResponse = {}
+pinnacle.window.v1.GetTitleRequest = {}
+pinnacle.window.v1.GetTitleResponse = {}
+pinnacle.window.v1.GetInstanceRequest = {}
+pinnacle.window.v1.GetInstanceResponse = {}
+pinnacle.window.v1.GetRoleRequest = {}
+pinnacle.window.v1.GetRoleResponse = {}
+pinnacle.window.v1.GetLocRequest = {}
+pinnacle.window.v1.GetLocResponse = {}
+pinnacle.window.v1.GetSizeRequest = {}
+pinnacle.window.v1.GetSizeResponse = {}
+pinnacle.window.v1.GetFocusedRequest = {}
+pinnacle.window.v1.GetFocusedResponse = {}
+pinnacle.window.v1.GetUrgentRequest = {}
+pinnacle.window.v1.GetUrgentResponse = {}
+pinnacle.window.v1.GetLayoutModeRequest = {}
+pinnacle.window.v1.GetLayoutModeResponse = {}
+pinnacle.window.v1.GetTagIdsRequest = {}
+pinnacle.window.v1.GetTagIdsResponse = {}
+pinnacle.window.v1.GetWindowsInDirRequest = {}
+pinnacle.window.v1.GetWindowsInDirResponse = {}
+pinnacle.window.v1.GetForeignToplevelListIdentifierRequest = {}
+pinnacle.window.v1.GetForeignToplevelListIdentifierResponse = {}
+pinnacle.window.v1.GetAllWithPropsRequest = {}
+pinnacle.window.v1.WindowProps = {}
+pinnacle.window.v1.GetAllWithPropsResponse = {}
+pinnacle.window.v1.CloseRequest = {}
+pinnacle.window.v1.SetGeometryRequest = {}
+pinnacle.window.v1.FloatWithGeometryRequest = {}
+pinnacle.window.v1.ResizeTileRequest = {}
+pinnacle.window.v1.SetFullscreenRequest = {}
+pinnacle.window.v1.SetMaximizedRequest = {}
+pinnacle.window.v1.SetFloatingRequest = {}
+pinnacle.window.v1.SetFocusedRequest = {}
+pinnacle.window.v1.SetDecorationModeRequest = {}
+pinnacle.window.v1.MoveToTagRequest = {}
+pinnacle.window.v1.SetTagRequest = {}
+pinnacle.window.v1.SetTagsRequest = {}
+pinnacle.window.v1.SetTagsResponse = {}
+pinnacle.window.v1.SetTagsBulkRequest = {}
+pinnacle.window.v1.VrrDemand = {}
+pinnacle.window.v1.SetVrrDemandRequest = {}
+pinnacle.window.v1.SetVrrDemandResponse = {}
+pinnacle.window.v1.MoveToOutputRequest = {}
+pinnacle.window.v1.MoveToOutputResponse = {}
+pinnacle.window.v1.RaiseRequest = {}
+pinnacle.window.v1.LowerRequest = {}
+pinnacle.window.v1.LowerResponse = {}
+pinnacle.window.v1.MoveGrabRequest = {}
+pinnacle.window.v1.ResizeGrabRequest = {}
+pinnacle.window.v1.SwapRequest = {}
+pinnacle.window.v1.SwapResponse = {}
+pinnacle.window.v1.SetMinimizeRestoreOnTagRequest = {}
+pinnacle.window.v1.SetUrgentRequest = {}
+pinnacle.window.v1.SetAppIdOverrideRequest = {}
+pinnacle.window.v1.SetWarpCursorOnFocusRequest = {}
+pinnacle.window.v1.SetGrabAllInputRequest = {}
+pinnacle.window.v1.SetInactiveOpacityRequest = {}
+pinnacle.window.v1.SetDecorationPolicyRequest = {}
+pinnacle.window.v1.SetOffscreenPolicyRequest = {}
+pinnacle.window.v1.SetBackgroundFpsCapRequest = {}
+pinnacle.window.v1.SetFloatingAppIdsRequest = {}
+pinnacle.window.v1.SetFloatingTitlesRequest = {}
+pinnacle.window.v1.WindowMatcher = {}
+pinnacle.window.v1.AddGeometryRuleRequest = {}
+pinnacle.window.v1.SetPreferredScaleRequest = {}
+pinnacle.window.v1.SetAspectRatioRequest = {}
+pinnacle.window.v1.SetCloseBehaviorRequest = {}
+pinnacle.window.v1.ToScratchpadRequest = {}
+pinnacle.window.v1.ToggleScratchpadRequest = {}
+pinnacle.window.v1.CreateGroupRequest = {}
+pinnacle.window.v1.RemoveGroupRequest = {}
+pinnacle.window.v1.AddToGroupRequest = {}
+pinnacle.window.v1.RemoveFromGroupRequest = {}
+pinnacle.window.v1.GetGroupWindowsRequest = {}
+pinnacle.window.v1.GetGroupWindowsResponse = {}
+pinnacle.window.v1.FocusInGroupRequest = {}
+pinnacle.window.v1.AddToGroupsOfRequest = {}
+pinnacle.window.v1.WindowRuleRequest = {}
+pinnacle.window.v1.WindowRuleRequest.Finished = {}
+pinnacle.window.v1.WindowRuleResponse = {}
+pinnacle.window.v1.WindowRuleResponse.NewWindowRequest = {}
+pinnacle.debug = {}
+pinnacle.debug.v1 = {}
+pinnacle.debug.v1.SetDamageVisualizationRequest = {}
+pinnacle.debug.v1.SetOpaqueRegionVisualizationRequest = {}
+pinnacle.debug.v1.SetCursorPlaneScanoutRequest = {}
+pinnacle.debug.v1.SetDirectScanoutRequest = {}
+pinnacle.debug.v1.SetProcessPipingRequest = {}
+pinnacle.debug.v1.SetOpaqueRegionCullingRequest = {}
+pinnacle.debug.v1.DumpStateRequest = {}
+pinnacle.debug.v1.TagDump = {}
+pinnacle.debug.v1.OutputDump = {}
+pinnacle.debug.v1.WindowDump = {}
+pinnacle.debug.v1.DumpStateResponse = {}
+pinnacle.input = {}
+pinnacle.input.v1 = {}
+pinnacle.input.v1.Bind = {}
+pinnacle.input.v1.BindRequest = {}
+pinnacle.input.v1.BindResponse = {}
+pinnacle.input.v1.BindProperties = {}
+pinnacle.input.v1.SetBindPropertiesRequest = {}
+pinnacle.input.v1.Keybind = {}
+pinnacle.input.v1.KeybindStreamRequest = {}
+pinnacle.input.v1.KeybindStreamResponse = {}
+pinnacle.input.v1.KeybindOnPressRequest = {}
+pinnacle.input.v1.Mousebind = {}
+pinnacle.input.v1.MousebindStreamRequest = {}
+pinnacle.input.v1.MousebindStreamResponse = {}
+pinnacle.input.v1.MousebindOnPressRequest = {}
+pinnacle.input.v1.ButtonMappingKeybind = {}
+pinnacle.input.v1.ButtonMapping = {}
+pinnacle.input.v1.SetMouseButtonMapRequest = {}
+pinnacle.input.v1.SetMiddleClickPasteRequest = {}
+pinnacle.input.v1.GestureBindStreamRequest = {}
+pinnacle.input.v1.GestureBindStreamResponse = {}
+pinnacle.input.v1.SetGrabAllInputEscapeRequest = {}
+pinnacle.input.v1.SetRelativeMotionScaleRequest = {}
+pinnacle.input.v1.SetRelativeMotionPolicyRequest = {}
+pinnacle.input.v1.SetResizeEdgeThresholdRequest = {}
+pinnacle.input.v1.SetPointerDefaultPositionRequest = {}
+pinnacle.input.v1.SetWarpOnOutputFocusRequest = {}
+pinnacle.input.v1.SetDoubleClickTimeRequest = {}
+pinnacle.input.v1.ResetPointerPositionRequest = {}
+pinnacle.input.v1.ConfinePointerToOutputRequest = {}
+pinnacle.input.v1.GetBindInfosRequest = {}
+pinnacle.input.v1.GetBindInfosResponse = {}
+pinnacle.input.v1.BindInfo = {}
+pinnacle.input.v1.GetBindLayerStackRequest = {}
+pinnacle.input.v1.GetBindLayerStackResponse = {}
+pinnacle.input.v1.EnterBindLayerRequest = {}
+pinnacle.input.v1.ExitBindLayerRequest = {}
+pinnacle.input.v1.SetBindLayerPassthroughRequest = {}
+pinnacle.input.v1.SetXkbConfigRequest = {}
+pinnacle.input.v1.SetRepeatRateRequest = {}
+pinnacle.input.v1.SetXkbKeymapRequest = {}
+pinnacle.input.v1.SwitchXkbLayoutRequest = {}
+pinnacle.input.v1.SetStickyKeysRequest = {}
+pinnacle.input.v1.SetNumlockRequest = {}
+pinnacle.input.v1.SetComposeEnabledRequest = {}
+pinnacle.input.v1.SetXcursorRequest = {}
+pinnacle.input.v1.SetCursorVisibleRequest = {}
+pinnacle.input.v1.SetCursorAutohideRequest = {}
+pinnacle.input.v1.TypeStringRequest = {}
+pinnacle.input.v1.SendComboRequest = {}
+pinnacle.input.v1.SetIdleDimRequest = {}
+pinnacle.input.v1.GetPointerFocusRequest = {}
+pinnacle.input.v1.GetPointerFocusResponse = {}
+pinnacle.input.v1.LayerSurfaceInfo = {}
+pinnacle.input.v1.GetKeyboardFocusRequest = {}
+pinnacle.input.v1.GetKeyboardFocusResponse = {}
+pinnacle.input.v1.GetIdleInhibitedRequest = {}
+pinnacle.input.v1.GetIdleInhibitedResponse = {}
+pinnacle.input.v1.CalibrationMatrix = {}
+pinnacle.input.v1.GetDevicesRequest = {}
+pinnacle.input.v1.GetDevicesResponse = {}
+pinnacle.input.v1.GetDeviceCapabilitiesRequest = {}
+pinnacle.input.v1.GetDeviceCapabilitiesResponse = {}
+pinnacle.input.v1.GetDeviceInfoRequest = {}
+pinnacle.input.v1.GetDeviceInfoResponse = {}
+pinnacle.input.v1.GetDeviceTypeRequest = {}
+pinnacle.input.v1.GetDeviceTypeResponse = {}
+pinnacle.input.v1.SetDeviceLibinputSettingRequest = {}
+pinnacle.input.v1.SetDeviceMapTargetRequest = {}
+pinnacle.layout = {}
+pinnacle.layout.v1 = {}
+pinnacle.layout.v1.Gaps = {}
+pinnacle.layout.v1.LayoutNode = {}
+pinnacle.layout.v1.LayoutNode.TraversalOverridesEntry = {}
+pinnacle.layout.v1.TraversalOverrides = {}
+pinnacle.layout.v1.NodeStyle = {}
+pinnacle.layout.v1.LayoutRequest = {}
+pinnacle.layout.v1.LayoutRequest.TreeResponse = {}
+pinnacle.layout.v1.LayoutRequest.ForceLayout = {}
+pinnacle.layout.v1.LayoutResponse = {}
 pinnacle.process = {}
 pinnacle.process.v1 = {}
 pinnacle.process.v1.SpawnRequest = {}
@@ -1469,10 +2242,25 @@ pinnacle.process.v1.SpawnResponse = {}
 pinnacle.process.v1.WaitOnSpawnRequest = {}
 pinnacle.process.v1.WaitOnSpawnResponse = {}
 pinnacle.process.v1.SetEnvRequest = {}
+pinnacle.process.v1.ReapRequest = {}
+pinnacle.process.v1.ReapResponse = {}
+pinnacle.process.v1.ChildStatus = {}
+pinnacle.process.v1.GetChildrenRequest = {}
+pinnacle.process.v1.GetChildrenResponse = {}
 pinnacle.render = {}
 pinnacle.render.v1 = {}
 pinnacle.render.v1.SetUpscaleFilterRequest = {}
 pinnacle.render.v1.SetDownscaleFilterRequest = {}
+pinnacle.render.v1.ScreenshotWindowRequest = {}
+pinnacle.render.v1.ScreenshotWindowResponse = {}
+pinnacle.render.v1.OutputThumbnailRequest = {}
+pinnacle.render.v1.OutputThumbnailResponse = {}
+pinnacle.render.v1.GetRenderStateRequest = {}
+pinnacle.render.v1.GetRenderStateResponse = {}
+pinnacle.render.v1.SetAnimationFpsCapRequest = {}
+pinnacle.render.v1.SetFrameCallbackPolicyRequest = {}
+pinnacle.render.v1.SetCursorModeRequest = {}
+pinnacle.render.v1.SetOverviewRequest = {}
 pinnacle.signal = {}
 pinnacle.signal.v1 = {}
 pinnacle.signal.v1.OutputConnectRequest = {}
@@ -1497,10 +2285,22 @@ pinnacle.signal.v1.WindowFocusedRequest = {}
 pinnacle.signal.v1.WindowFocusedResponse = {}
 pinnacle.signal.v1.WindowTitleChangedRequest = {}
 pinnacle.signal.v1.WindowTitleChangedResponse = {}
+pinnacle.signal.v1.WindowUrgentChangedRequest = {}
+pinnacle.signal.v1.WindowUrgentChangedResponse = {}
+pinnacle.signal.v1.WindowLayoutModeChangedRequest = {}
+pinnacle.signal.v1.WindowLayoutModeChangedResponse = {}
+pinnacle.signal.v1.WindowGeometryChangedRequest = {}
+pinnacle.signal.v1.WindowGeometryChangedResponse = {}
 pinnacle.signal.v1.TagActiveRequest = {}
 pinnacle.signal.v1.TagActiveResponse = {}
+pinnacle.signal.v1.TagWindowCountChangedRequest = {}
+pinnacle.signal.v1.TagWindowCountChangedResponse = {}
 pinnacle.signal.v1.InputDeviceAddedRequest = {}
 pinnacle.signal.v1.InputDeviceAddedResponse = {}
+pinnacle.signal.v1.PinnaclePrepareForSleepRequest = {}
+pinnacle.signal.v1.PinnaclePrepareForSleepResponse = {}
+pinnacle.signal.v1.PinnacleOutputLayoutChangedRequest = {}
+pinnacle.signal.v1.PinnacleOutputLayoutChangedResponse = {}
 pinnacle.tag = {}
 pinnacle.tag.v1 = {}
 pinnacle.tag.v1.GetRequest = {}
@@ -1516,6 +2316,9 @@ pinnacle.tag.v1.GetOutputNameRequest = {}
 pinnacle.tag.v1.GetOutputNameResponse = {}
 pinnacle.tag.v1.SetActiveRequest = {}
 pinnacle.tag.v1.SwitchToRequest = {}
+pinnacle.tag.v1.MoveToOutputRequest = {}
+pinnacle.tag.v1.SetOutputRequest = {}
+pinnacle.tag.v1.SwapRequest = {}
 pinnacle.v1 = {}
 pinnacle.v1.QuitRequest = {}
 pinnacle.v1.ReloadConfigRequest = {}
@@ -1523,65 +2326,35 @@ pinnacle.v1.KeepaliveRequest = {}
 pinnacle.v1.KeepaliveResponse = {}
 pinnacle.v1.BackendRequest = {}
 pinnacle.v1.BackendResponse = {}
+pinnacle.v1.DmabufFormat = {}
+pinnacle.v1.RendererInfoRequest = {}
+pinnacle.v1.RendererInfoResponse = {}
 pinnacle.v1.SetXwaylandClientSelfScaleRequest = {}
+pinnacle.v1.SetPreserveLayoutOnReloadRequest = {}
 pinnacle.v1.SetLastErrorRequest = {}
 pinnacle.v1.TakeLastErrorRequest = {}
 pinnacle.v1.TakeLastErrorResponse = {}
-pinnacle.window = {}
-pinnacle.window.v1 = {}
-pinnacle.window.v1.GetRequest = {}
-pinnacle.window.v1.GetResponse = {}
-pinnacle.window.v1.GetAppIdRequest = {}
-pinnacle.window.v1.GetAppIdResponse = {}
-pinnacle.window.v1.GetTitleRequest = {}
-pinnacle.window.v1.GetTitleResponse = {}
-pinnacle.window.v1.GetLocRequest = {}
-pinnacle.window.v1.GetLocResponse = {}
-pinnacle.window.v1.GetSizeRequest = {}
-pinnacle.window.v1.GetSizeResponse = {}
-pinnacle.window.v1.GetFocusedRequest = {}
-pinnacle.window.v1.GetFocusedResponse = {}
-pinnacle.window.v1.GetLayoutModeRequest = {}
-pinnacle.window.v1.GetLayoutModeResponse = {}
-pinnacle.window.v1.GetTagIdsRequest = {}
-pinnacle.window.v1.GetTagIdsResponse = {}
-pinnacle.window.v1.GetWindowsInDirRequest = {}
-pinnacle.window.v1.GetWindowsInDirResponse = {}
-pinnacle.window.v1.GetForeignToplevelListIdentifierRequest = {}
-pinnacle.window.v1.GetForeignToplevelListIdentifierResponse = {}
-pinnacle.window.v1.CloseRequest = {}
-pinnacle.window.v1.SetGeometryRequest = {}
-pinnacle.window.v1.ResizeTileRequest = {}
-pinnacle.window.v1.SetFullscreenRequest = {}
-pinnacle.window.v1.SetMaximizedRequest = {}
-pinnacle.window.v1.SetFloatingRequest = {}
-pinnacle.window.v1.SetFocusedRequest = {}
-pinnacle.window.v1.SetDecorationModeRequest = {}
-pinnacle.window.v1.MoveToTagRequest = {}
-pinnacle.window.v1.SetTagRequest = {}
-pinnacle.window.v1.SetTagsRequest = {}
-pinnacle.window.v1.SetTagsResponse = {}
-pinnacle.window.v1.VrrDemand = {}
-pinnacle.window.v1.SetVrrDemandRequest = {}
-pinnacle.window.v1.SetVrrDemandResponse = {}
-pinnacle.window.v1.MoveToOutputRequest = {}
-pinnacle.window.v1.MoveToOutputResponse = {}
-pinnacle.window.v1.RaiseRequest = {}
-pinnacle.window.v1.LowerRequest = {}
-pinnacle.window.v1.LowerResponse = {}
-pinnacle.window.v1.MoveGrabRequest = {}
-pinnacle.window.v1.ResizeGrabRequest = {}
-pinnacle.window.v1.SwapRequest = {}
-pinnacle.window.v1.SwapResponse = {}
-pinnacle.window.v1.WindowRuleRequest = {}
-pinnacle.window.v1.WindowRuleRequest.Finished = {}
-pinnacle.window.v1.WindowRuleResponse = {}
-pinnacle.window.v1.WindowRuleResponse.NewWindowRequest = {}
+pinnacle.v1.MetricsRequest = {}
+pinnacle.v1.MetricsResponse = {}
 pinnacle.util.v1.SetOrToggle = pinnacle_util_v1_SetOrToggle
 pinnacle.util.v1.AbsOrRel = pinnacle_util_v1_AbsOrRel
 pinnacle.util.v1.Dir = pinnacle_util_v1_Dir
+pinnacle.output.v1.Transform = pinnacle_output_v1_Transform
+pinnacle.output.v1.LayoutMode = pinnacle_output_v1_LayoutMode
+pinnacle.output.v1.ColorFilter = pinnacle_output_v1_ColorFilter
+pinnacle.output.v1.Vrr = pinnacle_output_v1_Vrr
+pinnacle.output.v1.ContentType = pinnacle_output_v1_ContentType
+pinnacle.output.v1.LegacyScaleMode = pinnacle_output_v1_LegacyScaleMode
+pinnacle.window.v1.LayoutMode = pinnacle_window_v1_LayoutMode
+pinnacle.window.v1.DecorationMode = pinnacle_window_v1_DecorationMode
+pinnacle.window.v1.InsertPosition = pinnacle_window_v1_InsertPosition
+pinnacle.window.v1.DecorationPolicy = pinnacle_window_v1_DecorationPolicy
+pinnacle.window.v1.OffscreenPolicy = pinnacle_window_v1_OffscreenPolicy
+pinnacle.window.v1.CloseBehavior = pinnacle_window_v1_CloseBehavior
 pinnacle.input.v1.Modifier = pinnacle_input_v1_Modifier
 pinnacle.input.v1.Edge = pinnacle_input_v1_Edge
+pinnacle.input.v1.Gesture = pinnacle_input_v1_Gesture
+pinnacle.input.v1.RelativeMotionPolicy = pinnacle_input_v1_RelativeMotionPolicy
 pinnacle.input.v1.ClickMethod = pinnacle_input_v1_ClickMethod
 pinnacle.input.v1.AccelProfile = pinnacle_input_v1_AccelProfile
 pinnacle.input.v1.ScrollMethod = pinnacle_input_v1_ScrollMethod
@@ -1589,14 +2362,1804 @@ pinnacle.input.v1.TapButtonMap = pinnacle_input_v1_TapButtonMap
 pinnacle.input.v1.SendEventsMode = pinnacle_input_v1_SendEventsMode
 pinnacle.input.v1.DeviceType = pinnacle_input_v1_DeviceType
 pinnacle.layout.v1.FlexDir = pinnacle_layout_v1_FlexDir
-pinnacle.output.v1.Transform = pinnacle_output_v1_Transform
-pinnacle.output.v1.Vrr = pinnacle_output_v1_Vrr
 pinnacle.render.v1.Filter = pinnacle_render_v1_Filter
+pinnacle.render.v1.RenderResult = pinnacle_render_v1_RenderResult
+pinnacle.render.v1.FrameCallbackPolicy = pinnacle_render_v1_FrameCallbackPolicy
+pinnacle.render.v1.CursorMode = pinnacle_render_v1_CursorMode
 pinnacle.signal.v1.StreamControl = pinnacle_signal_v1_StreamControl
 pinnacle.v1.Backend = pinnacle_v1_Backend
-pinnacle.window.v1.LayoutMode = pinnacle_window_v1_LayoutMode
-pinnacle.window.v1.DecorationMode = pinnacle_window_v1_DecorationMode
 
+pinnacle.output.v1.OutputService = {}
+pinnacle.output.v1.OutputService.Get = {}
+pinnacle.output.v1.OutputService.Get.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.Get.method = "Get"
+pinnacle.output.v1.OutputService.Get.request = ".pinnacle.output.v1.GetRequest"
+pinnacle.output.v1.OutputService.Get.response = ".pinnacle.output.v1.GetResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetRequest
+---
+---@return pinnacle.output.v1.GetResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_Get(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.Get, data)
+end
+pinnacle.output.v1.OutputService.SetLoc = {}
+pinnacle.output.v1.OutputService.SetLoc.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetLoc.method = "SetLoc"
+pinnacle.output.v1.OutputService.SetLoc.request = ".pinnacle.output.v1.SetLocRequest"
+pinnacle.output.v1.OutputService.SetLoc.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetLocRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetLoc(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetLoc, data)
+end
+pinnacle.output.v1.OutputService.SetMode = {}
+pinnacle.output.v1.OutputService.SetMode.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetMode.method = "SetMode"
+pinnacle.output.v1.OutputService.SetMode.request = ".pinnacle.output.v1.SetModeRequest"
+pinnacle.output.v1.OutputService.SetMode.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetModeRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetMode(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetMode, data)
+end
+pinnacle.output.v1.OutputService.SetModeline = {}
+pinnacle.output.v1.OutputService.SetModeline.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetModeline.method = "SetModeline"
+pinnacle.output.v1.OutputService.SetModeline.request = ".pinnacle.output.v1.SetModelineRequest"
+pinnacle.output.v1.OutputService.SetModeline.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetModelineRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetModeline(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetModeline, data)
+end
+pinnacle.output.v1.OutputService.TryMode = {}
+pinnacle.output.v1.OutputService.TryMode.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.TryMode.method = "TryMode"
+pinnacle.output.v1.OutputService.TryMode.request = ".pinnacle.output.v1.TryModeRequest"
+pinnacle.output.v1.OutputService.TryMode.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.TryModeRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_TryMode(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.TryMode, data)
+end
+pinnacle.output.v1.OutputService.ConfirmMode = {}
+pinnacle.output.v1.OutputService.ConfirmMode.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.ConfirmMode.method = "ConfirmMode"
+pinnacle.output.v1.OutputService.ConfirmMode.request = ".pinnacle.output.v1.ConfirmModeRequest"
+pinnacle.output.v1.OutputService.ConfirmMode.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.ConfirmModeRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_ConfirmMode(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.ConfirmMode, data)
+end
+pinnacle.output.v1.OutputService.SetScale = {}
+pinnacle.output.v1.OutputService.SetScale.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetScale.method = "SetScale"
+pinnacle.output.v1.OutputService.SetScale.request = ".pinnacle.output.v1.SetScaleRequest"
+pinnacle.output.v1.OutputService.SetScale.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetScaleRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetScale(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetScale, data)
+end
+pinnacle.output.v1.OutputService.SetTransform = {}
+pinnacle.output.v1.OutputService.SetTransform.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetTransform.method = "SetTransform"
+pinnacle.output.v1.OutputService.SetTransform.request = ".pinnacle.output.v1.SetTransformRequest"
+pinnacle.output.v1.OutputService.SetTransform.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetTransformRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetTransform(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetTransform, data)
+end
+pinnacle.output.v1.OutputService.SetPowered = {}
+pinnacle.output.v1.OutputService.SetPowered.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetPowered.method = "SetPowered"
+pinnacle.output.v1.OutputService.SetPowered.request = ".pinnacle.output.v1.SetPoweredRequest"
+pinnacle.output.v1.OutputService.SetPowered.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetPoweredRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetPowered(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetPowered, data)
+end
+pinnacle.output.v1.OutputService.SetBlanked = {}
+pinnacle.output.v1.OutputService.SetBlanked.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetBlanked.method = "SetBlanked"
+pinnacle.output.v1.OutputService.SetBlanked.request = ".pinnacle.output.v1.SetBlankedRequest"
+pinnacle.output.v1.OutputService.SetBlanked.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetBlankedRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetBlanked(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetBlanked, data)
+end
+pinnacle.output.v1.OutputService.SetVrr = {}
+pinnacle.output.v1.OutputService.SetVrr.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetVrr.method = "SetVrr"
+pinnacle.output.v1.OutputService.SetVrr.request = ".pinnacle.output.v1.SetVrrRequest"
+pinnacle.output.v1.OutputService.SetVrr.response = ".pinnacle.output.v1.SetVrrResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetVrrRequest
+---
+---@return pinnacle.output.v1.SetVrrResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetVrr(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetVrr, data)
+end
+pinnacle.output.v1.OutputService.SetLayoutMode = {}
+pinnacle.output.v1.OutputService.SetLayoutMode.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetLayoutMode.method = "SetLayoutMode"
+pinnacle.output.v1.OutputService.SetLayoutMode.request = ".pinnacle.output.v1.SetLayoutModeRequest"
+pinnacle.output.v1.OutputService.SetLayoutMode.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetLayoutModeRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetLayoutMode(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetLayoutMode, data)
+end
+pinnacle.output.v1.OutputService.SetColorFilter = {}
+pinnacle.output.v1.OutputService.SetColorFilter.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetColorFilter.method = "SetColorFilter"
+pinnacle.output.v1.OutputService.SetColorFilter.request = ".pinnacle.output.v1.SetColorFilterRequest"
+pinnacle.output.v1.OutputService.SetColorFilter.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetColorFilterRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetColorFilter(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetColorFilter, data)
+end
+pinnacle.output.v1.OutputService.SetSupersampleFactor = {}
+pinnacle.output.v1.OutputService.SetSupersampleFactor.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetSupersampleFactor.method = "SetSupersampleFactor"
+pinnacle.output.v1.OutputService.SetSupersampleFactor.request = ".pinnacle.output.v1.SetSupersampleFactorRequest"
+pinnacle.output.v1.OutputService.SetSupersampleFactor.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetSupersampleFactorRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetSupersampleFactor(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetSupersampleFactor, data)
+end
+pinnacle.output.v1.OutputService.SetVrrDemandMatcher = {}
+pinnacle.output.v1.OutputService.SetVrrDemandMatcher.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetVrrDemandMatcher.method = "SetVrrDemandMatcher"
+pinnacle.output.v1.OutputService.SetVrrDemandMatcher.request = ".pinnacle.output.v1.SetVrrDemandMatcherRequest"
+pinnacle.output.v1.OutputService.SetVrrDemandMatcher.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetVrrDemandMatcherRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetVrrDemandMatcher(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetVrrDemandMatcher, data)
+end
+pinnacle.output.v1.OutputService.SetAutoScale = {}
+pinnacle.output.v1.OutputService.SetAutoScale.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetAutoScale.method = "SetAutoScale"
+pinnacle.output.v1.OutputService.SetAutoScale.request = ".pinnacle.output.v1.SetAutoScaleRequest"
+pinnacle.output.v1.OutputService.SetAutoScale.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetAutoScaleRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetAutoScale(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetAutoScale, data)
+end
+pinnacle.output.v1.OutputService.SetLegacyScaleMode = {}
+pinnacle.output.v1.OutputService.SetLegacyScaleMode.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetLegacyScaleMode.method = "SetLegacyScaleMode"
+pinnacle.output.v1.OutputService.SetLegacyScaleMode.request = ".pinnacle.output.v1.SetLegacyScaleModeRequest"
+pinnacle.output.v1.OutputService.SetLegacyScaleMode.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetLegacyScaleModeRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetLegacyScaleMode(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetLegacyScaleMode, data)
+end
+pinnacle.output.v1.OutputService.SetDynamicTags = {}
+pinnacle.output.v1.OutputService.SetDynamicTags.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetDynamicTags.method = "SetDynamicTags"
+pinnacle.output.v1.OutputService.SetDynamicTags.request = ".pinnacle.output.v1.SetDynamicTagsRequest"
+pinnacle.output.v1.OutputService.SetDynamicTags.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetDynamicTagsRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetDynamicTags(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetDynamicTags, data)
+end
+pinnacle.output.v1.OutputService.SetDpmsTimeout = {}
+pinnacle.output.v1.OutputService.SetDpmsTimeout.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetDpmsTimeout.method = "SetDpmsTimeout"
+pinnacle.output.v1.OutputService.SetDpmsTimeout.request = ".pinnacle.output.v1.SetDpmsTimeoutRequest"
+pinnacle.output.v1.OutputService.SetDpmsTimeout.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetDpmsTimeoutRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetDpmsTimeout(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetDpmsTimeout, data)
+end
+pinnacle.output.v1.OutputService.SetTransitionAnimation = {}
+pinnacle.output.v1.OutputService.SetTransitionAnimation.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetTransitionAnimation.method = "SetTransitionAnimation"
+pinnacle.output.v1.OutputService.SetTransitionAnimation.request = ".pinnacle.output.v1.SetTransitionAnimationRequest"
+pinnacle.output.v1.OutputService.SetTransitionAnimation.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetTransitionAnimationRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetTransitionAnimation(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetTransitionAnimation, data)
+end
+pinnacle.output.v1.OutputService.SetOcclusionCulling = {}
+pinnacle.output.v1.OutputService.SetOcclusionCulling.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetOcclusionCulling.method = "SetOcclusionCulling"
+pinnacle.output.v1.OutputService.SetOcclusionCulling.request = ".pinnacle.output.v1.SetOcclusionCullingRequest"
+pinnacle.output.v1.OutputService.SetOcclusionCulling.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetOcclusionCullingRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetOcclusionCulling(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetOcclusionCulling, data)
+end
+pinnacle.output.v1.OutputService.SetRespectExclusiveZones = {}
+pinnacle.output.v1.OutputService.SetRespectExclusiveZones.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetRespectExclusiveZones.method = "SetRespectExclusiveZones"
+pinnacle.output.v1.OutputService.SetRespectExclusiveZones.request = ".pinnacle.output.v1.SetRespectExclusiveZonesRequest"
+pinnacle.output.v1.OutputService.SetRespectExclusiveZones.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetRespectExclusiveZonesRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetRespectExclusiveZones(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetRespectExclusiveZones, data)
+end
+pinnacle.output.v1.OutputService.SetExclusiveZoneIgnoredNamespaces = {}
+pinnacle.output.v1.OutputService.SetExclusiveZoneIgnoredNamespaces.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetExclusiveZoneIgnoredNamespaces.method = "SetExclusiveZoneIgnoredNamespaces"
+pinnacle.output.v1.OutputService.SetExclusiveZoneIgnoredNamespaces.request = ".pinnacle.output.v1.SetExclusiveZoneIgnoredNamespacesRequest"
+pinnacle.output.v1.OutputService.SetExclusiveZoneIgnoredNamespaces.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetExclusiveZoneIgnoredNamespacesRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetExclusiveZoneIgnoredNamespaces(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetExclusiveZoneIgnoredNamespaces, data)
+end
+pinnacle.output.v1.OutputService.SetAutoResolveOverlaps = {}
+pinnacle.output.v1.OutputService.SetAutoResolveOverlaps.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetAutoResolveOverlaps.method = "SetAutoResolveOverlaps"
+pinnacle.output.v1.OutputService.SetAutoResolveOverlaps.request = ".pinnacle.output.v1.SetAutoResolveOverlapsRequest"
+pinnacle.output.v1.OutputService.SetAutoResolveOverlaps.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetAutoResolveOverlapsRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetAutoResolveOverlaps(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetAutoResolveOverlaps, data)
+end
+pinnacle.output.v1.OutputService.SetGameMode = {}
+pinnacle.output.v1.OutputService.SetGameMode.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetGameMode.method = "SetGameMode"
+pinnacle.output.v1.OutputService.SetGameMode.request = ".pinnacle.output.v1.SetGameModeRequest"
+pinnacle.output.v1.OutputService.SetGameMode.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetGameModeRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetGameMode(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetGameMode, data)
+end
+pinnacle.output.v1.OutputService.SetGammaFromFile = {}
+pinnacle.output.v1.OutputService.SetGammaFromFile.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetGammaFromFile.method = "SetGammaFromFile"
+pinnacle.output.v1.OutputService.SetGammaFromFile.request = ".pinnacle.output.v1.SetGammaFromFileRequest"
+pinnacle.output.v1.OutputService.SetGammaFromFile.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetGammaFromFileRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetGammaFromFile(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetGammaFromFile, data)
+end
+pinnacle.output.v1.OutputService.AddCustomMode = {}
+pinnacle.output.v1.OutputService.AddCustomMode.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.AddCustomMode.method = "AddCustomMode"
+pinnacle.output.v1.OutputService.AddCustomMode.request = ".pinnacle.output.v1.AddCustomModeRequest"
+pinnacle.output.v1.OutputService.AddCustomMode.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.AddCustomModeRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_AddCustomMode(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.AddCustomMode, data)
+end
+pinnacle.output.v1.OutputService.Focus = {}
+pinnacle.output.v1.OutputService.Focus.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.Focus.method = "Focus"
+pinnacle.output.v1.OutputService.Focus.request = ".pinnacle.output.v1.FocusRequest"
+pinnacle.output.v1.OutputService.Focus.response = ".pinnacle.output.v1.FocusResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.FocusRequest
+---
+---@return pinnacle.output.v1.FocusResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_Focus(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.Focus, data)
+end
+pinnacle.output.v1.OutputService.FocusInDir = {}
+pinnacle.output.v1.OutputService.FocusInDir.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.FocusInDir.method = "FocusInDir"
+pinnacle.output.v1.OutputService.FocusInDir.request = ".pinnacle.output.v1.FocusInDirRequest"
+pinnacle.output.v1.OutputService.FocusInDir.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.FocusInDirRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_FocusInDir(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.FocusInDir, data)
+end
+pinnacle.output.v1.OutputService.ViewTags = {}
+pinnacle.output.v1.OutputService.ViewTags.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.ViewTags.method = "ViewTags"
+pinnacle.output.v1.OutputService.ViewTags.request = ".pinnacle.output.v1.ViewTagsRequest"
+pinnacle.output.v1.OutputService.ViewTags.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.ViewTagsRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_ViewTags(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.ViewTags, data)
+end
+pinnacle.output.v1.OutputService.GetInfo = {}
+pinnacle.output.v1.OutputService.GetInfo.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetInfo.method = "GetInfo"
+pinnacle.output.v1.OutputService.GetInfo.request = ".pinnacle.output.v1.GetInfoRequest"
+pinnacle.output.v1.OutputService.GetInfo.response = ".pinnacle.output.v1.GetInfoResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetInfoRequest
+---
+---@return pinnacle.output.v1.GetInfoResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetInfo(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetInfo, data)
+end
+pinnacle.output.v1.OutputService.SetIdentityOverride = {}
+pinnacle.output.v1.OutputService.SetIdentityOverride.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.SetIdentityOverride.method = "SetIdentityOverride"
+pinnacle.output.v1.OutputService.SetIdentityOverride.request = ".pinnacle.output.v1.SetIdentityOverrideRequest"
+pinnacle.output.v1.OutputService.SetIdentityOverride.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.SetIdentityOverrideRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_SetIdentityOverride(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.SetIdentityOverride, data)
+end
+pinnacle.output.v1.OutputService.GetLoc = {}
+pinnacle.output.v1.OutputService.GetLoc.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetLoc.method = "GetLoc"
+pinnacle.output.v1.OutputService.GetLoc.request = ".pinnacle.output.v1.GetLocRequest"
+pinnacle.output.v1.OutputService.GetLoc.response = ".pinnacle.output.v1.GetLocResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetLocRequest
+---
+---@return pinnacle.output.v1.GetLocResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetLoc(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetLoc, data)
+end
+pinnacle.output.v1.OutputService.GetLogicalSize = {}
+pinnacle.output.v1.OutputService.GetLogicalSize.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetLogicalSize.method = "GetLogicalSize"
+pinnacle.output.v1.OutputService.GetLogicalSize.request = ".pinnacle.output.v1.GetLogicalSizeRequest"
+pinnacle.output.v1.OutputService.GetLogicalSize.response = ".pinnacle.output.v1.GetLogicalSizeResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetLogicalSizeRequest
+---
+---@return pinnacle.output.v1.GetLogicalSizeResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetLogicalSize(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetLogicalSize, data)
+end
+pinnacle.output.v1.OutputService.GetPhysicalSize = {}
+pinnacle.output.v1.OutputService.GetPhysicalSize.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetPhysicalSize.method = "GetPhysicalSize"
+pinnacle.output.v1.OutputService.GetPhysicalSize.request = ".pinnacle.output.v1.GetPhysicalSizeRequest"
+pinnacle.output.v1.OutputService.GetPhysicalSize.response = ".pinnacle.output.v1.GetPhysicalSizeResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetPhysicalSizeRequest
+---
+---@return pinnacle.output.v1.GetPhysicalSizeResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetPhysicalSize(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetPhysicalSize, data)
+end
+pinnacle.output.v1.OutputService.GetModes = {}
+pinnacle.output.v1.OutputService.GetModes.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetModes.method = "GetModes"
+pinnacle.output.v1.OutputService.GetModes.request = ".pinnacle.output.v1.GetModesRequest"
+pinnacle.output.v1.OutputService.GetModes.response = ".pinnacle.output.v1.GetModesResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetModesRequest
+---
+---@return pinnacle.output.v1.GetModesResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetModes(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetModes, data)
+end
+pinnacle.output.v1.OutputService.GetPresentTiming = {}
+pinnacle.output.v1.OutputService.GetPresentTiming.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetPresentTiming.method = "GetPresentTiming"
+pinnacle.output.v1.OutputService.GetPresentTiming.request = ".pinnacle.output.v1.GetPresentTimingRequest"
+pinnacle.output.v1.OutputService.GetPresentTiming.response = ".pinnacle.output.v1.GetPresentTimingResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetPresentTimingRequest
+---
+---@return pinnacle.output.v1.GetPresentTimingResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetPresentTiming(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetPresentTiming, data)
+end
+pinnacle.output.v1.OutputService.GetFocused = {}
+pinnacle.output.v1.OutputService.GetFocused.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetFocused.method = "GetFocused"
+pinnacle.output.v1.OutputService.GetFocused.request = ".pinnacle.output.v1.GetFocusedRequest"
+pinnacle.output.v1.OutputService.GetFocused.response = ".pinnacle.output.v1.GetFocusedResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetFocusedRequest
+---
+---@return pinnacle.output.v1.GetFocusedResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetFocused(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetFocused, data)
+end
+pinnacle.output.v1.OutputService.GetTagIds = {}
+pinnacle.output.v1.OutputService.GetTagIds.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetTagIds.method = "GetTagIds"
+pinnacle.output.v1.OutputService.GetTagIds.request = ".pinnacle.output.v1.GetTagIdsRequest"
+pinnacle.output.v1.OutputService.GetTagIds.response = ".pinnacle.output.v1.GetTagIdsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetTagIdsRequest
+---
+---@return pinnacle.output.v1.GetTagIdsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetTagIds(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetTagIds, data)
+end
+pinnacle.output.v1.OutputService.GetScale = {}
+pinnacle.output.v1.OutputService.GetScale.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetScale.method = "GetScale"
+pinnacle.output.v1.OutputService.GetScale.request = ".pinnacle.output.v1.GetScaleRequest"
+pinnacle.output.v1.OutputService.GetScale.response = ".pinnacle.output.v1.GetScaleResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetScaleRequest
+---
+---@return pinnacle.output.v1.GetScaleResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetScale(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetScale, data)
+end
+pinnacle.output.v1.OutputService.GetTransform = {}
+pinnacle.output.v1.OutputService.GetTransform.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetTransform.method = "GetTransform"
+pinnacle.output.v1.OutputService.GetTransform.request = ".pinnacle.output.v1.GetTransformRequest"
+pinnacle.output.v1.OutputService.GetTransform.response = ".pinnacle.output.v1.GetTransformResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetTransformRequest
+---
+---@return pinnacle.output.v1.GetTransformResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetTransform(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetTransform, data)
+end
+pinnacle.output.v1.OutputService.GetEnabled = {}
+pinnacle.output.v1.OutputService.GetEnabled.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetEnabled.method = "GetEnabled"
+pinnacle.output.v1.OutputService.GetEnabled.request = ".pinnacle.output.v1.GetEnabledRequest"
+pinnacle.output.v1.OutputService.GetEnabled.response = ".pinnacle.output.v1.GetEnabledResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetEnabledRequest
+---
+---@return pinnacle.output.v1.GetEnabledResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetEnabled(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetEnabled, data)
+end
+pinnacle.output.v1.OutputService.GetPowered = {}
+pinnacle.output.v1.OutputService.GetPowered.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetPowered.method = "GetPowered"
+pinnacle.output.v1.OutputService.GetPowered.request = ".pinnacle.output.v1.GetPoweredRequest"
+pinnacle.output.v1.OutputService.GetPowered.response = ".pinnacle.output.v1.GetPoweredResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetPoweredRequest
+---
+---@return pinnacle.output.v1.GetPoweredResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetPowered(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetPowered, data)
+end
+pinnacle.output.v1.OutputService.GetBlanked = {}
+pinnacle.output.v1.OutputService.GetBlanked.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetBlanked.method = "GetBlanked"
+pinnacle.output.v1.OutputService.GetBlanked.request = ".pinnacle.output.v1.GetBlankedRequest"
+pinnacle.output.v1.OutputService.GetBlanked.response = ".pinnacle.output.v1.GetBlankedResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetBlankedRequest
+---
+---@return pinnacle.output.v1.GetBlankedResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetBlanked(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetBlanked, data)
+end
+pinnacle.output.v1.OutputService.GetFocusStackWindowIds = {}
+pinnacle.output.v1.OutputService.GetFocusStackWindowIds.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetFocusStackWindowIds.method = "GetFocusStackWindowIds"
+pinnacle.output.v1.OutputService.GetFocusStackWindowIds.request = ".pinnacle.output.v1.GetFocusStackWindowIdsRequest"
+pinnacle.output.v1.OutputService.GetFocusStackWindowIds.response = ".pinnacle.output.v1.GetFocusStackWindowIdsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetFocusStackWindowIdsRequest
+---
+---@return pinnacle.output.v1.GetFocusStackWindowIdsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetFocusStackWindowIds(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetFocusStackWindowIds, data)
+end
+pinnacle.output.v1.OutputService.GetOutputsInDir = {}
+pinnacle.output.v1.OutputService.GetOutputsInDir.service = "pinnacle.output.v1.OutputService"
+pinnacle.output.v1.OutputService.GetOutputsInDir.method = "GetOutputsInDir"
+pinnacle.output.v1.OutputService.GetOutputsInDir.request = ".pinnacle.output.v1.GetOutputsInDirRequest"
+pinnacle.output.v1.OutputService.GetOutputsInDir.response = ".pinnacle.output.v1.GetOutputsInDirResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.output.v1.GetOutputsInDirRequest
+---
+---@return pinnacle.output.v1.GetOutputsInDirResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_output_v1_OutputService_GetOutputsInDir(data)
+    return self:unary_request(pinnacle.output.v1.OutputService.GetOutputsInDir, data)
+end
+pinnacle.window.v1.WindowService = {}
+pinnacle.window.v1.WindowService.Get = {}
+pinnacle.window.v1.WindowService.Get.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Get.method = "Get"
+pinnacle.window.v1.WindowService.Get.request = ".pinnacle.window.v1.GetRequest"
+pinnacle.window.v1.WindowService.Get.response = ".pinnacle.window.v1.GetResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetRequest
+---
+---@return pinnacle.window.v1.GetResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_Get(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Get, data)
+end
+pinnacle.window.v1.WindowService.GetAppId = {}
+pinnacle.window.v1.WindowService.GetAppId.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetAppId.method = "GetAppId"
+pinnacle.window.v1.WindowService.GetAppId.request = ".pinnacle.window.v1.GetAppIdRequest"
+pinnacle.window.v1.WindowService.GetAppId.response = ".pinnacle.window.v1.GetAppIdResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetAppIdRequest
+---
+---@return pinnacle.window.v1.GetAppIdResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetAppId(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetAppId, data)
+end
+pinnacle.window.v1.WindowService.GetTitle = {}
+pinnacle.window.v1.WindowService.GetTitle.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetTitle.method = "GetTitle"
+pinnacle.window.v1.WindowService.GetTitle.request = ".pinnacle.window.v1.GetTitleRequest"
+pinnacle.window.v1.WindowService.GetTitle.response = ".pinnacle.window.v1.GetTitleResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetTitleRequest
+---
+---@return pinnacle.window.v1.GetTitleResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetTitle(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetTitle, data)
+end
+pinnacle.window.v1.WindowService.GetInstance = {}
+pinnacle.window.v1.WindowService.GetInstance.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetInstance.method = "GetInstance"
+pinnacle.window.v1.WindowService.GetInstance.request = ".pinnacle.window.v1.GetInstanceRequest"
+pinnacle.window.v1.WindowService.GetInstance.response = ".pinnacle.window.v1.GetInstanceResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetInstanceRequest
+---
+---@return pinnacle.window.v1.GetInstanceResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetInstance(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetInstance, data)
+end
+pinnacle.window.v1.WindowService.GetRole = {}
+pinnacle.window.v1.WindowService.GetRole.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetRole.method = "GetRole"
+pinnacle.window.v1.WindowService.GetRole.request = ".pinnacle.window.v1.GetRoleRequest"
+pinnacle.window.v1.WindowService.GetRole.response = ".pinnacle.window.v1.GetRoleResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetRoleRequest
+---
+---@return pinnacle.window.v1.GetRoleResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetRole(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetRole, data)
+end
+pinnacle.window.v1.WindowService.GetLoc = {}
+pinnacle.window.v1.WindowService.GetLoc.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetLoc.method = "GetLoc"
+pinnacle.window.v1.WindowService.GetLoc.request = ".pinnacle.window.v1.GetLocRequest"
+pinnacle.window.v1.WindowService.GetLoc.response = ".pinnacle.window.v1.GetLocResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetLocRequest
+---
+---@return pinnacle.window.v1.GetLocResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetLoc(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetLoc, data)
+end
+pinnacle.window.v1.WindowService.GetSize = {}
+pinnacle.window.v1.WindowService.GetSize.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetSize.method = "GetSize"
+pinnacle.window.v1.WindowService.GetSize.request = ".pinnacle.window.v1.GetSizeRequest"
+pinnacle.window.v1.WindowService.GetSize.response = ".pinnacle.window.v1.GetSizeResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetSizeRequest
+---
+---@return pinnacle.window.v1.GetSizeResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetSize(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetSize, data)
+end
+pinnacle.window.v1.WindowService.GetFocused = {}
+pinnacle.window.v1.WindowService.GetFocused.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetFocused.method = "GetFocused"
+pinnacle.window.v1.WindowService.GetFocused.request = ".pinnacle.window.v1.GetFocusedRequest"
+pinnacle.window.v1.WindowService.GetFocused.response = ".pinnacle.window.v1.GetFocusedResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetFocusedRequest
+---
+---@return pinnacle.window.v1.GetFocusedResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetFocused(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetFocused, data)
+end
+pinnacle.window.v1.WindowService.GetUrgent = {}
+pinnacle.window.v1.WindowService.GetUrgent.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetUrgent.method = "GetUrgent"
+pinnacle.window.v1.WindowService.GetUrgent.request = ".pinnacle.window.v1.GetUrgentRequest"
+pinnacle.window.v1.WindowService.GetUrgent.response = ".pinnacle.window.v1.GetUrgentResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetUrgentRequest
+---
+---@return pinnacle.window.v1.GetUrgentResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetUrgent(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetUrgent, data)
+end
+pinnacle.window.v1.WindowService.GetLayoutMode = {}
+pinnacle.window.v1.WindowService.GetLayoutMode.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetLayoutMode.method = "GetLayoutMode"
+pinnacle.window.v1.WindowService.GetLayoutMode.request = ".pinnacle.window.v1.GetLayoutModeRequest"
+pinnacle.window.v1.WindowService.GetLayoutMode.response = ".pinnacle.window.v1.GetLayoutModeResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetLayoutModeRequest
+---
+---@return pinnacle.window.v1.GetLayoutModeResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetLayoutMode(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetLayoutMode, data)
+end
+pinnacle.window.v1.WindowService.GetTagIds = {}
+pinnacle.window.v1.WindowService.GetTagIds.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetTagIds.method = "GetTagIds"
+pinnacle.window.v1.WindowService.GetTagIds.request = ".pinnacle.window.v1.GetTagIdsRequest"
+pinnacle.window.v1.WindowService.GetTagIds.response = ".pinnacle.window.v1.GetTagIdsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetTagIdsRequest
+---
+---@return pinnacle.window.v1.GetTagIdsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetTagIds(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetTagIds, data)
+end
+pinnacle.window.v1.WindowService.GetWindowsInDir = {}
+pinnacle.window.v1.WindowService.GetWindowsInDir.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetWindowsInDir.method = "GetWindowsInDir"
+pinnacle.window.v1.WindowService.GetWindowsInDir.request = ".pinnacle.window.v1.GetWindowsInDirRequest"
+pinnacle.window.v1.WindowService.GetWindowsInDir.response = ".pinnacle.window.v1.GetWindowsInDirResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetWindowsInDirRequest
+---
+---@return pinnacle.window.v1.GetWindowsInDirResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetWindowsInDir(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetWindowsInDir, data)
+end
+pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier = {}
+pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.method = "GetForeignToplevelListIdentifier"
+pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.request = ".pinnacle.window.v1.GetForeignToplevelListIdentifierRequest"
+pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.response = ".pinnacle.window.v1.GetForeignToplevelListIdentifierResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetForeignToplevelListIdentifierRequest
+---
+---@return pinnacle.window.v1.GetForeignToplevelListIdentifierResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetForeignToplevelListIdentifier(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier, data)
+end
+pinnacle.window.v1.WindowService.GetAllWithProps = {}
+pinnacle.window.v1.WindowService.GetAllWithProps.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetAllWithProps.method = "GetAllWithProps"
+pinnacle.window.v1.WindowService.GetAllWithProps.request = ".pinnacle.window.v1.GetAllWithPropsRequest"
+pinnacle.window.v1.WindowService.GetAllWithProps.response = ".pinnacle.window.v1.GetAllWithPropsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetAllWithPropsRequest
+---
+---@return pinnacle.window.v1.GetAllWithPropsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetAllWithProps(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetAllWithProps, data)
+end
+pinnacle.window.v1.WindowService.Close = {}
+pinnacle.window.v1.WindowService.Close.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Close.method = "Close"
+pinnacle.window.v1.WindowService.Close.request = ".pinnacle.window.v1.CloseRequest"
+pinnacle.window.v1.WindowService.Close.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.CloseRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_Close(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Close, data)
+end
+pinnacle.window.v1.WindowService.SetGeometry = {}
+pinnacle.window.v1.WindowService.SetGeometry.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetGeometry.method = "SetGeometry"
+pinnacle.window.v1.WindowService.SetGeometry.request = ".pinnacle.window.v1.SetGeometryRequest"
+pinnacle.window.v1.WindowService.SetGeometry.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetGeometryRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetGeometry(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetGeometry, data)
+end
+pinnacle.window.v1.WindowService.FloatWithGeometry = {}
+pinnacle.window.v1.WindowService.FloatWithGeometry.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.FloatWithGeometry.method = "FloatWithGeometry"
+pinnacle.window.v1.WindowService.FloatWithGeometry.request = ".pinnacle.window.v1.FloatWithGeometryRequest"
+pinnacle.window.v1.WindowService.FloatWithGeometry.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.FloatWithGeometryRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_FloatWithGeometry(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.FloatWithGeometry, data)
+end
+pinnacle.window.v1.WindowService.ResizeTile = {}
+pinnacle.window.v1.WindowService.ResizeTile.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.ResizeTile.method = "ResizeTile"
+pinnacle.window.v1.WindowService.ResizeTile.request = ".pinnacle.window.v1.ResizeTileRequest"
+pinnacle.window.v1.WindowService.ResizeTile.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.ResizeTileRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_ResizeTile(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.ResizeTile, data)
+end
+pinnacle.window.v1.WindowService.SetFullscreen = {}
+pinnacle.window.v1.WindowService.SetFullscreen.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetFullscreen.method = "SetFullscreen"
+pinnacle.window.v1.WindowService.SetFullscreen.request = ".pinnacle.window.v1.SetFullscreenRequest"
+pinnacle.window.v1.WindowService.SetFullscreen.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetFullscreenRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetFullscreen(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetFullscreen, data)
+end
+pinnacle.window.v1.WindowService.SetMaximized = {}
+pinnacle.window.v1.WindowService.SetMaximized.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetMaximized.method = "SetMaximized"
+pinnacle.window.v1.WindowService.SetMaximized.request = ".pinnacle.window.v1.SetMaximizedRequest"
+pinnacle.window.v1.WindowService.SetMaximized.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetMaximizedRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetMaximized(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetMaximized, data)
+end
+pinnacle.window.v1.WindowService.SetFloating = {}
+pinnacle.window.v1.WindowService.SetFloating.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetFloating.method = "SetFloating"
+pinnacle.window.v1.WindowService.SetFloating.request = ".pinnacle.window.v1.SetFloatingRequest"
+pinnacle.window.v1.WindowService.SetFloating.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetFloatingRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetFloating(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetFloating, data)
+end
+pinnacle.window.v1.WindowService.SetFocused = {}
+pinnacle.window.v1.WindowService.SetFocused.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetFocused.method = "SetFocused"
+pinnacle.window.v1.WindowService.SetFocused.request = ".pinnacle.window.v1.SetFocusedRequest"
+pinnacle.window.v1.WindowService.SetFocused.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetFocusedRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetFocused(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetFocused, data)
+end
+pinnacle.window.v1.WindowService.SetDecorationMode = {}
+pinnacle.window.v1.WindowService.SetDecorationMode.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetDecorationMode.method = "SetDecorationMode"
+pinnacle.window.v1.WindowService.SetDecorationMode.request = ".pinnacle.window.v1.SetDecorationModeRequest"
+pinnacle.window.v1.WindowService.SetDecorationMode.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetDecorationModeRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetDecorationMode(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetDecorationMode, data)
+end
+pinnacle.window.v1.WindowService.MoveToTag = {}
+pinnacle.window.v1.WindowService.MoveToTag.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.MoveToTag.method = "MoveToTag"
+pinnacle.window.v1.WindowService.MoveToTag.request = ".pinnacle.window.v1.MoveToTagRequest"
+pinnacle.window.v1.WindowService.MoveToTag.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.MoveToTagRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_MoveToTag(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.MoveToTag, data)
+end
+pinnacle.window.v1.WindowService.SetTag = {}
+pinnacle.window.v1.WindowService.SetTag.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetTag.method = "SetTag"
+pinnacle.window.v1.WindowService.SetTag.request = ".pinnacle.window.v1.SetTagRequest"
+pinnacle.window.v1.WindowService.SetTag.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetTagRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetTag(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetTag, data)
+end
+pinnacle.window.v1.WindowService.SetTags = {}
+pinnacle.window.v1.WindowService.SetTags.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetTags.method = "SetTags"
+pinnacle.window.v1.WindowService.SetTags.request = ".pinnacle.window.v1.SetTagsRequest"
+pinnacle.window.v1.WindowService.SetTags.response = ".pinnacle.window.v1.SetTagsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetTagsRequest
+---
+---@return pinnacle.window.v1.SetTagsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetTags(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetTags, data)
+end
+pinnacle.window.v1.WindowService.SetTagsBulk = {}
+pinnacle.window.v1.WindowService.SetTagsBulk.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetTagsBulk.method = "SetTagsBulk"
+pinnacle.window.v1.WindowService.SetTagsBulk.request = ".pinnacle.window.v1.SetTagsBulkRequest"
+pinnacle.window.v1.WindowService.SetTagsBulk.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetTagsBulkRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetTagsBulk(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetTagsBulk, data)
+end
+pinnacle.window.v1.WindowService.SetVrrDemand = {}
+pinnacle.window.v1.WindowService.SetVrrDemand.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetVrrDemand.method = "SetVrrDemand"
+pinnacle.window.v1.WindowService.SetVrrDemand.request = ".pinnacle.window.v1.SetVrrDemandRequest"
+pinnacle.window.v1.WindowService.SetVrrDemand.response = ".pinnacle.window.v1.SetVrrDemandResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetVrrDemandRequest
+---
+---@return pinnacle.window.v1.SetVrrDemandResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetVrrDemand(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetVrrDemand, data)
+end
+pinnacle.window.v1.WindowService.MoveToOutput = {}
+pinnacle.window.v1.WindowService.MoveToOutput.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.MoveToOutput.method = "MoveToOutput"
+pinnacle.window.v1.WindowService.MoveToOutput.request = ".pinnacle.window.v1.MoveToOutputRequest"
+pinnacle.window.v1.WindowService.MoveToOutput.response = ".pinnacle.window.v1.MoveToOutputResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.MoveToOutputRequest
+---
+---@return pinnacle.window.v1.MoveToOutputResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_MoveToOutput(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.MoveToOutput, data)
+end
+pinnacle.window.v1.WindowService.Raise = {}
+pinnacle.window.v1.WindowService.Raise.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Raise.method = "Raise"
+pinnacle.window.v1.WindowService.Raise.request = ".pinnacle.window.v1.RaiseRequest"
+pinnacle.window.v1.WindowService.Raise.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.RaiseRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_Raise(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Raise, data)
+end
+pinnacle.window.v1.WindowService.Lower = {}
+pinnacle.window.v1.WindowService.Lower.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Lower.method = "Lower"
+pinnacle.window.v1.WindowService.Lower.request = ".pinnacle.window.v1.LowerRequest"
+pinnacle.window.v1.WindowService.Lower.response = ".pinnacle.window.v1.LowerResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.LowerRequest
+---
+---@return pinnacle.window.v1.LowerResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_Lower(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Lower, data)
+end
+pinnacle.window.v1.WindowService.MoveGrab = {}
+pinnacle.window.v1.WindowService.MoveGrab.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.MoveGrab.method = "MoveGrab"
+pinnacle.window.v1.WindowService.MoveGrab.request = ".pinnacle.window.v1.MoveGrabRequest"
+pinnacle.window.v1.WindowService.MoveGrab.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.MoveGrabRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_MoveGrab(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.MoveGrab, data)
+end
+pinnacle.window.v1.WindowService.ResizeGrab = {}
+pinnacle.window.v1.WindowService.ResizeGrab.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.ResizeGrab.method = "ResizeGrab"
+pinnacle.window.v1.WindowService.ResizeGrab.request = ".pinnacle.window.v1.ResizeGrabRequest"
+pinnacle.window.v1.WindowService.ResizeGrab.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.ResizeGrabRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_ResizeGrab(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.ResizeGrab, data)
+end
+pinnacle.window.v1.WindowService.Swap = {}
+pinnacle.window.v1.WindowService.Swap.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.Swap.method = "Swap"
+pinnacle.window.v1.WindowService.Swap.request = ".pinnacle.window.v1.SwapRequest"
+pinnacle.window.v1.WindowService.Swap.response = ".pinnacle.window.v1.SwapResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SwapRequest
+---
+---@return pinnacle.window.v1.SwapResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_Swap(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.Swap, data)
+end
+pinnacle.window.v1.WindowService.SetMinimizeRestoreOnTag = {}
+pinnacle.window.v1.WindowService.SetMinimizeRestoreOnTag.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetMinimizeRestoreOnTag.method = "SetMinimizeRestoreOnTag"
+pinnacle.window.v1.WindowService.SetMinimizeRestoreOnTag.request = ".pinnacle.window.v1.SetMinimizeRestoreOnTagRequest"
+pinnacle.window.v1.WindowService.SetMinimizeRestoreOnTag.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetMinimizeRestoreOnTagRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetMinimizeRestoreOnTag(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetMinimizeRestoreOnTag, data)
+end
+pinnacle.window.v1.WindowService.ToScratchpad = {}
+pinnacle.window.v1.WindowService.ToScratchpad.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.ToScratchpad.method = "ToScratchpad"
+pinnacle.window.v1.WindowService.ToScratchpad.request = ".pinnacle.window.v1.ToScratchpadRequest"
+pinnacle.window.v1.WindowService.ToScratchpad.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.ToScratchpadRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_ToScratchpad(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.ToScratchpad, data)
+end
+pinnacle.window.v1.WindowService.ToggleScratchpad = {}
+pinnacle.window.v1.WindowService.ToggleScratchpad.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.ToggleScratchpad.method = "ToggleScratchpad"
+pinnacle.window.v1.WindowService.ToggleScratchpad.request = ".pinnacle.window.v1.ToggleScratchpadRequest"
+pinnacle.window.v1.WindowService.ToggleScratchpad.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.ToggleScratchpadRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_ToggleScratchpad(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.ToggleScratchpad, data)
+end
+pinnacle.window.v1.WindowService.SetUrgent = {}
+pinnacle.window.v1.WindowService.SetUrgent.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetUrgent.method = "SetUrgent"
+pinnacle.window.v1.WindowService.SetUrgent.request = ".pinnacle.window.v1.SetUrgentRequest"
+pinnacle.window.v1.WindowService.SetUrgent.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetUrgentRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetUrgent(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetUrgent, data)
+end
+pinnacle.window.v1.WindowService.SetAppIdOverride = {}
+pinnacle.window.v1.WindowService.SetAppIdOverride.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetAppIdOverride.method = "SetAppIdOverride"
+pinnacle.window.v1.WindowService.SetAppIdOverride.request = ".pinnacle.window.v1.SetAppIdOverrideRequest"
+pinnacle.window.v1.WindowService.SetAppIdOverride.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetAppIdOverrideRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetAppIdOverride(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetAppIdOverride, data)
+end
+pinnacle.window.v1.WindowService.SetWarpCursorOnFocus = {}
+pinnacle.window.v1.WindowService.SetWarpCursorOnFocus.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetWarpCursorOnFocus.method = "SetWarpCursorOnFocus"
+pinnacle.window.v1.WindowService.SetWarpCursorOnFocus.request = ".pinnacle.window.v1.SetWarpCursorOnFocusRequest"
+pinnacle.window.v1.WindowService.SetWarpCursorOnFocus.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetWarpCursorOnFocusRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetWarpCursorOnFocus(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetWarpCursorOnFocus, data)
+end
+pinnacle.window.v1.WindowService.SetGrabAllInput = {}
+pinnacle.window.v1.WindowService.SetGrabAllInput.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetGrabAllInput.method = "SetGrabAllInput"
+pinnacle.window.v1.WindowService.SetGrabAllInput.request = ".pinnacle.window.v1.SetGrabAllInputRequest"
+pinnacle.window.v1.WindowService.SetGrabAllInput.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetGrabAllInputRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetGrabAllInput(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetGrabAllInput, data)
+end
+pinnacle.window.v1.WindowService.SetInactiveOpacity = {}
+pinnacle.window.v1.WindowService.SetInactiveOpacity.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetInactiveOpacity.method = "SetInactiveOpacity"
+pinnacle.window.v1.WindowService.SetInactiveOpacity.request = ".pinnacle.window.v1.SetInactiveOpacityRequest"
+pinnacle.window.v1.WindowService.SetInactiveOpacity.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetInactiveOpacityRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetInactiveOpacity(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetInactiveOpacity, data)
+end
+pinnacle.window.v1.WindowService.SetDecorationPolicy = {}
+pinnacle.window.v1.WindowService.SetDecorationPolicy.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetDecorationPolicy.method = "SetDecorationPolicy"
+pinnacle.window.v1.WindowService.SetDecorationPolicy.request = ".pinnacle.window.v1.SetDecorationPolicyRequest"
+pinnacle.window.v1.WindowService.SetDecorationPolicy.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetDecorationPolicyRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetDecorationPolicy(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetDecorationPolicy, data)
+end
+pinnacle.window.v1.WindowService.SetOffscreenPolicy = {}
+pinnacle.window.v1.WindowService.SetOffscreenPolicy.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetOffscreenPolicy.method = "SetOffscreenPolicy"
+pinnacle.window.v1.WindowService.SetOffscreenPolicy.request = ".pinnacle.window.v1.SetOffscreenPolicyRequest"
+pinnacle.window.v1.WindowService.SetOffscreenPolicy.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetOffscreenPolicyRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetOffscreenPolicy(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetOffscreenPolicy, data)
+end
+pinnacle.window.v1.WindowService.SetBackgroundFpsCap = {}
+pinnacle.window.v1.WindowService.SetBackgroundFpsCap.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetBackgroundFpsCap.method = "SetBackgroundFpsCap"
+pinnacle.window.v1.WindowService.SetBackgroundFpsCap.request = ".pinnacle.window.v1.SetBackgroundFpsCapRequest"
+pinnacle.window.v1.WindowService.SetBackgroundFpsCap.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetBackgroundFpsCapRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetBackgroundFpsCap(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetBackgroundFpsCap, data)
+end
+pinnacle.window.v1.WindowService.SetFloatingAppIds = {}
+pinnacle.window.v1.WindowService.SetFloatingAppIds.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetFloatingAppIds.method = "SetFloatingAppIds"
+pinnacle.window.v1.WindowService.SetFloatingAppIds.request = ".pinnacle.window.v1.SetFloatingAppIdsRequest"
+pinnacle.window.v1.WindowService.SetFloatingAppIds.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetFloatingAppIdsRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetFloatingAppIds(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetFloatingAppIds, data)
+end
+pinnacle.window.v1.WindowService.SetFloatingTitles = {}
+pinnacle.window.v1.WindowService.SetFloatingTitles.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetFloatingTitles.method = "SetFloatingTitles"
+pinnacle.window.v1.WindowService.SetFloatingTitles.request = ".pinnacle.window.v1.SetFloatingTitlesRequest"
+pinnacle.window.v1.WindowService.SetFloatingTitles.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetFloatingTitlesRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetFloatingTitles(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetFloatingTitles, data)
+end
+pinnacle.window.v1.WindowService.AddGeometryRule = {}
+pinnacle.window.v1.WindowService.AddGeometryRule.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.AddGeometryRule.method = "AddGeometryRule"
+pinnacle.window.v1.WindowService.AddGeometryRule.request = ".pinnacle.window.v1.AddGeometryRuleRequest"
+pinnacle.window.v1.WindowService.AddGeometryRule.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.AddGeometryRuleRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_AddGeometryRule(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.AddGeometryRule, data)
+end
+pinnacle.window.v1.WindowService.SetPreferredScale = {}
+pinnacle.window.v1.WindowService.SetPreferredScale.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetPreferredScale.method = "SetPreferredScale"
+pinnacle.window.v1.WindowService.SetPreferredScale.request = ".pinnacle.window.v1.SetPreferredScaleRequest"
+pinnacle.window.v1.WindowService.SetPreferredScale.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetPreferredScaleRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetPreferredScale(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetPreferredScale, data)
+end
+pinnacle.window.v1.WindowService.SetAspectRatio = {}
+pinnacle.window.v1.WindowService.SetAspectRatio.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetAspectRatio.method = "SetAspectRatio"
+pinnacle.window.v1.WindowService.SetAspectRatio.request = ".pinnacle.window.v1.SetAspectRatioRequest"
+pinnacle.window.v1.WindowService.SetAspectRatio.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetAspectRatioRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetAspectRatio(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetAspectRatio, data)
+end
+pinnacle.window.v1.WindowService.SetCloseBehavior = {}
+pinnacle.window.v1.WindowService.SetCloseBehavior.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.SetCloseBehavior.method = "SetCloseBehavior"
+pinnacle.window.v1.WindowService.SetCloseBehavior.request = ".pinnacle.window.v1.SetCloseBehaviorRequest"
+pinnacle.window.v1.WindowService.SetCloseBehavior.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.SetCloseBehaviorRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_SetCloseBehavior(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.SetCloseBehavior, data)
+end
+pinnacle.window.v1.WindowService.CreateGroup = {}
+pinnacle.window.v1.WindowService.CreateGroup.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.CreateGroup.method = "CreateGroup"
+pinnacle.window.v1.WindowService.CreateGroup.request = ".pinnacle.window.v1.CreateGroupRequest"
+pinnacle.window.v1.WindowService.CreateGroup.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.CreateGroupRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_CreateGroup(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.CreateGroup, data)
+end
+pinnacle.window.v1.WindowService.RemoveGroup = {}
+pinnacle.window.v1.WindowService.RemoveGroup.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.RemoveGroup.method = "RemoveGroup"
+pinnacle.window.v1.WindowService.RemoveGroup.request = ".pinnacle.window.v1.RemoveGroupRequest"
+pinnacle.window.v1.WindowService.RemoveGroup.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.RemoveGroupRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_RemoveGroup(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.RemoveGroup, data)
+end
+pinnacle.window.v1.WindowService.AddToGroup = {}
+pinnacle.window.v1.WindowService.AddToGroup.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.AddToGroup.method = "AddToGroup"
+pinnacle.window.v1.WindowService.AddToGroup.request = ".pinnacle.window.v1.AddToGroupRequest"
+pinnacle.window.v1.WindowService.AddToGroup.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.AddToGroupRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_AddToGroup(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.AddToGroup, data)
+end
+pinnacle.window.v1.WindowService.RemoveFromGroup = {}
+pinnacle.window.v1.WindowService.RemoveFromGroup.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.RemoveFromGroup.method = "RemoveFromGroup"
+pinnacle.window.v1.WindowService.RemoveFromGroup.request = ".pinnacle.window.v1.RemoveFromGroupRequest"
+pinnacle.window.v1.WindowService.RemoveFromGroup.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.RemoveFromGroupRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_RemoveFromGroup(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.RemoveFromGroup, data)
+end
+pinnacle.window.v1.WindowService.GetGroupWindows = {}
+pinnacle.window.v1.WindowService.GetGroupWindows.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.GetGroupWindows.method = "GetGroupWindows"
+pinnacle.window.v1.WindowService.GetGroupWindows.request = ".pinnacle.window.v1.GetGroupWindowsRequest"
+pinnacle.window.v1.WindowService.GetGroupWindows.response = ".pinnacle.window.v1.GetGroupWindowsResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.GetGroupWindowsRequest
+---
+---@return pinnacle.window.v1.GetGroupWindowsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_GetGroupWindows(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.GetGroupWindows, data)
+end
+pinnacle.window.v1.WindowService.FocusInGroup = {}
+pinnacle.window.v1.WindowService.FocusInGroup.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.FocusInGroup.method = "FocusInGroup"
+pinnacle.window.v1.WindowService.FocusInGroup.request = ".pinnacle.window.v1.FocusInGroupRequest"
+pinnacle.window.v1.WindowService.FocusInGroup.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.FocusInGroupRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_FocusInGroup(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.FocusInGroup, data)
+end
+pinnacle.window.v1.WindowService.AddToGroupsOf = {}
+pinnacle.window.v1.WindowService.AddToGroupsOf.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.AddToGroupsOf.method = "AddToGroupsOf"
+pinnacle.window.v1.WindowService.AddToGroupsOf.request = ".pinnacle.window.v1.AddToGroupsOfRequest"
+pinnacle.window.v1.WindowService.AddToGroupsOf.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.window.v1.AddToGroupsOfRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_window_v1_WindowService_AddToGroupsOf(data)
+    return self:unary_request(pinnacle.window.v1.WindowService.AddToGroupsOf, data)
+end
+pinnacle.window.v1.WindowService.WindowRule = {}
+pinnacle.window.v1.WindowService.WindowRule.service = "pinnacle.window.v1.WindowService"
+pinnacle.window.v1.WindowService.WindowRule.method = "WindowRule"
+pinnacle.window.v1.WindowService.WindowRule.request = ".pinnacle.window.v1.WindowRuleRequest"
+pinnacle.window.v1.WindowService.WindowRule.response = ".pinnacle.window.v1.WindowRuleResponse"
+
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
+---
+---@nodiscard
+---
+---@param callback fun(response: pinnacle.window.v1.WindowRuleResponse, stream: grpc_client.h2.Stream)
+---
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_window_v1_WindowService_WindowRule(callback)
+    return self:bidirectional_streaming_request(pinnacle.window.v1.WindowService.WindowRule, callback)
+end
 pinnacle.debug.v1.DebugService = {}
 pinnacle.debug.v1.DebugService.SetDamageVisualization = {}
 pinnacle.debug.v1.DebugService.SetDamageVisualization.service = "pinnacle.debug.v1.DebugService"
@@ -1649,6 +4212,23 @@ pinnacle.debug.v1.DebugService.SetCursorPlaneScanout.response = ".google.protobu
 function Client:pinnacle_debug_v1_DebugService_SetCursorPlaneScanout(data)
     return self:unary_request(pinnacle.debug.v1.DebugService.SetCursorPlaneScanout, data)
 end
+pinnacle.debug.v1.DebugService.SetDirectScanout = {}
+pinnacle.debug.v1.DebugService.SetDirectScanout.service = "pinnacle.debug.v1.DebugService"
+pinnacle.debug.v1.DebugService.SetDirectScanout.method = "SetDirectScanout"
+pinnacle.debug.v1.DebugService.SetDirectScanout.request = ".pinnacle.debug.v1.SetDirectScanoutRequest"
+pinnacle.debug.v1.DebugService.SetDirectScanout.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.debug.v1.SetDirectScanoutRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_debug_v1_DebugService_SetDirectScanout(data)
+    return self:unary_request(pinnacle.debug.v1.DebugService.SetDirectScanout, data)
+end
 pinnacle.debug.v1.DebugService.SetProcessPiping = {}
 pinnacle.debug.v1.DebugService.SetProcessPiping.service = "pinnacle.debug.v1.DebugService"
 pinnacle.debug.v1.DebugService.SetProcessPiping.method = "SetProcessPiping"
@@ -1666,6 +4246,40 @@ pinnacle.debug.v1.DebugService.SetProcessPiping.response = ".google.protobuf.Emp
 function Client:pinnacle_debug_v1_DebugService_SetProcessPiping(data)
     return self:unary_request(pinnacle.debug.v1.DebugService.SetProcessPiping, data)
 end
+pinnacle.debug.v1.DebugService.SetOpaqueRegionCulling = {}
+pinnacle.debug.v1.DebugService.SetOpaqueRegionCulling.service = "pinnacle.debug.v1.DebugService"
+pinnacle.debug.v1.DebugService.SetOpaqueRegionCulling.method = "SetOpaqueRegionCulling"
+pinnacle.debug.v1.DebugService.SetOpaqueRegionCulling.request = ".pinnacle.debug.v1.SetOpaqueRegionCullingRequest"
+pinnacle.debug.v1.DebugService.SetOpaqueRegionCulling.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.debug.v1.SetOpaqueRegionCullingRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_debug_v1_DebugService_SetOpaqueRegionCulling(data)
+    return self:unary_request(pinnacle.debug.v1.DebugService.SetOpaqueRegionCulling, data)
+end
+pinnacle.debug.v1.DebugService.DumpState = {}
+pinnacle.debug.v1.DebugService.DumpState.service = "pinnacle.debug.v1.DebugService"
+pinnacle.debug.v1.DebugService.DumpState.method = "DumpState"
+pinnacle.debug.v1.DebugService.DumpState.request = ".pinnacle.debug.v1.DumpStateRequest"
+pinnacle.debug.v1.DebugService.DumpState.response = ".pinnacle.debug.v1.DumpStateResponse"
+
+---Performs a unary request.
+---
+---@nodiscard
+---
+---@param data pinnacle.debug.v1.DumpStateRequest
+---
+---@return pinnacle.debug.v1.DumpStateResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_debug_v1_DebugService_DumpState(data)
+    return self:unary_request(pinnacle.debug.v1.DebugService.DumpState, data)
+end
 pinnacle.input.v1.InputService = {}
 pinnacle.input.v1.InputService.Bind = {}
 pinnacle.input.v1.InputService.Bind.service = "pinnacle.input.v1.InputService"
@@ -1752,820 +4366,698 @@ pinnacle.input.v1.InputService.EnterBindLayer.response = ".google.protobuf.Empty
 function Client:pinnacle_input_v1_InputService_EnterBindLayer(data)
     return self:unary_request(pinnacle.input.v1.InputService.EnterBindLayer, data)
 end
-pinnacle.input.v1.InputService.KeybindStream = {}
-pinnacle.input.v1.InputService.KeybindStream.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.KeybindStream.method = "KeybindStream"
-pinnacle.input.v1.InputService.KeybindStream.request = ".pinnacle.input.v1.KeybindStreamRequest"
-pinnacle.input.v1.InputService.KeybindStream.response = ".pinnacle.input.v1.KeybindStreamResponse"
-
----Performs a server-streaming request.
----
----`callback` will be called with every streamed response.
----
----@nodiscard
----
----@param data pinnacle.input.v1.KeybindStreamRequest
----@param callback fun(response: pinnacle.input.v1.KeybindStreamResponse)
----
----@return string | nil An error string, if any
-function Client:pinnacle_input_v1_InputService_KeybindStream(data, callback)
-    return self:server_streaming_request(pinnacle.input.v1.InputService.KeybindStream, data, callback)
-end
-pinnacle.input.v1.InputService.MousebindStream = {}
-pinnacle.input.v1.InputService.MousebindStream.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.MousebindStream.method = "MousebindStream"
-pinnacle.input.v1.InputService.MousebindStream.request = ".pinnacle.input.v1.MousebindStreamRequest"
-pinnacle.input.v1.InputService.MousebindStream.response = ".pinnacle.input.v1.MousebindStreamResponse"
-
----Performs a server-streaming request.
----
----`callback` will be called with every streamed response.
----
----@nodiscard
----
----@param data pinnacle.input.v1.MousebindStreamRequest
----@param callback fun(response: pinnacle.input.v1.MousebindStreamResponse)
----
----@return string | nil An error string, if any
-function Client:pinnacle_input_v1_InputService_MousebindStream(data, callback)
-    return self:server_streaming_request(pinnacle.input.v1.InputService.MousebindStream, data, callback)
-end
-pinnacle.input.v1.InputService.KeybindOnPress = {}
-pinnacle.input.v1.InputService.KeybindOnPress.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.KeybindOnPress.method = "KeybindOnPress"
-pinnacle.input.v1.InputService.KeybindOnPress.request = ".pinnacle.input.v1.KeybindOnPressRequest"
-pinnacle.input.v1.InputService.KeybindOnPress.response = ".google.protobuf.Empty"
-
----Performs a unary request.
----
----@nodiscard
----
----@param data pinnacle.input.v1.KeybindOnPressRequest
----
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_KeybindOnPress(data)
-    return self:unary_request(pinnacle.input.v1.InputService.KeybindOnPress, data)
-end
-pinnacle.input.v1.InputService.MousebindOnPress = {}
-pinnacle.input.v1.InputService.MousebindOnPress.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.MousebindOnPress.method = "MousebindOnPress"
-pinnacle.input.v1.InputService.MousebindOnPress.request = ".pinnacle.input.v1.MousebindOnPressRequest"
-pinnacle.input.v1.InputService.MousebindOnPress.response = ".google.protobuf.Empty"
-
----Performs a unary request.
----
----@nodiscard
----
----@param data pinnacle.input.v1.MousebindOnPressRequest
----
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_MousebindOnPress(data)
-    return self:unary_request(pinnacle.input.v1.InputService.MousebindOnPress, data)
-end
-pinnacle.input.v1.InputService.SetXkbConfig = {}
-pinnacle.input.v1.InputService.SetXkbConfig.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetXkbConfig.method = "SetXkbConfig"
-pinnacle.input.v1.InputService.SetXkbConfig.request = ".pinnacle.input.v1.SetXkbConfigRequest"
-pinnacle.input.v1.InputService.SetXkbConfig.response = ".google.protobuf.Empty"
-
----Performs a unary request.
----
----@nodiscard
----
----@param data pinnacle.input.v1.SetXkbConfigRequest
----
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetXkbConfig(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetXkbConfig, data)
-end
-pinnacle.input.v1.InputService.SetRepeatRate = {}
-pinnacle.input.v1.InputService.SetRepeatRate.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetRepeatRate.method = "SetRepeatRate"
-pinnacle.input.v1.InputService.SetRepeatRate.request = ".pinnacle.input.v1.SetRepeatRateRequest"
-pinnacle.input.v1.InputService.SetRepeatRate.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.ExitBindLayer = {}
+pinnacle.input.v1.InputService.ExitBindLayer.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.ExitBindLayer.method = "ExitBindLayer"
+pinnacle.input.v1.InputService.ExitBindLayer.request = ".pinnacle.input.v1.ExitBindLayerRequest"
+pinnacle.input.v1.InputService.ExitBindLayer.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SetRepeatRateRequest
+---@param data pinnacle.input.v1.ExitBindLayerRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetRepeatRate(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetRepeatRate, data)
+function Client:pinnacle_input_v1_InputService_ExitBindLayer(data)
+    return self:unary_request(pinnacle.input.v1.InputService.ExitBindLayer, data)
 end
-pinnacle.input.v1.InputService.SetXkbKeymap = {}
-pinnacle.input.v1.InputService.SetXkbKeymap.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetXkbKeymap.method = "SetXkbKeymap"
-pinnacle.input.v1.InputService.SetXkbKeymap.request = ".pinnacle.input.v1.SetXkbKeymapRequest"
-pinnacle.input.v1.InputService.SetXkbKeymap.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.SetBindLayerPassthrough = {}
+pinnacle.input.v1.InputService.SetBindLayerPassthrough.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetBindLayerPassthrough.method = "SetBindLayerPassthrough"
+pinnacle.input.v1.InputService.SetBindLayerPassthrough.request = ".pinnacle.input.v1.SetBindLayerPassthroughRequest"
+pinnacle.input.v1.InputService.SetBindLayerPassthrough.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SetXkbKeymapRequest
+---@param data pinnacle.input.v1.SetBindLayerPassthroughRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetXkbKeymap(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetXkbKeymap, data)
+function Client:pinnacle_input_v1_InputService_SetBindLayerPassthrough(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetBindLayerPassthrough, data)
 end
-pinnacle.input.v1.InputService.SwitchXkbLayout = {}
-pinnacle.input.v1.InputService.SwitchXkbLayout.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SwitchXkbLayout.method = "SwitchXkbLayout"
-pinnacle.input.v1.InputService.SwitchXkbLayout.request = ".pinnacle.input.v1.SwitchXkbLayoutRequest"
-pinnacle.input.v1.InputService.SwitchXkbLayout.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.KeybindStream = {}
+pinnacle.input.v1.InputService.KeybindStream.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.KeybindStream.method = "KeybindStream"
+pinnacle.input.v1.InputService.KeybindStream.request = ".pinnacle.input.v1.KeybindStreamRequest"
+pinnacle.input.v1.InputService.KeybindStream.response = ".pinnacle.input.v1.KeybindStreamResponse"
 
----Performs a unary request.
----
----@nodiscard
----
----@param data pinnacle.input.v1.SwitchXkbLayoutRequest
+---Performs a server-streaming request.
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SwitchXkbLayout(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SwitchXkbLayout, data)
-end
-pinnacle.input.v1.InputService.SetXcursor = {}
-pinnacle.input.v1.InputService.SetXcursor.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetXcursor.method = "SetXcursor"
-pinnacle.input.v1.InputService.SetXcursor.request = ".pinnacle.input.v1.SetXcursorRequest"
-pinnacle.input.v1.InputService.SetXcursor.response = ".google.protobuf.Empty"
-
----Performs a unary request.
+---`callback` will be called with every streamed response.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SetXcursorRequest
+---@param data pinnacle.input.v1.KeybindStreamRequest
+---@param callback fun(response: pinnacle.input.v1.KeybindStreamResponse)
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetXcursor(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetXcursor, data)
+---@return string | nil An error string, if any
+function Client:pinnacle_input_v1_InputService_KeybindStream(data, callback)
+    return self:server_streaming_request(pinnacle.input.v1.InputService.KeybindStream, data, callback)
 end
-pinnacle.input.v1.InputService.GetDevices = {}
-pinnacle.input.v1.InputService.GetDevices.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.GetDevices.method = "GetDevices"
-pinnacle.input.v1.InputService.GetDevices.request = ".pinnacle.input.v1.GetDevicesRequest"
-pinnacle.input.v1.InputService.GetDevices.response = ".pinnacle.input.v1.GetDevicesResponse"
+pinnacle.input.v1.InputService.MousebindStream = {}
+pinnacle.input.v1.InputService.MousebindStream.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.MousebindStream.method = "MousebindStream"
+pinnacle.input.v1.InputService.MousebindStream.request = ".pinnacle.input.v1.MousebindStreamRequest"
+pinnacle.input.v1.InputService.MousebindStream.response = ".pinnacle.input.v1.MousebindStreamResponse"
 
----Performs a unary request.
----
----@nodiscard
----
----@param data pinnacle.input.v1.GetDevicesRequest
+---Performs a server-streaming request.
 ---
----@return pinnacle.input.v1.GetDevicesResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_GetDevices(data)
-    return self:unary_request(pinnacle.input.v1.InputService.GetDevices, data)
-end
-pinnacle.input.v1.InputService.GetDeviceCapabilities = {}
-pinnacle.input.v1.InputService.GetDeviceCapabilities.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.GetDeviceCapabilities.method = "GetDeviceCapabilities"
-pinnacle.input.v1.InputService.GetDeviceCapabilities.request = ".pinnacle.input.v1.GetDeviceCapabilitiesRequest"
-pinnacle.input.v1.InputService.GetDeviceCapabilities.response = ".pinnacle.input.v1.GetDeviceCapabilitiesResponse"
-
----Performs a unary request.
+---`callback` will be called with every streamed response.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.GetDeviceCapabilitiesRequest
+---@param data pinnacle.input.v1.MousebindStreamRequest
+---@param callback fun(response: pinnacle.input.v1.MousebindStreamResponse)
 ---
----@return pinnacle.input.v1.GetDeviceCapabilitiesResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_GetDeviceCapabilities(data)
-    return self:unary_request(pinnacle.input.v1.InputService.GetDeviceCapabilities, data)
+---@return string | nil An error string, if any
+function Client:pinnacle_input_v1_InputService_MousebindStream(data, callback)
+    return self:server_streaming_request(pinnacle.input.v1.InputService.MousebindStream, data, callback)
 end
-pinnacle.input.v1.InputService.GetDeviceInfo = {}
-pinnacle.input.v1.InputService.GetDeviceInfo.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.GetDeviceInfo.method = "GetDeviceInfo"
-pinnacle.input.v1.InputService.GetDeviceInfo.request = ".pinnacle.input.v1.GetDeviceInfoRequest"
-pinnacle.input.v1.InputService.GetDeviceInfo.response = ".pinnacle.input.v1.GetDeviceInfoResponse"
+pinnacle.input.v1.InputService.KeybindOnPress = {}
+pinnacle.input.v1.InputService.KeybindOnPress.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.KeybindOnPress.method = "KeybindOnPress"
+pinnacle.input.v1.InputService.KeybindOnPress.request = ".pinnacle.input.v1.KeybindOnPressRequest"
+pinnacle.input.v1.InputService.KeybindOnPress.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.GetDeviceInfoRequest
+---@param data pinnacle.input.v1.KeybindOnPressRequest
 ---
----@return pinnacle.input.v1.GetDeviceInfoResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_GetDeviceInfo(data)
-    return self:unary_request(pinnacle.input.v1.InputService.GetDeviceInfo, data)
+function Client:pinnacle_input_v1_InputService_KeybindOnPress(data)
+    return self:unary_request(pinnacle.input.v1.InputService.KeybindOnPress, data)
 end
-pinnacle.input.v1.InputService.GetDeviceType = {}
-pinnacle.input.v1.InputService.GetDeviceType.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.GetDeviceType.method = "GetDeviceType"
-pinnacle.input.v1.InputService.GetDeviceType.request = ".pinnacle.input.v1.GetDeviceTypeRequest"
-pinnacle.input.v1.InputService.GetDeviceType.response = ".pinnacle.input.v1.GetDeviceTypeResponse"
+pinnacle.input.v1.InputService.MousebindOnPress = {}
+pinnacle.input.v1.InputService.MousebindOnPress.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.MousebindOnPress.method = "MousebindOnPress"
+pinnacle.input.v1.InputService.MousebindOnPress.request = ".pinnacle.input.v1.MousebindOnPressRequest"
+pinnacle.input.v1.InputService.MousebindOnPress.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.GetDeviceTypeRequest
+---@param data pinnacle.input.v1.MousebindOnPressRequest
 ---
----@return pinnacle.input.v1.GetDeviceTypeResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_GetDeviceType(data)
-    return self:unary_request(pinnacle.input.v1.InputService.GetDeviceType, data)
+function Client:pinnacle_input_v1_InputService_MousebindOnPress(data)
+    return self:unary_request(pinnacle.input.v1.InputService.MousebindOnPress, data)
 end
-pinnacle.input.v1.InputService.SetDeviceLibinputSetting = {}
-pinnacle.input.v1.InputService.SetDeviceLibinputSetting.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetDeviceLibinputSetting.method = "SetDeviceLibinputSetting"
-pinnacle.input.v1.InputService.SetDeviceLibinputSetting.request = ".pinnacle.input.v1.SetDeviceLibinputSettingRequest"
-pinnacle.input.v1.InputService.SetDeviceLibinputSetting.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.GestureBindStream = {}
+pinnacle.input.v1.InputService.GestureBindStream.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GestureBindStream.method = "GestureBindStream"
+pinnacle.input.v1.InputService.GestureBindStream.request = ".pinnacle.input.v1.GestureBindStreamRequest"
+pinnacle.input.v1.InputService.GestureBindStream.response = ".pinnacle.input.v1.GestureBindStreamResponse"
 
----Performs a unary request.
+---Performs a server-streaming request.
+---
+---`callback` will be called with every streamed response.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SetDeviceLibinputSettingRequest
+---@param data pinnacle.input.v1.GestureBindStreamRequest
+---@param callback fun(response: pinnacle.input.v1.GestureBindStreamResponse)
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetDeviceLibinputSetting(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetDeviceLibinputSetting, data)
+---@return string | nil An error string, if any
+function Client:pinnacle_input_v1_InputService_GestureBindStream(data, callback)
+    return self:server_streaming_request(pinnacle.input.v1.InputService.GestureBindStream, data, callback)
 end
-pinnacle.input.v1.InputService.SetDeviceMapTarget = {}
-pinnacle.input.v1.InputService.SetDeviceMapTarget.service = "pinnacle.input.v1.InputService"
-pinnacle.input.v1.InputService.SetDeviceMapTarget.method = "SetDeviceMapTarget"
-pinnacle.input.v1.InputService.SetDeviceMapTarget.request = ".pinnacle.input.v1.SetDeviceMapTargetRequest"
-pinnacle.input.v1.InputService.SetDeviceMapTarget.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.SetMouseButtonMap = {}
+pinnacle.input.v1.InputService.SetMouseButtonMap.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetMouseButtonMap.method = "SetMouseButtonMap"
+pinnacle.input.v1.InputService.SetMouseButtonMap.request = ".pinnacle.input.v1.SetMouseButtonMapRequest"
+pinnacle.input.v1.InputService.SetMouseButtonMap.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.input.v1.SetDeviceMapTargetRequest
+---@param data pinnacle.input.v1.SetMouseButtonMapRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_input_v1_InputService_SetDeviceMapTarget(data)
-    return self:unary_request(pinnacle.input.v1.InputService.SetDeviceMapTarget, data)
+function Client:pinnacle_input_v1_InputService_SetMouseButtonMap(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetMouseButtonMap, data)
 end
-pinnacle.layout.v1.LayoutService = {}
-pinnacle.layout.v1.LayoutService.Layout = {}
-pinnacle.layout.v1.LayoutService.Layout.service = "pinnacle.layout.v1.LayoutService"
-pinnacle.layout.v1.LayoutService.Layout.method = "Layout"
-pinnacle.layout.v1.LayoutService.Layout.request = ".pinnacle.layout.v1.LayoutRequest"
-pinnacle.layout.v1.LayoutService.Layout.response = ".pinnacle.layout.v1.LayoutResponse"
+pinnacle.input.v1.InputService.SetMiddleClickPaste = {}
+pinnacle.input.v1.InputService.SetMiddleClickPaste.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetMiddleClickPaste.method = "SetMiddleClickPaste"
+pinnacle.input.v1.InputService.SetMiddleClickPaste.request = ".pinnacle.input.v1.SetMiddleClickPasteRequest"
+pinnacle.input.v1.InputService.SetMiddleClickPaste.response = ".google.protobuf.Empty"
 
----Performs a bidirectional-streaming request.
----
----`callback` will be called with every streamed response.
----
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.layout.v1.LayoutResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.input.v1.SetMiddleClickPasteRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_layout_v1_LayoutService_Layout(callback)
-    return self:bidirectional_streaming_request(pinnacle.layout.v1.LayoutService.Layout, callback)
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetMiddleClickPaste(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetMiddleClickPaste, data)
 end
-pinnacle.output.v1.OutputService = {}
-pinnacle.output.v1.OutputService.Get = {}
-pinnacle.output.v1.OutputService.Get.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.Get.method = "Get"
-pinnacle.output.v1.OutputService.Get.request = ".pinnacle.output.v1.GetRequest"
-pinnacle.output.v1.OutputService.Get.response = ".pinnacle.output.v1.GetResponse"
+pinnacle.input.v1.InputService.SetGrabAllInputEscape = {}
+pinnacle.input.v1.InputService.SetGrabAllInputEscape.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetGrabAllInputEscape.method = "SetGrabAllInputEscape"
+pinnacle.input.v1.InputService.SetGrabAllInputEscape.request = ".pinnacle.input.v1.SetGrabAllInputEscapeRequest"
+pinnacle.input.v1.InputService.SetGrabAllInputEscape.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetRequest
+---@param data pinnacle.input.v1.SetGrabAllInputEscapeRequest
 ---
----@return pinnacle.output.v1.GetResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_Get(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.Get, data)
+function Client:pinnacle_input_v1_InputService_SetGrabAllInputEscape(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetGrabAllInputEscape, data)
 end
-pinnacle.output.v1.OutputService.SetLoc = {}
-pinnacle.output.v1.OutputService.SetLoc.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetLoc.method = "SetLoc"
-pinnacle.output.v1.OutputService.SetLoc.request = ".pinnacle.output.v1.SetLocRequest"
-pinnacle.output.v1.OutputService.SetLoc.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.SetRelativeMotionScale = {}
+pinnacle.input.v1.InputService.SetRelativeMotionScale.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetRelativeMotionScale.method = "SetRelativeMotionScale"
+pinnacle.input.v1.InputService.SetRelativeMotionScale.request = ".pinnacle.input.v1.SetRelativeMotionScaleRequest"
+pinnacle.input.v1.InputService.SetRelativeMotionScale.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetLocRequest
+---@param data pinnacle.input.v1.SetRelativeMotionScaleRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetLoc(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetLoc, data)
+function Client:pinnacle_input_v1_InputService_SetRelativeMotionScale(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetRelativeMotionScale, data)
 end
-pinnacle.output.v1.OutputService.SetMode = {}
-pinnacle.output.v1.OutputService.SetMode.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetMode.method = "SetMode"
-pinnacle.output.v1.OutputService.SetMode.request = ".pinnacle.output.v1.SetModeRequest"
-pinnacle.output.v1.OutputService.SetMode.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.SetRelativeMotionPolicy = {}
+pinnacle.input.v1.InputService.SetRelativeMotionPolicy.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetRelativeMotionPolicy.method = "SetRelativeMotionPolicy"
+pinnacle.input.v1.InputService.SetRelativeMotionPolicy.request = ".pinnacle.input.v1.SetRelativeMotionPolicyRequest"
+pinnacle.input.v1.InputService.SetRelativeMotionPolicy.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetModeRequest
+---@param data pinnacle.input.v1.SetRelativeMotionPolicyRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetMode(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetMode, data)
+function Client:pinnacle_input_v1_InputService_SetRelativeMotionPolicy(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetRelativeMotionPolicy, data)
 end
-pinnacle.output.v1.OutputService.SetModeline = {}
-pinnacle.output.v1.OutputService.SetModeline.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetModeline.method = "SetModeline"
-pinnacle.output.v1.OutputService.SetModeline.request = ".pinnacle.output.v1.SetModelineRequest"
-pinnacle.output.v1.OutputService.SetModeline.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.SetResizeEdgeThreshold = {}
+pinnacle.input.v1.InputService.SetResizeEdgeThreshold.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetResizeEdgeThreshold.method = "SetResizeEdgeThreshold"
+pinnacle.input.v1.InputService.SetResizeEdgeThreshold.request = ".pinnacle.input.v1.SetResizeEdgeThresholdRequest"
+pinnacle.input.v1.InputService.SetResizeEdgeThreshold.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetModelineRequest
+---@param data pinnacle.input.v1.SetResizeEdgeThresholdRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetModeline(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetModeline, data)
+function Client:pinnacle_input_v1_InputService_SetResizeEdgeThreshold(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetResizeEdgeThreshold, data)
 end
-pinnacle.output.v1.OutputService.SetScale = {}
-pinnacle.output.v1.OutputService.SetScale.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetScale.method = "SetScale"
-pinnacle.output.v1.OutputService.SetScale.request = ".pinnacle.output.v1.SetScaleRequest"
-pinnacle.output.v1.OutputService.SetScale.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.SetPointerDefaultPosition = {}
+pinnacle.input.v1.InputService.SetPointerDefaultPosition.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetPointerDefaultPosition.method = "SetPointerDefaultPosition"
+pinnacle.input.v1.InputService.SetPointerDefaultPosition.request = ".pinnacle.input.v1.SetPointerDefaultPositionRequest"
+pinnacle.input.v1.InputService.SetPointerDefaultPosition.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetScaleRequest
+---@param data pinnacle.input.v1.SetPointerDefaultPositionRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetScale(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetScale, data)
+function Client:pinnacle_input_v1_InputService_SetPointerDefaultPosition(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetPointerDefaultPosition, data)
 end
-pinnacle.output.v1.OutputService.SetTransform = {}
-pinnacle.output.v1.OutputService.SetTransform.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetTransform.method = "SetTransform"
-pinnacle.output.v1.OutputService.SetTransform.request = ".pinnacle.output.v1.SetTransformRequest"
-pinnacle.output.v1.OutputService.SetTransform.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.SetWarpOnOutputFocus = {}
+pinnacle.input.v1.InputService.SetWarpOnOutputFocus.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetWarpOnOutputFocus.method = "SetWarpOnOutputFocus"
+pinnacle.input.v1.InputService.SetWarpOnOutputFocus.request = ".pinnacle.input.v1.SetWarpOnOutputFocusRequest"
+pinnacle.input.v1.InputService.SetWarpOnOutputFocus.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetTransformRequest
+---@param data pinnacle.input.v1.SetWarpOnOutputFocusRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetTransform(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetTransform, data)
+function Client:pinnacle_input_v1_InputService_SetWarpOnOutputFocus(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetWarpOnOutputFocus, data)
 end
-pinnacle.output.v1.OutputService.SetPowered = {}
-pinnacle.output.v1.OutputService.SetPowered.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetPowered.method = "SetPowered"
-pinnacle.output.v1.OutputService.SetPowered.request = ".pinnacle.output.v1.SetPoweredRequest"
-pinnacle.output.v1.OutputService.SetPowered.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.SetDoubleClickTime = {}
+pinnacle.input.v1.InputService.SetDoubleClickTime.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetDoubleClickTime.method = "SetDoubleClickTime"
+pinnacle.input.v1.InputService.SetDoubleClickTime.request = ".pinnacle.input.v1.SetDoubleClickTimeRequest"
+pinnacle.input.v1.InputService.SetDoubleClickTime.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetPoweredRequest
+---@param data pinnacle.input.v1.SetDoubleClickTimeRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetPowered(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetPowered, data)
+function Client:pinnacle_input_v1_InputService_SetDoubleClickTime(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetDoubleClickTime, data)
 end
-pinnacle.output.v1.OutputService.SetVrr = {}
-pinnacle.output.v1.OutputService.SetVrr.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.SetVrr.method = "SetVrr"
-pinnacle.output.v1.OutputService.SetVrr.request = ".pinnacle.output.v1.SetVrrRequest"
-pinnacle.output.v1.OutputService.SetVrr.response = ".pinnacle.output.v1.SetVrrResponse"
+pinnacle.input.v1.InputService.ResetPointerPosition = {}
+pinnacle.input.v1.InputService.ResetPointerPosition.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.ResetPointerPosition.method = "ResetPointerPosition"
+pinnacle.input.v1.InputService.ResetPointerPosition.request = ".pinnacle.input.v1.ResetPointerPositionRequest"
+pinnacle.input.v1.InputService.ResetPointerPosition.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.SetVrrRequest
+---@param data pinnacle.input.v1.ResetPointerPositionRequest
 ---
----@return pinnacle.output.v1.SetVrrResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_SetVrr(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.SetVrr, data)
+function Client:pinnacle_input_v1_InputService_ResetPointerPosition(data)
+    return self:unary_request(pinnacle.input.v1.InputService.ResetPointerPosition, data)
 end
-pinnacle.output.v1.OutputService.Focus = {}
-pinnacle.output.v1.OutputService.Focus.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.Focus.method = "Focus"
-pinnacle.output.v1.OutputService.Focus.request = ".pinnacle.output.v1.FocusRequest"
-pinnacle.output.v1.OutputService.Focus.response = ".pinnacle.output.v1.FocusResponse"
+pinnacle.input.v1.InputService.ConfinePointerToOutput = {}
+pinnacle.input.v1.InputService.ConfinePointerToOutput.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.ConfinePointerToOutput.method = "ConfinePointerToOutput"
+pinnacle.input.v1.InputService.ConfinePointerToOutput.request = ".pinnacle.input.v1.ConfinePointerToOutputRequest"
+pinnacle.input.v1.InputService.ConfinePointerToOutput.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.FocusRequest
+---@param data pinnacle.input.v1.ConfinePointerToOutputRequest
 ---
----@return pinnacle.output.v1.FocusResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_Focus(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.Focus, data)
+function Client:pinnacle_input_v1_InputService_ConfinePointerToOutput(data)
+    return self:unary_request(pinnacle.input.v1.InputService.ConfinePointerToOutput, data)
 end
-pinnacle.output.v1.OutputService.GetInfo = {}
-pinnacle.output.v1.OutputService.GetInfo.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetInfo.method = "GetInfo"
-pinnacle.output.v1.OutputService.GetInfo.request = ".pinnacle.output.v1.GetInfoRequest"
-pinnacle.output.v1.OutputService.GetInfo.response = ".pinnacle.output.v1.GetInfoResponse"
+pinnacle.input.v1.InputService.SetXkbConfig = {}
+pinnacle.input.v1.InputService.SetXkbConfig.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetXkbConfig.method = "SetXkbConfig"
+pinnacle.input.v1.InputService.SetXkbConfig.request = ".pinnacle.input.v1.SetXkbConfigRequest"
+pinnacle.input.v1.InputService.SetXkbConfig.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetInfoRequest
+---@param data pinnacle.input.v1.SetXkbConfigRequest
 ---
----@return pinnacle.output.v1.GetInfoResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetInfo(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetInfo, data)
+function Client:pinnacle_input_v1_InputService_SetXkbConfig(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetXkbConfig, data)
 end
-pinnacle.output.v1.OutputService.GetLoc = {}
-pinnacle.output.v1.OutputService.GetLoc.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetLoc.method = "GetLoc"
-pinnacle.output.v1.OutputService.GetLoc.request = ".pinnacle.output.v1.GetLocRequest"
-pinnacle.output.v1.OutputService.GetLoc.response = ".pinnacle.output.v1.GetLocResponse"
+pinnacle.input.v1.InputService.SetRepeatRate = {}
+pinnacle.input.v1.InputService.SetRepeatRate.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetRepeatRate.method = "SetRepeatRate"
+pinnacle.input.v1.InputService.SetRepeatRate.request = ".pinnacle.input.v1.SetRepeatRateRequest"
+pinnacle.input.v1.InputService.SetRepeatRate.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetLocRequest
+---@param data pinnacle.input.v1.SetRepeatRateRequest
 ---
----@return pinnacle.output.v1.GetLocResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetLoc(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetLoc, data)
+function Client:pinnacle_input_v1_InputService_SetRepeatRate(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetRepeatRate, data)
 end
-pinnacle.output.v1.OutputService.GetLogicalSize = {}
-pinnacle.output.v1.OutputService.GetLogicalSize.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetLogicalSize.method = "GetLogicalSize"
-pinnacle.output.v1.OutputService.GetLogicalSize.request = ".pinnacle.output.v1.GetLogicalSizeRequest"
-pinnacle.output.v1.OutputService.GetLogicalSize.response = ".pinnacle.output.v1.GetLogicalSizeResponse"
+pinnacle.input.v1.InputService.SetXkbKeymap = {}
+pinnacle.input.v1.InputService.SetXkbKeymap.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetXkbKeymap.method = "SetXkbKeymap"
+pinnacle.input.v1.InputService.SetXkbKeymap.request = ".pinnacle.input.v1.SetXkbKeymapRequest"
+pinnacle.input.v1.InputService.SetXkbKeymap.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetLogicalSizeRequest
+---@param data pinnacle.input.v1.SetXkbKeymapRequest
 ---
----@return pinnacle.output.v1.GetLogicalSizeResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetLogicalSize(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetLogicalSize, data)
+function Client:pinnacle_input_v1_InputService_SetXkbKeymap(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetXkbKeymap, data)
 end
-pinnacle.output.v1.OutputService.GetPhysicalSize = {}
-pinnacle.output.v1.OutputService.GetPhysicalSize.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetPhysicalSize.method = "GetPhysicalSize"
-pinnacle.output.v1.OutputService.GetPhysicalSize.request = ".pinnacle.output.v1.GetPhysicalSizeRequest"
-pinnacle.output.v1.OutputService.GetPhysicalSize.response = ".pinnacle.output.v1.GetPhysicalSizeResponse"
+pinnacle.input.v1.InputService.SwitchXkbLayout = {}
+pinnacle.input.v1.InputService.SwitchXkbLayout.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SwitchXkbLayout.method = "SwitchXkbLayout"
+pinnacle.input.v1.InputService.SwitchXkbLayout.request = ".pinnacle.input.v1.SwitchXkbLayoutRequest"
+pinnacle.input.v1.InputService.SwitchXkbLayout.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetPhysicalSizeRequest
+---@param data pinnacle.input.v1.SwitchXkbLayoutRequest
 ---
----@return pinnacle.output.v1.GetPhysicalSizeResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetPhysicalSize(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetPhysicalSize, data)
+function Client:pinnacle_input_v1_InputService_SwitchXkbLayout(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SwitchXkbLayout, data)
 end
-pinnacle.output.v1.OutputService.GetModes = {}
-pinnacle.output.v1.OutputService.GetModes.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetModes.method = "GetModes"
-pinnacle.output.v1.OutputService.GetModes.request = ".pinnacle.output.v1.GetModesRequest"
-pinnacle.output.v1.OutputService.GetModes.response = ".pinnacle.output.v1.GetModesResponse"
+pinnacle.input.v1.InputService.SetStickyKeys = {}
+pinnacle.input.v1.InputService.SetStickyKeys.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetStickyKeys.method = "SetStickyKeys"
+pinnacle.input.v1.InputService.SetStickyKeys.request = ".pinnacle.input.v1.SetStickyKeysRequest"
+pinnacle.input.v1.InputService.SetStickyKeys.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetModesRequest
+---@param data pinnacle.input.v1.SetStickyKeysRequest
 ---
----@return pinnacle.output.v1.GetModesResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetModes(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetModes, data)
-end
-pinnacle.output.v1.OutputService.GetFocused = {}
-pinnacle.output.v1.OutputService.GetFocused.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetFocused.method = "GetFocused"
-pinnacle.output.v1.OutputService.GetFocused.request = ".pinnacle.output.v1.GetFocusedRequest"
-pinnacle.output.v1.OutputService.GetFocused.response = ".pinnacle.output.v1.GetFocusedResponse"
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetStickyKeys(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetStickyKeys, data)
+end
+pinnacle.input.v1.InputService.SetNumlock = {}
+pinnacle.input.v1.InputService.SetNumlock.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetNumlock.method = "SetNumlock"
+pinnacle.input.v1.InputService.SetNumlock.request = ".pinnacle.input.v1.SetNumlockRequest"
+pinnacle.input.v1.InputService.SetNumlock.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetFocusedRequest
+---@param data pinnacle.input.v1.SetNumlockRequest
 ---
----@return pinnacle.output.v1.GetFocusedResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetFocused(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetFocused, data)
+function Client:pinnacle_input_v1_InputService_SetNumlock(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetNumlock, data)
 end
-pinnacle.output.v1.OutputService.GetTagIds = {}
-pinnacle.output.v1.OutputService.GetTagIds.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetTagIds.method = "GetTagIds"
-pinnacle.output.v1.OutputService.GetTagIds.request = ".pinnacle.output.v1.GetTagIdsRequest"
-pinnacle.output.v1.OutputService.GetTagIds.response = ".pinnacle.output.v1.GetTagIdsResponse"
+pinnacle.input.v1.InputService.SetComposeEnabled = {}
+pinnacle.input.v1.InputService.SetComposeEnabled.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetComposeEnabled.method = "SetComposeEnabled"
+pinnacle.input.v1.InputService.SetComposeEnabled.request = ".pinnacle.input.v1.SetComposeEnabledRequest"
+pinnacle.input.v1.InputService.SetComposeEnabled.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetTagIdsRequest
+---@param data pinnacle.input.v1.SetComposeEnabledRequest
 ---
----@return pinnacle.output.v1.GetTagIdsResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetTagIds(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetTagIds, data)
+function Client:pinnacle_input_v1_InputService_SetComposeEnabled(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetComposeEnabled, data)
 end
-pinnacle.output.v1.OutputService.GetScale = {}
-pinnacle.output.v1.OutputService.GetScale.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetScale.method = "GetScale"
-pinnacle.output.v1.OutputService.GetScale.request = ".pinnacle.output.v1.GetScaleRequest"
-pinnacle.output.v1.OutputService.GetScale.response = ".pinnacle.output.v1.GetScaleResponse"
+pinnacle.input.v1.InputService.SetXcursor = {}
+pinnacle.input.v1.InputService.SetXcursor.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetXcursor.method = "SetXcursor"
+pinnacle.input.v1.InputService.SetXcursor.request = ".pinnacle.input.v1.SetXcursorRequest"
+pinnacle.input.v1.InputService.SetXcursor.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetScaleRequest
+---@param data pinnacle.input.v1.SetXcursorRequest
 ---
----@return pinnacle.output.v1.GetScaleResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetScale(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetScale, data)
+function Client:pinnacle_input_v1_InputService_SetXcursor(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetXcursor, data)
 end
-pinnacle.output.v1.OutputService.GetTransform = {}
-pinnacle.output.v1.OutputService.GetTransform.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetTransform.method = "GetTransform"
-pinnacle.output.v1.OutputService.GetTransform.request = ".pinnacle.output.v1.GetTransformRequest"
-pinnacle.output.v1.OutputService.GetTransform.response = ".pinnacle.output.v1.GetTransformResponse"
+pinnacle.input.v1.InputService.SetCursorVisible = {}
+pinnacle.input.v1.InputService.SetCursorVisible.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetCursorVisible.method = "SetCursorVisible"
+pinnacle.input.v1.InputService.SetCursorVisible.request = ".pinnacle.input.v1.SetCursorVisibleRequest"
+pinnacle.input.v1.InputService.SetCursorVisible.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetTransformRequest
+---@param data pinnacle.input.v1.SetCursorVisibleRequest
 ---
----@return pinnacle.output.v1.GetTransformResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetTransform(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetTransform, data)
+function Client:pinnacle_input_v1_InputService_SetCursorVisible(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetCursorVisible, data)
 end
-pinnacle.output.v1.OutputService.GetEnabled = {}
-pinnacle.output.v1.OutputService.GetEnabled.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetEnabled.method = "GetEnabled"
-pinnacle.output.v1.OutputService.GetEnabled.request = ".pinnacle.output.v1.GetEnabledRequest"
-pinnacle.output.v1.OutputService.GetEnabled.response = ".pinnacle.output.v1.GetEnabledResponse"
+pinnacle.input.v1.InputService.SetCursorAutohide = {}
+pinnacle.input.v1.InputService.SetCursorAutohide.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetCursorAutohide.method = "SetCursorAutohide"
+pinnacle.input.v1.InputService.SetCursorAutohide.request = ".pinnacle.input.v1.SetCursorAutohideRequest"
+pinnacle.input.v1.InputService.SetCursorAutohide.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetEnabledRequest
+---@param data pinnacle.input.v1.SetCursorAutohideRequest
 ---
----@return pinnacle.output.v1.GetEnabledResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetEnabled(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetEnabled, data)
+function Client:pinnacle_input_v1_InputService_SetCursorAutohide(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetCursorAutohide, data)
 end
-pinnacle.output.v1.OutputService.GetPowered = {}
-pinnacle.output.v1.OutputService.GetPowered.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetPowered.method = "GetPowered"
-pinnacle.output.v1.OutputService.GetPowered.request = ".pinnacle.output.v1.GetPoweredRequest"
-pinnacle.output.v1.OutputService.GetPowered.response = ".pinnacle.output.v1.GetPoweredResponse"
+pinnacle.input.v1.InputService.TypeString = {}
+pinnacle.input.v1.InputService.TypeString.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.TypeString.method = "TypeString"
+pinnacle.input.v1.InputService.TypeString.request = ".pinnacle.input.v1.TypeStringRequest"
+pinnacle.input.v1.InputService.TypeString.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetPoweredRequest
+---@param data pinnacle.input.v1.TypeStringRequest
 ---
----@return pinnacle.output.v1.GetPoweredResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetPowered(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetPowered, data)
+function Client:pinnacle_input_v1_InputService_TypeString(data)
+    return self:unary_request(pinnacle.input.v1.InputService.TypeString, data)
 end
-pinnacle.output.v1.OutputService.GetFocusStackWindowIds = {}
-pinnacle.output.v1.OutputService.GetFocusStackWindowIds.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetFocusStackWindowIds.method = "GetFocusStackWindowIds"
-pinnacle.output.v1.OutputService.GetFocusStackWindowIds.request = ".pinnacle.output.v1.GetFocusStackWindowIdsRequest"
-pinnacle.output.v1.OutputService.GetFocusStackWindowIds.response = ".pinnacle.output.v1.GetFocusStackWindowIdsResponse"
+pinnacle.input.v1.InputService.SendCombo = {}
+pinnacle.input.v1.InputService.SendCombo.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SendCombo.method = "SendCombo"
+pinnacle.input.v1.InputService.SendCombo.request = ".pinnacle.input.v1.SendComboRequest"
+pinnacle.input.v1.InputService.SendCombo.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetFocusStackWindowIdsRequest
+---@param data pinnacle.input.v1.SendComboRequest
 ---
----@return pinnacle.output.v1.GetFocusStackWindowIdsResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetFocusStackWindowIds(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetFocusStackWindowIds, data)
+function Client:pinnacle_input_v1_InputService_SendCombo(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SendCombo, data)
 end
-pinnacle.output.v1.OutputService.GetOutputsInDir = {}
-pinnacle.output.v1.OutputService.GetOutputsInDir.service = "pinnacle.output.v1.OutputService"
-pinnacle.output.v1.OutputService.GetOutputsInDir.method = "GetOutputsInDir"
-pinnacle.output.v1.OutputService.GetOutputsInDir.request = ".pinnacle.output.v1.GetOutputsInDirRequest"
-pinnacle.output.v1.OutputService.GetOutputsInDir.response = ".pinnacle.output.v1.GetOutputsInDirResponse"
+pinnacle.input.v1.InputService.SetIdleDim = {}
+pinnacle.input.v1.InputService.SetIdleDim.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetIdleDim.method = "SetIdleDim"
+pinnacle.input.v1.InputService.SetIdleDim.request = ".pinnacle.input.v1.SetIdleDimRequest"
+pinnacle.input.v1.InputService.SetIdleDim.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.output.v1.GetOutputsInDirRequest
+---@param data pinnacle.input.v1.SetIdleDimRequest
 ---
----@return pinnacle.output.v1.GetOutputsInDirResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_output_v1_OutputService_GetOutputsInDir(data)
-    return self:unary_request(pinnacle.output.v1.OutputService.GetOutputsInDir, data)
+function Client:pinnacle_input_v1_InputService_SetIdleDim(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetIdleDim, data)
 end
-pinnacle.process.v1.ProcessService = {}
-pinnacle.process.v1.ProcessService.Spawn = {}
-pinnacle.process.v1.ProcessService.Spawn.service = "pinnacle.process.v1.ProcessService"
-pinnacle.process.v1.ProcessService.Spawn.method = "Spawn"
-pinnacle.process.v1.ProcessService.Spawn.request = ".pinnacle.process.v1.SpawnRequest"
-pinnacle.process.v1.ProcessService.Spawn.response = ".pinnacle.process.v1.SpawnResponse"
+pinnacle.input.v1.InputService.GetPointerFocus = {}
+pinnacle.input.v1.InputService.GetPointerFocus.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetPointerFocus.method = "GetPointerFocus"
+pinnacle.input.v1.InputService.GetPointerFocus.request = ".pinnacle.input.v1.GetPointerFocusRequest"
+pinnacle.input.v1.InputService.GetPointerFocus.response = ".pinnacle.input.v1.GetPointerFocusResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.process.v1.SpawnRequest
+---@param data pinnacle.input.v1.GetPointerFocusRequest
 ---
----@return pinnacle.process.v1.SpawnResponse | nil response
+---@return pinnacle.input.v1.GetPointerFocusResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_process_v1_ProcessService_Spawn(data)
-    return self:unary_request(pinnacle.process.v1.ProcessService.Spawn, data)
+function Client:pinnacle_input_v1_InputService_GetPointerFocus(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetPointerFocus, data)
 end
-pinnacle.process.v1.ProcessService.WaitOnSpawn = {}
-pinnacle.process.v1.ProcessService.WaitOnSpawn.service = "pinnacle.process.v1.ProcessService"
-pinnacle.process.v1.ProcessService.WaitOnSpawn.method = "WaitOnSpawn"
-pinnacle.process.v1.ProcessService.WaitOnSpawn.request = ".pinnacle.process.v1.WaitOnSpawnRequest"
-pinnacle.process.v1.ProcessService.WaitOnSpawn.response = ".pinnacle.process.v1.WaitOnSpawnResponse"
+pinnacle.input.v1.InputService.GetKeyboardFocus = {}
+pinnacle.input.v1.InputService.GetKeyboardFocus.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetKeyboardFocus.method = "GetKeyboardFocus"
+pinnacle.input.v1.InputService.GetKeyboardFocus.request = ".pinnacle.input.v1.GetKeyboardFocusRequest"
+pinnacle.input.v1.InputService.GetKeyboardFocus.response = ".pinnacle.input.v1.GetKeyboardFocusResponse"
 
----Performs a server-streaming request.
----
----`callback` will be called with every streamed response.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.process.v1.WaitOnSpawnRequest
----@param callback fun(response: pinnacle.process.v1.WaitOnSpawnResponse)
+---@param data pinnacle.input.v1.GetKeyboardFocusRequest
 ---
----@return string | nil An error string, if any
-function Client:pinnacle_process_v1_ProcessService_WaitOnSpawn(data, callback)
-    return self:server_streaming_request(pinnacle.process.v1.ProcessService.WaitOnSpawn, data, callback)
+---@return pinnacle.input.v1.GetKeyboardFocusResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_GetKeyboardFocus(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetKeyboardFocus, data)
 end
-pinnacle.process.v1.ProcessService.SetEnv = {}
-pinnacle.process.v1.ProcessService.SetEnv.service = "pinnacle.process.v1.ProcessService"
-pinnacle.process.v1.ProcessService.SetEnv.method = "SetEnv"
-pinnacle.process.v1.ProcessService.SetEnv.request = ".pinnacle.process.v1.SetEnvRequest"
-pinnacle.process.v1.ProcessService.SetEnv.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.GetIdleInhibited = {}
+pinnacle.input.v1.InputService.GetIdleInhibited.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetIdleInhibited.method = "GetIdleInhibited"
+pinnacle.input.v1.InputService.GetIdleInhibited.request = ".pinnacle.input.v1.GetIdleInhibitedRequest"
+pinnacle.input.v1.InputService.GetIdleInhibited.response = ".pinnacle.input.v1.GetIdleInhibitedResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.process.v1.SetEnvRequest
+---@param data pinnacle.input.v1.GetIdleInhibitedRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.input.v1.GetIdleInhibitedResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_process_v1_ProcessService_SetEnv(data)
-    return self:unary_request(pinnacle.process.v1.ProcessService.SetEnv, data)
+function Client:pinnacle_input_v1_InputService_GetIdleInhibited(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetIdleInhibited, data)
 end
-pinnacle.render.v1.RenderService = {}
-pinnacle.render.v1.RenderService.SetUpscaleFilter = {}
-pinnacle.render.v1.RenderService.SetUpscaleFilter.service = "pinnacle.render.v1.RenderService"
-pinnacle.render.v1.RenderService.SetUpscaleFilter.method = "SetUpscaleFilter"
-pinnacle.render.v1.RenderService.SetUpscaleFilter.request = ".pinnacle.render.v1.SetUpscaleFilterRequest"
-pinnacle.render.v1.RenderService.SetUpscaleFilter.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.GetDevices = {}
+pinnacle.input.v1.InputService.GetDevices.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetDevices.method = "GetDevices"
+pinnacle.input.v1.InputService.GetDevices.request = ".pinnacle.input.v1.GetDevicesRequest"
+pinnacle.input.v1.InputService.GetDevices.response = ".pinnacle.input.v1.GetDevicesResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.render.v1.SetUpscaleFilterRequest
+---@param data pinnacle.input.v1.GetDevicesRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.input.v1.GetDevicesResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_render_v1_RenderService_SetUpscaleFilter(data)
-    return self:unary_request(pinnacle.render.v1.RenderService.SetUpscaleFilter, data)
+function Client:pinnacle_input_v1_InputService_GetDevices(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetDevices, data)
 end
-pinnacle.render.v1.RenderService.SetDownscaleFilter = {}
-pinnacle.render.v1.RenderService.SetDownscaleFilter.service = "pinnacle.render.v1.RenderService"
-pinnacle.render.v1.RenderService.SetDownscaleFilter.method = "SetDownscaleFilter"
-pinnacle.render.v1.RenderService.SetDownscaleFilter.request = ".pinnacle.render.v1.SetDownscaleFilterRequest"
-pinnacle.render.v1.RenderService.SetDownscaleFilter.response = ".google.protobuf.Empty"
+pinnacle.input.v1.InputService.GetDeviceCapabilities = {}
+pinnacle.input.v1.InputService.GetDeviceCapabilities.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetDeviceCapabilities.method = "GetDeviceCapabilities"
+pinnacle.input.v1.InputService.GetDeviceCapabilities.request = ".pinnacle.input.v1.GetDeviceCapabilitiesRequest"
+pinnacle.input.v1.InputService.GetDeviceCapabilities.response = ".pinnacle.input.v1.GetDeviceCapabilitiesResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.render.v1.SetDownscaleFilterRequest
+---@param data pinnacle.input.v1.GetDeviceCapabilitiesRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.input.v1.GetDeviceCapabilitiesResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_render_v1_RenderService_SetDownscaleFilter(data)
-    return self:unary_request(pinnacle.render.v1.RenderService.SetDownscaleFilter, data)
+function Client:pinnacle_input_v1_InputService_GetDeviceCapabilities(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetDeviceCapabilities, data)
 end
-pinnacle.signal.v1.SignalService = {}
-pinnacle.signal.v1.SignalService.OutputConnect = {}
-pinnacle.signal.v1.SignalService.OutputConnect.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputConnect.method = "OutputConnect"
-pinnacle.signal.v1.SignalService.OutputConnect.request = ".pinnacle.signal.v1.OutputConnectRequest"
-pinnacle.signal.v1.SignalService.OutputConnect.response = ".pinnacle.signal.v1.OutputConnectResponse"
+pinnacle.input.v1.InputService.GetDeviceInfo = {}
+pinnacle.input.v1.InputService.GetDeviceInfo.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetDeviceInfo.method = "GetDeviceInfo"
+pinnacle.input.v1.InputService.GetDeviceInfo.request = ".pinnacle.input.v1.GetDeviceInfoRequest"
+pinnacle.input.v1.InputService.GetDeviceInfo.response = ".pinnacle.input.v1.GetDeviceInfoResponse"
 
----Performs a bidirectional-streaming request.
----
----`callback` will be called with every streamed response.
----
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputConnectResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.input.v1.GetDeviceInfoRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputConnect(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputConnect, callback)
+---@return pinnacle.input.v1.GetDeviceInfoResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_GetDeviceInfo(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetDeviceInfo, data)
 end
-pinnacle.signal.v1.SignalService.OutputDisconnect = {}
-pinnacle.signal.v1.SignalService.OutputDisconnect.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputDisconnect.method = "OutputDisconnect"
-pinnacle.signal.v1.SignalService.OutputDisconnect.request = ".pinnacle.signal.v1.OutputDisconnectRequest"
-pinnacle.signal.v1.SignalService.OutputDisconnect.response = ".pinnacle.signal.v1.OutputDisconnectResponse"
+pinnacle.input.v1.InputService.GetDeviceType = {}
+pinnacle.input.v1.InputService.GetDeviceType.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.GetDeviceType.method = "GetDeviceType"
+pinnacle.input.v1.InputService.GetDeviceType.request = ".pinnacle.input.v1.GetDeviceTypeRequest"
+pinnacle.input.v1.InputService.GetDeviceType.response = ".pinnacle.input.v1.GetDeviceTypeResponse"
 
----Performs a bidirectional-streaming request.
----
----`callback` will be called with every streamed response.
----
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputDisconnectResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.input.v1.GetDeviceTypeRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputDisconnect(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputDisconnect, callback)
+---@return pinnacle.input.v1.GetDeviceTypeResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_GetDeviceType(data)
+    return self:unary_request(pinnacle.input.v1.InputService.GetDeviceType, data)
 end
-pinnacle.signal.v1.SignalService.OutputResize = {}
-pinnacle.signal.v1.SignalService.OutputResize.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputResize.method = "OutputResize"
-pinnacle.signal.v1.SignalService.OutputResize.request = ".pinnacle.signal.v1.OutputResizeRequest"
-pinnacle.signal.v1.SignalService.OutputResize.response = ".pinnacle.signal.v1.OutputResizeResponse"
+pinnacle.input.v1.InputService.SetDeviceLibinputSetting = {}
+pinnacle.input.v1.InputService.SetDeviceLibinputSetting.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetDeviceLibinputSetting.method = "SetDeviceLibinputSetting"
+pinnacle.input.v1.InputService.SetDeviceLibinputSetting.request = ".pinnacle.input.v1.SetDeviceLibinputSettingRequest"
+pinnacle.input.v1.InputService.SetDeviceLibinputSetting.response = ".google.protobuf.Empty"
 
----Performs a bidirectional-streaming request.
+---Performs a unary request.
 ---
----`callback` will be called with every streamed response.
+---@nodiscard
 ---
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---@param data pinnacle.input.v1.SetDeviceLibinputSettingRequest
+---
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetDeviceLibinputSetting(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetDeviceLibinputSetting, data)
+end
+pinnacle.input.v1.InputService.SetDeviceMapTarget = {}
+pinnacle.input.v1.InputService.SetDeviceMapTarget.service = "pinnacle.input.v1.InputService"
+pinnacle.input.v1.InputService.SetDeviceMapTarget.method = "SetDeviceMapTarget"
+pinnacle.input.v1.InputService.SetDeviceMapTarget.request = ".pinnacle.input.v1.SetDeviceMapTargetRequest"
+pinnacle.input.v1.InputService.SetDeviceMapTarget.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputResizeResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.input.v1.SetDeviceMapTargetRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputResize(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputResize, callback)
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_input_v1_InputService_SetDeviceMapTarget(data)
+    return self:unary_request(pinnacle.input.v1.InputService.SetDeviceMapTarget, data)
 end
-pinnacle.signal.v1.SignalService.OutputMove = {}
-pinnacle.signal.v1.SignalService.OutputMove.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputMove.method = "OutputMove"
-pinnacle.signal.v1.SignalService.OutputMove.request = ".pinnacle.signal.v1.OutputMoveRequest"
-pinnacle.signal.v1.SignalService.OutputMove.response = ".pinnacle.signal.v1.OutputMoveResponse"
+pinnacle.layout.v1.LayoutService = {}
+pinnacle.layout.v1.LayoutService.Layout = {}
+pinnacle.layout.v1.LayoutService.Layout.service = "pinnacle.layout.v1.LayoutService"
+pinnacle.layout.v1.LayoutService.Layout.method = "Layout"
+pinnacle.layout.v1.LayoutService.Layout.request = ".pinnacle.layout.v1.LayoutRequest"
+pinnacle.layout.v1.LayoutService.Layout.response = ".pinnacle.layout.v1.LayoutResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -2575,379 +5067,408 @@ pinnacle.signal.v1.SignalService.OutputMove.response = ".pinnacle.signal.v1.Outp
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputMoveResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.layout.v1.LayoutResponse, stream: grpc_client.h2.Stream)
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputMove(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputMove, callback)
+function Client:pinnacle_layout_v1_LayoutService_Layout(callback)
+    return self:bidirectional_streaming_request(pinnacle.layout.v1.LayoutService.Layout, callback)
 end
-pinnacle.signal.v1.SignalService.OutputPointerEnter = {}
-pinnacle.signal.v1.SignalService.OutputPointerEnter.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputPointerEnter.method = "OutputPointerEnter"
-pinnacle.signal.v1.SignalService.OutputPointerEnter.request = ".pinnacle.signal.v1.OutputPointerEnterRequest"
-pinnacle.signal.v1.SignalService.OutputPointerEnter.response = ".pinnacle.signal.v1.OutputPointerEnterResponse"
+pinnacle.process.v1.ProcessService = {}
+pinnacle.process.v1.ProcessService.Spawn = {}
+pinnacle.process.v1.ProcessService.Spawn.service = "pinnacle.process.v1.ProcessService"
+pinnacle.process.v1.ProcessService.Spawn.method = "Spawn"
+pinnacle.process.v1.ProcessService.Spawn.request = ".pinnacle.process.v1.SpawnRequest"
+pinnacle.process.v1.ProcessService.Spawn.response = ".pinnacle.process.v1.SpawnResponse"
 
----Performs a bidirectional-streaming request.
----
----`callback` will be called with every streamed response.
----
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputPointerEnterResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.process.v1.SpawnRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputPointerEnter(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputPointerEnter, callback)
+---@return pinnacle.process.v1.SpawnResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_process_v1_ProcessService_Spawn(data)
+    return self:unary_request(pinnacle.process.v1.ProcessService.Spawn, data)
 end
-pinnacle.signal.v1.SignalService.OutputPointerLeave = {}
-pinnacle.signal.v1.SignalService.OutputPointerLeave.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputPointerLeave.method = "OutputPointerLeave"
-pinnacle.signal.v1.SignalService.OutputPointerLeave.request = ".pinnacle.signal.v1.OutputPointerLeaveRequest"
-pinnacle.signal.v1.SignalService.OutputPointerLeave.response = ".pinnacle.signal.v1.OutputPointerLeaveResponse"
+pinnacle.process.v1.ProcessService.WaitOnSpawn = {}
+pinnacle.process.v1.ProcessService.WaitOnSpawn.service = "pinnacle.process.v1.ProcessService"
+pinnacle.process.v1.ProcessService.WaitOnSpawn.method = "WaitOnSpawn"
+pinnacle.process.v1.ProcessService.WaitOnSpawn.request = ".pinnacle.process.v1.WaitOnSpawnRequest"
+pinnacle.process.v1.ProcessService.WaitOnSpawn.response = ".pinnacle.process.v1.WaitOnSpawnResponse"
 
----Performs a bidirectional-streaming request.
+---Performs a server-streaming request.
 ---
 ---`callback` will be called with every streamed response.
 ---
----The raw client-to-server stream is returned to allow you to send encoded messages.
----
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputPointerLeaveResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.process.v1.WaitOnSpawnRequest
+---@param callback fun(response: pinnacle.process.v1.WaitOnSpawnResponse)
 ---
----@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputPointerLeave(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputPointerLeave, callback)
+function Client:pinnacle_process_v1_ProcessService_WaitOnSpawn(data, callback)
+    return self:server_streaming_request(pinnacle.process.v1.ProcessService.WaitOnSpawn, data, callback)
 end
-pinnacle.signal.v1.SignalService.OutputFocused = {}
-pinnacle.signal.v1.SignalService.OutputFocused.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.OutputFocused.method = "OutputFocused"
-pinnacle.signal.v1.SignalService.OutputFocused.request = ".pinnacle.signal.v1.OutputFocusedRequest"
-pinnacle.signal.v1.SignalService.OutputFocused.response = ".pinnacle.signal.v1.OutputFocusedResponse"
+pinnacle.process.v1.ProcessService.SetEnv = {}
+pinnacle.process.v1.ProcessService.SetEnv.service = "pinnacle.process.v1.ProcessService"
+pinnacle.process.v1.ProcessService.SetEnv.method = "SetEnv"
+pinnacle.process.v1.ProcessService.SetEnv.request = ".pinnacle.process.v1.SetEnvRequest"
+pinnacle.process.v1.ProcessService.SetEnv.response = ".google.protobuf.Empty"
 
----Performs a bidirectional-streaming request.
----
----`callback` will be called with every streamed response.
----
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.OutputFocusedResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.process.v1.SetEnvRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_OutputFocused(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputFocused, callback)
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_process_v1_ProcessService_SetEnv(data)
+    return self:unary_request(pinnacle.process.v1.ProcessService.SetEnv, data)
 end
-pinnacle.signal.v1.SignalService.WindowPointerEnter = {}
-pinnacle.signal.v1.SignalService.WindowPointerEnter.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.WindowPointerEnter.method = "WindowPointerEnter"
-pinnacle.signal.v1.SignalService.WindowPointerEnter.request = ".pinnacle.signal.v1.WindowPointerEnterRequest"
-pinnacle.signal.v1.SignalService.WindowPointerEnter.response = ".pinnacle.signal.v1.WindowPointerEnterResponse"
+pinnacle.process.v1.ProcessService.Reap = {}
+pinnacle.process.v1.ProcessService.Reap.service = "pinnacle.process.v1.ProcessService"
+pinnacle.process.v1.ProcessService.Reap.method = "Reap"
+pinnacle.process.v1.ProcessService.Reap.request = ".pinnacle.process.v1.ReapRequest"
+pinnacle.process.v1.ProcessService.Reap.response = ".pinnacle.process.v1.ReapResponse"
 
----Performs a bidirectional-streaming request.
----
----`callback` will be called with every streamed response.
----
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.WindowPointerEnterResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.process.v1.ReapRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_WindowPointerEnter(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowPointerEnter, callback)
+---@return pinnacle.process.v1.ReapResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_process_v1_ProcessService_Reap(data)
+    return self:unary_request(pinnacle.process.v1.ProcessService.Reap, data)
 end
-pinnacle.signal.v1.SignalService.WindowPointerLeave = {}
-pinnacle.signal.v1.SignalService.WindowPointerLeave.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.WindowPointerLeave.method = "WindowPointerLeave"
-pinnacle.signal.v1.SignalService.WindowPointerLeave.request = ".pinnacle.signal.v1.WindowPointerLeaveRequest"
-pinnacle.signal.v1.SignalService.WindowPointerLeave.response = ".pinnacle.signal.v1.WindowPointerLeaveResponse"
+pinnacle.process.v1.ProcessService.GetChildren = {}
+pinnacle.process.v1.ProcessService.GetChildren.service = "pinnacle.process.v1.ProcessService"
+pinnacle.process.v1.ProcessService.GetChildren.method = "GetChildren"
+pinnacle.process.v1.ProcessService.GetChildren.request = ".pinnacle.process.v1.GetChildrenRequest"
+pinnacle.process.v1.ProcessService.GetChildren.response = ".pinnacle.process.v1.GetChildrenResponse"
 
----Performs a bidirectional-streaming request.
----
----`callback` will be called with every streamed response.
----
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.WindowPointerLeaveResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.process.v1.GetChildrenRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_WindowPointerLeave(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowPointerLeave, callback)
+---@return pinnacle.process.v1.GetChildrenResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_process_v1_ProcessService_GetChildren(data)
+    return self:unary_request(pinnacle.process.v1.ProcessService.GetChildren, data)
 end
-pinnacle.signal.v1.SignalService.WindowFocused = {}
-pinnacle.signal.v1.SignalService.WindowFocused.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.WindowFocused.method = "WindowFocused"
-pinnacle.signal.v1.SignalService.WindowFocused.request = ".pinnacle.signal.v1.WindowFocusedRequest"
-pinnacle.signal.v1.SignalService.WindowFocused.response = ".pinnacle.signal.v1.WindowFocusedResponse"
+pinnacle.render.v1.RenderService = {}
+pinnacle.render.v1.RenderService.SetUpscaleFilter = {}
+pinnacle.render.v1.RenderService.SetUpscaleFilter.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.SetUpscaleFilter.method = "SetUpscaleFilter"
+pinnacle.render.v1.RenderService.SetUpscaleFilter.request = ".pinnacle.render.v1.SetUpscaleFilterRequest"
+pinnacle.render.v1.RenderService.SetUpscaleFilter.response = ".google.protobuf.Empty"
 
----Performs a bidirectional-streaming request.
----
----`callback` will be called with every streamed response.
----
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.WindowFocusedResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.render.v1.SetUpscaleFilterRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_WindowFocused(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowFocused, callback)
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_render_v1_RenderService_SetUpscaleFilter(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.SetUpscaleFilter, data)
 end
-pinnacle.signal.v1.SignalService.WindowTitleChanged = {}
-pinnacle.signal.v1.SignalService.WindowTitleChanged.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.WindowTitleChanged.method = "WindowTitleChanged"
-pinnacle.signal.v1.SignalService.WindowTitleChanged.request = ".pinnacle.signal.v1.WindowTitleChangedRequest"
-pinnacle.signal.v1.SignalService.WindowTitleChanged.response = ".pinnacle.signal.v1.WindowTitleChangedResponse"
+pinnacle.render.v1.RenderService.SetDownscaleFilter = {}
+pinnacle.render.v1.RenderService.SetDownscaleFilter.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.SetDownscaleFilter.method = "SetDownscaleFilter"
+pinnacle.render.v1.RenderService.SetDownscaleFilter.request = ".pinnacle.render.v1.SetDownscaleFilterRequest"
+pinnacle.render.v1.RenderService.SetDownscaleFilter.response = ".google.protobuf.Empty"
 
----Performs a bidirectional-streaming request.
----
----`callback` will be called with every streamed response.
----
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.WindowTitleChangedResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.render.v1.SetDownscaleFilterRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_WindowTitleChanged(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowTitleChanged, callback)
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_render_v1_RenderService_SetDownscaleFilter(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.SetDownscaleFilter, data)
 end
-pinnacle.signal.v1.SignalService.TagActive = {}
-pinnacle.signal.v1.SignalService.TagActive.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.TagActive.method = "TagActive"
-pinnacle.signal.v1.SignalService.TagActive.request = ".pinnacle.signal.v1.TagActiveRequest"
-pinnacle.signal.v1.SignalService.TagActive.response = ".pinnacle.signal.v1.TagActiveResponse"
+pinnacle.render.v1.RenderService.ScreenshotWindow = {}
+pinnacle.render.v1.RenderService.ScreenshotWindow.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.ScreenshotWindow.method = "ScreenshotWindow"
+pinnacle.render.v1.RenderService.ScreenshotWindow.request = ".pinnacle.render.v1.ScreenshotWindowRequest"
+pinnacle.render.v1.RenderService.ScreenshotWindow.response = ".pinnacle.render.v1.ScreenshotWindowResponse"
 
----Performs a bidirectional-streaming request.
+---Performs a unary request.
 ---
----`callback` will be called with every streamed response.
+---@nodiscard
 ---
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---@param data pinnacle.render.v1.ScreenshotWindowRequest
+---
+---@return pinnacle.render.v1.ScreenshotWindowResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_render_v1_RenderService_ScreenshotWindow(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.ScreenshotWindow, data)
+end
+pinnacle.render.v1.RenderService.OutputThumbnail = {}
+pinnacle.render.v1.RenderService.OutputThumbnail.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.OutputThumbnail.method = "OutputThumbnail"
+pinnacle.render.v1.RenderService.OutputThumbnail.request = ".pinnacle.render.v1.OutputThumbnailRequest"
+pinnacle.render.v1.RenderService.OutputThumbnail.response = ".pinnacle.render.v1.OutputThumbnailResponse"
+
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.TagActiveResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.render.v1.OutputThumbnailRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_TagActive(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.TagActive, callback)
+---@return pinnacle.render.v1.OutputThumbnailResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_render_v1_RenderService_OutputThumbnail(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.OutputThumbnail, data)
 end
-pinnacle.signal.v1.SignalService.InputDeviceAdded = {}
-pinnacle.signal.v1.SignalService.InputDeviceAdded.service = "pinnacle.signal.v1.SignalService"
-pinnacle.signal.v1.SignalService.InputDeviceAdded.method = "InputDeviceAdded"
-pinnacle.signal.v1.SignalService.InputDeviceAdded.request = ".pinnacle.signal.v1.InputDeviceAddedRequest"
-pinnacle.signal.v1.SignalService.InputDeviceAdded.response = ".pinnacle.signal.v1.InputDeviceAddedResponse"
+pinnacle.render.v1.RenderService.GetRenderState = {}
+pinnacle.render.v1.RenderService.GetRenderState.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.GetRenderState.method = "GetRenderState"
+pinnacle.render.v1.RenderService.GetRenderState.request = ".pinnacle.render.v1.GetRenderStateRequest"
+pinnacle.render.v1.RenderService.GetRenderState.response = ".pinnacle.render.v1.GetRenderStateResponse"
 
----Performs a bidirectional-streaming request.
+---Performs a unary request.
 ---
----`callback` will be called with every streamed response.
+---@nodiscard
 ---
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---@param data pinnacle.render.v1.GetRenderStateRequest
+---
+---@return pinnacle.render.v1.GetRenderStateResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_render_v1_RenderService_GetRenderState(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.GetRenderState, data)
+end
+pinnacle.render.v1.RenderService.SetAnimationFpsCap = {}
+pinnacle.render.v1.RenderService.SetAnimationFpsCap.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.SetAnimationFpsCap.method = "SetAnimationFpsCap"
+pinnacle.render.v1.RenderService.SetAnimationFpsCap.request = ".pinnacle.render.v1.SetAnimationFpsCapRequest"
+pinnacle.render.v1.RenderService.SetAnimationFpsCap.response = ".google.protobuf.Empty"
+
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.signal.v1.InputDeviceAddedResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.render.v1.SetAnimationFpsCapRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_signal_v1_SignalService_InputDeviceAdded(callback)
-    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.InputDeviceAdded, callback)
+---@return google.protobuf.Empty | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_render_v1_RenderService_SetAnimationFpsCap(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.SetAnimationFpsCap, data)
 end
-pinnacle.tag.v1.TagService = {}
-pinnacle.tag.v1.TagService.Get = {}
-pinnacle.tag.v1.TagService.Get.service = "pinnacle.tag.v1.TagService"
-pinnacle.tag.v1.TagService.Get.method = "Get"
-pinnacle.tag.v1.TagService.Get.request = ".pinnacle.tag.v1.GetRequest"
-pinnacle.tag.v1.TagService.Get.response = ".pinnacle.tag.v1.GetResponse"
+pinnacle.render.v1.RenderService.SetFrameCallbackPolicy = {}
+pinnacle.render.v1.RenderService.SetFrameCallbackPolicy.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.SetFrameCallbackPolicy.method = "SetFrameCallbackPolicy"
+pinnacle.render.v1.RenderService.SetFrameCallbackPolicy.request = ".pinnacle.render.v1.SetFrameCallbackPolicyRequest"
+pinnacle.render.v1.RenderService.SetFrameCallbackPolicy.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.tag.v1.GetRequest
+---@param data pinnacle.render.v1.SetFrameCallbackPolicyRequest
 ---
----@return pinnacle.tag.v1.GetResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_tag_v1_TagService_Get(data)
-    return self:unary_request(pinnacle.tag.v1.TagService.Get, data)
+function Client:pinnacle_render_v1_RenderService_SetFrameCallbackPolicy(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.SetFrameCallbackPolicy, data)
 end
-pinnacle.tag.v1.TagService.GetActive = {}
-pinnacle.tag.v1.TagService.GetActive.service = "pinnacle.tag.v1.TagService"
-pinnacle.tag.v1.TagService.GetActive.method = "GetActive"
-pinnacle.tag.v1.TagService.GetActive.request = ".pinnacle.tag.v1.GetActiveRequest"
-pinnacle.tag.v1.TagService.GetActive.response = ".pinnacle.tag.v1.GetActiveResponse"
+pinnacle.render.v1.RenderService.SetCursorMode = {}
+pinnacle.render.v1.RenderService.SetCursorMode.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.SetCursorMode.method = "SetCursorMode"
+pinnacle.render.v1.RenderService.SetCursorMode.request = ".pinnacle.render.v1.SetCursorModeRequest"
+pinnacle.render.v1.RenderService.SetCursorMode.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.tag.v1.GetActiveRequest
+---@param data pinnacle.render.v1.SetCursorModeRequest
 ---
----@return pinnacle.tag.v1.GetActiveResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_tag_v1_TagService_GetActive(data)
-    return self:unary_request(pinnacle.tag.v1.TagService.GetActive, data)
+function Client:pinnacle_render_v1_RenderService_SetCursorMode(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.SetCursorMode, data)
 end
-pinnacle.tag.v1.TagService.GetName = {}
-pinnacle.tag.v1.TagService.GetName.service = "pinnacle.tag.v1.TagService"
-pinnacle.tag.v1.TagService.GetName.method = "GetName"
-pinnacle.tag.v1.TagService.GetName.request = ".pinnacle.tag.v1.GetNameRequest"
-pinnacle.tag.v1.TagService.GetName.response = ".pinnacle.tag.v1.GetNameResponse"
+pinnacle.render.v1.RenderService.SetOverview = {}
+pinnacle.render.v1.RenderService.SetOverview.service = "pinnacle.render.v1.RenderService"
+pinnacle.render.v1.RenderService.SetOverview.method = "SetOverview"
+pinnacle.render.v1.RenderService.SetOverview.request = ".pinnacle.render.v1.SetOverviewRequest"
+pinnacle.render.v1.RenderService.SetOverview.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.tag.v1.GetNameRequest
+---@param data pinnacle.render.v1.SetOverviewRequest
 ---
----@return pinnacle.tag.v1.GetNameResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_tag_v1_TagService_GetName(data)
-    return self:unary_request(pinnacle.tag.v1.TagService.GetName, data)
+function Client:pinnacle_render_v1_RenderService_SetOverview(data)
+    return self:unary_request(pinnacle.render.v1.RenderService.SetOverview, data)
 end
-pinnacle.tag.v1.TagService.GetOutputName = {}
-pinnacle.tag.v1.TagService.GetOutputName.service = "pinnacle.tag.v1.TagService"
-pinnacle.tag.v1.TagService.GetOutputName.method = "GetOutputName"
-pinnacle.tag.v1.TagService.GetOutputName.request = ".pinnacle.tag.v1.GetOutputNameRequest"
-pinnacle.tag.v1.TagService.GetOutputName.response = ".pinnacle.tag.v1.GetOutputNameResponse"
+pinnacle.signal.v1.SignalService = {}
+pinnacle.signal.v1.SignalService.OutputConnect = {}
+pinnacle.signal.v1.SignalService.OutputConnect.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputConnect.method = "OutputConnect"
+pinnacle.signal.v1.SignalService.OutputConnect.request = ".pinnacle.signal.v1.OutputConnectRequest"
+pinnacle.signal.v1.SignalService.OutputConnect.response = ".pinnacle.signal.v1.OutputConnectResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.tag.v1.GetOutputNameRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputConnectResponse, stream: grpc_client.h2.Stream)
 ---
----@return pinnacle.tag.v1.GetOutputNameResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_tag_v1_TagService_GetOutputName(data)
-    return self:unary_request(pinnacle.tag.v1.TagService.GetOutputName, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputConnect(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputConnect, callback)
 end
-pinnacle.tag.v1.TagService.Add = {}
-pinnacle.tag.v1.TagService.Add.service = "pinnacle.tag.v1.TagService"
-pinnacle.tag.v1.TagService.Add.method = "Add"
-pinnacle.tag.v1.TagService.Add.request = ".pinnacle.tag.v1.AddRequest"
-pinnacle.tag.v1.TagService.Add.response = ".pinnacle.tag.v1.AddResponse"
+pinnacle.signal.v1.SignalService.OutputDisconnect = {}
+pinnacle.signal.v1.SignalService.OutputDisconnect.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputDisconnect.method = "OutputDisconnect"
+pinnacle.signal.v1.SignalService.OutputDisconnect.request = ".pinnacle.signal.v1.OutputDisconnectRequest"
+pinnacle.signal.v1.SignalService.OutputDisconnect.response = ".pinnacle.signal.v1.OutputDisconnectResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.tag.v1.AddRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputDisconnectResponse, stream: grpc_client.h2.Stream)
 ---
----@return pinnacle.tag.v1.AddResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_tag_v1_TagService_Add(data)
-    return self:unary_request(pinnacle.tag.v1.TagService.Add, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputDisconnect(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputDisconnect, callback)
 end
-pinnacle.tag.v1.TagService.Remove = {}
-pinnacle.tag.v1.TagService.Remove.service = "pinnacle.tag.v1.TagService"
-pinnacle.tag.v1.TagService.Remove.method = "Remove"
-pinnacle.tag.v1.TagService.Remove.request = ".pinnacle.tag.v1.RemoveRequest"
-pinnacle.tag.v1.TagService.Remove.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputResize = {}
+pinnacle.signal.v1.SignalService.OutputResize.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputResize.method = "OutputResize"
+pinnacle.signal.v1.SignalService.OutputResize.request = ".pinnacle.signal.v1.OutputResizeRequest"
+pinnacle.signal.v1.SignalService.OutputResize.response = ".pinnacle.signal.v1.OutputResizeResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.tag.v1.RemoveRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputResizeResponse, stream: grpc_client.h2.Stream)
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_tag_v1_TagService_Remove(data)
-    return self:unary_request(pinnacle.tag.v1.TagService.Remove, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputResize(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputResize, callback)
 end
-pinnacle.tag.v1.TagService.SetActive = {}
-pinnacle.tag.v1.TagService.SetActive.service = "pinnacle.tag.v1.TagService"
-pinnacle.tag.v1.TagService.SetActive.method = "SetActive"
-pinnacle.tag.v1.TagService.SetActive.request = ".pinnacle.tag.v1.SetActiveRequest"
-pinnacle.tag.v1.TagService.SetActive.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputMove = {}
+pinnacle.signal.v1.SignalService.OutputMove.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputMove.method = "OutputMove"
+pinnacle.signal.v1.SignalService.OutputMove.request = ".pinnacle.signal.v1.OutputMoveRequest"
+pinnacle.signal.v1.SignalService.OutputMove.response = ".pinnacle.signal.v1.OutputMoveResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.tag.v1.SetActiveRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputMoveResponse, stream: grpc_client.h2.Stream)
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_tag_v1_TagService_SetActive(data)
-    return self:unary_request(pinnacle.tag.v1.TagService.SetActive, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputMove(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputMove, callback)
 end
-pinnacle.tag.v1.TagService.SwitchTo = {}
-pinnacle.tag.v1.TagService.SwitchTo.service = "pinnacle.tag.v1.TagService"
-pinnacle.tag.v1.TagService.SwitchTo.method = "SwitchTo"
-pinnacle.tag.v1.TagService.SwitchTo.request = ".pinnacle.tag.v1.SwitchToRequest"
-pinnacle.tag.v1.TagService.SwitchTo.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputPointerEnter = {}
+pinnacle.signal.v1.SignalService.OutputPointerEnter.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputPointerEnter.method = "OutputPointerEnter"
+pinnacle.signal.v1.SignalService.OutputPointerEnter.request = ".pinnacle.signal.v1.OutputPointerEnterRequest"
+pinnacle.signal.v1.SignalService.OutputPointerEnter.response = ".pinnacle.signal.v1.OutputPointerEnterResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.tag.v1.SwitchToRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputPointerEnterResponse, stream: grpc_client.h2.Stream)
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_tag_v1_TagService_SwitchTo(data)
-    return self:unary_request(pinnacle.tag.v1.TagService.SwitchTo, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputPointerEnter(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputPointerEnter, callback)
 end
-pinnacle.v1.PinnacleService = {}
-pinnacle.v1.PinnacleService.Quit = {}
-pinnacle.v1.PinnacleService.Quit.service = "pinnacle.v1.PinnacleService"
-pinnacle.v1.PinnacleService.Quit.method = "Quit"
-pinnacle.v1.PinnacleService.Quit.request = ".pinnacle.v1.QuitRequest"
-pinnacle.v1.PinnacleService.Quit.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputPointerLeave = {}
+pinnacle.signal.v1.SignalService.OutputPointerLeave.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputPointerLeave.method = "OutputPointerLeave"
+pinnacle.signal.v1.SignalService.OutputPointerLeave.request = ".pinnacle.signal.v1.OutputPointerLeaveRequest"
+pinnacle.signal.v1.SignalService.OutputPointerLeave.response = ".pinnacle.signal.v1.OutputPointerLeaveResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.v1.QuitRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputPointerLeaveResponse, stream: grpc_client.h2.Stream)
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_v1_PinnacleService_Quit(data)
-    return self:unary_request(pinnacle.v1.PinnacleService.Quit, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputPointerLeave(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputPointerLeave, callback)
 end
-pinnacle.v1.PinnacleService.ReloadConfig = {}
-pinnacle.v1.PinnacleService.ReloadConfig.service = "pinnacle.v1.PinnacleService"
-pinnacle.v1.PinnacleService.ReloadConfig.method = "ReloadConfig"
-pinnacle.v1.PinnacleService.ReloadConfig.request = ".pinnacle.v1.ReloadConfigRequest"
-pinnacle.v1.PinnacleService.ReloadConfig.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.OutputFocused = {}
+pinnacle.signal.v1.SignalService.OutputFocused.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.OutputFocused.method = "OutputFocused"
+pinnacle.signal.v1.SignalService.OutputFocused.request = ".pinnacle.signal.v1.OutputFocusedRequest"
+pinnacle.signal.v1.SignalService.OutputFocused.response = ".pinnacle.signal.v1.OutputFocusedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.v1.ReloadConfigRequest
+---@param callback fun(response: pinnacle.signal.v1.OutputFocusedResponse, stream: grpc_client.h2.Stream)
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_v1_PinnacleService_ReloadConfig(data)
-    return self:unary_request(pinnacle.v1.PinnacleService.ReloadConfig, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_OutputFocused(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.OutputFocused, callback)
 end
-pinnacle.v1.PinnacleService.Keepalive = {}
-pinnacle.v1.PinnacleService.Keepalive.service = "pinnacle.v1.PinnacleService"
-pinnacle.v1.PinnacleService.Keepalive.method = "Keepalive"
-pinnacle.v1.PinnacleService.Keepalive.request = ".pinnacle.v1.KeepaliveRequest"
-pinnacle.v1.PinnacleService.Keepalive.response = ".pinnacle.v1.KeepaliveResponse"
+pinnacle.signal.v1.SignalService.WindowPointerEnter = {}
+pinnacle.signal.v1.SignalService.WindowPointerEnter.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowPointerEnter.method = "WindowPointerEnter"
+pinnacle.signal.v1.SignalService.WindowPointerEnter.request = ".pinnacle.signal.v1.WindowPointerEnterRequest"
+pinnacle.signal.v1.SignalService.WindowPointerEnter.response = ".pinnacle.signal.v1.WindowPointerEnterResponse"
 
 ---Performs a bidirectional-streaming request.
 ---
@@ -2957,578 +5478,606 @@ pinnacle.v1.PinnacleService.Keepalive.response = ".pinnacle.v1.KeepaliveResponse
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.v1.KeepaliveResponse, stream: grpc_client.h2.Stream)
+---@param callback fun(response: pinnacle.signal.v1.WindowPointerEnterResponse, stream: grpc_client.h2.Stream)
 ---
 ---@return grpc_client.h2.Stream | nil
 ---@return string | nil An error string, if any
-function Client:pinnacle_v1_PinnacleService_Keepalive(callback)
-    return self:bidirectional_streaming_request(pinnacle.v1.PinnacleService.Keepalive, callback)
+function Client:pinnacle_signal_v1_SignalService_WindowPointerEnter(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowPointerEnter, callback)
 end
-pinnacle.v1.PinnacleService.Backend = {}
-pinnacle.v1.PinnacleService.Backend.service = "pinnacle.v1.PinnacleService"
-pinnacle.v1.PinnacleService.Backend.method = "Backend"
-pinnacle.v1.PinnacleService.Backend.request = ".pinnacle.v1.BackendRequest"
-pinnacle.v1.PinnacleService.Backend.response = ".pinnacle.v1.BackendResponse"
+pinnacle.signal.v1.SignalService.WindowPointerLeave = {}
+pinnacle.signal.v1.SignalService.WindowPointerLeave.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowPointerLeave.method = "WindowPointerLeave"
+pinnacle.signal.v1.SignalService.WindowPointerLeave.request = ".pinnacle.signal.v1.WindowPointerLeaveRequest"
+pinnacle.signal.v1.SignalService.WindowPointerLeave.response = ".pinnacle.signal.v1.WindowPointerLeaveResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.v1.BackendRequest
+---@param callback fun(response: pinnacle.signal.v1.WindowPointerLeaveResponse, stream: grpc_client.h2.Stream)
 ---
----@return pinnacle.v1.BackendResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_v1_PinnacleService_Backend(data)
-    return self:unary_request(pinnacle.v1.PinnacleService.Backend, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_WindowPointerLeave(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowPointerLeave, callback)
 end
-pinnacle.v1.PinnacleService.SetXwaylandClientSelfScale = {}
-pinnacle.v1.PinnacleService.SetXwaylandClientSelfScale.service = "pinnacle.v1.PinnacleService"
-pinnacle.v1.PinnacleService.SetXwaylandClientSelfScale.method = "SetXwaylandClientSelfScale"
-pinnacle.v1.PinnacleService.SetXwaylandClientSelfScale.request = ".pinnacle.v1.SetXwaylandClientSelfScaleRequest"
-pinnacle.v1.PinnacleService.SetXwaylandClientSelfScale.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.WindowFocused = {}
+pinnacle.signal.v1.SignalService.WindowFocused.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowFocused.method = "WindowFocused"
+pinnacle.signal.v1.SignalService.WindowFocused.request = ".pinnacle.signal.v1.WindowFocusedRequest"
+pinnacle.signal.v1.SignalService.WindowFocused.response = ".pinnacle.signal.v1.WindowFocusedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.v1.SetXwaylandClientSelfScaleRequest
+---@param callback fun(response: pinnacle.signal.v1.WindowFocusedResponse, stream: grpc_client.h2.Stream)
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_v1_PinnacleService_SetXwaylandClientSelfScale(data)
-    return self:unary_request(pinnacle.v1.PinnacleService.SetXwaylandClientSelfScale, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_WindowFocused(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowFocused, callback)
 end
-pinnacle.v1.PinnacleService.SetLastError = {}
-pinnacle.v1.PinnacleService.SetLastError.service = "pinnacle.v1.PinnacleService"
-pinnacle.v1.PinnacleService.SetLastError.method = "SetLastError"
-pinnacle.v1.PinnacleService.SetLastError.request = ".pinnacle.v1.SetLastErrorRequest"
-pinnacle.v1.PinnacleService.SetLastError.response = ".google.protobuf.Empty"
+pinnacle.signal.v1.SignalService.WindowTitleChanged = {}
+pinnacle.signal.v1.SignalService.WindowTitleChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowTitleChanged.method = "WindowTitleChanged"
+pinnacle.signal.v1.SignalService.WindowTitleChanged.request = ".pinnacle.signal.v1.WindowTitleChangedRequest"
+pinnacle.signal.v1.SignalService.WindowTitleChanged.response = ".pinnacle.signal.v1.WindowTitleChangedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.v1.SetLastErrorRequest
+---@param callback fun(response: pinnacle.signal.v1.WindowTitleChangedResponse, stream: grpc_client.h2.Stream)
 ---
----@return google.protobuf.Empty | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_v1_PinnacleService_SetLastError(data)
-    return self:unary_request(pinnacle.v1.PinnacleService.SetLastError, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_WindowTitleChanged(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowTitleChanged, callback)
 end
-pinnacle.v1.PinnacleService.TakeLastError = {}
-pinnacle.v1.PinnacleService.TakeLastError.service = "pinnacle.v1.PinnacleService"
-pinnacle.v1.PinnacleService.TakeLastError.method = "TakeLastError"
-pinnacle.v1.PinnacleService.TakeLastError.request = ".pinnacle.v1.TakeLastErrorRequest"
-pinnacle.v1.PinnacleService.TakeLastError.response = ".pinnacle.v1.TakeLastErrorResponse"
+pinnacle.signal.v1.SignalService.WindowUrgentChanged = {}
+pinnacle.signal.v1.SignalService.WindowUrgentChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowUrgentChanged.method = "WindowUrgentChanged"
+pinnacle.signal.v1.SignalService.WindowUrgentChanged.request = ".pinnacle.signal.v1.WindowUrgentChangedRequest"
+pinnacle.signal.v1.SignalService.WindowUrgentChanged.response = ".pinnacle.signal.v1.WindowUrgentChangedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.v1.TakeLastErrorRequest
+---@param callback fun(response: pinnacle.signal.v1.WindowUrgentChangedResponse, stream: grpc_client.h2.Stream)
 ---
----@return pinnacle.v1.TakeLastErrorResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_v1_PinnacleService_TakeLastError(data)
-    return self:unary_request(pinnacle.v1.PinnacleService.TakeLastError, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_WindowUrgentChanged(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowUrgentChanged, callback)
 end
-pinnacle.window.v1.WindowService = {}
-pinnacle.window.v1.WindowService.Get = {}
-pinnacle.window.v1.WindowService.Get.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.Get.method = "Get"
-pinnacle.window.v1.WindowService.Get.request = ".pinnacle.window.v1.GetRequest"
-pinnacle.window.v1.WindowService.Get.response = ".pinnacle.window.v1.GetResponse"
+pinnacle.signal.v1.SignalService.WindowLayoutModeChanged = {}
+pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.method = "WindowLayoutModeChanged"
+pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.request = ".pinnacle.signal.v1.WindowLayoutModeChangedRequest"
+pinnacle.signal.v1.SignalService.WindowLayoutModeChanged.response = ".pinnacle.signal.v1.WindowLayoutModeChangedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetRequest
+---@param callback fun(response: pinnacle.signal.v1.WindowLayoutModeChangedResponse, stream: grpc_client.h2.Stream)
 ---
----@return pinnacle.window.v1.GetResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_Get(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.Get, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_WindowLayoutModeChanged(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowLayoutModeChanged, callback)
 end
-pinnacle.window.v1.WindowService.GetAppId = {}
-pinnacle.window.v1.WindowService.GetAppId.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetAppId.method = "GetAppId"
-pinnacle.window.v1.WindowService.GetAppId.request = ".pinnacle.window.v1.GetAppIdRequest"
-pinnacle.window.v1.WindowService.GetAppId.response = ".pinnacle.window.v1.GetAppIdResponse"
+pinnacle.signal.v1.SignalService.WindowGeometryChanged = {}
+pinnacle.signal.v1.SignalService.WindowGeometryChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.WindowGeometryChanged.method = "WindowGeometryChanged"
+pinnacle.signal.v1.SignalService.WindowGeometryChanged.request = ".pinnacle.signal.v1.WindowGeometryChangedRequest"
+pinnacle.signal.v1.SignalService.WindowGeometryChanged.response = ".pinnacle.signal.v1.WindowGeometryChangedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetAppIdRequest
+---@param callback fun(response: pinnacle.signal.v1.WindowGeometryChangedResponse, stream: grpc_client.h2.Stream)
 ---
----@return pinnacle.window.v1.GetAppIdResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetAppId(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetAppId, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_WindowGeometryChanged(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.WindowGeometryChanged, callback)
 end
-pinnacle.window.v1.WindowService.GetTitle = {}
-pinnacle.window.v1.WindowService.GetTitle.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetTitle.method = "GetTitle"
-pinnacle.window.v1.WindowService.GetTitle.request = ".pinnacle.window.v1.GetTitleRequest"
-pinnacle.window.v1.WindowService.GetTitle.response = ".pinnacle.window.v1.GetTitleResponse"
+pinnacle.signal.v1.SignalService.TagActive = {}
+pinnacle.signal.v1.SignalService.TagActive.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.TagActive.method = "TagActive"
+pinnacle.signal.v1.SignalService.TagActive.request = ".pinnacle.signal.v1.TagActiveRequest"
+pinnacle.signal.v1.SignalService.TagActive.response = ".pinnacle.signal.v1.TagActiveResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetTitleRequest
+---@param callback fun(response: pinnacle.signal.v1.TagActiveResponse, stream: grpc_client.h2.Stream)
 ---
----@return pinnacle.window.v1.GetTitleResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetTitle(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetTitle, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_TagActive(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.TagActive, callback)
 end
-pinnacle.window.v1.WindowService.GetLoc = {}
-pinnacle.window.v1.WindowService.GetLoc.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetLoc.method = "GetLoc"
-pinnacle.window.v1.WindowService.GetLoc.request = ".pinnacle.window.v1.GetLocRequest"
-pinnacle.window.v1.WindowService.GetLoc.response = ".pinnacle.window.v1.GetLocResponse"
+pinnacle.signal.v1.SignalService.TagWindowCountChanged = {}
+pinnacle.signal.v1.SignalService.TagWindowCountChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.TagWindowCountChanged.method = "TagWindowCountChanged"
+pinnacle.signal.v1.SignalService.TagWindowCountChanged.request = ".pinnacle.signal.v1.TagWindowCountChangedRequest"
+pinnacle.signal.v1.SignalService.TagWindowCountChanged.response = ".pinnacle.signal.v1.TagWindowCountChangedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetLocRequest
+---@param callback fun(response: pinnacle.signal.v1.TagWindowCountChangedResponse, stream: grpc_client.h2.Stream)
 ---
----@return pinnacle.window.v1.GetLocResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetLoc(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetLoc, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_TagWindowCountChanged(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.TagWindowCountChanged, callback)
 end
-pinnacle.window.v1.WindowService.GetSize = {}
-pinnacle.window.v1.WindowService.GetSize.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetSize.method = "GetSize"
-pinnacle.window.v1.WindowService.GetSize.request = ".pinnacle.window.v1.GetSizeRequest"
-pinnacle.window.v1.WindowService.GetSize.response = ".pinnacle.window.v1.GetSizeResponse"
+pinnacle.signal.v1.SignalService.InputDeviceAdded = {}
+pinnacle.signal.v1.SignalService.InputDeviceAdded.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.InputDeviceAdded.method = "InputDeviceAdded"
+pinnacle.signal.v1.SignalService.InputDeviceAdded.request = ".pinnacle.signal.v1.InputDeviceAddedRequest"
+pinnacle.signal.v1.SignalService.InputDeviceAdded.response = ".pinnacle.signal.v1.InputDeviceAddedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetSizeRequest
+---@param callback fun(response: pinnacle.signal.v1.InputDeviceAddedResponse, stream: grpc_client.h2.Stream)
 ---
----@return pinnacle.window.v1.GetSizeResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetSize(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetSize, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_InputDeviceAdded(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.InputDeviceAdded, callback)
 end
-pinnacle.window.v1.WindowService.GetFocused = {}
-pinnacle.window.v1.WindowService.GetFocused.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetFocused.method = "GetFocused"
-pinnacle.window.v1.WindowService.GetFocused.request = ".pinnacle.window.v1.GetFocusedRequest"
-pinnacle.window.v1.WindowService.GetFocused.response = ".pinnacle.window.v1.GetFocusedResponse"
+pinnacle.signal.v1.SignalService.PinnaclePrepareForSleep = {}
+pinnacle.signal.v1.SignalService.PinnaclePrepareForSleep.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.PinnaclePrepareForSleep.method = "PinnaclePrepareForSleep"
+pinnacle.signal.v1.SignalService.PinnaclePrepareForSleep.request = ".pinnacle.signal.v1.PinnaclePrepareForSleepRequest"
+pinnacle.signal.v1.SignalService.PinnaclePrepareForSleep.response = ".pinnacle.signal.v1.PinnaclePrepareForSleepResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetFocusedRequest
+---@param callback fun(response: pinnacle.signal.v1.PinnaclePrepareForSleepResponse, stream: grpc_client.h2.Stream)
 ---
----@return pinnacle.window.v1.GetFocusedResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetFocused(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetFocused, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_PinnaclePrepareForSleep(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.PinnaclePrepareForSleep, callback)
 end
-pinnacle.window.v1.WindowService.GetLayoutMode = {}
-pinnacle.window.v1.WindowService.GetLayoutMode.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetLayoutMode.method = "GetLayoutMode"
-pinnacle.window.v1.WindowService.GetLayoutMode.request = ".pinnacle.window.v1.GetLayoutModeRequest"
-pinnacle.window.v1.WindowService.GetLayoutMode.response = ".pinnacle.window.v1.GetLayoutModeResponse"
+pinnacle.signal.v1.SignalService.PinnacleOutputLayoutChanged = {}
+pinnacle.signal.v1.SignalService.PinnacleOutputLayoutChanged.service = "pinnacle.signal.v1.SignalService"
+pinnacle.signal.v1.SignalService.PinnacleOutputLayoutChanged.method = "PinnacleOutputLayoutChanged"
+pinnacle.signal.v1.SignalService.PinnacleOutputLayoutChanged.request = ".pinnacle.signal.v1.PinnacleOutputLayoutChangedRequest"
+pinnacle.signal.v1.SignalService.PinnacleOutputLayoutChanged.response = ".pinnacle.signal.v1.PinnacleOutputLayoutChangedResponse"
 
----Performs a unary request.
+---Performs a bidirectional-streaming request.
+---
+---`callback` will be called with every streamed response.
+---
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetLayoutModeRequest
+---@param callback fun(response: pinnacle.signal.v1.PinnacleOutputLayoutChangedResponse, stream: grpc_client.h2.Stream)
 ---
----@return pinnacle.window.v1.GetLayoutModeResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetLayoutMode(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetLayoutMode, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_signal_v1_SignalService_PinnacleOutputLayoutChanged(callback)
+    return self:bidirectional_streaming_request(pinnacle.signal.v1.SignalService.PinnacleOutputLayoutChanged, callback)
 end
-pinnacle.window.v1.WindowService.GetTagIds = {}
-pinnacle.window.v1.WindowService.GetTagIds.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetTagIds.method = "GetTagIds"
-pinnacle.window.v1.WindowService.GetTagIds.request = ".pinnacle.window.v1.GetTagIdsRequest"
-pinnacle.window.v1.WindowService.GetTagIds.response = ".pinnacle.window.v1.GetTagIdsResponse"
+pinnacle.tag.v1.TagService = {}
+pinnacle.tag.v1.TagService.Get = {}
+pinnacle.tag.v1.TagService.Get.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.Get.method = "Get"
+pinnacle.tag.v1.TagService.Get.request = ".pinnacle.tag.v1.GetRequest"
+pinnacle.tag.v1.TagService.Get.response = ".pinnacle.tag.v1.GetResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetTagIdsRequest
+---@param data pinnacle.tag.v1.GetRequest
 ---
----@return pinnacle.window.v1.GetTagIdsResponse | nil response
+---@return pinnacle.tag.v1.GetResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetTagIds(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetTagIds, data)
+function Client:pinnacle_tag_v1_TagService_Get(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.Get, data)
 end
-pinnacle.window.v1.WindowService.GetWindowsInDir = {}
-pinnacle.window.v1.WindowService.GetWindowsInDir.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetWindowsInDir.method = "GetWindowsInDir"
-pinnacle.window.v1.WindowService.GetWindowsInDir.request = ".pinnacle.window.v1.GetWindowsInDirRequest"
-pinnacle.window.v1.WindowService.GetWindowsInDir.response = ".pinnacle.window.v1.GetWindowsInDirResponse"
+pinnacle.tag.v1.TagService.GetActive = {}
+pinnacle.tag.v1.TagService.GetActive.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.GetActive.method = "GetActive"
+pinnacle.tag.v1.TagService.GetActive.request = ".pinnacle.tag.v1.GetActiveRequest"
+pinnacle.tag.v1.TagService.GetActive.response = ".pinnacle.tag.v1.GetActiveResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetWindowsInDirRequest
+---@param data pinnacle.tag.v1.GetActiveRequest
 ---
----@return pinnacle.window.v1.GetWindowsInDirResponse | nil response
+---@return pinnacle.tag.v1.GetActiveResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetWindowsInDir(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetWindowsInDir, data)
+function Client:pinnacle_tag_v1_TagService_GetActive(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.GetActive, data)
 end
-pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier = {}
-pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.method = "GetForeignToplevelListIdentifier"
-pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.request = ".pinnacle.window.v1.GetForeignToplevelListIdentifierRequest"
-pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier.response = ".pinnacle.window.v1.GetForeignToplevelListIdentifierResponse"
+pinnacle.tag.v1.TagService.GetName = {}
+pinnacle.tag.v1.TagService.GetName.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.GetName.method = "GetName"
+pinnacle.tag.v1.TagService.GetName.request = ".pinnacle.tag.v1.GetNameRequest"
+pinnacle.tag.v1.TagService.GetName.response = ".pinnacle.tag.v1.GetNameResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.GetForeignToplevelListIdentifierRequest
+---@param data pinnacle.tag.v1.GetNameRequest
 ---
----@return pinnacle.window.v1.GetForeignToplevelListIdentifierResponse | nil response
+---@return pinnacle.tag.v1.GetNameResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_GetForeignToplevelListIdentifier(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.GetForeignToplevelListIdentifier, data)
+function Client:pinnacle_tag_v1_TagService_GetName(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.GetName, data)
 end
-pinnacle.window.v1.WindowService.Close = {}
-pinnacle.window.v1.WindowService.Close.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.Close.method = "Close"
-pinnacle.window.v1.WindowService.Close.request = ".pinnacle.window.v1.CloseRequest"
-pinnacle.window.v1.WindowService.Close.response = ".google.protobuf.Empty"
+pinnacle.tag.v1.TagService.GetOutputName = {}
+pinnacle.tag.v1.TagService.GetOutputName.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.GetOutputName.method = "GetOutputName"
+pinnacle.tag.v1.TagService.GetOutputName.request = ".pinnacle.tag.v1.GetOutputNameRequest"
+pinnacle.tag.v1.TagService.GetOutputName.response = ".pinnacle.tag.v1.GetOutputNameResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.CloseRequest
+---@param data pinnacle.tag.v1.GetOutputNameRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.tag.v1.GetOutputNameResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_Close(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.Close, data)
+function Client:pinnacle_tag_v1_TagService_GetOutputName(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.GetOutputName, data)
 end
-pinnacle.window.v1.WindowService.SetGeometry = {}
-pinnacle.window.v1.WindowService.SetGeometry.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetGeometry.method = "SetGeometry"
-pinnacle.window.v1.WindowService.SetGeometry.request = ".pinnacle.window.v1.SetGeometryRequest"
-pinnacle.window.v1.WindowService.SetGeometry.response = ".google.protobuf.Empty"
+pinnacle.tag.v1.TagService.Add = {}
+pinnacle.tag.v1.TagService.Add.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.Add.method = "Add"
+pinnacle.tag.v1.TagService.Add.request = ".pinnacle.tag.v1.AddRequest"
+pinnacle.tag.v1.TagService.Add.response = ".pinnacle.tag.v1.AddResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetGeometryRequest
+---@param data pinnacle.tag.v1.AddRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.tag.v1.AddResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetGeometry(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetGeometry, data)
+function Client:pinnacle_tag_v1_TagService_Add(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.Add, data)
 end
-pinnacle.window.v1.WindowService.ResizeTile = {}
-pinnacle.window.v1.WindowService.ResizeTile.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.ResizeTile.method = "ResizeTile"
-pinnacle.window.v1.WindowService.ResizeTile.request = ".pinnacle.window.v1.ResizeTileRequest"
-pinnacle.window.v1.WindowService.ResizeTile.response = ".google.protobuf.Empty"
+pinnacle.tag.v1.TagService.Remove = {}
+pinnacle.tag.v1.TagService.Remove.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.Remove.method = "Remove"
+pinnacle.tag.v1.TagService.Remove.request = ".pinnacle.tag.v1.RemoveRequest"
+pinnacle.tag.v1.TagService.Remove.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.ResizeTileRequest
+---@param data pinnacle.tag.v1.RemoveRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_ResizeTile(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.ResizeTile, data)
+function Client:pinnacle_tag_v1_TagService_Remove(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.Remove, data)
 end
-pinnacle.window.v1.WindowService.SetFullscreen = {}
-pinnacle.window.v1.WindowService.SetFullscreen.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetFullscreen.method = "SetFullscreen"
-pinnacle.window.v1.WindowService.SetFullscreen.request = ".pinnacle.window.v1.SetFullscreenRequest"
-pinnacle.window.v1.WindowService.SetFullscreen.response = ".google.protobuf.Empty"
+pinnacle.tag.v1.TagService.SetActive = {}
+pinnacle.tag.v1.TagService.SetActive.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.SetActive.method = "SetActive"
+pinnacle.tag.v1.TagService.SetActive.request = ".pinnacle.tag.v1.SetActiveRequest"
+pinnacle.tag.v1.TagService.SetActive.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetFullscreenRequest
+---@param data pinnacle.tag.v1.SetActiveRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetFullscreen(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetFullscreen, data)
+function Client:pinnacle_tag_v1_TagService_SetActive(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.SetActive, data)
 end
-pinnacle.window.v1.WindowService.SetMaximized = {}
-pinnacle.window.v1.WindowService.SetMaximized.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetMaximized.method = "SetMaximized"
-pinnacle.window.v1.WindowService.SetMaximized.request = ".pinnacle.window.v1.SetMaximizedRequest"
-pinnacle.window.v1.WindowService.SetMaximized.response = ".google.protobuf.Empty"
+pinnacle.tag.v1.TagService.SwitchTo = {}
+pinnacle.tag.v1.TagService.SwitchTo.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.SwitchTo.method = "SwitchTo"
+pinnacle.tag.v1.TagService.SwitchTo.request = ".pinnacle.tag.v1.SwitchToRequest"
+pinnacle.tag.v1.TagService.SwitchTo.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetMaximizedRequest
+---@param data pinnacle.tag.v1.SwitchToRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetMaximized(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetMaximized, data)
+function Client:pinnacle_tag_v1_TagService_SwitchTo(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.SwitchTo, data)
 end
-pinnacle.window.v1.WindowService.SetFloating = {}
-pinnacle.window.v1.WindowService.SetFloating.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetFloating.method = "SetFloating"
-pinnacle.window.v1.WindowService.SetFloating.request = ".pinnacle.window.v1.SetFloatingRequest"
-pinnacle.window.v1.WindowService.SetFloating.response = ".google.protobuf.Empty"
+pinnacle.tag.v1.TagService.MoveToOutput = {}
+pinnacle.tag.v1.TagService.MoveToOutput.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.MoveToOutput.method = "MoveToOutput"
+pinnacle.tag.v1.TagService.MoveToOutput.request = ".pinnacle.tag.v1.MoveToOutputRequest"
+pinnacle.tag.v1.TagService.MoveToOutput.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetFloatingRequest
+---@param data pinnacle.tag.v1.MoveToOutputRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetFloating(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetFloating, data)
+function Client:pinnacle_tag_v1_TagService_MoveToOutput(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.MoveToOutput, data)
 end
-pinnacle.window.v1.WindowService.SetFocused = {}
-pinnacle.window.v1.WindowService.SetFocused.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetFocused.method = "SetFocused"
-pinnacle.window.v1.WindowService.SetFocused.request = ".pinnacle.window.v1.SetFocusedRequest"
-pinnacle.window.v1.WindowService.SetFocused.response = ".google.protobuf.Empty"
+pinnacle.tag.v1.TagService.SetOutput = {}
+pinnacle.tag.v1.TagService.SetOutput.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.SetOutput.method = "SetOutput"
+pinnacle.tag.v1.TagService.SetOutput.request = ".pinnacle.tag.v1.SetOutputRequest"
+pinnacle.tag.v1.TagService.SetOutput.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetFocusedRequest
+---@param data pinnacle.tag.v1.SetOutputRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetFocused(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetFocused, data)
+function Client:pinnacle_tag_v1_TagService_SetOutput(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.SetOutput, data)
 end
-pinnacle.window.v1.WindowService.SetDecorationMode = {}
-pinnacle.window.v1.WindowService.SetDecorationMode.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetDecorationMode.method = "SetDecorationMode"
-pinnacle.window.v1.WindowService.SetDecorationMode.request = ".pinnacle.window.v1.SetDecorationModeRequest"
-pinnacle.window.v1.WindowService.SetDecorationMode.response = ".google.protobuf.Empty"
+pinnacle.tag.v1.TagService.Swap = {}
+pinnacle.tag.v1.TagService.Swap.service = "pinnacle.tag.v1.TagService"
+pinnacle.tag.v1.TagService.Swap.method = "Swap"
+pinnacle.tag.v1.TagService.Swap.request = ".pinnacle.tag.v1.SwapRequest"
+pinnacle.tag.v1.TagService.Swap.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetDecorationModeRequest
+---@param data pinnacle.tag.v1.SwapRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetDecorationMode(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetDecorationMode, data)
+function Client:pinnacle_tag_v1_TagService_Swap(data)
+    return self:unary_request(pinnacle.tag.v1.TagService.Swap, data)
 end
-pinnacle.window.v1.WindowService.MoveToTag = {}
-pinnacle.window.v1.WindowService.MoveToTag.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.MoveToTag.method = "MoveToTag"
-pinnacle.window.v1.WindowService.MoveToTag.request = ".pinnacle.window.v1.MoveToTagRequest"
-pinnacle.window.v1.WindowService.MoveToTag.response = ".google.protobuf.Empty"
+pinnacle.v1.PinnacleService = {}
+pinnacle.v1.PinnacleService.Quit = {}
+pinnacle.v1.PinnacleService.Quit.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.Quit.method = "Quit"
+pinnacle.v1.PinnacleService.Quit.request = ".pinnacle.v1.QuitRequest"
+pinnacle.v1.PinnacleService.Quit.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.MoveToTagRequest
+---@param data pinnacle.v1.QuitRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_MoveToTag(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.MoveToTag, data)
+function Client:pinnacle_v1_PinnacleService_Quit(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.Quit, data)
 end
-pinnacle.window.v1.WindowService.SetTag = {}
-pinnacle.window.v1.WindowService.SetTag.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetTag.method = "SetTag"
-pinnacle.window.v1.WindowService.SetTag.request = ".pinnacle.window.v1.SetTagRequest"
-pinnacle.window.v1.WindowService.SetTag.response = ".google.protobuf.Empty"
+pinnacle.v1.PinnacleService.ReloadConfig = {}
+pinnacle.v1.PinnacleService.ReloadConfig.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.ReloadConfig.method = "ReloadConfig"
+pinnacle.v1.PinnacleService.ReloadConfig.request = ".pinnacle.v1.ReloadConfigRequest"
+pinnacle.v1.PinnacleService.ReloadConfig.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetTagRequest
+---@param data pinnacle.v1.ReloadConfigRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetTag(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetTag, data)
+function Client:pinnacle_v1_PinnacleService_ReloadConfig(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.ReloadConfig, data)
 end
-pinnacle.window.v1.WindowService.SetTags = {}
-pinnacle.window.v1.WindowService.SetTags.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetTags.method = "SetTags"
-pinnacle.window.v1.WindowService.SetTags.request = ".pinnacle.window.v1.SetTagsRequest"
-pinnacle.window.v1.WindowService.SetTags.response = ".pinnacle.window.v1.SetTagsResponse"
+pinnacle.v1.PinnacleService.Keepalive = {}
+pinnacle.v1.PinnacleService.Keepalive.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.Keepalive.method = "Keepalive"
+pinnacle.v1.PinnacleService.Keepalive.request = ".pinnacle.v1.KeepaliveRequest"
+pinnacle.v1.PinnacleService.Keepalive.response = ".pinnacle.v1.KeepaliveResponse"
 
----Performs a unary request.
----
----@nodiscard
+---Performs a bidirectional-streaming request.
 ---
----@param data pinnacle.window.v1.SetTagsRequest
+---`callback` will be called with every streamed response.
 ---
----@return pinnacle.window.v1.SetTagsResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetTags(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetTags, data)
-end
-pinnacle.window.v1.WindowService.SetVrrDemand = {}
-pinnacle.window.v1.WindowService.SetVrrDemand.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.SetVrrDemand.method = "SetVrrDemand"
-pinnacle.window.v1.WindowService.SetVrrDemand.request = ".pinnacle.window.v1.SetVrrDemandRequest"
-pinnacle.window.v1.WindowService.SetVrrDemand.response = ".pinnacle.window.v1.SetVrrDemandResponse"
-
----Performs a unary request.
+---The raw client-to-server stream is returned to allow you to send encoded messages.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SetVrrDemandRequest
+---@param callback fun(response: pinnacle.v1.KeepaliveResponse, stream: grpc_client.h2.Stream)
 ---
----@return pinnacle.window.v1.SetVrrDemandResponse | nil response
----@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_SetVrrDemand(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.SetVrrDemand, data)
+---@return grpc_client.h2.Stream | nil
+---@return string | nil An error string, if any
+function Client:pinnacle_v1_PinnacleService_Keepalive(callback)
+    return self:bidirectional_streaming_request(pinnacle.v1.PinnacleService.Keepalive, callback)
 end
-pinnacle.window.v1.WindowService.MoveToOutput = {}
-pinnacle.window.v1.WindowService.MoveToOutput.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.MoveToOutput.method = "MoveToOutput"
-pinnacle.window.v1.WindowService.MoveToOutput.request = ".pinnacle.window.v1.MoveToOutputRequest"
-pinnacle.window.v1.WindowService.MoveToOutput.response = ".pinnacle.window.v1.MoveToOutputResponse"
+pinnacle.v1.PinnacleService.Backend = {}
+pinnacle.v1.PinnacleService.Backend.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.Backend.method = "Backend"
+pinnacle.v1.PinnacleService.Backend.request = ".pinnacle.v1.BackendRequest"
+pinnacle.v1.PinnacleService.Backend.response = ".pinnacle.v1.BackendResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.MoveToOutputRequest
+---@param data pinnacle.v1.BackendRequest
 ---
----@return pinnacle.window.v1.MoveToOutputResponse | nil response
+---@return pinnacle.v1.BackendResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_MoveToOutput(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.MoveToOutput, data)
+function Client:pinnacle_v1_PinnacleService_Backend(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.Backend, data)
 end
-pinnacle.window.v1.WindowService.Raise = {}
-pinnacle.window.v1.WindowService.Raise.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.Raise.method = "Raise"
-pinnacle.window.v1.WindowService.Raise.request = ".pinnacle.window.v1.RaiseRequest"
-pinnacle.window.v1.WindowService.Raise.response = ".google.protobuf.Empty"
+pinnacle.v1.PinnacleService.RendererInfo = {}
+pinnacle.v1.PinnacleService.RendererInfo.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.RendererInfo.method = "RendererInfo"
+pinnacle.v1.PinnacleService.RendererInfo.request = ".pinnacle.v1.RendererInfoRequest"
+pinnacle.v1.PinnacleService.RendererInfo.response = ".pinnacle.v1.RendererInfoResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.RaiseRequest
+---@param data pinnacle.v1.RendererInfoRequest
 ---
----@return google.protobuf.Empty | nil response
+---@return pinnacle.v1.RendererInfoResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_Raise(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.Raise, data)
+function Client:pinnacle_v1_PinnacleService_RendererInfo(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.RendererInfo, data)
 end
-pinnacle.window.v1.WindowService.Lower = {}
-pinnacle.window.v1.WindowService.Lower.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.Lower.method = "Lower"
-pinnacle.window.v1.WindowService.Lower.request = ".pinnacle.window.v1.LowerRequest"
-pinnacle.window.v1.WindowService.Lower.response = ".pinnacle.window.v1.LowerResponse"
+pinnacle.v1.PinnacleService.SetXwaylandClientSelfScale = {}
+pinnacle.v1.PinnacleService.SetXwaylandClientSelfScale.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.SetXwaylandClientSelfScale.method = "SetXwaylandClientSelfScale"
+pinnacle.v1.PinnacleService.SetXwaylandClientSelfScale.request = ".pinnacle.v1.SetXwaylandClientSelfScaleRequest"
+pinnacle.v1.PinnacleService.SetXwaylandClientSelfScale.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.LowerRequest
+---@param data pinnacle.v1.SetXwaylandClientSelfScaleRequest
 ---
----@return pinnacle.window.v1.LowerResponse | nil response
+---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_Lower(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.Lower, data)
+function Client:pinnacle_v1_PinnacleService_SetXwaylandClientSelfScale(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.SetXwaylandClientSelfScale, data)
 end
-pinnacle.window.v1.WindowService.MoveGrab = {}
-pinnacle.window.v1.WindowService.MoveGrab.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.MoveGrab.method = "MoveGrab"
-pinnacle.window.v1.WindowService.MoveGrab.request = ".pinnacle.window.v1.MoveGrabRequest"
-pinnacle.window.v1.WindowService.MoveGrab.response = ".google.protobuf.Empty"
+pinnacle.v1.PinnacleService.SetPreserveLayoutOnReload = {}
+pinnacle.v1.PinnacleService.SetPreserveLayoutOnReload.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.SetPreserveLayoutOnReload.method = "SetPreserveLayoutOnReload"
+pinnacle.v1.PinnacleService.SetPreserveLayoutOnReload.request = ".pinnacle.v1.SetPreserveLayoutOnReloadRequest"
+pinnacle.v1.PinnacleService.SetPreserveLayoutOnReload.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.MoveGrabRequest
+---@param data pinnacle.v1.SetPreserveLayoutOnReloadRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_MoveGrab(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.MoveGrab, data)
+function Client:pinnacle_v1_PinnacleService_SetPreserveLayoutOnReload(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.SetPreserveLayoutOnReload, data)
 end
-pinnacle.window.v1.WindowService.ResizeGrab = {}
-pinnacle.window.v1.WindowService.ResizeGrab.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.ResizeGrab.method = "ResizeGrab"
-pinnacle.window.v1.WindowService.ResizeGrab.request = ".pinnacle.window.v1.ResizeGrabRequest"
-pinnacle.window.v1.WindowService.ResizeGrab.response = ".google.protobuf.Empty"
+pinnacle.v1.PinnacleService.SetLastError = {}
+pinnacle.v1.PinnacleService.SetLastError.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.SetLastError.method = "SetLastError"
+pinnacle.v1.PinnacleService.SetLastError.request = ".pinnacle.v1.SetLastErrorRequest"
+pinnacle.v1.PinnacleService.SetLastError.response = ".google.protobuf.Empty"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.ResizeGrabRequest
+---@param data pinnacle.v1.SetLastErrorRequest
 ---
 ---@return google.protobuf.Empty | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_ResizeGrab(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.ResizeGrab, data)
+function Client:pinnacle_v1_PinnacleService_SetLastError(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.SetLastError, data)
 end
-pinnacle.window.v1.WindowService.Swap = {}
-pinnacle.window.v1.WindowService.Swap.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.Swap.method = "Swap"
-pinnacle.window.v1.WindowService.Swap.request = ".pinnacle.window.v1.SwapRequest"
-pinnacle.window.v1.WindowService.Swap.response = ".pinnacle.window.v1.SwapResponse"
+pinnacle.v1.PinnacleService.TakeLastError = {}
+pinnacle.v1.PinnacleService.TakeLastError.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.TakeLastError.method = "TakeLastError"
+pinnacle.v1.PinnacleService.TakeLastError.request = ".pinnacle.v1.TakeLastErrorRequest"
+pinnacle.v1.PinnacleService.TakeLastError.response = ".pinnacle.v1.TakeLastErrorResponse"
 
 ---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param data pinnacle.window.v1.SwapRequest
+---@param data pinnacle.v1.TakeLastErrorRequest
 ---
----@return pinnacle.window.v1.SwapResponse | nil response
+---@return pinnacle.v1.TakeLastErrorResponse | nil response
 ---@return string | nil error An error string, if any
-function Client:pinnacle_window_v1_WindowService_Swap(data)
-    return self:unary_request(pinnacle.window.v1.WindowService.Swap, data)
+function Client:pinnacle_v1_PinnacleService_TakeLastError(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.TakeLastError, data)
 end
-pinnacle.window.v1.WindowService.WindowRule = {}
-pinnacle.window.v1.WindowService.WindowRule.service = "pinnacle.window.v1.WindowService"
-pinnacle.window.v1.WindowService.WindowRule.method = "WindowRule"
-pinnacle.window.v1.WindowService.WindowRule.request = ".pinnacle.window.v1.WindowRuleRequest"
-pinnacle.window.v1.WindowService.WindowRule.response = ".pinnacle.window.v1.WindowRuleResponse"
+pinnacle.v1.PinnacleService.Metrics = {}
+pinnacle.v1.PinnacleService.Metrics.service = "pinnacle.v1.PinnacleService"
+pinnacle.v1.PinnacleService.Metrics.method = "Metrics"
+pinnacle.v1.PinnacleService.Metrics.request = ".pinnacle.v1.MetricsRequest"
+pinnacle.v1.PinnacleService.Metrics.response = ".pinnacle.v1.MetricsResponse"
 
----Performs a bidirectional-streaming request.
----
----`callback` will be called with every streamed response.
----
----The raw client-to-server stream is returned to allow you to send encoded messages.
+---Performs a unary request.
 ---
 ---@nodiscard
 ---
----@param callback fun(response: pinnacle.window.v1.WindowRuleResponse, stream: grpc_client.h2.Stream)
+---@param data pinnacle.v1.MetricsRequest
 ---
----@return grpc_client.h2.Stream | nil
----@return string | nil An error string, if any
-function Client:pinnacle_window_v1_WindowService_WindowRule(callback)
-    return self:bidirectional_streaming_request(pinnacle.window.v1.WindowService.WindowRule, callback)
+---@return pinnacle.v1.MetricsResponse | nil response
+---@return string | nil error An error string, if any
+function Client:pinnacle_v1_PinnacleService_Metrics(data)
+    return self:unary_request(pinnacle.v1.PinnacleService.Metrics, data)
 end
 return {
     google = google,
@@ -141,7 +141,10 @@ input.mouse_button_values = mouse_button_values
 ---An action that is run when the keybind is pressed.
 ---@field on_press fun()?
 ---An action that is run when the keybind is released.
+---If `on_press` ran, this always runs on release, even if focus changed while the key was held.
 ---@field on_release fun()?
+---Runs `on_press` repeatedly at the keyboard repeat rate while the key is held.
+---@field repeating boolean?
 
 ---@param kb pinnacle.input.Keybind
 local function keybind_inner(kb)
@@ -175,6 +178,7 @@ local function keybind_inner(kb)
                 quit = kb.quit,
                 reload_config = kb.reload_config,
                 allow_when_locked = kb.allow_when_locked,
+                ["repeat"] = kb.repeating,
             },
             key = {
                 key_code = key_code,
@@ -296,6 +300,9 @@ end
 ---@field on_press fun()?
 ---An action that will be run when the mousebind is released.
 ---@field on_release fun()?
+---An action that will be run when the mousebind is pressed twice in quick succession.
+---This runs on the second press, after `on_press`. See `Input.set_double_click_time`.
+---@field on_double_click fun()?
 
 ---@param mb pinnacle.input.Mousebind
 local function mousebind_inner(mb)
@@ -346,6 +353,12 @@ local function mousebind_inner(mb)
                     log.error("While handling `Mousebind:on_press`: " .. tostring(error))
                 end
             end
+            if response.double_click and mb.on_double_click then
+                local success, error = pcall(mb.on_double_click)
+                if not success then
+                    log.error("While handling `Mousebind:on_double_click`: " .. tostring(error))
+                end
+            end
         elseif response.edge == edge_values.release then
             if mb.on_release then
                 local success, error = pcall(mb.on_release)
@@ -356,7 +369,7 @@ local function mousebind_inner(mb)
         end
     end)
 
-    if mb.on_press then
+    if mb.on_press or mb.on_double_click then
         local _, err = client:pinnacle_input_v1_InputService_MousebindOnPress({
             bind_id = bind_id,
         })
@@ -424,6 +437,81 @@ function input.mousebind(mods, button, on_press, bind_info)
     mousebind_inner(mb)
 end
 
+---@enum (key) pinnacle.input.SwipeDirection
+local swipe_direction_values = {
+    up = input_v1.Gesture.GESTURE_SWIPE_UP,
+    down = input_v1.Gesture.GESTURE_SWIPE_DOWN,
+    left = input_v1.Gesture.GESTURE_SWIPE_LEFT,
+    right = input_v1.Gesture.GESTURE_SWIPE_RIGHT,
+}
+
+---Runs a function whenever a touchpad swipe with the given number of fingers
+---is performed in the given direction.
+---
+---The function runs once the fingers are lifted. Swipes with a bound finger count
+---aren't sent to clients.
+---
+---#### Example
+---```lua
+--- -- Switch to the next tag with a three-finger swipe
+---Input.on_swipe(3, "left", function()
+---    -- View the next tag
+---end)
+---```
+---
+---@param fingers integer
+---@param direction pinnacle.input.SwipeDirection
+---@param on_swipe fun()
+function input.on_swipe(fingers, direction, on_swipe)
+    local err = client:pinnacle_input_v1_InputService_GestureBindStream({
+        fingers = fingers,
+        gesture = swipe_direction_values[direction],
+    }, function(_)
+        local success, error = pcall(on_swipe)
+        if not success then
+            log.error("While handling 'Input.on_swipe': " .. tostring(error))
+        end
+    end)
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Runs a function whenever a touchpad pinch with the given number of fingers
+---is performed.
+---
+---The function runs once the fingers are lifted and receives the scale the fingers
+---ended at relative to where they started. A scale below 1.0 is a pinch in and
+---above 1.0 is a pinch out. Pinches with a bound finger count aren't sent to clients.
+---
+---#### Example
+---```lua
+---Input.on_pinch(4, function(scale)
+---    if scale < 1.0 then
+---        -- Show an overview
+---    end
+---end)
+---```
+---
+---@param fingers integer
+---@param on_pinch fun(scale: number)
+function input.on_pinch(fingers, on_pinch)
+    local err = client:pinnacle_input_v1_InputService_GestureBindStream({
+        fingers = fingers,
+        gesture = input_v1.Gesture.GESTURE_PINCH,
+    }, function(response)
+        local success, error = pcall(on_pinch, response.scale or 1.0)
+        if not success then
+            log.error("While handling 'Input.on_pinch': " .. tostring(error))
+        end
+    end)
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Enters the bind layer `layer`, or the default layer if `layer` is nil.
 ---
 ---@param layer string? The bind layer.
@@ -433,6 +521,53 @@ function input.enter_bind_layer(layer)
     })
 end
 
+---Exits the current bind layer, returning to the one entered before it.
+---
+---Exiting the last entered layer returns to the default layer.
+---
+---#### Example
+---```lua
+---Input.keybind({
+---    mods = {},
+---    key = "Escape",
+---    bind_layer = "resize",
+---    on_press = Input.exit_bind_layer,
+---})
+---```
+function input.exit_bind_layer()
+    local _, err = client:pinnacle_input_v1_InputService_ExitBindLayer({})
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether binds on the layers below `layer` stay in effect while it's active.
+---
+---By default, entering a layer suppresses the binds of every other layer.
+---With passthrough, binds on the layer entered before this one also fire,
+---down to the default layer if every layer in between passes through as well.
+---If both layers have the same bind, only `layer`'s fires.
+---
+---#### Example
+---```lua
+----- Keep the default binds working while in resize mode
+---Input.set_bind_layer_passthrough("resize", true)
+---```
+---
+---@param layer string The bind layer.
+---@param passthrough boolean
+function input.set_bind_layer_passthrough(layer, passthrough)
+    local _, err = client:pinnacle_input_v1_InputService_SetBindLayerPassthrough({
+        layer_name = layer,
+        passthrough = passthrough,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Bind information.
 ---
 ---Mainly used for the bind overlay.
@@ -453,6 +588,8 @@ end
 ---@field reload_config boolean
 ---Whether this bind is allowed when the session is locked.
 ---@field allow_when_locked boolean
+---Whether this bind repeats while held. Always `false` for mousebinds.
+---@field repeating boolean
 ---What kind of bind this is.
 ---@field kind pinnacle.input.BindInfoKind
 
@@ -518,6 +655,7 @@ function input.bind_infos()
         local quit = info.properties.quit or false
         local reload_config = info.properties.reload_config or false
         local allow_when_locked = info.properties.allow_when_locked or false
+        local repeating = info.properties["repeat"] or false
 
         ---@type pinnacle.input.BindInfo
         local bind_info = {
@@ -529,6 +667,7 @@ function input.bind_infos()
             quit = quit,
             reload_config = reload_config,
             allow_when_locked = allow_when_locked,
+            repeating = repeating,
             kind = bind_kind,
         }
 
@@ -602,6 +741,75 @@ function input.set_repeat_rate(rate, delay)
     end
 end
 
+---Sets whether sticky keys are enabled.
+---
+---With sticky keys, modifiers can be pressed one after another instead of held.
+---Tapping a modifier latches it for the next key, so tapping Ctrl then C acts
+---like Ctrl+C. Tapping a modifier twice locks it until it's tapped again.
+---
+---#### Example
+---```lua
+---Input.set_sticky_keys(true)
+---```
+---
+---@param enabled boolean
+function input.set_sticky_keys(enabled)
+    local _, err = client:pinnacle_input_v1_InputService_SetStickyKeys({
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether the compose key types characters from key sequences.
+---
+---With compose on, pressing the compose key followed by, for example, `'` and `e`
+---types `é`. Sequences come from the compose table for the locale Pinnacle was started in.
+---
+---Your keymap needs a compose key for this, such as one set with the `compose:ralt`
+---xkb option.
+---
+---#### Example
+---```lua
+---Input.set_xkb_config({ options = "compose:ralt" })
+---Input.set_compose_enabled(true)
+---```
+---
+---@param enabled boolean
+function input.set_compose_enabled(enabled)
+    local _, err = client:pinnacle_input_v1_InputService_SetComposeEnabled({
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Turns NumLock on or off.
+---
+---Call this in your config to set NumLock's state on startup.
+---You can also set `numlock_on_start` in the `[input]` table of `pinnacle.toml`
+---to turn it on before the config starts.
+---
+---#### Example
+---```lua
+---Input.set_numlock(true)
+---```
+---
+---@param enabled boolean
+function input.set_numlock(enabled)
+    local _, err = client:pinnacle_input_v1_InputService_SetNumlock({
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Sets the XKB keymap.
 ---
 ---#### Examples
@@ -692,6 +900,562 @@ function input.set_xcursor_size(size)
     end
 end
 
+---Sets whether the cursor is drawn.
+---
+---This only hides the cursor; the pointer still moves and clicks as usual.
+---
+---#### Example
+---```lua
+---Input.set_cursor_visible(false)
+---```
+---
+---@param visible boolean
+function input.set_cursor_visible(visible)
+    local _, err = client:pinnacle_input_v1_InputService_SetCursorVisible({
+        visible = visible,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Hides the cursor after the pointer goes `hide_after_ms` milliseconds without moving.
+---
+---The cursor reappears as soon as the pointer moves.
+---
+---#### Example
+---```lua
+---Input.set_cursor_autohide(3000)
+---```
+---
+---@param hide_after_ms integer
+function input.set_cursor_autohide(hide_after_ms)
+    local _, err = client:pinnacle_input_v1_InputService_SetCursorAutohide({
+        hide_after_ms = hide_after_ms,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Disables cursor auto-hiding set with `Input.set_cursor_autohide`.
+function input.unset_cursor_autohide()
+    local _, err = client:pinnacle_input_v1_InputService_SetCursorAutohide({})
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Types text into the window with keyboard focus.
+---
+---Each character is mapped to a key in the current keyboard layout.
+---Characters that the layout can't type are skipped.
+---
+---This requires `allow_input_injection` to be set in your `pinnacle.toml`
+---or Pinnacle to be started with `--allow-input-injection`. Otherwise, nothing happens.
+---
+---#### Example
+---```lua
+---Input.keybind({ "super" }, "e", function()
+---    Input.type_string("me@example.com")
+---end)
+---```
+---
+---@param text string The text to type.
+function input.type_string(text)
+    local _, err = client:pinnacle_input_v1_InputService_TypeString({
+        text = text,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Presses the given keys in order then releases them in reverse order,
+---sending them to the window with keyboard focus.
+---
+---Keybinds are not triggered by the combo.
+---If any of the keys can't be typed with the current keyboard layout, nothing is sent.
+---
+---This requires `allow_input_injection` to be set in your `pinnacle.toml`
+---or Pinnacle to be started with `--allow-input-injection`. Otherwise, nothing happens.
+---
+---#### Example
+---```lua
+--- -- Send Ctrl+Shift+T
+---Input.send_combo({ Input.key.Control_L, Input.key.Shift_L, "t" })
+---```
+---
+---@param keys (pinnacle.input.Key | string)[] The keys to press, in the same format as keybinds.
+function input.send_combo(keys)
+    ---@type pinnacle.input.v1.Keybind[]
+    local key_msgs = {}
+
+    for _, key in ipairs(keys) do
+        if type(key) == "number" then
+            table.insert(key_msgs, { key_code = key })
+        else
+            table.insert(key_msgs, { xkb_name = key })
+        end
+    end
+
+    local _, err = client:pinnacle_input_v1_InputService_SendCombo({
+        keys = key_msgs,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Dims all outputs after `dim_after_ms` milliseconds without any input.
+---
+---`dim_level` is how dark outputs get, from 0.0 (no dimming) to 1.0 (black).
+---Outputs are undimmed on the next input. Outputs won't dim while idle is inhibited,
+---for example while a video is playing.
+---
+---Fails if `dim_level` is outside that range.
+---
+---#### Example
+---```lua
+--- -- Dim outputs to 50% after 5 minutes of inactivity
+---Input.set_idle_dim(300000, 0.5)
+---```
+---
+---@param dim_after_ms integer
+---@param dim_level number
+---
+---@return boolean success Whether idle dimming was set.
+function input.set_idle_dim(dim_after_ms, dim_level)
+    local _, err = client:pinnacle_input_v1_InputService_SetIdleDim({
+        dim_after_ms = dim_after_ms,
+        dim_level = dim_level,
+    })
+
+    if err then
+        log.error(err)
+        return false
+    end
+
+    return true
+end
+
+---Disables idle dimming set with `Input.set_idle_dim`.
+function input.unset_idle_dim()
+    local _, err = client:pinnacle_input_v1_InputService_SetIdleDim({
+        dim_level = 0.0,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Information about a layer-shell surface.
+---@class pinnacle.input.LayerInfo
+---The namespace the layer surface's client gave it.
+---@field namespace string
+---The output the layer surface is on.
+---@field output pinnacle.output.OutputHandle
+
+---What has pointer focus. Exactly one field is set.
+---@class pinnacle.input.PointerFocus
+---A window, including its popups and decorations.
+---@field window pinnacle.window.WindowHandle?
+---A layer-shell surface, like a bar or launcher.
+---@field layer pinnacle.input.LayerInfo?
+
+---Gets what currently has pointer focus.
+---
+---Returns `nil` if the pointer is over nothing, like an empty part of the desktop.
+---
+---#### Example
+---```lua
+---local focus = Input.pointer_focus()
+---if focus and focus.window then
+---    print("Hovering " .. focus.window:title())
+---end
+---```
+---
+---@return pinnacle.input.PointerFocus?
+function input.pointer_focus()
+    local response, err = client:pinnacle_input_v1_InputService_GetPointerFocus({})
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    assert(response)
+
+    if response.window_id then
+        return { window = require("pinnacle.window").handle.new(response.window_id) }
+    elseif response.layer then
+        return {
+            layer = {
+                namespace = response.layer.namespace or "",
+                output = require("pinnacle.output").handle.new(response.layer.output_name or ""),
+            },
+        }
+    end
+
+    return nil
+end
+
+---Gets the window that currently has keyboard focus.
+---
+---Returns `nil` if nothing or something other than a window, like a layer surface,
+---has keyboard focus.
+---
+---@return pinnacle.window.WindowHandle?
+function input.keyboard_focus()
+    local response, err = client:pinnacle_input_v1_InputService_GetKeyboardFocus({})
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    assert(response)
+
+    if not response.window_id then
+        return nil
+    end
+
+    return require("pinnacle.window").handle.new(response.window_id)
+end
+
+---Gets whether idle is inhibited.
+---
+---Idle is inhibited by visible surfaces using the idle-inhibit protocol
+---and by apps using the `org.freedesktop.ScreenSaver` DBus interface, like browsers
+---playing video.
+---
+---@return boolean
+function input.idle_inhibited()
+    local response, err = client:pinnacle_input_v1_InputService_GetIdleInhibited({})
+
+    if err then
+        log.error(err)
+        return false
+    end
+
+    assert(response)
+
+    return response.inhibited or false
+end
+
+---Sets the key that stops the focused window from grabbing all input.
+---
+---Pressing it while a window set with `WindowHandle:set_grab_all_input` is focused
+---unsets that window's grab, so keybinds work again. The key isn't sent to the window.
+---Pass `nil` to remove the escape.
+---
+---#### Example
+---```lua
+---Input.set_grab_all_input_escape({ "super" }, Input.key.Escape)
+---```
+---
+---@param mods pinnacle.input.Mod[]?
+---@param key (pinnacle.input.Key | string)?
+function input.set_grab_all_input_escape(mods, key)
+    local mod_msgs = {}
+    for _, mod in ipairs(mods or {}) do
+        table.insert(mod_msgs, modifier_values[mod])
+    end
+
+    local key_msg = nil
+    if key then
+        key_msg = type(key) == "number" and { key_code = key } or { xkb_name = key }
+    end
+
+    local _, err = client:pinnacle_input_v1_InputService_SetGrabAllInputEscape({
+        mods = mod_msgs,
+        key = key_msg,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---A mouse button remapping.
+---
+---Exactly one of `button` and `keybind` should be set.
+---@class pinnacle.input.ButtonMapping
+---@field from pinnacle.input.MouseButton The button being remapped.
+---@field button pinnacle.input.MouseButton? Act as this button instead.
+---@field keybind { mods: pinnacle.input.Mod[], key: pinnacle.input.Key | string }? Trigger keybinds as if this key was pressed instead. The button is not sent to clients.
+
+---Remaps mouse buttons.
+---
+---Buttons are remapped before anything else sees them, so mousebinds and clients
+---receive the remapped button. Each call replaces all previous mappings;
+---pass an empty table to remove them.
+---
+---#### Example
+---```lua
+---Input.set_mouse_button_map({
+---    -- Left-handed mouse
+---    { from = "btn_left", button = "btn_right" },
+---    { from = "btn_right", button = "btn_left" },
+---    -- Trigger the `Super + Tab` keybind with the side button
+---    { from = "btn_side", keybind = { mods = { "super" }, key = Input.key.Tab } },
+---})
+---```
+---
+---@param mappings pinnacle.input.ButtonMapping[]
+function input.set_mouse_button_map(mappings)
+    ---@type pinnacle.input.v1.ButtonMapping[]
+    local mapping_msgs = {}
+
+    for _, mapping in ipairs(mappings) do
+        ---@type pinnacle.input.v1.ButtonMapping
+        local msg = { from_button = mouse_button_values[mapping.from] }
+
+        if mapping.button then
+            msg.button = mouse_button_values[mapping.button]
+        elseif mapping.keybind then
+            local mods = {}
+            for _, mod in ipairs(mapping.keybind.mods) do
+                table.insert(mods, modifier_values[mod])
+            end
+
+            local key = mapping.keybind.key
+            msg.keybind = {
+                mods = mods,
+                key = type(key) == "number" and { key_code = key } or { xkb_name = key },
+            }
+        end
+
+        table.insert(mapping_msgs, msg)
+    end
+
+    local _, err = client:pinnacle_input_v1_InputService_SetMouseButtonMap({
+        mappings = mapping_msgs,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether middle clicking pastes the primary selection.
+---
+---The primary selection is the most recently selected text, which most apps
+---paste on middle click. Disable this to avoid accidental pastes.
+---Middle clicks are still sent to clients, so they can be used for other things
+---like opening links in new tabs.
+---
+---This is enabled by default.
+---
+---#### Example
+---```lua
+---Input.set_middle_click_paste(false)
+---```
+---
+---@param enabled boolean
+function input.set_middle_click_paste(enabled)
+    local _, err = client:pinnacle_input_v1_InputService_SetMiddleClickPaste({
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Scales relative pointer motion sent to clients.
+---
+---Games that lock the pointer read relative motion instead of the cursor position,
+---so this tunes their sensitivity without changing how fast the cursor moves.
+---The default is `1.0`. Scales that aren't positive are rejected.
+---
+---#### Example
+---```lua
+----- Halve in-game sensitivity
+---Input.set_relative_motion_scale(0.5)
+---```
+---
+---@param scale number
+function input.set_relative_motion_scale(scale)
+    local _, err = client:pinnacle_input_v1_InputService_SetRelativeMotionScale({
+        scale = scale,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---How relative pointer motion is delivered to clients.
+---
+---- `"accelerated"`: Send accelerated and unaccelerated motion as libinput reports them. This is the default.
+---- `"unaccelerated"`: Send unaccelerated motion in place of accelerated motion, giving games that only read the latter raw input.
+---@alias pinnacle.input.RelativeMotionPolicy
+---| "accelerated"
+---| "unaccelerated"
+
+---Sets how relative pointer motion is delivered to clients.
+---
+---#### Example
+---```lua
+---Input.set_relative_motion_policy("unaccelerated")
+---```
+---
+---@param policy pinnacle.input.RelativeMotionPolicy
+function input.set_relative_motion_policy(policy)
+    local policies = {
+        accelerated = input_v1.RelativeMotionPolicy.RELATIVE_MOTION_POLICY_ACCELERATED,
+        unaccelerated = input_v1.RelativeMotionPolicy.RELATIVE_MOTION_POLICY_UNACCELERATED,
+    }
+
+    local _, err = client:pinnacle_input_v1_InputService_SetRelativeMotionPolicy({
+        policy = policies[policy],
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets how close to a window's edges, in pixels, `Window.begin_resize` has to start
+---to resize those edges.
+---
+---Starting a resize within this distance of an edge resizes it, and starting within it
+---of two edges resizes the corner. Elsewhere, the closest edge is resized. Edge regions
+---are capped at a third of the window so small windows can still be resized on one edge.
+---
+---Passing `nil` splits the window into thirds, which is the default.
+---
+---#### Example
+---```lua
+---Input.set_resize_edge_threshold(32)
+---```
+---
+---@param threshold integer?
+function input.set_resize_edge_threshold(threshold)
+    local _, err = client:pinnacle_input_v1_InputService_SetResizeEdgeThreshold({
+        threshold = threshold,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether the pointer is centered on the focused output until it's first moved.
+---
+---This keeps the pointer from starting out in a corner or between outputs. If the pointer
+---hasn't been moved yet, it's centered immediately, then again whenever outputs are set up
+---or moved until it is.
+---
+---#### Example
+---```lua
+---Input.set_pointer_default_position(true)
+---```
+---
+---@param enabled boolean
+function input.set_pointer_default_position(enabled)
+    local _, err = client:pinnacle_input_v1_InputService_SetPointerDefaultPosition({
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether the pointer is warped to an output when it becomes focused.
+---
+---When an output is focused some way other than moving the pointer onto it, like with
+---`Output.focus_in_direction`, the pointer is moved to its center. This does nothing with a
+---single output.
+---
+---#### Example
+---```lua
+---Input.set_warp_on_output_focus(true)
+---```
+---
+---@param enabled boolean
+function input.set_warp_on_output_focus(enabled)
+    local _, err = client:pinnacle_input_v1_InputService_SetWarpOnOutputFocus({
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets how soon mouse button presses have to follow each other to be a double click.
+---
+---This is what a mousebind's `on_double_click` uses. The time is clamped
+---between 50 milliseconds and 2 seconds. It defaults to 400 milliseconds.
+---
+---#### Example
+---```lua
+---Input.set_double_click_time(300)
+---```
+---
+---@param time_ms integer
+function input.set_double_click_time(time_ms)
+    local _, err = client:pinnacle_input_v1_InputService_SetDoubleClickTime({
+        time_ms = time_ms,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Warps the pointer to the center of the focused output.
+---
+---Does nothing if there are no outputs.
+---
+---#### Example
+---```lua
+---Input.reset_pointer_position()
+---```
+function input.reset_pointer_position()
+    local _, err = client:pinnacle_input_v1_InputService_ResetPointerPosition({})
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Keeps the pointer inside the given output, or lets it move freely again if `nil`.
+---
+---This is useful for games and streaming on multi-monitor setups, where the pointer
+---shouldn't wander onto another output. If the pointer is outside the output,
+---it's warped to the output's center.
+---
+---Confinement is released if the output is disconnected or disabled.
+---
+---#### Example
+---```lua
+---Input.confine_pointer_to_output(Output.get_focused())
+---
+------ Release it
+---Input.confine_pointer_to_output(nil)
+---```
+---
+---@param output pinnacle.output.OutputHandle?
+function input.confine_pointer_to_output(output)
+    local _, err = client:pinnacle_input_v1_InputService_ConfinePointerToOutput({
+        output_name = output and output.name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---@class pinnacle.input.InputSignal Signals related to input events.
 ---@field device_added fun(device: pinnacle.input.libinput.DeviceHandle)? A new input device was connected.
 
@@ -150,7 +150,7 @@ local signal_name_to_SignalName = {
 ---@class pinnacle.output.OutputSignal Signals related to output events.
 ---@field connect fun(output: pinnacle.output.OutputHandle)? An output was connected. FIXME: This currently does not fire for outputs that have been previously connected and disconnected.
 ---@field disconnect fun(output: pinnacle.output.OutputHandle)? An output was disconnected.
----@field resize fun(output: pinnacle.output.OutputHandle, logical_width: integer, logical_height: integer)? An output's logical size changed.
+---@field resize fun(output: pinnacle.output.OutputHandle, logical_width: integer, logical_height: integer)? An output's mode, scale, or transform changed, which may have changed its logical size. Fires after the change is fully applied.
 ---@field move fun(output: pinnacle.output.OutputHandle, x: integer, y: integer)? An output moved.
 ---@field pointer_enter fun(output: pinnacle.output.OutputHandle)? The pointer entered an output.
 ---@field pointer_leave fun(output: pinnacle.output.OutputHandle)? The pointer left an output.
@@ -191,6 +191,348 @@ function output.connect_signal(signals)
     return handles
 end
 
+---A content type hinted by a window's client.
+---@enum (key) pinnacle.output.ContentType
+local content_type_name_to_code = {
+    ---The window has no specific content type.
+    none = output_v1.ContentType.CONTENT_TYPE_NONE,
+    ---The window is displaying photos.
+    photo = output_v1.ContentType.CONTENT_TYPE_PHOTO,
+    ---The window is displaying video.
+    video = output_v1.ContentType.CONTENT_TYPE_VIDEO,
+    ---The window is displaying a game.
+    game = output_v1.ContentType.CONTENT_TYPE_GAME,
+}
+
+---A matcher that gives windows a vrr demand without one being set explicitly.
+---
+---All set conditions must match for a window to demand vrr.
+---@class pinnacle.output.VrrDemandMatcher
+---@field app_id string? A regex the window's app id must match.
+---@field content_types pinnacle.output.ContentType[]? The content types the window must have one of.
+---@field fullscreen boolean? Whether the window must be fullscreen for vrr to turn on.
+
+---Sets the matcher that gives windows a vrr demand on outputs with on-demand vrr.
+---
+---Windows that have had a demand set explicitly through `WindowHandle:set_vrr_demand`
+---are unaffected.
+---
+---#### Example
+---```lua
+--- -- Turn on vrr for fullscreen games
+---Output.set_vrr_demand_matcher({ content_types = { "game" }, fullscreen = true })
+---
+--- -- Turn on vrr for Steam apps
+---Output.set_vrr_demand_matcher({ app_id = "^steam_app_" })
+---
+--- -- Remove the matcher
+---Output.set_vrr_demand_matcher(nil)
+---```
+---
+---@param matcher pinnacle.output.VrrDemandMatcher? The matcher, or `nil` to remove the current one.
+function output.set_vrr_demand_matcher(matcher)
+    local matcher_msg = nil
+
+    if matcher then
+        ---@type pinnacle.output.v1.ContentType[]
+        local content_types = {}
+        for _, content_type in ipairs(matcher.content_types or {}) do
+            table.insert(content_types, content_type_name_to_code[content_type])
+        end
+
+        matcher_msg = {
+            app_id = matcher.app_id,
+            content_types = content_types,
+            fullscreen = matcher.fullscreen or false,
+        }
+    end
+
+    local _, err = client:pinnacle_output_v1_OutputService_SetVrrDemandMatcher({
+        matcher = matcher_msg,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether outputs get a scale guessed from their physical size and resolution.
+---
+---When enabled, newly connected outputs are given a scale that brings their
+---pixel density close to 96 dpi, in steps of 0.25. Outputs that were connected
+---before, and so have a saved scale, keep that scale instead. Outputs that don't
+---report a physical size get a scale of 1.0.
+---
+---Enabling this also applies the heuristic to currently connected outputs whose scale
+---hasn't been set explicitly, restored, or already guessed, so reloading the config
+---doesn't change any scales. Explicitly setting a scale afterwards with
+---`OutputHandle:set_scale` overrides it.
+---
+---#### Example
+---```lua
+---Output.set_auto_scale(true)
+---```
+---
+---@param auto_scale boolean
+function output.set_auto_scale(auto_scale)
+    local _, err = client:pinnacle_output_v1_OutputService_SetAutoScale({
+        auto_scale = auto_scale,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets how the integer `wl_output` scale advertised to clients without fractional
+---scaling is chosen for fractionally scaled outputs.
+---
+---Such clients render at an integer scale and are then scaled to the output's
+---fractional scale, so this decides whether they look blurry or crisp.
+---
+---`mode` is one of:
+--- - `"ceil"`: Round the fractional scale up. This is the default.
+--- - `"floor"`: Round the fractional scale down.
+--- - `"round"`: Round the fractional scale to the nearest integer.
+--- - An integer: Always use this scale.
+---
+---#### Example
+---```lua
+------ Make legacy apps render at scale 1 on a 1.5 scale output
+---Output.set_legacy_scale_mode("floor")
+---```
+---
+---@param mode "ceil" | "floor" | "round" | integer
+function output.set_legacy_scale_mode(mode)
+    local mode_msg = output_v1.LegacyScaleMode.LEGACY_SCALE_MODE_UNSPECIFIED
+    local preferred_scale = 0
+
+    if mode == "ceil" then
+        mode_msg = output_v1.LegacyScaleMode.LEGACY_SCALE_MODE_CEIL
+    elseif mode == "floor" then
+        mode_msg = output_v1.LegacyScaleMode.LEGACY_SCALE_MODE_FLOOR
+    elseif mode == "round" then
+        mode_msg = output_v1.LegacyScaleMode.LEGACY_SCALE_MODE_ROUND
+    elseif type(mode) == "number" then
+        mode_msg = output_v1.LegacyScaleMode.LEGACY_SCALE_MODE_PREFER
+        preferred_scale = mode
+    end
+
+    local _, err = client:pinnacle_output_v1_OutputService_SetLegacyScaleMode({
+        mode = mode_msg,
+        preferred_scale = preferred_scale,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Keeps all modes set with `OutputHandle:try_mode` that haven't been reverted yet.
+---
+---#### Example
+---```lua
+---Output.confirm_mode()
+---```
+function output.confirm_mode()
+    local _, err = client:pinnacle_output_v1_OutputService_ConfirmMode({})
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether windows that open while no tag is active get a new tag.
+---
+---When enabled, opening a window on an output with no active tags creates
+---and activates a new tag named after the lowest unused number. These tags
+---are removed once their last window closes.
+---
+---#### Example
+---```lua
+---Output.set_dynamic_tags(true)
+---```
+---
+---@param dynamic_tags boolean
+function output.set_dynamic_tags(dynamic_tags)
+    local _, err = client:pinnacle_output_v1_OutputService_SetDynamicTags({
+        dynamic_tags = dynamic_tags,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Powers off outputs after `timeout_ms` milliseconds without any input.
+---
+---Outputs are powered back on with the next input. Outputs won't power off while
+---idle is inhibited, for example while a video is playing.
+---
+---Outputs with their own timeout from `OutputHandle:set_dpms_timeout` use that instead.
+---
+---#### Example
+---```lua
+--- -- Power off outputs after 10 minutes of inactivity
+---Output.set_dpms_timeout(600000)
+---```
+---
+---@param timeout_ms integer
+function output.set_dpms_timeout(timeout_ms)
+    local _, err = client:pinnacle_output_v1_OutputService_SetDpmsTimeout({
+        timeout_ms = timeout_ms,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Disables powering off outputs set with `Output.set_dpms_timeout`.
+function output.unset_dpms_timeout()
+    local _, err = client:pinnacle_output_v1_OutputService_SetDpmsTimeout({})
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether outputs crossfade from their old contents when their mode or scale changes.
+---
+---#### Example
+---```lua
+---Output.set_transition_animation(true)
+---```
+---
+---@param enabled boolean
+function output.set_transition_animation(enabled)
+    local _, err = client:pinnacle_output_v1_OutputService_SetTransitionAnimation({
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether windows covered by a fullscreen window are skipped when rendering.
+---
+---This saves work when a fullscreen window hides everything behind it.
+---Fullscreen windows with transparent parts don't hide the windows behind them.
+---
+---#### Example
+---```lua
+---Output.set_occlusion_culling(true)
+---```
+---
+---@param enabled boolean
+function output.set_occlusion_culling(enabled)
+    local _, err = client:pinnacle_output_v1_OutputService_SetOcclusionCulling({
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether layer surfaces' exclusive zones shrink the area windows are laid out in.
+---
+---Bars and panels usually reserve space at the edge of an output so windows don't
+---cover them. When this is off, tiled and maximized windows use the whole output
+---and bars are drawn over them. This defaults to on.
+---
+---To ignore only some bars, use `Output.set_exclusive_zone_ignored_namespaces`.
+---
+---#### Example
+---```lua
+---Output.set_respect_exclusive_zones(false)
+---```
+---
+---@param respect boolean
+function output.set_respect_exclusive_zones(respect)
+    local _, err = client:pinnacle_output_v1_OutputService_SetRespectExclusiveZones({
+        respect = respect,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets the namespaces of layer surfaces whose exclusive zones are ignored.
+---
+---Layer surfaces with these namespaces don't reserve space, making them overlay
+---windows instead. This replaces any previously set namespaces.
+---
+---#### Example
+---```lua
+------ Let windows go under waybar
+---Output.set_exclusive_zone_ignored_namespaces({ "waybar" })
+---```
+---
+---@param namespaces string[]
+function output.set_exclusive_zone_ignored_namespaces(namespaces)
+    local _, err = client:pinnacle_output_v1_OutputService_SetExclusiveZoneIgnoredNamespaces({
+        namespaces = namespaces,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether outputs are moved to stop them from overlapping after one changes.
+---
+---When on, moving an output with `OutputHandle:set_loc` or changing its mode, scale,
+---or transform pushes any outputs it now overlaps out of the way. The output that changed
+---stays where it was put. Outputs that don't overlap anything aren't moved, so gaps
+---between outputs are kept. This defaults to off.
+---
+---#### Example
+---```lua
+---Output.set_auto_resolve_overlaps(true)
+---```
+---
+---@param auto_resolve boolean
+function output.set_auto_resolve_overlaps(auto_resolve)
+    local _, err = client:pinnacle_output_v1_OutputService_SetAutoResolveOverlaps({
+        auto_resolve = auto_resolve,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Focuses the closest output in the given direction from the focused output.
+---
+---The focused output is where new windows open and what tag operations act on by default.
+---Does nothing if there is no output in that direction.
+---
+---#### Example
+---```lua
+---Output.focus_in_direction("right")
+---```
+---
+---@param direction "left" | "right" | "up" | "down"
+function output.focus_in_direction(direction)
+    local dir = ({
+        left = util_v1.Dir.DIR_LEFT,
+        right = util_v1.Dir.DIR_RIGHT,
+        up = util_v1.Dir.DIR_UP,
+        down = util_v1.Dir.DIR_DOWN,
+    })[direction] or util_v1.Dir.DIR_UNSPECIFIED
+
+    local _, err = client:pinnacle_output_v1_OutputService_FocusInDir({
+        dir = dir,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---------------------------------------------------------------------
 
 ---Sets the location of this output in the global space.
@@ -383,6 +725,47 @@ function OutputHandle:set_mode(width, height, refresh_rate_mhz)
     end
 end
 
+---Sets this output's mode, going back to the previous mode after `revert_after_ms` milliseconds
+---unless `Output.confirm_mode` is called first.
+---
+---Use this like a display settings dialog would, so a mode that leaves the screen
+---black undoes itself. Trying another mode before confirming still goes back to
+---the mode from before the first try. If the output is disconnected in the meantime,
+---nothing is reverted.
+---
+---The mode is picked like in `OutputHandle:set_mode`, but unsupported modes
+---are an error instead of being ignored.
+---
+---#### Example
+---```lua
+---Output.get_focused():try_mode(2560, 1440, 144000, 15000)
+---
+------ Later, once the user confirms that they can see things
+---Output.confirm_mode()
+---```
+---
+---@param width integer The mode's width.
+---@param height integer The mode's height.
+---@param refresh_rate_mhz integer? The mode's refresh rate in millihertz, or `nil` to auto-select.
+---@param revert_after_ms integer How long to wait for confirmation.
+---
+---@return boolean success Whether the mode was set.
+function OutputHandle:try_mode(width, height, refresh_rate_mhz, revert_after_ms)
+    local _, err = client:pinnacle_output_v1_OutputService_TryMode({
+        output_name = self.name,
+        size = { width = width, height = height },
+        refresh_rate_mhz = refresh_rate_mhz,
+        revert_after_ms = revert_after_ms,
+    })
+
+    if err then
+        log.error(err)
+        return false
+    end
+
+    return true
+end
+
 ---Sets this output's mode to a custom one.
 ---
 ---If `refresh_rate_mhz` is provided, Pinnacle will create a new mode with that refresh rate.
@@ -411,6 +794,51 @@ function OutputHandle:set_custom_mode(width, height, refresh_rate_mhz)
     end
 end
 
+---Options for `OutputHandle:add_custom_mode`.
+---@class pinnacle.output.CustomModeFlags
+---Use CVT reduced blanking timings, which lower the pixel clock.
+---@field reduced_blanking boolean?
+
+---Creates a user-defined mode with CVT timings and adds it to this output's modes.
+---
+---Once added, the mode can be selected with `set_mode`.
+---
+---The refresh rate is in millihertz.
+---
+---This is only supported on the udev backend. It fails if the mode is outside
+---the limits of the output's GPU or the driver rejects it in a test commit.
+---Added modes are kept if the output is disconnected and reconnected.
+---
+---#### Example
+---```lua
+---local output = Output.get_focused()
+---if output:add_custom_mode(2560, 1080, 75000, { reduced_blanking = true }) then
+---    output:set_mode(2560, 1080, 75000)
+---end
+---```
+---
+---@param width integer
+---@param height integer
+---@param refresh_rate_mhz integer
+---@param flags pinnacle.output.CustomModeFlags?
+---
+---@return boolean success Whether the mode was added.
+function OutputHandle:add_custom_mode(width, height, refresh_rate_mhz, flags)
+    local _, err = client:pinnacle_output_v1_OutputService_AddCustomMode({
+        output_name = self.name,
+        size = { width = width, height = height },
+        refresh_rate_mhz = refresh_rate_mhz,
+        reduced_blanking = flags and flags.reduced_blanking or false,
+    })
+
+    if err then
+        log.error(err)
+        return false
+    end
+
+    return true
+end
+
 ---A custom modeline.
 ---@class pinnacle.output.Modeline
 ---@field clock number
@@ -576,6 +1004,146 @@ function OutputHandle:toggle_powered()
     end
 end
 
+---Powers off this output after `timeout_ms` milliseconds without any input,
+---overriding the timeout from `Output.set_dpms_timeout`.
+---
+---@param timeout_ms integer
+function OutputHandle:set_dpms_timeout(timeout_ms)
+    local _, err = client:pinnacle_output_v1_OutputService_SetDpmsTimeout({
+        output_name = self.name,
+        timeout_ms = timeout_ms,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Makes this output use the timeout from `Output.set_dpms_timeout` again.
+function OutputHandle:unset_dpms_timeout()
+    local _, err = client:pinnacle_output_v1_OutputService_SetDpmsTimeout({
+        output_name = self.name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Blanks or unblanks this output.
+---
+---Blanked outputs display a black frame but stay powered, which is useful for
+---screensavers. While the session is locked, the lock screen is shown instead.
+---
+---@param blanked boolean
+function OutputHandle:set_blanked(blanked)
+    local _, err = client:pinnacle_output_v1_OutputService_SetBlanked({
+        output_name = self.name,
+        blanked = blanked,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether windows on this output are laid out or all float.
+---
+---Switching to `"floating"` floats every tiled window where it is.
+---Switching back to `"tiling"` tiles those windows again.
+---Windows that were already floating stay floating.
+---
+---#### Example
+---```lua
+---Output.get_by_name("HDMI-1"):set_layout_mode("floating")
+---```
+---
+---@param layout_mode
+---| "tiling" # Windows tile according to their own layout mode.
+---| "floating" # Every window floats and no layout is requested.
+function OutputHandle:set_layout_mode(layout_mode)
+    local layout_mode_msg = output_v1.LayoutMode.LAYOUT_MODE_UNSPECIFIED
+
+    if layout_mode == "tiling" then
+        layout_mode_msg = output_v1.LayoutMode.LAYOUT_MODE_TILING
+    elseif layout_mode == "floating" then
+        layout_mode_msg = output_v1.LayoutMode.LAYOUT_MODE_FLOATING
+    end
+
+    local _, err = client:pinnacle_output_v1_OutputService_SetLayoutMode({
+        output_name = self.name,
+        layout_mode = layout_mode_msg,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets how many times larger than its mode this output is rendered before
+---being downscaled.
+---
+---Supersampling smooths out fractional scales and rotated outputs, which is nice
+---for screenshots and recordings, but it's expensive: a factor of 2.0 renders
+---four times as many pixels. A factor of 1.0 disables it.
+---
+---The factor is clamped to at most 4.0 and lowered further for outputs
+---that would need an overly large render target.
+---
+---#### Example
+---```lua
+---Output.get_focused():set_supersample_factor(2.0)
+---```
+---
+---@param factor number Must be at least 1.0.
+function OutputHandle:set_supersample_factor(factor)
+    local _, err = client:pinnacle_output_v1_OutputService_SetSupersampleFactor({
+        output_name = self.name,
+        factor = factor,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets the color filter everything on this output is drawn through.
+---
+---The filter also applies to screencopies of this output.
+---
+---#### Example
+---```lua
+---Output.get_focused():set_color_filter("grayscale")
+---```
+---
+---@param color_filter
+---| "none" # No filter.
+---| "grayscale" # Desaturates everything.
+---| "protanopia" # Shifts colors to help people with protanopia (red-blindness).
+---| "deuteranopia" # Shifts colors to help people with deuteranopia (green-blindness).
+---| "tritanopia" # Shifts colors to help people with tritanopia (blue-blindness).
+---| "invert" # Inverts all colors.
+function OutputHandle:set_color_filter(color_filter)
+    local color_filters = {
+        none = output_v1.ColorFilter.COLOR_FILTER_NONE,
+        grayscale = output_v1.ColorFilter.COLOR_FILTER_GRAYSCALE,
+        protanopia = output_v1.ColorFilter.COLOR_FILTER_PROTANOPIA,
+        deuteranopia = output_v1.ColorFilter.COLOR_FILTER_DEUTERANOPIA,
+        tritanopia = output_v1.ColorFilter.COLOR_FILTER_TRITANOPIA,
+        invert = output_v1.ColorFilter.COLOR_FILTER_INVERT,
+    }
+
+    local _, err = client:pinnacle_output_v1_OutputService_SetColorFilter({
+        output_name = self.name,
+        color_filter = color_filters[color_filter]
+            or output_v1.ColorFilter.COLOR_FILTER_UNSPECIFIED,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Sets the variable refresh rate state of this output.
 ---
 ---@param vrr
@@ -603,6 +1171,100 @@ function OutputHandle:set_vrr(vrr)
     end
 end
 
+---Turns game mode on this output on or off.
+---
+---Game mode forces vrr on if the output supports it, stops compositor-driven
+---animations on this output, and skips rendering windows covered by an opaque
+---fullscreen window. Turning it off restores the previous vrr setting.
+---
+---#### Example
+---```lua
+---Output.get_focused():set_game_mode(true)
+---```
+---
+---@param enabled boolean
+function OutputHandle:set_game_mode(enabled)
+    local _, err = client:pinnacle_output_v1_OutputService_SetGameMode({
+        output_name = self.name,
+        enabled = enabled,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Loads a calibration LUT and sets it as this output's gamma ramps.
+---
+---`path` can be a 1D LUT in the `.cube` format, a headerless file with one `r g b` row
+---of values from 0.0 to 1.0 per line, or an ICC profile with a `vcgt` tag.
+---The LUT is resampled to the output's gamma size.
+---
+---This fails if the file can't be parsed or the output doesn't support setting gamma.
+---
+---#### Example
+---```lua
+---Output.get_focused():set_gamma_from_file("/usr/share/color/icc/calibrated.icc")
+---```
+---
+---@param path string
+---
+---@return boolean success Whether gamma was set.
+function OutputHandle:set_gamma_from_file(path)
+    local _, err = client:pinnacle_output_v1_OutputService_SetGammaFromFile({
+        output_name = self.name,
+        path = path,
+    })
+
+    if err then
+        log.error(err)
+        return false
+    end
+
+    return true
+end
+
+---Overrides the make, model, and serial this output's display reports.
+---
+---Some displays report garbage or identical identities, which breaks telling them apart.
+---With an override, `make`, `model`, and `serial` return the overridden values,
+---and Pinnacle uses them to restore the display's location, scale, and tags when it's reconnected.
+---
+---The override belongs to this output's connector, so it stays in effect
+---when displays are unplugged and plugged back in.
+---
+---#### Example
+---```lua
+---Output.get_by_name("DP-1"):set_identity_override("Dell", "U2720Q", "ABC123")
+---```
+---
+---@param make string
+---@param model string
+---@param serial string
+function OutputHandle:set_identity_override(make, model, serial)
+    local _, err = client:pinnacle_output_v1_OutputService_SetIdentityOverride({
+        output_name = self.name,
+        make = make,
+        model = model,
+        serial = serial,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Removes the identity override set with `set_identity_override`.
+function OutputHandle:clear_identity_override()
+    local _, err = client:pinnacle_output_v1_OutputService_SetIdentityOverride({
+        output_name = self.name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Focuses this output.
 function OutputHandle:focus()
     local _, err = client:pinnacle_output_v1_OutputService_Focus({
@@ -614,6 +1276,51 @@ function OutputHandle:focus()
     end
 end
 
+---Shows the tags with the given names on this output and hides all others.
+---
+---Unlike activating tags one by one, this only lays out the output once.
+---Names that don't match a tag on this output are ignored, and if none match,
+---nothing happens.
+---
+---#### Example
+---```lua
+----- View tags "1" and "2" at the same time
+---Output.get_focused():view_tags({ "1", "2" })
+---```
+---
+---@param names string[]
+function OutputHandle:view_tags(names)
+    local _, err = client:pinnacle_output_v1_OutputService_ViewTags({
+        output_name = self.name,
+        tag_names = names,
+        keep_active = false,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Shows the tag with the given name on this output in addition to the tags already shown.
+---
+---#### Example
+---```lua
+---Output.get_focused():add_tag_to_view("3")
+---```
+---
+---@param name string
+function OutputHandle:add_tag_to_view(name)
+    local _, err = client:pinnacle_output_v1_OutputService_ViewTags({
+        output_name = self.name,
+        tag_names = { name },
+        keep_active = true,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---An output pixel dimension and refresh rate configuration.
 ---@class pinnacle.output.Mode
 ---The width of the mode, in pixels.
@@ -705,6 +1412,30 @@ function OutputHandle:current_mode()
     return ret
 end
 
+---Gets the refresh rate of this output's current mode, in millihertz.
+---
+---For example, 60Hz is returned as 60000.
+---
+---@return integer? # The refresh rate, or `nil` if the output is disabled or doesn't exist.
+function OutputHandle:refresh_rate()
+    local response, err =
+        client:pinnacle_output_v1_OutputService_GetPresentTiming({ output_name = self.name })
+
+    return response and response.refresh_rate_mhz
+end
+
+---Gets how long ago a frame was last presented on this output, in milliseconds.
+---
+---Along with `OutputHandle:refresh_rate`, this can be used to sync animations to the display.
+---
+---@return integer? # The time since the last presentation, or `nil` if no frame has been presented or the backend doesn't track presentation.
+function OutputHandle:time_since_last_present()
+    local response, err =
+        client:pinnacle_output_v1_OutputService_GetPresentTiming({ output_name = self.name })
+
+    return response and response.ms_since_last_presentation
+end
+
 ---Gets this output's preferred mode.
 ---
 ---@return pinnacle.output.Mode? # The preferred mode, or `nil` if the output doesn't exist.
@@ -781,6 +1512,39 @@ function OutputHandle:tags()
     return handles
 end
 
+---Gets the first tag with the given name on this output.
+---
+---Unlike `Tag.get`, this only looks at this output's tags,
+---so outputs that have tags with the same names can be told apart.
+---
+---#### Example
+---```lua
+---local tag_on_hdmi = Output.get_by_name("HDMI-1"):tag("1")
+---```
+---
+---@param name string
+---
+---@return pinnacle.tag.TagHandle | nil
+function OutputHandle:tag(name)
+    local tags = self:tags()
+
+    ---@type (fun(): string)[]
+    local batch = {}
+    for i, tag in ipairs(tags) do
+        batch[i] = function()
+            return tag:name()
+        end
+    end
+
+    local names = require("pinnacle.util").batch(batch)
+
+    for i, tag_name in ipairs(names) do
+        if tag_name == name then
+            return tags[i]
+        end
+    end
+end
+
 ---Gets the active tags this output has.
 ---
 ---@return pinnacle.tag.TagHandle[]
@@ -888,6 +1652,28 @@ function OutputHandle:powered()
     return response and response.powered or false
 end
 
+---Gets whether this output was blanked with `set_blanked`.
+---
+---@return boolean
+function OutputHandle:blanked()
+    local response, err =
+        client:pinnacle_output_v1_OutputService_GetBlanked({ output_name = self.name })
+
+    return response and response.blanked or false
+end
+
+---Renders a thumbnail of this output that fits within `max_width` by `max_height`.
+---
+---See `Render.output_thumbnail` for details.
+---
+---@param max_width integer
+---@param max_height integer
+---
+---@return pinnacle.render.Screenshot | nil
+function OutputHandle:snapshot(max_width, max_height)
+    return require("pinnacle.render").output_thumbnail(self, max_width, max_height)
+end
+
 ---Gets this output's keyboard focus stack.
 ---
 ---This includes *all* windows on the output, even those on inactive tags.
@@ -335,6 +335,103 @@ function process.command(cmd)
     return cmd --[[@as pinnacle.process.Command]]
 end
 
+---Runs a function when the spawned process with the given pid exits.
+---
+---This works for any process spawned through this module, including ones
+---spawned by a previous config before a reload. If the process isn't known
+---or has already been cleaned up, `on_exit` receives an empty table.
+---
+---#### Example
+---```lua
+---local child = Process.spawn("waybar")
+---if child then
+---    Process.on_exit(child.pid, function(exit_info)
+---        print("waybar exited with " .. tostring(exit_info.exit_code))
+---    end)
+---end
+---```
+---
+---@param pid integer The pid of the spawned process.
+---@param on_exit fun(exit_info: { exit_code: integer?, exit_msg: string? })
+function process.on_exit(pid, on_exit)
+    local err = client:pinnacle_process_v1_ProcessService_WaitOnSpawn({
+        pid = pid,
+    }, function(response)
+        local success, error = pcall(on_exit, {
+            exit_code = response.exit_code,
+            exit_msg = response.exit_msg,
+        })
+        if not success then
+            log.error("While handling `Process.on_exit`: " .. tostring(error))
+        end
+    end)
+
+    if err then
+        log.error(err)
+    end
+end
+
+---The status of a process spawned through this module.
+---@class pinnacle.process.ChildStatus
+---@field pid integer The process's pid.
+---@field alive boolean Whether the process hasn't exited yet.
+
+---Gets the processes spawned through this module that are still tracked, ordered by pid.
+---
+---Spawned processes are reaped as soon as they exit, so they never linger as zombies,
+---but they stay tracked with `alive` set to false until they're waited on
+---or `Process.reap` is called. Processes that daemonize exit once they fork,
+---so they show up as not alive.
+---
+---#### Example
+---```lua
+---for _, child in ipairs(Process.running_children()) do
+---    print(child.pid, child.alive)
+---end
+---```
+---
+---@return pinnacle.process.ChildStatus[]
+function process.running_children()
+    local response, err = client:pinnacle_process_v1_ProcessService_GetChildren({})
+
+    if err then
+        log.error(err)
+        return {}
+    end
+
+    assert(response)
+
+    ---@type pinnacle.process.ChildStatus[]
+    local children = {}
+
+    for _, child in ipairs(response.children or {}) do
+        table.insert(children, {
+            pid = child.pid,
+            alive = child.alive or false,
+        })
+    end
+
+    return children
+end
+
+---Stops tracking spawned processes that have exited.
+---
+---Reaped processes can no longer be waited on.
+---
+---@return integer[] pids The pids of the reaped processes.
+function process.reap()
+    local response, err = client:pinnacle_process_v1_ProcessService_Reap({})
+
+    if err then
+        log.error(err)
+        return {}
+    end
+
+    assert(response)
+
+    return response.pids or {}
+end
+
 ---Adds an environment variable that all newly spawned processes will inherit.
 ---
 ---@param key string The environment variable's key.
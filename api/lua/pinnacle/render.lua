@@ -5,6 +5,7 @@
 local log = require("pinnacle.log")
 local client = require("pinnacle.grpc.client").client
 local render_v1 = require("pinnacle.grpc.defs").pinnacle.render.v1
+local set_or_toggle = require("pinnacle.grpc.defs").pinnacle.util.v1.SetOrToggle
 
 ---Rendering management.
 ---
@@ -46,4 +47,308 @@ function render.set_downscale_filter(filter)
     end
 end
 
+---Limits how many frames per second compositor-driven animations get.
+---
+---This throttles animations like output transitions and animated cursors,
+---which can save GPU and CPU time on low-end hardware. Clients and input still
+---render as soon as they need to.
+---
+---Passing `0` disables animations entirely. Passing `nil` removes the cap,
+---which is the default.
+---
+---#### Example
+---```lua
+---Render.set_animation_fps_cap(30)
+---```
+---
+---@param fps_cap integer?
+function render.set_animation_fps_cap(fps_cap)
+    local _, err = client:pinnacle_render_v1_RenderService_SetAnimationFpsCap({
+        fps_cap = fps_cap,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets how windows that aren't displayed, like ones on inactive tags or minimized ones,
+---get frame callbacks.
+---
+---Frame callbacks tell clients when to draw their next frame. Clients that don't get
+---them stop animating, which saves power but can freeze things like clocks.
+---
+---`policy` is one of:
+--- - An integer: Hidden windows get a frame callback once per this many milliseconds.
+---   This defaults to 1000.
+--- - `"always"`: Hidden windows get frame callbacks as often as displayed windows on their output.
+--- - `"never"`: Hidden windows get no frame callbacks.
+---
+---#### Example
+---```lua
+------ Let hidden windows draw twice a second
+---Render.set_frame_callback_policy(500)
+---
+------ Save as much power as possible
+---Render.set_frame_callback_policy("never")
+---```
+---
+---@param policy integer | "always" | "never"
+function render.set_frame_callback_policy(policy)
+    local policy_msg = render_v1.FrameCallbackPolicy.FRAME_CALLBACK_POLICY_UNSPECIFIED
+    local throttle_interval_ms = 0
+
+    if type(policy) == "number" then
+        policy_msg = render_v1.FrameCallbackPolicy.FRAME_CALLBACK_POLICY_THROTTLE
+        throttle_interval_ms = policy
+    elseif policy == "always" then
+        policy_msg = render_v1.FrameCallbackPolicy.FRAME_CALLBACK_POLICY_ALWAYS
+    elseif policy == "never" then
+        policy_msg = render_v1.FrameCallbackPolicy.FRAME_CALLBACK_POLICY_NEVER
+    end
+
+    local _, err = client:pinnacle_render_v1_RenderService_SetFrameCallbackPolicy({
+        policy = policy_msg,
+        throttle_interval_ms = throttle_interval_ms,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether the cursor may be scanned out on a hardware cursor plane.
+---
+---Some drivers have buggy cursor planes that make the cursor flicker.
+---`"software"` works around this by always compositing the cursor.
+---
+---This defaults to `"plane"`. It only affects the udev backend;
+---the winit backend always composites the cursor.
+---
+---#### Example
+---```lua
+---Render.set_cursor_mode("software")
+---```
+---
+---@param mode "plane" | "software"
+function render.set_cursor_mode(mode)
+    local mode_msg = render_v1.CursorMode.CURSOR_MODE_UNSPECIFIED
+
+    if mode == "plane" then
+        mode_msg = render_v1.CursorMode.CURSOR_MODE_PLANE
+    elseif mode == "software" then
+        mode_msg = render_v1.CursorMode.CURSOR_MODE_SOFTWARE
+    end
+
+    local _, err = client:pinnacle_render_v1_RenderService_SetCursorMode({
+        mode = mode_msg,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Opens or closes the overview.
+---
+---The overview shows every window on the focused output, across all of its tags,
+---scaled down onto a grid. Clicking a window or selecting it with the arrow keys and
+---pressing Return focuses it, switching to its tag if needed. Escape or clicking
+---outside a window closes the overview without changing focus.
+---
+---#### Example
+---```lua
+---Render.set_overview(true)
+---```
+---
+---@param open boolean
+function render.set_overview(open)
+    local _, err = client:pinnacle_render_v1_RenderService_SetOverview({
+        set_or_toggle = open and set_or_toggle.SET_OR_TOGGLE_SET
+            or set_or_toggle.SET_OR_TOGGLE_UNSET,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Toggles the overview.
+---
+---See `Render.set_overview` for details.
+---
+---#### Example
+---```lua
+---Input.keybind({ "super" }, "w", function()
+---    Render.toggle_overview()
+---end)
+---```
+function render.toggle_overview()
+    local _, err = client:pinnacle_render_v1_RenderService_SetOverview({
+        set_or_toggle = set_or_toggle.SET_OR_TOGGLE_TOGGLE,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---A captured image of a window or output.
+---@class pinnacle.render.Screenshot
+---@field width integer The width of the image in pixels.
+---@field height integer The height of the image in pixels.
+---@field rgba string 8-bit RGBA pixel data, row by row from the top.
+
+---Renders the given window, including its decorations, and saves it as a PNG to `path`.
+---
+---The image is cropped to the window's bounds and rendered at the scale of the window's output.
+---This fails if the window isn't mapped.
+---
+---#### Example
+---```lua
+---local focused = Window.get_focused()
+---if focused then
+---    Render.screenshot_window(focused, "/tmp/focused.png")
+---end
+---```
+---
+---@param window pinnacle.window.WindowHandle
+---@param path string
+---
+---@return boolean success Whether the screenshot was saved.
+function render.screenshot_window(window, path)
+    local _, err = client:pinnacle_render_v1_RenderService_ScreenshotWindow({
+        window_id = window.id,
+        path = path,
+    })
+
+    if err then
+        log.error(err)
+        return false
+    end
+
+    return true
+end
+
+---Renders the given window, including its decorations, and returns the raw pixels.
+---
+---This is useful for in-memory thumbnails.
+---The image is cropped to the window's bounds and rendered at the scale of the window's output.
+---This fails if the window isn't mapped.
+---
+---@param window pinnacle.window.WindowHandle
+---
+---@return pinnacle.render.Screenshot | nil
+function render.screenshot_window_rgba(window)
+    local response, err = client:pinnacle_render_v1_RenderService_ScreenshotWindow({
+        window_id = window.id,
+    })
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    assert(response)
+
+    ---@type pinnacle.render.Screenshot
+    return {
+        width = response.width or 0,
+        height = response.height or 0,
+        rgba = response.rgba or "",
+    }
+end
+
+---Renders a scaled-down capture of the given output and returns the raw pixels.
+---
+---The thumbnail fits within `max_width` by `max_height` while preserving the output's
+---aspect ratio. It is never scaled up. The cursor isn't included.
+---
+---Thumbnails are cached for a short time, so calling this repeatedly won't render
+---the output every time.
+---
+---This fails if the session is locked.
+---
+---#### Example
+---```lua
+---local focused = Output.get_focused()
+---if focused then
+---    local thumbnail = Render.output_thumbnail(focused, 320, 180)
+---end
+---```
+---
+---@param output pinnacle.output.OutputHandle
+---@param max_width integer
+---@param max_height integer
+---
+---@return pinnacle.render.Screenshot | nil
+function render.output_thumbnail(output, max_width, max_height)
+    local response, err = client:pinnacle_render_v1_RenderService_OutputThumbnail({
+        output_name = output.name,
+        max_width = max_width,
+        max_height = max_height,
+    })
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    assert(response)
+
+    ---@type pinnacle.render.Screenshot
+    return {
+        width = response.width or 0,
+        height = response.height or 0,
+        rgba = response.rgba or "",
+    }
+end
+
+---Diagnostics about an output's rendering.
+---@class pinnacle.render.RenderState
+---@field last_result "submitted" | "no_damage" | "skipped" | nil The result of the last render attempt, or nil if the output hasn't been rendered yet.
+---@field ms_since_last_presentation integer? How long ago a frame was last presented, in milliseconds, if known.
+---@field render_scheduled boolean Whether a render is currently scheduled.
+
+---Gets diagnostics about the given output's rendering.
+---
+---This is mainly useful for figuring out why an output has stopped updating.
+---
+---#### Example
+---```lua
+---local state = Render.render_state(Output.get_focused())
+---if state then
+---    print(state.last_result)
+---end
+---```
+---
+---@param output pinnacle.output.OutputHandle
+---
+---@return pinnacle.render.RenderState | nil
+function render.render_state(output)
+    local response, err = client:pinnacle_render_v1_RenderService_GetRenderState({
+        output_name = output.name,
+    })
+
+    if err then
+        log.error(err)
+        return nil
+    end
+
+    assert(response)
+
+    local results = {
+        [render_v1.RenderResult.RENDER_RESULT_SUBMITTED] = "submitted",
+        [render_v1.RenderResult.RENDER_RESULT_NO_DAMAGE] = "no_damage",
+        [render_v1.RenderResult.RENDER_RESULT_SKIPPED] = "skipped",
+    }
+
+    ---@type pinnacle.render.RenderState
+    return {
+        last_result = results[response.last_result],
+        ms_since_last_presentation = response.ms_since_last_presentation,
+        render_scheduled = response.render_scheduled or false,
+    }
+end
+
 return render
@@ -8,6 +8,16 @@ local signal_service = require("pinnacle.grpc.defs").pinnacle.signal.v1.SignalSe
 
 local stream_control = require("pinnacle.grpc.defs").pinnacle.signal.v1.StreamControl
 
+local layout_mode_def = require("pinnacle.grpc.defs").pinnacle.window.v1.LayoutMode
+
+local layout_mode_names = {
+    [layout_mode_def.LAYOUT_MODE_TILED] = "tiled",
+    [layout_mode_def.LAYOUT_MODE_FLOATING] = "floating",
+    [layout_mode_def.LAYOUT_MODE_FULLSCREEN] = "fullscreen",
+    [layout_mode_def.LAYOUT_MODE_MAXIMIZED] = "maximized",
+    [layout_mode_def.LAYOUT_MODE_SPILLED] = "spilled",
+}
+
 local signals = {
     OutputConnect = {
         ---@type grpc_client.h2.Stream?
@@ -97,6 +107,30 @@ local signals = {
         ---@type fun(response: table)
         on_response = nil,
     },
+    WindowUrgentChanged = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(window: pinnacle.window.WindowHandle, urgent: boolean) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    WindowLayoutModeChanged = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(window: pinnacle.window.WindowHandle, layout_mode: pinnacle.layout.LayoutMode|"spilled") }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    WindowGeometryChanged = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(window: pinnacle.window.WindowHandle, geometry: { x: integer, y: integer, width: integer, height: integer }) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
     TagActive = {
         ---@type grpc_client.h2.Stream?
         sender = nil,
@@ -105,6 +139,14 @@ local signals = {
         ---@type fun(response: table)
         on_response = nil,
     },
+    TagWindowCountChanged = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(tag: pinnacle.tag.TagHandle, count: integer) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
     InputDeviceAdded = {
         ---@type grpc_client.h2.Stream?
         sender = nil,
@@ -113,6 +155,22 @@ local signals = {
         ---@type fun(response: table)
         on_response = nil,
     },
+    PinnaclePrepareForSleep = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun(sleeping: boolean) }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
+    PinnacleOutputLayoutChanged = {
+        ---@type grpc_client.h2.Stream?
+        sender = nil,
+        ---@type { callback_id: integer, callback: fun() }[]
+        callbacks = {},
+        ---@type fun(response: table)
+        on_response = nil,
+    },
 }
 
 ---Call a signal callback in protected mode
@@ -266,6 +324,48 @@ signals.WindowTitleChanged.on_response = function(response)
     end
 end
 
+signals.WindowUrgentChanged.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local window_handle = require("pinnacle.window").handle.new(response.window_id)
+    local callbacks = require("pinnacle.util").deep_copy(signals.WindowUrgentChanged.callbacks)
+    local urgent = response.urgent or false
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("WindowUrgentChanged", callback.callback, window_handle, urgent)
+    end
+end
+
+signals.WindowLayoutModeChanged.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local window_handle = require("pinnacle.window").handle.new(response.window_id)
+    local callbacks = require("pinnacle.util").deep_copy(signals.WindowLayoutModeChanged.callbacks)
+    local layout_mode = layout_mode_names[response.layout_mode] or "tiled"
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("WindowLayoutModeChanged", callback.callback, window_handle, layout_mode)
+    end
+end
+
+signals.WindowGeometryChanged.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local window_handle = require("pinnacle.window").handle.new(response.window_id)
+    local callbacks = require("pinnacle.util").deep_copy(signals.WindowGeometryChanged.callbacks)
+
+    local geometry = response.geometry or {}
+    local loc = geometry.loc or {}
+    local size = geometry.size or {}
+    local geo = {
+        x = loc.x or 0,
+        y = loc.y or 0,
+        width = size.width or 0,
+        height = size.height or 0,
+    }
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("WindowGeometryChanged", callback.callback, window_handle, geo)
+    end
+end
+
 signals.TagActive.on_response = function(response)
     ---@diagnostic disable-next-line: invisible
     local tag_handle = require("pinnacle.tag").handle.new(response.tag_id)
@@ -276,6 +376,21 @@ signals.TagActive.on_response = function(response)
     end
 end
 
+signals.TagWindowCountChanged.on_response = function(response)
+    ---@diagnostic disable-next-line: invisible
+    local tag_handle = require("pinnacle.tag").handle.new(response.tag_id)
+    local callbacks = require("pinnacle.util").deep_copy(signals.TagWindowCountChanged.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback(
+            "TagWindowCountChanged",
+            callback.callback,
+            tag_handle,
+            response.count or 0
+        )
+    end
+end
+
 signals.InputDeviceAdded.on_response = function(response)
     ---@diagnostic disable-next-line: invisible
     local device_handle = require("pinnacle.input.libinput").new_device(response.device_sysname)
@@ -286,6 +401,24 @@ signals.InputDeviceAdded.on_response = function(response)
     end
 end
 
+signals.PinnaclePrepareForSleep.on_response = function(response)
+    local callbacks = require("pinnacle.util").deep_copy(signals.PinnaclePrepareForSleep.callbacks)
+    local sleeping = response.sleeping or false
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("PinnaclePrepareForSleep", callback.callback, nil, sleeping)
+    end
+end
+
+signals.PinnacleOutputLayoutChanged.on_response = function(_)
+    local callbacks =
+        require("pinnacle.util").deep_copy(signals.PinnacleOutputLayoutChanged.callbacks)
+
+    for _, callback in ipairs(callbacks) do
+        protected_callback("PinnacleOutputLayoutChanged", callback.callback)
+    end
+end
+
 -----------------------------------------------------------------------------
 
 ---@class pinnacle.signal.SignalHandleModule
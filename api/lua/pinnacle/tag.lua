@@ -194,12 +194,38 @@ function tag.remove(tags)
     end
 end
 
+---Swaps the windows on two tags.
+---
+---Windows on one tag are moved to the other and vice versa. If the tags are on different
+---outputs, windows move to the other output and lose any other tags they had on the old one.
+---Windows that have both tags stay where they are.
+---
+---#### Example
+---```lua
+---Tag.swap(Tag.get("1"), Tag.get("2"))
+---```
+---
+---@param tg pinnacle.tag.TagHandle
+---@param other pinnacle.tag.TagHandle
+function tag.swap(tg, other)
+    local _, err = client:pinnacle_tag_v1_TagService_Swap({
+        tag_id = tg.id,
+        other_tag_id = other.id,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 local signal_name_to_SignalName = {
     active = "TagActive",
+    window_count_changed = "TagWindowCountChanged",
 }
 
 ---@class pinnacle.tag.TagSignal Signals related to tag events.
 ---@field active fun(tag: pinnacle.tag.TagHandle, active: boolean)? A tag was set to active or not active.
+---@field window_count_changed fun(tag: pinnacle.tag.TagHandle, count: integer)? The number of windows on a tag changed.
 
 ---Connects to a tag signal.
 ---
@@ -252,6 +278,51 @@ function TagHandle:remove()
     tag.remove({ self })
 end
 
+---Moves this tag to the given output.
+---
+---All windows with this tag move along with it. Those windows lose
+---any other tags they had on the old output.
+---
+---#### Example
+---```lua
+---Tag.get("3"):move_to_output(Output.get_by_name("DP-2"))
+---```
+---
+---@param output pinnacle.output.OutputHandle
+function TagHandle:move_to_output(output)
+    local _, err = client:pinnacle_tag_v1_TagService_MoveToOutput({
+        tag_id = self.id,
+        output_name = output.name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets the output this tag lives on.
+---
+---This works like `TagHandle:move_to_output`, but also relocates tags
+---whose output was disconnected. Those tags won't come back when the old
+---output is connected again.
+---
+---#### Example
+---```lua
+---Tag.get("3"):set_output(Output.get_by_name("DP-2"))
+---```
+---
+---@param output pinnacle.output.OutputHandle
+function TagHandle:set_output(output)
+    local _, err = client:pinnacle_tag_v1_TagService_SetOutput({
+        tag_id = self.id,
+        output_name = output.name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Activates this tag and deactivates all other ones on the same output.
 ---
 ---#### Example
@@ -134,6 +134,444 @@ function window.begin_resize(button)
     end
 end
 
+---Sets whether minimized windows are unminimized when one of their tags becomes active.
+---
+---By default, minimized windows stay minimized across tag switches.
+---
+---#### Example
+---```lua
+---Window.set_minimize_restore_on_tag(true)
+---```
+---
+---@param restore boolean
+function window.set_minimize_restore_on_tag(restore)
+    local _, err = client:pinnacle_window_v1_WindowService_SetMinimizeRestoreOnTag({
+        restore = restore,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets the opacity of windows that don't have keyboard focus.
+---
+---`opacity` ranges from 0.0 (fully transparent) to 1.0 (fully opaque).
+---Fullscreen windows are always fully opaque.
+---
+---The default is 1.0, which disables this.
+---
+---#### Example
+---```lua
+---Window.set_inactive_opacity(0.8)
+---```
+---
+---@param opacity number
+function window.set_inactive_opacity(opacity)
+    local _, err = client:pinnacle_window_v1_WindowService_SetInactiveOpacity({
+        opacity = opacity,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+local decoration_policy = {
+    prefer_server_side = window_v1.DecorationPolicy.DECORATION_POLICY_PREFER_SERVER_SIDE,
+    prefer_client_side = window_v1.DecorationPolicy.DECORATION_POLICY_PREFER_CLIENT_SIDE,
+    force_server_side = window_v1.DecorationPolicy.DECORATION_POLICY_FORCE_SERVER_SIDE,
+    force_client_side = window_v1.DecorationPolicy.DECORATION_POLICY_FORCE_CLIENT_SIDE,
+}
+
+---Sets how decoration modes are negotiated with clients.
+---
+---The `prefer` policies use the mode clients ask for, while the `force` policies
+---override it. A mode set with `WindowHandle:set_decoration_mode` always takes precedence.
+---
+---Clients that don't support the xdg-decoration or KDE server decoration protocols
+---draw their own decorations regardless of this policy.
+---
+---The default is `"prefer_client_side"`.
+---
+---#### Example
+---```lua
+---Window.set_decoration_policy("force_server_side")
+---```
+---
+---@param policy "prefer_server_side" | "prefer_client_side" | "force_server_side" | "force_client_side"
+function window.set_decoration_policy(policy)
+    local _, err = client:pinnacle_window_v1_WindowService_SetDecorationPolicy({
+        policy = decoration_policy[policy],
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+local offscreen_policy = {
+    allow = window_v1.OffscreenPolicy.OFFSCREEN_POLICY_ALLOW,
+    keep_visible = window_v1.OffscreenPolicy.OFFSCREEN_POLICY_KEEP_VISIBLE,
+    clamp = window_v1.OffscreenPolicy.OFFSCREEN_POLICY_CLAMP,
+}
+
+---Sets whether floating windows can be moved outside the work area of their output.
+---
+---- `"allow"`: Windows can be moved anywhere, including completely off-screen.
+---- `"keep_visible"`: At least `min_visible` pixels of a window stay inside the work area
+---  on each axis.
+---- `"clamp"`: Windows stay completely inside the work area.
+---
+---The work area is the part of an output not taken up by exclusive layer surfaces like bars.
+---The policy is enforced when windows are moved with `WindowHandle:set_geometry`,
+---moved interactively, and when outputs are moved, resized, or removed.
+---
+---The default is `"allow"`.
+---
+---#### Example
+---```lua
+---Window.set_offscreen_policy("keep_visible", 32)
+---```
+---
+---@param policy "allow" | "keep_visible" | "clamp"
+---@param min_visible integer? Pixels to keep visible with `"keep_visible"`, defaults to 32
+function window.set_offscreen_policy(policy, min_visible)
+    local _, err = client:pinnacle_window_v1_WindowService_SetOffscreenPolicy({
+        policy = offscreen_policy[policy],
+        min_visible = policy == "keep_visible" and (min_visible or 32) or 0,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Limits how many frame callbacks per second windows in the background get.
+---
+---A window is in the background while an opaque window above it covers it completely.
+---Throttling frame callbacks makes these windows redraw less, which saves power.
+---Windows get frame callbacks at their full rate again as soon as they're uncovered.
+---Windows on inactive tags aren't shown, so they don't get frame callbacks at all.
+---
+---Passing `nil` removes the cap, which is the default.
+---
+---#### Example
+---```lua
+---Window.set_background_fps_cap(5)
+---```
+---
+---@param fps_cap integer? Must not be 0.
+function window.set_background_fps_cap(fps_cap)
+    local _, err = client:pinnacle_window_v1_WindowService_SetBackgroundFpsCap({
+        fps_cap = fps_cap,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets the app ids of windows that always open floating.
+---
+---This is a simpler alternative to a window rule that floats windows. Window rules run
+---afterwards, so a layout mode set by one still takes precedence.
+---
+---Replaces any previously set app ids.
+---
+---#### Example
+---```lua
+---Window.set_floating_app_ids({ "pavucontrol", "nm-connection-editor" })
+---```
+---
+---@param app_ids string[]
+function window.set_floating_app_ids(app_ids)
+    local _, err = client:pinnacle_window_v1_WindowService_SetFloatingAppIds({
+        app_ids = app_ids,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets regexes for the titles of windows that always open floating.
+---
+---Window rules run afterwards, so a layout mode set by one still takes precedence.
+---
+---Replaces any previously set titles.
+---
+---#### Example
+---```lua
+---Window.set_floating_titles({ "^Picture-in-Picture$", "Preferences$" })
+---```
+---
+---@param titles string[] Regexes matched against window titles.
+function window.set_floating_titles(titles)
+    local _, err = client:pinnacle_window_v1_WindowService_SetFloatingTitles({
+        titles = titles,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Matches windows by their app id and title.
+---
+---All set conditions must match. An empty matcher matches all windows.
+---@class pinnacle.window.WindowMatcher
+---@field app_id string? A regex the window's app id must match.
+---@field title string? A regex the window's title must match.
+
+---Gives windows matching `matcher` an initial geometry if they open floating.
+---
+---The geometry is applied before the window is first configured, so it opens
+---at that geometry without a separate window rule. Its location is relative to
+---the output the window opens on. A width or height of 0 leaves that dimension
+---up to the window.
+---
+---This only affects windows that open floating, whether from `set_floating_app_ids`,
+---`set_floating_titles`, a window rule, or heuristics. Windows that open tiled are unaffected.
+---Geometry set by a window rule takes precedence.
+---
+---If several rules match a window, the one added first wins.
+---
+---#### Example
+---```lua
+------ Open a floating calculator at 400x500 near the top-right of a 1920-wide output
+---Window.set_floating_app_ids({ "org.gnome.Calculator" })
+---Window.add_geometry_rule(
+---    { app_id = "^org\\.gnome\\.Calculator$" },
+---    { x = 1500, y = 20, width = 400, height = 500 }
+---)
+---```
+---
+---@param matcher pinnacle.window.WindowMatcher
+---@param geometry { x: integer, y: integer, width: integer, height: integer }
+function window.add_geometry_rule(matcher, geometry)
+    local _, err = client:pinnacle_window_v1_WindowService_AddGeometryRule({
+        matcher = {
+            app_id = matcher.app_id,
+            title = matcher.title,
+        },
+        geometry = {
+            loc = { x = geometry.x, y = geometry.y },
+            size = { width = geometry.width, height = geometry.height },
+        },
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---New tags for a window, used with `Window.set_tags_bulk`.
+---
+---@class pinnacle.window.TagChange
+---@field window pinnacle.window.WindowHandle The window to set tags on
+---@field tags pinnacle.tag.TagHandle[] The tags to set
+
+---Sets the exact tags of many windows at once.
+---
+---This is like calling `WindowHandle:set_tags` on each window, but every affected
+---output is laid out only once, after all tags are set. Use this when moving lots
+---of windows around, for example when reorganizing a workspace.
+---
+---Windows given an empty table of tags keep their current tags.
+---
+---#### Example
+---```lua
+----- Move every window to tag "2"
+---local changes = {}
+---for _, win in ipairs(Window.get_all()) do
+---    table.insert(changes, { window = win, tags = { Tag.get("2") } })
+---end
+---Window.set_tags_bulk(changes)
+---```
+---
+---@param changes pinnacle.window.TagChange[]
+function window.set_tags_bulk(changes)
+    ---@type pinnacle.window.v1.SetTagsRequest[]
+    local windows = {}
+
+    for _, change in ipairs(changes) do
+        ---@type integer[]
+        local ids = {}
+
+        for _, tag in ipairs(change.tags) do
+            table.insert(ids, tag.id)
+        end
+
+        table.insert(windows, {
+            window_id = change.window.id,
+            tag_ids = ids,
+        })
+    end
+
+    local _, err = client:pinnacle_window_v1_WindowService_SetTagsBulk({
+        windows = windows,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Shows or hides the window in the scratchpad with the given name.
+---
+---If the window is showing on the focused output, it is hidden.
+---Otherwise, it is moved to the focused output, made floating, centered, and focused.
+---
+---Does nothing if no window is in the scratchpad.
+---
+---See `WindowHandle:to_scratchpad` to put a window in a scratchpad.
+---
+---#### Example
+---```lua
+---Window.toggle_scratchpad("terminal")
+---```
+---
+---@param name string
+function window.toggle_scratchpad(name)
+    local _, err = client:pinnacle_window_v1_WindowService_ToggleScratchpad({
+        name = name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Creates an empty named group of windows.
+---
+---Groups are lightweight collections of windows that can be acted on together,
+---separate from tags. Windows are added with `WindowHandle:add_to_group` and
+---leave all groups when they close. Does nothing if the group already exists.
+---
+---#### Example
+---```lua
+---Window.create_group("browsers")
+---```
+---
+---@param name string
+function window.create_group(name)
+    local _, err = client:pinnacle_window_v1_WindowService_CreateGroup({
+        name = name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Removes a group of windows.
+---
+---The windows in the group are left alone.
+---
+---#### Example
+---```lua
+---Window.remove_group("browsers")
+---```
+---
+---@param name string
+function window.remove_group(name)
+    local _, err = client:pinnacle_window_v1_WindowService_RemoveGroup({
+        name = name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Gets handles to the windows in a group, in the order they were added.
+---
+---Returns an empty table if the group doesn't exist.
+---
+---#### Example
+---```lua
+---for _, win in ipairs(Window.group_windows("browsers")) do
+---    win:close()
+---end
+---```
+---
+---@param name string
+---
+---@return pinnacle.window.WindowHandle[] windows Handles to the windows in the group
+function window.group_windows(name)
+    local response, err = client:pinnacle_window_v1_WindowService_GetGroupWindows({
+        name = name,
+    })
+
+    if err then
+        log.error(err)
+        return {}
+    end
+
+    assert(response)
+
+    return window_handle.new_from_table(response.window_ids or {})
+end
+
+---Focuses and raises the next window in the focused window's group, wrapping around.
+---
+---Windows in the group that aren't on an active tag are skipped.
+---If the focused window is in more than one group, the first by name is used.
+---Does nothing if the focused window isn't in a group.
+---
+---#### Example
+---```lua
+---Window.focus_next_in_group()
+---```
+function window.focus_next_in_group()
+    local _, err = client:pinnacle_window_v1_WindowService_FocusInGroup({
+        backward = false,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Focuses and raises the previous window in the focused window's group, wrapping around.
+---
+---See `Window.focus_next_in_group` for details.
+---
+---#### Example
+---```lua
+---Window.focus_prev_in_group()
+---```
+function window.focus_prev_in_group()
+    local _, err = client:pinnacle_window_v1_WindowService_FocusInGroup({
+        backward = true,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Adds the focused window to every group `target` is in.
+---
+---Does nothing if no window is focused.
+---
+---#### Example
+---```lua
+---local target = Window.get_all()[1]
+---if target then
+---    Window.add_focused_to_group_of(target)
+---end
+---```
+---
+---@param target pinnacle.window.WindowHandle
+function window.add_focused_to_group_of(target)
+    local focused = window.get_focused()
+    if focused then
+        focused:add_to_groups_of(target)
+    end
+end
+
 ---A window's current layout mode.
 ---@enum (key) pinnacle.layout.LayoutMode
 local layout_mode = {
@@ -148,11 +586,90 @@ local layout_mode = {
 }
 require("pinnacle.util").make_bijective(layout_mode)
 
+---A snapshot of a window's properties.
+---
+---Missing properties have the same values that the getters on `WindowHandle` would return.
+---@class pinnacle.window.WindowProps
+---@field window pinnacle.window.WindowHandle The window these properties belong to
+---@field title string
+---@field app_id string
+---@field tags pinnacle.tag.TagHandle[]
+---@field loc { x: integer, y: integer }?
+---@field size { width: integer, height: integer }?
+---@field layout_mode pinnacle.layout.LayoutMode
+
+---@param output_name string?
+---@return pinnacle.window.WindowProps[]
+local function get_props(output_name)
+    local response, err = client:pinnacle_window_v1_WindowService_GetAllWithProps({
+        output_name = output_name,
+    })
+
+    if err then
+        log.error(err)
+        return {}
+    end
+
+    assert(response)
+
+    ---@type pinnacle.window.WindowProps[]
+    local props = {}
+
+    for i, win in ipairs(response.windows or {}) do
+        props[i] = {
+            window = window_handle.new(win.window_id),
+            title = win.title or "",
+            app_id = win.app_id or "",
+            tags = require("pinnacle.tag").handle.new_from_table(win.tag_ids or {}),
+            loc = win.loc,
+            size = win.size,
+            layout_mode = layout_mode[win.layout_mode] or "tiled",
+        }
+    end
+
+    return props
+end
+
+---Gets handles to all windows on the given output.
+---
+---@param output pinnacle.output.OutputHandle
+---
+---@return pinnacle.window.WindowHandle[] windows Handles to all windows on the output
+function window.get_all_on_output(output)
+    local handles = {}
+
+    for i, props in ipairs(get_props(output.name)) do
+        handles[i] = props.window
+    end
+
+    return handles
+end
+
+---Gets the properties of all windows.
+---
+---This gets everything in `WindowProps` with one request, which is much faster
+---than calling the individual getters on every window.
+---
+---#### Example
+---```lua
+---for _, props in ipairs(Window.get_all_with_props()) do
+---    print(props.app_id .. ": " .. props.title)
+---end
+---```
+---
+---@return pinnacle.window.WindowProps[]
+function window.get_all_with_props()
+    return get_props(nil)
+end
+
 local signal_name_to_SignalName = {
     pointer_enter = "WindowPointerEnter",
     pointer_leave = "WindowPointerLeave",
     focused = "WindowFocused",
     title_changed = "WindowTitleChanged",
+    urgent_changed = "WindowUrgentChanged",
+    layout_mode_changed = "WindowLayoutModeChanged",
+    geometry_changed = "WindowGeometryChanged",
 }
 
 ---@class pinnacle.window.WindowSignal Signals related to compositor events.
@@ -160,6 +677,9 @@ local signal_name_to_SignalName = {
 ---@field pointer_leave fun(window: pinnacle.window.WindowHandle)? The pointer left a window.
 ---@field focused fun(window: pinnacle.window.WindowHandle)? The window got keyboard focus.
 ---@field title_changed fun(window: pinnacle.window.WindowHandle, title: string)? A window's title changed.
+---@field urgent_changed fun(window: pinnacle.window.WindowHandle, urgent: boolean)? A window was marked as wanting attention or stopped wanting it.
+---@field layout_mode_changed fun(window: pinnacle.window.WindowHandle, layout_mode: pinnacle.layout.LayoutMode|"spilled")? A window's layout mode changed.
+---@field geometry_changed fun(window: pinnacle.window.WindowHandle, geometry: { x: integer, y: integer, width: integer, height: integer })? A window's geometry changed. Interactive moves and resizes only fire once they end.
 
 ---Connects to a window signal.
 ---
@@ -250,6 +770,9 @@ end
 ------------------------------------------------------------------------
 
 ---Sends a close request to this window.
+---
+---If this window's close behavior isn't `"close"`, it is minimized
+---or hidden in a scratchpad instead. See `WindowHandle:set_close_behavior`.
 function WindowHandle:close()
     local _, err = client:pinnacle_window_v1_WindowService_Close({ window_id = self.id })
 
@@ -298,6 +821,34 @@ function WindowHandle:set_geometry(geo)
     end
 end
 
+---Makes this window floating with the given location and size.
+---
+---Unlike calling `set_floating` and then `set_geometry`, the geometry is set before
+---the window becomes floating, so it never shows up floating with its old geometry.
+---If this window is already floating, it is only moved and resized.
+---
+---#### Example
+---```lua
+---local focused = Window.get_focused()
+---if focused then
+---    focused:float_with_geometry({ x = 100, y = 100, width = 800, height = 600 })
+---end
+---```
+---@param geo { x: integer, y: integer, width: integer, height: integer } The new location and size
+function WindowHandle:float_with_geometry(geo)
+    local _, err = client:pinnacle_window_v1_WindowService_FloatWithGeometry({
+        window_id = self.id,
+        x = geo.x,
+        y = geo.y,
+        w = geo.width,
+        h = geo.height,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---If this window is tiled, resizes its tile by shifting the left, right,
 ---top, and bottom edges by the provided pixel amounts.
 ---
@@ -443,6 +994,202 @@ function WindowHandle:toggle_focused()
     end
 end
 
+---Marks this window as wanting attention or not.
+---
+---Focusing the window clears this.
+---Connect to the `urgent_changed` signal to react to changes.
+---
+---@param urgent boolean
+function WindowHandle:set_urgent(urgent)
+    local _, err = client:pinnacle_window_v1_WindowService_SetUrgent({
+        window_id = self.id,
+        urgent = urgent,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Reports `app_id` as this window's app id instead of the one it set.
+---
+---Use this to fix apps with useless or duplicate app ids. The override is seen
+---everywhere the app id is, including `WindowHandle:app_id`, window rules,
+---and other clients like taskbars. Pass `nil` to remove the override.
+---
+---Overrides are removed when the config reloads.
+---
+---#### Example
+---```lua
+---Window.add_window_rule(function(window)
+---    if window:app_id() == "electron" and window:title():find("Discord") then
+---        window:set_app_id_override("discord")
+---    end
+---end)
+---```
+---
+---@param app_id string?
+function WindowHandle:set_app_id_override(app_id)
+    local _, err = client:pinnacle_window_v1_WindowService_SetAppIdOverride({
+        window_id = self.id,
+        app_id = app_id,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether the pointer is moved onto this window when it gets keyboard focus.
+---
+---The pointer is warped to the center of the window unless it's already over it.
+---Windows spanning multiple outputs are centered on the part on their own output.
+---
+---#### Example
+---```lua
+---Window.add_window_rule(function(window)
+---    if window:app_id() == "gimp" then
+---        window:set_warp_cursor_on_focus(true)
+---    end
+---end)
+---```
+---
+---@param warp boolean
+function WindowHandle:set_warp_cursor_on_focus(warp)
+    local _, err = client:pinnacle_window_v1_WindowService_SetWarpCursorOnFocus({
+        window_id = self.id,
+        warp = warp,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets whether keybinds are bypassed while this window is focused.
+---
+---While set, every key is sent to this window, which is useful for things like remote desktops
+---and virtual machines. This works like a client inhibiting keyboard shortcuts, except it's up to
+---the config. Quit and reload config keybinds still work, as does the escape key set with
+---`Input.set_grab_all_input_escape`.
+---
+---#### Example
+---```lua
+---Window.add_window_rule(function(window)
+---    if window:app_id() == "virt-manager" then
+---        window:set_grab_all_input(true)
+---    end
+---end)
+---```
+---
+---@param grab_all_input boolean
+function WindowHandle:set_grab_all_input(grab_all_input)
+    local _, err = client:pinnacle_window_v1_WindowService_SetGrabAllInput({
+        window_id = self.id,
+        grab_all_input = grab_all_input,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Overrides the fractional scale advertised to this window.
+---
+---Some apps render poorly at fractional scales; this lets you
+---force a scale they handle well, independent of the output's scale.
+---Pass `nil` to go back to advertising the output's scale.
+---
+---#### Example
+---```lua
+--- -- Render the focused window at 2x and let the compositor downscale it
+---local focused = Window.get_focused()
+---if focused then
+---    focused:set_preferred_scale(2.0)
+---end
+---```
+---
+---@param scale number?
+function WindowHandle:set_preferred_scale(scale)
+    local _, err = client:pinnacle_window_v1_WindowService_SetPreferredScale({
+        window_id = self.id,
+        scale = scale,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Locks the width to height ratio this window keeps when resized while floating.
+---
+---When the window is resized, either interactively or through `WindowHandle:set_geometry`,
+---its height follows its width to keep the ratio. Pass `nil` to let the window
+---resize freely again.
+---
+---#### Example
+---```lua
+---local focused = Window.get_focused()
+---if focused then
+---    focused:set_aspect_ratio(16, 9)
+---end
+---```
+---
+---@param width integer?
+---@param height integer?
+function WindowHandle:set_aspect_ratio(width, height)
+    local _, err = client:pinnacle_window_v1_WindowService_SetAspectRatio({
+        window_id = self.id,
+        width = width,
+        height = height,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Sets what happens when this window is asked to close.
+---
+---This applies to `WindowHandle:close` and close requests from taskbars and other
+---foreign toplevel clients. It doesn't stop apps from closing on their own.
+---
+---- `"close"`: The window is asked to close. This is the default.
+---- `"minimize"`: The window is minimized instead.
+---- `{ scratchpad = name }`: The window is hidden in the scratchpad with the given name instead.
+---
+---#### Example
+---```lua
+---Window.add_window_rule(function(window)
+---    if window:app_id() == "discord" then
+---        window:set_close_behavior("minimize")
+---    end
+---end)
+---```
+---
+---@param behavior "close" | "minimize" | { scratchpad: string }
+function WindowHandle:set_close_behavior(behavior)
+    local close_behavior = window_v1.CloseBehavior.CLOSE_BEHAVIOR_CLOSE
+    local scratchpad_name = nil
+
+    if behavior == "minimize" then
+        close_behavior = window_v1.CloseBehavior.CLOSE_BEHAVIOR_MINIMIZE
+    elseif type(behavior) == "table" then
+        close_behavior = window_v1.CloseBehavior.CLOSE_BEHAVIOR_SCRATCHPAD
+        scratchpad_name = behavior.scratchpad
+    end
+
+    local _, err = client:pinnacle_window_v1_WindowService_SetCloseBehavior({
+        window_id = self.id,
+        behavior = close_behavior,
+        scratchpad_name = scratchpad_name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Sets this window's decoration mode.
 ---
 ---If not set, the client is allowed to choose its decoration mode, defaulting to client-side if it doesn't.
@@ -477,14 +1224,34 @@ function WindowHandle:move_to_output(output)
     end
 end
 
+local insert_position = {
+    start = window_v1.InsertPosition.INSERT_POSITION_START,
+    ["end"] = window_v1.InsertPosition.INSERT_POSITION_END,
+    after_focused = window_v1.InsertPosition.INSERT_POSITION_AFTER_FOCUSED,
+}
+
 ---Moves this window to the specified tag.
 ---
 ---This will remove all tags from this window and add the tag `tag`.
 ---
+---If `position` is given, the window is placed there in the tag's layout order:
+---before all other windows on the tag, after all of them, or after the most recently
+---focused one. Otherwise it keeps its current place in the layout order.
+---
+---#### Example
+---```lua
+----- Move the focused window to tag "Code", making it the master window
+---Window.get_focused():move_to_tag(Tag.get("Code"), "start")
+---```
+---
 ---@param tag pinnacle.tag.TagHandle The tag to move this window to
-function WindowHandle:move_to_tag(tag)
-    local _, err =
-        client:pinnacle_window_v1_WindowService_MoveToTag({ window_id = self.id, tag_id = tag.id })
+---@param position ("start" | "end" | "after_focused")? Where to place the window in the tag's layout order
+function WindowHandle:move_to_tag(tag, position)
+    local _, err = client:pinnacle_window_v1_WindowService_MoveToTag({
+        window_id = self.id,
+        tag_id = tag.id,
+        insert_position = insert_position[position],
+    })
 
     if err then
         log.error(err)
@@ -604,6 +1371,92 @@ function WindowHandle:lower()
     end
 end
 
+---Hides this window in the scratchpad with the given name.
+---
+---The window is removed from all its tags until it is summoned with `Window.toggle_scratchpad`.
+---If the scratchpad already had a different window, that window is shown on the focused output.
+---
+---#### Example
+---```lua
+---local focused = Window.get_focused()
+---if focused then
+---    focused:to_scratchpad("terminal")
+---end
+---```
+---
+---@param name string
+function WindowHandle:to_scratchpad(name)
+    local _, err = client:pinnacle_window_v1_WindowService_ToScratchpad({
+        window_id = self.id,
+        name = name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Adds this window to a group, creating the group if it doesn't exist.
+---
+---See `Window.create_group` for more on groups.
+---
+---#### Example
+---```lua
+---local focused = Window.get_focused()
+---if focused then
+---    focused:add_to_group("browsers")
+---end
+---```
+---
+---@param group_name string
+function WindowHandle:add_to_group(group_name)
+    local _, err = client:pinnacle_window_v1_WindowService_AddToGroup({
+        window_id = self.id,
+        group_name = group_name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Adds this window to every group `target` is in.
+---
+---#### Example
+---```lua
+---local focused = Window.get_focused()
+---local target = Window.get_all()[1]
+---if focused and target then
+---    focused:add_to_groups_of(target)
+---end
+---```
+---
+---@param target pinnacle.window.WindowHandle
+function WindowHandle:add_to_groups_of(target)
+    local _, err = client:pinnacle_window_v1_WindowService_AddToGroupsOf({
+        window_id = self.id,
+        target_id = target.id,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
+---Removes this window from a group.
+---
+---@param group_name string
+function WindowHandle:remove_from_group(group_name)
+    local _, err = client:pinnacle_window_v1_WindowService_RemoveFromGroup({
+        window_id = self.id,
+        group_name = group_name,
+    })
+
+    if err then
+        log.error(err)
+    end
+end
+
 ---Returns whether or not this window is on an active tag.
 ---
 ---@return boolean
@@ -657,6 +1510,31 @@ function WindowHandle:app_id()
     return response and response.app_id or ""
 end
 
+---Gets this window's X11 WM_CLASS instance.
+---
+---Xwayland windows have both a class, returned by `WindowHandle:app_id`,
+---and an instance. Wayland windows don't have an instance, so this returns
+---an empty string for them.
+---
+---@return string
+function WindowHandle:instance()
+    local response, err =
+        client:pinnacle_window_v1_WindowService_GetInstance({ window_id = self.id })
+
+    return response and response.instance or ""
+end
+
+---Gets this window's X11 WM_WINDOW_ROLE.
+---
+---If it doesn't have one or it is a Wayland window, this returns an empty string.
+---
+---@return string
+function WindowHandle:role()
+    local response, err = client:pinnacle_window_v1_WindowService_GetRole({ window_id = self.id })
+
+    return response and response.role or ""
+end
+
 ---Gets this window's title.
 ---
 ---@return string
@@ -676,6 +1554,15 @@ function WindowHandle:focused()
     return response and response.focused or false
 end
 
+---Gets whether or not this window wants attention.
+---
+---@return boolean
+function WindowHandle:urgent()
+    local response, err = client:pinnacle_window_v1_WindowService_GetUrgent({ window_id = self.id })
+
+    return response and response.urgent or false
+end
+
 ---Gets this window's output.
 ---
 ---This is currently implemented as the output of the first tag on this window.
@@ -3,14 +3,22 @@
 //! WARNING: This module is not governed by the API stability guarantees.
 
 use pinnacle_api_defs::pinnacle::{
+    self,
     debug::v1::{
-        SetCursorPlaneScanoutRequest, SetDamageVisualizationRequest,
+        DumpStateRequest, SetCursorPlaneScanoutRequest, SetDamageVisualizationRequest,
+        SetDirectScanoutRequest, SetOpaqueRegionCullingRequest,
         SetOpaqueRegionVisualizationRequest, SetProcessPipingRequest,
     },
     util::v1::SetOrToggle,
 };
 
-use crate::{BlockOnTokio, client::Client};
+use crate::{
+    BlockOnTokio,
+    client::Client,
+    output::Mode,
+    util::{Point, Rect, Size},
+    window::LayoutMode,
+};
 
 /// Sets damage visualization.
 ///
@@ -98,6 +106,39 @@ pub fn toggle_cursor_plane_scanout() {
         .unwrap();
 }
 
+/// Enables or disables direct scanout.
+///
+/// When disabled, everything is composited and nothing, including the cursor,
+/// is scanned out on a plane directly. This is useful for telling driver scanout
+/// bugs apart from compositor rendering bugs.
+///
+/// This only has an effect when running in a tty.
+#[doc(alias = "set_force_composite")]
+pub fn set_direct_scanout(set: bool) {
+    Client::debug()
+        .set_direct_scanout(SetDirectScanoutRequest {
+            set_or_toggle: match set {
+                true => SetOrToggle::Set,
+                false => SetOrToggle::Unset,
+            }
+            .into(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Toggles direct scanout.
+///
+/// See [`set_direct_scanout`] for more information.
+pub fn toggle_direct_scanout() {
+    Client::debug()
+        .set_direct_scanout(SetDirectScanoutRequest {
+            set_or_toggle: SetOrToggle::Toggle.into(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
 /// Enables or disables process spawning setting up pipes to expose fds to the config.
 pub fn set_process_piping(set: bool) {
     Client::debug()
@@ -121,3 +162,180 @@ pub fn toggle_process_piping() {
         .block_on_tokio()
         .unwrap();
 }
+
+/// Enables or disables opaque region culling.
+///
+/// When enabled, elements completely hidden behind the opaque regions of elements above them,
+/// like windows behind an opaque window, aren't drawn. Surfaces that don't declare
+/// an opaque region never hide anything.
+///
+/// This is disabled by default, as rendering already skips drawing behind opaque regions.
+/// Use [`set_opaque_region_visualization`] to see what clients have declared as opaque.
+pub fn set_opaque_region_culling(set: bool) {
+    Client::debug()
+        .set_opaque_region_culling(SetOpaqueRegionCullingRequest {
+            set_or_toggle: match set {
+                true => SetOrToggle::Set,
+                false => SetOrToggle::Unset,
+            }
+            .into(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Toggles opaque region culling.
+///
+/// See [`set_opaque_region_culling`] for more information.
+pub fn toggle_opaque_region_culling() {
+    Client::debug()
+        .set_opaque_region_culling(SetOpaqueRegionCullingRequest {
+            set_or_toggle: SetOrToggle::Toggle.into(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// A snapshot of compositor state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateDump {
+    /// All outputs.
+    pub outputs: Vec<OutputDump>,
+    /// All mapped windows.
+    pub windows: Vec<WindowDump>,
+    /// The name of the focused output.
+    pub focused_output_name: Option<String>,
+    /// The id of the window with keyboard focus.
+    pub focused_window_id: Option<u32>,
+}
+
+/// An output in a [`StateDump`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputDump {
+    /// The output's name.
+    pub name: String,
+    /// The output's logical geometry, or `None` if it isn't mapped.
+    pub geometry: Option<Rect>,
+    /// The output's current mode.
+    pub current_mode: Option<Mode>,
+    /// All modes the output supports.
+    pub modes: Vec<Mode>,
+    /// The output's scale.
+    pub scale: f32,
+    /// Whether the output is enabled.
+    pub enabled: bool,
+    /// The output's tags.
+    pub tags: Vec<TagDump>,
+}
+
+/// A tag in a [`StateDump`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagDump {
+    /// The tag's id.
+    pub id: u32,
+    /// The tag's name.
+    pub name: String,
+    /// Whether the tag is active.
+    pub active: bool,
+}
+
+/// A window in a [`StateDump`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowDump {
+    /// The window's id.
+    pub id: u32,
+    /// The window's app id.
+    pub app_id: String,
+    /// The window's title.
+    pub title: String,
+    /// The ids of the window's tags.
+    pub tag_ids: Vec<u32>,
+    /// The window's layout mode.
+    pub layout_mode: LayoutMode,
+    /// The window's geometry, or `None` if it isn't mapped.
+    pub geometry: Option<Rect>,
+}
+
+fn from_api_rect(rect: pinnacle::util::v1::Rect) -> Rect {
+    let loc = rect.loc.unwrap_or_default();
+    let size = rect.size.unwrap_or_default();
+    Rect {
+        loc: Point { x: loc.x, y: loc.y },
+        size: Size {
+            w: size.width,
+            h: size.height,
+        },
+    }
+}
+
+fn from_api_mode(mode: pinnacle::output::v1::Mode) -> Mode {
+    let size = mode.size.unwrap_or_default();
+    Mode {
+        size: Size {
+            w: size.width,
+            h: size.height,
+        },
+        refresh_rate_mhz: mode.refresh_rate_mhz,
+    }
+}
+
+/// Gets a snapshot of outputs, tags, windows, and focus.
+///
+/// Everything is gathered at once, so the snapshot is consistent.
+/// This is useful for bug reports and debugging configs.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::debug;
+/// println!("{:#?}", debug::dump_state());
+/// ```
+pub fn dump_state() -> StateDump {
+    let response = Client::debug()
+        .dump_state(DumpStateRequest {})
+        .block_on_tokio()
+        .unwrap()
+        .into_inner();
+
+    let outputs = response
+        .outputs
+        .into_iter()
+        .map(|output| OutputDump {
+            name: output.name,
+            geometry: output.geometry.map(from_api_rect),
+            current_mode: output.current_mode.map(from_api_mode),
+            modes: output.modes.into_iter().map(from_api_mode).collect(),
+            scale: output.scale,
+            enabled: output.enabled,
+            tags: output
+                .tags
+                .into_iter()
+                .map(|tag| TagDump {
+                    id: tag.tag_id,
+                    name: tag.name,
+                    active: tag.active,
+                })
+                .collect(),
+        })
+        .collect();
+
+    let windows = response
+        .windows
+        .into_iter()
+        .map(|window| WindowDump {
+            id: window.window_id,
+            layout_mode: window.layout_mode().try_into().unwrap_or(LayoutMode::Tiled),
+            app_id: window.app_id,
+            title: window.title,
+            tag_ids: window.tag_ids,
+            geometry: window.geometry.map(from_api_rect),
+        })
+        .collect();
+
+    StateDump {
+        outputs,
+        windows,
+        focused_output_name: response.focused_output_name,
+        focused_window_id: response.focused_window_id,
+    }
+}
@@ -6,24 +6,38 @@
 //!
 //! This module provides ways to manage bindings, input devices, and other input settings.
 
+use std::time::Duration;
+
 use num_enum::{FromPrimitive, IntoPrimitive};
 use pinnacle_api_defs::pinnacle::input::{
     self,
     v1::{
-        BindProperties, BindRequest, EnterBindLayerRequest, GetBindInfosRequest,
-        KeybindOnPressRequest, KeybindStreamRequest, MousebindOnPressRequest,
-        MousebindStreamRequest, SetBindPropertiesRequest, SetRepeatRateRequest, SetXcursorRequest,
-        SetXkbConfigRequest, SetXkbKeymapRequest, SwitchXkbLayoutRequest,
+        BindProperties, BindRequest, ConfinePointerToOutputRequest, EnterBindLayerRequest,
+        ExitBindLayerRequest, GestureBindStreamRequest, GestureBindStreamResponse,
+        GetBindInfosRequest, GetIdleInhibitedRequest, GetKeyboardFocusRequest,
+        GetPointerFocusRequest, KeybindOnPressRequest, KeybindStreamRequest,
+        MousebindOnPressRequest, MousebindStreamRequest, ResetPointerPositionRequest,
+        SendComboRequest, SetBindLayerPassthroughRequest, SetBindPropertiesRequest,
+        SetComposeEnabledRequest, SetCursorAutohideRequest, SetCursorVisibleRequest,
+        SetDoubleClickTimeRequest, SetGrabAllInputEscapeRequest, SetIdleDimRequest,
+        SetMiddleClickPasteRequest, SetMouseButtonMapRequest, SetNumlockRequest,
+        SetPointerDefaultPositionRequest, SetRelativeMotionPolicyRequest,
+        SetRelativeMotionScaleRequest, SetRepeatRateRequest, SetResizeEdgeThresholdRequest,
+        SetStickyKeysRequest, SetWarpOnOutputFocusRequest, SetXcursorRequest, SetXkbConfigRequest,
+        SetXkbKeymapRequest, SwitchXkbLayoutRequest, TypeStringRequest, get_pointer_focus_response,
         switch_xkb_layout_request,
     },
 };
 use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
 use tokio_stream::StreamExt;
+use tonic::Streaming;
 
 use crate::{
     BlockOnTokio,
     client::Client,
+    output::OutputHandle,
     signal::{InputSignal, SignalHandle},
+    window::WindowHandle,
 };
 
 pub mod libinput;
@@ -186,12 +200,62 @@ impl BindLayer {
             .unwrap();
     }
 
+    /// Sets whether binds on the layers below this one stay in effect while it's active.
+    ///
+    /// By default, entering a layer suppresses the binds of every other layer.
+    /// With passthrough, binds on the layer entered before this one also fire,
+    /// down to the [`DEFAULT`][Self::DEFAULT] layer if every layer in between
+    /// passes through as well. If both layers have the same bind, only this layer's fires.
+    ///
+    /// This does nothing for the default layer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::input::BindLayer;
+    /// // Keep the default binds working while in resize mode
+    /// BindLayer::get("resize").set_passthrough(true);
+    /// ```
+    pub fn set_passthrough(&self, passthrough: bool) {
+        let Some(layer_name) = self.name.clone() else {
+            return;
+        };
+
+        Client::input()
+            .set_bind_layer_passthrough(SetBindLayerPassthroughRequest {
+                layer_name,
+                passthrough,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
     /// Returns this bind layer's name, or `None` if this is the default bind layer.
     pub fn name(&self) -> Option<String> {
         self.name.clone()
     }
 }
 
+/// Exits the current bind layer, returning to the one entered before it.
+///
+/// Exiting the last entered layer returns to the [`DEFAULT`][BindLayer::DEFAULT] layer.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::input::{BindLayer, Keysym, Mod};
+/// BindLayer::get("resize")
+///     .keybind(Mod::empty(), Keysym::Escape)
+///     .on_press(input::exit_bind_layer);
+/// ```
+pub fn exit_bind_layer() {
+    Client::input()
+        .exit_bind_layer(ExitBindLayerRequest {})
+        .block_on_tokio()
+        .unwrap();
+}
+
 /// Functionality common to all bind types.
 pub trait Bind {
     /// Sets this bind's group.
@@ -321,6 +385,10 @@ impl Keybind {
     }
 
     /// Runs a closure whenever this keybind is released.
+    ///
+    /// If this keybind's `on_press` closures ran, this always runs when the key is
+    /// released, even if focus changed while the key was held. This makes it usable
+    /// for things like push-to-talk.
     pub fn on_release<F: FnMut() + Send + 'static>(&mut self, on_release: F) -> &mut Self {
         let sender = self
             .callback_sender
@@ -329,6 +397,38 @@ impl Keybind {
 
         self
     }
+
+    /// Makes this keybind repeat while held.
+    ///
+    /// After the keyboard's repeat delay, the press callbacks are run again
+    /// at the keyboard's repeat rate until the key is released.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::input;
+    /// # use pinnacle_api::input::Mod;
+    /// # use pinnacle_api::input::Keysym;
+    /// input::keybind(Mod::SUPER, Keysym::Right)
+    ///     .repeat()
+    ///     .on_press(|| {
+    ///         // Do something like stepping a value
+    ///     });
+    /// ```
+    pub fn repeat(&mut self) -> &mut Self {
+        Client::input()
+            .set_bind_properties(SetBindPropertiesRequest {
+                bind_id: self.bind_id,
+                properties: Some(BindProperties {
+                    repeat: Some(true),
+                    ..Default::default()
+                }),
+            })
+            .block_on_tokio()
+            .unwrap();
+
+        self
+    }
 }
 
 async fn new_keybind(mods: Mod, key: impl ToKeysym, layer: &BindLayer) -> Keybind {
@@ -407,7 +507,13 @@ async fn new_keybind_stream(
 
 // Mousebinds
 
-type MousebindCallback = (Box<dyn FnMut() + Send + 'static>, Edge);
+enum MouseTrigger {
+    Press,
+    Release,
+    DoubleClick,
+}
+
+type MousebindCallback = (Box<dyn FnMut() + Send + 'static>, MouseTrigger);
 
 /// A mousebind.
 pub struct Mousebind {
@@ -428,7 +534,7 @@ impl Mousebind {
         let sender = self
             .callback_sender
             .get_or_insert_with(|| new_mousebind_stream(self.bind_id).block_on_tokio());
-        let _ = sender.send((Box::new(on_press), Edge::Press));
+        let _ = sender.send((Box::new(on_press), MouseTrigger::Press));
 
         Client::input()
             .mousebind_on_press(MousebindOnPressRequest {
@@ -445,7 +551,45 @@ impl Mousebind {
         let sender = self
             .callback_sender
             .get_or_insert_with(|| new_mousebind_stream(self.bind_id).block_on_tokio());
-        let _ = sender.send((Box::new(on_release), Edge::Release));
+        let _ = sender.send((Box::new(on_release), MouseTrigger::Release));
+
+        self
+    }
+
+    /// Runs a closure whenever this mousebind is pressed twice in quick succession.
+    ///
+    /// How quick is set with [`set_double_click_time`]. This runs on the second press,
+    /// after any [`on_press`][Self::on_press] closures. Like `on_press`, this keeps
+    /// presses of this bind from reaching clients.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::input;
+    /// # use pinnacle_api::input::Mod;
+    /// # use pinnacle_api::input::MouseButton;
+    /// # use pinnacle_api::window;
+    /// input::mousebind(Mod::SUPER, MouseButton::Left).on_double_click(|| {
+    ///     if let Some(window) = window::get_focused() {
+    ///         window.toggle_maximized();
+    ///     }
+    /// });
+    /// ```
+    pub fn on_double_click<F: FnMut() + Send + 'static>(
+        &mut self,
+        on_double_click: F,
+    ) -> &mut Self {
+        let sender = self
+            .callback_sender
+            .get_or_insert_with(|| new_mousebind_stream(self.bind_id).block_on_tokio());
+        let _ = sender.send((Box::new(on_double_click), MouseTrigger::DoubleClick));
+
+        Client::input()
+            .mousebind_on_press(MousebindOnPressRequest {
+                bind_id: self.bind_id,
+            })
+            .block_on_tokio()
+            .unwrap();
 
         self
     }
@@ -478,9 +622,7 @@ async fn new_mousebind(mods: Mod, button: MouseButton, layer: &BindLayer) -> Mou
     }
 }
 
-async fn new_mousebind_stream(
-    bind_id: u32,
-) -> UnboundedSender<(Box<dyn FnMut() + Send + 'static>, Edge)> {
+async fn new_mousebind_stream(bind_id: u32) -> UnboundedSender<MousebindCallback> {
     let mut from_server = Client::input()
         .mousebind_stream(MousebindStreamRequest { bind_id })
         .await
@@ -492,6 +634,7 @@ async fn new_mousebind_stream(
     tokio::spawn(async move {
         let mut on_presses = Vec::<Box<dyn FnMut() + Send + 'static>>::new();
         let mut on_releases = Vec::<Box<dyn FnMut() + Send + 'static>>::new();
+        let mut on_double_clicks = Vec::<Box<dyn FnMut() + Send + 'static>>::new();
 
         loop {
             tokio::select! {
@@ -502,6 +645,11 @@ async fn new_mousebind_stream(
                             for on_press in on_presses.iter_mut() {
                                 on_press();
                             }
+                            if response.double_click {
+                                for on_double_click in on_double_clicks.iter_mut() {
+                                    on_double_click();
+                                }
+                            }
                         }
                         input::v1::Edge::Release => {
                             for on_release in on_releases.iter_mut() {
@@ -510,10 +658,11 @@ async fn new_mousebind_stream(
                         }
                     }
                 }
-                Some((cb, edge)) = recv.recv() => {
-                    match edge {
-                        Edge::Press => on_presses.push(cb),
-                        Edge::Release => on_releases.push(cb),
+                Some((cb, trigger)) = recv.recv() => {
+                    match trigger {
+                        MouseTrigger::Press => on_presses.push(cb),
+                        MouseTrigger::Release => on_releases.push(cb),
+                        MouseTrigger::DoubleClick => on_double_clicks.push(cb),
                     }
                 }
                 else => break,
@@ -524,6 +673,99 @@ async fn new_mousebind_stream(
     send
 }
 
+// Gestures
+
+/// The direction of a touchpad swipe.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum SwipeDirection {
+    /// The fingers moved up.
+    Up,
+    /// The fingers moved down.
+    Down,
+    /// The fingers moved left.
+    Left,
+    /// The fingers moved right.
+    Right,
+}
+
+/// Runs a closure whenever a touchpad swipe with the given number of fingers
+/// is performed in the given direction.
+///
+/// The closure runs once the fingers are lifted. Swipes with a bound finger count
+/// aren't sent to clients.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::input::SwipeDirection;
+/// // Switch to the next tag with a three-finger swipe
+/// input::on_swipe(3, SwipeDirection::Left, || {
+///     // View the next tag
+/// });
+/// ```
+pub fn on_swipe<F: FnMut() + Send + 'static>(
+    fingers: u32,
+    direction: SwipeDirection,
+    mut on_swipe: F,
+) {
+    let gesture = match direction {
+        SwipeDirection::Up => input::v1::Gesture::SwipeUp,
+        SwipeDirection::Down => input::v1::Gesture::SwipeDown,
+        SwipeDirection::Left => input::v1::Gesture::SwipeLeft,
+        SwipeDirection::Right => input::v1::Gesture::SwipeRight,
+    };
+
+    let mut from_server = new_gesture_stream(fingers, gesture).block_on_tokio();
+
+    tokio::spawn(async move {
+        while let Some(Ok(_)) = from_server.next().await {
+            on_swipe();
+        }
+    });
+}
+
+/// Runs a closure whenever a touchpad pinch with the given number of fingers
+/// is performed.
+///
+/// The closure runs once the fingers are lifted and receives the scale the fingers
+/// ended at relative to where they started. A scale below 1.0 is a pinch in and
+/// above 1.0 is a pinch out. Pinches with a bound finger count aren't sent to clients.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// input::on_pinch(4, |scale| {
+///     if scale < 1.0 {
+///         // Show an overview
+///     }
+/// });
+/// ```
+pub fn on_pinch<F: FnMut(f64) + Send + 'static>(fingers: u32, mut on_pinch: F) {
+    let mut from_server = new_gesture_stream(fingers, input::v1::Gesture::Pinch).block_on_tokio();
+
+    tokio::spawn(async move {
+        while let Some(Ok(response)) = from_server.next().await {
+            on_pinch(response.scale.unwrap_or(1.0));
+        }
+    });
+}
+
+async fn new_gesture_stream(
+    fingers: u32,
+    gesture: input::v1::Gesture,
+) -> Streaming<GestureBindStreamResponse> {
+    Client::input()
+        .gesture_bind_stream(GestureBindStreamRequest {
+            fingers,
+            gesture: gesture.into(),
+        })
+        .await
+        .unwrap()
+        .into_inner()
+}
+
 /// A struct that lets you define xkeyboard config options.
 ///
 /// See `xkeyboard-config(7)` for more information.
@@ -680,6 +922,8 @@ pub struct BindInfo {
     pub reload_config: bool,
     /// Whether this bind is allowed when the session is locked.
     pub allow_when_locked: bool,
+    /// Whether this bind repeats while held. Always `false` for mousebinds.
+    pub repeat: bool,
     /// What kind of bind this is.
     pub kind: BindInfoKind,
 }
@@ -726,6 +970,67 @@ pub fn set_repeat_rate(rate: i32, delay: i32) {
         .unwrap();
 }
 
+/// Sets whether sticky keys are enabled.
+///
+/// With sticky keys, modifiers can be pressed one after another instead of held.
+/// Tapping a modifier latches it for the next key, so tapping Ctrl then C acts
+/// like Ctrl+C. Tapping a modifier twice locks it until it's tapped again.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// input::set_sticky_keys(true);
+/// ```
+pub fn set_sticky_keys(enabled: bool) {
+    Client::input()
+        .set_sticky_keys(SetStickyKeysRequest { enabled })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets whether the compose key types characters from key sequences.
+///
+/// With compose on, pressing the compose key followed by, for example, `'` and `e`
+/// types `é`. Sequences come from the compose table for the locale Pinnacle was started in.
+///
+/// Your keymap needs a compose key for this, such as one set with the `compose:ralt`
+/// xkb option.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::input::XkbConfig;
+/// input::set_xkb_config(XkbConfig::new().with_options("compose:ralt"));
+/// input::set_compose_enabled(true);
+/// ```
+pub fn set_compose_enabled(enabled: bool) {
+    Client::input()
+        .set_compose_enabled(SetComposeEnabledRequest { enabled })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Turns NumLock on or off.
+///
+/// Call this in your config to set NumLock's state on startup.
+/// You can also set `numlock_on_start` in the `[input]` table of `pinnacle.toml`
+/// to turn it on before the config starts.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// input::set_numlock(true);
+/// ```
+pub fn set_numlock(enabled: bool) {
+    Client::input()
+        .set_numlock(SetNumlockRequest { enabled })
+        .block_on_tokio()
+        .unwrap();
+}
+
 /// Sets the xcursor theme.
 ///
 /// Pinnacle reads `$XCURSOR_THEME` on startup to determine the theme.
@@ -768,6 +1073,552 @@ pub fn set_xcursor_size(size: u32) {
         .unwrap();
 }
 
+/// Sets whether the cursor is drawn.
+///
+/// This only hides the cursor; the pointer still moves and clicks as usual.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// input::set_cursor_visible(false);
+/// ```
+pub fn set_cursor_visible(visible: bool) {
+    Client::input()
+        .set_cursor_visible(SetCursorVisibleRequest { visible })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Hides the cursor after the pointer goes `hide_after` without moving.
+///
+/// The cursor reappears as soon as the pointer moves.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use std::time::Duration;
+/// input::set_cursor_autohide(Duration::from_secs(3));
+/// ```
+pub fn set_cursor_autohide(hide_after: Duration) {
+    Client::input()
+        .set_cursor_autohide(SetCursorAutohideRequest {
+            hide_after_ms: Some(hide_after.as_millis().try_into().unwrap_or(u32::MAX)),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Disables cursor auto-hiding set with [`set_cursor_autohide`].
+pub fn unset_cursor_autohide() {
+    Client::input()
+        .set_cursor_autohide(SetCursorAutohideRequest {
+            hide_after_ms: None,
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Types text into the window with keyboard focus.
+///
+/// Each character is mapped to a key in the current keyboard layout.
+/// Characters that the layout can't type are skipped.
+///
+/// This requires `allow_input_injection` to be set in your `pinnacle.toml`
+/// or Pinnacle to be started with `--allow-input-injection`. Otherwise, nothing happens.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::input::Mod;
+/// input::keybind(Mod::SUPER, 'e').on_press(|| {
+///     input::type_string("me@example.com");
+/// });
+/// ```
+pub fn type_string(text: impl ToString) {
+    Client::input()
+        .type_string(TypeStringRequest {
+            text: text.to_string(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Presses the given keys in order then releases them in reverse order,
+/// sending them to the window with keyboard focus.
+///
+/// Keybinds are not triggered by the combo.
+/// If any of the keys can't be typed with the current keyboard layout, nothing is sent.
+///
+/// This requires `allow_input_injection` to be set in your `pinnacle.toml`
+/// or Pinnacle to be started with `--allow-input-injection`. Otherwise, nothing happens.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::input::Keysym;
+/// // Send Ctrl+Shift+T
+/// input::send_combo([Keysym::Control_L, Keysym::Shift_L, Keysym::t]);
+/// ```
+pub fn send_combo(keys: impl IntoIterator<Item = impl ToKeysym>) {
+    let keys = keys
+        .into_iter()
+        .map(|key| input::v1::Keybind {
+            key_code: Some(key.to_keysym().raw()),
+            xkb_name: None,
+        })
+        .collect();
+
+    Client::input()
+        .send_combo(SendComboRequest { keys })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Dims all outputs after `dim_after` has passed without any input.
+///
+/// `dim_level` is how dark outputs get, from `0.0` (no dimming) to `1.0` (black).
+/// Outputs are undimmed on the next input. Outputs won't dim while idle is inhibited,
+/// for example while a video is playing.
+///
+/// Fails if `dim_level` is outside that range.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use std::time::Duration;
+/// // Dim outputs to 50% after 5 minutes of inactivity
+/// input::set_idle_dim(Duration::from_secs(300), 0.5).unwrap();
+/// ```
+pub fn set_idle_dim(dim_after: Duration, dim_level: f32) -> Result<(), IdleDimError> {
+    Client::input()
+        .set_idle_dim(SetIdleDimRequest {
+            dim_after_ms: Some(dim_after.as_millis().try_into().unwrap_or(u32::MAX)),
+            dim_level,
+        })
+        .block_on_tokio()
+        .map(|_| ())
+        .map_err(|status| IdleDimError(status.message().to_string()))
+}
+
+/// Disables idle dimming set with [`set_idle_dim`].
+pub fn unset_idle_dim() {
+    Client::input()
+        .set_idle_dim(SetIdleDimRequest {
+            dim_after_ms: None,
+            dim_level: 0.0,
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// What has pointer focus.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PointerFocus {
+    /// A window, including its popups and decorations.
+    Window(WindowHandle),
+    /// A layer-shell surface, like a bar or launcher.
+    Layer(LayerInfo),
+}
+
+/// Information about a layer-shell surface.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LayerInfo {
+    /// The namespace the layer surface's client gave it.
+    pub namespace: String,
+    /// The output the layer surface is on.
+    pub output: OutputHandle,
+}
+
+/// Gets what currently has pointer focus.
+///
+/// Returns `None` if the pointer is over nothing, like an empty part of the desktop.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::input::PointerFocus;
+/// match input::pointer_focus() {
+///     Some(PointerFocus::Window(window)) => println!("Hovering {}", window.title()),
+///     Some(PointerFocus::Layer(layer)) => println!("Hovering {}", layer.namespace),
+///     None => println!("Hovering nothing"),
+/// }
+/// ```
+pub fn pointer_focus() -> Option<PointerFocus> {
+    let focus = Client::input()
+        .get_pointer_focus(GetPointerFocusRequest {})
+        .block_on_tokio()
+        .unwrap()
+        .into_inner()
+        .focus?;
+
+    Some(match focus {
+        get_pointer_focus_response::Focus::WindowId(id) => {
+            PointerFocus::Window(WindowHandle::from_id(id))
+        }
+        get_pointer_focus_response::Focus::Layer(layer) => PointerFocus::Layer(LayerInfo {
+            namespace: layer.namespace,
+            output: OutputHandle::from_name(layer.output_name),
+        }),
+    })
+}
+
+/// Gets the window that currently has keyboard focus.
+///
+/// Returns `None` if nothing or something other than a window, like a layer surface,
+/// has keyboard focus.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// if let Some(window) = input::keyboard_focus() {
+///     println!("Typing into {}", window.app_id());
+/// }
+/// ```
+pub fn keyboard_focus() -> Option<WindowHandle> {
+    Client::input()
+        .get_keyboard_focus(GetKeyboardFocusRequest {})
+        .block_on_tokio()
+        .unwrap()
+        .into_inner()
+        .window_id
+        .map(WindowHandle::from_id)
+}
+
+/// Gets whether idle is inhibited.
+///
+/// Idle is inhibited by visible surfaces using the idle-inhibit protocol
+/// and by apps using the `org.freedesktop.ScreenSaver` DBus interface, like browsers
+/// playing video.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// if input::idle_inhibited() {
+///     println!("Something is keeping the screen awake");
+/// }
+/// ```
+pub fn idle_inhibited() -> bool {
+    Client::input()
+        .get_idle_inhibited(GetIdleInhibitedRequest {})
+        .block_on_tokio()
+        .unwrap()
+        .into_inner()
+        .inhibited
+}
+
+/// What a mouse button does after being remapped with [`set_mouse_button_map`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ButtonAction {
+    /// Act as a different mouse button.
+    Button(MouseButton),
+    /// Trigger keybinds as if the given key was pressed with the given modifiers.
+    ///
+    /// The button is not sent to clients.
+    Keybind(Mod, Keysym),
+}
+
+/// Sets the key that stops the focused window from grabbing all input.
+///
+/// Pressing it while a window set with
+/// [`WindowHandle::set_grab_all_input`][crate::window::WindowHandle::set_grab_all_input]
+/// is focused unsets that window's grab, so keybinds work again.
+/// The key isn't sent to the window.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::input::{Keysym, Mod};
+/// input::set_grab_all_input_escape(Mod::SUPER, Keysym::Escape);
+/// ```
+pub fn set_grab_all_input_escape(mods: Mod, key: impl ToKeysym) {
+    Client::input()
+        .set_grab_all_input_escape(SetGrabAllInputEscapeRequest {
+            mods: mods.api_mods().into_iter().map(|m| m.into()).collect(),
+            key: Some(input::v1::Keybind {
+                key_code: Some(key.to_keysym().raw()),
+                xkb_name: None,
+            }),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Removes the key set with [`set_grab_all_input_escape`].
+pub fn clear_grab_all_input_escape() {
+    Client::input()
+        .set_grab_all_input_escape(SetGrabAllInputEscapeRequest {
+            mods: Vec::new(),
+            key: None,
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Remaps mouse buttons.
+///
+/// Buttons are remapped before anything else sees them, so mousebinds and clients
+/// receive the remapped button. Each call replaces all previous mappings;
+/// pass an empty iterator to remove them.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::input::{ButtonAction, Keysym, Mod, MouseButton};
+/// input::set_mouse_button_map([
+///     // Left-handed mouse
+///     (MouseButton::Left, ButtonAction::Button(MouseButton::Right)),
+///     (MouseButton::Right, ButtonAction::Button(MouseButton::Left)),
+///     // Trigger the `Super + Tab` keybind with the side button
+///     (MouseButton::Side, ButtonAction::Keybind(Mod::SUPER, Keysym::Tab)),
+/// ]);
+/// ```
+pub fn set_mouse_button_map(map: impl IntoIterator<Item = (MouseButton, ButtonAction)>) {
+    let mappings = map
+        .into_iter()
+        .map(|(from, action)| input::v1::ButtonMapping {
+            from_button: from.into(),
+            to: Some(match action {
+                ButtonAction::Button(button) => {
+                    input::v1::button_mapping::To::Button(button.into())
+                }
+                ButtonAction::Keybind(mods, key) => {
+                    input::v1::button_mapping::To::Keybind(input::v1::ButtonMappingKeybind {
+                        mods: mods.api_mods().into_iter().map(|m| m.into()).collect(),
+                        key: Some(input::v1::Keybind {
+                            key_code: Some(key.raw()),
+                            xkb_name: None,
+                        }),
+                    })
+                }
+            }),
+        })
+        .collect();
+
+    Client::input()
+        .set_mouse_button_map(SetMouseButtonMapRequest { mappings })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets whether middle clicking pastes the primary selection.
+///
+/// The primary selection is the most recently selected text, which most apps
+/// paste on middle click. Disable this to avoid accidental pastes.
+/// Middle clicks are still sent to clients, so they can be used for other things
+/// like opening links in new tabs.
+///
+/// This is enabled by default.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// input::set_middle_click_paste(false);
+/// ```
+pub fn set_middle_click_paste(enabled: bool) {
+    Client::input()
+        .set_middle_click_paste(SetMiddleClickPasteRequest { enabled })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Scales relative pointer motion sent to clients.
+///
+/// Games that lock the pointer read relative motion instead of the cursor position,
+/// so this tunes their sensitivity without changing how fast the cursor moves.
+/// The default is `1.0`. Scales that aren't positive are ignored.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// // Halve in-game sensitivity
+/// input::set_relative_motion_scale(0.5);
+/// ```
+pub fn set_relative_motion_scale(scale: f32) {
+    let _ = Client::input()
+        .set_relative_motion_scale(SetRelativeMotionScaleRequest { scale })
+        .block_on_tokio();
+}
+
+/// How relative pointer motion is delivered to clients.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RelativeMotionPolicy {
+    /// Send accelerated and unaccelerated motion as libinput reports them.
+    #[default]
+    Accelerated,
+    /// Send unaccelerated motion in place of accelerated motion.
+    ///
+    /// Some games only read accelerated motion, which makes aim
+    /// depend on how fast the mouse moves. This gives them raw input.
+    Unaccelerated,
+}
+
+/// Sets how relative pointer motion is delivered to clients.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::input::RelativeMotionPolicy;
+/// input::set_relative_motion_policy(RelativeMotionPolicy::Unaccelerated);
+/// ```
+pub fn set_relative_motion_policy(policy: RelativeMotionPolicy) {
+    let policy = match policy {
+        RelativeMotionPolicy::Accelerated => input::v1::RelativeMotionPolicy::Accelerated,
+        RelativeMotionPolicy::Unaccelerated => input::v1::RelativeMotionPolicy::Unaccelerated,
+    };
+
+    Client::input()
+        .set_relative_motion_policy(SetRelativeMotionPolicyRequest {
+            policy: policy.into(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets how close to a window's edges, in pixels, [`window::begin_resize`] has to start
+/// to resize those edges.
+///
+/// Starting a resize within this distance of an edge resizes it, and starting within it
+/// of two edges resizes the corner. Elsewhere, the closest edge is resized. Edge regions
+/// are capped at a third of the window so small windows can still be resized on one edge.
+///
+/// Passing `None` splits the window into thirds, which is the default.
+///
+/// [`window::begin_resize`]: crate::window::begin_resize
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// input::set_resize_edge_threshold(32);
+/// ```
+pub fn set_resize_edge_threshold(threshold: impl Into<Option<u32>>) {
+    Client::input()
+        .set_resize_edge_threshold(SetResizeEdgeThresholdRequest {
+            threshold: threshold.into(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets whether the pointer is centered on the focused output until it's first moved.
+///
+/// This keeps the pointer from starting out in a corner or between outputs. If the pointer
+/// hasn't been moved yet, it's centered immediately, then again whenever outputs are set up
+/// or moved until it is.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// input::set_pointer_default_position(true);
+/// ```
+pub fn set_pointer_default_position(enabled: bool) {
+    Client::input()
+        .set_pointer_default_position(SetPointerDefaultPositionRequest { enabled })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets whether the pointer is warped to an output when it becomes focused.
+///
+/// When an output is focused some way other than moving the pointer onto it, like with
+/// [`output::focus_in_direction`][crate::output::focus_in_direction], the pointer is moved
+/// to its center. This does nothing with a single output.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// input::set_warp_on_output_focus(true);
+/// ```
+pub fn set_warp_on_output_focus(enabled: bool) {
+    Client::input()
+        .set_warp_on_output_focus(SetWarpOnOutputFocusRequest { enabled })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets how soon mouse button presses have to follow each other to be a double click.
+///
+/// This is what [`Mousebind::on_double_click`] uses. The time is clamped
+/// between 50 milliseconds and 2 seconds. It defaults to 400 milliseconds.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use std::time::Duration;
+/// input::set_double_click_time(Duration::from_millis(300));
+/// ```
+pub fn set_double_click_time(time: Duration) {
+    Client::input()
+        .set_double_click_time(SetDoubleClickTimeRequest {
+            time_ms: time.as_millis().try_into().unwrap_or(u32::MAX),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Warps the pointer to the center of the focused output.
+///
+/// Does nothing if there are no outputs.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// input::reset_pointer_position();
+/// ```
+pub fn reset_pointer_position() {
+    Client::input()
+        .reset_pointer_position(ResetPointerPositionRequest {})
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Keeps the pointer inside the given output, or lets it move freely again if `None`.
+///
+/// This is useful for games and streaming on multi-monitor setups, where the pointer
+/// shouldn't wander onto another output. If the pointer is outside the output,
+/// it's warped to the output's center.
+///
+/// Confinement is released if the output is disconnected or disabled.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input;
+/// # use pinnacle_api::output;
+/// # || {
+/// input::confine_pointer_to_output(Some(&output::get_focused()?));
+///
+/// // Release it
+/// input::confine_pointer_to_output(None);
+/// # Some(())
+/// # };
+/// ```
+pub fn confine_pointer_to_output(output: Option<&OutputHandle>) {
+    Client::input()
+        .confine_pointer_to_output(ConfinePointerToOutputRequest {
+            output_name: output.map(|output| output.name()),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
 /// A trait that designates anything that can be converted into a [`Keysym`].
 pub trait ToKeysym {
     /// Converts this into a [`Keysym`].
@@ -875,6 +1726,11 @@ pub fn bind_infos() -> impl Iterator<Item = BindInfo> {
             .as_ref()
             .and_then(|props| props.allow_when_locked)
             .unwrap_or_default();
+        let repeat = info
+            .properties
+            .as_ref()
+            .and_then(|props| props.repeat)
+            .unwrap_or_default();
 
         Some(BindInfo {
             group,
@@ -884,6 +1740,7 @@ pub fn bind_infos() -> impl Iterator<Item = BindInfo> {
             quit,
             reload_config,
             allow_when_locked,
+            repeat,
             kind: bind_kind,
         })
     })
@@ -907,3 +1764,15 @@ pub fn connect_signal(signal: InputSignal) -> SignalHandle {
         InputSignal::DeviceAdded(f) => signal_state.input_device_added.add_callback(f),
     }
 }
+
+/// Error returned when idle dimming couldn't be set.
+#[derive(Debug, Clone)]
+pub struct IdleDimError(String);
+
+impl std::fmt::Display for IdleDimError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for IdleDimError {}
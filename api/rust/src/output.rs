@@ -8,19 +8,26 @@
 //!
 //! Outputs are uniquely identified by their name, a.k.a. the name of the connector they're plugged in to.
 
-use std::str::FromStr;
+use std::{path::Path, str::FromStr, time::Duration};
 
 use futures::FutureExt;
 use pinnacle_api_defs::pinnacle::{
     output::{
         self,
         v1::{
-            FocusRequest, GetEnabledRequest, GetFocusStackWindowIdsRequest, GetFocusedRequest,
+            AddCustomModeRequest, ConfirmModeRequest, FocusInDirRequest, FocusRequest,
+            GetBlankedRequest, GetEnabledRequest, GetFocusStackWindowIdsRequest, GetFocusedRequest,
             GetInfoRequest, GetLocRequest, GetLogicalSizeRequest, GetModesRequest,
-            GetOutputsInDirRequest, GetPhysicalSizeRequest, GetPoweredRequest, GetRequest,
-            GetScaleRequest, GetTagIdsRequest, GetTransformRequest, SetLocRequest, SetModeRequest,
-            SetModelineRequest, SetPoweredRequest, SetScaleRequest, SetTransformRequest,
-            SetVrrRequest,
+            GetOutputsInDirRequest, GetPhysicalSizeRequest, GetPoweredRequest,
+            GetPresentTimingRequest, GetRequest, GetScaleRequest, GetTagIdsRequest,
+            GetTransformRequest, SetAutoResolveOverlapsRequest, SetAutoScaleRequest,
+            SetBlankedRequest, SetColorFilterRequest, SetDpmsTimeoutRequest, SetDynamicTagsRequest,
+            SetExclusiveZoneIgnoredNamespacesRequest, SetGameModeRequest, SetGammaFromFileRequest,
+            SetIdentityOverrideRequest, SetLayoutModeRequest, SetLegacyScaleModeRequest,
+            SetLocRequest, SetModeRequest, SetModelineRequest, SetOcclusionCullingRequest,
+            SetPoweredRequest, SetRespectExclusiveZonesRequest, SetScaleRequest,
+            SetSupersampleFactorRequest, SetTransformRequest, SetTransitionAnimationRequest,
+            SetVrrDemandMatcherRequest, SetVrrRequest, TryModeRequest, ViewTagsRequest,
         },
     },
     util::v1::{AbsOrRel, SetOrToggle},
@@ -29,6 +36,7 @@ use pinnacle_api_defs::pinnacle::{
 use crate::{
     BlockOnTokio,
     client::Client,
+    render::{Screenshot, ScreenshotError},
     signal::{OutputSignal, SignalHandle},
     tag::TagHandle,
     util::{Batch, Direction, Point, Size},
@@ -166,6 +174,324 @@ pub fn connect_signal(signal: OutputSignal) -> SignalHandle {
     }
 }
 
+/// Sets the matcher that gives windows a [`VrrDemand`] on outputs using [`Vrr::OnDemand`].
+///
+/// Windows that have had a demand set explicitly through
+/// [`WindowHandle::set_vrr_demand`] are unaffected.
+///
+/// Pass in `None` to remove the current matcher.
+///
+/// # Panics
+///
+/// Panics if the matcher's app id regex is invalid.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// # use pinnacle_api::output::ContentType;
+/// # use pinnacle_api::output::VrrDemandMatcher;
+/// // Turn on vrr for fullscreen games and Steam apps
+/// output::set_vrr_demand_matcher(
+///     VrrDemandMatcher::new()
+///         .content_types([ContentType::Game])
+///         .fullscreen(true),
+/// );
+/// output::set_vrr_demand_matcher(VrrDemandMatcher::new().app_id("^steam_app_"));
+///
+/// // Remove the matcher
+/// output::set_vrr_demand_matcher(None);
+/// ```
+///
+/// [`VrrDemand`]: crate::window::VrrDemand
+pub fn set_vrr_demand_matcher(matcher: impl Into<Option<VrrDemandMatcher>>) {
+    let matcher = matcher.into().map(|matcher| output::v1::VrrDemandMatcher {
+        app_id: matcher.app_id,
+        content_types: matcher
+            .content_types
+            .into_iter()
+            .map(|content_type| output::v1::ContentType::from(content_type) as i32)
+            .collect(),
+        fullscreen: matcher.fullscreen,
+    });
+
+    Client::output()
+        .set_vrr_demand_matcher(SetVrrDemandMatcherRequest { matcher })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets whether outputs get a scale guessed from their physical size and resolution.
+///
+/// When enabled, newly connected outputs are given a scale that brings their
+/// pixel density close to 96 dpi, in steps of 0.25. Outputs that were connected
+/// before, and so have a saved scale, keep that scale instead. Outputs that don't
+/// report a physical size get a scale of 1.0.
+///
+/// Enabling this also applies the heuristic to currently connected outputs whose scale
+/// hasn't been set explicitly, restored, or already guessed, so reloading the config
+/// doesn't change any scales. Explicitly setting a scale afterwards with
+/// [`OutputHandle::set_scale`] overrides it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// output::set_auto_scale(true);
+/// ```
+pub fn set_auto_scale(auto_scale: bool) {
+    Client::output()
+        .set_auto_scale(SetAutoScaleRequest { auto_scale })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// How the integer scale is chosen for clients that don't support fractional scaling.
+///
+/// Such clients render at an integer scale and are then scaled to the output's
+/// fractional scale, so this decides whether they look blurry or crisp.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LegacyScale {
+    /// Round the fractional scale up.
+    ///
+    /// Legacy clients render larger and are downscaled, keeping them sharp.
+    #[default]
+    Ceil,
+    /// Round the fractional scale down.
+    ///
+    /// Legacy clients render smaller and are upscaled, making them blurry.
+    Floor,
+    /// Round the fractional scale to the nearest integer.
+    Round,
+    /// Always use this scale.
+    Prefer(i32),
+}
+
+/// Sets how the integer `wl_output` scale advertised to clients without fractional
+/// scaling is chosen for fractionally scaled outputs.
+///
+/// This defaults to [`LegacyScale::Ceil`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// # use pinnacle_api::output::LegacyScale;
+/// // Make legacy apps render at scale 1 on a 1.5 scale output
+/// output::set_legacy_scale_mode(LegacyScale::Floor);
+/// ```
+pub fn set_legacy_scale_mode(mode: LegacyScale) {
+    let (mode, preferred_scale) = match mode {
+        LegacyScale::Ceil => (output::v1::LegacyScaleMode::Ceil, 0),
+        LegacyScale::Floor => (output::v1::LegacyScaleMode::Floor, 0),
+        LegacyScale::Round => (output::v1::LegacyScaleMode::Round, 0),
+        LegacyScale::Prefer(scale) => (output::v1::LegacyScaleMode::Prefer, scale),
+    };
+
+    Client::output()
+        .set_legacy_scale_mode(SetLegacyScaleModeRequest {
+            mode: mode.into(),
+            preferred_scale,
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Keeps all modes set with [`OutputHandle::try_mode`] that haven't been reverted yet.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// output::confirm_mode();
+/// ```
+pub fn confirm_mode() {
+    Client::output()
+        .confirm_mode(ConfirmModeRequest {})
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets whether windows that open while no tag is active get a new tag.
+///
+/// When enabled, opening a window on an output with no active tags creates
+/// and activates a new tag named after the lowest unused number. These tags
+/// are removed once their last window closes.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// output::set_dynamic_tags(true);
+/// ```
+pub fn set_dynamic_tags(dynamic_tags: bool) {
+    Client::output()
+        .set_dynamic_tags(SetDynamicTagsRequest { dynamic_tags })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Powers off outputs after `timeout` has passed without any input.
+///
+/// Outputs are powered back on with the next input. Outputs won't power off while
+/// idle is inhibited, for example while a video is playing.
+///
+/// Outputs with their own timeout from [`OutputHandle::set_dpms_timeout`] use that instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// # use std::time::Duration;
+/// // Power off outputs after 10 minutes of inactivity
+/// output::set_dpms_timeout(Duration::from_secs(600));
+/// ```
+pub fn set_dpms_timeout(timeout: Duration) {
+    Client::output()
+        .set_dpms_timeout(SetDpmsTimeoutRequest {
+            output_name: None,
+            timeout_ms: Some(timeout.as_millis().try_into().unwrap_or(u32::MAX)),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Disables powering off outputs set with [`set_dpms_timeout`].
+pub fn unset_dpms_timeout() {
+    Client::output()
+        .set_dpms_timeout(SetDpmsTimeoutRequest {
+            output_name: None,
+            timeout_ms: None,
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets whether outputs crossfade from their old contents when their mode or scale changes.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// output::set_transition_animation(true);
+/// ```
+pub fn set_transition_animation(enabled: bool) {
+    Client::output()
+        .set_transition_animation(SetTransitionAnimationRequest { enabled })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets whether windows covered by a fullscreen window are skipped when rendering.
+///
+/// This saves work when a fullscreen window hides everything behind it.
+/// Fullscreen windows with transparent parts don't hide the windows behind them.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// output::set_occlusion_culling(true);
+/// ```
+pub fn set_occlusion_culling(enabled: bool) {
+    Client::output()
+        .set_occlusion_culling(SetOcclusionCullingRequest { enabled })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets whether layer surfaces' exclusive zones shrink the area windows are laid out in.
+///
+/// Bars and panels usually reserve space at the edge of an output so windows don't
+/// cover them. When this is off, tiled and maximized windows use the whole output
+/// and bars are drawn over them. This defaults to on.
+///
+/// To ignore only some bars, use [`set_exclusive_zone_ignored_namespaces`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// output::set_respect_exclusive_zones(false);
+/// ```
+pub fn set_respect_exclusive_zones(respect: bool) {
+    Client::output()
+        .set_respect_exclusive_zones(SetRespectExclusiveZonesRequest { respect })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets the namespaces of layer surfaces whose exclusive zones are ignored.
+///
+/// Layer surfaces with these namespaces don't reserve space, making them overlay
+/// windows instead. This replaces any previously set namespaces.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// // Let windows go under waybar
+/// output::set_exclusive_zone_ignored_namespaces(["waybar"]);
+/// ```
+pub fn set_exclusive_zone_ignored_namespaces(namespaces: impl IntoIterator<Item = impl ToString>) {
+    Client::output()
+        .set_exclusive_zone_ignored_namespaces(SetExclusiveZoneIgnoredNamespacesRequest {
+            namespaces: namespaces
+                .into_iter()
+                .map(|namespace| namespace.to_string())
+                .collect(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets whether outputs are moved to stop them from overlapping after one changes.
+///
+/// When on, moving an output with [`OutputHandle::set_loc`] or changing its mode, scale,
+/// or transform pushes any outputs it now overlaps out of the way. The output that changed
+/// stays where it was put. Outputs that don't overlap anything aren't moved, so gaps
+/// between outputs are kept. This defaults to off.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// output::set_auto_resolve_overlaps(true);
+/// ```
+pub fn set_auto_resolve_overlaps(auto_resolve: bool) {
+    Client::output()
+        .set_auto_resolve_overlaps(SetAutoResolveOverlapsRequest { auto_resolve })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Focuses the closest output in the given direction from the focused output.
+///
+/// The focused output is where new windows open and what tag operations act on by default.
+/// Does nothing if there is no output in that direction.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::output;
+/// # use pinnacle_api::util::Direction;
+/// output::focus_in_direction(Direction::Right);
+/// ```
+pub fn focus_in_direction(direction: Direction) {
+    let mut request = FocusInDirRequest::default();
+    request.set_dir(match direction {
+        Direction::Left => pinnacle_api_defs::pinnacle::util::v1::Dir::Left,
+        Direction::Right => pinnacle_api_defs::pinnacle::util::v1::Dir::Right,
+        Direction::Up => pinnacle_api_defs::pinnacle::util::v1::Dir::Up,
+        Direction::Down => pinnacle_api_defs::pinnacle::util::v1::Dir::Down,
+    });
+
+    Client::output()
+        .focus_in_dir(request)
+        .block_on_tokio()
+        .unwrap();
+}
+
 /// A handle to an output.
 ///
 /// This allows you to manipulate outputs and get their properties.
@@ -261,6 +587,34 @@ impl From<Transform> for output::v1::Transform {
     }
 }
 
+/// How windows on an output are arranged.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum OutputLayoutMode {
+    /// Windows tile according to their own layout mode.
+    #[default]
+    Tiling,
+    /// Every window floats and no layout is requested.
+    Floating,
+}
+
+/// A filter applied to everything drawn on an output.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ColorFilter {
+    /// No filter.
+    #[default]
+    None,
+    /// Desaturates everything.
+    Grayscale,
+    /// Shifts colors to help people with protanopia (red-blindness).
+    Protanopia,
+    /// Shifts colors to help people with deuteranopia (green-blindness).
+    Deuteranopia,
+    /// Shifts colors to help people with tritanopia (blue-blindness).
+    Tritanopia,
+    /// Inverts all colors.
+    Invert,
+}
+
 /// The state of variable refresh rate on an output.
 #[doc(alias = "AdaptiveSync")]
 #[doc(alias = "VariableRefreshRate")]
@@ -273,9 +627,76 @@ pub enum Vrr {
     AlwaysOn,
     /// Variable refresh rate should be on when a window with an
     /// active [`VrrDemand`](crate::window::VrrDemand) is visible.
+    ///
+    /// Windows can also be given a demand through [`set_vrr_demand_matcher`].
     OnDemand,
 }
 
+/// The kind of content a window is displaying, as hinted by its client.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ContentType {
+    /// The window has no specific content type.
+    None,
+    /// The window is displaying photos.
+    Photo,
+    /// The window is displaying video.
+    Video,
+    /// The window is displaying a game.
+    Game,
+}
+
+impl From<ContentType> for output::v1::ContentType {
+    fn from(value: ContentType) -> Self {
+        match value {
+            ContentType::None => output::v1::ContentType::None,
+            ContentType::Photo => output::v1::ContentType::Photo,
+            ContentType::Video => output::v1::ContentType::Video,
+            ContentType::Game => output::v1::ContentType::Game,
+        }
+    }
+}
+
+/// A matcher that gives windows a [`VrrDemand`](crate::window::VrrDemand)
+/// without one being set explicitly.
+///
+/// All set conditions must match for a window to demand vrr.
+/// An empty matcher matches all windows.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct VrrDemandMatcher {
+    /// A regex the window's app id must match.
+    pub app_id: Option<String>,
+    /// The content types the window must have one of. If empty, all content types match.
+    pub content_types: Vec<ContentType>,
+    /// Whether the window must be fullscreen for vrr to turn on.
+    pub fullscreen: bool,
+}
+
+impl VrrDemandMatcher {
+    /// Creates an empty [`VrrDemandMatcher`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Requires windows to have an app id matching the given regex.
+    pub fn app_id(mut self, regex: impl ToString) -> Self {
+        self.app_id = Some(regex.to_string());
+        self
+    }
+
+    /// Requires windows to have one of the given content types.
+    pub fn content_types(mut self, content_types: impl IntoIterator<Item = ContentType>) -> Self {
+        self.content_types = content_types.into_iter().collect();
+        self
+    }
+
+    /// Sets whether windows must be fullscreen for vrr to turn on.
+    pub fn fullscreen(mut self, fullscreen: bool) -> Self {
+        self.fullscreen = fullscreen;
+        self
+    }
+}
+
 impl OutputHandle {
     /// Creates an output handle from a name.
     pub fn from_name(name: impl ToString) -> Self {
@@ -472,6 +893,51 @@ impl OutputHandle {
             .unwrap();
     }
 
+    /// Sets this output's mode, going back to the previous mode after `revert_after`
+    /// unless [`output::confirm_mode`] is called first.
+    ///
+    /// Use this like a display settings dialog would, so a mode that leaves the screen
+    /// black undoes itself. Trying another mode before confirming still goes back to
+    /// the mode from before the first try. If the output is disconnected in the meantime,
+    /// nothing is reverted.
+    ///
+    /// The mode is picked like in [`set_mode`][Self::set_mode], but unsupported modes
+    /// are an error instead of being ignored.
+    ///
+    /// [`output::confirm_mode`]: confirm_mode
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # use std::time::Duration;
+    /// # || {
+    /// output::get_focused()?.try_mode(2560, 1440, 144000, Duration::from_secs(15));
+    ///
+    /// // Later, once the user confirms that they can see things
+    /// output::confirm_mode();
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn try_mode(
+        &self,
+        width: u32,
+        height: u32,
+        refresh_rate_mhz: impl Into<Option<u32>>,
+        revert_after: Duration,
+    ) -> Result<(), TryModeError> {
+        Client::output()
+            .try_mode(TryModeRequest {
+                output_name: self.name(),
+                size: Some(pinnacle_api_defs::pinnacle::util::v1::Size { width, height }),
+                refresh_rate_mhz: refresh_rate_mhz.into(),
+                revert_after_ms: revert_after.as_millis() as u32,
+            })
+            .block_on_tokio()
+            .map(|_| ())
+            .map_err(|status| TryModeError(status.message().to_string()))
+    }
+
     /// Sets this output's mode to a custom one.
     ///
     /// If `refresh_rate_mhz` is provided, Pinnacle will create a new mode with that refresh rate.
@@ -534,6 +1000,52 @@ impl OutputHandle {
             .unwrap();
     }
 
+    /// Creates a user-defined mode with CVT timings and adds it to this output's [`modes`].
+    ///
+    /// Once added, the mode can be selected with [`set_mode`].
+    ///
+    /// The refresh rate should be given in millihertz.
+    ///
+    /// This is only supported on the udev backend. It fails if the mode is outside
+    /// the limits of the output's GPU or the driver rejects it in a test commit.
+    /// Added modes are kept if the output is disconnected and reconnected.
+    ///
+    /// [`modes`]: OutputHandle::modes
+    /// [`set_mode`]: OutputHandle::set_mode
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # use pinnacle_api::output::CustomModeFlags;
+    /// # || {
+    /// let output = output::get_focused()?;
+    /// output
+    ///     .add_custom_mode(2560, 1080, 75000, CustomModeFlags::REDUCED_BLANKING)
+    ///     .ok()?;
+    /// output.set_mode(2560, 1080, 75000);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn add_custom_mode(
+        &self,
+        width: u32,
+        height: u32,
+        refresh_rate_mhz: u32,
+        flags: CustomModeFlags,
+    ) -> Result<(), CustomModeError> {
+        Client::output()
+            .add_custom_mode(AddCustomModeRequest {
+                output_name: self.name(),
+                size: Some(pinnacle_api_defs::pinnacle::util::v1::Size { width, height }),
+                refresh_rate_mhz,
+                reduced_blanking: flags.contains(CustomModeFlags::REDUCED_BLANKING),
+            })
+            .block_on_tokio()
+            .map(|_| ())
+            .map_err(|status| CustomModeError(status.message().to_string()))
+    }
+
     /// Sets this output's scaling factor.
     pub fn set_scale(&self, scale: f32) {
         Client::output()
@@ -624,6 +1136,158 @@ impl OutputHandle {
             .unwrap();
     }
 
+    /// Powers off this output after `timeout` has passed without any input,
+    /// overriding the timeout from [`set_dpms_timeout`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # use std::time::Duration;
+    /// # || {
+    /// output::get_focused()?.set_dpms_timeout(Duration::from_secs(60));
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_dpms_timeout(&self, timeout: Duration) {
+        Client::output()
+            .set_dpms_timeout(SetDpmsTimeoutRequest {
+                output_name: Some(self.name()),
+                timeout_ms: Some(timeout.as_millis().try_into().unwrap_or(u32::MAX)),
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Makes this output use the timeout from [`set_dpms_timeout`]
+    /// again.
+    pub fn unset_dpms_timeout(&self) {
+        Client::output()
+            .set_dpms_timeout(SetDpmsTimeoutRequest {
+                output_name: Some(self.name()),
+                timeout_ms: None,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Blanks or unblanks this output.
+    ///
+    /// Blanked outputs display a black frame but stay powered, which is useful for
+    /// screensavers. While the session is locked, the lock screen is shown instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// output::get_focused()?.set_blanked(true);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_blanked(&self, blanked: bool) {
+        Client::output()
+            .set_blanked(SetBlankedRequest {
+                output_name: self.name(),
+                blanked,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Sets whether windows on this output are laid out or all float.
+    ///
+    /// Switching to [`OutputLayoutMode::Floating`] floats every tiled window where it is.
+    /// Switching back to [`OutputLayoutMode::Tiling`] tiles those windows again.
+    /// Windows that were already floating stay floating.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # use pinnacle_api::output::OutputLayoutMode;
+    /// # || {
+    /// output::get_by_name("HDMI-1")?.set_layout_mode(OutputLayoutMode::Floating);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_layout_mode(&self, layout_mode: OutputLayoutMode) {
+        Client::output()
+            .set_layout_mode(SetLayoutModeRequest {
+                output_name: self.name(),
+                layout_mode: match layout_mode {
+                    OutputLayoutMode::Tiling => output::v1::LayoutMode::Tiling,
+                    OutputLayoutMode::Floating => output::v1::LayoutMode::Floating,
+                } as i32,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Sets how many times larger than its mode this output is rendered before
+    /// being downscaled.
+    ///
+    /// Supersampling smooths out fractional scales and rotated outputs, which is nice
+    /// for screenshots and recordings, but it's expensive: a factor of 2.0 renders
+    /// four times as many pixels. A factor of 1.0 disables it.
+    ///
+    /// The factor is clamped to at most 4.0 and lowered further for outputs
+    /// that would need an overly large render target.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is less than 1.0 or isn't finite.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// output::get_focused()?.set_supersample_factor(2.0);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_supersample_factor(&self, factor: f32) {
+        Client::output()
+            .set_supersample_factor(SetSupersampleFactorRequest {
+                output_name: self.name(),
+                factor,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Sets the color filter everything on this output is drawn through.
+    ///
+    /// The filter also applies to screencopies of this output.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # use pinnacle_api::output::ColorFilter;
+    /// # || {
+    /// output::get_focused()?.set_color_filter(ColorFilter::Grayscale);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_color_filter(&self, color_filter: ColorFilter) {
+        Client::output()
+            .set_color_filter(SetColorFilterRequest {
+                output_name: self.name(),
+                color_filter: match color_filter {
+                    ColorFilter::None => output::v1::ColorFilter::None,
+                    ColorFilter::Grayscale => output::v1::ColorFilter::Grayscale,
+                    ColorFilter::Protanopia => output::v1::ColorFilter::Protanopia,
+                    ColorFilter::Deuteranopia => output::v1::ColorFilter::Deuteranopia,
+                    ColorFilter::Tritanopia => output::v1::ColorFilter::Tritanopia,
+                    ColorFilter::Invert => output::v1::ColorFilter::Invert,
+                } as i32,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
     /// Sets the variable refresh rate state of this output.
     ///
     /// See [`Vrr`] for possible states and their behaviors.
@@ -643,6 +1307,61 @@ impl OutputHandle {
             .unwrap();
     }
 
+    /// Turns game mode on this output on or off.
+    ///
+    /// Game mode forces vrr on if the output supports it, stops compositor-driven
+    /// animations on this output, and skips rendering windows covered by an opaque
+    /// fullscreen window. Turning it off restores the previous [`Vrr`] setting.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// output::get_focused()?.set_game_mode(true);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_game_mode(&self, enabled: bool) {
+        Client::output()
+            .set_game_mode(SetGameModeRequest {
+                output_name: self.name(),
+                enabled,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Loads a calibration LUT and sets it as this output's gamma ramps.
+    ///
+    /// `path` can be a 1D LUT in the `.cube` format, a headerless file with one `r g b` row
+    /// of values from 0.0 to 1.0 per line, or an ICC profile with a `vcgt` tag.
+    /// The LUT is resampled to the output's gamma size.
+    ///
+    /// This fails if the file can't be parsed or the output doesn't support setting gamma.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// output::get_focused()?
+    ///     .set_gamma_from_file("/usr/share/color/icc/calibrated.icc")
+    ///     .ok()?;
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_gamma_from_file(&self, path: impl AsRef<Path>) -> Result<(), GammaError> {
+        Client::output()
+            .set_gamma_from_file(SetGammaFromFileRequest {
+                output_name: self.name(),
+                path: path.as_ref().to_string_lossy().into_owned(),
+            })
+            .block_on_tokio()
+            .map(|_| ())
+            .map_err(|status| GammaError(status.message().to_string()))
+    }
+
     /// Focuses this output.
     pub fn focus(&self) {
         Client::output()
@@ -653,6 +1372,104 @@ impl OutputHandle {
             .unwrap();
     }
 
+    /// Shows the tags with the given names on this output and hides all others.
+    ///
+    /// Unlike activating tags one by one, this only lays out the output once.
+    /// Names that don't match a tag on this output are ignored, and if none match,
+    /// nothing happens.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// // View tags "1" and "2" at the same time
+    /// output::get_focused()?.view_tags(["1", "2"]);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn view_tags(&self, names: impl IntoIterator<Item = impl ToString>) {
+        Client::output()
+            .view_tags(ViewTagsRequest {
+                output_name: self.name(),
+                tag_names: names.into_iter().map(|name| name.to_string()).collect(),
+                keep_active: false,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Shows the tag with the given name on this output in addition to the tags already shown.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// output::get_focused()?.add_tag_to_view("3");
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn add_tag_to_view(&self, name: impl ToString) {
+        Client::output()
+            .view_tags(ViewTagsRequest {
+                output_name: self.name(),
+                tag_names: vec![name.to_string()],
+                keep_active: true,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Overrides the make, model, and serial this output's display reports.
+    ///
+    /// Some displays report garbage or identical identities, which breaks telling them apart.
+    /// With an override, [`make`](Self::make), [`model`](Self::model), and
+    /// [`serial`](Self::serial) return the overridden values, and Pinnacle uses them to restore
+    /// the display's location, scale, and tags when it's reconnected.
+    ///
+    /// The override belongs to this output's connector, so it stays in effect
+    /// when displays are unplugged and plugged back in.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// output::get_by_name("DP-1")?.set_identity_override("Dell", "U2720Q", "ABC123");
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_identity_override(
+        &self,
+        make: impl ToString,
+        model: impl ToString,
+        serial: impl ToString,
+    ) {
+        Client::output()
+            .set_identity_override(SetIdentityOverrideRequest {
+                output_name: self.name(),
+                make: Some(make.to_string()),
+                model: Some(model.to_string()),
+                serial: Some(serial.to_string()),
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Removes the identity override set with [`set_identity_override`](Self::set_identity_override).
+    pub fn clear_identity_override(&self) {
+        Client::output()
+            .set_identity_override(SetIdentityOverrideRequest {
+                output_name: self.name(),
+                make: None,
+                model: None,
+                serial: None,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
     /// Gets this output's make.
     pub fn make(&self) -> String {
         self.make_async().block_on_tokio()
@@ -778,6 +1595,50 @@ impl OutputHandle {
             })
     }
 
+    /// Gets the refresh rate of this output's current mode, in millihertz.
+    ///
+    /// For example, 60Hz is returned as 60000.
+    ///
+    /// Returns `None` if the output is disabled or doesn't exist.
+    pub fn refresh_rate(&self) -> Option<u32> {
+        self.refresh_rate_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::refresh_rate`].
+    pub async fn refresh_rate_async(&self) -> Option<u32> {
+        Client::output()
+            .get_present_timing(GetPresentTimingRequest {
+                output_name: self.name(),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .refresh_rate_mhz
+    }
+
+    /// Gets how long ago a frame was last presented on this output.
+    ///
+    /// Along with [`Self::refresh_rate`], this can be used to sync animations to the display.
+    ///
+    /// Returns `None` if no frame has been presented yet or the backend
+    /// doesn't track presentation.
+    pub fn time_since_last_present(&self) -> Option<Duration> {
+        self.time_since_last_present_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::time_since_last_present`].
+    pub async fn time_since_last_present_async(&self) -> Option<Duration> {
+        Client::output()
+            .get_present_timing(GetPresentTimingRequest {
+                output_name: self.name(),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .ms_since_last_presentation
+            .map(Duration::from_millis)
+    }
+
     /// Gets this output's preferred mode.
     ///
     /// May return `None` if it is disabled.
@@ -891,6 +1752,32 @@ impl OutputHandle {
             .map(|id| TagHandle { id })
     }
 
+    /// Gets a handle to the first tag with the given `name` on this output.
+    ///
+    /// Unlike [`tag::get`][crate::tag::get], this only looks at this output's tags,
+    /// so outputs that have tags with the same names can be told apart.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// let tag_on_hdmi = output::get_by_name("HDMI-1")?.tag("1")?;
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn tag(&self, name: impl ToString) -> Option<TagHandle> {
+        self.tag_async(name).block_on_tokio()
+    }
+
+    /// Async impl for [`Self::tag`].
+    pub async fn tag_async(&self, name: impl ToString) -> Option<TagHandle> {
+        let name = name.to_string();
+        self.tags_async()
+            .await
+            .batch_find(|tag| tag.name_async().boxed(), |n| *n == name)
+    }
+
     /// Gets handles to all active tags on this output.
     pub fn active_tags(&self) -> impl Iterator<Item = TagHandle> + use<> {
         self.active_tags_async().block_on_tokio()
@@ -1035,6 +1922,23 @@ impl OutputHandle {
             .powered
     }
 
+    /// Gets whether or not this output was blanked with [`Self::set_blanked`].
+    pub fn blanked(&self) -> bool {
+        self.blanked_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::blanked`].
+    pub async fn blanked_async(&self) -> bool {
+        Client::output()
+            .get_blanked(GetBlankedRequest {
+                output_name: self.name(),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .blanked
+    }
+
     /// Gets all outputs in the provided direction, sorted closest to farthest.
     pub fn in_direction(&self, direction: Direction) -> impl Iterator<Item = OutputHandle> + use<> {
         self.in_direction_async(direction).block_on_tokio()
@@ -1071,6 +1975,32 @@ impl OutputHandle {
             .map(OutputHandle::from_name)
     }
 
+    /// Renders a thumbnail of this output that fits within `max_width` by `max_height`.
+    ///
+    /// See [`render::output_thumbnail`][crate::render::output_thumbnail] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// let thumbnail = output::get_focused()?.snapshot(320, 180).ok()?;
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn snapshot(&self, max_width: u32, max_height: u32) -> Result<Screenshot, ScreenshotError> {
+        self.snapshot_async(max_width, max_height).block_on_tokio()
+    }
+
+    /// Async impl for [`Self::snapshot`].
+    pub async fn snapshot_async(
+        &self,
+        max_width: u32,
+        max_height: u32,
+    ) -> Result<Screenshot, ScreenshotError> {
+        crate::render::output_thumbnail_async(self, max_width, max_height).await
+    }
+
     /// Returns this output's unique name (the name of its connector).
     pub fn name(&self) -> String {
         self.name.to_string()
@@ -1123,6 +2053,53 @@ impl From<Modeline> for output::v1::Modeline {
     }
 }
 
+/// Error returned when gamma couldn't be set from a file.
+#[derive(Debug, Clone)]
+pub struct GammaError(String);
+
+impl std::fmt::Display for GammaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for GammaError {}
+
+bitflags::bitflags! {
+    /// Options for [`OutputHandle::add_custom_mode`].
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+    pub struct CustomModeFlags: u32 {
+        /// Use CVT reduced blanking timings, which lower the pixel clock.
+        ///
+        /// Useful for high resolutions and refresh rates on bandwidth-limited links.
+        const REDUCED_BLANKING = 1;
+    }
+}
+
+/// Error returned when a custom mode couldn't be added.
+#[derive(Debug, Clone)]
+pub struct CustomModeError(String);
+
+impl std::fmt::Display for CustomModeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for CustomModeError {}
+
+/// Error returned when a mode couldn't be tried.
+#[derive(Debug, Clone)]
+pub struct TryModeError(String);
+
+impl std::fmt::Display for TryModeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for TryModeError {}
+
 /// Error for the `FromStr` implementation for [`Modeline`].
 #[derive(Debug)]
 pub struct ParseModelineError(ParseModelineErrorKind);
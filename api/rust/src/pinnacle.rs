@@ -9,13 +9,18 @@
 use pinnacle_api_defs::pinnacle::{
     self,
     v1::{
-        BackendRequest, KeepaliveRequest, KeepaliveResponse, QuitRequest, ReloadConfigRequest,
-        SetLastErrorRequest, SetXwaylandClientSelfScaleRequest, TakeLastErrorRequest,
+        BackendRequest, KeepaliveRequest, KeepaliveResponse, MetricsRequest, QuitRequest,
+        ReloadConfigRequest, RendererInfoRequest, SetLastErrorRequest,
+        SetPreserveLayoutOnReloadRequest, SetXwaylandClientSelfScaleRequest, TakeLastErrorRequest,
     },
 };
 use tonic::Streaming;
 
-use crate::{BlockOnTokio, client::Client};
+use crate::{
+    BlockOnTokio,
+    client::Client,
+    signal::{PinnacleSignal, SignalHandle},
+};
 
 /// A backend that Pinnacle runs with.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -57,6 +62,120 @@ pub fn backend() -> Backend {
     }
 }
 
+/// Information about Pinnacle's renderer.
+///
+/// Useful for bug reports and triaging rendering issues.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RendererInfo {
+    /// The `GL_RENDERER` string.
+    pub renderer: String,
+    /// The `GL_VENDOR` string.
+    pub vendor: String,
+    /// The `GL_VERSION` string.
+    pub version: String,
+    /// The device path of the gpu that is rendering, if known.
+    ///
+    /// On a tty this is the primary gpu.
+    pub gpu_path: Option<String>,
+    /// The dmabuf formats the renderer can import.
+    pub dmabuf_formats: Vec<DmabufFormat>,
+    /// Why hardware acceleration failed to initialize, if it did.
+    ///
+    /// If this is set, Pinnacle is running in a degraded mode where clients
+    /// can only share buffers through shared memory.
+    pub hardware_accel_error: Option<String>,
+}
+
+/// A dmabuf format and modifier pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DmabufFormat {
+    /// The DRM fourcc code.
+    pub fourcc: u32,
+    /// The DRM format modifier.
+    pub modifier: u64,
+}
+
+/// Gets information about the renderer.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::pinnacle;
+/// let info = pinnacle::renderer_info();
+/// println!("Rendering with {} from {}", info.renderer, info.vendor);
+/// ```
+pub fn renderer_info() -> RendererInfo {
+    let response = Client::pinnacle()
+        .renderer_info(RendererInfoRequest {})
+        .block_on_tokio()
+        .unwrap()
+        .into_inner();
+
+    RendererInfo {
+        renderer: response.renderer,
+        vendor: response.vendor,
+        version: response.version,
+        gpu_path: response.gpu_path,
+        dmabuf_formats: response
+            .dmabuf_formats
+            .into_iter()
+            .map(|format| DmabufFormat {
+                fourcc: format.fourcc,
+                modifier: format.modifier,
+            })
+            .collect(),
+        hardware_accel_error: response.hardware_accel_error,
+    }
+}
+
+/// Internal counters of Pinnacle, for diagnosing leaks and load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Metrics {
+    /// The number of open windows.
+    pub windows: u32,
+    /// The number of connected outputs.
+    pub outputs: u32,
+    /// The number of frames rendered and submitted across all outputs.
+    pub frames_rendered: u64,
+    /// The number of render attempts across all outputs that didn't happen or failed.
+    pub frames_skipped: u64,
+    /// The number of gRPC streams that are currently open.
+    ///
+    /// This includes streams used by signals and layout managers.
+    pub active_grpc_streams: u32,
+    /// The number of times the config was restarted.
+    pub config_restarts: u64,
+    /// The resident memory usage of Pinnacle in bytes, if known.
+    pub memory_usage: Option<u64>,
+}
+
+/// Gets Pinnacle's internal counters.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::pinnacle;
+/// let metrics = pinnacle::metrics();
+/// println!("{} windows open", metrics.windows);
+/// ```
+pub fn metrics() -> Metrics {
+    let response = Client::pinnacle()
+        .metrics(MetricsRequest {})
+        .block_on_tokio()
+        .unwrap()
+        .into_inner();
+
+    Metrics {
+        windows: response.windows,
+        outputs: response.outputs,
+        frames_rendered: response.frames_rendered,
+        frames_skipped: response.frames_skipped,
+        active_grpc_streams: response.active_grpc_streams,
+        config_restarts: response.config_restarts,
+        memory_usage: response.memory_usage,
+    }
+}
+
 /// Sets whether or not xwayland clients should scale themselves.
 ///
 /// If `true`, xwayland clients will be told they are on an output with a larger or smaller size than
@@ -76,6 +195,28 @@ pub fn set_xwayland_self_scaling(should_self_scale: bool) {
         .unwrap();
 }
 
+/// Sets whether window tags and floating geometries are restored after a config reload.
+///
+/// Before the config reloads, every window's tags are saved by name along with its
+/// floating geometry. Once the new config adds tags with those names, windows are put
+/// back on them. Windows that closed during the reload are matched to another window
+/// with the same app id and title.
+///
+/// This is reset on reload, so the new config has to set it again to keep it on.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::pinnacle;
+/// pinnacle::set_preserve_layout_on_reload(true);
+/// ```
+pub fn set_preserve_layout_on_reload(preserve: bool) {
+    Client::pinnacle()
+        .set_preserve_layout_on_reload(SetPreserveLayoutOnReloadRequest { preserve })
+        .block_on_tokio()
+        .unwrap();
+}
+
 /// Sets an error message that is held by the compositor until it is retrieved.
 pub fn set_last_error(error: impl std::fmt::Display) {
     Client::pinnacle()
@@ -97,6 +238,32 @@ pub fn take_last_error() -> Option<String> {
         .error
 }
 
+/// Connects to a [`PinnacleSignal`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::pinnacle;
+/// # use pinnacle_api::signal::PinnacleSignal;
+/// pinnacle::connect_signal(PinnacleSignal::PrepareForSleep(Box::new(|sleeping| {
+///     if sleeping {
+///         println!("Going to sleep");
+///     }
+/// })));
+/// ```
+pub fn connect_signal(signal: PinnacleSignal) -> SignalHandle {
+    let mut signal_state = Client::signal_state();
+
+    match signal {
+        PinnacleSignal::PrepareForSleep(f) => {
+            signal_state.pinnacle_prepare_for_sleep.add_callback(f)
+        }
+        PinnacleSignal::OutputLayoutChanged(f) => {
+            signal_state.pinnacle_output_layout_changed.add_callback(f)
+        }
+    }
+}
+
 pub(crate) async fn keepalive() -> (
     tokio::sync::mpsc::Sender<KeepaliveRequest>,
     Streaming<KeepaliveResponse>,
@@ -12,7 +12,9 @@ use std::{
 };
 
 use passfd::FdPassingExt;
-use pinnacle_api_defs::pinnacle::process::v1::{SetEnvRequest, SpawnRequest, WaitOnSpawnRequest};
+use pinnacle_api_defs::pinnacle::process::v1::{
+    GetChildrenRequest, ReapRequest, SetEnvRequest, SpawnRequest, WaitOnSpawnRequest,
+};
 use tokio_stream::StreamExt;
 
 use crate::{BlockOnTokio, client::Client};
@@ -68,6 +70,11 @@ pub struct ExitInfo {
 }
 
 impl Child {
+    /// Returns this process's pid.
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
     /// Waits for this process to exit, blocking the current thread.
     pub fn wait(self) -> ExitInfo {
         self.wait_async().block_on_tokio()
@@ -75,25 +82,108 @@ impl Child {
 
     /// Async impl for [`Self::wait`].
     pub async fn wait_async(self) -> ExitInfo {
-        let mut exit_status = Client::process()
-            .wait_on_spawn(WaitOnSpawnRequest { pid: self.pid })
-            .await
-            .unwrap()
-            .into_inner();
+        wait_on_pid(self.pid).await
+    }
+}
 
-        let thing = exit_status.next().await;
+async fn wait_on_pid(pid: u32) -> ExitInfo {
+    let mut exit_status = Client::process()
+        .wait_on_spawn(WaitOnSpawnRequest { pid })
+        .await
+        .unwrap()
+        .into_inner();
 
-        let Some(Ok(response)) = thing else {
-            return Default::default();
-        };
+    let thing = exit_status.next().await;
 
-        ExitInfo {
-            exit_code: response.exit_code,
-            exit_msg: response.exit_msg,
-        }
+    let Some(Ok(response)) = thing else {
+        return Default::default();
+    };
+
+    ExitInfo {
+        exit_code: response.exit_code,
+        exit_msg: response.exit_msg,
     }
 }
 
+/// Runs a closure when the spawned process with the given `pid` exits.
+///
+/// This works for any process spawned through a [`Command`], including ones
+/// spawned by a previous config before a reload. If the process isn't known
+/// or has already been cleaned up, `on_exit` is called with an empty [`ExitInfo`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::process;
+/// # use pinnacle_api::process::Command;
+/// # || {
+/// let child = Command::new("waybar").spawn()?;
+///
+/// process::on_exit(child.pid(), |exit_info| {
+///     println!("waybar exited with {:?}", exit_info.exit_code);
+/// });
+/// # Some(())
+/// # };
+/// ```
+pub fn on_exit(pid: u32, on_exit: impl FnOnce(ExitInfo) + Send + 'static) {
+    tokio::spawn(async move {
+        on_exit(wait_on_pid(pid).await);
+    });
+}
+
+/// The status of a process spawned through a [`Command`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChildStatus {
+    /// The process's pid.
+    pub pid: u32,
+    /// Whether the process hasn't exited yet.
+    pub alive: bool,
+}
+
+/// Returns the processes spawned through a [`Command`] that are still tracked,
+/// ordered by pid.
+///
+/// Spawned processes are reaped as soon as they exit, so they never linger as zombies,
+/// but they stay tracked with `alive` set to false until they're waited on with
+/// [`on_exit`] or [`Child::wait`], or [`reap`] is called. Processes that daemonize exit once they fork,
+/// so they show up as not alive.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::process;
+/// for child in process::running_children() {
+///     println!("{} alive: {}", child.pid, child.alive);
+/// }
+/// ```
+pub fn running_children() -> Vec<ChildStatus> {
+    Client::process()
+        .get_children(GetChildrenRequest {})
+        .block_on_tokio()
+        .unwrap()
+        .into_inner()
+        .children
+        .into_iter()
+        .map(|child| ChildStatus {
+            pid: child.pid,
+            alive: child.alive,
+        })
+        .collect()
+}
+
+/// Stops tracking spawned processes that have exited, returning their pids.
+///
+/// Reaped processes can no longer be waited on, so [`on_exit`] and
+/// [`Child::wait`] get an empty [`ExitInfo`] for them.
+pub fn reap() -> Vec<u32> {
+    Client::process()
+        .reap(ReapRequest {})
+        .block_on_tokio()
+        .unwrap()
+        .into_inner()
+        .pids
+}
+
 impl Drop for Child {
     fn drop(&mut self) {
         let pid = self.pid;
@@ -1,11 +1,20 @@
 //! Rendering management.
 
-use pinnacle_api_defs::pinnacle::render::{
-    self,
-    v1::{SetDownscaleFilterRequest, SetUpscaleFilterRequest},
+use std::{path::Path, time::Duration};
+
+use pinnacle_api_defs::pinnacle::{
+    render::{
+        self,
+        v1::{
+            GetRenderStateRequest, OutputThumbnailRequest, ScreenshotWindowRequest,
+            SetAnimationFpsCapRequest, SetCursorModeRequest, SetDownscaleFilterRequest,
+            SetFrameCallbackPolicyRequest, SetOverviewRequest, SetUpscaleFilterRequest,
+        },
+    },
+    util::v1::SetOrToggle,
 };
 
-use crate::{BlockOnTokio, client::Client};
+use crate::{BlockOnTokio, client::Client, output::OutputHandle, window::WindowHandle};
 
 /// What filter to use when scaling.
 pub enum ScalingFilter {
@@ -63,3 +72,394 @@ pub fn set_downscale_filter(filter: ScalingFilter) {
         .block_on_tokio()
         .unwrap();
 }
+
+/// Limits how many frames per second compositor-driven animations get.
+///
+/// This throttles animations like output transitions and animated cursors,
+/// which can save GPU and CPU time on low-end hardware. Clients and input still
+/// render as soon as they need to.
+///
+/// Passing `0` disables animations entirely. Passing `None` removes the cap,
+/// which is the default.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::render;
+/// render::set_animation_fps_cap(30);
+/// ```
+pub fn set_animation_fps_cap(fps_cap: impl Into<Option<u32>>) {
+    Client::render()
+        .set_animation_fps_cap(SetAnimationFpsCapRequest {
+            fps_cap: fps_cap.into(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// How windows that aren't displayed, like ones on inactive tags, get frame callbacks.
+///
+/// Frame callbacks tell clients when to draw their next frame. Clients that don't get
+/// them stop animating, which saves power but can freeze things like clocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameCallbackPolicy {
+    /// Hidden windows get a frame callback once per interval.
+    Throttle(Duration),
+    /// Hidden windows get frame callbacks as often as displayed windows on their output.
+    Always,
+    /// Hidden windows get no frame callbacks.
+    Never,
+}
+
+impl Default for FrameCallbackPolicy {
+    fn default() -> Self {
+        Self::Throttle(Duration::from_secs(1))
+    }
+}
+
+/// Sets how windows that aren't displayed, like ones on inactive tags or minimized ones,
+/// get frame callbacks.
+///
+/// This defaults to one frame callback per second.
+///
+/// # Panics
+///
+/// Panics if the throttle interval is shorter than a millisecond.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::render;
+/// # use pinnacle_api::render::FrameCallbackPolicy;
+/// # use std::time::Duration;
+/// // Let hidden windows draw twice a second
+/// render::set_frame_callback_policy(FrameCallbackPolicy::Throttle(Duration::from_millis(500)));
+///
+/// // Save as much power as possible
+/// render::set_frame_callback_policy(FrameCallbackPolicy::Never);
+/// ```
+pub fn set_frame_callback_policy(policy: FrameCallbackPolicy) {
+    let (policy, throttle_interval_ms) = match policy {
+        FrameCallbackPolicy::Throttle(interval) => (
+            render::v1::FrameCallbackPolicy::Throttle,
+            interval.as_millis().min(u32::MAX as u128) as u32,
+        ),
+        FrameCallbackPolicy::Always => (render::v1::FrameCallbackPolicy::Always, 0),
+        FrameCallbackPolicy::Never => (render::v1::FrameCallbackPolicy::Never, 0),
+    };
+
+    Client::render()
+        .set_frame_callback_policy(SetFrameCallbackPolicyRequest {
+            policy: policy.into(),
+            throttle_interval_ms,
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// How the cursor is put on screen.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CursorMode {
+    /// Scan the cursor out on a hardware cursor plane when possible.
+    #[default]
+    Plane,
+    /// Always composite the cursor into the frame.
+    Software,
+}
+
+impl From<CursorMode> for render::v1::CursorMode {
+    fn from(value: CursorMode) -> Self {
+        match value {
+            CursorMode::Plane => render::v1::CursorMode::Plane,
+            CursorMode::Software => render::v1::CursorMode::Software,
+        }
+    }
+}
+
+/// Sets whether the cursor may be scanned out on a hardware cursor plane.
+///
+/// Some drivers have buggy cursor planes that make the cursor flicker.
+/// [`CursorMode::Software`] works around this by always compositing the cursor.
+///
+/// This defaults to [`CursorMode::Plane`]. It only affects the udev backend;
+/// the winit backend always composites the cursor.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::render;
+/// # use pinnacle_api::render::CursorMode;
+/// render::set_cursor_mode(CursorMode::Software);
+/// ```
+pub fn set_cursor_mode(mode: CursorMode) {
+    Client::render()
+        .set_cursor_mode(SetCursorModeRequest {
+            mode: render::v1::CursorMode::from(mode).into(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Opens or closes the overview.
+///
+/// The overview shows every window on the focused output, across all of its tags,
+/// scaled down onto a grid. Clicking a window or selecting it with the arrow keys and
+/// pressing Return focuses it, switching to its tag if needed. Escape or clicking
+/// outside a window closes the overview without changing focus.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::render;
+/// render::set_overview(true);
+/// ```
+pub fn set_overview(open: bool) {
+    Client::render()
+        .set_overview(SetOverviewRequest {
+            set_or_toggle: match open {
+                true => SetOrToggle::Set,
+                false => SetOrToggle::Unset,
+            }
+            .into(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Toggles the overview.
+///
+/// See [`set_overview`] for details.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::input::{self, Mod};
+/// # use pinnacle_api::render;
+/// input::keybind(Mod::SUPER, 'w').on_press(render::toggle_overview);
+/// ```
+pub fn toggle_overview() {
+    Client::render()
+        .set_overview(SetOverviewRequest {
+            set_or_toggle: SetOrToggle::Toggle.into(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// A captured image of a window or output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Screenshot {
+    /// The width of the image in pixels.
+    pub width: u32,
+    /// The height of the image in pixels.
+    pub height: u32,
+    /// 8-bit RGBA pixel data, row by row from the top.
+    pub rgba: Vec<u8>,
+}
+
+/// Error returned when a screenshot couldn't be taken.
+#[derive(Debug, Clone)]
+pub struct ScreenshotError(String);
+
+impl std::fmt::Display for ScreenshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ScreenshotError {}
+
+/// Renders the given window, including its decorations, and saves it as a PNG to `path`.
+///
+/// The image is cropped to the window's bounds and rendered at the scale of the window's output.
+/// This fails if the window isn't mapped.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::render;
+/// # use pinnacle_api::window;
+/// # || {
+/// render::screenshot_window(&window::get_focused()?, "/tmp/focused.png").ok()?;
+/// # Some(())
+/// # };
+/// ```
+pub fn screenshot_window(
+    window: &WindowHandle,
+    path: impl AsRef<Path>,
+) -> Result<(), ScreenshotError> {
+    let path = path.as_ref().to_string_lossy().into_owned();
+
+    screenshot(window, Some(path)).block_on_tokio().map(|_| ())
+}
+
+/// Renders the given window, including its decorations, and returns the raw pixels.
+///
+/// This is useful for in-memory thumbnails.
+/// The image is cropped to the window's bounds and rendered at the scale of the window's output.
+/// This fails if the window isn't mapped.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::render;
+/// # use pinnacle_api::window;
+/// # || {
+/// let screenshot = render::screenshot_window_rgba(&window::get_focused()?).ok()?;
+/// println!("{}x{}", screenshot.width, screenshot.height);
+/// # Some(())
+/// # };
+/// ```
+pub fn screenshot_window_rgba(window: &WindowHandle) -> Result<Screenshot, ScreenshotError> {
+    screenshot_window_rgba_async(window).block_on_tokio()
+}
+
+/// Async impl for [`screenshot_window_rgba`].
+pub async fn screenshot_window_rgba_async(
+    window: &WindowHandle,
+) -> Result<Screenshot, ScreenshotError> {
+    screenshot(window, None).await
+}
+
+async fn screenshot(
+    window: &WindowHandle,
+    path: Option<String>,
+) -> Result<Screenshot, ScreenshotError> {
+    let response = Client::render()
+        .screenshot_window(ScreenshotWindowRequest {
+            window_id: window.id(),
+            path,
+        })
+        .await
+        .map_err(|status| ScreenshotError(status.message().to_string()))?
+        .into_inner();
+
+    Ok(Screenshot {
+        width: response.width,
+        height: response.height,
+        rgba: response.rgba,
+    })
+}
+
+/// Renders a scaled-down capture of the given output and returns the raw pixels.
+///
+/// The thumbnail fits within `max_width` by `max_height` while preserving the output's
+/// aspect ratio. It is never scaled up. The cursor isn't included.
+///
+/// Thumbnails are cached for a short time, so calling this repeatedly, like every frame
+/// in an output switcher, won't render the output every time.
+///
+/// This fails if the output doesn't exist or the session is locked.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::render;
+/// # use pinnacle_api::output;
+/// # || {
+/// let thumbnail = render::output_thumbnail(&output::get_focused()?, 320, 180).ok()?;
+/// println!("{}x{}", thumbnail.width, thumbnail.height);
+/// # Some(())
+/// # };
+/// ```
+pub fn output_thumbnail(
+    output: &OutputHandle,
+    max_width: u32,
+    max_height: u32,
+) -> Result<Screenshot, ScreenshotError> {
+    output_thumbnail_async(output, max_width, max_height).block_on_tokio()
+}
+
+/// Async impl for [`output_thumbnail`].
+pub async fn output_thumbnail_async(
+    output: &OutputHandle,
+    max_width: u32,
+    max_height: u32,
+) -> Result<Screenshot, ScreenshotError> {
+    let response = Client::render()
+        .output_thumbnail(OutputThumbnailRequest {
+            output_name: output.name(),
+            max_width,
+            max_height,
+        })
+        .await
+        .map_err(|status| ScreenshotError(status.message().to_string()))?
+        .into_inner();
+
+    Ok(Screenshot {
+        width: response.width,
+        height: response.height,
+        rgba: response.rgba,
+    })
+}
+
+/// The result of an attempt to render an output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RenderResult {
+    /// A frame was rendered and submitted.
+    Submitted,
+    /// Nothing changed, so no frame was submitted.
+    NoDamage,
+    /// Rendering didn't happen or failed.
+    Skipped,
+}
+
+/// Diagnostics about an output's rendering.
+///
+/// This is mainly useful for figuring out why an output has stopped updating.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RenderState {
+    /// The result of the last render attempt, or `None` if the output hasn't been rendered yet.
+    pub last_result: Option<RenderResult>,
+    /// How long ago a frame was last presented.
+    ///
+    /// This is `None` if no frame has been presented or the backend doesn't track this.
+    pub time_since_last_presentation: Option<Duration>,
+    /// Whether a render is currently scheduled.
+    pub render_scheduled: bool,
+}
+
+/// Gets diagnostics about the given output's rendering.
+///
+/// Returns `None` if the output doesn't exist.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::render;
+/// # use pinnacle_api::output;
+/// # || {
+/// let state = render::render_state(&output::get_focused()?)?;
+/// println!("{:?}", state.last_result);
+/// # Some(())
+/// # };
+/// ```
+pub fn render_state(output: &OutputHandle) -> Option<RenderState> {
+    render_state_async(output).block_on_tokio()
+}
+
+/// Async impl for [`render_state`].
+pub async fn render_state_async(output: &OutputHandle) -> Option<RenderState> {
+    let response = Client::render()
+        .get_render_state(GetRenderStateRequest {
+            output_name: output.name(),
+        })
+        .await
+        .ok()?
+        .into_inner();
+
+    let last_result = match response.last_result() {
+        render::v1::RenderResult::Unspecified => None,
+        render::v1::RenderResult::Submitted => Some(RenderResult::Submitted),
+        render::v1::RenderResult::NoDamage => Some(RenderResult::NoDamage),
+        render::v1::RenderResult::Skipped => Some(RenderResult::Skipped),
+    };
+
+    Some(RenderState {
+        last_result,
+        time_since_last_presentation: response
+            .ms_since_last_presentation
+            .map(Duration::from_millis),
+        render_scheduled: response.render_scheduled,
+    })
+}
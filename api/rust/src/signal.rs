@@ -26,8 +26,12 @@ use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
 use tonic::Streaming;
 
 use crate::{
-    BlockOnTokio, input::libinput::DeviceHandle, output::OutputHandle, tag::TagHandle,
-    window::WindowHandle,
+    BlockOnTokio,
+    input::libinput::DeviceHandle,
+    output::OutputHandle,
+    tag::TagHandle,
+    util::{Point, Rect, Size},
+    window::{LayoutMode, WindowHandle},
 };
 
 pub(crate) trait Signal {
@@ -155,7 +159,10 @@ signals! {
                 }
             },
         }
-        /// An output's logical size changed.
+        /// An output's mode, scale, or transform changed, which may have changed
+        /// its logical size.
+        ///
+        /// This fires after the change is fully applied.
         ///
         /// Callbacks receive the output and new width and height.
         OutputResize = {
@@ -294,6 +301,67 @@ signals! {
                 }
             },
         }
+        /// A window was marked as wanting attention or stopped wanting it.
+        ///
+        /// Callbacks receive the window and whether it is now urgent.
+        WindowUrgentChanged = {
+            enum_name = UrgentChanged,
+            callback_type = Box<dyn FnMut(&WindowHandle, bool) + Send + 'static>,
+            client_request = window_urgent_changed,
+            on_response = |response, callbacks| {
+                let handle = WindowHandle { id: response.window_id };
+
+                for callback in callbacks {
+                    callback(&handle, response.urgent);
+                }
+            },
+        }
+        /// A window's layout mode changed.
+        ///
+        /// Callbacks receive the window and its new layout mode.
+        WindowLayoutModeChanged = {
+            enum_name = LayoutModeChanged,
+            callback_type = Box<dyn FnMut(&WindowHandle, LayoutMode) + Send + 'static>,
+            client_request = window_layout_mode_changed,
+            on_response = |response, callbacks| {
+                let handle = WindowHandle { id: response.window_id };
+                let layout_mode = response
+                    .layout_mode()
+                    .try_into()
+                    .unwrap_or(LayoutMode::Tiled);
+
+                for callback in callbacks {
+                    callback(&handle, layout_mode);
+                }
+            },
+        }
+        /// A window's geometry changed.
+        ///
+        /// This fires after a layout, [`set_geometry`], or an interactive move or resize.
+        /// Interactive moves and resizes only fire once they end.
+        ///
+        /// Callbacks receive the window and its new geometry.
+        ///
+        /// [`set_geometry`]: crate::window::WindowHandle::set_geometry
+        WindowGeometryChanged = {
+            enum_name = GeometryChanged,
+            callback_type = Box<dyn FnMut(&WindowHandle, Rect) + Send + 'static>,
+            client_request = window_geometry_changed,
+            on_response = |response, callbacks| {
+                let handle = WindowHandle { id: response.window_id };
+                let geometry = response.geometry.unwrap_or_default();
+                let loc = geometry.loc.unwrap_or_default();
+                let size = geometry.size.unwrap_or_default();
+                let geometry = Rect {
+                    loc: Point { x: loc.x, y: loc.y },
+                    size: Size { w: size.width, h: size.height },
+                };
+
+                for callback in callbacks {
+                    callback(&handle, geometry);
+                }
+            },
+        }
     }
     /// Signals relating to tag events.
     TagSignal => {
@@ -310,6 +378,22 @@ signals! {
                 }
             },
         }
+        /// The number of windows on a tag changed.
+        ///
+        /// Windows on multiple tags are counted for each tag.
+        /// Bursts of window changes only fire this once with the final count.
+        TagWindowCountChanged = {
+            enum_name = WindowCountChanged,
+            callback_type = Box<dyn FnMut(&TagHandle, u32) + Send + 'static>,
+            client_request = tag_window_count_changed,
+            on_response = |response, callbacks| {
+                let handle = TagHandle { id: response.tag_id };
+
+                for callback in callbacks {
+                    callback(&handle, response.count);
+                }
+            },
+        }
     }
     /// Signals relating to input events.
     InputSignal => {
@@ -327,6 +411,39 @@ signals! {
             },
         }
     }
+    /// Signals relating to the compositor and session.
+    PinnacleSignal => {
+        /// The system is about to sleep or has just resumed.
+        ///
+        /// Callbacks receive `true` before the system sleeps and `false` after it resumes.
+        ///
+        /// When systemd-logind is available, sleep is delayed until all callbacks have returned,
+        /// up to logind's `InhibitDelayMaxSec`.
+        PinnaclePrepareForSleep = {
+            enum_name = PrepareForSleep,
+            callback_type = Box<dyn FnMut(bool) + Send + 'static>,
+            client_request = pinnacle_prepare_for_sleep,
+            on_response = |response, callbacks| {
+                for callback in callbacks {
+                    callback(response.sleeping);
+                }
+            },
+        }
+        /// The output layout changed.
+        ///
+        /// This fires once after outputs are moved, resized, enabled, disabled, connected,
+        /// or disconnected, no matter how many outputs changed at once.
+        PinnacleOutputLayoutChanged = {
+            enum_name = OutputLayoutChanged,
+            callback_type = Box<dyn FnMut() + Send + 'static>,
+            client_request = pinnacle_output_layout_changed,
+            on_response = |_response, callbacks| {
+                for callback in callbacks {
+                    callback();
+                }
+            },
+        }
+    }
 }
 
 pub(crate) type SingleOutputFn = Box<dyn FnMut(&OutputHandle) + Send + 'static>;
@@ -345,10 +462,17 @@ pub(crate) struct SignalState {
     pub(crate) window_pointer_leave: SignalData<WindowPointerLeave>,
     pub(crate) window_focused: SignalData<WindowFocused>,
     pub(crate) window_title_changed: SignalData<WindowTitleChanged>,
+    pub(crate) window_urgent_changed: SignalData<WindowUrgentChanged>,
+    pub(crate) window_layout_mode_changed: SignalData<WindowLayoutModeChanged>,
+    pub(crate) window_geometry_changed: SignalData<WindowGeometryChanged>,
 
     pub(crate) tag_active: SignalData<TagActive>,
+    pub(crate) tag_window_count_changed: SignalData<TagWindowCountChanged>,
 
     pub(crate) input_device_added: SignalData<InputDeviceAdded>,
+
+    pub(crate) pinnacle_prepare_for_sleep: SignalData<PinnaclePrepareForSleep>,
+    pub(crate) pinnacle_output_layout_changed: SignalData<PinnacleOutputLayoutChanged>,
 }
 
 impl std::fmt::Debug for SignalState {
@@ -372,10 +496,17 @@ impl SignalState {
             window_pointer_leave: SignalData::new(),
             window_focused: SignalData::new(),
             window_title_changed: SignalData::new(),
+            window_urgent_changed: SignalData::new(),
+            window_layout_mode_changed: SignalData::new(),
+            window_geometry_changed: SignalData::new(),
 
             tag_active: SignalData::new(),
+            tag_window_count_changed: SignalData::new(),
 
             input_device_added: SignalData::new(),
+
+            pinnacle_prepare_for_sleep: SignalData::new(),
+            pinnacle_output_layout_changed: SignalData::new(),
         }
     }
 
@@ -392,10 +523,17 @@ impl SignalState {
         self.window_pointer_leave.reset();
         self.window_focused.reset();
         self.window_title_changed.reset();
+        self.window_urgent_changed.reset();
+        self.window_layout_mode_changed.reset();
+        self.window_geometry_changed.reset();
 
         self.tag_active.reset();
+        self.tag_window_count_changed.reset();
 
         self.input_device_added.reset();
+
+        self.pinnacle_prepare_for_sleep.reset();
+        self.pinnacle_output_layout_changed.reset();
     }
 }
 
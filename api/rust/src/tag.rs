@@ -27,7 +27,8 @@ use futures::FutureExt;
 use pinnacle_api_defs::pinnacle::{
     tag::v1::{
         AddRequest, GetActiveRequest, GetNameRequest, GetOutputNameRequest, GetRequest,
-        RemoveRequest, SetActiveRequest, SwitchToRequest,
+        MoveToOutputRequest, RemoveRequest, SetActiveRequest, SetOutputRequest, SwapRequest,
+        SwitchToRequest,
     },
     util::v1::SetOrToggle,
 };
@@ -181,6 +182,31 @@ pub fn remove(tags: impl IntoIterator<Item = TagHandle>) {
         .unwrap();
 }
 
+/// Swaps the windows on two tags.
+///
+/// Windows on one tag are moved to the other and vice versa. If the tags are on different
+/// outputs, windows move to the other output and lose any other tags they had on the old one.
+/// Windows that have both tags stay where they are.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::tag;
+/// # || {
+/// tag::swap(&tag::get("1")?, &tag::get("2")?);
+/// # Some(())
+/// # };
+/// ```
+pub fn swap(tag: &TagHandle, other: &TagHandle) {
+    Client::tag()
+        .swap(SwapRequest {
+            tag_id: tag.id,
+            other_tag_id: other.id,
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
 /// Connects to a [`TagSignal`].
 ///
 /// # Examples
@@ -197,6 +223,7 @@ pub fn connect_signal(signal: TagSignal) -> SignalHandle {
 
     match signal {
         TagSignal::Active(f) => signal_state.tag_active.add_callback(f),
+        TagSignal::WindowCountChanged(f) => signal_state.tag_window_count_changed.add_callback(f),
     }
 }
 
@@ -342,6 +369,61 @@ impl TagHandle {
             .unwrap();
     }
 
+    /// Moves this tag to the given output.
+    ///
+    /// All windows with this tag move along with it. Those windows lose
+    /// any other tags they had on the old output.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::tag;
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// tag::get("3")?.move_to_output(&output::get_by_name("DP-2")?);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn move_to_output(&self, output: &OutputHandle) {
+        let tag_id = self.id;
+
+        Client::tag()
+            .move_to_output(MoveToOutputRequest {
+                tag_id,
+                output_name: output.name(),
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Sets the output this tag lives on.
+    ///
+    /// This works like [`TagHandle::move_to_output`], but also relocates tags
+    /// whose output was disconnected. Those tags won't come back when the old
+    /// output is connected again.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::tag;
+    /// # use pinnacle_api::output;
+    /// # || {
+    /// tag::get("3")?.set_output(&output::get_by_name("DP-2")?);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_output(&self, output: &OutputHandle) {
+        let tag_id = self.id;
+
+        Client::tag()
+            .set_output(SetOutputRequest {
+                tag_id,
+                output_name: output.name(),
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
     /// Gets whether or not this tag is active.
     pub fn active(&self) -> bool {
         self.active_async().block_on_tokio()
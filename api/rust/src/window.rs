@@ -18,13 +18,22 @@ use pinnacle_api_defs::pinnacle::{
     window::{
         self,
         v1::{
-            GetAppIdRequest, GetFocusedRequest, GetForeignToplevelListIdentifierRequest,
-            GetLayoutModeRequest, GetLocRequest, GetSizeRequest, GetTagIdsRequest, GetTitleRequest,
+            AddGeometryRuleRequest, AddToGroupRequest, AddToGroupsOfRequest, CreateGroupRequest,
+            FloatWithGeometryRequest, FocusInGroupRequest, GetAllWithPropsRequest, GetAppIdRequest,
+            GetFocusedRequest, GetForeignToplevelListIdentifierRequest, GetGroupWindowsRequest,
+            GetInstanceRequest, GetLayoutModeRequest, GetLocRequest, GetRoleRequest,
+            GetSizeRequest, GetTagIdsRequest, GetTitleRequest, GetUrgentRequest,
             GetWindowsInDirRequest, LowerRequest, MoveGrabRequest, MoveToOutputRequest,
-            MoveToTagRequest, RaiseRequest, ResizeGrabRequest, ResizeTileRequest,
-            SetDecorationModeRequest, SetFloatingRequest, SetFocusedRequest, SetFullscreenRequest,
-            SetGeometryRequest, SetMaximizedRequest, SetTagRequest, SetTagsRequest,
-            SetVrrDemandRequest, SwapRequest,
+            MoveToTagRequest, RaiseRequest, RemoveFromGroupRequest, RemoveGroupRequest,
+            ResizeGrabRequest, ResizeTileRequest, SetAppIdOverrideRequest, SetAspectRatioRequest,
+            SetBackgroundFpsCapRequest, SetCloseBehaviorRequest, SetDecorationModeRequest,
+            SetDecorationPolicyRequest, SetFloatingAppIdsRequest, SetFloatingRequest,
+            SetFloatingTitlesRequest, SetFocusedRequest, SetFullscreenRequest, SetGeometryRequest,
+            SetGrabAllInputRequest, SetInactiveOpacityRequest, SetMaximizedRequest,
+            SetMinimizeRestoreOnTagRequest, SetOffscreenPolicyRequest, SetPreferredScaleRequest,
+            SetTagRequest, SetTagsBulkRequest, SetTagsRequest, SetUrgentRequest,
+            SetVrrDemandRequest, SetWarpCursorOnFocusRequest, SwapRequest, ToScratchpadRequest,
+            ToggleScratchpadRequest,
         },
     },
 };
@@ -38,7 +47,7 @@ use crate::{
     output::OutputHandle,
     signal::{SignalHandle, WindowSignal},
     tag::TagHandle,
-    util::{Batch, Direction, Point, Size},
+    util::{Batch, Direction, Point, Rect, Size},
 };
 
 /// Gets handles to all windows.
@@ -67,6 +76,86 @@ pub async fn get_all_async() -> impl Iterator<Item = WindowHandle> {
     window_ids.into_iter().map(|id| WindowHandle { id })
 }
 
+/// Gets handles to all windows on the given output.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// # use pinnacle_api::output;
+/// # || {
+/// let focused_output = output::get_focused()?;
+/// for win in window::get_all_on_output(&focused_output) {
+///     println!("{}", win.title());
+/// }
+/// # Some(())
+/// # };
+/// ```
+pub fn get_all_on_output(output: &OutputHandle) -> impl Iterator<Item = WindowHandle> + use<> {
+    get_all_on_output_async(output).block_on_tokio()
+}
+
+/// Async impl for [`get_all_on_output`].
+pub async fn get_all_on_output_async(
+    output: &OutputHandle,
+) -> impl Iterator<Item = WindowHandle> + use<> {
+    get_props(Some(output.name()))
+        .await
+        .into_iter()
+        .map(|props| props.window)
+}
+
+/// Gets the properties of all windows.
+///
+/// This gets everything in [`WindowProps`] with one request, which is much faster
+/// than calling the individual getters on every window.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// for props in window::get_all_with_props() {
+///     println!("{}: {}", props.app_id, props.title);
+/// }
+/// ```
+pub fn get_all_with_props() -> Vec<WindowProps> {
+    get_all_with_props_async().block_on_tokio()
+}
+
+/// Async impl for [`get_all_with_props`].
+pub async fn get_all_with_props_async() -> Vec<WindowProps> {
+    get_props(None).await
+}
+
+async fn get_props(output_name: Option<String>) -> Vec<WindowProps> {
+    Client::window()
+        .get_all_with_props(GetAllWithPropsRequest { output_name })
+        .await
+        .unwrap()
+        .into_inner()
+        .windows
+        .into_iter()
+        .map(|props| WindowProps {
+            window: WindowHandle {
+                id: props.window_id,
+            },
+            layout_mode: props.layout_mode().try_into().unwrap_or(LayoutMode::Tiled),
+            title: props.title,
+            app_id: props.app_id,
+            tags: props
+                .tag_ids
+                .into_iter()
+                .map(|id| TagHandle { id })
+                .collect(),
+            loc: props.loc.map(|loc| Point { x: loc.x, y: loc.y }),
+            size: props.size.map(|size| Size {
+                w: size.width,
+                h: size.height,
+            }),
+        })
+        .collect()
+}
+
 /// Gets a handle to the window with the current keyboard focus.
 ///
 /// # Examples
@@ -142,6 +231,419 @@ pub fn begin_resize(button: MouseButton) {
         .unwrap();
 }
 
+/// Sets whether minimized windows are unminimized when one of their tags becomes active.
+///
+/// By default, minimized windows stay minimized across tag switches.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// window::set_minimize_restore_on_tag(true);
+/// ```
+pub fn set_minimize_restore_on_tag(restore: bool) {
+    Client::window()
+        .set_minimize_restore_on_tag(SetMinimizeRestoreOnTagRequest { restore })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets the opacity of windows that don't have keyboard focus.
+///
+/// `opacity` ranges from 0.0 (fully transparent) to 1.0 (fully opaque).
+/// Fullscreen windows are always fully opaque.
+///
+/// The default is 1.0, which disables this.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// window::set_inactive_opacity(0.8);
+/// ```
+pub fn set_inactive_opacity(opacity: f32) {
+    Client::window()
+        .set_inactive_opacity(SetInactiveOpacityRequest { opacity })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets how decoration modes are negotiated with clients.
+///
+/// The `Prefer` policies use the mode clients ask for, while the `Force` policies
+/// override it. A mode set with [`WindowHandle::set_decoration_mode`] always takes precedence.
+///
+/// Clients that don't support the xdg-decoration or KDE server decoration protocols
+/// draw their own decorations regardless of this policy.
+///
+/// The default is [`DecorationPolicy::PreferClientSide`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// # use pinnacle_api::window::DecorationPolicy;
+/// window::set_decoration_policy(DecorationPolicy::ForceServerSide);
+/// ```
+pub fn set_decoration_policy(policy: DecorationPolicy) {
+    let policy = match policy {
+        DecorationPolicy::PreferServerSide => window::v1::DecorationPolicy::PreferServerSide,
+        DecorationPolicy::PreferClientSide => window::v1::DecorationPolicy::PreferClientSide,
+        DecorationPolicy::ForceServerSide => window::v1::DecorationPolicy::ForceServerSide,
+        DecorationPolicy::ForceClientSide => window::v1::DecorationPolicy::ForceClientSide,
+    };
+
+    Client::window()
+        .set_decoration_policy(SetDecorationPolicyRequest {
+            policy: policy.into(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets whether floating windows can be moved outside the work area of their output.
+///
+/// The work area is the part of an output not taken up by exclusive layer surfaces like bars.
+/// The policy is enforced when windows are moved with [`WindowHandle::set_geometry`],
+/// moved interactively, and when outputs are moved, resized, or removed.
+///
+/// The default is [`OffscreenPolicy::Allow`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// # use pinnacle_api::window::OffscreenPolicy;
+/// window::set_offscreen_policy(OffscreenPolicy::KeepVisible(32));
+/// ```
+pub fn set_offscreen_policy(policy: OffscreenPolicy) {
+    let (policy, min_visible) = match policy {
+        OffscreenPolicy::Allow => (window::v1::OffscreenPolicy::Allow, 0),
+        OffscreenPolicy::KeepVisible(min_visible) => {
+            (window::v1::OffscreenPolicy::KeepVisible, min_visible)
+        }
+        OffscreenPolicy::Clamp => (window::v1::OffscreenPolicy::Clamp, 0),
+    };
+
+    Client::window()
+        .set_offscreen_policy(SetOffscreenPolicyRequest {
+            policy: policy.into(),
+            min_visible,
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Limits how many frame callbacks per second windows in the background get.
+///
+/// A window is in the background while an opaque window above it covers it completely.
+/// Throttling frame callbacks makes these windows redraw less, which saves power.
+/// Windows get frame callbacks at their full rate again as soon as they're uncovered.
+/// Windows on inactive tags aren't shown, so they don't get frame callbacks at all.
+///
+/// Passing `None` removes the cap, which is the default.
+///
+/// # Panics
+///
+/// Panics if `fps_cap` is 0.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// window::set_background_fps_cap(5);
+/// ```
+pub fn set_background_fps_cap(fps_cap: impl Into<Option<u32>>) {
+    Client::window()
+        .set_background_fps_cap(SetBackgroundFpsCapRequest {
+            fps_cap: fps_cap.into(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets the app ids of windows that always open floating.
+///
+/// This is a simpler alternative to a window rule that floats windows. Window rules run
+/// afterwards, so a layout mode set by one still takes precedence.
+///
+/// Replaces any previously set app ids.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// window::set_floating_app_ids(["pavucontrol", "nm-connection-editor"]);
+/// ```
+pub fn set_floating_app_ids(app_ids: impl IntoIterator<Item = impl ToString>) {
+    Client::window()
+        .set_floating_app_ids(SetFloatingAppIdsRequest {
+            app_ids: app_ids
+                .into_iter()
+                .map(|app_id| app_id.to_string())
+                .collect(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets regexes for the titles of windows that always open floating.
+///
+/// Window rules run afterwards, so a layout mode set by one still takes precedence.
+///
+/// Replaces any previously set titles.
+///
+/// # Panics
+///
+/// Panics if any of the titles is not a valid regex.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// window::set_floating_titles(["^Picture-in-Picture$", "Preferences$"]);
+/// ```
+pub fn set_floating_titles(titles: impl IntoIterator<Item = impl ToString>) {
+    Client::window()
+        .set_floating_titles(SetFloatingTitlesRequest {
+            titles: titles.into_iter().map(|title| title.to_string()).collect(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Gives windows matching `matcher` an initial geometry if they open floating.
+///
+/// The geometry is applied before the window is first configured, so it opens
+/// at that geometry without a separate window rule. Its location is relative to
+/// the output the window opens on. A width or height of 0 leaves that dimension
+/// up to the window.
+///
+/// This only affects windows that open floating, whether from
+/// [`set_floating_app_ids`], [`set_floating_titles`], a window rule, or heuristics.
+/// Windows that open tiled are unaffected. Geometry set by a window rule takes precedence.
+///
+/// If several rules match a window, the one added first wins.
+///
+/// # Panics
+///
+/// Panics if the matcher's app id or title regex is invalid.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// # use pinnacle_api::window::WindowMatcher;
+/// # use pinnacle_api::util::{Point, Rect, Size};
+/// // Open a floating calculator at 400x500 near the top-right of a 1920-wide output
+/// window::set_floating_app_ids(["org.gnome.Calculator"]);
+/// window::add_geometry_rule(
+///     WindowMatcher::new().app_id("^org\\.gnome\\.Calculator$"),
+///     Rect {
+///         loc: Point { x: 1500, y: 20 },
+///         size: Size { w: 400, h: 500 },
+///     },
+/// );
+/// ```
+pub fn add_geometry_rule(matcher: WindowMatcher, geometry: Rect) {
+    Client::window()
+        .add_geometry_rule(AddGeometryRuleRequest {
+            matcher: Some(window::v1::WindowMatcher {
+                app_id: matcher.app_id,
+                title: matcher.title,
+            }),
+            geometry: Some(geometry.into()),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Sets the exact tags of many windows at once.
+///
+/// This is like calling [`WindowHandle::set_tags`] on each window, but every affected
+/// output is laid out only once, after all tags are set. Use this when moving lots
+/// of windows around, for example when reorganizing a workspace.
+///
+/// Windows given an empty collection of tags keep their current tags.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// # use pinnacle_api::tag;
+/// # || {
+/// let tag = tag::get("2")?;
+///
+/// // Move every window to tag "2"
+/// window::set_tags_bulk(window::get_all().map(|win| (win, vec![tag.clone()])));
+/// # Some(())
+/// # };
+/// ```
+pub fn set_tags_bulk(changes: impl IntoIterator<Item = (WindowHandle, Vec<TagHandle>)>) {
+    let windows = changes
+        .into_iter()
+        .map(|(window, tags)| SetTagsRequest {
+            window_id: window.id,
+            tag_ids: tags.into_iter().map(|tag| tag.id).collect(),
+        })
+        .collect();
+
+    Client::window()
+        .set_tags_bulk(SetTagsBulkRequest { windows })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Shows or hides the window in the scratchpad with the given name.
+///
+/// If the window is showing on the focused output, it is hidden.
+/// Otherwise, it is moved to the focused output, made floating, centered, and focused.
+///
+/// Does nothing if no window is in the scratchpad.
+///
+/// See [`WindowHandle::to_scratchpad`] to put a window in a scratchpad.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// window::toggle_scratchpad("terminal");
+/// ```
+pub fn toggle_scratchpad(name: impl ToString) {
+    Client::window()
+        .toggle_scratchpad(ToggleScratchpadRequest {
+            name: name.to_string(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Creates an empty named group of windows.
+///
+/// Groups are lightweight collections of windows that can be acted on together,
+/// separate from tags. Windows are added with [`WindowHandle::add_to_group`] and
+/// leave all groups when they close. Does nothing if the group already exists.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// window::create_group("browsers");
+/// ```
+pub fn create_group(name: impl ToString) {
+    Client::window()
+        .create_group(CreateGroupRequest {
+            name: name.to_string(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Removes a group of windows.
+///
+/// The windows in the group are left alone.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// window::remove_group("browsers");
+/// ```
+pub fn remove_group(name: impl ToString) {
+    Client::window()
+        .remove_group(RemoveGroupRequest {
+            name: name.to_string(),
+        })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Gets handles to the windows in a group, in the order they were added.
+///
+/// Returns nothing if the group doesn't exist.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// for window in window::group_windows("browsers") {
+///     window.close();
+/// }
+/// ```
+pub fn group_windows(name: impl ToString) -> impl Iterator<Item = WindowHandle> {
+    group_windows_async(name).block_on_tokio()
+}
+
+/// Focuses and raises the next window in the focused window's group, wrapping around.
+///
+/// Windows in the group that aren't on an active tag are skipped.
+/// If the focused window is in more than one group, the first by name is used.
+/// Does nothing if the focused window isn't in a group.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// window::focus_next_in_group();
+/// ```
+pub fn focus_next_in_group() {
+    Client::window()
+        .focus_in_group(FocusInGroupRequest { backward: false })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Focuses and raises the previous window in the focused window's group, wrapping around.
+///
+/// See [`focus_next_in_group`] for details.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// window::focus_prev_in_group();
+/// ```
+pub fn focus_prev_in_group() {
+    Client::window()
+        .focus_in_group(FocusInGroupRequest { backward: true })
+        .block_on_tokio()
+        .unwrap();
+}
+
+/// Adds the focused window to every group `target` is in.
+///
+/// Does nothing if no window is focused.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pinnacle_api::window;
+/// # || {
+/// let target = window::get_all().next()?;
+/// window::add_focused_to_group_of(&target);
+/// # Some(())
+/// # };
+/// ```
+pub fn add_focused_to_group_of(target: &WindowHandle) {
+    if let Some(focused) = get_focused() {
+        focused.add_to_groups_of(target);
+    }
+}
+
+/// Async impl for [`group_windows`].
+pub async fn group_windows_async(name: impl ToString) -> impl Iterator<Item = WindowHandle> {
+    let window_ids = Client::window()
+        .get_group_windows(GetGroupWindowsRequest {
+            name: name.to_string(),
+        })
+        .await
+        .unwrap()
+        .into_inner()
+        .window_ids;
+
+    window_ids.into_iter().map(|id| WindowHandle { id })
+}
+
 /// Connects to a [`WindowSignal`].
 ///
 /// # Examples
@@ -161,6 +663,11 @@ pub fn connect_signal(signal: WindowSignal) -> SignalHandle {
         WindowSignal::PointerLeave(f) => signal_state.window_pointer_leave.add_callback(f),
         WindowSignal::Focused(f) => signal_state.window_focused.add_callback(f),
         WindowSignal::TitleChanged(f) => signal_state.window_title_changed.add_callback(f),
+        WindowSignal::UrgentChanged(f) => signal_state.window_urgent_changed.add_callback(f),
+        WindowSignal::LayoutModeChanged(f) => {
+            signal_state.window_layout_mode_changed.add_callback(f)
+        }
+        WindowSignal::GeometryChanged(f) => signal_state.window_geometry_changed.add_callback(f),
     }
 }
 
@@ -172,6 +679,29 @@ pub struct WindowHandle {
     pub(crate) id: u32,
 }
 
+/// A snapshot of a window's properties.
+///
+/// Missing properties have the same values that the getters on [`WindowHandle`]
+/// would return.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct WindowProps {
+    /// The window these properties belong to.
+    pub window: WindowHandle,
+    /// The window's title.
+    pub title: String,
+    /// The window's app id (class if it's an xwayland window).
+    pub app_id: String,
+    /// The window's tags.
+    pub tags: Vec<TagHandle>,
+    /// The window's location.
+    pub loc: Option<Point>,
+    /// The window's size.
+    pub size: Option<Size>,
+    /// The window's layout mode.
+    pub layout_mode: LayoutMode,
+}
+
 /// A window's current layout mode.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum LayoutMode {
@@ -204,6 +734,23 @@ impl TryFrom<pinnacle_api_defs::pinnacle::window::v1::LayoutMode> for LayoutMode
     }
 }
 
+/// What happens when a window is asked to close.
+///
+/// This applies to [`WindowHandle::close`] and close requests from taskbars and other
+/// foreign toplevel clients. It doesn't stop apps from closing on their own.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+pub enum CloseBehavior {
+    /// The window is asked to close.
+    #[default]
+    Close,
+    /// The window is minimized instead.
+    Minimize,
+    /// The window is hidden in the scratchpad with the given name instead.
+    ///
+    /// See [`WindowHandle::to_scratchpad`].
+    Scratchpad(String),
+}
+
 /// A mode for window decorations (titlebar, shadows, etc).
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum DecorationMode {
@@ -213,6 +760,52 @@ pub enum DecorationMode {
     ServerSide,
 }
 
+/// Where a window goes in the layout order of a tag it's moved to.
+///
+/// See [`WindowHandle::move_to_tag_at`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum InsertPosition {
+    /// Before all other windows on the tag.
+    Start,
+    /// After all other windows on the tag.
+    End,
+    /// After the most recently focused window on the tag,
+    /// or after all of them if none have been focused.
+    AfterFocused,
+}
+
+/// A policy for negotiating decoration modes with clients.
+///
+/// See [`set_decoration_policy`].
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DecorationPolicy {
+    /// Use the mode clients ask for, defaulting to server-side decorations.
+    PreferServerSide,
+    /// Use the mode clients ask for, leaving the choice to them if they don't.
+    #[default]
+    PreferClientSide,
+    /// Always use server-side decorations, ignoring what clients ask for.
+    ForceServerSide,
+    /// Always use client-side decorations, ignoring what clients ask for.
+    ForceClientSide,
+}
+
+/// Whether floating windows can be moved outside the work area of their output.
+///
+/// See [`set_offscreen_policy`].
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum OffscreenPolicy {
+    /// Windows can be moved anywhere, including completely off-screen.
+    #[default]
+    Allow,
+    /// At least this many pixels of a window stay inside the work area on each axis.
+    KeepVisible(u32),
+    /// Windows stay completely inside the work area.
+    ///
+    /// Windows larger than the work area are pinned to its top-left corner.
+    Clamp,
+}
+
 /// A demand for variable refresh rate on an output.
 #[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
 #[non_exhaustive]
@@ -234,10 +827,44 @@ impl VrrDemand {
     }
 }
 
+/// Matches windows by their app id and title.
+///
+/// All set conditions must match. An empty matcher matches all windows.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct WindowMatcher {
+    /// A regex the window's app id must match.
+    pub app_id: Option<String>,
+    /// A regex the window's title must match.
+    pub title: Option<String>,
+}
+
+impl WindowMatcher {
+    /// Creates an empty [`WindowMatcher`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Requires windows to have an app id matching the given regex.
+    pub fn app_id(mut self, regex: impl ToString) -> Self {
+        self.app_id = Some(regex.to_string());
+        self
+    }
+
+    /// Requires windows to have a title matching the given regex.
+    pub fn title(mut self, regex: impl ToString) -> Self {
+        self.title = Some(regex.to_string());
+        self
+    }
+}
+
 impl WindowHandle {
     /// Sends a close request to this window.
     ///
     /// If the window is unresponsive, it may not close.
+    ///
+    /// If this window's [`CloseBehavior`] isn't [`CloseBehavior::Close`],
+    /// it is minimized or hidden in a scratchpad instead.
     pub fn close(&self) {
         let window_id = self.id;
         Client::window()
@@ -269,6 +896,35 @@ impl WindowHandle {
             .unwrap();
     }
 
+    /// Makes this window floating with the given location and size.
+    ///
+    /// Unlike calling [`set_floating`][Self::set_floating] and then
+    /// [`set_geometry`][Self::set_geometry], the geometry is set before the window
+    /// becomes floating, so it never shows up floating with its old geometry.
+    /// If this window is already floating, it is only moved and resized.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # || {
+    /// window::get_focused()?.float_with_geometry(100, 100, 800, 600);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn float_with_geometry(&self, x: i32, y: i32, w: u32, h: u32) {
+        Client::window()
+            .float_with_geometry(FloatWithGeometryRequest {
+                window_id: self.id,
+                x,
+                y,
+                w,
+                h,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
     /// If this window is tiled, resizes its tile by shifting the left, right,
     /// top, and bottom edges by the provided pixel amounts.
     ///
@@ -424,6 +1080,182 @@ impl WindowHandle {
             .unwrap();
     }
 
+    /// Marks this window as wanting attention or not.
+    ///
+    /// Focusing the window clears this.
+    /// Connect to [`WindowSignal::UrgentChanged`] to react to changes.
+    pub fn set_urgent(&self, urgent: bool) {
+        let window_id = self.id;
+        Client::window()
+            .set_urgent(SetUrgentRequest { window_id, urgent })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Reports `app_id` as this window's app id instead of the one it set.
+    ///
+    /// Use this to fix apps with useless or duplicate app ids. The override is seen
+    /// everywhere the app id is, including [`app_id`][Self::app_id], window rules,
+    /// and other clients like taskbars. Pass `None` to remove the override.
+    ///
+    /// Overrides are removed when the config reloads.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// window::add_window_rule(|window| {
+    ///     if window.app_id() == "electron" && window.title().contains("Discord") {
+    ///         window.set_app_id_override(Some("discord"));
+    ///     }
+    /// });
+    /// ```
+    pub fn set_app_id_override(&self, app_id: Option<&str>) {
+        let window_id = self.id;
+        Client::window()
+            .set_app_id_override(SetAppIdOverrideRequest {
+                window_id,
+                app_id: app_id.map(|app_id| app_id.to_string()),
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Sets whether the pointer is moved onto this window when it gets keyboard focus.
+    ///
+    /// The pointer is warped to the center of the window unless it's already over it.
+    /// Windows spanning multiple outputs are centered on the part on their own output.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// window::add_window_rule(|window| {
+    ///     if window.app_id() == "gimp" {
+    ///         window.set_warp_cursor_on_focus(true);
+    ///     }
+    /// });
+    /// ```
+    pub fn set_warp_cursor_on_focus(&self, warp: bool) {
+        let window_id = self.id;
+        Client::window()
+            .set_warp_cursor_on_focus(SetWarpCursorOnFocusRequest { window_id, warp })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Sets whether keybinds are bypassed while this window is focused.
+    ///
+    /// While set, every key is sent to this window, which is useful for things like
+    /// remote desktops and virtual machines. This works like a client inhibiting
+    /// keyboard shortcuts, except it's up to the config. Quit and reload config keybinds
+    /// still work, as does the escape key set with
+    /// [`input::set_grab_all_input_escape`][crate::input::set_grab_all_input_escape].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// window::add_window_rule(|window| {
+    ///     if window.app_id() == "virt-manager" {
+    ///         window.set_grab_all_input(true);
+    ///     }
+    /// });
+    /// ```
+    pub fn set_grab_all_input(&self, grab_all_input: bool) {
+        let window_id = self.id;
+        Client::window()
+            .set_grab_all_input(SetGrabAllInputRequest {
+                window_id,
+                grab_all_input,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Overrides the fractional scale advertised to this window.
+    ///
+    /// Some apps render poorly at fractional scales; this lets you
+    /// force a scale they handle well, independent of the output's scale.
+    /// Pass `None` to go back to advertising the output's scale.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # || {
+    /// // Render the focused window at 2x and let the compositor downscale it
+    /// window::get_focused()?.set_preferred_scale(2.0);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_preferred_scale(&self, scale: impl Into<Option<f32>>) {
+        Client::window()
+            .set_preferred_scale(SetPreferredScaleRequest {
+                window_id: self.id,
+                scale: scale.into(),
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Locks the width to height ratio this window keeps when resized while floating.
+    ///
+    /// When the window is resized, either interactively or through [`Self::set_geometry`],
+    /// its height follows its width to keep the ratio. Pass `None` to let the window
+    /// resize freely again.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # || {
+    /// window::get_focused()?.set_aspect_ratio((16, 9));
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn set_aspect_ratio(&self, ratio: impl Into<Option<(u32, u32)>>) {
+        let ratio = ratio.into();
+        Client::window()
+            .set_aspect_ratio(SetAspectRatioRequest {
+                window_id: self.id,
+                width: ratio.map(|(width, _)| width),
+                height: ratio.map(|(_, height)| height),
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Sets what happens when this window is asked to close.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # use pinnacle_api::window::CloseBehavior;
+    /// window::add_window_rule(|window| {
+    ///     if window.app_id() == "discord" {
+    ///         window.set_close_behavior(CloseBehavior::Minimize);
+    ///     }
+    /// });
+    /// ```
+    pub fn set_close_behavior(&self, behavior: CloseBehavior) {
+        let (behavior, scratchpad_name) = match behavior {
+            CloseBehavior::Close => (window::v1::CloseBehavior::Close, String::new()),
+            CloseBehavior::Minimize => (window::v1::CloseBehavior::Minimize, String::new()),
+            CloseBehavior::Scratchpad(name) => (window::v1::CloseBehavior::Scratchpad, name),
+        };
+
+        Client::window()
+            .set_close_behavior(SetCloseBehaviorRequest {
+                window_id: self.id,
+                behavior: behavior.into(),
+                scratchpad_name,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
     /// Sets this window's decoration mode.
     pub fn set_decoration_mode(&self, mode: DecorationMode) {
         Client::window()
@@ -487,7 +1319,48 @@ impl WindowHandle {
         let window_id = self.id;
         let tag_id = tag.id;
         Client::window()
-            .move_to_tag(MoveToTagRequest { window_id, tag_id })
+            .move_to_tag(MoveToTagRequest {
+                window_id,
+                tag_id,
+                insert_position: window::v1::InsertPosition::Unspecified.into(),
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Moves this window to the given `tag`, placing it at `position`
+    /// in the tag's layout order.
+    ///
+    /// [`move_to_tag`][Self::move_to_tag] keeps the window's current place in the layout order,
+    /// which may put it anywhere among the tag's windows.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # use pinnacle_api::window::InsertPosition;
+    /// # use pinnacle_api::tag;
+    /// # || {
+    /// // Move the focused window to tag "Code", making it the master window
+    /// window::get_focused()?.move_to_tag_at(&tag::get("Code")?, InsertPosition::Start);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn move_to_tag_at(&self, tag: &TagHandle, position: InsertPosition) {
+        let window_id = self.id;
+        let tag_id = tag.id;
+        let insert_position = match position {
+            InsertPosition::Start => window::v1::InsertPosition::Start,
+            InsertPosition::End => window::v1::InsertPosition::End,
+            InsertPosition::AfterFocused => window::v1::InsertPosition::AfterFocused,
+        };
+
+        Client::window()
+            .move_to_tag(MoveToTagRequest {
+                window_id,
+                tag_id,
+                insert_position: insert_position.into(),
+            })
             .block_on_tokio()
             .unwrap();
     }
@@ -630,6 +1503,100 @@ impl WindowHandle {
             .unwrap();
     }
 
+    /// Hides this window in the scratchpad with the given name.
+    ///
+    /// The window is removed from all its tags until it is summoned with
+    /// [`toggle_scratchpad`]. If the scratchpad already had a different window,
+    /// that window is shown on the focused output.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # || {
+    /// window::get_focused()?.to_scratchpad("terminal");
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn to_scratchpad(&self, name: impl ToString) {
+        let window_id = self.id;
+        Client::window()
+            .to_scratchpad(ToScratchpadRequest {
+                window_id,
+                name: name.to_string(),
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Adds this window to a group, creating the group if it doesn't exist.
+    ///
+    /// See [`create_group`] for more on groups.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # || {
+    /// window::get_focused()?.add_to_group("browsers");
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn add_to_group(&self, group_name: impl ToString) {
+        let window_id = self.id;
+        Client::window()
+            .add_to_group(AddToGroupRequest {
+                window_id,
+                group_name: group_name.to_string(),
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Adds this window to every group `target` is in.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # || {
+    /// let target = window::get_all().next()?;
+    /// window::get_focused()?.add_to_groups_of(&target);
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn add_to_groups_of(&self, target: &WindowHandle) {
+        Client::window()
+            .add_to_groups_of(AddToGroupsOfRequest {
+                window_id: self.id,
+                target_id: target.id,
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
+    /// Removes this window from a group.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pinnacle_api::window;
+    /// # || {
+    /// window::get_focused()?.remove_from_group("browsers");
+    /// # Some(())
+    /// # };
+    /// ```
+    pub fn remove_from_group(&self, group_name: impl ToString) {
+        let window_id = self.id;
+        Client::window()
+            .remove_from_group(RemoveFromGroupRequest {
+                window_id,
+                group_name: group_name.to_string(),
+            })
+            .block_on_tokio()
+            .unwrap();
+    }
+
     /// Gets this window's current location in the global space.
     pub fn loc(&self) -> Option<Point> {
         self.loc_async().block_on_tokio()
@@ -685,6 +1652,47 @@ impl WindowHandle {
             .app_id
     }
 
+    /// Gets this window's X11 `WM_CLASS` instance.
+    ///
+    /// Xwayland windows have both a class, returned by [`WindowHandle::app_id`],
+    /// and an instance. Wayland windows don't have an instance, so this returns
+    /// an empty string for them.
+    pub fn instance(&self) -> String {
+        self.instance_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::instance`].
+    pub async fn instance_async(&self) -> String {
+        let window_id = self.id;
+        Client::window()
+            .get_instance(GetInstanceRequest { window_id })
+            .await
+            .unwrap()
+            .into_inner()
+            .instance
+    }
+
+    /// Gets this window's X11 `WM_WINDOW_ROLE`.
+    ///
+    /// Some applications use this to tell their windows apart, for example
+    /// Firefox's main windows have the role "browser".
+    ///
+    /// If it doesn't have one or it is a Wayland window, this returns an empty string.
+    pub fn role(&self) -> String {
+        self.role_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::role`].
+    pub async fn role_async(&self) -> String {
+        let window_id = self.id;
+        Client::window()
+            .get_role(GetRoleRequest { window_id })
+            .await
+            .unwrap()
+            .into_inner()
+            .role
+    }
+
     /// Gets this window's title.
     ///
     /// If it doesn't have one, this returns an empty string.
@@ -734,6 +1742,22 @@ impl WindowHandle {
             .focused
     }
 
+    /// Gets whether or not this window wants attention.
+    pub fn urgent(&self) -> bool {
+        self.urgent_async().block_on_tokio()
+    }
+
+    /// Async impl for [`Self::urgent`].
+    pub async fn urgent_async(&self) -> bool {
+        let window_id = self.id;
+        Client::window()
+            .get_urgent(GetUrgentRequest { window_id })
+            .await
+            .unwrap()
+            .into_inner()
+            .urgent
+    }
+
     /// Gets this window's current [`LayoutMode`].
     pub fn layout_mode(&self) -> LayoutMode {
         self.layout_mode_async().block_on_tokio()
@@ -73,8 +73,12 @@ pub mod pinnacle {
                 WindowPointerLeaveRequest,
                 WindowFocusedRequest,
                 WindowTitleChangedRequest,
+                WindowUrgentChangedRequest,
                 TagActiveRequest,
-                InputDeviceAddedRequest
+                TagWindowCountChangedRequest,
+                InputDeviceAddedRequest,
+                PinnaclePrepareForSleepRequest,
+                PinnacleOutputLayoutChangedRequest
             );
         }
     }
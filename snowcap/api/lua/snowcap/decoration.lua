@@ -55,11 +55,7 @@ function decoration.new_widget(args)
     require("snowcap.widget")._traverse_widget_tree(
         widget_def,
         callbacks,
-        function(callbacks, widget)
-            if widget.button and widget.button.on_press then
-                callbacks[widget.button.widget_id] = widget.button.on_press
-            end
-        end
+        require("snowcap.widget")._collect_callbacks
     )
 
     ---@type snowcap.decoration.v1.NewDecorationRequest
@@ -91,7 +87,7 @@ function decoration.new_widget(args)
         decoration_id = decoration_id,
     }, function(response)
         local widget_id = response.widget_id or 0
-        if response.button then
+        if response.button or response.hover then
             if callbacks[widget_id] then
                 args.program:update(callbacks[widget_id])
                 local widget_def = args.program:view()
@@ -100,11 +96,7 @@ function decoration.new_widget(args)
                 require("snowcap.widget")._traverse_widget_tree(
                     widget_def,
                     callbacks,
-                    function(callbacks, widget)
-                        if widget.button and widget.button.on_press then
-                            callbacks[widget.button.widget_id] = widget.button.on_press
-                        end
-                    end
+                    require("snowcap.widget")._collect_callbacks
                 )
 
                 local _, err = client:snowcap_decoration_v1_DecorationService_UpdateDecoration({
@@ -123,11 +115,7 @@ function decoration.new_widget(args)
         require("snowcap.widget")._traverse_widget_tree(
             widget_def,
             callbacks,
-            function(callbacks, widget)
-                if widget.button and widget.button.on_press then
-                    callbacks[widget.button.widget_id] = widget.button.on_press
-                end
-            end
+            require("snowcap.widget")._collect_callbacks
         )
 
         local _, err = client:snowcap_decoration_v1_DecorationService_UpdateDecoration({
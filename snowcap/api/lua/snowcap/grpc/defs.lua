@@ -528,6 +528,16 @@ local snowcap_widget_v1_Font_Style = {
     STYLE_OBLIQUE = 3,
 }
 
+---@enum snowcap.widget.v1.Tooltip.Position
+local snowcap_widget_v1_Tooltip_Position = {
+    POSITION_UNSPECIFIED = 0,
+    POSITION_FOLLOW_CURSOR = 1,
+    POSITION_TOP = 2,
+    POSITION_BOTTOM = 3,
+    POSITION_LEFT = 4,
+    POSITION_RIGHT = 5,
+}
+
 ---@enum snowcap.widget.v1.Image.ContentFit
 local snowcap_widget_v1_Image_ContentFit = {
     CONTENT_FIT_UNSPECIFIED = 0,
@@ -794,12 +804,27 @@ local snowcap_layer_v1_Layer = {
 ---@field clip boolean?
 ---@field child snowcap.widget.v1.WidgetDef?
 ---@field style snowcap.widget.v1.Container.Style?
+---@field tooltip snowcap.widget.v1.Tooltip?
+---@field hover_widget_id integer?
+---@field unhover_widget_id integer?
 
 ---@class snowcap.widget.v1.Container.Style
 ---@field text_color snowcap.widget.v1.Color?
 ---@field background_color snowcap.widget.v1.Color?
 ---@field border snowcap.widget.v1.Border?
 
+---@class snowcap.widget.v1.Tooltip
+---@field text string?
+---@field position snowcap.widget.v1.Tooltip.Position?
+---@field gap number?
+---@field padding number?
+---@field style snowcap.widget.v1.Container.Style?
+
+---@class snowcap.widget.v1.Hover
+
+---@class snowcap.widget.v1.Hover.Event
+---@field entered boolean?
+
 ---@class snowcap.widget.v1.InputRegion
 ---@field add boolean?
 ---@field width snowcap.widget.v1.Length?
@@ -814,6 +839,9 @@ local snowcap_layer_v1_Layer = {
 ---@field clip boolean?
 ---@field style snowcap.widget.v1.Button.Style?
 ---@field widget_id integer?
+---@field tooltip snowcap.widget.v1.Tooltip?
+---@field hover_widget_id integer?
+---@field unhover_widget_id integer?
 
 ---@class snowcap.widget.v1.Button.Style
 ---@field active snowcap.widget.v1.Button.Style.Inner?
@@ -853,6 +881,7 @@ local snowcap_layer_v1_Layer = {
 ---@class snowcap.widget.v1.GetWidgetEventsResponse
 ---@field widget_id integer?
 ---@field button snowcap.widget.v1.Button.Event?
+---@field hover snowcap.widget.v1.Hover.Event?
 
 ---@class snowcap.decoration.v1.Bounds
 ---@field left integer?
@@ -1095,6 +1124,9 @@ snowcap.widget.v1.Scrollable.Direction = {}
 snowcap.widget.v1.Scrollable.Scrollbar = {}
 snowcap.widget.v1.Container = {}
 snowcap.widget.v1.Container.Style = {}
+snowcap.widget.v1.Tooltip = {}
+snowcap.widget.v1.Hover = {}
+snowcap.widget.v1.Hover.Event = {}
 snowcap.widget.v1.InputRegion = {}
 snowcap.widget.v1.Button = {}
 snowcap.widget.v1.Button.Style = {}
@@ -1159,6 +1191,7 @@ snowcap.widget.v1.Alignment = snowcap_widget_v1_Alignment
 snowcap.widget.v1.Font.Weight = snowcap_widget_v1_Font_Weight
 snowcap.widget.v1.Font.Stretch = snowcap_widget_v1_Font_Stretch
 snowcap.widget.v1.Font.Style = snowcap_widget_v1_Font_Style
+snowcap.widget.v1.Tooltip.Position = snowcap_widget_v1_Tooltip_Position
 snowcap.widget.v1.Image.ContentFit = snowcap_widget_v1_Image_ContentFit
 snowcap.widget.v0alpha1.Alignment = snowcap_widget_v0alpha1_Alignment
 snowcap.widget.v0alpha1.ScrollableAlignment = snowcap_widget_v0alpha1_ScrollableAlignment
@@ -94,11 +94,7 @@ function layer.new_widget(args)
     require("snowcap.widget")._traverse_widget_tree(
         widget_def,
         callbacks,
-        function(callbacks, widget)
-            if widget.button and widget.button.on_press then
-                callbacks[widget.button.widget_id] = widget.button.on_press
-            end
-        end
+        require("snowcap.widget")._collect_callbacks
     )
 
     ---@type snowcap.layer.v1.NewLayerRequest
@@ -130,7 +126,7 @@ function layer.new_widget(args)
         layer_id = layer_id,
     }, function(response)
         local widget_id = response.widget_id or 0
-        if response.button then
+        if response.button or response.hover then
             if callbacks[widget_id] then
                 args.program:update(callbacks[widget_id])
                 local widget_def = args.program:view()
@@ -139,11 +135,7 @@ function layer.new_widget(args)
                 require("snowcap.widget")._traverse_widget_tree(
                     widget_def,
                     callbacks,
-                    function(callbacks, widget)
-                        if widget.button and widget.button.on_press then
-                            callbacks[widget.button.widget_id] = widget.button.on_press
-                        end
-                    end
+                    require("snowcap.widget")._collect_callbacks
                 )
 
                 local _, err = client:snowcap_layer_v1_LayerService_UpdateLayer({
@@ -165,11 +157,7 @@ function layer.new_widget(args)
         require("snowcap.widget")._traverse_widget_tree(
             widget_def,
             callbacks,
-            function(callbacks, widget)
-                if widget.button and widget.button.on_press then
-                    callbacks[widget.button.widget_id] = widget.button.on_press
-                end
-            end
+            require("snowcap.widget")._collect_callbacks
         )
 
         local _, err = client:snowcap_layer_v1_LayerService_UpdateLayer({
@@ -114,6 +114,11 @@
 ---@field clip boolean?
 ---@field child snowcap.widget.WidgetDef
 ---@field style snowcap.widget.container.Style?
+---@field tooltip snowcap.widget.Tooltip?
+---@field on_hover any? A message sent when the cursor enters this container.
+---@field on_unhover any? A message sent when the cursor leaves this container.
+---@field private hover_widget_id integer?
+---@field private unhover_widget_id integer?
 
 ---@class snowcap.widget.container.Style
 ---@field text_color snowcap.widget.Color?
@@ -128,7 +133,12 @@
 ---@field clip boolean?
 ---@field style snowcap.widget.button.Styles?
 ---@field on_press any?
+---@field tooltip snowcap.widget.Tooltip?
+---@field on_hover any? A message sent when the cursor enters this button.
+---@field on_unhover any? A message sent when the cursor leaves this button.
 ---@field private widget_id integer?
+---@field private hover_widget_id integer?
+---@field private unhover_widget_id integer?
 
 ---@class snowcap.widget.button.Styles
 ---@field active snowcap.widget.button.Style?
@@ -141,6 +151,23 @@
 ---@field background_color snowcap.widget.Color?
 ---@field border snowcap.widget.Border?
 
+---Text shown next to a widget while the cursor hovers over it.
+---@class snowcap.widget.Tooltip
+---@field text string
+---@field position snowcap.widget.tooltip.Position? Defaults to following the cursor.
+---@field gap number? The distance between the widget and the tooltip.
+---@field padding number?
+---@field style snowcap.widget.container.Style?
+
+---@enum snowcap.widget.tooltip.Position
+local tooltip_position = {
+    FOLLOW_CURSOR = 1,
+    TOP = 2,
+    BOTTOM = 3,
+    LEFT = 4,
+    RIGHT = 5,
+}
+
 ---@class snowcap.widget.Image
 ---@field handle snowcap.widget.image.Handle
 ---@field width snowcap.widget.Length?
@@ -306,10 +333,37 @@ local widget = {
     image = {
         content_fit = content_fit,
     },
+    tooltip = {
+        position = tooltip_position,
+    },
 }
 
 local widget_id_counter = 0
 
+---@return integer
+local function next_widget_id()
+    local widget_id = widget_id_counter
+    widget_id_counter = widget_id_counter + 1
+    return widget_id
+end
+
+---@param def snowcap.widget.Tooltip?
+---@return snowcap.widget.v1.Tooltip?
+local function tooltip_into_api(def)
+    if not def then
+        return nil
+    end
+
+    ---@type snowcap.widget.v1.Tooltip
+    return {
+        text = def.text,
+        position = def.position,
+        gap = def.gap,
+        padding = def.padding,
+        style = def.style --[[@as snowcap.widget.v1.Container.Style]],
+    }
+end
+
 ---@param def snowcap.widget.Text
 ---@return snowcap.widget.v1.Text
 local function text_into_api(def)
@@ -339,6 +393,9 @@ local function container_into_api(def)
         clip = def.clip,
         child = widget.widget_def_into_api(def.child),
         style = def.style --[[@as snowcap.widget.v1.Container.Style]],
+        tooltip = tooltip_into_api(def.tooltip),
+        hover_widget_id = def.hover_widget_id,
+        unhover_widget_id = def.unhover_widget_id,
     }
 end
 
@@ -407,6 +464,9 @@ local function button_into_api(def)
         clip = def.clip,
         style = def.style --[[@as snowcap.widget.v1.Button.Style]],
         widget_id = def.widget_id,
+        tooltip = tooltip_into_api(def.tooltip),
+        hover_widget_id = def.hover_widget_id,
+        unhover_widget_id = def.unhover_widget_id,
     }
 end
 
@@ -512,6 +572,13 @@ end
 ---
 ---@return snowcap.widget.WidgetDef
 function widget.container(container)
+    if container.on_hover then
+        container.hover_widget_id = next_widget_id()
+    end
+    if container.on_unhover then
+        container.unhover_widget_id = next_widget_id()
+    end
+
     return {
         container = container,
     }
@@ -522,8 +589,13 @@ end
 ---@return snowcap.widget.WidgetDef
 function widget.button(button)
     if button.on_press then
-        button.widget_id = widget_id_counter
-        widget_id_counter = widget_id_counter + 1
+        button.widget_id = next_widget_id()
+    end
+    if button.on_hover then
+        button.hover_widget_id = next_widget_id()
+    end
+    if button.on_unhover then
+        button.unhover_widget_id = next_widget_id()
     end
 
     ---@type snowcap.widget.WidgetDef
@@ -578,4 +650,29 @@ function widget._traverse_widget_tree(wgt, callbacks, with_widget)
     end
 end
 
+---@private
+---@lcat nodoc
+---@param callbacks table<integer, any>
+---@param wgt snowcap.widget.WidgetDef
+function widget._collect_callbacks(callbacks, wgt)
+    if wgt.button then
+        if wgt.button.on_press then
+            callbacks[wgt.button.widget_id] = wgt.button.on_press
+        end
+        if wgt.button.on_hover then
+            callbacks[wgt.button.hover_widget_id] = wgt.button.on_hover
+        end
+        if wgt.button.on_unhover then
+            callbacks[wgt.button.unhover_widget_id] = wgt.button.on_unhover
+        end
+    elseif wgt.container then
+        if wgt.container.on_hover then
+            callbacks[wgt.container.hover_widget_id] = wgt.container.on_hover
+        end
+        if wgt.container.on_unhover then
+            callbacks[wgt.container.unhover_widget_id] = wgt.container.on_unhover
+        end
+    end
+end
+
 return widget
@@ -16,7 +16,7 @@ use tracing::error;
 use crate::{
     BlockOnTokio,
     client::Client,
-    widget::{Program, Widget, WidgetId},
+    widget::{Program, WidgetDef, WidgetId},
 };
 
 /// The bounds of a window or decoration.
@@ -79,11 +79,7 @@ where
 
     let widget_def = program.view();
 
-    widget_def.collect_messages(&mut callbacks, |def, cbs| {
-        if let Widget::Button(button) = &def.widget {
-            cbs.extend(button.on_press.clone());
-        }
-    });
+    widget_def.collect_messages(&mut callbacks, WidgetDef::collect_callbacks);
 
     let response = Client::decoration()
         .new_decoration(NewDecorationRequest {
@@ -118,6 +114,9 @@ where
                         get_widget_events_response::Event::Button(_event) => {
                             callbacks.get(&id).cloned()
                         }
+                        get_widget_events_response::Event::Hover(_event) => {
+                            callbacks.get(&id).cloned()
+                        }
                     }
                 }
                 Some(msg) = msg_recv.recv() => {
@@ -135,11 +134,7 @@ where
 
             callbacks.clear();
 
-            widget_def.collect_messages(&mut callbacks, |def, cbs| {
-                if let Widget::Button(button) = &def.widget {
-                    cbs.extend(button.on_press.clone());
-                }
-            });
+            widget_def.collect_messages(&mut callbacks, WidgetDef::collect_callbacks);
 
             Client::decoration()
                 .update_decoration(UpdateDecorationRequest {
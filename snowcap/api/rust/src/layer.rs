@@ -19,7 +19,7 @@ use crate::{
     BlockOnTokio,
     client::Client,
     input::Modifiers,
-    widget::{Program, Widget, WidgetId},
+    widget::{Program, WidgetDef, WidgetId},
 };
 
 // TODO: change to bitflag
@@ -141,11 +141,7 @@ where
 
     let widget_def = program.view();
 
-    widget_def.collect_messages(&mut callbacks, |def, cbs| {
-        if let Widget::Button(button) = &def.widget {
-            cbs.extend(button.on_press.clone());
-        }
-    });
+    widget_def.collect_messages(&mut callbacks, WidgetDef::collect_callbacks);
 
     let response = Client::layer()
         .new_layer(NewLayerRequest {
@@ -184,6 +180,9 @@ where
                         get_widget_events_response::Event::Button(_event) => {
                             callbacks.get(&id).cloned()
                         }
+                        get_widget_events_response::Event::Hover(_event) => {
+                            callbacks.get(&id).cloned()
+                        }
                     }
                 }
                 Some(msg) = msg_recv.recv() => {
@@ -202,11 +201,7 @@ where
 
             callbacks.clear();
 
-            widget_def.collect_messages(&mut callbacks, |def, cbs| {
-                if let Widget::Button(button) = &def.widget {
-                    cbs.extend(button.on_press.clone());
-                }
-            });
+            widget_def.collect_messages(&mut callbacks, WidgetDef::collect_callbacks);
 
             Client::layer()
                 .update_layer(UpdateLayerRequest {
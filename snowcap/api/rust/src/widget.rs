@@ -11,6 +11,7 @@ pub mod input_region;
 pub mod row;
 pub mod scrollable;
 pub mod text;
+pub mod tooltip;
 
 use std::{
     collections::HashMap,
@@ -139,6 +140,24 @@ impl<Msg> WidgetDef<Msg> {
     }
 }
 
+impl<Msg: Clone> WidgetDef<Msg> {
+    /// Collects the messages this widget sends, keyed by widget id.
+    pub(crate) fn collect_callbacks(def: &WidgetDef<Msg>, callbacks: &mut HashMap<WidgetId, Msg>) {
+        match &def.widget {
+            Widget::Button(button) => {
+                callbacks.extend(button.on_press.clone());
+                callbacks.extend(button.on_hover.clone());
+                callbacks.extend(button.on_unhover.clone());
+            }
+            Widget::Container(container) => {
+                callbacks.extend(container.on_hover.clone());
+                callbacks.extend(container.on_unhover.clone());
+            }
+            _ => (),
+        }
+    }
+}
+
 impl<Msg> From<WidgetDef<Msg>> for widget::v1::WidgetDef {
     fn from(value: WidgetDef<Msg>) -> Self {
         Self {
@@ -1,6 +1,6 @@
 use snowcap_api_defs::snowcap::widget;
 
-use super::{Border, Color, Length, Padding, Widget, WidgetDef, WidgetId};
+use super::{Border, Color, Length, Padding, Widget, WidgetDef, WidgetId, tooltip::Tooltip};
 
 #[derive(Clone)]
 pub struct Button<Msg> {
@@ -10,7 +10,10 @@ pub struct Button<Msg> {
     pub padding: Option<Padding>,
     pub clip: Option<bool>,
     pub style: Option<Styles>,
+    pub tooltip: Option<Tooltip>,
     pub(crate) on_press: Option<(WidgetId, Msg)>,
+    pub(crate) on_hover: Option<(WidgetId, Msg)>,
+    pub(crate) on_unhover: Option<(WidgetId, Msg)>,
 }
 
 impl<Msg: std::fmt::Debug> std::fmt::Debug for Button<Msg> {
@@ -22,7 +25,10 @@ impl<Msg: std::fmt::Debug> std::fmt::Debug for Button<Msg> {
             .field("padding", &self.padding)
             .field("clip", &self.clip)
             .field("style", &self.style)
+            .field("tooltip", &self.tooltip)
             .field("on_press", &"...")
+            .field("on_hover", &"...")
+            .field("on_unhover", &"...")
             .finish()
     }
 }
@@ -35,7 +41,10 @@ impl<Msg: PartialEq> PartialEq for Button<Msg> {
             && self.padding == other.padding
             && self.clip == other.clip
             && self.style == other.style
+            && self.tooltip == other.tooltip
             && self.on_press == other.on_press
+            && self.on_hover == other.on_hover
+            && self.on_unhover == other.on_unhover
     }
 }
 
@@ -48,7 +57,10 @@ impl<Msg> Button<Msg> {
             padding: None,
             clip: None,
             style: None,
+            tooltip: None,
             on_press: None,
+            on_hover: None,
+            on_unhover: None,
         }
     }
 
@@ -87,6 +99,30 @@ impl<Msg> Button<Msg> {
         }
     }
 
+    /// Sends the given message when the cursor enters this button.
+    pub fn on_hover(self, message: Msg) -> Self {
+        Self {
+            on_hover: Some((WidgetId::next(), message)),
+            ..self
+        }
+    }
+
+    /// Sends the given message when the cursor leaves this button.
+    pub fn on_unhover(self, message: Msg) -> Self {
+        Self {
+            on_unhover: Some((WidgetId::next(), message)),
+            ..self
+        }
+    }
+
+    /// Shows a tooltip while the cursor hovers over this button.
+    pub fn tooltip(self, tooltip: Tooltip) -> Self {
+        Self {
+            tooltip: Some(tooltip),
+            ..self
+        }
+    }
+
     pub fn style(self, styles: Styles) -> Self {
         Self {
             style: Some(styles),
@@ -111,6 +147,9 @@ impl<Msg> From<Button<Msg>> for widget::v1::Button {
             clip: value.clip,
             style: value.style.map(From::from),
             widget_id: value.on_press.map(|(id, _)| id.to_inner()),
+            tooltip: value.tooltip.map(From::from),
+            hover_widget_id: value.on_hover.map(|(id, _)| id.to_inner()),
+            unhover_widget_id: value.on_unhover.map(|(id, _)| id.to_inner()),
         }
     }
 }
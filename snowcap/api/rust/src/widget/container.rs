@@ -1,6 +1,8 @@
 use snowcap_api_defs::snowcap::widget;
 
-use super::{Alignment, Border, Color, Length, Padding, Widget, WidgetDef};
+use super::{
+    Alignment, Border, Color, Length, Padding, Widget, WidgetDef, WidgetId, tooltip::Tooltip,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Container<Msg> {
@@ -14,6 +16,9 @@ pub struct Container<Msg> {
     pub clip: Option<bool>,
     pub child: WidgetDef<Msg>,
     pub style: Option<Style>,
+    pub tooltip: Option<Tooltip>,
+    pub(crate) on_hover: Option<(WidgetId, Msg)>,
+    pub(crate) on_unhover: Option<(WidgetId, Msg)>,
 }
 
 impl<Msg> Container<Msg> {
@@ -29,6 +34,9 @@ impl<Msg> Container<Msg> {
             vertical_alignment: None,
             clip: None,
             style: None,
+            tooltip: None,
+            on_hover: None,
+            on_unhover: None,
         }
     }
 
@@ -94,6 +102,30 @@ impl<Msg> Container<Msg> {
             ..self
         }
     }
+
+    /// Sends the given message when the cursor enters this container.
+    pub fn on_hover(self, message: Msg) -> Self {
+        Self {
+            on_hover: Some((WidgetId::next(), message)),
+            ..self
+        }
+    }
+
+    /// Sends the given message when the cursor leaves this container.
+    pub fn on_unhover(self, message: Msg) -> Self {
+        Self {
+            on_unhover: Some((WidgetId::next(), message)),
+            ..self
+        }
+    }
+
+    /// Shows a tooltip while the cursor hovers over this container.
+    pub fn tooltip(self, tooltip: Tooltip) -> Self {
+        Self {
+            tooltip: Some(tooltip),
+            ..self
+        }
+    }
 }
 
 impl<Msg> From<Container<Msg>> for widget::v1::Container {
@@ -113,6 +145,9 @@ impl<Msg> From<Container<Msg>> for widget::v1::Container {
             clip: value.clip,
             child: Some(Box::new(value.child.into())),
             style: value.style.map(From::from),
+            tooltip: value.tooltip.map(From::from),
+            hover_widget_id: value.on_hover.map(|(id, _)| id.to_inner()),
+            unhover_widget_id: value.on_unhover.map(|(id, _)| id.to_inner()),
         }
     }
 }
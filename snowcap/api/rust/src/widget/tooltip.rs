@@ -0,0 +1,134 @@
+use snowcap_api_defs::snowcap::widget;
+
+use super::container;
+
+/// Text shown next to a widget while the cursor hovers over it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tooltip {
+    pub text: String,
+    pub position: Option<Position>,
+    pub gap: Option<f32>,
+    pub padding: Option<f32>,
+    pub style: Option<container::Style>,
+}
+
+impl Tooltip {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn position(self, position: Position) -> Self {
+        Self {
+            position: Some(position),
+            ..self
+        }
+    }
+
+    pub fn gap(self, gap: f32) -> Self {
+        Self {
+            gap: Some(gap),
+            ..self
+        }
+    }
+
+    pub fn padding(self, padding: f32) -> Self {
+        Self {
+            padding: Some(padding),
+            ..self
+        }
+    }
+
+    pub fn style(self, style: container::Style) -> Self {
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+}
+
+/// Where a [`Tooltip`] is placed relative to its widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Position {
+    /// Show the tooltip next to the cursor.
+    #[default]
+    FollowCursor,
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl From<Position> for widget::v1::tooltip::Position {
+    fn from(value: Position) -> Self {
+        match value {
+            Position::FollowCursor => Self::FollowCursor,
+            Position::Top => Self::Top,
+            Position::Bottom => Self::Bottom,
+            Position::Left => Self::Left,
+            Position::Right => Self::Right,
+        }
+    }
+}
+
+impl From<Tooltip> for widget::v1::Tooltip {
+    fn from(value: Tooltip) -> Self {
+        Self {
+            text: value.text,
+            position: value
+                .position
+                .map(|it| widget::v1::tooltip::Position::from(it) as i32),
+            gap: value.gap,
+            padding: value.padding,
+            style: value.style.map(From::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::widget::{Color, button::Button, container::Container, text::Text};
+
+    use super::*;
+
+    #[test]
+    fn tooltip_converts_into_api() {
+        let tooltip = Tooltip::new("Hello")
+            .position(Position::Bottom)
+            .gap(4.0)
+            .style(container::Style::new().text_color(Color::rgb(1.0, 0.0, 0.0)));
+
+        let api = widget::v1::Tooltip::from(tooltip.clone());
+
+        assert_eq!(api.text, "Hello");
+        assert_eq!(api.position(), widget::v1::tooltip::Position::Bottom);
+        assert_eq!(api.gap, Some(4.0));
+        assert_eq!(api.padding, None);
+        assert!(api.style.and_then(|style| style.text_color).is_some());
+
+        let container: widget::v1::Container = Container::<()>::new(Text::new("container"))
+            .tooltip(tooltip.clone())
+            .into();
+        assert_eq!(container.tooltip, Some(api.clone()));
+
+        let button: widget::v1::Button = Button::<()>::new(Text::new("button"))
+            .tooltip(tooltip)
+            .into();
+        assert_eq!(button.tooltip, Some(api));
+    }
+
+    #[test]
+    fn hover_messages_get_widget_ids() {
+        let button: widget::v1::Button = Button::new(Text::new("button"))
+            .on_hover(0)
+            .on_unhover(1)
+            .into();
+
+        assert!(button.hover_widget_id.is_some());
+        assert!(button.unhover_widget_id.is_some());
+        assert_ne!(button.hover_widget_id, button.unhover_widget_id);
+        assert_eq!(button.widget_id, None);
+    }
+}
@@ -53,6 +53,11 @@ impl widget_service_server::WidgetService for super::WidgetService {
                                 widget::v1::button::Event {},
                             )
                         }
+                        WidgetEvent::Hover(entered) => {
+                            widget::v1::get_widget_events_response::Event::Hover(
+                                widget::v1::hover::Event { entered },
+                            )
+                        }
                     }),
                 })
             },
@@ -352,6 +357,9 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                 clip,
                 child,
                 style,
+                tooltip,
+                hover_widget_id,
+                unhover_widget_id,
             } = *container_def;
 
             let child_widget_fn = child.and_then(|def| widget_def_to_fn(*def));
@@ -428,7 +436,12 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
 
                 container = container.style(style);
 
-                container.into()
+                with_tooltip_and_hover(
+                    container.into(),
+                    tooltip.clone(),
+                    hover_widget_id,
+                    unhover_widget_id,
+                )
             });
 
             Some(f)
@@ -442,6 +455,9 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
                 clip,
                 style,
                 widget_id,
+                tooltip,
+                hover_widget_id,
+                unhover_widget_id,
             } = *button;
 
             let child_widget_fn = child.and_then(|def| widget_def_to_fn(*def));
@@ -501,7 +517,12 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
 
                 button = button.style(style);
 
-                button.into()
+                with_tooltip_and_hover(
+                    button.into(),
+                    tooltip.clone(),
+                    hover_widget_id,
+                    unhover_widget_id,
+                )
             });
 
             Some(f)
@@ -615,6 +636,75 @@ pub fn widget_def_to_fn(def: WidgetDef) -> Option<ViewFn> {
     }
 }
 
+/// Wraps a widget in a mouse area that sends hover events
+/// and a tooltip, if either were requested.
+fn with_tooltip_and_hover(
+    element: crate::widget::Element,
+    tooltip: Option<widget::v1::Tooltip>,
+    hover_widget_id: Option<u32>,
+    unhover_widget_id: Option<u32>,
+) -> crate::widget::Element {
+    let mut element = element;
+
+    if hover_widget_id.is_some() || unhover_widget_id.is_some() {
+        let mut mouse_area = iced::widget::MouseArea::new(element);
+
+        if let Some(widget_id) = hover_widget_id {
+            mouse_area = mouse_area.on_enter(crate::widget::SnowcapMessage::WidgetEvent(
+                WidgetId(widget_id),
+                WidgetEvent::Hover(true),
+            ));
+        }
+        if let Some(widget_id) = unhover_widget_id {
+            mouse_area = mouse_area.on_exit(crate::widget::SnowcapMessage::WidgetEvent(
+                WidgetId(widget_id),
+                WidgetEvent::Hover(false),
+            ));
+        }
+
+        element = mouse_area.into();
+    }
+
+    let Some(tooltip) = tooltip else {
+        return element;
+    };
+
+    let position = match tooltip.position() {
+        widget::v1::tooltip::Position::Unspecified
+        | widget::v1::tooltip::Position::FollowCursor => {
+            iced::widget::tooltip::Position::FollowCursor
+        }
+        widget::v1::tooltip::Position::Top => iced::widget::tooltip::Position::Top,
+        widget::v1::tooltip::Position::Bottom => iced::widget::tooltip::Position::Bottom,
+        widget::v1::tooltip::Position::Left => iced::widget::tooltip::Position::Left,
+        widget::v1::tooltip::Position::Right => iced::widget::tooltip::Position::Right,
+    };
+
+    let widget::v1::Tooltip {
+        text,
+        position: _,
+        gap,
+        padding,
+        style,
+    } = tooltip;
+
+    let mut tooltip = iced::widget::Tooltip::new(element, iced::widget::Text::new(text), position);
+
+    if let Some(gap) = gap {
+        tooltip = tooltip.gap(gap);
+    }
+    if let Some(padding) = padding {
+        tooltip = tooltip.padding(padding);
+    }
+
+    tooltip = match style {
+        Some(style) => tooltip.style(move |_theme| iced::widget::container::Style::from_api(style)),
+        None => tooltip.style(iced::widget::container::rounded_box),
+    };
+
+    tooltip.into()
+}
+
 impl FromApi<widget::v1::Length> for iced::Length {
     fn from_api(length: widget::v1::Length) -> Self {
         use widget::v1::length::Strategy;
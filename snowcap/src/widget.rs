@@ -203,4 +203,6 @@ pub enum SnowcapMessage {
 #[derive(Debug, Clone)]
 pub enum WidgetEvent {
     Button,
+    /// The cursor entered (`true`) or left (`false`) a widget.
+    Hover(bool),
 }
@@ -20,7 +20,7 @@ use tokio_stream::{Stream, StreamExt};
 use tonic::{Response, Status, Streaming};
 use tracing::{debug, warn};
 
-use crate::state::State;
+use crate::{metrics::GrpcStreamGuard, state::State};
 
 pub type ResponseStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send>>;
 pub type StateFnSender = calloop::channel::Sender<Box<dyn FnOnce(&mut State) + Send>>;
@@ -81,11 +81,12 @@ where
         + 'static,
     T: Send + 'static,
 {
-    let (msg_send, msg_recv) = tokio::sync::oneshot::channel::<Result<(), Status>>();
+    let (msg_send, msg_recv) = tokio::sync::oneshot::channel::<Result<GrpcStreamGuard, Status>>();
     let (sender, receiver) = unbounded_channel::<Result<T, Status>>();
 
     let f = Box::new(|state: &mut State| {
-        if msg_send.send(with_state(state, sender)).is_err() {
+        let res = with_state(state, sender).map(|()| state.pinnacle.metrics.track_grpc_stream());
+        if msg_send.send(res).is_err() {
             warn!("failed to send result of API call to config; receiver already dropped");
         }
     });
@@ -101,12 +102,14 @@ where
     });
 
     let res = match res {
-        Ok(res) => res.map(move |()| {
-            Response::new(
-                Box::pin(tokio_stream::wrappers::UnboundedReceiverStream::new(
-                    receiver,
-                )) as _,
-            )
+        Ok(res) => res.map(move |guard| {
+            Response::new(Box::pin(
+                tokio_stream::wrappers::UnboundedReceiverStream::new(receiver).map(move |msg| {
+                    // Keeps the stream counted until it's dropped
+                    let _ = &guard;
+                    msg
+                }),
+            ) as _)
         }),
         Err(err) => return Err(err),
     };
@@ -197,7 +200,12 @@ where
 
     let join_handle = tokio::spawn(with_in_stream);
 
+    // The stream is returned before the state is available, so the guard is sent to it
+    // and held in the channel until the stream is dropped
+    let (guard_send, guard_recv) = tokio::sync::oneshot::channel::<GrpcStreamGuard>();
+
     let with_out_stream_and_in_stream_join_handle = Box::new(|state: &mut State| {
+        let _ = guard_send.send(state.pinnacle.metrics.track_grpc_stream());
         with_out_stream_and_in_stream_join_handle(state, sender, join_handle);
     });
 
@@ -205,5 +213,8 @@ where
         .send(with_out_stream_and_in_stream_join_handle)
         .map_err(|_| Status::internal("failed to execute request"))?;
 
-    Ok(Response::new(Box::pin(receiver.map(map))))
+    Ok(Response::new(Box::pin(receiver.map(move |msg| {
+        let _ = &guard_recv;
+        map(msg)
+    }))))
 }
@@ -2,15 +2,45 @@ use pinnacle_api_defs::pinnacle::{
     debug::{
         self,
         v1::{
-            SetCursorPlaneScanoutRequest, SetDamageVisualizationRequest,
-            SetOpaqueRegionVisualizationRequest, SetProcessPipingRequest,
+            DumpStateRequest, DumpStateResponse, OutputDump, SetCursorPlaneScanoutRequest,
+            SetDamageVisualizationRequest, SetDirectScanoutRequest, SetOpaqueRegionCullingRequest,
+            SetOpaqueRegionVisualizationRequest, SetProcessPipingRequest, TagDump, WindowDump,
         },
     },
-    util::v1::SetOrToggle,
+    output,
+    util::{self, v1::SetOrToggle},
 };
+use smithay::utils::{Logical, Rectangle};
 use tonic::{Request, Status};
 
-use crate::api::{TonicResult, run_unary_no_response};
+use crate::{
+    api::{TonicResult, run_unary, run_unary_no_response, window::api_layout_mode},
+    focus::keyboard::KeyboardFocusTarget,
+    state::WithState,
+};
+
+fn api_rect(rect: Rectangle<i32, Logical>) -> util::v1::Rect {
+    util::v1::Rect {
+        loc: Some(util::v1::Point {
+            x: rect.loc.x,
+            y: rect.loc.y,
+        }),
+        size: Some(util::v1::Size {
+            width: rect.size.w.try_into().unwrap_or_default(),
+            height: rect.size.h.try_into().unwrap_or_default(),
+        }),
+    }
+}
+
+fn api_mode(mode: smithay::output::Mode) -> output::v1::Mode {
+    output::v1::Mode {
+        size: Some(util::v1::Size {
+            width: mode.size.w.try_into().unwrap_or_default(),
+            height: mode.size.h.try_into().unwrap_or_default(),
+        }),
+        refresh_rate_mhz: mode.refresh as u32,
+    }
+}
 
 #[tonic::async_trait]
 impl debug::v1::debug_service_server::DebugService for super::DebugService {
@@ -96,6 +126,34 @@ impl debug::v1::debug_service_server::DebugService for super::DebugService {
         .await
     }
 
+    async fn set_direct_scanout(
+        &self,
+        request: Request<SetDirectScanoutRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+        let set_or_toggle = request.set_or_toggle();
+
+        let set = match set_or_toggle {
+            SetOrToggle::Set => Some(true),
+            SetOrToggle::Unset => Some(false),
+            SetOrToggle::Toggle => None,
+            SetOrToggle::Unspecified => {
+                return Err(Status::invalid_argument("no set or toggle specified"));
+            }
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.debug.disable_direct_scanout = set
+                .map(|set| !set)
+                .unwrap_or(!state.pinnacle.config.debug.disable_direct_scanout);
+            tracing::debug!(
+                "Direct scanout: {}",
+                !state.pinnacle.config.debug.disable_direct_scanout
+            );
+        })
+        .await
+    }
+
     async fn set_process_piping(
         &self,
         request: Request<SetProcessPipingRequest>,
@@ -123,4 +181,119 @@ impl debug::v1::debug_service_server::DebugService for super::DebugService {
         })
         .await
     }
+
+    async fn set_opaque_region_culling(
+        &self,
+        request: Request<SetOpaqueRegionCullingRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+        let set_or_toggle = request.set_or_toggle();
+
+        let set = match set_or_toggle {
+            SetOrToggle::Set => Some(true),
+            SetOrToggle::Unset => Some(false),
+            SetOrToggle::Toggle => None,
+            SetOrToggle::Unspecified => {
+                return Err(Status::invalid_argument("no set or toggle specified"));
+            }
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.debug.opaque_region_culling =
+                set.unwrap_or(!state.pinnacle.config.debug.opaque_region_culling);
+            tracing::debug!(
+                "Opaque region culling: {}",
+                state.pinnacle.config.debug.opaque_region_culling
+            );
+
+            for output in state.pinnacle.outputs.clone() {
+                state.schedule_render(&output);
+            }
+        })
+        .await
+    }
+
+    async fn dump_state(
+        &self,
+        _request: Request<DumpStateRequest>,
+    ) -> TonicResult<DumpStateResponse> {
+        run_unary(&self.sender, move |state| {
+            let pinnacle = &state.pinnacle;
+
+            let outputs = pinnacle
+                .outputs
+                .iter()
+                .map(|output| {
+                    let (modes, tags, enabled) = output.with_state(|state| {
+                        let tags = state
+                            .tags
+                            .iter()
+                            .map(|tag| TagDump {
+                                tag_id: tag.id().to_inner(),
+                                name: tag.name(),
+                                active: tag.active(),
+                            })
+                            .collect();
+                        (
+                            state.modes.iter().cloned().map(api_mode).collect(),
+                            tags,
+                            state.enabled_global_id.is_some(),
+                        )
+                    });
+
+                    OutputDump {
+                        name: output.name(),
+                        geometry: pinnacle.space.output_geometry(output).map(api_rect),
+                        current_mode: output.current_mode().map(api_mode),
+                        modes,
+                        scale: output.current_scale().fractional_scale() as f32,
+                        enabled,
+                        tags,
+                    }
+                })
+                .collect();
+
+            let windows = pinnacle
+                .windows
+                .iter()
+                .map(|window| {
+                    let (window_id, tag_ids, layout_mode) = window.with_state(|state| {
+                        (
+                            state.id.0,
+                            state.tags.iter().map(|tag| tag.id().to_inner()).collect(),
+                            state.layout_mode,
+                        )
+                    });
+
+                    WindowDump {
+                        window_id,
+                        app_id: window.class().unwrap_or_default(),
+                        title: window.title().unwrap_or_default(),
+                        tag_ids,
+                        layout_mode: api_layout_mode(layout_mode).into(),
+                        geometry: pinnacle.space.element_geometry(window).map(api_rect),
+                    }
+                })
+                .collect();
+
+            let focused_window_id = pinnacle
+                .seat
+                .get_keyboard()
+                .and_then(|keyboard| keyboard.current_focus())
+                .and_then(|focus| match focus {
+                    KeyboardFocusTarget::Window(window) => {
+                        Some(window.with_state(|state| state.id.0))
+                    }
+                    _ => None,
+                });
+
+            Ok(DumpStateResponse {
+                outputs,
+                windows,
+                focused_output_name: pinnacle.focused_output().map(|output| output.name()),
+                focused_window_id,
+            })
+        })
+        .await
+    }
 }
@@ -1,21 +1,35 @@
+use std::{collections::HashMap, time::Duration};
+
 use pinnacle_api_defs::pinnacle::input::{
     self,
     v1::{
-        AccelProfile, BindInfo, BindRequest, BindResponse, ClickMethod, EnterBindLayerRequest,
-        GetBindInfosRequest, GetBindInfosResponse, GetBindLayerStackRequest,
-        GetBindLayerStackResponse, GetDeviceCapabilitiesRequest, GetDeviceCapabilitiesResponse,
-        GetDeviceInfoRequest, GetDeviceInfoResponse, GetDeviceTypeRequest, GetDeviceTypeResponse,
-        GetDevicesRequest, GetDevicesResponse, KeybindOnPressRequest, KeybindStreamRequest,
-        KeybindStreamResponse, MousebindOnPressRequest, MousebindStreamRequest,
-        MousebindStreamResponse, ScrollMethod, SendEventsMode, SetBindPropertiesRequest,
-        SetDeviceLibinputSettingRequest, SetDeviceMapTargetRequest, SetRepeatRateRequest,
+        AccelProfile, BindInfo, BindRequest, BindResponse, ClickMethod,
+        ConfinePointerToOutputRequest, EnterBindLayerRequest, ExitBindLayerRequest, Gesture,
+        GestureBindStreamRequest, GestureBindStreamResponse, GetBindInfosRequest,
+        GetBindInfosResponse, GetBindLayerStackRequest, GetBindLayerStackResponse,
+        GetDeviceCapabilitiesRequest, GetDeviceCapabilitiesResponse, GetDeviceInfoRequest,
+        GetDeviceInfoResponse, GetDeviceTypeRequest, GetDeviceTypeResponse, GetDevicesRequest,
+        GetDevicesResponse, GetIdleInhibitedRequest, GetIdleInhibitedResponse,
+        GetKeyboardFocusRequest, GetKeyboardFocusResponse, GetPointerFocusRequest,
+        GetPointerFocusResponse, KeybindOnPressRequest, KeybindStreamRequest,
+        KeybindStreamResponse, LayerSurfaceInfo, MousebindOnPressRequest, MousebindStreamRequest,
+        MousebindStreamResponse, RelativeMotionPolicy, ResetPointerPositionRequest, ScrollMethod,
+        SendComboRequest, SendEventsMode, SetBindLayerPassthroughRequest, SetBindPropertiesRequest,
+        SetComposeEnabledRequest, SetCursorAutohideRequest, SetCursorVisibleRequest,
+        SetDeviceLibinputSettingRequest, SetDeviceMapTargetRequest, SetDoubleClickTimeRequest,
+        SetGrabAllInputEscapeRequest, SetIdleDimRequest, SetMiddleClickPasteRequest,
+        SetMouseButtonMapRequest, SetNumlockRequest, SetPointerDefaultPositionRequest,
+        SetRelativeMotionPolicyRequest, SetRelativeMotionScaleRequest, SetRepeatRateRequest,
+        SetResizeEdgeThresholdRequest, SetStickyKeysRequest, SetWarpOnOutputFocusRequest,
         SetXcursorRequest, SetXkbConfigRequest, SetXkbKeymapRequest, SwitchXkbLayoutRequest,
-        TapButtonMap, set_device_map_target_request::Target, switch_xkb_layout_request::Action,
+        TapButtonMap, TypeStringRequest, get_pointer_focus_response,
+        set_device_map_target_request::Target, switch_xkb_layout_request::Action,
     },
 };
 use smithay::reexports::input as libinput;
 use smithay::{
-    input::keyboard::XkbConfig,
+    desktop::{find_popup_root_surface, layer_map_for_output},
+    input::keyboard::{ModifiersState, XkbConfig},
     output::Output,
     utils::{Logical, Rectangle},
 };
@@ -24,11 +38,16 @@ use tracing::{error, warn};
 
 use crate::{
     api::{ResponseStream, TonicResult, run_server_streaming, run_unary, run_unary_no_response},
+    focus::keyboard::KeyboardFocusTarget,
+    handlers::idle::IdleDimConfig,
     input::{
+        ButtonMapTarget,
         bind::{Edge, ModMask},
+        gesture::{GestureEvent, GestureKind, SwipeDirection},
         libinput::device_type,
     },
     output::OutputName,
+    state::WithState,
 };
 
 use super::InputService;
@@ -37,6 +56,7 @@ use super::InputService;
 impl input::v1::input_service_server::InputService for InputService {
     type KeybindStreamStream = ResponseStream<KeybindStreamResponse>;
     type MousebindStreamStream = ResponseStream<MousebindStreamResponse>;
+    type GestureBindStreamStream = ResponseStream<GestureBindStreamResponse>;
 
     async fn bind(&self, request: Request<BindRequest>) -> TonicResult<BindResponse> {
         let request = request.into_inner();
@@ -95,6 +115,11 @@ impl input::v1::input_service_server::InputService for InputService {
             .as_ref()
             .and_then(|props| props.allow_when_locked)
             .unwrap_or_default();
+        let repeat = bind
+            .properties
+            .as_ref()
+            .and_then(|props| props.repeat)
+            .unwrap_or_default();
 
         let Some(bind) = bind.bind else {
             return Err(Status::invalid_argument("bind.bind was not specified"));
@@ -103,27 +128,7 @@ impl input::v1::input_service_server::InputService for InputService {
         run_unary(&self.sender, move |state| {
             let bind_id = match bind {
                 input::v1::bind::Bind::Key(keybind) => {
-                    let mut keysym = None;
-                    if let Some(xkb_name) = keybind.xkb_name {
-                        keysym = Some(if xkb_name.chars().count() == 1 {
-                            let Some(ch) = xkb_name.chars().next() else {
-                                unreachable!()
-                            };
-                            let keysym = xkbcommon::xkb::Keysym::from_char(ch);
-                            keysym
-                        } else {
-                            let keysym = xkbcommon::xkb::keysym_from_name(
-                                &xkb_name,
-                                xkbcommon::xkb::KEYSYM_NO_FLAGS,
-                            );
-                            keysym
-                        })
-                    }
-                    if let Some(key_code) = keybind.key_code {
-                        keysym = Some(xkbcommon::xkb::Keysym::new(key_code));
-                    }
-
-                    let Some(keysym) = keysym else {
+                    let Some(keysym) = keysym_from_keybind(&keybind) else {
                         return Err(Status::invalid_argument("no key was specified"));
                     };
 
@@ -138,6 +143,12 @@ impl input::v1::input_service_server::InputService for InputService {
                         allow_when_locked,
                     );
 
+                    state
+                        .pinnacle
+                        .input_state
+                        .bind_state
+                        .set_repeat(bind_id, repeat);
+
                     bind_id
                 }
                 input::v1::bind::Bind::Mouse(mousebind) => {
@@ -184,6 +195,7 @@ impl input::v1::input_service_server::InputService for InputService {
             quit,
             reload_config,
             allow_when_locked,
+            repeat,
         } = properties;
 
         run_unary_no_response(&self.sender, move |state| {
@@ -222,6 +234,13 @@ impl input::v1::input_service_server::InputService for InputService {
                     .bind_state
                     .set_allow_when_locked(bind_id, allow_when_locked);
             }
+            if let Some(repeat) = repeat {
+                state
+                    .pinnacle
+                    .input_state
+                    .bind_state
+                    .set_repeat(bind_id, repeat);
+            }
         })
         .await
     }
@@ -310,6 +329,7 @@ impl input::v1::input_service_server::InputService for InputService {
                                 quit: Some(keybind.bind_data.is_quit_bind),
                                 reload_config: Some(keybind.bind_data.is_reload_config_bind),
                                 allow_when_locked: Some(keybind.bind_data.allow_when_locked),
+                                repeat: Some(keybind.repeat),
                             }),
                             bind: Some(input::v1::bind::Bind::Key(input::v1::Keybind {
                                 key_code: Some(keybind.key.into()),
@@ -381,6 +401,7 @@ impl input::v1::input_service_server::InputService for InputService {
                                 quit: Some(mousebind.bind_data.is_quit_bind),
                                 reload_config: Some(mousebind.bind_data.is_reload_config_bind),
                                 allow_when_locked: Some(mousebind.bind_data.allow_when_locked),
+                                repeat: None,
                             }),
                             bind: Some(input::v1::bind::Bind::Mouse(input::v1::Mousebind {
                                 button: mousebind.button,
@@ -423,6 +444,29 @@ impl input::v1::input_service_server::InputService for InputService {
         .await
     }
 
+    async fn exit_bind_layer(&self, _request: Request<ExitBindLayerRequest>) -> TonicResult<()> {
+        run_unary_no_response(&self.sender, |state| {
+            state.pinnacle.input_state.bind_state.enter_previous_layer();
+        })
+        .await
+    }
+
+    async fn set_bind_layer_passthrough(
+        &self,
+        request: Request<SetBindLayerPassthroughRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        run_unary_no_response(&self.sender, move |state| {
+            state
+                .pinnacle
+                .input_state
+                .bind_state
+                .set_layer_passthrough(request.layer_name, request.passthrough);
+        })
+        .await
+    }
+
     async fn keybind_stream(
         &self,
         request: Request<KeybindStreamRequest>,
@@ -497,13 +541,14 @@ impl input::v1::input_service_server::InputService for InputService {
             };
 
             tokio::spawn(async move {
-                while let Some(edge) = recv.recv().await {
+                while let Some(mouse_edge) = recv.recv().await {
                     let msg = Ok(MousebindStreamResponse {
-                        edge: match edge {
+                        edge: match mouse_edge.edge {
                             Edge::Press => input::v1::Edge::Press,
                             Edge::Release => input::v1::Edge::Release,
                         }
                         .into(),
+                        double_click: mouse_edge.double_click,
                     });
                     if sender.send(msg).is_err() {
                         break;
@@ -548,6 +593,278 @@ impl input::v1::input_service_server::InputService for InputService {
         .await
     }
 
+    async fn gesture_bind_stream(
+        &self,
+        request: Request<GestureBindStreamRequest>,
+    ) -> TonicResult<Self::GestureBindStreamStream> {
+        let request = request.into_inner();
+
+        let fingers = request.fingers;
+        if fingers == 0 {
+            return Err(Status::invalid_argument("fingers must be at least 1"));
+        }
+
+        let kind = match request.gesture() {
+            Gesture::Unspecified => {
+                return Err(Status::invalid_argument("gesture was unspecified"));
+            }
+            Gesture::SwipeUp => GestureKind::Swipe(SwipeDirection::Up),
+            Gesture::SwipeDown => GestureKind::Swipe(SwipeDirection::Down),
+            Gesture::SwipeLeft => GestureKind::Swipe(SwipeDirection::Left),
+            Gesture::SwipeRight => GestureKind::Swipe(SwipeDirection::Right),
+            Gesture::Pinch => GestureKind::Pinch,
+        };
+
+        run_server_streaming(&self.sender, move |state, sender| {
+            let mut recv = state.pinnacle.input_state.gestures.add_bind(fingers, kind);
+
+            tokio::spawn(async move {
+                while let Some(event) = recv.recv().await {
+                    let msg = Ok(GestureBindStreamResponse {
+                        scale: match event {
+                            GestureEvent::Swipe => None,
+                            GestureEvent::Pinch { scale } => Some(scale),
+                        },
+                    });
+                    if sender.send(msg).is_err() {
+                        break;
+                    }
+                    tokio::task::yield_now().await;
+                }
+            });
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn set_middle_click_paste(
+        &self,
+        request: Request<SetMiddleClickPasteRequest>,
+    ) -> TonicResult<()> {
+        let enabled = request.into_inner().enabled;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.middle_click_paste = enabled;
+        })
+        .await
+    }
+
+    async fn set_mouse_button_map(
+        &self,
+        request: Request<SetMouseButtonMapRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let mut button_map = HashMap::new();
+
+        for mapping in request.mappings {
+            let target = match mapping.to {
+                Some(input::v1::button_mapping::To::Button(button)) => {
+                    ButtonMapTarget::Button(button)
+                }
+                Some(input::v1::button_mapping::To::Keybind(keybind)) => {
+                    let Some(key) = keybind.key.as_ref().and_then(keysym_from_keybind) else {
+                        return Err(Status::invalid_argument("no key was specified"));
+                    };
+
+                    let mut mods = ModifiersState::default();
+                    for modif in keybind.mods() {
+                        match modif {
+                            input::v1::Modifier::Unspecified => (),
+                            input::v1::Modifier::Shift => mods.shift = true,
+                            input::v1::Modifier::Ctrl => mods.ctrl = true,
+                            input::v1::Modifier::Alt => mods.alt = true,
+                            input::v1::Modifier::Super => mods.logo = true,
+                            input::v1::Modifier::IsoLevel3Shift => mods.iso_level3_shift = true,
+                            input::v1::Modifier::IsoLevel5Shift => mods.iso_level5_shift = true,
+                        }
+                    }
+
+                    ButtonMapTarget::Keybind { key, mods }
+                }
+                None => {
+                    return Err(Status::invalid_argument(format!(
+                        "no target was specified for button {}",
+                        mapping.from_button
+                    )));
+                }
+            };
+
+            button_map.insert(mapping.from_button, target);
+        }
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.input_state.button_map = button_map;
+        })
+        .await
+    }
+
+    async fn set_grab_all_input_escape(
+        &self,
+        request: Request<SetGrabAllInputEscapeRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let escape = match request.key.as_ref() {
+            Some(key) => {
+                let Some(key) = keysym_from_keybind(key) else {
+                    return Err(Status::invalid_argument("no key was specified"));
+                };
+
+                let mut mods = ModMask::new();
+                for modif in request.mods() {
+                    match modif {
+                        input::v1::Modifier::Unspecified => (),
+                        input::v1::Modifier::Shift => mods.shift = Some(true),
+                        input::v1::Modifier::Ctrl => mods.ctrl = Some(true),
+                        input::v1::Modifier::Alt => mods.alt = Some(true),
+                        input::v1::Modifier::Super => mods.super_ = Some(true),
+                        input::v1::Modifier::IsoLevel3Shift => mods.iso_level3_shift = Some(true),
+                        input::v1::Modifier::IsoLevel5Shift => mods.iso_level5_shift = Some(true),
+                    }
+                }
+
+                Some((key, mods))
+            }
+            None => None,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.input_state.grab_all_input_escape = escape;
+        })
+        .await
+    }
+
+    async fn set_relative_motion_scale(
+        &self,
+        request: Request<SetRelativeMotionScaleRequest>,
+    ) -> TonicResult<()> {
+        let scale = request.into_inner().scale;
+
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(Status::invalid_argument(format!(
+                "relative motion scale must be positive, got {scale}"
+            )));
+        }
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.input_state.relative_motion.scale = scale as f64;
+        })
+        .await
+    }
+
+    async fn set_relative_motion_policy(
+        &self,
+        request: Request<SetRelativeMotionPolicyRequest>,
+    ) -> TonicResult<()> {
+        let policy = match request.into_inner().policy() {
+            RelativeMotionPolicy::Unspecified => {
+                return Err(Status::invalid_argument(
+                    "unspecified relative motion policy",
+                ));
+            }
+            RelativeMotionPolicy::Accelerated => crate::input::RelativeMotionPolicy::Accelerated,
+            RelativeMotionPolicy::Unaccelerated => {
+                crate::input::RelativeMotionPolicy::Unaccelerated
+            }
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.input_state.relative_motion.policy = policy;
+        })
+        .await
+    }
+
+    async fn set_resize_edge_threshold(
+        &self,
+        request: Request<SetResizeEdgeThresholdRequest>,
+    ) -> TonicResult<()> {
+        let threshold = request.into_inner().threshold;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.input_state.resize_edge_threshold = threshold;
+        })
+        .await
+    }
+
+    async fn set_pointer_default_position(
+        &self,
+        request: Request<SetPointerDefaultPositionRequest>,
+    ) -> TonicResult<()> {
+        let enabled = request.into_inner().enabled;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.set_pointer_default_position(enabled);
+        })
+        .await
+    }
+
+    async fn set_warp_on_output_focus(
+        &self,
+        request: Request<SetWarpOnOutputFocusRequest>,
+    ) -> TonicResult<()> {
+        let enabled = request.into_inner().enabled;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.input_state.warp_on_output_focus = enabled;
+        })
+        .await
+    }
+
+    async fn set_double_click_time(
+        &self,
+        request: Request<SetDoubleClickTimeRequest>,
+    ) -> TonicResult<()> {
+        let time = Duration::from_millis(request.into_inner().time_ms.into());
+
+        run_unary_no_response(&self.sender, move |state| {
+            state
+                .pinnacle
+                .input_state
+                .clicks
+                .set_double_click_time(time);
+        })
+        .await
+    }
+
+    async fn reset_pointer_position(
+        &self,
+        _request: Request<ResetPointerPositionRequest>,
+    ) -> TonicResult<()> {
+        run_unary_no_response(&self.sender, move |state| {
+            state.reset_pointer_position();
+        })
+        .await
+    }
+
+    async fn confine_pointer_to_output(
+        &self,
+        request: Request<ConfinePointerToOutputRequest>,
+    ) -> TonicResult<()> {
+        let output_name = request.into_inner().output_name.map(OutputName);
+
+        run_unary(&self.sender, move |state| {
+            let output = match output_name {
+                Some(output_name) => {
+                    let Some(output) = output_name.output(&state.pinnacle) else {
+                        return Err(Status::not_found(format!(
+                            "output {} does not exist",
+                            output_name.0
+                        )));
+                    };
+                    Some(output)
+                }
+                None => None,
+            };
+
+            state.confine_pointer_to_output(output.as_ref());
+
+            Ok(())
+        })
+        .await
+    }
+
     async fn set_xkb_config(&self, request: Request<SetXkbConfigRequest>) -> TonicResult<()> {
         let request = request.into_inner();
 
@@ -559,10 +876,14 @@ impl input::v1::input_service_server::InputService for InputService {
                 layout: request.layout(),
                 options: request.options.clone(),
             };
-            if let Some(kb) = state.pinnacle.seat.get_keyboard()
-                && let Err(err) = kb.set_xkb_config(state, new_config)
-            {
-                error!("Failed to set xkbconfig: {err}");
+            let Some(kb) = state.pinnacle.seat.get_keyboard() else {
+                return;
+            };
+            // A new keymap comes with a fresh xkb state, which would turn NumLock off
+            let num_lock = kb.modifier_state().num_lock;
+            match kb.set_xkb_config(state, new_config) {
+                Ok(()) => state.set_numlock(num_lock),
+                Err(err) => error!("Failed to set xkbconfig: {err}"),
             }
         })
         .await
@@ -593,8 +914,10 @@ impl input::v1::input_service_server::InputService for InputService {
             let Some(kb) = state.pinnacle.seat.get_keyboard() else {
                 return;
             };
-            if let Err(err) = kb.set_keymap_from_string(state, keymap) {
-                warn!("Failed to set keymap: {err}");
+            let num_lock = kb.modifier_state().num_lock;
+            match kb.set_keymap_from_string(state, keymap) {
+                Ok(()) => state.set_numlock(num_lock),
+                Err(err) => warn!("Failed to set keymap: {err}"),
             }
         })
         .await
@@ -625,6 +948,39 @@ impl input::v1::input_service_server::InputService for InputService {
         .await
     }
 
+    async fn set_sticky_keys(&self, request: Request<SetStickyKeysRequest>) -> TonicResult<()> {
+        let enabled = request.into_inner().enabled;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.set_sticky_keys(enabled);
+        })
+        .await
+    }
+
+    async fn set_numlock(&self, request: Request<SetNumlockRequest>) -> TonicResult<()> {
+        let enabled = request.into_inner().enabled;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.set_numlock(enabled);
+        })
+        .await
+    }
+
+    async fn set_compose_enabled(
+        &self,
+        request: Request<SetComposeEnabledRequest>,
+    ) -> TonicResult<()> {
+        let enabled = request.into_inner().enabled;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.input_state.compose.set_enabled(enabled);
+            if enabled {
+                state.reserve_spare_key();
+            }
+        })
+        .await
+    }
+
     // FIXME: FROM IMPLS PLEASE
     async fn set_device_libinput_setting(
         &self,
@@ -819,6 +1175,169 @@ impl input::v1::input_service_server::InputService for InputService {
         .await
     }
 
+    async fn set_cursor_visible(
+        &self,
+        request: Request<SetCursorVisibleRequest>,
+    ) -> TonicResult<()> {
+        let visible = request.into_inner().visible;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.set_cursor_visible(visible);
+        })
+        .await
+    }
+
+    async fn set_cursor_autohide(
+        &self,
+        request: Request<SetCursorAutohideRequest>,
+    ) -> TonicResult<()> {
+        let hide_after = request
+            .into_inner()
+            .hide_after_ms
+            .map(|hide_after_ms| Duration::from_millis(hide_after_ms.into()));
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.set_cursor_autohide(hide_after);
+        })
+        .await
+    }
+
+    async fn type_string(&self, request: Request<TypeStringRequest>) -> TonicResult<()> {
+        let text = request.into_inner().text;
+
+        run_unary_no_response(&self.sender, move |state| {
+            if !state.pinnacle.config.allow_input_injection {
+                warn!("Not typing string, input injection is not allowed");
+                return;
+            }
+
+            state.type_string(&text);
+        })
+        .await
+    }
+
+    async fn send_combo(&self, request: Request<SendComboRequest>) -> TonicResult<()> {
+        let keysyms = request
+            .into_inner()
+            .keys
+            .iter()
+            .map(|key| {
+                keysym_from_keybind(key).ok_or_else(|| Status::invalid_argument("no key specified"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        run_unary_no_response(&self.sender, move |state| {
+            if !state.pinnacle.config.allow_input_injection {
+                warn!("Not sending combo, input injection is not allowed");
+                return;
+            }
+
+            state.send_combo(&keysyms);
+        })
+        .await
+    }
+
+    async fn set_idle_dim(&self, request: Request<SetIdleDimRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let config = match request.dim_after_ms {
+            Some(dim_after_ms) => {
+                if !(0.0..=1.0).contains(&request.dim_level) {
+                    return Err(Status::invalid_argument(
+                        "dim level must be between 0.0 and 1.0",
+                    ));
+                }
+
+                Some(IdleDimConfig {
+                    dim_after: Duration::from_millis(dim_after_ms.into()),
+                    dim_level: request.dim_level,
+                })
+            }
+            None => None,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.set_idle_dim(config);
+        })
+        .await
+    }
+
+    async fn get_pointer_focus(
+        &self,
+        _request: Request<GetPointerFocusRequest>,
+    ) -> TonicResult<GetPointerFocusResponse> {
+        run_unary(&self.sender, move |state| {
+            let focus = state
+                .pinnacle
+                .seat
+                .get_pointer()
+                .and_then(|pointer| pointer.current_focus())
+                .and_then(|target| {
+                    if let Some(window) = target.window_for(&state.pinnacle) {
+                        return Some(get_pointer_focus_response::Focus::WindowId(
+                            window.with_state(|state| state.id.0),
+                        ));
+                    }
+
+                    let layer = target.layer_for(&state.pinnacle)?;
+                    let output_name = state
+                        .pinnacle
+                        .space
+                        .outputs()
+                        .find(|op| layer_map_for_output(op).layers().any(|l| l == &layer))
+                        .map(|op| op.name())
+                        .unwrap_or_default();
+
+                    Some(get_pointer_focus_response::Focus::Layer(LayerSurfaceInfo {
+                        namespace: layer.namespace().to_string(),
+                        output_name,
+                    }))
+                });
+
+            Ok(GetPointerFocusResponse { focus })
+        })
+        .await
+    }
+
+    async fn get_keyboard_focus(
+        &self,
+        _request: Request<GetKeyboardFocusRequest>,
+    ) -> TonicResult<GetKeyboardFocusResponse> {
+        run_unary(&self.sender, move |state| {
+            let window_id = state
+                .pinnacle
+                .seat
+                .get_keyboard()
+                .and_then(|keyboard| keyboard.current_focus())
+                .and_then(|target| match target {
+                    KeyboardFocusTarget::Window(window) => Some(window),
+                    KeyboardFocusTarget::Popup(popup) => {
+                        let root = find_popup_root_surface(&popup).ok()?;
+                        state.pinnacle.window_for_surface(&root).cloned()
+                    }
+                    KeyboardFocusTarget::LayerSurface(_) | KeyboardFocusTarget::LockSurface(_) => {
+                        None
+                    }
+                })
+                .map(|window| window.with_state(|state| state.id.0));
+
+            Ok(GetKeyboardFocusResponse { window_id })
+        })
+        .await
+    }
+
+    async fn get_idle_inhibited(
+        &self,
+        _request: Request<GetIdleInhibitedRequest>,
+    ) -> TonicResult<GetIdleInhibitedResponse> {
+        run_unary(&self.sender, move |state| {
+            Ok(GetIdleInhibitedResponse {
+                inhibited: state.pinnacle.is_idle_inhibited(),
+            })
+        })
+        .await
+    }
+
     async fn get_devices(
         &self,
         _request: Request<GetDevicesRequest>,
@@ -990,3 +1509,20 @@ impl input::v1::input_service_server::InputService for InputService {
         .await
     }
 }
+
+/// Resolves the keysym of a [`Keybind`][input::v1::Keybind].
+///
+/// The key code takes precedence over the xkb name.
+fn keysym_from_keybind(keybind: &input::v1::Keybind) -> Option<xkbcommon::xkb::Keysym> {
+    if let Some(key_code) = keybind.key_code {
+        return Some(xkbcommon::xkb::Keysym::new(key_code));
+    }
+
+    let xkb_name = keybind.xkb_name.as_ref()?;
+
+    let mut chars = xkb_name.chars();
+    Some(match (chars.next(), chars.next()) {
+        (Some(ch), None) => xkbcommon::xkb::Keysym::from_char(ch),
+        _ => xkbcommon::xkb::keysym_from_name(xkb_name, xkbcommon::xkb::KEYSYM_NO_FLAGS),
+    })
+}
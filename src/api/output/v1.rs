@@ -1,17 +1,27 @@
+use std::{path::PathBuf, time::Duration};
+
 use pinnacle_api_defs::pinnacle::{
     output::{
         self,
         v1::{
-            FocusRequest, FocusResponse, GetEnabledRequest, GetEnabledResponse,
-            GetFocusStackWindowIdsRequest, GetFocusStackWindowIdsResponse, GetFocusedRequest,
-            GetFocusedResponse, GetInfoRequest, GetInfoResponse, GetLocRequest, GetLocResponse,
-            GetLogicalSizeRequest, GetLogicalSizeResponse, GetModesRequest, GetModesResponse,
-            GetOutputsInDirRequest, GetOutputsInDirResponse, GetPhysicalSizeRequest,
-            GetPhysicalSizeResponse, GetPoweredRequest, GetPoweredResponse, GetRequest,
-            GetResponse, GetScaleRequest, GetScaleResponse, GetTagIdsRequest, GetTagIdsResponse,
-            GetTransformRequest, GetTransformResponse, SetLocRequest, SetModeRequest,
-            SetModelineRequest, SetPoweredRequest, SetScaleRequest, SetTransformRequest,
-            SetVrrRequest, SetVrrResponse,
+            AddCustomModeRequest, ConfirmModeRequest, FocusInDirRequest, FocusRequest,
+            FocusResponse, GetBlankedRequest, GetBlankedResponse, GetEnabledRequest,
+            GetEnabledResponse, GetFocusStackWindowIdsRequest, GetFocusStackWindowIdsResponse,
+            GetFocusedRequest, GetFocusedResponse, GetInfoRequest, GetInfoResponse, GetLocRequest,
+            GetLocResponse, GetLogicalSizeRequest, GetLogicalSizeResponse, GetModesRequest,
+            GetModesResponse, GetOutputsInDirRequest, GetOutputsInDirResponse,
+            GetPhysicalSizeRequest, GetPhysicalSizeResponse, GetPoweredRequest, GetPoweredResponse,
+            GetPresentTimingRequest, GetPresentTimingResponse, GetRequest, GetResponse,
+            GetScaleRequest, GetScaleResponse, GetTagIdsRequest, GetTagIdsResponse,
+            GetTransformRequest, GetTransformResponse, SetAutoResolveOverlapsRequest,
+            SetAutoScaleRequest, SetBlankedRequest, SetColorFilterRequest, SetDpmsTimeoutRequest,
+            SetDynamicTagsRequest, SetExclusiveZoneIgnoredNamespacesRequest, SetGameModeRequest,
+            SetGammaFromFileRequest, SetIdentityOverrideRequest, SetLayoutModeRequest,
+            SetLegacyScaleModeRequest, SetLocRequest, SetModeRequest, SetModelineRequest,
+            SetOcclusionCullingRequest, SetPoweredRequest, SetRespectExclusiveZonesRequest,
+            SetScaleRequest, SetSupersampleFactorRequest, SetTransformRequest,
+            SetTransitionAnimationRequest, SetVrrDemandMatcherRequest, SetVrrRequest,
+            SetVrrResponse, TryModeRequest, ViewTagsRequest,
         },
     },
     util::{
@@ -19,7 +29,9 @@ use pinnacle_api_defs::pinnacle::{
         v1::{AbsOrRel, SetOrToggle},
     },
 };
-use smithay::output::Scale;
+use smithay::{
+    output::Scale, reexports::wayland_protocols::wp::content_type::v1::server::wp_content_type_v1,
+};
 use tonic::{Request, Status};
 use tracing::debug;
 
@@ -27,9 +39,14 @@ use crate::{
     api::{TonicResult, run_unary, run_unary_no_response},
     backend::udev::drm_mode_from_modeinfo,
     config::ConnectorSavedState,
-    output::{OutputMode, OutputName},
+    output::{
+        LegacyScaleMode, OutputIdentity, OutputIdentityOverride, OutputLayoutMode, OutputMode,
+        OutputName, auto_scale,
+    },
+    render::{color_filter::ColorFilter, color_lut, supersample::MAX_SUPERSAMPLE_FACTOR},
     state::{State, WithState},
     util::rect::Direction,
+    window::window_state::VrrDemandMatcher,
 };
 
 #[tonic::async_trait]
@@ -130,6 +147,7 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
                 return Ok(());
             };
 
+            state.pinnacle.cancel_mode_revert(&output);
             state.pinnacle.change_output_state(
                 &mut state.backend,
                 &output,
@@ -149,6 +167,64 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
         .await
     }
 
+    async fn try_mode(&self, request: Request<TryModeRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+        let output_name = OutputName(request.output_name);
+
+        let Some(size) = request.size else {
+            return Err(Status::invalid_argument("no size specified"));
+        };
+
+        if request.revert_after_ms == 0 {
+            return Err(Status::invalid_argument("revert timeout must be non-zero"));
+        }
+
+        let revert_after = Duration::from_millis(request.revert_after_ms as u64);
+
+        run_unary(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return Err(Status::not_found(format!(
+                    "output {} does not exist",
+                    output_name.0
+                )));
+            };
+
+            let Some(mode) = crate::output::try_pick_mode(
+                &output,
+                size.width,
+                size.height,
+                request.refresh_rate_mhz,
+            ) else {
+                return Err(Status::invalid_argument(format!(
+                    "output {} does not support that mode",
+                    output_name.0
+                )));
+            };
+
+            state
+                .backend
+                .test_output_mode(&output, OutputMode::Smithay(mode))
+                .map_err(|err| {
+                    Status::invalid_argument(format!(
+                        "output {} rejected that mode: {err:#}",
+                        output_name.0
+                    ))
+                })?;
+
+            state.try_output_mode(&output, mode, revert_after);
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn confirm_mode(&self, _request: Request<ConfirmModeRequest>) -> TonicResult<()> {
+        run_unary_no_response(&self.sender, |state| {
+            state.confirm_output_modes();
+        })
+        .await
+    }
+
     async fn set_modeline(&self, request: Request<SetModelineRequest>) -> TonicResult<()> {
         let request = request.into_inner();
         let output_name = OutputName(request.output_name);
@@ -189,6 +265,7 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
                 vsync,
             );
 
+            state.pinnacle.cancel_mode_revert(&output);
             state.pinnacle.change_output_state(
                 &mut state.backend,
                 &output,
@@ -231,6 +308,8 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
 
             current_scale = f64::max(current_scale, 0.25);
 
+            output.with_state_mut(|state| state.scale_settled = true);
+
             state.pinnacle.change_output_state(
                 &mut state.backend,
                 &output,
@@ -324,6 +403,96 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
         .await
     }
 
+    async fn set_blanked(&self, request: Request<SetBlankedRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+        let blanked = request.blanked;
+        let output_name = OutputName(request.output_name);
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return;
+            };
+
+            state.set_output_blanked(&output, blanked);
+        })
+        .await
+    }
+
+    async fn set_layout_mode(&self, request: Request<SetLayoutModeRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+        let output_name = OutputName(request.output_name);
+
+        let layout_mode = match request.layout_mode() {
+            output::v1::LayoutMode::Unspecified => {
+                return Err(Status::invalid_argument("unspecified layout mode"));
+            }
+            output::v1::LayoutMode::Tiling => OutputLayoutMode::Tiling,
+            output::v1::LayoutMode::Floating => OutputLayoutMode::Floating,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return;
+            };
+
+            state.set_output_layout_mode(&output, layout_mode);
+        })
+        .await
+    }
+
+    async fn set_color_filter(&self, request: Request<SetColorFilterRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+        let output_name = OutputName(request.output_name);
+
+        let color_filter = match request.color_filter() {
+            output::v1::ColorFilter::Unspecified => {
+                return Err(Status::invalid_argument("unspecified color filter"));
+            }
+            output::v1::ColorFilter::None => ColorFilter::None,
+            output::v1::ColorFilter::Grayscale => ColorFilter::Grayscale,
+            output::v1::ColorFilter::Protanopia => ColorFilter::Protanopia,
+            output::v1::ColorFilter::Deuteranopia => ColorFilter::Deuteranopia,
+            output::v1::ColorFilter::Tritanopia => ColorFilter::Tritanopia,
+            output::v1::ColorFilter::Invert => ColorFilter::Invert,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return;
+            };
+
+            output.with_state_mut(|state| state.color_filter = color_filter);
+            state.schedule_render(&output);
+        })
+        .await
+    }
+
+    async fn set_supersample_factor(
+        &self,
+        request: Request<SetSupersampleFactorRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+        let output_name = OutputName(request.output_name);
+
+        if !request.factor.is_finite() || request.factor < 1.0 {
+            return Err(Status::invalid_argument(
+                "supersample factor must be at least 1.0",
+            ));
+        }
+
+        let factor = request.factor.min(MAX_SUPERSAMPLE_FACTOR);
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return;
+            };
+
+            output.with_state_mut(|state| state.supersample_factor = factor);
+            state.schedule_render(&output);
+        })
+        .await
+    }
+
     async fn set_vrr(&self, request: Request<SetVrrRequest>) -> TonicResult<SetVrrResponse> {
         let request = request.into_inner();
         let vrr = request.vrr();
@@ -354,6 +523,316 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
         .await
     }
 
+    async fn set_vrr_demand_matcher(
+        &self,
+        request: Request<SetVrrDemandMatcherRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let matcher = match request.matcher {
+            Some(matcher) => {
+                let app_id = matcher
+                    .app_id
+                    .as_deref()
+                    .map(regex::Regex::new)
+                    .transpose()
+                    .map_err(|err| {
+                        Status::invalid_argument(format!("invalid app id regex: {err}"))
+                    })?;
+
+                let content_types = matcher
+                    .content_types()
+                    .filter_map(|content_type| match content_type {
+                        output::v1::ContentType::Unspecified => None,
+                        output::v1::ContentType::None => Some(wp_content_type_v1::Type::None),
+                        output::v1::ContentType::Photo => Some(wp_content_type_v1::Type::Photo),
+                        output::v1::ContentType::Video => Some(wp_content_type_v1::Type::Video),
+                        output::v1::ContentType::Game => Some(wp_content_type_v1::Type::Game),
+                    })
+                    .collect();
+
+                Some(VrrDemandMatcher {
+                    app_id,
+                    content_types,
+                    fullscreen: matcher.fullscreen,
+                })
+            }
+            None => None,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.vrr_demand_matcher = matcher;
+
+            for win in state.pinnacle.windows.iter() {
+                win.with_state_mut(|state| state.matched_vrr_demand = None);
+            }
+        })
+        .await
+    }
+
+    async fn set_legacy_scale_mode(
+        &self,
+        request: Request<SetLegacyScaleModeRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let mode = match request.mode() {
+            output::v1::LegacyScaleMode::Unspecified => {
+                return Err(Status::invalid_argument("unspecified legacy scale mode"));
+            }
+            output::v1::LegacyScaleMode::Ceil => LegacyScaleMode::Ceil,
+            output::v1::LegacyScaleMode::Floor => LegacyScaleMode::Floor,
+            output::v1::LegacyScaleMode::Round => LegacyScaleMode::Round,
+            output::v1::LegacyScaleMode::Prefer => {
+                if request.preferred_scale < 1 {
+                    return Err(Status::invalid_argument(
+                        "preferred scale must be at least 1",
+                    ));
+                }
+                LegacyScaleMode::Prefer(request.preferred_scale)
+            }
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.set_legacy_scale_mode(mode);
+        })
+        .await
+    }
+
+    async fn set_auto_scale(&self, request: Request<SetAutoScaleRequest>) -> TonicResult<()> {
+        let enabled = request.into_inner().auto_scale;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.auto_scale = enabled;
+
+            if !enabled {
+                return;
+            }
+
+            // Outputs connected on startup were connected before the config, so apply
+            // the heuristic to the ones whose scale wasn't set or restored. Outputs it was
+            // already applied to are left alone so reloads don't undo later changes.
+            let outputs = state
+                .pinnacle
+                .space
+                .outputs()
+                .filter(|output| output.with_state(|state| !state.scale_settled))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            for output in outputs {
+                output.with_state_mut(|state| state.scale_settled = true);
+                let scale = Scale::Fractional(auto_scale(&output));
+                state.pinnacle.change_output_state(
+                    &mut state.backend,
+                    &output,
+                    None,
+                    None,
+                    Some(scale),
+                    None,
+                );
+
+                state.pinnacle.request_layout(&output);
+                state.schedule_render(&output);
+            }
+
+            state
+                .pinnacle
+                .output_management_manager_state
+                .update::<State>();
+        })
+        .await
+    }
+
+    async fn set_dynamic_tags(&self, request: Request<SetDynamicTagsRequest>) -> TonicResult<()> {
+        let dynamic_tags = request.into_inner().dynamic_tags;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.dynamic_tags = dynamic_tags;
+        })
+        .await
+    }
+
+    async fn set_dpms_timeout(&self, request: Request<SetDpmsTimeoutRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let timeout = request
+            .timeout_ms
+            .map(|timeout_ms| Duration::from_millis(timeout_ms.into()));
+        let output_name = request.output_name.map(OutputName);
+
+        run_unary_no_response(&self.sender, move |state| match output_name {
+            Some(output_name) => {
+                let Some(output) = output_name.output(&state.pinnacle) else {
+                    return;
+                };
+                state.pinnacle.set_output_dpms_timeout(&output, timeout);
+            }
+            None => state.pinnacle.set_dpms_timeout(timeout),
+        })
+        .await
+    }
+
+    async fn set_transition_animation(
+        &self,
+        request: Request<SetTransitionAnimationRequest>,
+    ) -> TonicResult<()> {
+        let enabled = request.into_inner().enabled;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.transition_animation = enabled;
+        })
+        .await
+    }
+
+    async fn set_occlusion_culling(
+        &self,
+        request: Request<SetOcclusionCullingRequest>,
+    ) -> TonicResult<()> {
+        let enabled = request.into_inner().enabled;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.occlusion_culling = enabled;
+
+            for output in state.pinnacle.outputs.clone() {
+                state.schedule_render(&output);
+            }
+        })
+        .await
+    }
+
+    async fn set_respect_exclusive_zones(
+        &self,
+        request: Request<SetRespectExclusiveZonesRequest>,
+    ) -> TonicResult<()> {
+        let respect = request.into_inner().respect;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.respect_exclusive_zones = respect;
+
+            for output in state.pinnacle.outputs.clone() {
+                state.pinnacle.request_layout(&output);
+            }
+        })
+        .await
+    }
+
+    async fn set_exclusive_zone_ignored_namespaces(
+        &self,
+        request: Request<SetExclusiveZoneIgnoredNamespacesRequest>,
+    ) -> TonicResult<()> {
+        let namespaces = request.into_inner().namespaces;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.exclusive_zone_ignored_namespaces = namespaces;
+
+            for output in state.pinnacle.outputs.clone() {
+                state.pinnacle.request_layout(&output);
+            }
+        })
+        .await
+    }
+
+    async fn set_auto_resolve_overlaps(
+        &self,
+        request: Request<SetAutoResolveOverlapsRequest>,
+    ) -> TonicResult<()> {
+        let auto_resolve = request.into_inner().auto_resolve;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.auto_resolve_output_overlaps = auto_resolve;
+
+            if auto_resolve && let Some(output) = state.pinnacle.focused_output().cloned() {
+                state.pinnacle.resolve_output_overlaps(&output);
+                state
+                    .pinnacle
+                    .output_management_manager_state
+                    .update::<State>();
+            }
+        })
+        .await
+    }
+
+    async fn set_game_mode(&self, request: Request<SetGameModeRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+        let output_name = OutputName(request.output_name);
+        let enabled = request.enabled;
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return;
+            };
+
+            state.set_output_game_mode(&output, enabled);
+        })
+        .await
+    }
+
+    async fn set_gamma_from_file(
+        &self,
+        request: Request<SetGammaFromFileRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let output_name = OutputName(request.output_name);
+        let path = PathBuf::from(request.path);
+
+        run_unary(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return Err(Status::not_found(format!(
+                    "output {} does not exist",
+                    output_name.0
+                )));
+            };
+
+            color_lut::load_color_lut(&mut state.backend, &output, &path).map_err(|err| {
+                Status::invalid_argument(format!("failed to set gamma from file: {err:#}"))
+            })
+        })
+        .await
+    }
+
+    async fn add_custom_mode(&self, request: Request<AddCustomModeRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let output_name = OutputName(request.output_name);
+        let Some(size) = request.size else {
+            return Err(Status::invalid_argument("no size specified"));
+        };
+        let refresh_rate_mhz = request.refresh_rate_mhz;
+        let reduced_blanking = request.reduced_blanking;
+
+        run_unary(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return Err(Status::not_found(format!(
+                    "output {} does not exist",
+                    output_name.0
+                )));
+            };
+
+            state
+                .backend
+                .add_custom_mode(
+                    &output,
+                    size.width,
+                    size.height,
+                    refresh_rate_mhz,
+                    reduced_blanking,
+                )
+                .map_err(|err| {
+                    Status::invalid_argument(format!("failed to add custom mode: {err:#}"))
+                })?;
+
+            state
+                .pinnacle
+                .output_management_manager_state
+                .update::<State>();
+
+            Ok(())
+        })
+        .await
+    }
+
     async fn focus(&self, request: Request<FocusRequest>) -> TonicResult<FocusResponse> {
         let request = request.into_inner();
 
@@ -371,23 +850,34 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
         .await
     }
 
+    async fn view_tags(&self, request: Request<ViewTagsRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let output_name = OutputName(request.output_name);
+        let tag_names = request.tag_names;
+        let keep_active = request.keep_active;
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return;
+            };
+
+            crate::api::tag::view_tags(state, &output, &tag_names, keep_active);
+        })
+        .await
+    }
+
     async fn get_info(&self, request: Request<GetInfoRequest>) -> TonicResult<GetInfoResponse> {
         let output_name = OutputName(request.into_inner().output_name);
 
         run_unary(&self.sender, move |state| {
-            let output = output_name.output(&state.pinnacle);
-
-            let make = output
-                .as_ref()
-                .map(|op| op.physical_properties().make)
-                .unwrap_or_default();
-            let model = output
-                .as_ref()
-                .map(|op| op.physical_properties().model)
-                .unwrap_or_default();
-            let serial = output
-                .as_ref()
-                .map(|op| op.physical_properties().serial_number)
+            let OutputIdentity {
+                make,
+                model,
+                serial,
+            } = output_name
+                .output(&state.pinnacle)
+                .map(|output| state.pinnacle.output_identity(&output))
                 .unwrap_or_default();
 
             Ok(GetInfoResponse {
@@ -399,6 +889,31 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
         .await
     }
 
+    async fn set_identity_override(
+        &self,
+        request: Request<SetIdentityOverrideRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let output_name = OutputName(request.output_name);
+        let identity_override = OutputIdentityOverride {
+            make: request.make,
+            model: request.model,
+            serial: request.serial,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            let overrides = &mut state.pinnacle.config.output_identity_overrides;
+
+            if identity_override == OutputIdentityOverride::default() {
+                overrides.remove(&output_name);
+            } else {
+                overrides.insert(output_name, identity_override);
+            }
+        })
+        .await
+    }
+
     async fn get_loc(&self, request: Request<GetLocRequest>) -> TonicResult<GetLocResponse> {
         let output_name = OutputName(request.into_inner().output_name);
 
@@ -502,6 +1017,31 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
         .await
     }
 
+    async fn get_present_timing(
+        &self,
+        request: Request<GetPresentTimingRequest>,
+    ) -> TonicResult<GetPresentTimingResponse> {
+        let output_name = OutputName(request.into_inner().output_name);
+
+        run_unary(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return Ok(GetPresentTimingResponse::default());
+            };
+
+            let diagnostics = state
+                .backend
+                .render_diagnostics(&output, &state.pinnacle.clock);
+
+            Ok(GetPresentTimingResponse {
+                refresh_rate_mhz: output.current_mode().map(|mode| mode.refresh as u32),
+                ms_since_last_presentation: diagnostics
+                    .time_since_last_presentation
+                    .map(|time| time.as_millis() as u64),
+            })
+        })
+        .await
+    }
+
     async fn get_focused(
         &self,
         request: Request<GetFocusedRequest>,
@@ -632,6 +1172,23 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
         .await
     }
 
+    async fn get_blanked(
+        &self,
+        request: Request<GetBlankedRequest>,
+    ) -> TonicResult<GetBlankedResponse> {
+        let output_name = OutputName(request.into_inner().output_name);
+
+        run_unary(&self.sender, move |state| {
+            let blanked = output_name
+                .output(&state.pinnacle)
+                .map(|output| output.with_state(|state| state.blanked))
+                .unwrap_or_default();
+
+            Ok(GetBlankedResponse { blanked })
+        })
+        .await
+    }
+
     async fn get_focus_stack_window_ids(
         &self,
         request: Request<GetFocusStackWindowIdsRequest>,
@@ -676,32 +1233,51 @@ impl output::v1::output_service_server::OutputService for super::OutputService {
                 return Ok(GetOutputsInDirResponse::default());
             };
 
-            let Some(op_rect) = state.pinnacle.space.output_geometry(&op) else {
-                return Ok(GetOutputsInDirResponse::default());
-            };
+            let output_names = state
+                .pinnacle
+                .outputs_in_dir(&op, direction_from_dir(dir))
+                .into_iter()
+                .map(|op| op.name())
+                .collect();
 
-            let candidates = state.pinnacle.space.outputs().collect::<Vec<_>>();
-            let rects = candidates
-                .iter()
-                .map(|op| state.pinnacle.space.output_geometry(op).expect("mapped"))
-                .collect::<Vec<_>>();
+            Ok(GetOutputsInDirResponse { output_names })
+        })
+        .await
+    }
 
-            let idxs = crate::util::rect::closest_in_dir(
-                op_rect,
-                &rects,
-                match dir {
-                    util::v1::Dir::Unspecified => unreachable!(),
-                    util::v1::Dir::Left => Direction::Left,
-                    util::v1::Dir::Right => Direction::Right,
-                    util::v1::Dir::Up => Direction::Up,
-                    util::v1::Dir::Down => Direction::Down,
-                },
-            );
+    async fn focus_in_dir(&self, request: Request<FocusInDirRequest>) -> TonicResult<()> {
+        let dir = request.into_inner().dir();
 
-            let output_names = idxs.into_iter().map(|idx| candidates[idx].name()).collect();
+        if dir == util::v1::Dir::Unspecified {
+            return Err(Status::invalid_argument("no dir was specified"));
+        }
 
-            Ok(GetOutputsInDirResponse { output_names })
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(focused) = state.pinnacle.focused_output().cloned() else {
+                return;
+            };
+
+            let Some(output) = state
+                .pinnacle
+                .outputs_in_dir(&focused, direction_from_dir(dir))
+                .into_iter()
+                .next()
+            else {
+                return;
+            };
+
+            state.pinnacle.focus_output(&output);
         })
         .await
     }
 }
+
+fn direction_from_dir(dir: util::v1::Dir) -> Direction {
+    match dir {
+        util::v1::Dir::Unspecified => unreachable!(),
+        util::v1::Dir::Left => Direction::Left,
+        util::v1::Dir::Right => Direction::Right,
+        util::v1::Dir::Up => Direction::Up,
+        util::v1::Dir::Down => Direction::Down,
+    }
+}
@@ -1,9 +1,10 @@
 use pinnacle_api_defs::pinnacle::{
     self,
     v1::{
-        self, BackendRequest, BackendResponse, KeepaliveRequest, KeepaliveResponse, QuitRequest,
-        ReloadConfigRequest, SetLastErrorRequest, SetXwaylandClientSelfScaleRequest,
-        TakeLastErrorRequest, TakeLastErrorResponse,
+        self, BackendRequest, BackendResponse, KeepaliveRequest, KeepaliveResponse, MetricsRequest,
+        MetricsResponse, QuitRequest, ReloadConfigRequest, RendererInfoRequest,
+        RendererInfoResponse, SetLastErrorRequest, SetPreserveLayoutOnReloadRequest,
+        SetXwaylandClientSelfScaleRequest, TakeLastErrorRequest, TakeLastErrorResponse,
     },
 };
 use tonic::{Request, Streaming};
@@ -48,6 +49,9 @@ impl v1::pinnacle_service_server::PinnacleService for super::PinnacleService {
             |state, sender, _| {
                 let (oneshot_tx, oneshot_rx) = tokio::sync::oneshot::channel::<()>();
                 state.pinnacle.config.keepalive_sender.replace(oneshot_tx);
+                // Configs open the keepalive stream once they've finished setting up,
+                // so tags that saved layouts are still waiting for likely won't be added
+                state.pinnacle.finish_restoring_saved_window_layouts();
                 tokio::spawn(async move {
                     let _sender = sender;
                     let _ = oneshot_rx.await;
@@ -74,6 +78,34 @@ impl v1::pinnacle_service_server::PinnacleService for super::PinnacleService {
         .await
     }
 
+    async fn renderer_info(
+        &self,
+        _request: Request<RendererInfoRequest>,
+    ) -> TonicResult<RendererInfoResponse> {
+        run_unary(&self.sender, |state| {
+            let info = state.backend.renderer_info();
+
+            Ok(RendererInfoResponse {
+                renderer: info.renderer,
+                vendor: info.vendor,
+                version: info.version,
+                gpu_path: info
+                    .gpu_path
+                    .map(|path| path.to_string_lossy().into_owned()),
+                dmabuf_formats: info
+                    .dmabuf_formats
+                    .into_iter()
+                    .map(|format| v1::DmabufFormat {
+                        fourcc: format.code as u32,
+                        modifier: format.modifier.into(),
+                    })
+                    .collect(),
+                hardware_accel_error: state.pinnacle.hardware_accel_error.clone(),
+            })
+        })
+        .await
+    }
+
     async fn set_xwayland_client_self_scale(
         &self,
         request: Request<SetXwaylandClientSelfScaleRequest>,
@@ -89,6 +121,18 @@ impl v1::pinnacle_service_server::PinnacleService for super::PinnacleService {
         .await
     }
 
+    async fn set_preserve_layout_on_reload(
+        &self,
+        request: Request<SetPreserveLayoutOnReloadRequest>,
+    ) -> TonicResult<()> {
+        let preserve = request.into_inner().preserve;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.preserve_layout_on_reload = preserve;
+        })
+        .await
+    }
+
     async fn set_last_error(&self, request: Request<SetLastErrorRequest>) -> TonicResult<()> {
         let error = request.into_inner().error;
 
@@ -108,4 +152,21 @@ impl v1::pinnacle_service_server::PinnacleService for super::PinnacleService {
         })
         .await
     }
+
+    async fn metrics(&self, _request: Request<MetricsRequest>) -> TonicResult<MetricsResponse> {
+        run_unary(&self.sender, move |state| {
+            let metrics = &state.pinnacle.metrics;
+
+            Ok(MetricsResponse {
+                windows: state.pinnacle.windows.len() as u32,
+                outputs: state.pinnacle.outputs.len() as u32,
+                frames_rendered: metrics.frames_rendered,
+                frames_skipped: metrics.frames_skipped,
+                active_grpc_streams: metrics.active_grpc_streams(),
+                config_restarts: metrics.config_restarts,
+                memory_usage: crate::metrics::memory_usage(),
+            })
+        })
+        .await
+    }
 }
@@ -1,6 +1,9 @@
 use pinnacle_api_defs::pinnacle::process::{
     self,
-    v1::{SetEnvRequest, SpawnRequest, SpawnResponse, WaitOnSpawnRequest, WaitOnSpawnResponse},
+    v1::{
+        GetChildrenRequest, GetChildrenResponse, ReapRequest, ReapResponse, SetEnvRequest,
+        SpawnRequest, SpawnResponse, WaitOnSpawnRequest, WaitOnSpawnResponse,
+    },
 };
 use tonic::Request;
 
@@ -64,6 +67,7 @@ impl process::v1::process_service_server::ProcessService for super::ProcessServi
         request: Request<WaitOnSpawnRequest>,
     ) -> TonicResult<Self::WaitOnSpawnStream> {
         let pid = request.into_inner().pid;
+        let fn_sender = self.sender.clone();
 
         run_server_streaming(&self.sender, move |state, sender| {
             let wait_recv = state.pinnacle.process_state.wait_on_spawn(pid);
@@ -82,6 +86,11 @@ impl process::v1::process_service_server::ProcessService for super::ProcessServi
                     exit_code: exit.as_ref().and_then(|exit| exit.exit_code),
                     exit_msg: exit.as_ref().and_then(|exit| exit.exit_msg.clone()),
                 }));
+
+                // The exit info has been delivered, so the process doesn't need tracking anymore
+                let _ = fn_sender.send(Box::new(move |state| {
+                    state.pinnacle.process_state.remove_waited(pid);
+                }));
             });
 
             Ok(())
@@ -99,4 +108,31 @@ impl process::v1::process_service_server::ProcessService for super::ProcessServi
         })
         .await
     }
+
+    async fn reap(&self, _request: Request<ReapRequest>) -> TonicResult<ReapResponse> {
+        run_unary(&self.sender, move |state| {
+            let pids = state.pinnacle.process_state.reap();
+
+            Ok(ReapResponse { pids })
+        })
+        .await
+    }
+
+    async fn get_children(
+        &self,
+        _request: Request<GetChildrenRequest>,
+    ) -> TonicResult<GetChildrenResponse> {
+        run_unary(&self.sender, move |state| {
+            let mut children = state
+                .pinnacle
+                .process_state
+                .children()
+                .map(|(pid, alive)| process::v1::ChildStatus { pid, alive })
+                .collect::<Vec<_>>();
+            children.sort_by_key(|child| child.pid);
+
+            Ok(GetChildrenResponse { children })
+        })
+        .await
+    }
 }
@@ -1,13 +1,28 @@
+use std::{path::PathBuf, time::Duration};
+
 use pinnacle_api_defs::pinnacle::render::{
     self,
-    v1::{Filter, SetDownscaleFilterRequest, SetUpscaleFilterRequest},
+    v1::{
+        Filter, GetRenderStateRequest, GetRenderStateResponse, OutputThumbnailRequest,
+        OutputThumbnailResponse, ScreenshotWindowRequest, ScreenshotWindowResponse,
+        SetAnimationFpsCapRequest, SetCursorModeRequest, SetDownscaleFilterRequest,
+        SetFrameCallbackPolicyRequest, SetOverviewRequest, SetUpscaleFilterRequest,
+    },
+};
+use pinnacle_api_defs::pinnacle::util::v1::SetOrToggle;
+use smithay::{
+    backend::renderer::TextureFilter,
+    utils::{Physical, Size},
 };
-use smithay::backend::renderer::TextureFilter;
 use tonic::{Request, Status};
 
 use crate::{
-    api::{TonicResult, run_unary_no_response},
-    backend::BackendData,
+    api::{TonicResult, run_unary, run_unary_no_response},
+    backend::{BackendData, RenderResult},
+    cursor::CursorMode,
+    output::OutputName,
+    state::WithState,
+    window::{FrameCallbackPolicy, window_state::WindowId},
 };
 
 #[tonic::async_trait]
@@ -61,4 +76,224 @@ impl render::v1::render_service_server::RenderService for super::RenderService {
         })
         .await
     }
+
+    async fn screenshot_window(
+        &self,
+        request: Request<ScreenshotWindowRequest>,
+    ) -> TonicResult<ScreenshotWindowResponse> {
+        let request = request.into_inner();
+
+        let window_id = WindowId(request.window_id);
+        let path = request.path.map(PathBuf::from);
+
+        run_unary(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return Err(Status::not_found(format!(
+                    "window {} does not exist or is not mapped",
+                    window_id.0
+                )));
+            };
+
+            let scale = window
+                .output(&state.pinnacle)
+                .map(|output| output.current_scale().fractional_scale())
+                .unwrap_or(1.0);
+
+            let screenshot = state
+                .backend
+                .screenshot_window(&window, scale)
+                .map_err(|err| Status::internal(format!("failed to capture window: {err:#}")))?;
+
+            let width = screenshot.size.w as u32;
+            let height = screenshot.size.h as u32;
+
+            let rgba = match path {
+                Some(path) => {
+                    screenshot.save_png(&path).map_err(|err| {
+                        Status::invalid_argument(format!("failed to save screenshot: {err:#}"))
+                    })?;
+                    Vec::new()
+                }
+                None => screenshot.rgba,
+            };
+
+            Ok(ScreenshotWindowResponse {
+                width,
+                height,
+                rgba,
+            })
+        })
+        .await
+    }
+
+    async fn output_thumbnail(
+        &self,
+        request: Request<OutputThumbnailRequest>,
+    ) -> TonicResult<OutputThumbnailResponse> {
+        let request = request.into_inner();
+
+        let output_name = OutputName(request.output_name);
+
+        if request.max_width == 0 || request.max_height == 0 {
+            return Err(Status::invalid_argument(
+                "max_width and max_height must be non-zero",
+            ));
+        }
+
+        let max_size = Size::<i32, Physical>::from((
+            request.max_width.min(i32::MAX as u32) as i32,
+            request.max_height.min(i32::MAX as u32) as i32,
+        ));
+
+        run_unary(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return Err(Status::not_found(format!(
+                    "output {} does not exist",
+                    output_name.0
+                )));
+            };
+
+            let thumbnail = state
+                .output_thumbnail(&output, max_size)
+                .map_err(|err| Status::internal(format!("failed to capture output: {err:#}")))?;
+
+            Ok(OutputThumbnailResponse {
+                width: thumbnail.size.w as u32,
+                height: thumbnail.size.h as u32,
+                rgba: thumbnail.rgba,
+            })
+        })
+        .await
+    }
+
+    async fn set_animation_fps_cap(
+        &self,
+        request: Request<SetAnimationFpsCapRequest>,
+    ) -> TonicResult<()> {
+        let fps_cap = request.into_inner().fps_cap;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.animation_fps_cap = fps_cap;
+
+            if fps_cap == Some(0) {
+                for output in state.pinnacle.outputs.clone() {
+                    if output
+                        .with_state_mut(|state| state.transition.take())
+                        .is_some()
+                    {
+                        state.schedule_render(&output);
+                    }
+                }
+            }
+        })
+        .await
+    }
+
+    async fn set_frame_callback_policy(
+        &self,
+        request: Request<SetFrameCallbackPolicyRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let policy = match request.policy() {
+            render::v1::FrameCallbackPolicy::Unspecified => {
+                return Err(Status::invalid_argument(
+                    "unspecified frame callback policy",
+                ));
+            }
+            render::v1::FrameCallbackPolicy::Throttle => {
+                if request.throttle_interval_ms == 0 {
+                    return Err(Status::invalid_argument("throttle interval must not be 0"));
+                }
+                FrameCallbackPolicy::Throttle(Duration::from_millis(
+                    request.throttle_interval_ms.into(),
+                ))
+            }
+            render::v1::FrameCallbackPolicy::Always => FrameCallbackPolicy::Always,
+            render::v1::FrameCallbackPolicy::Never => FrameCallbackPolicy::Never,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.frame_callback_policy = policy;
+        })
+        .await
+    }
+
+    async fn set_cursor_mode(&self, request: Request<SetCursorModeRequest>) -> TonicResult<()> {
+        let mode = match request.into_inner().mode() {
+            render::v1::CursorMode::Unspecified => {
+                return Err(Status::invalid_argument("unspecified cursor mode"));
+            }
+            render::v1::CursorMode::Plane => CursorMode::Plane,
+            render::v1::CursorMode::Software => CursorMode::Software,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.cursor_mode = mode;
+            for output in state.pinnacle.outputs.clone() {
+                state.schedule_render(&output);
+            }
+        })
+        .await
+    }
+
+    async fn get_render_state(
+        &self,
+        request: Request<GetRenderStateRequest>,
+    ) -> TonicResult<GetRenderStateResponse> {
+        let output_name = OutputName(request.into_inner().output_name);
+
+        run_unary(&self.sender, move |state| {
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return Err(Status::not_found(format!(
+                    "output {} does not exist",
+                    output_name.0
+                )));
+            };
+
+            let diagnostics = state
+                .backend
+                .render_diagnostics(&output, &state.pinnacle.clock);
+
+            let last_result = match diagnostics.last_result {
+                None => render::v1::RenderResult::Unspecified,
+                Some(RenderResult::Submitted) => render::v1::RenderResult::Submitted,
+                Some(RenderResult::NoDamage) => render::v1::RenderResult::NoDamage,
+                Some(RenderResult::Skipped) => render::v1::RenderResult::Skipped,
+            };
+
+            Ok(GetRenderStateResponse {
+                last_result: last_result.into(),
+                ms_since_last_presentation: diagnostics
+                    .time_since_last_presentation
+                    .map(|time| time.as_millis() as u64),
+                render_scheduled: diagnostics.render_scheduled,
+            })
+        })
+        .await
+    }
+
+    async fn set_overview(&self, request: Request<SetOverviewRequest>) -> TonicResult<()> {
+        let set_or_toggle = request.into_inner().set_or_toggle();
+
+        if set_or_toggle == SetOrToggle::Unspecified {
+            return Err(Status::invalid_argument("unspecified set or toggle"));
+        }
+
+        run_unary_no_response(&self.sender, move |state| {
+            let open = match set_or_toggle {
+                SetOrToggle::Set => true,
+                SetOrToggle::Unset => false,
+                SetOrToggle::Toggle => state.pinnacle.overview.is_none(),
+                SetOrToggle::Unspecified => unreachable!(),
+            };
+
+            if open == state.pinnacle.overview.is_some() {
+                return;
+            }
+
+            state.toggle_overview();
+        })
+        .await
+    }
 }
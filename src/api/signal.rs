@@ -3,21 +3,32 @@ use std::{
     sync::atomic::{AtomicU32, Ordering},
 };
 
-use pinnacle_api_defs::pinnacle::signal::{
-    self,
-    v1::{
-        InputDeviceAddedRequest, InputDeviceAddedResponse, OutputConnectRequest,
-        OutputConnectResponse, OutputDisconnectRequest, OutputDisconnectResponse,
-        OutputFocusedRequest, OutputFocusedResponse, OutputMoveRequest, OutputMoveResponse,
-        OutputPointerEnterRequest, OutputPointerEnterResponse, OutputPointerLeaveRequest,
-        OutputPointerLeaveResponse, OutputResizeRequest, OutputResizeResponse, SignalRequest,
-        StreamControl, TagActiveRequest, TagActiveResponse, WindowFocusedRequest,
-        WindowFocusedResponse, WindowPointerEnterRequest, WindowPointerEnterResponse,
-        WindowPointerLeaveRequest, WindowPointerLeaveResponse, WindowTitleChangedRequest,
-        WindowTitleChangedResponse,
+use pinnacle_api_defs::pinnacle::{
+    signal::{
+        self,
+        v1::{
+            InputDeviceAddedRequest, InputDeviceAddedResponse, OutputConnectRequest,
+            OutputConnectResponse, OutputDisconnectRequest, OutputDisconnectResponse,
+            OutputFocusedRequest, OutputFocusedResponse, OutputMoveRequest, OutputMoveResponse,
+            OutputPointerEnterRequest, OutputPointerEnterResponse, OutputPointerLeaveRequest,
+            OutputPointerLeaveResponse, OutputResizeRequest, OutputResizeResponse,
+            PinnacleOutputLayoutChangedRequest, PinnacleOutputLayoutChangedResponse,
+            PinnaclePrepareForSleepRequest, PinnaclePrepareForSleepResponse, SignalRequest,
+            StreamControl, TagActiveRequest, TagActiveResponse, TagWindowCountChangedRequest,
+            TagWindowCountChangedResponse, WindowFocusedRequest, WindowFocusedResponse,
+            WindowGeometryChangedRequest, WindowGeometryChangedResponse,
+            WindowLayoutModeChangedRequest, WindowLayoutModeChangedResponse,
+            WindowPointerEnterRequest, WindowPointerEnterResponse, WindowPointerLeaveRequest,
+            WindowPointerLeaveResponse, WindowTitleChangedRequest, WindowTitleChangedResponse,
+            WindowUrgentChangedRequest, WindowUrgentChangedResponse,
+        },
     },
+    util,
+};
+use smithay::{
+    output::Output,
+    utils::{Logical, Rectangle},
 };
-use smithay::output::Output;
 use tonic::{Request, Response, Status, Streaming};
 use tracing::warn;
 
@@ -28,7 +39,7 @@ use crate::{
     window::WindowElement,
 };
 
-use super::{ResponseStream, StateFnSender, run_bidirectional_streaming};
+use super::{ResponseStream, StateFnSender, run_bidirectional_streaming, window::api_layout_mode};
 
 #[derive(Debug, Default)]
 pub struct SignalState {
@@ -46,12 +57,20 @@ pub struct SignalState {
     pub window_pointer_leave: WindowPointerLeave,
     pub window_focused: WindowFocused,
     pub window_title_changed: WindowTitleChanged,
+    pub window_urgent_changed: WindowUrgentChanged,
+    pub window_layout_mode_changed: WindowLayoutModeChanged,
+    pub window_geometry_changed: WindowGeometryChanged,
 
     // Tag
     pub tag_active: TagActive,
+    pub tag_window_count_changed: TagWindowCountChanged,
 
     // Input
     pub input_device_added: InputDeviceAdded,
+
+    // Pinnacle
+    pub pinnacle_prepare_for_sleep: PinnaclePrepareForSleep,
+    pub pinnacle_output_layout_changed: PinnacleOutputLayoutChanged,
 }
 
 impl SignalState {
@@ -68,10 +87,17 @@ impl SignalState {
         self.window_pointer_leave.clear();
         self.window_focused.clear();
         self.window_title_changed.clear();
+        self.window_urgent_changed.clear();
+        self.window_layout_mode_changed.clear();
+        self.window_geometry_changed.clear();
 
         self.tag_active.clear();
+        self.tag_window_count_changed.clear();
 
         self.input_device_added.clear();
+
+        self.pinnacle_prepare_for_sleep.clear();
+        self.pinnacle_output_layout_changed.clear();
     }
 }
 
@@ -332,6 +358,83 @@ impl Signal for WindowTitleChanged {
     }
 }
 
+#[derive(Debug, Default)]
+pub struct WindowUrgentChanged {
+    v1: SignalData<WindowUrgentChangedResponse>,
+}
+
+impl Signal for WindowUrgentChanged {
+    type Args<'a> = &'a WindowElement;
+
+    fn signal(&mut self, window: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(WindowUrgentChangedResponse {
+                window_id: window.with_state(|state| state.id.0),
+                urgent: window.with_state(|state| state.urgent),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.instances.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WindowLayoutModeChanged {
+    v1: SignalData<WindowLayoutModeChangedResponse>,
+}
+
+impl Signal for WindowLayoutModeChanged {
+    type Args<'a> = &'a WindowElement;
+
+    fn signal(&mut self, window: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(WindowLayoutModeChangedResponse {
+                window_id: window.with_state(|state| state.id.0),
+                layout_mode: api_layout_mode(window.with_state(|state| state.layout_mode)).into(),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.instances.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WindowGeometryChanged {
+    v1: SignalData<WindowGeometryChangedResponse>,
+}
+
+impl Signal for WindowGeometryChanged {
+    type Args<'a> = (&'a WindowElement, Rectangle<i32, Logical>);
+
+    /// Args: (window, new geometry)
+    fn signal(&mut self, args: Self::Args<'_>) {
+        let (window, geo) = args;
+        self.v1.signal(|buf| {
+            buf.push_back(WindowGeometryChangedResponse {
+                window_id: window.with_state(|state| state.id.0),
+                geometry: Some(util::v1::Rect {
+                    loc: Some(util::v1::Point {
+                        x: geo.loc.x,
+                        y: geo.loc.y,
+                    }),
+                    size: Some(util::v1::Size {
+                        width: geo.size.w.try_into().unwrap_or_default(),
+                        height: geo.size.h.try_into().unwrap_or_default(),
+                    }),
+                }),
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.instances.clear();
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct TagActive {
     v1: SignalData<signal::v1::TagActiveResponse>,
@@ -354,6 +457,28 @@ impl Signal for TagActive {
     }
 }
 
+#[derive(Debug, Default)]
+pub struct TagWindowCountChanged {
+    v1: SignalData<signal::v1::TagWindowCountChangedResponse>,
+}
+
+impl Signal for TagWindowCountChanged {
+    type Args<'a> = (&'a Tag, u32);
+
+    fn signal(&mut self, (tag, count): Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(signal::v1::TagWindowCountChangedResponse {
+                tag_id: tag.id().to_inner(),
+                count,
+            });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.instances.clear();
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct InputDeviceAdded {
     v1: SignalData<signal::v1::InputDeviceAddedResponse>,
@@ -375,6 +500,51 @@ impl Signal for InputDeviceAdded {
     }
 }
 
+#[derive(Debug, Default)]
+pub struct PinnaclePrepareForSleep {
+    v1: SignalData<PinnaclePrepareForSleepResponse>,
+}
+
+impl PinnaclePrepareForSleep {
+    /// Returns whether every connected client has finished handling this signal.
+    pub fn is_handled(&self) -> bool {
+        self.v1.is_idle()
+    }
+}
+
+impl Signal for PinnaclePrepareForSleep {
+    type Args<'a> = bool;
+
+    fn signal(&mut self, sleeping: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(PinnaclePrepareForSleepResponse { sleeping });
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.instances.clear();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PinnacleOutputLayoutChanged {
+    v1: SignalData<PinnacleOutputLayoutChangedResponse>,
+}
+
+impl Signal for PinnacleOutputLayoutChanged {
+    type Args<'a> = ();
+
+    fn signal(&mut self, _args: Self::Args<'_>) {
+        self.v1.signal(|buf| {
+            buf.push_back(PinnacleOutputLayoutChangedResponse {});
+        });
+    }
+
+    fn clear(&mut self) {
+        self.v1.instances.clear();
+    }
+}
+
 ////////////////////////////////////////////////////
 
 type ClientSignalId = u32;
@@ -402,6 +572,13 @@ impl<T> SignalData<T> {
         })
     }
 
+    /// Returns whether every connected client has received and handled all sent signals.
+    fn is_idle(&self) -> bool {
+        self.instances
+            .values()
+            .all(|instance| instance.ready && instance.buffer.is_empty())
+    }
+
     fn connect(&mut self, id: ClientSignalId, sender: Sender<Result<T, Status>>) {
         self.instances.insert(
             id,
@@ -492,11 +669,18 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
     type WindowPointerLeaveStream = ResponseStream<WindowPointerLeaveResponse>;
     type WindowFocusedStream = ResponseStream<WindowFocusedResponse>;
     type WindowTitleChangedStream = ResponseStream<WindowTitleChangedResponse>;
+    type WindowUrgentChangedStream = ResponseStream<WindowUrgentChangedResponse>;
+    type WindowLayoutModeChangedStream = ResponseStream<WindowLayoutModeChangedResponse>;
+    type WindowGeometryChangedStream = ResponseStream<WindowGeometryChangedResponse>;
 
     type TagActiveStream = ResponseStream<TagActiveResponse>;
+    type TagWindowCountChangedStream = ResponseStream<TagWindowCountChangedResponse>;
 
     type InputDeviceAddedStream = ResponseStream<InputDeviceAddedResponse>;
 
+    type PinnaclePrepareForSleepStream = ResponseStream<PinnaclePrepareForSleepResponse>;
+    type PinnacleOutputLayoutChangedStream = ResponseStream<PinnacleOutputLayoutChangedResponse>;
+
     async fn output_connect(
         &self,
         request: Request<Streaming<OutputConnectRequest>>,
@@ -618,6 +802,39 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         })
     }
 
+    async fn window_urgent_changed(
+        &self,
+        request: Request<Streaming<WindowUrgentChangedRequest>>,
+    ) -> Result<Response<Self::WindowUrgentChangedStream>, Status> {
+        let in_stream = request.into_inner();
+
+        start_signal_stream(self.sender.clone(), in_stream, |state| {
+            &mut state.pinnacle.signal_state.window_urgent_changed.v1
+        })
+    }
+
+    async fn window_layout_mode_changed(
+        &self,
+        request: Request<Streaming<WindowLayoutModeChangedRequest>>,
+    ) -> Result<Response<Self::WindowLayoutModeChangedStream>, Status> {
+        let in_stream = request.into_inner();
+
+        start_signal_stream(self.sender.clone(), in_stream, |state| {
+            &mut state.pinnacle.signal_state.window_layout_mode_changed.v1
+        })
+    }
+
+    async fn window_geometry_changed(
+        &self,
+        request: Request<Streaming<WindowGeometryChangedRequest>>,
+    ) -> Result<Response<Self::WindowGeometryChangedStream>, Status> {
+        let in_stream = request.into_inner();
+
+        start_signal_stream(self.sender.clone(), in_stream, |state| {
+            &mut state.pinnacle.signal_state.window_geometry_changed.v1
+        })
+    }
+
     async fn tag_active(
         &self,
         request: Request<Streaming<TagActiveRequest>>,
@@ -629,6 +846,17 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
         })
     }
 
+    async fn tag_window_count_changed(
+        &self,
+        request: Request<Streaming<TagWindowCountChangedRequest>>,
+    ) -> Result<Response<Self::TagWindowCountChangedStream>, Status> {
+        let in_stream = request.into_inner();
+
+        start_signal_stream(self.sender.clone(), in_stream, |state| {
+            &mut state.pinnacle.signal_state.tag_window_count_changed.v1
+        })
+    }
+
     async fn input_device_added(
         &self,
         request: Request<Streaming<InputDeviceAddedRequest>>,
@@ -639,4 +867,30 @@ impl signal::v1::signal_service_server::SignalService for SignalService {
             &mut state.pinnacle.signal_state.input_device_added.v1
         })
     }
+
+    async fn pinnacle_prepare_for_sleep(
+        &self,
+        request: Request<Streaming<PinnaclePrepareForSleepRequest>>,
+    ) -> Result<Response<Self::PinnaclePrepareForSleepStream>, Status> {
+        let in_stream = request.into_inner();
+
+        start_signal_stream(self.sender.clone(), in_stream, |state| {
+            &mut state.pinnacle.signal_state.pinnacle_prepare_for_sleep.v1
+        })
+    }
+
+    async fn pinnacle_output_layout_changed(
+        &self,
+        request: Request<Streaming<PinnacleOutputLayoutChangedRequest>>,
+    ) -> Result<Response<Self::PinnacleOutputLayoutChangedStream>, Status> {
+        let in_stream = request.into_inner();
+
+        start_signal_stream(self.sender.clone(), in_stream, |state| {
+            &mut state
+                .pinnacle
+                .signal_state
+                .pinnacle_output_layout_changed
+                .v1
+        })
+    }
 }
@@ -2,13 +2,17 @@ pub mod v1;
 
 use std::mem;
 
+use smithay::{
+    output::Output,
+    utils::{Logical, Point},
+};
 use tracing::warn;
 
 use crate::{
     output::OutputName,
     state::{State, WithState},
     tag::Tag,
-    window::UnmappedState,
+    window::{UnmappedState, WindowElement},
 };
 
 use super::{StateFnSender, signal::Signal};
@@ -32,6 +36,10 @@ pub fn set_active(state: &mut State, tag: &Tag, set: Option<bool>) {
 
     if tag.set_active(active) {
         state.pinnacle.signal_state.tag_active.signal(tag);
+
+        if active {
+            restore_minimized_windows(state, tag);
+        }
     }
 
     state.pinnacle.update_xwayland_stacking_order();
@@ -46,17 +54,23 @@ pub fn switch_to(state: &mut State, tag: &Tag) {
         return;
     };
 
-    output.with_state(|op_state| {
+    let activated = output.with_state(|op_state| {
         for op_tag in op_state.tags.iter() {
             if op_tag.set_active(false) {
                 state.pinnacle.signal_state.tag_active.signal(op_tag);
             }
         }
-        if tag.set_active(true) {
+        let activated = tag.set_active(true);
+        if activated {
             state.pinnacle.signal_state.tag_active.signal(tag);
         }
+        activated
     });
 
+    if activated {
+        restore_minimized_windows(state, tag);
+    }
+
     state.pinnacle.update_xwayland_stacking_order();
 
     state.pinnacle.request_layout(&output);
@@ -64,6 +78,230 @@ pub fn switch_to(state: &mut State, tag: &Tag) {
     state.schedule_render(&output);
 }
 
+/// Activates the tags on `output` whose names are in `names`, all at once.
+///
+/// Unless `keep_active` is set, every other tag on the output is deactivated.
+/// Nothing happens if no tag matches.
+pub fn view_tags(state: &mut State, output: &Output, names: &[String], keep_active: bool) {
+    let tags = output.with_state(|op_state| op_state.tags.clone());
+
+    if !tags.iter().any(|tag| names.contains(&tag.name())) {
+        return;
+    }
+
+    for tag in tags.iter() {
+        let active = names.contains(&tag.name()) || (keep_active && tag.active());
+
+        if tag.set_active(active) {
+            state.pinnacle.signal_state.tag_active.signal(tag);
+
+            if active {
+                restore_minimized_windows(state, tag);
+            }
+        }
+    }
+
+    state.pinnacle.update_xwayland_stacking_order();
+
+    state.pinnacle.request_layout(output);
+
+    state.schedule_render(output);
+}
+
+/// Unminimizes windows on a tag that just became active if the config asked for it.
+fn restore_minimized_windows(state: &mut State, tag: &Tag) {
+    if !state.pinnacle.config.minimize_restore_on_tag {
+        return;
+    }
+
+    let windows = state
+        .pinnacle
+        .windows
+        .iter()
+        .filter(|win| win.with_state(|win_state| win_state.tags.contains(tag)))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    for win in windows {
+        crate::api::window::set_minimized(state, &win, false);
+    }
+}
+
+/// Moves a tag to another output, taking all windows with it.
+///
+/// Windows on the tag lose any tags from the old output, as a window's tags
+/// must all be on the same output.
+pub fn move_to_output(state: &mut State, tag: &Tag, target: Output) {
+    let Some(current) = tag.output(&state.pinnacle) else {
+        return;
+    };
+
+    if current == target {
+        return;
+    }
+
+    if state.pinnacle.space.output_geometry(&target).is_none() {
+        warn!("Tried to move a tag to an unmapped output");
+        return;
+    }
+
+    current.with_state_mut(|state| {
+        state.tags.shift_remove(tag);
+    });
+
+    let offset = target.current_location() - current.current_location();
+    move_windows_with_tag(state, tag, &target, offset);
+
+    for output in [current, target] {
+        state.pinnacle.request_layout(&output);
+        state.schedule_render(&output);
+    }
+}
+
+/// Sets the output a tag lives on, taking all windows with it.
+///
+/// Unlike [`move_to_output`], this also relocates tags whose output was disconnected.
+/// Such tags are taken out of the disconnected output's saved state, so they
+/// don't return to it when it's connected again.
+pub fn set_output(state: &mut State, tag: &Tag, target: Output) {
+    if tag.output(&state.pinnacle).is_some() {
+        move_to_output(state, tag, target);
+        return;
+    }
+
+    if state.pinnacle.space.output_geometry(&target).is_none() {
+        warn!("Tried to move a tag to an unmapped output");
+        return;
+    }
+
+    let Some(saved_state) = state
+        .pinnacle
+        .config
+        .connector_saved_states
+        .values_mut()
+        .find(|saved_state| saved_state.tags.contains(tag))
+    else {
+        return;
+    };
+
+    saved_state.tags.shift_remove(tag);
+    let offset = target.current_location() - saved_state.loc;
+
+    move_windows_with_tag(state, tag, &target, offset);
+
+    state.pinnacle.request_layout(&target);
+    state.schedule_render(&target);
+}
+
+/// Adds `tag` to `target` and moves windows with it there.
+///
+/// Moved windows lose tags not on `target`, and floating ones are shifted by `offset`.
+fn move_windows_with_tag(
+    state: &mut State,
+    tag: &Tag,
+    target: &Output,
+    offset: Point<i32, Logical>,
+) {
+    let target_tags = target.with_state_mut(|state| {
+        state.tags.insert(tag.clone());
+        state.tags.clone()
+    });
+    state.pinnacle.window_tags_changed = true;
+
+    for window in state.pinnacle.windows.clone() {
+        let moved = window.with_state_mut(|state| {
+            if !state.tags.contains(tag) {
+                return false;
+            }
+
+            state.tags.retain(|tag| target_tags.contains(tag));
+
+            if let Some(loc) = state.floating_loc() {
+                state.set_floating_loc(loc + offset);
+            }
+
+            true
+        });
+
+        if moved {
+            let layout_mode = window.with_state(|state| state.layout_mode);
+            state.pinnacle.update_window_geometry(
+                &window,
+                layout_mode.is_tiled() || layout_mode.is_spilled(),
+            );
+        }
+    }
+
+    state.pinnacle.update_xwayland_stacking_order();
+}
+
+/// Swaps the windows on two tags.
+///
+/// Windows with one tag get the other in its place. If the tags are on different outputs,
+/// windows that switch outputs lose any other tags from the old output. Windows with both
+/// tags are left alone.
+pub fn swap(state: &mut State, tag: &Tag, other: &Tag) {
+    if tag == other {
+        return;
+    }
+
+    let (Some(output), Some(other_output)) =
+        (tag.output(&state.pinnacle), other.output(&state.pinnacle))
+    else {
+        return;
+    };
+
+    let offset = other_output.current_location() - output.current_location();
+    let other_offset = output.current_location() - other_output.current_location();
+
+    state.pinnacle.window_tags_changed = true;
+
+    for window in state.pinnacle.windows.clone() {
+        let moved = window.with_state_mut(|state| {
+            let (from, to, offset) = match (state.tags.contains(tag), state.tags.contains(other)) {
+                (true, false) => (tag, other, offset),
+                (false, true) => (other, tag, other_offset),
+                _ => return false,
+            };
+
+            if output == other_output {
+                let Some(index) = state.tags.get_index_of(from) else {
+                    return false;
+                };
+                state.tags.shift_remove_index(index);
+                state.tags.shift_insert(index, to.clone());
+                return false;
+            }
+
+            state.tags = [to.clone()].into_iter().collect();
+
+            if let Some(loc) = state.floating_loc() {
+                state.set_floating_loc(loc + offset);
+            }
+
+            true
+        });
+
+        if moved {
+            let layout_mode = window.with_state(|state| state.layout_mode);
+            state.pinnacle.update_window_geometry(
+                &window,
+                layout_mode.is_tiled() || layout_mode.is_spilled(),
+            );
+        }
+    }
+
+    state.pinnacle.update_xwayland_stacking_order();
+
+    state.pinnacle.request_layout(&output);
+    state.schedule_render(&output);
+
+    if other_output != output {
+        state.pinnacle.request_layout(&other_output);
+        state.schedule_render(&other_output);
+    }
+}
+
 pub fn add(
     state: &mut State,
     tag_names: impl IntoIterator<Item = String>,
@@ -97,6 +335,8 @@ pub fn add(
             state.pinnacle.request_window_rules(unmapped);
         }
         state.pinnacle.unmapped_windows = unmapped_windows;
+
+        state.pinnacle.restore_saved_window_layouts();
     }
 
     state.pinnacle.update_xwayland_stacking_order();
@@ -104,6 +344,77 @@ pub fn add(
     new_tags
 }
 
+/// Adds a dynamic tag to the focused output for a new window if dynamic tags
+/// are enabled, the window has no output yet, and no tag on the output is active.
+pub fn add_dynamic_for_window(state: &mut State, window: &WindowElement) {
+    if !state.pinnacle.config.dynamic_tags || window.output(&state.pinnacle).is_some() {
+        return;
+    }
+
+    let Some(output) = state.pinnacle.focused_output().cloned() else {
+        return;
+    };
+
+    if output.with_state(|state| state.focused_tags().next().is_none()) {
+        add_dynamic(state, &output);
+    }
+}
+
+/// Adds and activates a dynamic tag on `output`, named after the lowest unused number.
+pub fn add_dynamic(state: &mut State, output: &Output) -> Option<Tag> {
+    let tag_names = output.with_state(|state| {
+        state
+            .tags
+            .iter()
+            .filter(|tag| !tag.defunct())
+            .map(|tag| tag.name())
+            .collect::<Vec<_>>()
+    });
+
+    let name = (1..)
+        .map(|num: u32| num.to_string())
+        .find(|name| !tag_names.contains(name))?;
+
+    let tag = Tag::new(name);
+    tag.make_dynamic();
+
+    output.with_state_mut(|state| state.add_tags([tag.clone()]));
+
+    // The tag may have replaced a defunct one, so get the one actually on the output
+    let tag = tag.id().tag(&state.pinnacle)?;
+
+    set_active(state, &tag, Some(true));
+
+    Some(tag)
+}
+
+/// Removes the dynamic tags in `tags` that no longer have any windows.
+pub fn remove_empty_dynamic(state: &mut State, tags: Vec<Tag>) {
+    let has_windows = |tag: &Tag| {
+        state
+            .pinnacle
+            .windows
+            .iter()
+            .chain(
+                state
+                    .pinnacle
+                    .unmapped_windows
+                    .iter()
+                    .map(|unmapped| &unmapped.window),
+            )
+            .any(|win| win.with_state(|state| state.tags.contains(tag)))
+    };
+
+    let empty_tags = tags
+        .into_iter()
+        .filter(|tag| tag.dynamic() && !has_windows(tag))
+        .collect::<Vec<_>>();
+
+    if !empty_tags.is_empty() {
+        remove(state, empty_tags);
+    }
+}
+
 pub fn remove(state: &mut State, tags_to_remove: Vec<Tag>) {
     for window in state.pinnacle.windows.iter() {
         window.with_state_mut(|state| {
@@ -112,6 +423,7 @@ pub fn remove(state: &mut State, tags_to_remove: Vec<Tag>) {
             }
         })
     }
+    state.pinnacle.window_tags_changed = true;
 
     for output in state.pinnacle.outputs.clone() {
         output.with_state_mut(|state| {
@@ -2,7 +2,8 @@ use pinnacle_api_defs::pinnacle::{
     tag::v1::{
         self, AddRequest, AddResponse, GetActiveRequest, GetActiveResponse, GetNameRequest,
         GetNameResponse, GetOutputNameRequest, GetOutputNameResponse, GetRequest, GetResponse,
-        RemoveRequest, SetActiveRequest, SwitchToRequest,
+        MoveToOutputRequest, RemoveRequest, SetActiveRequest, SetOutputRequest, SwapRequest,
+        SwitchToRequest,
     },
     util::v1::SetOrToggle,
 };
@@ -122,6 +123,65 @@ impl v1::tag_service_server::TagService for super::TagService {
         .await
     }
 
+    async fn move_to_output(&self, request: Request<MoveToOutputRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let tag_id = TagId::new(request.tag_id);
+        let output_name = OutputName(request.output_name);
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(tag) = tag_id.tag(&state.pinnacle) else { return };
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return;
+            };
+            crate::api::tag::move_to_output(state, &tag, output);
+        })
+        .await
+    }
+
+    async fn set_output(&self, request: Request<SetOutputRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let tag_id = TagId::new(request.tag_id);
+        let output_name = OutputName(request.output_name);
+
+        run_unary_no_response(&self.sender, move |state| {
+            // Tags on disconnected outputs only live in their saved state
+            let tag = tag_id.tag(&state.pinnacle).or_else(|| {
+                state
+                    .pinnacle
+                    .config
+                    .connector_saved_states
+                    .values()
+                    .flat_map(|saved_state| saved_state.tags.iter())
+                    .find(|tag| tag.id() == tag_id && !tag.defunct())
+                    .cloned()
+            });
+            let Some(tag) = tag else { return };
+            let Some(output) = output_name.output(&state.pinnacle) else {
+                return;
+            };
+            crate::api::tag::set_output(state, &tag, output);
+        })
+        .await
+    }
+
+    async fn swap(&self, request: Request<SwapRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let tag_id = TagId::new(request.tag_id);
+        let other_tag_id = TagId::new(request.other_tag_id);
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(tag) = tag_id.tag(&state.pinnacle) else { return };
+            let Some(other) = other_tag_id.tag(&state.pinnacle) else {
+                return;
+            };
+            crate::api::tag::swap(state, &tag, &other);
+        })
+        .await
+    }
+
     async fn add(&self, request: Request<AddRequest>) -> TonicResult<AddResponse> {
         let request = request.into_inner();
 
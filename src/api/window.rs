@@ -1,20 +1,27 @@
 mod v1;
 
+use indexmap::IndexSet;
+use pinnacle_api_defs::pinnacle::window;
 use smithay::{
+    output::Output,
     reexports::wayland_protocols::xdg::{
         decoration::zv1::server::zxdg_toplevel_decoration_v1, shell::server,
     },
-    utils::{Point, SERIAL_COUNTER, Size},
+    utils::{Logical, Point, SERIAL_COUNTER, Size},
     wayland::seat::WaylandFocus,
 };
 use tracing::warn;
 
 use crate::{
     focus::keyboard::KeyboardFocusTarget,
+    layout::InsertPosition,
     state::{State, WithState},
     tag::Tag,
     util::transaction::TransactionBuilder,
-    window::WindowElement,
+    window::{
+        WindowElement,
+        window_state::{CloseBehavior, LayoutMode, LayoutModeKind},
+    },
 };
 
 use super::StateFnSender;
@@ -53,6 +60,10 @@ pub fn set_geometry(
     window_size.w = w.map(|w| w as i32).unwrap_or(window_size.w);
     window_size.h = h.map(|h| h as i32).unwrap_or(window_size.h);
 
+    // Only the height was given, so let the width follow it
+    let from_height = w.is_none() && h.is_some();
+    window_size = window.with_state(|state| state.apply_aspect_ratio(window_size, from_height));
+
     window.with_state_mut(|state| {
         state.floating_x = x.or(state.floating_x);
         state.floating_y = y.or(state.floating_y);
@@ -65,7 +76,180 @@ pub fn set_geometry(
     );
 }
 
-// TODO: minimized
+/// Makes a window floating with the given geometry.
+///
+/// The floating geometry is set before the layout mode changes, so the window
+/// is configured once with its new geometry. Windows that are already floating
+/// are only moved and resized.
+pub fn float_with_geometry(
+    state: &mut State,
+    window: &WindowElement,
+    loc: Point<i32, Logical>,
+    size: Size<i32, Logical>,
+) {
+    if window.with_state(|state| state.layout_mode.is_floating()) {
+        set_geometry(state, window, loc.x, loc.y, size.w as u32, size.h as u32);
+        return;
+    }
+
+    let size = window.with_state(|state| state.apply_aspect_ratio(size, false));
+
+    window.with_state_mut(|state| {
+        state.floating_x = Some(loc.x);
+        state.floating_y = Some(loc.y);
+        state.floating_size = size;
+    });
+
+    state
+        .pinnacle
+        .update_window_layout_mode(window, |layout_mode| layout_mode.set_floating(true));
+}
+
+/// Asks a window to close, or minimizes it or hides it in a scratchpad
+/// if its close behavior says so.
+pub fn close(state: &mut State, window: &WindowElement) {
+    match window.with_state(|state| state.close_behavior.clone()) {
+        CloseBehavior::Close => window.close(),
+        CloseBehavior::Minimize => set_minimized(state, window, true),
+        CloseBehavior::Scratchpad(name) => to_scratchpad(state, window, name),
+    }
+}
+
+/// Sets a window to minimized or not.
+///
+/// Minimized windows are removed from the layout and can't be focused.
+/// Unminimizing a window puts it back where it was.
+pub fn set_minimized(state: &mut State, window: &WindowElement, set: impl Into<Option<bool>>) {
+    let minimized = set
+        .into()
+        .unwrap_or_else(|| window.with_state(|state| !state.minimized));
+
+    if window.with_state(|state| state.minimized) == minimized {
+        return;
+    }
+
+    window.with_state_mut(|state| state.minimized = minimized);
+
+    let Some(output) = window.output(&state.pinnacle) else {
+        return;
+    };
+
+    state.pinnacle.request_layout(&output);
+    state.schedule_render(&output);
+}
+
+/// Hides a window in the scratchpad with the given name.
+///
+/// The window loses all its tags so it won't show up on any output until
+/// it is summoned with [`toggle_scratchpad`]. If the scratchpad already held
+/// a different window, that window is shown on the focused output.
+pub fn to_scratchpad(state: &mut State, window: &WindowElement, name: String) {
+    state.pinnacle.scratchpads.retain(|_, win| win != window);
+
+    let replaced = state.pinnacle.scratchpads.insert(name, window.clone());
+
+    hide_scratchpad_window(state, window);
+
+    if let Some(replaced) = replaced
+        && let Some(output) = state.pinnacle.focused_output().cloned()
+    {
+        show_scratchpad_window(state, &replaced, &output);
+    }
+}
+
+/// Shows or hides the window in the scratchpad with the given name.
+///
+/// If the window is showing on the focused output, it is hidden. Otherwise it is
+/// moved to the focused output, floated, centered, and focused.
+pub fn toggle_scratchpad(state: &mut State, name: &str) {
+    let Some(window) = state.pinnacle.scratchpads.get(name).cloned() else {
+        return;
+    };
+
+    let Some(output) = state.pinnacle.focused_output().cloned() else {
+        return;
+    };
+
+    let showing_on_output =
+        window.is_on_active_tag() && window.output(&state.pinnacle).as_ref() == Some(&output);
+
+    if showing_on_output {
+        hide_scratchpad_window(state, &window);
+    } else {
+        show_scratchpad_window(state, &window, &output);
+    }
+}
+
+fn hide_scratchpad_window(state: &mut State, window: &WindowElement) {
+    let output = window.output(&state.pinnacle);
+
+    if window.with_state(|state| state.layout_mode.is_floating())
+        && let Some(loc) = state.pinnacle.space.element_location(window)
+    {
+        window.with_state_mut(|state| state.set_floating_loc(loc));
+    }
+
+    window.with_state_mut(|state| state.tags.clear());
+    state.pinnacle.window_tags_changed = true;
+
+    // Tagless windows belong to no output, so layouts won't unmap this for us
+    let Some(output) = output else {
+        return;
+    };
+
+    state
+        .pinnacle
+        .unmap_window(&mut state.backend, window, &output);
+
+    state.pinnacle.request_layout(&output);
+    state.schedule_render(&output);
+    state.pinnacle.update_xwayland_stacking_order();
+}
+
+fn show_scratchpad_window(state: &mut State, window: &WindowElement, output: &Output) {
+    let source_output = window.output(&state.pinnacle);
+    let was_tiled = window.with_state(|state| state.layout_mode.is_tiled());
+
+    window.set_tags_to_output(output);
+    state.pinnacle.window_tags_changed = true;
+
+    let mut size = window.with_state(|state| state.floating_size);
+    if size.w == 0 {
+        size.w = window.geometry().size.w;
+    }
+    if size.h == 0 {
+        size.h = window.geometry().size.h;
+    }
+
+    let zone = state.pinnacle.non_exclusive_zone(output);
+    let offset = Point::new((zone.size.w - size.w) / 2, (zone.size.h - size.h) / 2);
+    let loc = output.current_location() + zone.loc + offset;
+
+    window.with_state_mut(|state| {
+        state.layout_mode.set_floating(true);
+        state.set_floating_loc(loc);
+    });
+
+    if let Some(source_output) = source_output
+        && &source_output != output
+    {
+        state.pinnacle.request_layout(&source_output);
+        state.schedule_render(&source_output);
+    }
+
+    window.configure_states();
+    state.pinnacle.update_window_geometry(window, was_tiled);
+
+    state.pinnacle.raise_window(window.clone());
+    state
+        .pinnacle
+        .keyboard_focus_stack
+        .set_focus(window.clone());
+    state.pinnacle.on_demand_layer_focus = None;
+
+    state.schedule_render(output);
+    state.pinnacle.update_xwayland_stacking_order();
+}
 
 /// Sets a window to focused or not.
 ///
@@ -145,8 +329,76 @@ pub fn set_focused(state: &mut State, window: &WindowElement, set: impl Into<Opt
     }
 }
 
+/// Returns the name of the group `window` is in.
+///
+/// If it's in more than one, the first by name is used.
+fn group_of(state: &State, window: &WindowElement) -> Option<String> {
+    state
+        .pinnacle
+        .window_groups
+        .iter()
+        .filter(|(_, group)| group.contains(window))
+        .map(|(name, _)| name)
+        .min()
+        .cloned()
+}
+
+/// Focuses and raises the next or previous window in the focused window's group,
+/// wrapping around.
+///
+/// Windows in the group that aren't shown are skipped.
+/// Does nothing if the focused window isn't in a group.
+pub fn focus_in_group(state: &mut State, forward: bool) {
+    let Some(KeyboardFocusTarget::Window(focused)) = state
+        .pinnacle
+        .seat
+        .get_keyboard()
+        .and_then(|keyboard| keyboard.current_focus())
+    else {
+        return;
+    };
+
+    let Some(group_name) = group_of(state, &focused) else {
+        return;
+    };
+
+    let group = state.pinnacle.window_groups[&group_name]
+        .iter()
+        .filter(|win| *win == &focused || state.pinnacle.space.element_location(win).is_some())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let Some(idx) = group.iter().position(|win| win == &focused) else {
+        return;
+    };
+
+    let next_idx = if forward {
+        (idx + 1) % group.len()
+    } else {
+        (idx + group.len() - 1) % group.len()
+    };
+
+    let next = group[next_idx].clone();
+
+    if next == focused {
+        return;
+    }
+
+    set_focused(state, &next, true);
+    raise(state, next);
+}
+
+/// Adds a window to every group `target` is in.
+pub fn add_to_groups_of(state: &mut State, window: &WindowElement, target: &WindowElement) {
+    for group in state.pinnacle.window_groups.values_mut() {
+        if group.contains(target) && !group.contains(window) {
+            group.push(window.clone());
+        }
+    }
+}
+
 pub fn set_decoration_mode(
-    _state: &mut State,
+    state: &mut State,
     window: &WindowElement,
     decoration_mode: zxdg_toplevel_decoration_v1::Mode,
 ) {
@@ -166,14 +418,35 @@ pub fn set_decoration_mode(
 
         toplevel.send_pending_configure();
     }
+
+    // Decoration bounds change the size the window is configured with,
+    // so recompute its geometry for its current layout mode.
+    if state.pinnacle.windows.contains(window) && window.output(&state.pinnacle).is_some() {
+        let is_tiled = window.with_state(|state| state.layout_mode.is_tiled());
+        state.pinnacle.update_window_geometry(window, is_tiled);
+    }
 }
 
-pub fn move_to_tag(state: &mut State, window: &WindowElement, tag: &Tag) {
+/// Moves a window to a tag, removing all its other tags.
+///
+/// If `position` is set, the window is moved to that position in the tag's layout order.
+/// Otherwise it keeps its current place in the layout order.
+pub fn move_to_tag(
+    state: &mut State,
+    window: &WindowElement,
+    tag: &Tag,
+    position: Option<InsertPosition>,
+) {
     let source_output = window.output(&state.pinnacle);
 
     window.with_state_mut(|state| {
         state.tags = std::iter::once(tag.clone()).collect();
     });
+    state.pinnacle.window_tags_changed = true;
+
+    if let Some(position) = position {
+        state.pinnacle.insert_window_on_tag(window, tag, position);
+    }
 
     if let Some(output) = source_output.as_ref() {
         state.pinnacle.request_layout(output);
@@ -216,6 +489,7 @@ pub fn set_tag(state: &mut State, window: &WindowElement, tag: &Tag, set: impl I
             });
         }
     }
+    state.pinnacle.window_tags_changed = true;
 
     let Some(output) = tag.output(&state.pinnacle) else {
         return;
@@ -226,6 +500,28 @@ pub fn set_tag(state: &mut State, window: &WindowElement, tag: &Tag, set: impl I
     state.pinnacle.update_xwayland_stacking_order();
 }
 
+/// Sets the exact tags of many windows at once.
+///
+/// Every affected output is laid out once after all tags are set,
+/// instead of once per window.
+pub fn set_tags_bulk(state: &mut State, changes: Vec<(WindowElement, IndexSet<Tag>)>) {
+    let mut outputs = IndexSet::<Output>::new();
+
+    for (window, tags) in changes {
+        outputs.extend(window.output(&state.pinnacle));
+        outputs.extend(tags.iter().filter_map(|tag| tag.output(&state.pinnacle)));
+        window.with_state_mut(|state| state.tags = tags);
+    }
+    state.pinnacle.window_tags_changed = true;
+
+    for output in outputs {
+        state.pinnacle.request_layout(&output);
+        state.schedule_render(&output);
+    }
+
+    state.pinnacle.update_xwayland_stacking_order();
+}
+
 pub fn raise(state: &mut State, window: WindowElement) {
     for output in state.pinnacle.space.outputs_for_element(&window) {
         state.schedule_render(&output);
@@ -293,8 +589,27 @@ pub fn resize_grab(state: &mut State, button: u32) {
     let rel_x = (pointer_loc.x - window_loc.x).clamp(0, window_size.w);
     let rel_y = (pointer_loc.y - window_loc.y).clamp(0, window_size.h);
 
-    let quadrant_x = (rel_x * 3 / window_size.w).clamp(0, 2);
-    let quadrant_y = (rel_y * 3 / window_size.h).clamp(0, 2);
+    let threshold = state.pinnacle.input_state.resize_edge_threshold;
+
+    let quadrant = |rel: i32, size: i32| {
+        let Some(threshold) = threshold else {
+            return (rel * 3 / size).clamp(0, 2);
+        };
+
+        // Edge regions are capped at a third of the window so small windows keep a middle
+        let region = (threshold as i32).min(size / 3);
+
+        if rel < region {
+            0
+        } else if rel >= size - region {
+            2
+        } else {
+            1
+        }
+    };
+
+    let quadrant_x = quadrant(rel_x, window_size.w);
+    let quadrant_y = quadrant(rel_y, window_size.h);
 
     let edges = match (quadrant_x, quadrant_y) {
         (0, 0) => server::xdg_toplevel::ResizeEdge::TopLeft,
@@ -407,6 +722,7 @@ pub fn swap(state: &mut State, window: WindowElement, target: WindowElement) {
 
     window.with_state_mut(|state| state.tags = target_tags);
     target.with_state_mut(|state| state.tags = window_tags);
+    state.pinnacle.window_tags_changed = true;
 
     // Swap floating attribute. In case of cross-output swap, this prevent window jumping back.
     let window_floating_x = window.with_state(|state| state.floating_x);
@@ -481,3 +797,13 @@ pub fn swap(state: &mut State, window: WindowElement, target: WindowElement) {
             builder.into_pending(unmappings, state.pinnacle.layout_state.pending_swap, false),
         );
 }
+
+pub fn api_layout_mode(layout_mode: LayoutMode) -> window::v1::LayoutMode {
+    match layout_mode.current() {
+        LayoutModeKind::Tiled => window::v1::LayoutMode::Tiled,
+        LayoutModeKind::Floating => window::v1::LayoutMode::Floating,
+        LayoutModeKind::Maximized => window::v1::LayoutMode::Maximized,
+        LayoutModeKind::Fullscreen => window::v1::LayoutMode::Fullscreen,
+        LayoutModeKind::Spilled => window::v1::LayoutMode::Spilled,
+    }
+}
@@ -6,27 +6,33 @@ use std::sync::{
 use indexmap::IndexSet;
 use pinnacle_api_defs::pinnacle::{
     util::{self, v1::SetOrToggle},
-    window::{
-        self,
-        v1::{
-            self, CloseRequest, GetAppIdRequest, GetAppIdResponse, GetFocusedRequest,
-            GetFocusedResponse, GetForeignToplevelListIdentifierRequest,
-            GetForeignToplevelListIdentifierResponse, GetLayoutModeRequest, GetLayoutModeResponse,
-            GetLocRequest, GetLocResponse, GetRequest, GetResponse, GetSizeRequest,
-            GetSizeResponse, GetTagIdsRequest, GetTagIdsResponse, GetTitleRequest,
-            GetTitleResponse, GetWindowsInDirRequest, GetWindowsInDirResponse, LowerRequest,
-            LowerResponse, MoveGrabRequest, MoveToOutputRequest, MoveToOutputResponse,
-            MoveToTagRequest, RaiseRequest, ResizeGrabRequest, ResizeTileRequest,
-            SetDecorationModeRequest, SetFloatingRequest, SetFocusedRequest, SetFullscreenRequest,
-            SetGeometryRequest, SetMaximizedRequest, SetTagRequest, SetTagsRequest,
-            SetTagsResponse, SetVrrDemandRequest, SetVrrDemandResponse, SwapRequest, SwapResponse,
-            WindowRuleRequest, WindowRuleResponse,
-        },
+    window::v1::{
+        self, AddGeometryRuleRequest, AddToGroupRequest, AddToGroupsOfRequest, CloseRequest,
+        CreateGroupRequest, FloatWithGeometryRequest, FocusInGroupRequest, GetAllWithPropsRequest,
+        GetAllWithPropsResponse, GetAppIdRequest, GetAppIdResponse, GetFocusedRequest,
+        GetFocusedResponse, GetForeignToplevelListIdentifierRequest,
+        GetForeignToplevelListIdentifierResponse, GetGroupWindowsRequest, GetGroupWindowsResponse,
+        GetInstanceRequest, GetInstanceResponse, GetLayoutModeRequest, GetLayoutModeResponse,
+        GetLocRequest, GetLocResponse, GetRequest, GetResponse, GetRoleRequest, GetRoleResponse,
+        GetSizeRequest, GetSizeResponse, GetTagIdsRequest, GetTagIdsResponse, GetTitleRequest,
+        GetTitleResponse, GetUrgentRequest, GetUrgentResponse, GetWindowsInDirRequest,
+        GetWindowsInDirResponse, LowerRequest, LowerResponse, MoveGrabRequest, MoveToOutputRequest,
+        MoveToOutputResponse, MoveToTagRequest, RaiseRequest, RemoveFromGroupRequest,
+        RemoveGroupRequest, ResizeGrabRequest, ResizeTileRequest, SetAppIdOverrideRequest,
+        SetAspectRatioRequest, SetBackgroundFpsCapRequest, SetCloseBehaviorRequest,
+        SetDecorationModeRequest, SetDecorationPolicyRequest, SetFloatingAppIdsRequest,
+        SetFloatingRequest, SetFloatingTitlesRequest, SetFocusedRequest, SetFullscreenRequest,
+        SetGeometryRequest, SetGrabAllInputRequest, SetInactiveOpacityRequest, SetMaximizedRequest,
+        SetMinimizeRestoreOnTagRequest, SetOffscreenPolicyRequest, SetPreferredScaleRequest,
+        SetTagRequest, SetTagsBulkRequest, SetTagsRequest, SetTagsResponse, SetUrgentRequest,
+        SetVrrDemandRequest, SetVrrDemandResponse, SetWarpCursorOnFocusRequest, SwapRequest,
+        SwapResponse, ToScratchpadRequest, ToggleScratchpadRequest, WindowProps, WindowRuleRequest,
+        WindowRuleResponse,
     },
 };
 use smithay::{
     reexports::wayland_protocols::xdg::decoration::zv1::server::zxdg_toplevel_decoration_v1,
-    utils::Size,
+    utils::{Point, Rectangle, Size},
 };
 use tonic::{Request, Status, Streaming};
 use tracing::warn;
@@ -34,17 +40,19 @@ use tracing::warn;
 use crate::{
     api::{
         ResponseStream, TonicResult, run_bidirectional_streaming_mapped, run_unary,
-        run_unary_no_response,
+        run_unary_no_response, window::api_layout_mode,
     },
     focus::keyboard::KeyboardFocusTarget,
-    layout::tree::ResizeDir,
+    handlers::decoration::DecorationPolicy,
+    layout::{InsertPosition, tree::ResizeDir},
     output::OutputName,
     state::WithState,
     tag::TagId,
     util::rect::Direction,
     window::{
-        UnmappedState,
-        window_state::{LayoutMode, LayoutModeKind, VrrDemand, WindowId},
+        OffscreenPolicy, UnmappedState,
+        rules::{GeometryRule, WindowMatcher},
+        window_state::{CloseBehavior, LayoutMode, VrrDemand, WindowId},
     },
 };
 
@@ -104,6 +112,50 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn get_instance(
+        &self,
+        request: Request<GetInstanceRequest>,
+    ) -> TonicResult<GetInstanceResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_unary(&self.sender, move |state| {
+            let instance = window_id
+                .window(&state.pinnacle)
+                .or_else(|| {
+                    window_id
+                        .unmapped_window(&state.pinnacle)
+                        .map(|unmapped| unmapped.window.clone())
+                })
+                .and_then(|win| win.instance())
+                .unwrap_or_default();
+
+            Ok(GetInstanceResponse { instance })
+        })
+        .await
+    }
+
+    async fn get_role(&self, request: Request<GetRoleRequest>) -> TonicResult<GetRoleResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_unary(&self.sender, move |state| {
+            let role = window_id
+                .window(&state.pinnacle)
+                .or_else(|| {
+                    window_id
+                        .unmapped_window(&state.pinnacle)
+                        .map(|unmapped| unmapped.window.clone())
+                })
+                .and_then(|win| {
+                    let surface = win.x11_surface()?;
+                    state.pinnacle.xwayland_state.as_ref()?.window_role(surface)
+                })
+                .unwrap_or_default();
+
+            Ok(GetRoleResponse { role })
+        })
+        .await
+    }
+
     async fn get_loc(&self, request: Request<GetLocRequest>) -> TonicResult<GetLocResponse> {
         let window_id = WindowId(request.into_inner().window_id);
 
@@ -163,6 +215,22 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn get_urgent(
+        &self,
+        request: Request<GetUrgentRequest>,
+    ) -> TonicResult<GetUrgentResponse> {
+        let window_id = WindowId(request.into_inner().window_id);
+
+        run_unary(&self.sender, move |state| {
+            let urgent = window_id
+                .window(&state.pinnacle)
+                .is_some_and(|win| win.with_state(|state| state.urgent));
+
+            Ok(GetUrgentResponse { urgent })
+        })
+        .await
+    }
+
     async fn get_layout_mode(
         &self,
         request: Request<GetLayoutModeRequest>,
@@ -181,14 +249,7 @@ impl v1::window_service_server::WindowService for super::WindowService {
                 .unwrap_or(LayoutMode::new_tiled());
 
             Ok(GetLayoutModeResponse {
-                layout_mode: match layout_mode.current() {
-                    LayoutModeKind::Tiled => window::v1::LayoutMode::Tiled,
-                    LayoutModeKind::Floating => window::v1::LayoutMode::Floating,
-                    LayoutModeKind::Maximized => window::v1::LayoutMode::Maximized,
-                    LayoutModeKind::Fullscreen => window::v1::LayoutMode::Fullscreen,
-                    LayoutModeKind::Spilled => window::v1::LayoutMode::Spilled,
-                }
-                .into(),
+                layout_mode: api_layout_mode(layout_mode).into(),
             })
         })
         .await
@@ -305,6 +366,59 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn get_all_with_props(
+        &self,
+        request: Request<GetAllWithPropsRequest>,
+    ) -> TonicResult<GetAllWithPropsResponse> {
+        let output_name = request.into_inner().output_name.map(OutputName);
+
+        run_unary(&self.sender, move |state| {
+            let output = output_name
+                .as_ref()
+                .map(|output_name| output_name.output(&state.pinnacle));
+
+            let windows = state
+                .pinnacle
+                .windows
+                .iter()
+                .filter(|win| match &output {
+                    Some(output) => output.is_some() && &win.output(&state.pinnacle) == output,
+                    None => true,
+                })
+                .map(|win| {
+                    let geo = state.pinnacle.space.element_geometry(win);
+
+                    let (window_id, tag_ids, layout_mode) = win.with_state(|state| {
+                        (
+                            state.id.0,
+                            state.tags.iter().map(|tag| tag.id().to_inner()).collect(),
+                            state.layout_mode,
+                        )
+                    });
+
+                    WindowProps {
+                        window_id,
+                        title: win.title().unwrap_or_default(),
+                        app_id: win.class().unwrap_or_default(),
+                        tag_ids,
+                        loc: geo.map(|geo| util::v1::Point {
+                            x: geo.loc.x,
+                            y: geo.loc.y,
+                        }),
+                        size: geo.map(|geo| util::v1::Size {
+                            width: geo.size.w.try_into().unwrap_or_default(),
+                            height: geo.size.h.try_into().unwrap_or_default(),
+                        }),
+                        layout_mode: api_layout_mode(layout_mode).into(),
+                    }
+                })
+                .collect();
+
+            Ok(GetAllWithPropsResponse { windows })
+        })
+        .await
+    }
+
     async fn close(&self, request: Request<CloseRequest>) -> TonicResult<()> {
         let request = request.into_inner();
 
@@ -315,7 +429,7 @@ impl v1::window_service_server::WindowService for super::WindowService {
                 return;
             };
 
-            window.close();
+            crate::api::window::close(state, &window);
         })
         .await
     }
@@ -346,6 +460,35 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn float_with_geometry(
+        &self,
+        request: Request<FloatWithGeometryRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let window_id = WindowId(request.window_id);
+
+        let loc = Point::from((request.x, request.y));
+        let size = Size::from((request.w as i32, request.h as i32));
+
+        run_unary_no_response(&self.sender, move |state| {
+            if let Some(window) = window_id.window(&state.pinnacle) {
+                crate::api::window::float_with_geometry(state, &window, loc, size);
+            } else if let Some(unmapped) = window_id.unmapped_window_mut(&mut state.pinnacle)
+                && let UnmappedState::WaitingForRules { rules, .. } = &mut unmapped.state
+            {
+                rules
+                    .layout_mode
+                    .get_or_insert(LayoutMode::new_floating())
+                    .set_floating(true);
+                rules.floating_x = Some(loc.x);
+                rules.floating_y = Some(loc.y);
+                rules.floating_size = Some(size);
+            }
+        })
+        .await
+    }
+
     async fn resize_tile(&self, request: Request<ResizeTileRequest>) -> TonicResult<()> {
         let request = request.into_inner();
 
@@ -602,12 +745,18 @@ impl v1::window_service_server::WindowService for super::WindowService {
 
         let window_id = WindowId(request.window_id);
         let tag_id = TagId::new(request.tag_id);
+        let position = match request.insert_position() {
+            v1::InsertPosition::Unspecified => None,
+            v1::InsertPosition::Start => Some(InsertPosition::Start),
+            v1::InsertPosition::End => Some(InsertPosition::End),
+            v1::InsertPosition::AfterFocused => Some(InsertPosition::AfterFocused),
+        };
 
         run_unary_no_response(&self.sender, move |state| {
             let Some(tag) = tag_id.tag(&state.pinnacle) else { return };
 
             if let Some(window) = window_id.window(&state.pinnacle) {
-                crate::api::window::move_to_tag(state, &window, &tag);
+                crate::api::window::move_to_tag(state, &window, &tag, position);
             } else if let Some(unmapped) = window_id.unmapped_window_mut(&mut state.pinnacle)
                 && let UnmappedState::WaitingForRules { rules, .. } = &mut unmapped.state
             {
@@ -691,6 +840,7 @@ impl v1::window_service_server::WindowService for super::WindowService {
 
             if let Some(window) = window_id.window(&state.pinnacle) {
                 window.with_state_mut(|state| state.tags = tags);
+                state.pinnacle.window_tags_changed = true;
             } else if let Some(unmapped) = window_id.unmapped_window_mut(&mut state.pinnacle)
                 && let UnmappedState::WaitingForRules { rules, .. } = &mut unmapped.state
             {
@@ -702,6 +852,42 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn set_tags_bulk(&self, request: Request<SetTagsBulkRequest>) -> TonicResult<()> {
+        let windows = request.into_inner().windows;
+
+        run_unary_no_response(&self.sender, move |state| {
+            let mut changes = Vec::new();
+
+            for SetTagsRequest { window_id, tag_ids } in windows {
+                let window_id = WindowId(window_id);
+
+                let Some(tags) = tag_ids
+                    .into_iter()
+                    .map(|tag_id| TagId::new(tag_id).tag(&state.pinnacle))
+                    .collect::<Option<IndexSet<_>>>()
+                else {
+                    continue;
+                };
+
+                if tags.is_empty() {
+                    warn!("Cannot set a windows tags to empty");
+                    continue;
+                }
+
+                if let Some(window) = window_id.window(&state.pinnacle) {
+                    changes.push((window, tags));
+                } else if let Some(unmapped) = window_id.unmapped_window_mut(&mut state.pinnacle)
+                    && let UnmappedState::WaitingForRules { rules, .. } = &mut unmapped.state
+                {
+                    rules.tags = Some(tags);
+                }
+            }
+
+            crate::api::window::set_tags_bulk(state, changes);
+        })
+        .await
+    }
+
     async fn set_vrr_demand(
         &self,
         request: Request<SetVrrDemandRequest>,
@@ -830,6 +1016,460 @@ impl v1::window_service_server::WindowService for super::WindowService {
         .await
     }
 
+    async fn set_minimize_restore_on_tag(
+        &self,
+        request: Request<SetMinimizeRestoreOnTagRequest>,
+    ) -> TonicResult<()> {
+        let restore = request.into_inner().restore;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.minimize_restore_on_tag = restore;
+        })
+        .await
+    }
+
+    async fn set_urgent(&self, request: Request<SetUrgentRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
+        let urgent = request.urgent;
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return;
+            };
+
+            state.pinnacle.set_window_urgent(&window, urgent);
+        })
+        .await
+    }
+
+    async fn set_app_id_override(
+        &self,
+        request: Request<SetAppIdOverrideRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
+        let app_id = request.app_id;
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return;
+            };
+
+            state.pinnacle.set_window_app_id_override(&window, app_id);
+        })
+        .await
+    }
+
+    async fn set_warp_cursor_on_focus(
+        &self,
+        request: Request<SetWarpCursorOnFocusRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
+        let warp = request.warp;
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return;
+            };
+
+            window.with_state_mut(|state| state.warp_cursor_on_focus = warp);
+        })
+        .await
+    }
+
+    async fn set_grab_all_input(
+        &self,
+        request: Request<SetGrabAllInputRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
+        let grab_all_input = request.grab_all_input;
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return;
+            };
+
+            window.with_state_mut(|state| state.grab_all_input = grab_all_input);
+        })
+        .await
+    }
+
+    async fn set_preferred_scale(
+        &self,
+        request: Request<SetPreferredScaleRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
+        let scale = request.scale;
+
+        if scale.is_some_and(|scale| scale <= 0.0) {
+            return Err(Status::invalid_argument("scale must be greater than 0.0"));
+        }
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return;
+            };
+
+            state
+                .pinnacle
+                .set_window_preferred_scale(&window, scale.map(f64::from));
+        })
+        .await
+    }
+
+    async fn set_aspect_ratio(&self, request: Request<SetAspectRatioRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
+
+        let aspect_ratio = match (request.width, request.height) {
+            (Some(0), _) | (_, Some(0)) => {
+                return Err(Status::invalid_argument("aspect ratio must not be zero"));
+            }
+            (Some(width), Some(height)) => Some((width, height)),
+            (None, None) => None,
+            _ => {
+                return Err(Status::invalid_argument(
+                    "aspect ratio needs both a width and a height",
+                ));
+            }
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return;
+            };
+
+            window.with_state_mut(|state| state.aspect_ratio = aspect_ratio);
+
+            if window.with_state(|state| state.layout_mode.is_floating()) {
+                // Snap the current size to the new ratio
+                crate::api::window::set_geometry(state, &window, None, None, None, None);
+            }
+        })
+        .await
+    }
+
+    async fn set_close_behavior(
+        &self,
+        request: Request<SetCloseBehaviorRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
+
+        let close_behavior = match request.behavior() {
+            v1::CloseBehavior::Unspecified => {
+                return Err(Status::invalid_argument("close behavior was unspecified"));
+            }
+            v1::CloseBehavior::Close => CloseBehavior::Close,
+            v1::CloseBehavior::Minimize => CloseBehavior::Minimize,
+            v1::CloseBehavior::Scratchpad => CloseBehavior::Scratchpad(request.scratchpad_name),
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return;
+            };
+
+            window.with_state_mut(|state| state.close_behavior = close_behavior);
+        })
+        .await
+    }
+
+    async fn set_inactive_opacity(
+        &self,
+        request: Request<SetInactiveOpacityRequest>,
+    ) -> TonicResult<()> {
+        let opacity = request.into_inner().opacity;
+
+        if !(0.0..=1.0).contains(&opacity) {
+            return Err(Status::invalid_argument(
+                "opacity must be between 0.0 and 1.0",
+            ));
+        }
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.inactive_window_opacity = opacity;
+
+            let focused = state
+                .pinnacle
+                .seat
+                .get_keyboard()
+                .and_then(|keyboard| keyboard.current_focus())
+                .and_then(|focus| match focus {
+                    KeyboardFocusTarget::Window(window) => Some(window),
+                    _ => None,
+                });
+
+            state.update_window_opacities(focused.as_ref());
+        })
+        .await
+    }
+
+    async fn set_decoration_policy(
+        &self,
+        request: Request<SetDecorationPolicyRequest>,
+    ) -> TonicResult<()> {
+        let policy = match request.into_inner().policy() {
+            v1::DecorationPolicy::Unspecified => {
+                return Err(Status::invalid_argument(
+                    "decoration policy was unspecified",
+                ));
+            }
+            v1::DecorationPolicy::PreferServerSide => DecorationPolicy::PreferServerSide,
+            v1::DecorationPolicy::PreferClientSide => DecorationPolicy::PreferClientSide,
+            v1::DecorationPolicy::ForceServerSide => DecorationPolicy::ForceServerSide,
+            v1::DecorationPolicy::ForceClientSide => DecorationPolicy::ForceClientSide,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.decoration_policy = policy;
+        })
+        .await
+    }
+
+    async fn set_offscreen_policy(
+        &self,
+        request: Request<SetOffscreenPolicyRequest>,
+    ) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let policy = match request.policy() {
+            v1::OffscreenPolicy::Unspecified => {
+                return Err(Status::invalid_argument("offscreen policy was unspecified"));
+            }
+            v1::OffscreenPolicy::Allow => OffscreenPolicy::Allow,
+            v1::OffscreenPolicy::KeepVisible => {
+                let Ok(min_visible) = i32::try_from(request.min_visible) else {
+                    return Err(Status::invalid_argument("min_visible is too large"));
+                };
+                OffscreenPolicy::KeepVisible(min_visible)
+            }
+            v1::OffscreenPolicy::Clamp => OffscreenPolicy::Clamp,
+        };
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.offscreen_policy = policy;
+            state.pinnacle.constrain_floating_windows();
+        })
+        .await
+    }
+
+    async fn set_background_fps_cap(
+        &self,
+        request: Request<SetBackgroundFpsCapRequest>,
+    ) -> TonicResult<()> {
+        let fps_cap = request.into_inner().fps_cap;
+
+        if fps_cap == Some(0) {
+            return Err(Status::invalid_argument("background fps cap must not be 0"));
+        }
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.background_fps_cap = fps_cap;
+        })
+        .await
+    }
+
+    async fn set_floating_app_ids(
+        &self,
+        request: Request<SetFloatingAppIdsRequest>,
+    ) -> TonicResult<()> {
+        let app_ids = request.into_inner().app_ids;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.floating_app_ids = app_ids;
+        })
+        .await
+    }
+
+    async fn set_floating_titles(
+        &self,
+        request: Request<SetFloatingTitlesRequest>,
+    ) -> TonicResult<()> {
+        let titles = request
+            .into_inner()
+            .titles
+            .iter()
+            .map(|title| regex::Regex::new(title))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| Status::invalid_argument(format!("invalid title regex: {err}")))?;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.floating_titles = titles;
+        })
+        .await
+    }
+
+    async fn add_geometry_rule(&self, request: Request<AddGeometryRuleRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+
+        let matcher = request.matcher.unwrap_or_default();
+        let app_id = matcher
+            .app_id
+            .map(|app_id| regex::Regex::new(&app_id))
+            .transpose()
+            .map_err(|err| Status::invalid_argument(format!("invalid app id regex: {err}")))?;
+        let title = matcher
+            .title
+            .map(|title| regex::Regex::new(&title))
+            .transpose()
+            .map_err(|err| Status::invalid_argument(format!("invalid title regex: {err}")))?;
+
+        let Some(geometry) = request.geometry else {
+            return Err(Status::invalid_argument("no geometry specified"));
+        };
+        let loc = geometry.loc.unwrap_or_default();
+        let size = geometry.size.unwrap_or_default();
+        let geometry = Rectangle::new(
+            (loc.x, loc.y).into(),
+            (
+                size.width.min(i32::MAX as u32) as i32,
+                size.height.min(i32::MAX as u32) as i32,
+            )
+                .into(),
+        );
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.config.geometry_rules.push(GeometryRule {
+                matcher: WindowMatcher { app_id, title },
+                geometry,
+            });
+        })
+        .await
+    }
+
+    async fn to_scratchpad(&self, request: Request<ToScratchpadRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
+        let name = request.name;
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return;
+            };
+
+            crate::api::window::to_scratchpad(state, &window, name);
+        })
+        .await
+    }
+
+    async fn toggle_scratchpad(
+        &self,
+        request: Request<ToggleScratchpadRequest>,
+    ) -> TonicResult<()> {
+        let name = request.into_inner().name;
+
+        run_unary_no_response(&self.sender, move |state| {
+            crate::api::window::toggle_scratchpad(state, &name);
+        })
+        .await
+    }
+
+    async fn create_group(&self, request: Request<CreateGroupRequest>) -> TonicResult<()> {
+        let name = request.into_inner().name;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.window_groups.entry(name).or_default();
+        })
+        .await
+    }
+
+    async fn remove_group(&self, request: Request<RemoveGroupRequest>) -> TonicResult<()> {
+        let name = request.into_inner().name;
+
+        run_unary_no_response(&self.sender, move |state| {
+            state.pinnacle.window_groups.remove(&name);
+        })
+        .await
+    }
+
+    async fn add_to_group(&self, request: Request<AddToGroupRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
+        let group_name = request.group_name;
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return;
+            };
+
+            let group = state.pinnacle.window_groups.entry(group_name).or_default();
+            if !group.contains(&window) {
+                group.push(window);
+            }
+        })
+        .await
+    }
+
+    async fn remove_from_group(&self, request: Request<RemoveFromGroupRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
+        let group_name = request.group_name;
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return;
+            };
+
+            if let Some(group) = state.pinnacle.window_groups.get_mut(&group_name) {
+                group.retain(|win| win != &window);
+            }
+        })
+        .await
+    }
+
+    async fn get_group_windows(
+        &self,
+        request: Request<GetGroupWindowsRequest>,
+    ) -> TonicResult<GetGroupWindowsResponse> {
+        let name = request.into_inner().name;
+
+        run_unary(&self.sender, move |state| {
+            let window_ids = state
+                .pinnacle
+                .window_groups
+                .get(&name)
+                .into_iter()
+                .flatten()
+                .map(|win| win.with_state(|state| state.id.0))
+                .collect();
+
+            Ok(GetGroupWindowsResponse { window_ids })
+        })
+        .await
+    }
+
+    async fn focus_in_group(&self, request: Request<FocusInGroupRequest>) -> TonicResult<()> {
+        let backward = request.into_inner().backward;
+
+        run_unary_no_response(&self.sender, move |state| {
+            crate::api::window::focus_in_group(state, !backward);
+        })
+        .await
+    }
+
+    async fn add_to_groups_of(&self, request: Request<AddToGroupsOfRequest>) -> TonicResult<()> {
+        let request = request.into_inner();
+        let window_id = WindowId(request.window_id);
+        let target_id = WindowId(request.target_id);
+
+        run_unary_no_response(&self.sender, move |state| {
+            let Some(window) = window_id.window(&state.pinnacle) else {
+                return;
+            };
+            let Some(target) = target_id.window(&state.pinnacle) else {
+                return;
+            };
+
+            crate::api::window::add_to_groups_of(state, &window, &target);
+        })
+        .await
+    }
+
     async fn window_rule(
         &self,
         request: Request<Streaming<WindowRuleRequest>>,
@@ -1,15 +1,22 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+use std::{ffi::CStr, path::PathBuf, time::Duration};
+
 use anyhow::Context;
 use smithay::{
     backend::{
-        allocator::{dmabuf::Dmabuf, format::FormatSet},
+        allocator::{Format, dmabuf::Dmabuf, format::FormatSet},
         drm::{DrmNode, NodeType},
-        renderer::{ImportDma, Renderer, TextureFilter, gles::GlesRenderer},
+        egl::EGLDevice,
+        renderer::{
+            ImportDma, Renderer, TextureFilter,
+            gles::{GlesRenderer, ffi},
+        },
     },
     delegate_dmabuf,
     output::Output,
     reexports::{calloop::LoopHandle, wayland_server::protocol::wl_surface::WlSurface},
+    utils::{Clock, Monotonic},
     wayland::dmabuf::{
         DmabufFeedbackBuilder, DmabufGlobal, DmabufHandler, DmabufState, ImportNotifier,
     },
@@ -19,7 +26,9 @@ use wayland_backend::server::GlobalId;
 
 use crate::{
     output::OutputMode,
+    render::util::screenshot::Screenshot,
     state::{Pinnacle, State, WithState},
+    window::WindowElement,
 };
 
 #[cfg(feature = "testing")]
@@ -103,6 +112,21 @@ impl Backend {
         }
     }
 
+    /// Renders a window into a [`Screenshot`] at the given scale.
+    pub fn screenshot_window(
+        &mut self,
+        window: &WindowElement,
+        scale: f64,
+    ) -> anyhow::Result<Screenshot> {
+        match self {
+            Backend::Winit(_) | Backend::Udev(_) => self
+                .with_renderer(|renderer| window.screenshot(renderer, scale.into()))
+                .context("no renderer available")?,
+            #[cfg(feature = "testing")]
+            Backend::Dummy(dummy) => Ok(dummy.screenshot_window(window, scale)),
+        }
+    }
+
     pub fn set_output_vrr(&mut self, output: &Output, vrr: bool) {
         match self {
             Backend::Winit(_) => (),
@@ -112,6 +136,39 @@ impl Backend {
         }
     }
 
+    /// Creates a custom mode for `output` and adds it to the output's modes.
+    ///
+    /// This is only supported on the udev backend.
+    pub fn add_custom_mode(
+        &mut self,
+        output: &Output,
+        width: u32,
+        height: u32,
+        refresh_mhz: u32,
+        reduced_blanking: bool,
+    ) -> anyhow::Result<smithay::output::Mode> {
+        match self {
+            Backend::Winit(_) => anyhow::bail!("custom modes are not supported on winit"),
+            Backend::Udev(udev) => {
+                udev.add_custom_mode(output, width, height, refresh_mhz, reduced_blanking)
+            }
+            #[cfg(feature = "testing")]
+            Backend::Dummy(dummy) => dummy.add_custom_mode(output, width, height, refresh_mhz),
+        }
+    }
+
+    /// Checks that `output` can be switched to `mode` without switching it.
+    ///
+    /// Only the udev backend can test modes; other backends accept every mode.
+    pub fn test_output_mode(&mut self, output: &Output, mode: OutputMode) -> anyhow::Result<()> {
+        match self {
+            Backend::Winit(_) => Ok(()),
+            Backend::Udev(udev) => udev.test_output_mode(output, mode),
+            #[cfg(feature = "testing")]
+            Backend::Dummy(_) => Ok(()),
+        }
+    }
+
     fn set_output_powered(
         &mut self,
         output: &Output,
@@ -150,16 +207,78 @@ impl Backend {
     }
 
     pub fn render_scheduled_outputs(&mut self, pinnacle: &mut Pinnacle) {
-        if let Backend::Udev(udev) = self {
-            for output in pinnacle
-                .outputs
-                .iter()
-                .filter(|op| op.with_state(|state| state.enabled_global_id.is_some()))
-                .cloned()
-                .collect::<Vec<_>>()
-            {
-                udev.render_if_scheduled(pinnacle, &output);
+        match self {
+            Backend::Udev(udev) => {
+                for output in pinnacle
+                    .outputs
+                    .iter()
+                    .filter(|op| op.with_state(|state| state.enabled_global_id.is_some()))
+                    .cloned()
+                    .collect::<Vec<_>>()
+                {
+                    udev.render_if_scheduled(pinnacle, &output);
+                }
             }
+            // The dummy backend doesn't render, but on-demand vrr, blanking, and render
+            // results are still evaluated so they can be tested.
+            #[cfg(feature = "testing")]
+            Backend::Dummy(dummy) => {
+                for output in pinnacle.outputs.iter() {
+                    dummy.update_output_vrr(pinnacle, output);
+                    dummy.update_output_blanking(pinnacle, output);
+                }
+                dummy.render_scheduled_outputs(pinnacle);
+            }
+            Backend::Winit(_) => (),
+        }
+    }
+
+    /// Returns rendering diagnostics for the given output.
+    ///
+    /// Backends that don't track some of this leave it at its default.
+    pub fn render_diagnostics(
+        &mut self,
+        output: &Output,
+        clock: &Clock<Monotonic>,
+    ) -> RenderDiagnostics {
+        let diagnostics = match self {
+            Backend::Udev(udev) => udev.render_diagnostics(output, clock),
+            Backend::Winit(winit) => winit.render_diagnostics(clock),
+            #[cfg(feature = "testing")]
+            Backend::Dummy(dummy) => dummy.render_diagnostics(output),
+        };
+
+        RenderDiagnostics {
+            last_result: output.with_state(|state| state.last_render_result),
+            ..diagnostics
+        }
+    }
+
+    /// Queries information about the renderer, for diagnostics.
+    pub fn renderer_info(&mut self) -> RendererInfo {
+        match self {
+            Backend::Winit(winit) => RendererInfo::from_gles(winit.backend.renderer()),
+            Backend::Udev(udev) => {
+                let gpu_path = udev.primary_gpu.dev_path();
+                let info = udev
+                    .renderer()
+                    .ok()
+                    .map(|mut renderer| RendererInfo::from_gles(renderer.as_mut()))
+                    .unwrap_or_default();
+
+                RendererInfo {
+                    gpu_path: gpu_path.or(info.gpu_path),
+                    ..info
+                }
+            }
+            #[cfg(feature = "testing")]
+            Backend::Dummy(_) => RendererInfo {
+                renderer: "Dummy".to_string(),
+                vendor: "Pinnacle".to_string(),
+                version: String::new(),
+                gpu_path: None,
+                dmabuf_formats: Vec::new(),
+            },
         }
     }
 
@@ -182,6 +301,7 @@ impl Backend {
 
 impl State {
     pub fn set_output_powered(&mut self, output: &Output, powered: bool) {
+        output.with_state_mut(|state| state.dpms_powered_off = false);
         self.backend
             .set_output_powered(output, &self.pinnacle.loop_handle, powered);
         self.pinnacle
@@ -201,13 +321,84 @@ impl Drop for State {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// The result of an attempt to render an output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RenderResult {
+    /// A frame was rendered and submitted.
     Submitted,
+    /// Nothing changed, so no frame was submitted.
     NoDamage,
+    /// Rendering didn't happen or failed.
     Skipped,
 }
 
+/// Diagnostics about an output's rendering, for figuring out why an output stopped updating.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RenderDiagnostics {
+    /// The result of the last render attempt, or `None` if the output hasn't been rendered yet.
+    pub last_result: Option<RenderResult>,
+    /// How long ago a frame was last presented, if known.
+    pub time_since_last_presentation: Option<Duration>,
+    /// Whether a render is currently scheduled.
+    pub render_scheduled: bool,
+}
+
+/// Information about the renderer, for bug reports and triaging rendering issues.
+#[derive(Debug, Default, Clone)]
+pub struct RendererInfo {
+    /// The `GL_RENDERER` string.
+    pub renderer: String,
+    /// The `GL_VENDOR` string.
+    pub vendor: String,
+    /// The `GL_VERSION` string.
+    pub version: String,
+    /// The device path of the gpu that is rendering, if known.
+    pub gpu_path: Option<PathBuf>,
+    /// The dmabuf formats the renderer can import.
+    pub dmabuf_formats: Vec<Format>,
+}
+
+impl RendererInfo {
+    fn from_gles(renderer: &mut GlesRenderer) -> Self {
+        fn gl_string(gl: &ffi::Gles2, name: ffi::types::GLenum) -> String {
+            // SAFETY: The context is current inside `with_context`, and `GetString` returns
+            // either null or a static nul-terminated string.
+            unsafe {
+                let ptr = gl.GetString(name);
+                if ptr.is_null() {
+                    return String::new();
+                }
+                CStr::from_ptr(ptr.cast()).to_string_lossy().into_owned()
+            }
+        }
+
+        let (renderer_name, vendor, version) = renderer
+            .with_context(|gl| {
+                (
+                    gl_string(gl, ffi::RENDERER),
+                    gl_string(gl, ffi::VENDOR),
+                    gl_string(gl, ffi::VERSION),
+                )
+            })
+            .unwrap_or_default();
+
+        let gpu_path = EGLDevice::device_for_display(renderer.egl_context().display())
+            .ok()
+            .and_then(|device| device.try_get_render_node().ok().flatten())
+            .and_then(|node| node.dev_path());
+
+        let dmabuf_formats = renderer.dmabuf_formats().iter().copied().collect();
+
+        Self {
+            renderer: renderer_name,
+            vendor,
+            version,
+            gpu_path,
+            dmabuf_formats,
+        }
+    }
+}
+
 pub trait BackendData: 'static {
     fn seat_name(&self) -> String;
     fn reset_buffers(&mut self, output: &Output);
@@ -216,6 +407,9 @@ pub trait BackendData: 'static {
     fn early_import(&mut self, surface: &WlSurface);
 
     fn set_output_mode(&mut self, output: &Output, mode: OutputMode);
+
+    /// Runs the given closure with a renderer, or returns `None` if this backend doesn't have one.
+    fn with_gles_renderer<T>(&mut self, f: impl FnOnce(&mut GlesRenderer) -> T) -> Option<T>;
 }
 
 impl BackendData for Backend {
@@ -254,6 +448,10 @@ impl BackendData for Backend {
             Backend::Dummy(dummy) => dummy.set_output_mode(output, mode),
         }
     }
+
+    fn with_gles_renderer<T>(&mut self, f: impl FnOnce(&mut GlesRenderer) -> T) -> Option<T> {
+        self.with_renderer(f)
+    }
 }
 
 impl DmabufHandler for State {
@@ -311,4 +509,15 @@ impl Pinnacle {
 
         Ok((dmabuf_global, drm_global_id))
     }
+
+    /// Records that hardware acceleration couldn't be initialized.
+    ///
+    /// Pinnacle keeps running, but clients can only share buffers through shared memory.
+    pub fn hardware_accel_failed(&mut self, err: &anyhow::Error) {
+        error!(
+            "Failed to initialize EGL hardware acceleration, \
+            falling back to shared memory buffers: {err:#}"
+        );
+        self.hardware_accel_error = Some(format!("{err:#}"));
+    }
 }
@@ -1,7 +1,9 @@
 use smithay::backend::renderer::ImportMemWl;
+use smithay::backend::renderer::gles::GlesRenderer;
 use smithay::backend::renderer::test::DummyRenderer;
+use smithay::desktop::space::SpaceElement;
 use smithay::reexports::wayland_server::protocol::wl_surface::WlSurface;
-use smithay::utils::{Logical, Physical, Point, Size};
+use smithay::utils::{Logical, Physical, Point, Raw, Size};
 
 use smithay::{
     output::{Output, Subpixel},
@@ -9,11 +11,13 @@ use smithay::{
 };
 
 use crate::api::signal::Signal;
-use crate::output::OutputMode;
+use crate::output::{BlankingState, OutputMode, auto_scale};
+use crate::render::util::screenshot::Screenshot;
 use crate::state::{Pinnacle, State, WithState};
+use crate::window::WindowElement;
 
-use super::BackendData;
 use super::{Backend, UninitBackend};
+use super::{BackendData, RenderDiagnostics, RenderResult};
 
 pub const DUMMY_OUTPUT_NAME: &str = "Dummy Window";
 
@@ -25,6 +29,8 @@ pub struct Wlcs {
 
 pub struct Dummy {
     pub renderer: DummyRenderer,
+    /// Outputs that have a render scheduled.
+    scheduled_outputs: Vec<Output>,
     // pub dmabuf_state: (DmabufState, DmabufGlobal, Option<DmabufFeedback>),
     #[cfg(feature = "wlcs")]
     pub wlcs_state: Wlcs,
@@ -52,12 +58,16 @@ impl BackendData for Dummy {
     fn set_output_mode(&mut self, output: &Output, mode: OutputMode) {
         output.change_current_state(Some(mode.into()), None, None, None);
     }
+    fn with_gles_renderer<T>(&mut self, _f: impl FnOnce(&mut GlesRenderer) -> T) -> Option<T> {
+        None
+    }
 }
 
 impl Dummy {
     pub(crate) fn try_new() -> UninitBackend<Dummy> {
         let dummy = Dummy {
             renderer: DummyRenderer,
+            scheduled_outputs: Vec::new(),
             // dmabuf_state,
             #[cfg(feature = "wlcs")]
             wlcs_state: Wlcs::default(),
@@ -82,6 +92,90 @@ impl Dummy {
     pub(super) fn set_output_vrr(&self, output: &Output, vrr: bool) {
         output.with_state_mut(|state| state.is_vrr_on = vrr);
     }
+
+    pub(super) fn update_output_vrr(&self, pinnacle: &Pinnacle, output: &Output) {
+        if output.with_state(|state| !state.is_vrr_on_demand) {
+            return;
+        }
+
+        // Nothing is actually scanned out here, so treat windows on active tags as visible
+        let vrr = pinnacle.output_demands_vrr(output, |win| win.is_on_active_tag());
+
+        self.set_output_vrr(output, vrr);
+    }
+
+    pub(super) fn add_custom_mode(
+        &self,
+        output: &Output,
+        width: u32,
+        height: u32,
+        refresh_mhz: u32,
+    ) -> anyhow::Result<smithay::output::Mode> {
+        // There's no hardware to reject modes, so just check that the mode makes sense
+        anyhow::ensure!(
+            width > 0 && height > 0 && refresh_mhz > 0,
+            "{width}x{height}@{refresh_mhz}mHz is not a valid mode"
+        );
+
+        let mode = smithay::output::Mode {
+            size: (width as i32, height as i32).into(),
+            refresh: refresh_mhz as i32,
+        };
+
+        output.with_state_mut(|state| {
+            if !state.modes.contains(&mode) {
+                state.modes.push(mode);
+            }
+        });
+
+        Ok(mode)
+    }
+
+    pub(super) fn screenshot_window(&self, window: &WindowElement, scale: f64) -> Screenshot {
+        // Nothing is actually rendered, so return a blank image the size of the window
+        let size = window.bbox().size.to_physical_precise_round(scale);
+        Screenshot::transparent(size)
+    }
+
+    pub fn schedule_render(&mut self, output: &Output) {
+        if !self.scheduled_outputs.contains(output) {
+            self.scheduled_outputs.push(output.clone());
+        }
+    }
+
+    pub(super) fn render_scheduled_outputs(&mut self, pinnacle: &mut Pinnacle) {
+        for output in std::mem::take(&mut self.scheduled_outputs) {
+            // Nothing is actually rendered, so every scheduled frame is submitted immediately
+            let result = if output.with_state(|state| state.powered) {
+                if pinnacle.advance_output_transition(&output)
+                    && pinnacle.request_animation_frame(&output)
+                {
+                    self.schedule_render(&output);
+                }
+                RenderResult::Submitted
+            } else {
+                RenderResult::Skipped
+            };
+
+            pinnacle.set_render_result(&output, result);
+        }
+    }
+
+    pub(super) fn render_diagnostics(&self, output: &Output) -> RenderDiagnostics {
+        RenderDiagnostics {
+            render_scheduled: self.scheduled_outputs.contains(output),
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn update_output_blanking(&self, pinnacle: &Pinnacle, output: &Output) {
+        if !pinnacle.should_blank_output(output) {
+            return;
+        }
+
+        // Nothing is actually rendered, so the blank frame is "displayed" immediately
+        output.with_state_mut(|state| state.blanking_state = BlankingState::Blanked);
+    }
 }
 
 impl Pinnacle {
@@ -92,6 +186,7 @@ impl Pinnacle {
         model: impl std::fmt::Display,
         loc: Point<i32, Logical>,
         size: Size<i32, Physical>,
+        physical_size: Size<i32, Raw>,
         refresh: i32,
         scale: f64,
         transform: Transform,
@@ -99,7 +194,7 @@ impl Pinnacle {
         let mode = smithay::output::Mode { size, refresh };
 
         let physical_properties = smithay::output::PhysicalProperties {
-            size: (0, 0).into(),
+            size: physical_size,
             subpixel: Subpixel::Unknown,
             make: make.to_string(),
             model: model.to_string(),
@@ -118,6 +213,12 @@ impl Pinnacle {
         output.set_preferred(mode);
         output.with_state_mut(|state| state.modes = vec![mode]);
 
+        if self.config.auto_scale {
+            let scale = smithay::output::Scale::Fractional(auto_scale(&output));
+            output.change_current_state(None, None, Some(scale), None);
+            output.with_state_mut(|state| state.scale_settled = true);
+        }
+
         let global = output.create_global::<State>(&self.display_handle);
 
         output.with_state_mut(|state| state.enabled_global_id = Some(global));
@@ -125,6 +226,7 @@ impl Pinnacle {
         self.outputs.push(output.clone());
 
         self.space.map_output(&output, loc);
+        self.output_layout_changed = true;
 
         self.signal_state.output_connect.signal(&output);
 
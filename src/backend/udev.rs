@@ -10,10 +10,10 @@ use frame::FrameClock;
 use indexmap::IndexSet;
 use wayland_backend::server::GlobalId;
 
-use std::{collections::HashMap, mem, path::Path, time::Duration};
+use std::{collections::HashMap, mem, ops::RangeBounds, path::Path, time::Duration};
 
 use anyhow::{Context, anyhow, ensure};
-use drm::{create_drm_mode, refresh_interval};
+use drm::{create_custom_drm_mode, create_drm_mode, refresh_interval};
 use smithay::{
     backend::{
         SwapBuffersError,
@@ -44,7 +44,7 @@ use smithay::{
         udev::{self, UdevBackend, UdevEvent},
     },
     desktop::utils::{OutputPresentationFeedback, surface_primary_scanout_output},
-    output::{Output, PhysicalProperties, Subpixel},
+    output::{Output, PhysicalProperties, Scale, Subpixel},
     reexports::{
         calloop::{
             self, Dispatcher, Interest, LoopHandle, PostAction, RegistrationToken,
@@ -63,7 +63,7 @@ use smithay::{
             protocol::{wl_shm, wl_surface::WlSurface},
         },
     },
-    utils::{DeviceFd, Rectangle, Transform},
+    utils::{Clock, DeviceFd, Monotonic, Rectangle, Transform},
     wayland::{
         dmabuf::{self, DmabufFeedback, DmabufFeedbackBuilder, DmabufGlobal},
         presentation::Refresh,
@@ -77,8 +77,9 @@ use crate::{
     api::signal::Signal,
     backend::Backend,
     config::ConnectorSavedState,
+    cursor::CursorMode,
     input::libinput::DeviceState,
-    output::{BlankingState, OutputMode, OutputName},
+    output::{BlankingState, OutputMode, OutputName, auto_scale},
     render::{
         CLEAR_COLOR, CLEAR_COLOR_LOCKED, OutputRenderElement, pointer::pointer_render_elements,
         take_presentation_feedback,
@@ -86,7 +87,7 @@ use crate::{
     state::{FrameCallbackSequence, Pinnacle, State, WithState},
 };
 
-use super::{BackendData, UninitBackend};
+use super::{BackendData, RenderDiagnostics, RenderResult, UninitBackend};
 
 const SUPPORTED_FORMATS: &[Fourcc] = &[
     Fourcc::Abgr2101010,
@@ -127,6 +128,9 @@ pub struct Udev {
     /// The global corresponding to the primary gpu
     dmabuf_global: Option<DmabufGlobal>,
     drm_global: Option<GlobalId>,
+    /// User-defined modes added through the API, kept per connector so they survive
+    /// the connector being unplugged or disabled
+    custom_modes: HashMap<OutputName, Vec<smithay::reexports::drm::control::Mode>>,
 
     pub(super) upscale_filter: TextureFilter,
     pub(super) downscale_filter: TextureFilter,
@@ -210,6 +214,7 @@ impl Udev {
             devices: HashMap::new(),
             dmabuf_global: None,
             drm_global: None,
+            custom_modes: HashMap::new(),
 
             upscale_filter: TextureFilter::Linear,
             downscale_filter: TextureFilter::Linear,
@@ -239,6 +244,8 @@ impl Udev {
                     }
                 }
 
+                udev.fall_back_from_primary_gpu(pinnacle);
+
                 // Initialize libinput backend
                 let mut libinput_context = Libinput::new_with_udev::<
                     LibinputSessionInterface<LibSeatSession>,
@@ -502,6 +509,126 @@ impl Udev {
             }
         }
     }
+
+    /// Creates a user-defined mode with CVT timings and makes it selectable on `output`.
+    ///
+    /// Fails if the mode is outside the framebuffer limits of the output's device
+    /// or the driver rejects it in a test commit.
+    pub fn add_custom_mode(
+        &mut self,
+        output: &Output,
+        width: u32,
+        height: u32,
+        refresh_mhz: u32,
+        reduced_blanking: bool,
+    ) -> anyhow::Result<smithay::output::Mode> {
+        let UdevOutputData { device_id, .. } = output
+            .user_data()
+            .get()
+            .context("no udev output data for output")?;
+
+        let resources = self
+            .devices
+            .get(device_id)
+            .context("no udev backend data for output")?
+            .drm_output_manager
+            .device()
+            .resource_handles()?;
+
+        ensure!(refresh_mhz > 0, "refresh rate must be greater than 0");
+        ensure!(
+            width <= u16::MAX as u32
+                && height <= u16::MAX as u32
+                && resources.supported_fb_width().contains(&width)
+                && resources.supported_fb_height().contains(&height),
+            "{width}x{height} is outside the limits of {}",
+            output.name()
+        );
+
+        let drm_mode =
+            create_custom_drm_mode(width as i32, height as i32, refresh_mhz, reduced_blanking);
+        let mode = smithay::output::Mode::from(drm_mode);
+
+        let surface =
+            render_surface_for_output(output, &mut self.devices).context("output is disabled")?;
+
+        test_drm_mode(surface, drm_mode).with_context(|| {
+            format!(
+                "{width}x{height}@{:.3}Hz was rejected by {}",
+                refresh_mhz as f64 / 1000.0,
+                output.name()
+            )
+        })?;
+
+        let custom_modes = self
+            .custom_modes
+            .entry(OutputName(output.name()))
+            .or_default();
+        custom_modes.retain(|custom| smithay::output::Mode::from(*custom) != mode);
+        custom_modes.push(drm_mode);
+
+        output.with_state_mut(|state| {
+            if !state.modes.contains(&mode) {
+                state.modes.push(mode);
+            }
+        });
+
+        Ok(mode)
+    }
+
+    /// Returns the drm mode to use for `mode` on `output`, preferring custom and
+    /// advertised modes and creating one otherwise.
+    fn drm_mode_for_output(
+        &self,
+        output: &Output,
+        mode: OutputMode,
+    ) -> smithay::reexports::drm::control::Mode {
+        let custom_mode = self
+            .custom_modes
+            .get(&OutputName(output.name()))
+            .into_iter()
+            .flatten()
+            .find(|custom| smithay::output::Mode::from(**custom) == mode.into())
+            .copied();
+
+        custom_mode
+            .or_else(|| {
+                self.devices.iter().find_map(|(_, device)| {
+                    device
+                        .drm_scanner
+                        .crtcs()
+                        .find(|(_, handle)| {
+                            output
+                                .user_data()
+                                .get::<UdevOutputData>()
+                                .is_some_and(|data| &data.crtc == handle)
+                        })
+                        .and_then(|(info, _)| {
+                            info.modes()
+                                .iter()
+                                .find(|m| smithay::output::Mode::from(**m) == mode.into())
+                        })
+                        .copied()
+                })
+            })
+            .unwrap_or_else(|| {
+                info!("Unknown mode for {}, creating new one", output.name());
+                match mode {
+                    OutputMode::Smithay(mode) => {
+                        create_drm_mode(mode.size.w, mode.size.h, Some(mode.refresh as u32))
+                    }
+                    OutputMode::Drm(mode) => mode,
+                }
+            })
+    }
+
+    /// Checks that `output` can be switched to `mode` with a test commit.
+    pub fn test_output_mode(&mut self, output: &Output, mode: OutputMode) -> anyhow::Result<()> {
+        let drm_mode = self.drm_mode_for_output(output, mode);
+        let surface =
+            render_surface_for_output(output, &mut self.devices).context("output is disabled")?;
+        test_drm_mode(surface, drm_mode)
+    }
 }
 
 impl State {
@@ -545,35 +672,7 @@ impl BackendData for Udev {
     fn set_output_mode(&mut self, output: &Output, mode: OutputMode) {
         let _span = tracy_client::span!("Udev: BackendData::set_output_mode");
 
-        let drm_mode = self
-            .devices
-            .iter()
-            .find_map(|(_, device)| {
-                device
-                    .drm_scanner
-                    .crtcs()
-                    .find(|(_, handle)| {
-                        output
-                            .user_data()
-                            .get::<UdevOutputData>()
-                            .is_some_and(|data| &data.crtc == handle)
-                    })
-                    .and_then(|(info, _)| {
-                        info.modes()
-                            .iter()
-                            .find(|m| smithay::output::Mode::from(**m) == mode.into())
-                    })
-                    .copied()
-            })
-            .unwrap_or_else(|| {
-                info!("Unknown mode for {}, creating new one", output.name());
-                match mode {
-                    OutputMode::Smithay(mode) => {
-                        create_drm_mode(mode.size.w, mode.size.h, Some(mode.refresh as u32))
-                    }
-                    OutputMode::Drm(mode) => mode,
-                }
-            });
+        let drm_mode = self.drm_mode_for_output(output, mode);
 
         if let Some(render_surface) = render_surface_for_output(output, &mut self.devices)
             && let Ok(mut renderer) = self.gpu_manager.single_renderer(&self.primary_gpu)
@@ -604,6 +703,9 @@ impl BackendData for Udev {
             }
         }
     }
+    fn with_gles_renderer<T>(&mut self, f: impl FnOnce(&mut GlesRenderer) -> T) -> Option<T> {
+        Some(f(self.renderer().ok()?.as_mut()))
+    }
 }
 
 // TODO: document desperately
@@ -757,6 +859,67 @@ impl Udev {
         Ok(self.gpu_manager.single_renderer(&self.primary_gpu)?)
     }
 
+    /// Initializes hardware acceleration on the primary gpu.
+    fn init_primary_gpu(
+        &mut self,
+        pinnacle: &mut Pinnacle,
+        render_node: DrmNode,
+    ) -> anyhow::Result<()> {
+        let renderer = self.gpu_manager.single_renderer(&render_node)?;
+
+        let (dmabuf_global, drm_global_id) =
+            pinnacle.init_hardware_accel(render_node, renderer.dmabuf_formats())?;
+
+        assert!(self.dmabuf_global.replace(dmabuf_global).is_none());
+        assert!(self.drm_global.replace(drm_global_id).is_none());
+
+        pinnacle.hardware_accel_error = None;
+
+        // Update the per drm surface dmabuf feedback
+        for device in self.devices.values_mut() {
+            for surface in device.surfaces.values_mut() {
+                let dmabuf_feedback = surface.drm_output.with_compositor(|compositor| {
+                    get_surface_dmabuf_feedback(
+                        render_node,
+                        surface.render_node,
+                        &mut self.gpu_manager,
+                        compositor.surface(),
+                    )
+                });
+
+                if let Some(dmabuf_feedback) = dmabuf_feedback {
+                    surface.dmabuf_feedback.replace(dmabuf_feedback);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Switches the primary gpu to another connected gpu if hardware acceleration
+    /// couldn't be initialized on it.
+    fn fall_back_from_primary_gpu(&mut self, pinnacle: &mut Pinnacle) {
+        if self.dmabuf_global.is_some() {
+            return;
+        }
+
+        let primary_gpu = self.primary_gpu;
+        let gpus = self
+            .devices
+            .values()
+            .map(|device| device.render_node)
+            .collect::<Vec<_>>();
+
+        fall_back_from_primary_gpu(pinnacle, primary_gpu, gpus, |pinnacle, render_node| {
+            self.primary_gpu = render_node;
+            let result = self.init_primary_gpu(pinnacle, render_node);
+            if result.is_err() {
+                self.primary_gpu = primary_gpu;
+            }
+            result
+        });
+    }
+
     /// A GPU was plugged in.
     fn device_added(
         &mut self,
@@ -815,35 +978,10 @@ impl Udev {
             .add_node(render_node, gbm.clone())
             .context("failed to add device to GpuManager")?;
 
-        if render_node == self.primary_gpu {
-            let renderer = self.gpu_manager.single_renderer(&render_node)?;
-
-            let (dmabuf_global, drm_global_id) = pinnacle
-                .init_hardware_accel(render_node, renderer.dmabuf_formats())
-                .inspect_err(|err| {
-                    error!("Failed to initialize EGL hardware acceleration: {err}");
-                })?;
-
-            assert!(self.dmabuf_global.replace(dmabuf_global).is_none());
-            assert!(self.drm_global.replace(drm_global_id).is_none());
-
-            // Update the per drm surface dmabuf feedback
-            for device in self.devices.values_mut() {
-                for surface in device.surfaces.values_mut() {
-                    let dmabuf_feedback = surface.drm_output.with_compositor(|compositor| {
-                        get_surface_dmabuf_feedback(
-                            render_node,
-                            surface.render_node,
-                            &mut self.gpu_manager,
-                            compositor.surface(),
-                        )
-                    });
-
-                    if let Some(dmabuf_feedback) = dmabuf_feedback {
-                        surface.dmabuf_feedback.replace(dmabuf_feedback);
-                    }
-                }
-            }
+        if render_node == self.primary_gpu
+            && let Err(err) = self.init_primary_gpu(pinnacle, render_node)
+        {
+            pinnacle.hardware_accel_failed(&err);
         }
 
         let allocator = GbmAllocator::new(
@@ -970,6 +1108,7 @@ impl Udev {
 
         pinnacle.outputs.push(output.clone());
         pinnacle.output_focus_stack.add_to_end(output.clone());
+        pinnacle.output_layout_changed = true;
 
         output.with_state_mut(|state| {
             state.debug_damage_tracker = OutputDamageTracker::from_output(&output);
@@ -977,12 +1116,23 @@ impl Udev {
 
         output.set_preferred(smithay_mode);
 
-        let modes = connector
+        let mut modes = connector
             .modes()
             .iter()
             .cloned()
             .map(smithay::output::Mode::from)
             .collect::<Vec<_>>();
+        for custom_mode in self
+            .custom_modes
+            .get(&OutputName(output.name()))
+            .into_iter()
+            .flatten()
+        {
+            let custom_mode = smithay::output::Mode::from(*custom_mode);
+            if !modes.contains(&custom_mode) {
+                modes.push(custom_mode);
+            }
+        }
         output.with_state_mut(|state| state.modes = modes);
 
         pinnacle
@@ -1057,18 +1207,23 @@ impl Udev {
             Some(position),
         );
 
-        // If there is saved connector state, the connector was previously plugged in.
+        // If there is saved state, the display or connector was previously plugged in.
         // In this case, restore its tags and location.
-        // TODO: instead of checking the connector, check the monitor's edid info instead
-        if let Some(saved_state) = pinnacle
-            .config
-            .connector_saved_states
-            .get(&OutputName(output.name()))
-        {
-            let ConnectorSavedState { loc, tags, scale } = saved_state;
-            output.with_state_mut(|state| state.tags.clone_from(tags));
-            pinnacle.change_output_state(self, &output, None, None, *scale, Some(*loc));
+        if let Some(saved_state) = pinnacle.saved_output_state(&output).cloned() {
+            let ConnectorSavedState {
+                loc, tags, scale, ..
+            } = saved_state;
+            output.with_state_mut(|state| {
+                state.tags = tags;
+                state.scale_settled = scale.is_some();
+            });
+            pinnacle.change_output_state(self, &output, None, None, scale, Some(loc));
         } else {
+            if pinnacle.config.auto_scale {
+                output.with_state_mut(|state| state.scale_settled = true);
+                let scale = Scale::Fractional(auto_scale(&output));
+                pinnacle.change_output_state(self, &output, None, None, Some(scale), None);
+            }
             pinnacle.signal_state.output_connect.signal(&output);
         }
 
@@ -1309,7 +1464,10 @@ impl Udev {
             }
         };
 
-        if render_needed || pinnacle.cursor_state.is_current_cursor_animated() {
+        let animating = pinnacle.cursor_state.is_current_cursor_animated()
+            || output.with_state(|state| state.transition.is_some());
+
+        if render_needed || (animating && pinnacle.request_animation_frame(&output)) {
             self.schedule_render(&output);
         } else {
             pinnacle.send_frame_callbacks(&output, Some(surface.frame_callback_sequence));
@@ -1360,11 +1518,13 @@ impl Udev {
         if !is_active {
             warn!("Device is inactive");
             make_idle(&mut surface.render_state, &pinnacle.loop_handle);
+            pinnacle.set_render_result(output, RenderResult::Skipped);
             return;
         }
 
         if !pinnacle.outputs.contains(output) {
             make_idle(&mut surface.render_state, &pinnacle.loop_handle);
+            pinnacle.set_render_result(output, RenderResult::Skipped);
             return;
         }
 
@@ -1372,6 +1532,7 @@ impl Udev {
         // does nothing on powered off outputs
         if output.with_state(|state| !state.powered) {
             make_idle(&mut surface.render_state, &pinnacle.loop_handle);
+            pinnacle.set_render_result(output, RenderResult::Skipped);
             return;
         }
 
@@ -1406,9 +1567,7 @@ impl Udev {
 
         let mut output_render_elements = Vec::new();
 
-        let should_blank = pinnacle.lock_state.is_locking()
-            || (pinnacle.lock_state.is_locked()
-                && output.with_state(|state| state.lock_surface.is_none()));
+        let should_blank = pinnacle.should_blank_output(output);
 
         let (pointer_render_elements, cursor_ids) = pointer_render_elements(
             output,
@@ -1428,7 +1587,7 @@ impl Udev {
         if should_blank {
             output.with_state_mut(|state| {
                 if let BlankingState::NotBlanked = state.blanking_state {
-                    debug!("Blanking output {} for session lock", output.name());
+                    debug!("Blanking output {}", output.name());
                     state.blanking_state = BlankingState::Blanking;
                 }
             });
@@ -1446,14 +1605,36 @@ impl Udev {
                 output_render_elements.extend(elems);
             }
         } else {
+            output_render_elements.extend(
+                crate::render::idle_dim_render_element(pinnacle, output)
+                    .map(OutputRenderElement::from),
+            );
+            output_render_elements.extend(
+                crate::render::output_transition_render_element(
+                    output,
+                    &mut renderer,
+                    pinnacle.clock.now().into(),
+                )
+                .map(OutputRenderElement::from),
+            );
+            pinnacle.advance_output_transition(output);
             output_render_elements.extend(crate::render::output_render_elements(
                 output,
                 &mut renderer,
                 &pinnacle.space,
                 &pinnacle.z_index_stack,
+                pinnacle.overview.as_ref(),
+                pinnacle.occluding_window(output).as_ref(),
             ));
         }
 
+        if pinnacle.config.debug.opaque_region_culling {
+            crate::render::util::cull_opaque_occluded_elements(
+                &mut output_render_elements,
+                smithay::utils::Scale::from(output.current_scale().fractional_scale()),
+            );
+        }
+
         if pinnacle.config.debug.visualize_opaque_regions {
             crate::render::util::render_opaque_regions(
                 &mut output_render_elements,
@@ -1482,13 +1663,22 @@ impl Udev {
             CLEAR_COLOR_LOCKED
         };
 
-        // No overlay planes cuz they wonk
-        let mut frame_flags =
-            FrameFlags::ALLOW_PRIMARY_PLANE_SCANOUT_ANY | FrameFlags::ALLOW_CURSOR_PLANE_SCANOUT;
+        crate::render::supersample::apply_output_supersampling(
+            &mut renderer,
+            output,
+            &mut output_render_elements,
+            clear_color,
+        );
 
-        if pinnacle.config.debug.disable_cursor_plane_scanout {
-            frame_flags.remove(FrameFlags::ALLOW_CURSOR_PLANE_SCANOUT);
-        }
+        crate::render::color_filter::apply_output_color_filter(
+            &mut renderer,
+            output,
+            &mut output_render_elements,
+            clear_color,
+        );
+
+        let mut frame_flags =
+            scanout_frame_flags(&pinnacle.config.debug, pinnacle.config.cursor_mode);
 
         if surface.frame_clock.vrr()
             && let Some(time_since_last_presentation) = surface
@@ -1597,6 +1787,8 @@ impl Udev {
 
                             self.update_output_vrr(pinnacle, output);
 
+                            pinnacle.set_render_result(output, RenderResult::Submitted);
+
                             // Return here to not queue the estimated vblank timer on a submitted frame
                             return;
                         }
@@ -1618,6 +1810,15 @@ impl Udev {
 
         Self::queue_estimated_vblank_timer(surface, pinnacle, output, time_to_next_presentation);
 
+        pinnacle.set_render_result(
+            output,
+            if failed {
+                RenderResult::Skipped
+            } else {
+                RenderResult::NoDamage
+            },
+        );
+
         if failed {
             surface.render_state = if let RenderState::WaitingForEstimatedVblank(token)
             | RenderState::WaitingForEstimatedVblankAndScheduled(
@@ -1633,6 +1834,29 @@ impl Udev {
         pinnacle.send_frame_callbacks(output, Some(surface.frame_callback_sequence));
     }
 
+    pub(super) fn render_diagnostics(
+        &mut self,
+        output: &Output,
+        clock: &Clock<Monotonic>,
+    ) -> RenderDiagnostics {
+        let Some(surface) = render_surface_for_output(output, &mut self.devices) else {
+            return RenderDiagnostics::default();
+        };
+
+        RenderDiagnostics {
+            time_since_last_presentation: surface.frame_clock.time_since_last_presentation(clock),
+            render_scheduled: matches!(
+                surface.render_state,
+                RenderState::Scheduled
+                    | RenderState::WaitingForVblank {
+                        render_needed: true
+                    }
+                    | RenderState::WaitingForEstimatedVblankAndScheduled(_)
+            ),
+            ..Default::default()
+        }
+    }
+
     fn queue_estimated_vblank_timer(
         surface: &mut RenderSurface,
         pinnacle: &mut Pinnacle,
@@ -1704,7 +1928,9 @@ impl Udev {
             }
         }
 
-        if pinnacle.cursor_state.is_current_cursor_animated() {
+        if pinnacle.cursor_state.is_current_cursor_animated()
+            || output.with_state(|state| state.transition.is_some())
+        {
             self.schedule_render(output);
         } else {
             pinnacle.send_frame_callbacks(output, Some(surface.frame_callback_sequence));
@@ -1716,22 +1942,14 @@ impl Udev {
             return;
         }
 
-        let vrr = pinnacle.space.elements_for_output(output).any(|win| {
-            let Some(demand) = win.with_state(|state| state.vrr_demand) else {
-                return false;
-            };
-
+        let vrr = pinnacle.output_demands_vrr(output, |win| {
             let mut visible = false;
             win.with_surfaces(|surface, states| {
                 if surface_primary_scanout_output(surface, states).as_ref() == Some(output) {
                     visible = true;
                 }
             });
-
             visible
-                // FIXME: We probably want to check the actual fullscreen state, not the layout mode,
-                // but this isn't a *super* huge deal
-                && (!demand.fullscreen || win.with_state(|state| state.layout_mode.is_fullscreen()))
         });
 
         self.set_output_vrr(output, vrr);
@@ -1764,6 +1982,22 @@ impl Udev {
     }
 }
 
+/// Test commits `drm_mode` on `surface`, leaving its pending mode unchanged.
+///
+/// Legacy drm can't test commit, so this always succeeds there.
+fn test_drm_mode(
+    surface: &RenderSurface,
+    drm_mode: smithay::reexports::drm::control::Mode,
+) -> anyhow::Result<()> {
+    surface.drm_output.with_compositor(|compositor| {
+        let drm_surface = compositor.surface();
+        let current_mode = drm_surface.pending_mode();
+        drm_surface.use_mode(drm_mode)?;
+        drm_surface.use_mode(current_mode)?;
+        Ok(())
+    })
+}
+
 fn render_surface_for_output<'a>(
     output: &Output,
     devices: &'a mut HashMap<DrmNode, Device>,
@@ -2067,3 +2301,95 @@ fn handle_pending_screencopy<'a>(
         }
     }
 }
+
+/// Returns the frame flags that determine which planes elements may be scanned out on.
+fn scanout_frame_flags(debug: &crate::config::Debug, cursor_mode: CursorMode) -> FrameFlags {
+    // Everything is composited onto the primary plane's buffer
+    if debug.disable_direct_scanout {
+        return FrameFlags::empty();
+    }
+
+    // No overlay planes cuz they wonk
+    let mut frame_flags =
+        FrameFlags::ALLOW_PRIMARY_PLANE_SCANOUT_ANY | FrameFlags::ALLOW_CURSOR_PLANE_SCANOUT;
+
+    if debug.disable_cursor_plane_scanout || cursor_mode == CursorMode::Software {
+        frame_flags.remove(FrameFlags::ALLOW_CURSOR_PLANE_SCANOUT);
+    }
+
+    frame_flags
+}
+
+/// Tries initializing hardware acceleration on each of `gpus` other than `primary_gpu`
+/// until one succeeds, returning it.
+///
+/// This is for when hardware acceleration couldn't be initialized on the primary gpu.
+/// If the primary gpu isn't in `gpus` because it couldn't be added, that's recorded
+/// as the hardware acceleration error.
+pub fn fall_back_from_primary_gpu<N: Copy + PartialEq + std::fmt::Display>(
+    pinnacle: &mut Pinnacle,
+    primary_gpu: N,
+    gpus: Vec<N>,
+    mut init_primary_gpu: impl FnMut(&mut Pinnacle, N) -> anyhow::Result<()>,
+) -> Option<N> {
+    if !gpus.contains(&primary_gpu) {
+        pinnacle.hardware_accel_failed(&anyhow::anyhow!(
+            "primary gpu {primary_gpu} failed to initialize"
+        ));
+    }
+
+    for gpu in gpus.into_iter().filter(|gpu| *gpu != primary_gpu) {
+        match init_primary_gpu(pinnacle, gpu) {
+            Ok(()) => {
+                info!("Falling back to {gpu} as primary gpu");
+                return Some(gpu);
+            }
+            Err(err) => {
+                warn!("Failed to initialize {gpu} as a fallback primary gpu: {err}");
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Debug;
+
+    use super::*;
+
+    #[test]
+    fn scanout_frame_flags_allow_primary_and_cursor_planes_by_default() {
+        let flags = scanout_frame_flags(&Debug::default(), CursorMode::Plane);
+
+        assert!(flags.contains(FrameFlags::ALLOW_PRIMARY_PLANE_SCANOUT_ANY));
+        assert!(flags.contains(FrameFlags::ALLOW_CURSOR_PLANE_SCANOUT));
+        assert!(!flags.contains(FrameFlags::ALLOW_OVERLAY_PLANE_SCANOUT));
+    }
+
+    #[test]
+    fn scanout_frame_flags_disable_direct_scanout_disallows_all_planes() {
+        let flags = scanout_frame_flags(
+            &Debug {
+                disable_direct_scanout: true,
+                ..Default::default()
+            },
+            CursorMode::Plane,
+        );
+
+        assert!(!flags.intersects(
+            FrameFlags::ALLOW_PRIMARY_PLANE_SCANOUT_ANY
+                | FrameFlags::ALLOW_OVERLAY_PLANE_SCANOUT
+                | FrameFlags::ALLOW_CURSOR_PLANE_SCANOUT
+        ));
+    }
+
+    #[test]
+    fn scanout_frame_flags_software_cursor_disallows_cursor_plane() {
+        let flags = scanout_frame_flags(&Debug::default(), CursorMode::Software);
+
+        assert!(flags.contains(FrameFlags::ALLOW_PRIMARY_PLANE_SCANOUT_ANY));
+        assert!(!flags.contains(FrameFlags::ALLOW_CURSOR_PLANE_SCANOUT));
+    }
+}
@@ -75,13 +75,37 @@ pub fn create_drm_mode(width: i32, height: i32, refresh_mhz: Option<u32>) -> drm
         width,
         height,
         refresh_mhz.map(|refresh| refresh as f64 / 1000.0),
+        false,
+    ))
+}
+
+/// Create a new user-defined drm mode with CVT timings, optionally with reduced blanking.
+pub fn create_custom_drm_mode(
+    width: i32,
+    height: i32,
+    refresh_mhz: u32,
+    reduced_blanking: bool,
+) -> drm::control::Mode {
+    drm::control::Mode::from(generate_cvt_mode(
+        width,
+        height,
+        Some(refresh_mhz as f64 / 1000.0),
+        reduced_blanking,
     ))
 }
 
 // From https://gitlab.freedesktop.org/wlroots/wlroots/-/blob/95ac3e99242b4e7f59f00dd073ede405ff8e9e26/backend/drm/util.c#L247
-fn generate_cvt_mode(hdisplay: i32, vdisplay: i32, vrefresh: Option<f64>) -> drm_mode_modeinfo {
+fn generate_cvt_mode(
+    hdisplay: i32,
+    vdisplay: i32,
+    vrefresh: Option<f64>,
+    reduced_blanking: bool,
+) -> drm_mode_modeinfo {
     let options = cvt::Options {
-        red_blank_ver: ReducedBlankingVersion::None,
+        red_blank_ver: match reduced_blanking {
+            true => ReducedBlankingVersion::V1,
+            false => ReducedBlankingVersion::None,
+        },
         h_pixels: hdisplay,
         v_lines: vdisplay,
         ip_freq_rqd: vrefresh.unwrap_or(60.0),
@@ -118,7 +142,11 @@ fn generate_cvt_mode(hdisplay: i32, vdisplay: i32, vrefresh: Option<f64>) -> drm
         vtotal: vsync_end + timing.v_back_porch as u16,
         vscan: 0,
         vrefresh: f64::round(timing.act_frame_rate) as u32,
-        flags: DRM_MODE_FLAG_NHSYNC | DRM_MODE_FLAG_PVSYNC,
+        // Reduced blanking timings use the opposite sync polarities
+        flags: match reduced_blanking {
+            true => DRM_MODE_FLAG_PHSYNC | DRM_MODE_FLAG_NVSYNC,
+            false => DRM_MODE_FLAG_NHSYNC | DRM_MODE_FLAG_PVSYNC,
+        },
         type_: DRM_MODE_TYPE_USERDEF,
         name,
     }
@@ -28,7 +28,7 @@ use smithay::{
             window::{Icon, WindowAttributes},
         },
     },
-    utils::{Rectangle, Transform},
+    utils::{Clock, Monotonic, Rectangle, Transform},
     wayland::{dmabuf, presentation::Refresh},
 };
 use tracing::{debug, error, info, trace, warn};
@@ -42,7 +42,7 @@ use crate::{
     state::{Pinnacle, State, WithState},
 };
 
-use super::{Backend, BackendData, UninitBackend};
+use super::{Backend, BackendData, RenderDiagnostics, RenderResult, UninitBackend};
 
 const LOGO_BYTES: &[u8] = include_bytes!("../../resources/pinnacle_logo_icon.rgba");
 
@@ -51,6 +51,10 @@ pub struct Winit {
     pub damage_tracker: OutputDamageTracker,
     pub full_redraw: u8,
     output: Output,
+    /// Whether a redraw was requested and hasn't happened yet.
+    render_scheduled: bool,
+    /// When the last frame was presented.
+    last_presentation_time: Option<Duration>,
 }
 
 impl BackendData for Winit {
@@ -67,6 +71,9 @@ impl BackendData for Winit {
     fn set_output_mode(&mut self, output: &Output, mode: OutputMode) {
         output.change_current_state(Some(mode.into()), None, None, None);
     }
+    fn with_gles_renderer<T>(&mut self, f: impl FnOnce(&mut GlesRenderer) -> T) -> Option<T> {
+        Some(f(self.backend.renderer()))
+    }
 }
 
 impl Backend {
@@ -127,6 +134,8 @@ impl Winit {
             backend: winit_backend,
             damage_tracker: OutputDamageTracker::from_output(&output),
             full_redraw: 0,
+            render_scheduled: false,
+            last_presentation_time: None,
             output,
         };
 
@@ -143,14 +152,14 @@ impl Winit {
                 Ok(Some(render_node)) => {
                     match pinnacle.init_hardware_accel(render_node, dmabuf_formats) {
                         Ok(_) => info!("EGL hardware acceleration enabled"),
-                        Err(err) => warn!("Failed to bind EGL display: {err}"),
+                        Err(err) => pinnacle.hardware_accel_failed(&err),
                     }
                 }
                 Ok(None) => {
-                    warn!("Failed to bind EGL display: failed to query render node");
+                    pinnacle.hardware_accel_failed(&anyhow::anyhow!("failed to query render node"));
                 }
                 Err(err) => {
-                    warn!("Failed to bind EGL display: {err}");
+                    pinnacle.hardware_accel_failed(&err.into());
                 }
             };
 
@@ -218,12 +227,25 @@ impl Winit {
     /// Schedule a render on the winit window.
     pub fn schedule_render(&mut self) {
         let _span = tracy_client::span!("Winit::schedule_render");
+        self.render_scheduled = true;
         self.backend.window().request_redraw();
     }
 
+    pub(super) fn render_diagnostics(&self, clock: &Clock<Monotonic>) -> RenderDiagnostics {
+        RenderDiagnostics {
+            time_since_last_presentation: self
+                .last_presentation_time
+                .and_then(|past| Duration::from(clock.now()).checked_sub(past)),
+            render_scheduled: self.render_scheduled,
+            ..Default::default()
+        }
+    }
+
     fn render_winit_window(&mut self, pinnacle: &mut Pinnacle) {
         let _span = tracy_client::span!("Winit::render_winit_window");
 
+        self.render_scheduled = false;
+
         let full_redraw = &mut self.full_redraw;
         *full_redraw = full_redraw.saturating_sub(1);
 
@@ -259,14 +281,12 @@ impl Winit {
             );
         }
 
-        let should_blank = pinnacle.lock_state.is_locking()
-            || (pinnacle.lock_state.is_locked()
-                && self.output.with_state(|state| state.lock_surface.is_none()));
+        let should_blank = pinnacle.should_blank_output(&self.output);
 
         if should_blank {
             self.output.with_state_mut(|state| {
                 if let BlankingState::NotBlanked = state.blanking_state {
-                    debug!("Blanking output {} for session lock", self.output.name());
+                    debug!("Blanking output {}", self.output.name());
                     state.blanking_state = BlankingState::Blanking;
                 }
             });
@@ -284,14 +304,27 @@ impl Winit {
                 output_render_elements.extend(elems);
             }
         } else {
+            output_render_elements.extend(
+                crate::render::idle_dim_render_element(pinnacle, &self.output)
+                    .map(OutputRenderElement::from),
+            );
             output_render_elements.extend(crate::render::output_render_elements(
                 &self.output,
                 self.backend.renderer(),
                 &pinnacle.space,
                 &pinnacle.z_index_stack,
+                pinnacle.overview.as_ref(),
+                pinnacle.occluding_window(&self.output).as_ref(),
             ));
         }
 
+        if pinnacle.config.debug.opaque_region_culling {
+            crate::render::util::cull_opaque_occluded_elements(
+                &mut output_render_elements,
+                smithay::utils::Scale::from(self.output.current_scale().fractional_scale()),
+            );
+        }
+
         if pinnacle.config.debug.visualize_opaque_regions {
             crate::render::util::render_opaque_regions(
                 &mut output_render_elements,
@@ -322,13 +355,27 @@ impl Winit {
         // };
         let age = 0;
 
-        let render_res = self.backend.bind().and_then(|(renderer, mut framebuffer)| {
-            let clear_color = if pinnacle.lock_state.is_unlocked() {
-                CLEAR_COLOR
-            } else {
-                CLEAR_COLOR_LOCKED
-            };
+        let clear_color = if pinnacle.lock_state.is_unlocked() {
+            CLEAR_COLOR
+        } else {
+            CLEAR_COLOR_LOCKED
+        };
+
+        crate::render::supersample::apply_output_supersampling(
+            self.backend.renderer(),
+            &self.output,
+            &mut output_render_elements,
+            clear_color,
+        );
 
+        crate::render::color_filter::apply_output_color_filter(
+            self.backend.renderer(),
+            &self.output,
+            &mut output_render_elements,
+            clear_color,
+        );
+
+        let render_res = self.backend.bind().and_then(|(renderer, mut framebuffer)| {
             self.damage_tracker
                 .render_output(
                     renderer,
@@ -352,6 +399,13 @@ impl Winit {
                     .submit(render_output_result.damage.map(|damage| damage.as_slice()))
                 {
                     Ok(()) => {
+                        let result = if has_rendered {
+                            RenderResult::Submitted
+                        } else {
+                            RenderResult::NoDamage
+                        };
+                        pinnacle.set_render_result(&self.output, result);
+
                         if has_rendered {
                             self.output.with_state_mut(|state| {
                                 if matches!(state.blanking_state, BlankingState::Blanking) {
@@ -364,6 +418,7 @@ impl Winit {
                     }
                     Err(err) => {
                         error!("Failed to submit buffer: {:?}", err);
+                        pinnacle.set_render_result(&self.output, RenderResult::Skipped);
                     }
                 }
 
@@ -381,6 +436,8 @@ impl Winit {
                 pinnacle.update_primary_scanout_output(&self.output, &render_output_result.states);
 
                 if has_rendered {
+                    self.last_presentation_time = Some(now.into());
+
                     let mut output_presentation_feedback = take_presentation_feedback(
                         &self.output,
                         &pinnacle.space,
@@ -403,13 +460,16 @@ impl Winit {
             }
             Err(err) => {
                 warn!("{}", err);
+                pinnacle.set_render_result(&self.output, RenderResult::Skipped);
             }
         };
 
         pinnacle.send_frame_callbacks(&self.output, None);
 
         // At the end cuz borrow checker
-        if pinnacle.cursor_state.is_current_cursor_animated() {
+        if pinnacle.cursor_state.is_current_cursor_animated()
+            && pinnacle.request_animation_frame(&self.output)
+        {
             self.schedule_render();
         }
     }
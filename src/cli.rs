@@ -46,6 +46,10 @@ pub struct Cli {
     #[arg(long)]
     pub no_xwayland: bool,
 
+    /// Allow configs to inject synthetic keyboard input into clients
+    #[arg(long)]
+    pub allow_input_injection: bool,
+
     /// Open the gRPC socket at the specified directory
     #[arg(short, long, value_name("DIR"), value_hint(ValueHint::DirPath))]
     pub socket_dir: Option<PathBuf>,
@@ -5,9 +5,15 @@ use crate::{
         signal::SignalService, tag::TagService, window::WindowService,
     },
     cli::Cli,
-    output::OutputName,
+    cursor::CursorMode,
+    handlers::decoration::DecorationPolicy,
+    output::{LegacyScaleMode, OutputIdentity, OutputIdentityOverride, OutputName},
     state::Pinnacle,
     tag::Tag,
+    window::{
+        FrameCallbackPolicy, OffscreenPolicy, WindowElement, rules::GeometryRule,
+        window_state::VrrDemandMatcher,
+    },
 };
 use std::{
     collections::HashMap,
@@ -15,10 +21,12 @@ use std::{
     io::{self, Write},
     path::{Path, PathBuf},
     process::Stdio,
+    time::Duration,
 };
 
 use anyhow::Context;
 use indexmap::IndexSet;
+use itertools::Itertools;
 use pinnacle_api_defs::pinnacle::{
     debug::v1::debug_service_server::DebugServiceServer,
     input::v1::input_service_server::InputServiceServer,
@@ -31,9 +39,14 @@ use pinnacle_api_defs::pinnacle::{
     v1::pinnacle_service_server::PinnacleServiceServer,
     window::v1::window_service_server::WindowServiceServer,
 };
+use regex::Regex;
 use smithay::{
-    reexports::calloop::{self, LoopHandle, RegistrationToken, channel::Event},
-    utils::{Logical, Point},
+    reexports::calloop::{
+        self, LoopHandle, RegistrationToken,
+        channel::Event,
+        timer::{TimeoutAction, Timer},
+    },
+    utils::{IsAlive, Logical, Point, Size},
 };
 use tokio::{
     io::{AsyncBufReadExt, BufReader},
@@ -42,6 +55,7 @@ use tokio::{
 use toml::Table;
 
 use tracing::{Instrument, debug, debug_span, error, info, warn};
+use tracing_subscriber::{Layer, fmt::MakeWriter, registry::LookupSpan};
 use xdg::BaseDirectories;
 
 use crate::{
@@ -61,6 +75,8 @@ mod builtin {
 }
 
 const STARTUP_CONFIG_TOML_NAME: &str = "pinnacle.toml";
+/// How long a reloaded config has to add the tags saved window layouts are waiting for
+const SAVED_WINDOW_LAYOUT_TIMEOUT: Duration = Duration::from_secs(3);
 const LUA_STARTUP_CONFIG: &str = include_str!("../api/lua/examples/default/pinnacle.toml");
 const LUA_CONFIG: &str = include_str!("../api/lua/examples/default/default_config.lua");
 const LUA_RC_JSON: &str = include_str!("../api/lua/examples/default/.luarc.json");
@@ -136,6 +152,60 @@ pub struct StartupConfig {
     pub socket_dir: Option<PathBuf>,
     pub no_config: Option<bool>,
     pub no_xwayland: Option<bool>,
+    pub allow_input_injection: Option<bool>,
+    pub shortcut_inhibit: Option<ShortcutInhibitConfig>,
+    pub logging: Option<LoggingConfig>,
+    pub input: Option<InputStartupConfig>,
+}
+
+/// The `[shortcut_inhibit]` table of the startup config.
+#[derive(serde::Deserialize, Debug, PartialEq, Default, Clone)]
+pub struct ShortcutInhibitConfig {
+    /// App ids of windows that are allowed to inhibit compositor keybinds.
+    pub allow: Vec<String>,
+}
+
+/// The `[logging]` table of the startup config.
+#[derive(serde::Deserialize, Debug, PartialEq, Default, Clone)]
+pub struct LoggingConfig {
+    /// The format log files are written in.
+    pub format: Option<LogFormat>,
+}
+
+/// The `[input]` table of the startup config.
+#[derive(serde::Deserialize, Debug, PartialEq, Default, Clone)]
+pub struct InputStartupConfig {
+    /// Whether NumLock is turned on at startup.
+    pub numlock_on_start: Option<bool>,
+}
+
+/// The format log files are written in.
+#[derive(serde::Deserialize, Debug, PartialEq, Eq, Default, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Compact, human-readable lines.
+    #[default]
+    Compact,
+    /// One JSON object per line.
+    Json,
+}
+
+impl LogFormat {
+    /// Builds a formatting layer that writes logs in this format to `writer`, without ansi colors.
+    pub fn file_log_layer<S, W>(self, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+    where
+        S: tracing::Subscriber + for<'a> LookupSpan<'a> + 'static,
+        W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+    {
+        let layer = tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(writer);
+
+        match self {
+            LogFormat::Compact => layer.compact().boxed(),
+            LogFormat::Json => layer.json().boxed(),
+        }
+    }
 }
 
 /// A startup config with fields resolved.
@@ -152,6 +222,11 @@ pub struct ResolvedStartupConfig {
     pub socket_dir: PathBuf,
     pub no_config: bool,
     pub no_xwayland: bool,
+    pub allow_input_injection: bool,
+    /// App ids allowed to inhibit keyboard shortcuts, or `None` to allow every app.
+    pub shortcut_inhibit_allowlist: Option<Vec<String>>,
+    pub log_format: LogFormat,
+    pub numlock_on_start: bool,
 }
 
 impl StartupConfig {
@@ -161,27 +236,23 @@ impl StartupConfig {
         cli: Option<&crate::cli::Cli>,
         config_dir: &Path,
     ) -> anyhow::Result<ResolvedStartupConfig> {
-        let socket_dir = if let Some(socket_dir) = cli
+        let configured_socket_dir = cli
             .and_then(|cli| cli.socket_dir.as_ref())
             .or(self.socket_dir.as_ref())
-        {
-            let socket_dir = shellexpand::path::full(socket_dir)?.to_path_buf();
+            .and_then(|socket_dir| match shellexpand::path::full(socket_dir) {
+                // Relative paths like ./dir/here are relative to the startup config dir
+                Ok(socket_dir) => Some(config_dir.join(socket_dir)),
+                Err(err) => {
+                    warn!(
+                        "Failed to expand socket dir {}: {err}",
+                        socket_dir.display()
+                    );
+                    None
+                }
+            });
 
-            // cd into the startup config dir and canonicalize to preserve relative paths
-            // like ./dir/here
-            let current_dir = std::env::current_dir()?;
-
-            std::env::set_current_dir(config_dir)?;
-            let socket_dir = socket_dir.canonicalize()?;
-            std::env::set_current_dir(current_dir)?;
-            socket_dir
-        } else {
-            // Otherwise, use $XDG_RUNTIME_DIR. If that doesn't exist, use /tmp.
-            BaseDirectories::with_prefix("pinnacle")
-                .get_runtime_directory()
-                .cloned()
-                .unwrap_or(PathBuf::from(DEFAULT_SOCKET_DIR))
-        };
+        let socket_dir = first_usable_socket_dir(socket_dir_fallbacks(configured_socket_dir))?;
+        let socket_dir = socket_dir.canonicalize().unwrap_or(socket_dir);
 
         Ok(ResolvedStartupConfig {
             run: self.run,
@@ -195,10 +266,68 @@ impl StartupConfig {
                 .and_then(|cli| cli.no_xwayland.then_some(true))
                 .or(self.no_xwayland)
                 .unwrap_or_default(),
+            allow_input_injection: cli
+                .and_then(|cli| cli.allow_input_injection.then_some(true))
+                .or(self.allow_input_injection)
+                .unwrap_or_default(),
+            shortcut_inhibit_allowlist: self.shortcut_inhibit.map(|config| config.allow),
+            log_format: self
+                .logging
+                .and_then(|config| config.format)
+                .unwrap_or_default(),
+            numlock_on_start: self
+                .input
+                .and_then(|config| config.numlock_on_start)
+                .unwrap_or_default(),
         })
     }
 }
 
+/// Returns the directories to try placing the gRPC socket in, in order of preference.
+///
+/// This is `preferred`, then `$XDG_RUNTIME_DIR`, then `/tmp`.
+fn socket_dir_fallbacks(preferred: Option<PathBuf>) -> Vec<PathBuf> {
+    let runtime_dir = BaseDirectories::with_prefix("pinnacle")
+        .get_runtime_directory()
+        .cloned();
+
+    preferred
+        .into_iter()
+        .chain(runtime_dir)
+        .chain([PathBuf::from(DEFAULT_SOCKET_DIR)])
+        .unique()
+        .collect()
+}
+
+/// Returns the first of `dirs` that can be created and written to.
+fn first_usable_socket_dir(dirs: Vec<PathBuf>) -> anyhow::Result<PathBuf> {
+    let mut errors = Vec::new();
+
+    for dir in dirs {
+        match ensure_writable_dir(&dir) {
+            Ok(()) => {
+                info!("Using socket dir {}", dir.display());
+                return Ok(dir);
+            }
+            Err(err) => {
+                warn!("Socket dir {} is unusable: {err}", dir.display());
+                errors.push(format!("{}: {err}", dir.display()));
+            }
+        }
+    }
+
+    anyhow::bail!("no usable socket dir found ({})", errors.join(", "))
+}
+
+/// Creates `dir` if needed and checks that files can be created inside it.
+fn ensure_writable_dir(dir: &Path) -> io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+
+    let probe = dir.join(format!(".pinnacle-probe-{}", std::process::id()));
+    File::create(&probe)?;
+    std::fs::remove_file(&probe)
+}
+
 #[cfg(feature = "testing")]
 impl ResolvedStartupConfig {
     pub fn new_for_testing(no_config: bool, no_xwayland: bool) -> Self {
@@ -208,6 +337,10 @@ impl ResolvedStartupConfig {
             socket_dir: PathBuf::from(""),
             no_config,
             no_xwayland,
+            allow_input_injection: false,
+            shortcut_inhibit_allowlist: None,
+            log_format: LogFormat::default(),
+            numlock_on_start: false,
         }
     }
 }
@@ -220,6 +353,8 @@ pub struct Config {
 
     pub config_join_handle: Option<JoinHandle<()>>,
     pub(crate) config_reload_on_crash_token: Option<RegistrationToken>,
+    /// The timer that gives up on saved window layouts the new config never restores
+    pub(crate) saved_window_layouts_token: Option<RegistrationToken>,
 
     pub keepalive_sender: Option<tokio::sync::oneshot::Sender<()>>,
 
@@ -232,6 +367,75 @@ pub struct Config {
     pub last_error: Option<String>,
 
     pub process_envs: HashMap<String, String>,
+
+    /// Gives windows without an explicit vrr demand one on on-demand vrr outputs
+    pub vrr_demand_matcher: Option<VrrDemandMatcher>,
+
+    /// Whether the config is allowed to inject synthetic keyboard input
+    pub allow_input_injection: bool,
+
+    /// App ids allowed to inhibit keyboard shortcuts, or `None` to allow every app
+    pub shortcut_inhibit_allowlist: Option<Vec<String>>,
+
+    /// Whether minimized windows are unminimized when one of their tags becomes active
+    pub minimize_restore_on_tag: bool,
+
+    /// The opacity of windows without keyboard focus
+    pub inactive_window_opacity: f32,
+
+    /// Whether newly connected outputs without saved state get a scale guessed from their dpi
+    pub auto_scale: bool,
+
+    /// How the integer scale advertised to clients without fractional scaling is chosen
+    pub legacy_scale_mode: LegacyScaleMode,
+
+    /// Whether a new tag is created for windows that open while no tag is active
+    pub dynamic_tags: bool,
+
+    /// Whether outputs crossfade from their previous contents after a mode or scale change
+    pub transition_animation: bool,
+
+    /// The most frames per second compositor-driven animations get.
+    ///
+    /// `None` leaves animations uncapped and `Some(0)` disables them.
+    pub animation_fps_cap: Option<u32>,
+
+    /// Whether window tags and floating geometries are restored after a config reload
+    pub preserve_layout_on_reload: bool,
+
+    /// Window arrangements saved before the last config reload that are waiting
+    /// for all of their tags to be added again
+    pub saved_window_layouts: Vec<SavedWindowLayout>,
+
+    /// How decoration modes are negotiated with clients
+    pub decoration_policy: DecorationPolicy,
+
+    /// App ids of windows that always open floating
+    pub floating_app_ids: Vec<String>,
+    /// Title regexes of windows that always open floating
+    pub floating_titles: Vec<Regex>,
+    /// Initial geometries for matching floating windows, first match wins
+    pub geometry_rules: Vec<GeometryRule>,
+    /// Whether windows fully covered by an opaque fullscreen window are skipped when rendering
+    pub occlusion_culling: bool,
+    /// Whether floating windows can be moved outside the work area of their output
+    pub offscreen_policy: OffscreenPolicy,
+    /// The most frame callbacks per second occluded windows get
+    pub background_fps_cap: Option<u32>,
+    /// How windows that aren't displayed get frame callbacks
+    pub frame_callback_policy: FrameCallbackPolicy,
+    /// Identity overrides for misreporting displays, keyed by connector
+    pub output_identity_overrides: HashMap<OutputName, OutputIdentityOverride>,
+    /// Whether middle clicking pastes the primary selection
+    pub middle_click_paste: bool,
+    /// Whether the cursor may be scanned out on a cursor plane
+    pub cursor_mode: CursorMode,
+    /// Whether layer surfaces' exclusive zones shrink the area windows are laid out in
+    pub respect_exclusive_zones: bool,
+    /// Namespaces of layer surfaces whose exclusive zones are ignored
+    pub exclusive_zone_ignored_namespaces: Vec<String>,
+    /// Whether outputs are moved to stop them from overlapping after one changes
+    pub auto_resolve_output_overlaps: bool,
 }
 
 #[derive(Debug, Default)]
@@ -239,7 +443,16 @@ pub struct Debug {
     pub visualize_damage: bool,
     pub visualize_opaque_regions: bool,
     pub disable_cursor_plane_scanout: bool,
+    /// Composite everything instead of scanning out elements on planes directly.
+    ///
+    /// Only affects the udev backend.
+    pub disable_direct_scanout: bool,
     pub disable_process_piping: bool,
+    /// Drop elements hidden behind opaque regions before rendering.
+    ///
+    /// The damage tracker already skips drawing behind opaque regions, so this is off
+    /// unless turned on.
+    pub opaque_region_culling: bool,
 }
 
 impl Drop for Config {
@@ -256,6 +469,7 @@ impl Config {
             connector_saved_states: HashMap::new(),
             config_join_handle: None,
             config_reload_on_crash_token: None,
+            saved_window_layouts_token: None,
             keepalive_sender: None,
             config_dir,
             cli,
@@ -263,6 +477,32 @@ impl Config {
             debug: Default::default(),
             last_error: None,
             process_envs: Default::default(),
+            vrr_demand_matcher: None,
+            allow_input_injection: false,
+            shortcut_inhibit_allowlist: None,
+            minimize_restore_on_tag: false,
+            inactive_window_opacity: 1.0,
+            auto_scale: false,
+            legacy_scale_mode: LegacyScaleMode::default(),
+            dynamic_tags: false,
+            transition_animation: false,
+            animation_fps_cap: None,
+            preserve_layout_on_reload: false,
+            saved_window_layouts: Vec::new(),
+            decoration_policy: DecorationPolicy::default(),
+            floating_app_ids: Vec::new(),
+            floating_titles: Vec::new(),
+            geometry_rules: Vec::new(),
+            occlusion_culling: false,
+            offscreen_policy: OffscreenPolicy::default(),
+            background_fps_cap: None,
+            frame_callback_policy: FrameCallbackPolicy::default(),
+            output_identity_overrides: HashMap::new(),
+            middle_click_paste: true,
+            cursor_mode: CursorMode::default(),
+            respect_exclusive_zones: true,
+            exclusive_zone_ignored_namespaces: Vec::new(),
+            auto_resolve_output_overlaps: false,
         }
     }
 
@@ -279,15 +519,60 @@ impl Config {
         if let Some(token) = self.config_reload_on_crash_token.take() {
             loop_handle.remove(token);
         }
+        if let Some(token) = self.saved_window_layouts_token.take() {
+            loop_handle.remove(token);
+        }
 
         std::mem::take(&mut self.debug);
 
         self.process_envs.clear();
+
+        self.vrr_demand_matcher = None;
+        self.allow_input_injection = false;
+        self.shortcut_inhibit_allowlist = None;
+        self.minimize_restore_on_tag = false;
+        self.inactive_window_opacity = 1.0;
+        self.auto_scale = false;
+        self.legacy_scale_mode = LegacyScaleMode::default();
+        self.dynamic_tags = false;
+        self.transition_animation = false;
+        self.animation_fps_cap = None;
+        self.preserve_layout_on_reload = false;
+        self.saved_window_layouts.clear();
+        self.decoration_policy = DecorationPolicy::default();
+        self.floating_app_ids.clear();
+        self.floating_titles.clear();
+        self.geometry_rules.clear();
+        self.occlusion_culling = false;
+        self.offscreen_policy = OffscreenPolicy::default();
+        self.background_fps_cap = None;
+        self.frame_callback_policy = FrameCallbackPolicy::default();
+        self.output_identity_overrides.clear();
+        self.middle_click_paste = true;
+        self.cursor_mode = CursorMode::default();
+        self.respect_exclusive_zones = true;
+        self.exclusive_zone_ignored_namespaces.clear();
+        self.auto_resolve_output_overlaps = false;
+    }
+
+    /// Returns whether `window` has an app id or title that was set to always open floating.
+    pub fn opens_floating(&self, window: &WindowElement) -> bool {
+        if let Some(app_id) = window.class()
+            && self.floating_app_ids.contains(&app_id)
+        {
+            return true;
+        }
+
+        window.title().is_some_and(|title| {
+            self.floating_titles
+                .iter()
+                .any(|regex| regex.is_match(&title))
+        })
     }
 }
 
-/// State saved when an output is disconnected. When the output is reconnected to the same
-/// connector, the saved state will apply to restore its state.
+/// State saved when an output is disconnected. When the same display or, failing that,
+/// a display on the same connector is connected, the saved state will apply to restore its state.
 #[derive(Debug, Default, Clone)]
 pub struct ConnectorSavedState {
     /// The old location
@@ -296,9 +581,28 @@ pub struct ConnectorSavedState {
     pub tags: IndexSet<Tag>,
     /// The output's previous scale
     pub scale: Option<smithay::output::Scale>,
+    /// The identity of the display that was connected
+    pub identity: Option<OutputIdentity>,
     // TODO: transform
 }
 
+/// A window's arrangement saved before a config reload.
+///
+/// Tags are recreated by the new config, so they are saved by name.
+#[derive(Debug, Clone)]
+pub struct SavedWindowLayout {
+    /// The saved window. If it closed during the reload, another window
+    /// with the same app id and title takes its place.
+    pub window: WindowElement,
+    pub app_id: Option<String>,
+    pub title: Option<String>,
+    /// The output the window's tags were on
+    pub output_name: OutputName,
+    pub tag_names: Vec<String>,
+    /// The floating location and size, if the window was floating
+    pub floating: Option<(Option<Point<i32, Logical>>, Size<i32, Logical>)>,
+}
+
 /// Parse a `pinnacle.toml` file in `config_dir`, if any.
 pub fn parse_startup_config(config_dir: &Path) -> anyhow::Result<StartupConfig> {
     let startup_config_path = config_dir.join(STARTUP_CONFIG_TOML_NAME);
@@ -327,6 +631,13 @@ pub fn get_config_dir(xdg_base_dirs: &BaseDirectories) -> PathBuf {
 
 impl Pinnacle {
     pub fn start_config(&mut self, builtin: bool) -> anyhow::Result<()> {
+        let saved_window_layouts = match self.config.preserve_layout_on_reload {
+            true => self.save_window_layouts(),
+            false => Vec::new(),
+        };
+
+        self.metrics.record_config_start();
+
         // Clear state
 
         debug!("Clearing tags");
@@ -346,6 +657,39 @@ impl Pinnacle {
 
         self.config.clear(&self.loop_handle);
 
+        if !saved_window_layouts.is_empty() {
+            self.config.saved_window_layouts = saved_window_layouts;
+
+            let token = self
+                .loop_handle
+                .insert_source(
+                    Timer::from_duration(SAVED_WINDOW_LAYOUT_TIMEOUT),
+                    |_, _, state| {
+                        state.pinnacle.config.saved_window_layouts_token = None;
+                        state.pinnacle.finish_restoring_saved_window_layouts();
+                        TimeoutAction::Drop
+                    },
+                )
+                .map_err(|err| {
+                    anyhow::anyhow!("failed to insert saved window layout timer: {err}")
+                })?;
+            self.config.saved_window_layouts_token = Some(token);
+        }
+
+        self.set_idle_dim(None);
+
+        for output in self.outputs.iter() {
+            output.with_state_mut(|state| state.dpms_timeout = None);
+        }
+        self.set_dpms_timeout(None);
+
+        for win in self.windows.iter() {
+            win.with_state_mut(|state| state.app_id_override = None);
+        }
+
+        self.set_cursor_visible(true);
+        self.set_cursor_autohide(None);
+
         self.signal_state.clear();
 
         #[cfg(feature = "snowcap")]
@@ -392,6 +736,9 @@ impl Pinnacle {
         let startup_config =
             startup_config.merge_and_resolve(self.config.cli.as_ref(), &self.config.config_dir)?;
 
+        self.config.allow_input_injection = startup_config.allow_input_injection;
+        self.config.shortcut_inhibit_allowlist = startup_config.shortcut_inhibit_allowlist;
+
         if startup_config.no_config {
             info!("`no-config` option was set, not spawning config");
             return Ok(());
@@ -528,7 +875,8 @@ impl Pinnacle {
     }
 
     pub fn start_grpc_server(&mut self, socket_dir: &Path) -> anyhow::Result<()> {
-        std::fs::create_dir_all(socket_dir)?;
+        // The dir may have become unusable since the startup config was resolved
+        let socket_dir = &first_usable_socket_dir(socket_dir_fallbacks(Some(socket_dir.into())))?;
 
         let socket_name = format!("pinnacle-grpc-{}.sock", std::process::id());
 
@@ -597,6 +945,126 @@ impl Pinnacle {
 
         Ok(())
     }
+
+    /// Saves the tags and floating geometry of every window with tags on an output.
+    fn save_window_layouts(&self) -> Vec<SavedWindowLayout> {
+        self.windows
+            .iter()
+            .filter_map(|window| {
+                let output = window.output(self)?;
+                let (tag_names, floating) = window.with_state(|state| {
+                    let tag_names = state.tags.iter().map(|tag| tag.name()).collect();
+                    let floating = state
+                        .layout_mode
+                        .is_floating()
+                        .then(|| (state.floating_loc(), state.floating_size));
+                    (tag_names, floating)
+                });
+
+                Some(SavedWindowLayout {
+                    window: window.clone(),
+                    app_id: window.class(),
+                    title: window.title(),
+                    output_name: OutputName(output.name()),
+                    tag_names,
+                    floating,
+                })
+            })
+            .collect()
+    }
+
+    /// Reapplies window arrangements saved before a config reload once tags with
+    /// all of the saved names exist again.
+    ///
+    /// Windows that closed during the reload are replaced by an unsaved window with the
+    /// same app id and title. If there isn't one, the saved arrangement is dropped.
+    pub fn restore_saved_window_layouts(&mut self) {
+        self.restore_saved_window_layouts_inner(false);
+    }
+
+    /// Reapplies the remaining saved window arrangements with whichever of their
+    /// tags exist, then drops them.
+    ///
+    /// This is called once the config has had time to add its tags.
+    pub fn finish_restoring_saved_window_layouts(&mut self) {
+        if let Some(token) = self.config.saved_window_layouts_token.take() {
+            self.loop_handle.remove(token);
+        }
+
+        self.restore_saved_window_layouts_inner(true);
+        self.config.saved_window_layouts.clear();
+    }
+
+    fn restore_saved_window_layouts_inner(&mut self, allow_missing_tags: bool) {
+        let saved_window_layouts = std::mem::take(&mut self.config.saved_window_layouts);
+        let saved_windows = saved_window_layouts
+            .iter()
+            .map(|saved| saved.window.clone())
+            .collect::<Vec<_>>();
+        let mut replacements = Vec::new();
+
+        for saved in saved_window_layouts {
+            let window = match saved.window.alive() {
+                true => Some(saved.window.clone()),
+                false => self
+                    .windows
+                    .iter()
+                    .find(|win| {
+                        !saved_windows.contains(*win)
+                            && !replacements.contains(*win)
+                            && win.class() == saved.app_id
+                            && win.title() == saved.title
+                    })
+                    .cloned(),
+            };
+
+            let Some(window) = window else {
+                continue;
+            };
+
+            let Some(output) = saved.output_name.output(self) else {
+                continue;
+            };
+
+            let tags = output.with_state(|state| {
+                state
+                    .tags
+                    .iter()
+                    .filter(|tag| !tag.defunct() && saved.tag_names.contains(&tag.name()))
+                    .cloned()
+                    .collect::<IndexSet<_>>()
+            });
+
+            let has_all_tags = saved
+                .tag_names
+                .iter()
+                .all(|name| tags.iter().any(|tag| tag.name() == *name));
+
+            if tags.is_empty() || !(has_all_tags || allow_missing_tags) {
+                self.config.saved_window_layouts.push(saved);
+                continue;
+            }
+
+            window.with_state_mut(|state| {
+                state.tags = tags;
+                if let Some((loc, size)) = saved.floating {
+                    state.set_floating_loc(loc);
+                    state.floating_size = size;
+                }
+            });
+            self.window_tags_changed = true;
+
+            if window != saved.window {
+                replacements.push(window.clone());
+            }
+
+            let layout_mode = window.with_state(|state| state.layout_mode);
+            self.update_window_geometry(&window, layout_mode.is_tiled());
+            self.request_layout(&output);
+        }
+
+        self.update_xwayland_stacking_order();
+    }
 }
 
 #[cfg(test)]
@@ -693,10 +1161,20 @@ mod tests {
 
             no_config = true
             no_xwayland = true
+            allow_input_injection = true
 
             [envs]
             MARCO = "polo"
             SUN = "chips"
+
+            [shortcut_inhibit]
+            allow = ["virt-manager", "org.remmina.Remmina"]
+
+            [logging]
+            format = "json"
+
+            [input]
+            numlock_on_start = true
         "#;
 
         let config_dir = tempfile::tempdir()?;
@@ -714,6 +1192,19 @@ mod tests {
             socket_dir: Some("/path/to/socket/dir".into()),
             no_config: Some(true),
             no_xwayland: Some(true),
+            allow_input_injection: Some(true),
+            shortcut_inhibit: Some(ShortcutInhibitConfig {
+                allow: vec![
+                    "virt-manager".to_string(),
+                    "org.remmina.Remmina".to_string(),
+                ],
+            }),
+            logging: Some(LoggingConfig {
+                format: Some(LogFormat::Json),
+            }),
+            input: Some(InputStartupConfig {
+                numlock_on_start: Some(true),
+            }),
         };
 
         assert_eq!(
@@ -742,6 +1233,10 @@ mod tests {
             socket_dir: None,
             no_config: None,
             no_xwayland: None,
+            allow_input_injection: None,
+            shortcut_inhibit: None,
+            logging: None,
+            input: None,
         };
 
         assert_eq!(
@@ -767,4 +1262,196 @@ mod tests {
     }
 
     // TODO: test for error if `run` isn't present
+
+    #[test]
+    fn unwritable_socket_dir_falls_back_to_runtime_dir() -> anyhow::Result<()> {
+        let config_dir = tempfile::tempdir()?;
+        let runtime_dir = tempfile::tempdir()?;
+
+        // A dir can't be created under a regular file, even as root
+        let file = config_dir.path().join("file");
+        std::fs::write(&file, "")?;
+
+        let startup_config = StartupConfig {
+            socket_dir: Some(file.join("socket_dir")),
+            ..Default::default()
+        };
+
+        temp_env::with_var("XDG_RUNTIME_DIR", Some(runtime_dir.path()), || {
+            let resolved = startup_config.merge_and_resolve(None, config_dir.path())?;
+            assert_eq!(resolved.socket_dir, runtime_dir.path().canonicalize()?);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn unwritable_socket_dir_falls_back_to_tmp_without_runtime_dir() -> anyhow::Result<()> {
+        let config_dir = tempfile::tempdir()?;
+
+        let file = config_dir.path().join("file");
+        std::fs::write(&file, "")?;
+
+        let startup_config = StartupConfig {
+            socket_dir: Some(file.join("socket_dir")),
+            ..Default::default()
+        };
+
+        temp_env::with_var_unset("XDG_RUNTIME_DIR", || {
+            let resolved = startup_config.merge_and_resolve(None, config_dir.path())?;
+            assert_eq!(
+                resolved.socket_dir,
+                PathBuf::from(DEFAULT_SOCKET_DIR).canonicalize()?
+            );
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn relative_socket_dir_is_created_relative_to_config_dir() -> anyhow::Result<()> {
+        let config_dir = tempfile::tempdir()?;
+
+        let startup_config = StartupConfig {
+            socket_dir: Some("./sockets".into()),
+            ..Default::default()
+        };
+
+        let resolved = startup_config.merge_and_resolve(None, config_dir.path())?;
+        assert_eq!(
+            resolved.socket_dir,
+            config_dir.path().join("sockets").canonicalize()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn shortcut_inhibit_allowlist_is_resolved() -> anyhow::Result<()> {
+        let config_dir = tempfile::tempdir()?;
+
+        let resolved = StartupConfig::default().merge_and_resolve(None, config_dir.path())?;
+        assert_eq!(resolved.shortcut_inhibit_allowlist, None);
+
+        let startup_config = StartupConfig {
+            shortcut_inhibit: Some(ShortcutInhibitConfig {
+                allow: vec!["virt-manager".to_string()],
+            }),
+            ..Default::default()
+        };
+
+        let resolved = startup_config.merge_and_resolve(None, config_dir.path())?;
+        assert_eq!(
+            resolved.shortcut_inhibit_allowlist,
+            Some(vec!["virt-manager".to_string()])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn log_format_is_resolved() -> anyhow::Result<()> {
+        let config_dir = tempfile::tempdir()?;
+
+        let resolved = StartupConfig::default().merge_and_resolve(None, config_dir.path())?;
+        assert_eq!(resolved.log_format, LogFormat::Compact);
+
+        let startup_config = StartupConfig {
+            logging: Some(LoggingConfig {
+                format: Some(LogFormat::Json),
+            }),
+            ..Default::default()
+        };
+
+        let resolved = startup_config.merge_and_resolve(None, config_dir.path())?;
+        assert_eq!(resolved.log_format, LogFormat::Json);
+
+        Ok(())
+    }
+
+    #[test]
+    fn numlock_on_start_is_resolved() -> anyhow::Result<()> {
+        let config_dir = tempfile::tempdir()?;
+
+        let resolved = StartupConfig::default().merge_and_resolve(None, config_dir.path())?;
+        assert!(!resolved.numlock_on_start);
+
+        let startup_config = StartupConfig {
+            input: Some(InputStartupConfig {
+                numlock_on_start: Some(true),
+            }),
+            ..Default::default()
+        };
+
+        let resolved = startup_config.merge_and_resolve(None, config_dir.path())?;
+        assert!(resolved.numlock_on_start);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_log_format_does_not_parse() -> anyhow::Result<()> {
+        let startup_config_text = r#"
+            run = ["lua", "init.lua"]
+
+            [logging]
+            format = "pretty"
+        "#;
+
+        let config_dir = tempfile::tempdir()?;
+        std::fs::write(
+            config_dir.path().join(STARTUP_CONFIG_TOML_NAME),
+            startup_config_text,
+        )?;
+
+        assert!(parse_startup_config(config_dir.path()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_file_log_layer_writes_json() {
+        use std::sync::{Arc, Mutex};
+        use tracing_subscriber::layer::SubscriberExt;
+
+        #[derive(Clone, Default)]
+        struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for Buffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+
+        let subscriber = tracing_subscriber::registry()
+            .with(LogFormat::Json.file_log_layer(move || writer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            info!(answer = 42, "hello");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let line = output.lines().next().unwrap();
+
+        assert!(line.starts_with('{') && line.ends_with('}'));
+        assert!(line.contains(r#""message":"hello""#));
+        assert!(line.contains(r#""answer":42"#));
+    }
+
+    #[test]
+    fn no_usable_socket_dir_errors() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let file = dir.path().join("file");
+        std::fs::write(&file, "")?;
+
+        assert!(first_usable_socket_dir(vec![file.join("one"), file.join("two")]).is_err());
+
+        Ok(())
+    }
 }
@@ -1,10 +1,14 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::{collections::HashMap, rc::Rc};
 
 use anyhow::Context;
 use smithay::backend::allocator::Fourcc;
+use smithay::reexports::calloop::{
+    RegistrationToken,
+    timer::{TimeoutAction, Timer},
+};
 use smithay::utils::IsAlive;
 use smithay::{
     backend::renderer::element::memory::MemoryRenderBuffer,
@@ -17,16 +21,43 @@ use xcursor::{
 };
 
 use crate::render::pointer::PointerElement;
+use crate::state::Pinnacle;
 
 static FALLBACK_CURSOR_DATA: &[u8] = include_bytes!("../resources/cursor.rgba");
 
+/// How the cursor is put on screen.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CursorMode {
+    /// Scan the cursor out on a hardware cursor plane when possible.
+    #[default]
+    Plane,
+    /// Always composite the cursor into the frame.
+    ///
+    /// Some drivers have buggy cursor planes that flicker. The winit backend
+    /// always does this.
+    Software,
+}
+
 pub struct CursorState {
     current_cursor_image: CursorImageStatus,
     theme: CursorTheme,
     size: u32,
+    /// The scale of the output the pointer is on.
+    ///
+    /// Named cursors are loaded at their size times this scale so they stay sharp
+    /// on that output and look the same size on every output.
+    output_scale: f64,
     mem_buffer_cache: Vec<(Image, MemoryRenderBuffer)>,
     /// A map of cursor icons to loaded images
     loaded_images: HashMap<CursorIcon, Option<Rc<XCursor>>>,
+    /// Whether the config wants the cursor drawn.
+    visible: bool,
+    /// How long the pointer has to go without moving before the cursor is hidden.
+    autohide_after: Option<Duration>,
+    /// Whether the cursor is hidden because the pointer hasn't moved in a while.
+    autohidden: bool,
+    last_motion: Instant,
+    autohide_timer: Option<RegistrationToken>,
 }
 
 impl CursorState {
@@ -43,11 +74,24 @@ impl CursorState {
             current_cursor_image: CursorImageStatus::default_named(),
             theme: CursorTheme::load(&theme),
             size,
+            output_scale: 1.0,
             mem_buffer_cache: Default::default(),
             loaded_images: Default::default(),
+            visible: true,
+            autohide_after: None,
+            autohidden: false,
+            last_motion: Instant::now(),
+            autohide_timer: None,
         }
     }
 
+    /// Returns whether the cursor is drawn.
+    ///
+    /// This is false if the config hid the cursor or it was auto-hidden.
+    pub fn is_visible(&self) -> bool {
+        self.visible && !self.autohidden
+    }
+
     pub fn set_theme(&mut self, theme: &str) {
         // SAFETY: All set_vars occur on the event loop thread
         unsafe {
@@ -74,6 +118,16 @@ impl CursorState {
         self.size * scale as u32
     }
 
+    /// Sets the scale of the output the pointer is on.
+    pub fn set_output_scale(&mut self, scale: f64) {
+        self.output_scale = scale;
+    }
+
+    /// Returns the size named cursors are loaded at for the output the pointer is on.
+    pub fn scaled_size(&self) -> u32 {
+        (self.size as f64 * self.output_scale).round() as u32
+    }
+
     pub fn set_cursor_image(&mut self, image: CursorImageStatus) {
         self.current_cursor_image = image;
     }
@@ -97,21 +151,23 @@ impl CursorState {
             .clone()
     }
 
-    pub fn buffer_for_image(&mut self, image: Image, scale: i32) -> MemoryRenderBuffer {
+    /// Returns a buffer with the given image's pixels.
+    ///
+    /// The buffer has a scale of 1; renderers size it for the scale it was loaded at.
+    pub fn buffer_for_image(&mut self, image: Image) -> MemoryRenderBuffer {
         let _span = tracy_client::span!("CursorState::buffer_for_image");
 
         self.mem_buffer_cache
             .iter()
             .find_map(|(img, buf)| (*img == image).then(|| buf.clone()))
             .unwrap_or_else(|| {
-                // TODO: scale
                 let buffer = MemoryRenderBuffer::from_slice(
                     &image.pixels_rgba,
                     // Don't make Abgr, then the format doesn't match the
                     // cursor bo and this doesn't get put on the cursor plane
                     Fourcc::Argb8888,
                     (image.width as i32, image.height as i32),
-                    scale,
+                    1,
                     Transform::Normal,
                     None,
                 );
@@ -125,6 +181,10 @@ impl CursorState {
     pub fn pointer_element(&mut self) -> PointerElement {
         let _span = tracy_client::span!("CursorState::pointer_element");
 
+        if !self.is_visible() {
+            return PointerElement::Hidden;
+        }
+
         match &self.current_cursor_image {
             CursorImageStatus::Hidden => PointerElement::Hidden,
             CursorImageStatus::Named(icon) => {
@@ -134,7 +194,8 @@ impl CursorState {
                     .unwrap();
                 PointerElement::Named {
                     cursor,
-                    size: self.size,
+                    size: self.scaled_size(),
+                    scale: self.output_scale,
                 }
             }
             CursorImageStatus::Surface(surface) => PointerElement::Surface {
@@ -146,6 +207,10 @@ impl CursorState {
     pub fn is_current_cursor_animated(&mut self) -> bool {
         let _span = tracy_client::span!("CursorState::is_current_cursor_animated");
 
+        if !self.is_visible() {
+            return false;
+        }
+
         match &self.current_cursor_image {
             CursorImageStatus::Hidden => false,
             CursorImageStatus::Named(icon) => {
@@ -154,7 +219,7 @@ impl CursorState {
                     .or_else(|| self.get_xcursor_images(CursorIcon::Default))
                     .unwrap();
 
-                let img_count = nearest_size_images(self.size, &cursor.images).count();
+                let img_count = nearest_size_images(self.scaled_size(), &cursor.images).count();
 
                 let is_animated = img_count > 1;
                 is_animated
@@ -228,6 +293,90 @@ fn load_xcursor_theme_from_env() -> (String, u32) {
     (theme, size)
 }
 
+impl Pinnacle {
+    /// Sets whether the cursor is drawn.
+    ///
+    /// This only affects rendering; the pointer still moves and sends events.
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        if self.cursor_state.visible == visible {
+            return;
+        }
+
+        self.cursor_state.visible = visible;
+        self.render_cursor_visibility_change();
+    }
+
+    /// Sets how long the pointer has to go without moving before the cursor is hidden.
+    ///
+    /// Passing `None` disables auto-hiding and shows the cursor if it was auto-hidden.
+    pub fn set_cursor_autohide(&mut self, hide_after: Option<Duration>) {
+        self.cursor_state.autohide_after = hide_after;
+        self.cursor_state.last_motion = Instant::now();
+
+        if self.cursor_state.autohidden {
+            self.cursor_state.autohidden = false;
+            self.render_cursor_visibility_change();
+        }
+
+        self.arm_cursor_autohide_timer();
+    }
+
+    /// Records pointer motion, showing the cursor if it was auto-hidden.
+    pub fn notify_cursor_motion(&mut self) {
+        self.cursor_state.last_motion = Instant::now();
+
+        if self.cursor_state.autohidden {
+            self.cursor_state.autohidden = false;
+            self.render_cursor_visibility_change();
+            self.arm_cursor_autohide_timer();
+        }
+    }
+
+    fn render_cursor_visibility_change(&self) {
+        self.loop_handle.insert_idle(|state| {
+            for output in state.pinnacle.outputs.clone() {
+                state.schedule_render(&output);
+            }
+        });
+    }
+
+    fn arm_cursor_autohide_timer(&mut self) {
+        if let Some(token) = self.cursor_state.autohide_timer.take() {
+            self.loop_handle.remove(token);
+        }
+
+        let Some(hide_after) = self.cursor_state.autohide_after else {
+            return;
+        };
+
+        let token = self
+            .loop_handle
+            .insert_source(Timer::from_duration(hide_after), |_, _, state| {
+                let cursor_state = &mut state.pinnacle.cursor_state;
+
+                let Some(hide_after) = cursor_state.autohide_after else {
+                    cursor_state.autohide_timer = None;
+                    return TimeoutAction::Drop;
+                };
+
+                // Motion doesn't rearm the timer, so check if there was any since it was armed
+                let still_for = cursor_state.last_motion.elapsed();
+                if still_for < hide_after {
+                    return TimeoutAction::ToDuration(hide_after - still_for);
+                }
+
+                cursor_state.autohide_timer = None;
+                cursor_state.autohidden = true;
+                state.pinnacle.render_cursor_visibility_change();
+
+                TimeoutAction::Drop
+            })
+            .expect("failed to insert cursor autohide timer");
+
+        self.cursor_state.autohide_timer = Some(token);
+    }
+}
+
 /// Load xcursor images for the given theme and icon.
 ///
 /// Looks through legacy names as fallback.
@@ -2,9 +2,10 @@
 
 use keyboard::KeyboardFocusTarget;
 use smithay::{
-    desktop::layer_map_for_output,
+    backend::renderer::utils::with_renderer_surface_state,
+    desktop::{LayerSurface, layer_map_for_output},
     output::Output,
-    utils::{IsAlive, SERIAL_COUNTER},
+    utils::{IsAlive, Point, SERIAL_COUNTER},
     wayland::shell::wlr_layer::{self, KeyboardInteractivity},
 };
 
@@ -34,6 +35,8 @@ impl State {
     ///   but this may change in the future.
     /// - On-demand layer surfaces can only be focused by clicking on them.
     ///   They retain focus unless a window is focused or it is clicked off of.
+    ///   They also lose focus when they unmap or stop accepting keyboard focus,
+    ///   returning focus to the focused window.
     /// - Only the focused window on the focused output gets focus.
     ///   If the focused output changes, the window may lose focus.
     pub fn update_keyboard_focus(&mut self) {
@@ -80,7 +83,7 @@ impl State {
         }
 
         // Refresh exclusive layer shell focus
-        let mut exclusive_layer_focus: Option<smithay::desktop::LayerSurface> = None;
+        let mut exclusive_layer_focus: Option<LayerSurface> = None;
 
         for op in self.pinnacle.output_focus_stack.outputs().rev() {
             let possible_overlay_focus = layer_map_for_output(op)
@@ -88,6 +91,7 @@ impl State {
                 .rev()
                 .find(|layer| {
                     layer.cached_state().keyboard_interactivity == KeyboardInteractivity::Exclusive
+                        && is_layer_mapped(layer)
                 })
                 .cloned();
 
@@ -105,6 +109,7 @@ impl State {
                     .find(|layer| {
                         layer.cached_state().keyboard_interactivity
                             == KeyboardInteractivity::Exclusive
+                            && is_layer_mapped(layer)
                     })
                     .cloned();
 
@@ -130,13 +135,15 @@ impl State {
                 }
             }
 
+            self.update_window_opacities(None);
+
             return;
         }
 
         // Handle on-demand layer shell focus
-        self.pinnacle
-            .on_demand_layer_focus
-            .take_if(|layer| !layer.alive());
+        self.pinnacle.on_demand_layer_focus.take_if(|layer| {
+            !layer.alive() || !is_layer_mapped(layer) || !layer.can_receive_keyboard_focus()
+        });
 
         if let Some(layer) = self.pinnacle.on_demand_layer_focus.as_ref() {
             let layer_target = KeyboardFocusTarget::LayerSurface(layer.clone());
@@ -154,6 +161,8 @@ impl State {
                 }
             }
 
+            self.update_window_opacities(None);
+
             return;
         }
 
@@ -181,12 +190,54 @@ impl State {
             }
         }
 
+        if let Some(focused_window) = focused_window.as_ref() {
+            self.pinnacle.set_window_urgent(focused_window, false);
+        }
+
+        self.update_window_opacities(focused_window.as_ref());
+
+        let warp_to = focused_window
+            .clone()
+            .filter(|win| win.with_state(|state| state.warp_cursor_on_focus));
+
         keyboard.set_focus(
             self,
             focused_window.map(KeyboardFocusTarget::Window),
             SERIAL_COUNTER.next_serial(),
         );
+
+        if let Some(window) = warp_to {
+            self.center_pointer_on_window(&window);
+        }
     }
+
+    /// Renders `focused` fully opaque and all other windows with the inactive window opacity.
+    pub fn update_window_opacities(&mut self, focused: Option<&WindowElement>) {
+        let inactive_opacity = self.pinnacle.config.inactive_window_opacity;
+
+        let mut outputs_to_render = Vec::new();
+
+        for win in self.pinnacle.windows.iter() {
+            let opacity = if Some(win) == focused { 1.0 } else { inactive_opacity };
+
+            let changed = win
+                .with_state_mut(|state| std::mem::replace(&mut state.opacity, opacity) != opacity);
+
+            if changed && let Some(output) = win.output(&self.pinnacle) {
+                outputs_to_render.push(output);
+            }
+        }
+
+        for output in outputs_to_render {
+            self.schedule_render(&output);
+        }
+    }
+}
+
+/// Returns whether a layer surface has a buffer attached and is being shown.
+fn is_layer_mapped(layer: &LayerSurface) -> bool {
+    with_renderer_surface_state(layer.wl_surface(), |state| state.buffer().is_some())
+        .unwrap_or_default()
 }
 
 impl Pinnacle {
@@ -265,6 +316,22 @@ impl Pinnacle {
         }
         self.output_focus_stack.set_focus(output.clone());
         self.signal_state.output_focused.signal(output);
+
+        if self.input_state.warp_on_output_focus
+            && let Some(output_geo) = self.space.output_geometry(output)
+            && let Some(pointer) = self.seat.get_pointer()
+            && !output_geo.to_f64().contains(pointer.current_location())
+        {
+            let center = output_geo.loc.to_f64()
+                + Point::from((
+                    output_geo.size.w as f64 / 2.0,
+                    output_geo.size.h as f64 / 2.0,
+                ));
+            // We don't have the `State` here
+            self.loop_handle.insert_idle(move |state| {
+                state.warp_cursor_to_global_loc(center);
+            });
+        }
     }
 }
 
@@ -346,8 +413,8 @@ impl WindowKeyboardFocusStack {
 
     /// Gets the currently focused window on this stack.
     ///
-    /// This is the topmost window that is on an active tag and not
-    /// an OR window.
+    /// This is the topmost window that is on an active tag, not minimized,
+    /// and not an OR window.
     pub fn current_focus(&self) -> Option<&WindowElement> {
         if !self.focused {
             return None;
@@ -357,6 +424,7 @@ impl WindowKeyboardFocusStack {
             .iter()
             .rev()
             .filter(|win| win.is_on_active_tag())
+            .filter(|win| win.with_state(|state| !state.minimized))
             .find(|win| !win.is_x11_override_redirect())
     }
 }
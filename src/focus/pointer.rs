@@ -478,6 +478,8 @@ impl Pinnacle {
             }
             if let Some(new) = new_op {
                 self.signal_state.output_pointer_enter.signal(&new);
+                self.cursor_state
+                    .set_output_scale(new.current_scale().fractional_scale());
             }
         }
 
@@ -78,6 +78,7 @@ impl PointerGrab<State> for MoveSurfaceGrab {
                     && Some(&output_under_pointer) != tag_output.as_ref()
                 {
                     self.window.set_tags_to_output(&output_under_pointer);
+                    state.pinnacle.window_tags_changed = true;
 
                     if self.window.with_state(|state| state.layout_mode.is_tiled()) {
                         self.window
@@ -139,14 +140,19 @@ impl PointerGrab<State> for MoveSurfaceGrab {
             }
             LayoutModeKind::Floating | LayoutModeKind::Spilled => {
                 let delta = event.location - self.start_data.location;
-                let new_loc = self.initial_window_loc.to_f64() + delta;
+                let mut new_loc = (self.initial_window_loc.to_f64() + delta).to_i32_round();
 
-                state
-                    .pinnacle
-                    .map_window_to(&self.window, new_loc.to_i32_round());
+                // Keep the window in the work area of the output it's being dragged onto
+                if let Some(output) = output_under_pointer.as_ref().or(win_output.as_ref()) {
+                    new_loc = state
+                        .pinnacle
+                        .constrain_floating_loc(&self.window, new_loc, output);
+                }
+
+                state.pinnacle.map_window_to(&self.window, new_loc);
 
                 self.window.with_state_mut(|state| {
-                    state.set_floating_loc(new_loc.to_i32_round());
+                    state.set_floating_loc(new_loc);
                 });
             }
             LayoutModeKind::Maximized | LayoutModeKind::Fullscreen => {
@@ -176,6 +176,25 @@ impl PointerGrab<State> for ResizeSurfaceGrab {
             new_window_height = self.initial_window_geo.size.h + delta.y;
         }
 
+        if self
+            .window
+            .with_state(|state| state.layout_mode.is_floating())
+        {
+            // Dragging only the top or bottom edge changes the height, so let the width follow it
+            let from_height = matches!(
+                self.edges.0,
+                xdg_toplevel::ResizeEdge::Top | xdg_toplevel::ResizeEdge::Bottom
+            );
+            let size = self.window.with_state(|state| {
+                state.apply_aspect_ratio(
+                    Size::from((new_window_width, new_window_height)),
+                    from_height,
+                )
+            });
+            new_window_width = size.w;
+            new_window_height = size.h;
+        }
+
         let (min_size, max_size) = match self.window.underlying_surface() {
             WindowSurface::Wayland(toplevel) => {
                 compositor::with_states(toplevel.wl_surface(), |states| {
@@ -20,10 +20,11 @@ use smithay::{
         input::TabletToolDescriptor,
         renderer::utils::{self, with_renderer_surface_state},
     },
-    delegate_compositor, delegate_cursor_shape, delegate_data_control, delegate_data_device,
-    delegate_ext_data_control, delegate_fractional_scale, delegate_keyboard_shortcuts_inhibit,
-    delegate_layer_shell, delegate_output, delegate_pointer_constraints, delegate_pointer_gestures,
-    delegate_presentation, delegate_primary_selection, delegate_relative_pointer, delegate_seat,
+    delegate_compositor, delegate_content_type, delegate_cursor_shape, delegate_data_control,
+    delegate_data_device, delegate_ext_data_control, delegate_fractional_scale,
+    delegate_keyboard_shortcuts_inhibit, delegate_layer_shell, delegate_output,
+    delegate_pointer_constraints, delegate_pointer_gestures, delegate_presentation,
+    delegate_primary_selection, delegate_relative_pointer, delegate_seat,
     delegate_security_context, delegate_shm, delegate_single_pixel_buffer, delegate_tablet_manager,
     delegate_viewporter, delegate_xwayland_keyboard_grab, delegate_xwayland_shell,
     desktop::{
@@ -157,8 +158,17 @@ impl CompositorHandler for State {
                     unreachable!("on_commit_buffer_handler was called previously");
                 };
 
+                // Clients may attach a buffer before receiving the initial configure,
+                // for example while there are no tags to put the window on. Those windows
+                // stay unmapped until their rules are done and the configure is sent.
+                let ready_to_map = cfg!(feature = "wlcs")
+                    || matches!(
+                        self.pinnacle.unmapped_windows[idx].state,
+                        UnmappedState::PostInitialConfigure { .. }
+                    );
+
                 // Unmapped window has become mapped
-                if is_mapped {
+                if is_mapped && ready_to_map {
                     let unmapped = self.pinnacle.unmapped_windows.remove(idx);
 
                     unmapped.window.on_commit();
@@ -183,13 +193,13 @@ impl CompositorHandler for State {
                     unmapped.window.on_commit();
 
                     if matches!(unmapped.state, UnmappedState::WaitingForTags { .. }) {
+                        crate::api::tag::add_dynamic_for_window(self, &unmapped.window);
+
                         if unmapped.window.output(&self.pinnacle).is_some() {
                             self.pinnacle.request_window_rules(&mut unmapped);
                         } else if let Some(output) = self.pinnacle.focused_output().cloned()
                             && output.with_state(|state| !state.tags.is_empty())
                         {
-                            // FIXME: If there are no tags and the window still commits a buffer,
-                            // Pinnacle will crash at `map_new_window`.
                             unmapped.window.set_tags_to_output(&output);
                             self.pinnacle.request_window_rules(&mut unmapped);
                         }
@@ -652,9 +662,18 @@ impl FractionalScaleHandler for State {
                         }
                     })
                     .or_else(|| self.pinnacle.space.outputs().next().cloned());
-            if let Some(output) = primary_scanout_output {
+            let preferred_scale = self
+                .pinnacle
+                .window_for_surface(&root)
+                .and_then(|window| window.with_state(|state| state.preferred_scale));
+
+            let scale = preferred_scale.or_else(|| {
+                primary_scanout_output.map(|output| output.current_scale().fractional_scale())
+            });
+
+            if let Some(scale) = scale {
                 fractional_scale::with_fractional_scale(states, |fractional_scale| {
-                    fractional_scale.set_preferred_scale(output.current_scale().fractional_scale());
+                    fractional_scale.set_preferred_scale(scale);
                 });
             }
         });
@@ -889,6 +908,9 @@ impl OutputManagementHandler for State {
         let _span = tracy_client::span!("OutputManagementHandler::apply_configuration");
 
         for (output, config) in config {
+            // The configuration replaces any mode still being tried
+            self.pinnacle.cancel_mode_revert(&output);
+
             match config {
                 OutputConfiguration::Disabled => {
                     self.pinnacle.set_output_enabled(&output, false);
@@ -904,6 +926,10 @@ impl OutputManagementHandler for State {
                     self.pinnacle.set_output_enabled(&output, true);
                     self.set_output_powered(&output, true);
 
+                    if scale.is_some() {
+                        output.with_state_mut(|state| state.scale_settled = true);
+                    }
+
                     let mode = mode.map(|(size, refresh)| {
                         if let Some(refresh) = refresh {
                             Mode {
@@ -957,7 +983,9 @@ impl OutputManagementHandler for State {
 
     fn test_configuration(&mut self, config: HashMap<Output, OutputConfiguration>) -> bool {
         debug!(?config);
-        true
+        config
+            .iter()
+            .all(|(output, config)| self.test_output_configuration(output, config))
     }
 }
 delegate_output_management!(State);
@@ -996,8 +1024,27 @@ impl KeyboardShortcutsInhibitHandler for State {
     }
 
     fn new_inhibitor(&mut self, inhibitor: KeyboardShortcutsInhibitor) {
-        // TODO: Some way to not unconditionally activate the inhibitor
-        inhibitor.activate();
+        let Some(allowlist) = self.pinnacle.config.shortcut_inhibit_allowlist.as_ref() else {
+            inhibitor.activate();
+            return;
+        };
+
+        let app_id = self
+            .pinnacle
+            .window_for_surface(inhibitor.wl_surface())
+            .and_then(|win| win.class());
+
+        if app_id
+            .as_ref()
+            .is_some_and(|app_id| allowlist.contains(app_id))
+        {
+            inhibitor.activate();
+        } else {
+            debug!(
+                ?app_id,
+                "Not activating keyboard shortcuts inhibitor, app is not in the allowlist"
+            );
+        }
     }
 }
 delegate_keyboard_shortcuts_inhibit!(State);
@@ -1016,6 +1063,8 @@ delegate_pointer_gestures!(State);
 
 delegate_single_pixel_buffer!(State);
 
+delegate_content_type!(State);
+
 impl Pinnacle {
     fn position_popup(&self, popup: &PopupSurface) -> anyhow::Result<()> {
         let _span = tracy_client::span!("Pinnacle::position_popup");
@@ -23,6 +23,47 @@ use tracing::debug;
 use crate::state::{State, WithState};
 use crate::window::UnmappedState;
 
+/// How decoration modes are negotiated with clients.
+///
+/// A decoration mode set on a window through the API or a window rule always takes precedence.
+/// Clients that support neither xdg-decoration nor KDE server decoration
+/// draw their own decorations regardless.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecorationPolicy {
+    /// Use the mode clients ask for, defaulting to server-side.
+    PreferServerSide,
+    /// Use the mode clients ask for, leaving the choice to them if they don't.
+    #[default]
+    PreferClientSide,
+    /// Always use server-side decorations, ignoring what clients ask for.
+    ForceServerSide,
+    /// Always use client-side decorations, ignoring what clients ask for.
+    ForceClientSide,
+}
+
+impl DecorationPolicy {
+    /// Resolves the decoration mode a window should use from the mode set on it
+    /// and the mode its client asked for.
+    pub fn resolve(
+        self,
+        window_rule_mode: Option<zxdg_toplevel_decoration_v1::Mode>,
+        client_mode: Option<zxdg_toplevel_decoration_v1::Mode>,
+    ) -> Option<zxdg_toplevel_decoration_v1::Mode> {
+        window_rule_mode.or(match self {
+            DecorationPolicy::PreferServerSide => {
+                client_mode.or(Some(zxdg_toplevel_decoration_v1::Mode::ServerSide))
+            }
+            DecorationPolicy::PreferClientSide => client_mode,
+            DecorationPolicy::ForceServerSide => {
+                Some(zxdg_toplevel_decoration_v1::Mode::ServerSide)
+            }
+            DecorationPolicy::ForceClientSide => {
+                Some(zxdg_toplevel_decoration_v1::Mode::ClientSide)
+            }
+        })
+    }
+}
+
 impl State {
     fn new_decoration(
         &mut self,
@@ -40,21 +81,26 @@ impl State {
             })
             .and_then(|window| window.with_state(|state| state.decoration_mode));
 
+        let mode = self
+            .pinnacle
+            .config
+            .decoration_policy
+            .resolve(window_rule_mode, None);
+
         toplevel.with_pending_state(|state| {
-            state.decoration_mode = window_rule_mode;
+            state.decoration_mode = mode;
         });
 
-        window_rule_mode
-            .and_then(|mode| match mode {
-                zxdg_toplevel_decoration_v1::Mode::ClientSide => {
-                    Some(org_kde_kwin_server_decoration::Mode::Client)
-                }
-                zxdg_toplevel_decoration_v1::Mode::ServerSide => {
-                    Some(org_kde_kwin_server_decoration::Mode::Server)
-                }
-                _ => None,
-            })
-            .unwrap_or(org_kde_kwin_server_decoration::Mode::Client)
+        mode.and_then(|mode| match mode {
+            zxdg_toplevel_decoration_v1::Mode::ClientSide => {
+                Some(org_kde_kwin_server_decoration::Mode::Client)
+            }
+            zxdg_toplevel_decoration_v1::Mode::ServerSide => {
+                Some(org_kde_kwin_server_decoration::Mode::Server)
+            }
+            _ => None,
+        })
+        .unwrap_or(org_kde_kwin_server_decoration::Mode::Client)
     }
 
     fn request_mode(
@@ -64,21 +110,19 @@ impl State {
     ) -> org_kde_kwin_server_decoration::Mode {
         let _span = tracy_client::span!("State::request_mode");
 
-        if let Some(window) = self.pinnacle.window_for_surface(toplevel.wl_surface()) {
+        let policy = self.pinnacle.config.decoration_policy;
+
+        let mode = if let Some(window) = self.pinnacle.window_for_surface(toplevel.wl_surface()) {
             let window_rule_mode = window.with_state(|state| state.decoration_mode);
+            let mode = policy.resolve(window_rule_mode, Some(mode));
 
             toplevel.with_pending_state(|state| {
-                state.decoration_mode = Some(window_rule_mode.unwrap_or(mode));
+                state.decoration_mode = mode;
             });
 
             toplevel.send_configure();
 
-            match window_rule_mode.unwrap_or(mode) {
-                zxdg_toplevel_decoration_v1::Mode::ServerSide => {
-                    org_kde_kwin_server_decoration::Mode::Server
-                }
-                _ => org_kde_kwin_server_decoration::Mode::Client,
-            }
+            mode
         } else if let Some(unmapped) = self
             .pinnacle
             .unmapped_window_for_surface_mut(toplevel.wl_surface())
@@ -91,45 +135,45 @@ impl State {
                 } => {
                     client_requests.decoration_mode = Some(mode);
 
-                    match mode {
-                        zxdg_toplevel_decoration_v1::Mode::ServerSide => {
-                            org_kde_kwin_server_decoration::Mode::Server
-                        }
-                        _ => org_kde_kwin_server_decoration::Mode::Client,
-                    }
+                    policy.resolve(None, Some(mode))
                 }
                 UnmappedState::PostInitialConfigure { .. } => {
                     let window = &unmapped.window;
 
                     let window_rule_mode = window.with_state(|state| state.decoration_mode);
+                    let mode = policy.resolve(window_rule_mode, Some(mode));
 
                     toplevel.with_pending_state(|state| {
-                        state.decoration_mode = Some(window_rule_mode.unwrap_or(mode));
+                        state.decoration_mode = mode;
                     });
 
                     toplevel.send_configure();
 
-                    match window_rule_mode.unwrap_or(mode) {
-                        zxdg_toplevel_decoration_v1::Mode::ServerSide => {
-                            org_kde_kwin_server_decoration::Mode::Server
-                        }
-                        _ => org_kde_kwin_server_decoration::Mode::Client,
-                    }
+                    mode
                 }
             }
         } else {
-            org_kde_kwin_server_decoration::Mode::Client
+            None
+        };
+
+        match mode {
+            Some(zxdg_toplevel_decoration_v1::Mode::ServerSide) => {
+                org_kde_kwin_server_decoration::Mode::Server
+            }
+            _ => org_kde_kwin_server_decoration::Mode::Client,
         }
     }
 
     fn unset_mode(&mut self, toplevel: ToplevelSurface) {
         let _span = tracy_client::span!("State::unset_mode");
 
+        let policy = self.pinnacle.config.decoration_policy;
+
         if let Some(window) = self.pinnacle.window_for_surface(toplevel.wl_surface()) {
             let window_rule_mode = window.with_state(|state| state.decoration_mode);
 
             toplevel.with_pending_state(|state| {
-                state.decoration_mode = window_rule_mode;
+                state.decoration_mode = policy.resolve(window_rule_mode, None);
             });
 
             toplevel.send_pending_configure();
@@ -153,7 +197,7 @@ impl State {
                     let window_rule_mode = window.with_state(|state| state.decoration_mode);
 
                     toplevel.with_pending_state(|state| {
-                        state.decoration_mode = window_rule_mode;
+                        state.decoration_mode = policy.resolve(window_rule_mode, None);
                     });
 
                     toplevel.send_pending_configure();
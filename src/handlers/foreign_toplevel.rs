@@ -21,6 +21,10 @@ impl ForeignToplevelHandler for State {
             return;
         };
 
+        if window.with_state(|state| state.minimized) {
+            crate::api::window::set_minimized(self, &window, false);
+        }
+
         self.pinnacle.keyboard_focus_stack.set_focus(window.clone());
         self.pinnacle.raise_window(window.clone());
 
@@ -44,11 +48,11 @@ impl ForeignToplevelHandler for State {
     fn close(&mut self, wl_surface: WlSurface) {
         let _span = tracy_client::span!("ForeignToplevelHandler::close");
 
-        let Some(window) = self.pinnacle.window_for_surface(&wl_surface) else {
+        let Some(window) = self.pinnacle.window_for_surface(&wl_surface).cloned() else {
             return;
         };
 
-        window.close();
+        crate::api::window::close(self, &window);
     }
 
     fn set_fullscreen(&mut self, wl_surface: WlSurface, _wl_output: Option<WlOutput>) {
@@ -103,40 +107,24 @@ impl ForeignToplevelHandler for State {
             });
     }
 
-    // TODO:
     fn set_minimized(&mut self, wl_surface: WlSurface) {
         let _span = tracy_client::span!("ForeignToplevelHandler::set_minimized");
 
-        let Some(window) = self.pinnacle.window_for_surface(&wl_surface) else {
-            return;
-        };
-
-        window.with_state_mut(|state| state.minimized = true);
-
-        let Some(output) = window.output(&self.pinnacle) else {
+        let Some(window) = self.pinnacle.window_for_surface(&wl_surface).cloned() else {
             return;
         };
 
-        self.pinnacle.request_layout(&output);
-        self.schedule_render(&output);
+        crate::api::window::set_minimized(self, &window, true);
     }
 
-    // TODO:
     fn unset_minimized(&mut self, wl_surface: WlSurface) {
         let _span = tracy_client::span!("ForeignToplevelHandler::unset_minimized");
 
-        let Some(window) = self.pinnacle.window_for_surface(&wl_surface) else {
-            return;
-        };
-
-        window.with_state_mut(|state| state.minimized = false);
-
-        let Some(output) = window.output(&self.pinnacle) else {
+        let Some(window) = self.pinnacle.window_for_surface(&wl_surface).cloned() else {
             return;
         };
 
-        self.pinnacle.request_layout(&output);
-        self.schedule_render(&output);
+        crate::api::window::set_minimized(self, &window, false);
     }
 }
 delegate_foreign_toplevel!(State);
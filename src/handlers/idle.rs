@@ -1,7 +1,16 @@
+use std::time::Duration;
+
 use smithay::{
     delegate_idle_inhibit, delegate_idle_notify,
     desktop::utils::surface_primary_scanout_output,
-    reexports::wayland_server::protocol::wl_surface::WlSurface,
+    output::Output,
+    reexports::{
+        calloop::{
+            RegistrationToken,
+            timer::{TimeoutAction, Timer},
+        },
+        wayland_server::protocol::wl_surface::WlSurface,
+    },
     utils::IsAlive,
     wayland::{
         compositor,
@@ -9,8 +18,9 @@ use smithay::{
         idle_notify::{IdleNotifierHandler, IdleNotifierState},
     },
 };
+use tracing::debug;
 
-use crate::state::{Pinnacle, State};
+use crate::state::{Pinnacle, State, WithState};
 
 impl IdleNotifierHandler for State {
     fn idle_notifier_state(&mut self) -> &mut IdleNotifierState<Self> {
@@ -38,12 +48,285 @@ impl Pinnacle {
 
         self.idle_inhibiting_surfaces.retain(|s| s.alive());
 
-        let is_inhibited = self.idle_inhibiting_surfaces.iter().any(|surface| {
-            compositor::with_states(surface, |states| {
-                surface_primary_scanout_output(surface, states).is_some()
-            })
-        });
+        let is_inhibited = self.is_idle_inhibited();
 
         self.idle_notifier_state.set_is_inhibited(is_inhibited);
     }
+
+    /// Returns whether a visible surface or a DBus client is inhibiting idle.
+    pub fn is_idle_inhibited(&self) -> bool {
+        if self.screensaver_state.is_inhibited() {
+            return true;
+        }
+
+        self.idle_inhibiting_surfaces.iter().any(|surface| {
+            surface.alive()
+                && compositor::with_states(surface, |states| {
+                    surface_primary_scanout_output(surface, states).is_some()
+                })
+        })
+    }
+
+    /// Sets how long to wait without activity before dimming outputs.
+    ///
+    /// Passing `None` disables dimming. This undims outputs and restarts the idle timer.
+    pub fn set_idle_dim(&mut self, config: Option<IdleDimConfig>) {
+        self.idle_dim.config = config;
+        self.idle_dim.last_activity = self.clock.now().into();
+        self.undim_outputs();
+        self.arm_idle_dim_timer();
+    }
+
+    /// Records user activity for idle dimming, undimming outputs if they are dimmed.
+    pub fn notify_idle_dim_activity(&mut self) {
+        self.idle_dim.last_activity = self.clock.now().into();
+
+        if self.idle_dim.dimmed {
+            self.undim_outputs();
+            self.arm_idle_dim_timer();
+        }
+    }
+
+    fn undim_outputs(&mut self) {
+        if !self.idle_dim.dimmed {
+            return;
+        }
+
+        debug!("Undimming outputs");
+        self.idle_dim.dimmed = false;
+
+        self.loop_handle.insert_idle(|state| {
+            for output in state.pinnacle.outputs.clone() {
+                state.schedule_render(&output);
+            }
+        });
+    }
+
+    fn arm_idle_dim_timer(&mut self) {
+        if let Some(token) = self.idle_dim.timer.take() {
+            self.loop_handle.remove(token);
+        }
+
+        let Some(config) = self.idle_dim.config else {
+            return;
+        };
+
+        let token = self
+            .loop_handle
+            .insert_source(Timer::from_duration(config.dim_after), |_, _, state| {
+                let Some(config) = state.pinnacle.idle_dim.config else {
+                    state.pinnacle.idle_dim.timer = None;
+                    return TimeoutAction::Drop;
+                };
+
+                // Activity doesn't rearm the timer, so check if there was any since it was armed
+                let idle_for = Duration::from(state.pinnacle.clock.now())
+                    .saturating_sub(state.pinnacle.idle_dim.last_activity);
+                if idle_for < config.dim_after {
+                    return TimeoutAction::ToDuration(config.dim_after - idle_for);
+                }
+
+                if state.pinnacle.is_idle_inhibited() {
+                    return TimeoutAction::ToDuration(config.dim_after);
+                }
+
+                debug!("Dimming outputs after {:?} of inactivity", config.dim_after);
+                state.pinnacle.idle_dim.timer = None;
+                state.pinnacle.idle_dim.dimmed = true;
+
+                for output in state.pinnacle.outputs.clone() {
+                    state.schedule_render(&output);
+                }
+
+                TimeoutAction::Drop
+            })
+            .expect("failed to insert idle dim timer");
+
+        self.idle_dim.timer = Some(token);
+    }
+}
+
+impl Pinnacle {
+    /// Sets how long to wait without activity before powering off outputs.
+    ///
+    /// Passing `None` disables this, although outputs with their own timeout still
+    /// power off. This powers outputs back on and restarts the idle timer.
+    pub fn set_dpms_timeout(&mut self, timeout: Option<Duration>) {
+        self.idle_dpms.timeout = timeout;
+        self.restart_dpms_timer();
+    }
+
+    /// Sets how long to wait without activity before powering off `output`,
+    /// overriding the global timeout.
+    ///
+    /// Passing `None` makes the output use the global timeout.
+    pub fn set_output_dpms_timeout(&mut self, output: &Output, timeout: Option<Duration>) {
+        output.with_state_mut(|state| state.dpms_timeout = timeout);
+        self.restart_dpms_timer();
+    }
+
+    /// Gets the timeout after which `output` is powered off from inactivity.
+    pub fn dpms_timeout(&self, output: &Output) -> Option<Duration> {
+        output
+            .with_state(|state| state.dpms_timeout)
+            .or(self.idle_dpms.timeout)
+    }
+
+    fn restart_dpms_timer(&mut self) {
+        self.idle_dpms.last_activity = self.clock.now().into();
+        self.loop_handle
+            .insert_idle(|state| state.power_on_dpms_outputs());
+        self.arm_dpms_timer();
+    }
+
+    fn arm_dpms_timer(&mut self) {
+        if let Some(token) = self.idle_dpms.timer.take() {
+            self.loop_handle.remove(token);
+        }
+
+        let idle_for =
+            Duration::from(self.clock.now()).saturating_sub(self.idle_dpms.last_activity);
+
+        let next_timeout = self
+            .outputs
+            .iter()
+            .filter(|output| output.with_state(|state| state.powered))
+            .filter_map(|output| self.dpms_timeout(output))
+            .map(|timeout| timeout.saturating_sub(idle_for))
+            .min();
+
+        let Some(next_timeout) = next_timeout else {
+            return;
+        };
+
+        let token = self
+            .loop_handle
+            .insert_source(Timer::from_duration(next_timeout), |_, _, state| {
+                state.pinnacle.idle_dpms.timer = None;
+                state.power_off_idle_outputs();
+                TimeoutAction::Drop
+            })
+            .expect("failed to insert dpms timer");
+
+        self.idle_dpms.timer = Some(token);
+    }
+}
+
+impl State {
+    /// Records user activity for DPMS, powering on outputs that were
+    /// powered off from inactivity.
+    pub fn notify_dpms_activity(&mut self) {
+        self.pinnacle.idle_dpms.last_activity = self.pinnacle.clock.now().into();
+
+        // Also arm the timer if it isn't running, e.g. when outputs connected after setting it
+        if self.power_on_dpms_outputs() || self.pinnacle.idle_dpms.timer.is_none() {
+            self.pinnacle.arm_dpms_timer();
+        }
+    }
+
+    /// Powers on outputs that were powered off from inactivity.
+    ///
+    /// Returns whether any were.
+    fn power_on_dpms_outputs(&mut self) -> bool {
+        let mut powered_on = false;
+
+        for output in self.pinnacle.outputs.clone() {
+            if output.with_state(|state| !state.dpms_powered_off) {
+                continue;
+            }
+
+            debug!(output = output.name(), "Powering on output after activity");
+            self.set_output_powered(&output, true);
+            self.schedule_render(&output);
+            powered_on = true;
+        }
+
+        powered_on
+    }
+
+    /// Powers off outputs that have been idle for longer than their timeout,
+    /// then rearms the timer for the next one.
+    fn power_off_idle_outputs(&mut self) {
+        if self.pinnacle.is_idle_inhibited() {
+            // Start over once nothing is inhibiting idle anymore
+            self.pinnacle.idle_dpms.last_activity = self.pinnacle.clock.now().into();
+            self.pinnacle.arm_dpms_timer();
+            return;
+        }
+
+        let idle_for = Duration::from(self.pinnacle.clock.now())
+            .saturating_sub(self.pinnacle.idle_dpms.last_activity);
+
+        for output in self.pinnacle.outputs.clone() {
+            let Some(timeout) = self.pinnacle.dpms_timeout(&output) else {
+                continue;
+            };
+
+            if idle_for < timeout || output.with_state(|state| !state.powered) {
+                continue;
+            }
+
+            debug!(
+                output = output.name(),
+                "Powering off output after {timeout:?} of inactivity"
+            );
+            self.set_output_powered(&output, false);
+            output.with_state_mut(|state| state.dpms_powered_off = true);
+        }
+
+        self.pinnacle.arm_dpms_timer();
+    }
+}
+
+/// How long to wait without activity before dimming outputs, and by how much.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IdleDimConfig {
+    /// How long to wait after the last activity before dimming.
+    pub dim_after: Duration,
+    /// How much to dim outputs by, from 0.0 (not at all) to 1.0 (fully black).
+    pub dim_level: f32,
+}
+
+/// State for dimming outputs after inactivity.
+#[derive(Debug)]
+pub struct IdleDim {
+    /// The dim config, or `None` if dimming is disabled.
+    pub config: Option<IdleDimConfig>,
+    /// Whether outputs are currently dimmed.
+    pub dimmed: bool,
+    /// When the last activity happened, on the compositor clock.
+    last_activity: Duration,
+    timer: Option<RegistrationToken>,
+}
+
+impl Default for IdleDim {
+    fn default() -> Self {
+        Self {
+            config: None,
+            dimmed: false,
+            last_activity: Duration::ZERO,
+            timer: None,
+        }
+    }
+}
+
+/// State for powering off outputs after inactivity.
+#[derive(Debug)]
+pub struct IdleDpms {
+    /// How long to wait before powering off outputs without their own timeout,
+    /// or `None` to leave them on.
+    pub timeout: Option<Duration>,
+    /// When the last activity happened, on the compositor clock.
+    last_activity: Duration,
+    timer: Option<RegistrationToken>,
+}
+
+impl Default for IdleDpms {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            last_activity: Duration::ZERO,
+            timer: None,
+        }
+    }
 }
@@ -104,7 +104,7 @@ impl XdgActivationHandler for State {
                     }
                 }
                 ActivationContext::UrgentOnly => {
-                    // TODO: add urgent state to windows, use in a focus border/taskbar flash
+                    self.pinnacle.set_window_urgent(&window, true);
                 }
             }
         } else if let Some(unmapped) = self.pinnacle.unmapped_window_for_surface_mut(&surface) {
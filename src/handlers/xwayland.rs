@@ -1,11 +1,13 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use std::{
+    collections::HashMap,
     os::fd::OwnedFd,
     process::Stdio,
     sync::{
         Arc,
         atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError},
     },
     time::Duration,
 };
@@ -13,7 +15,18 @@ use std::{
 use smithay::{
     desktop::Window,
     input::pointer::CursorIcon,
-    reexports::wayland_server::Client,
+    reexports::{
+        calloop::{LoopHandle, channel},
+        wayland_server::Client,
+        x11rb::{
+            self,
+            connection::Connection as _,
+            protocol::{
+                Event,
+                xproto::{AtomEnum, ChangeWindowAttributesAux, ConnectionExt as _, EventMask},
+            },
+        },
+    },
     utils::{Logical, Point, Rectangle, SERIAL_COUNTER, Size},
     wayland::selection::{
         SelectionTarget,
@@ -50,6 +63,110 @@ pub struct XwaylandState {
     pub client: Client,
     pub should_clients_self_scale: bool,
     pub current_scale: Option<f64>,
+    /// Watches the `WM_WINDOW_ROLE` of X11 windows, which the xwm doesn't track.
+    pub role_watcher: Option<RoleWatcher>,
+    /// The `WM_WINDOW_ROLE` of X11 windows that have one, by window id.
+    pub roles: HashMap<u32, String>,
+}
+
+impl XwaylandState {
+    /// Gets the `WM_WINDOW_ROLE` of an X11 window.
+    pub fn window_role(&self, surface: &X11Surface) -> Option<String> {
+        self.roles.get(&surface.window_id()).cloned()
+    }
+}
+
+/// Watches `WM_WINDOW_ROLE` on X11 windows over a separate connection on its own thread.
+///
+/// Xwayland is a client of the compositor, so its replies can't be waited on
+/// from the event loop. The thread sends roles back as they're set or changed.
+#[derive(Debug)]
+pub struct RoleWatcher {
+    watch_sender: mpsc::Sender<u32>,
+}
+
+impl RoleWatcher {
+    fn spawn(
+        display_number: u32,
+        loop_handle: &LoopHandle<'static, State>,
+    ) -> anyhow::Result<Self> {
+        let (watch_sender, watch_receiver) = mpsc::channel::<u32>();
+        let (role_sender, role_channel) = channel::channel::<(u32, Option<String>)>();
+
+        loop_handle
+            .insert_source(role_channel, |event, _, state| {
+                let channel::Event::Msg((window, role)) = event else {
+                    return;
+                };
+                let Some(xwayland_state) = state.pinnacle.xwayland_state.as_mut() else {
+                    return;
+                };
+                match role {
+                    Some(role) => xwayland_state.roles.insert(window, role),
+                    None => xwayland_state.roles.remove(&window),
+                };
+            })
+            .map_err(|err| anyhow::anyhow!("failed to insert role channel: {err}"))?;
+
+        std::thread::Builder::new()
+            .name("xwayland_roles".to_string())
+            .spawn(move || {
+                if let Err(err) = watch_roles(display_number, watch_receiver, role_sender) {
+                    warn!("Stopped watching xwayland window roles: {err}");
+                }
+            })?;
+
+        Ok(Self { watch_sender })
+    }
+
+    /// Starts watching the role of the X11 window with the given id.
+    fn watch(&self, window: u32) {
+        let _ = self.watch_sender.send(window);
+    }
+}
+
+fn watch_roles(
+    display_number: u32,
+    watch_receiver: mpsc::Receiver<u32>,
+    role_sender: channel::Sender<(u32, Option<String>)>,
+) -> anyhow::Result<()> {
+    let (conn, _) = x11rb::connect(Some(&format!(":{display_number}")))?;
+    let wm_window_role = conn.intern_atom(false, b"WM_WINDOW_ROLE")?.reply()?.atom;
+
+    let read_role = |window: u32| -> Option<String> {
+        let reply = conn
+            .get_property(false, window, wm_window_role, AtomEnum::ANY, 0, 1024)
+            .ok()?
+            .reply()
+            .ok()?;
+        // Roles are usually STRING but some clients set UTF8_STRING
+        (reply.format == 8 && !reply.value.is_empty())
+            .then(|| String::from_utf8_lossy(&reply.value).into_owned())
+    };
+
+    loop {
+        match watch_receiver.recv_timeout(Duration::from_millis(50)) {
+            Ok(window) => {
+                // Event masks are per client, so this doesn't affect the xwm's
+                conn.change_window_attributes(
+                    window,
+                    &ChangeWindowAttributesAux::new().event_mask(EventMask::PROPERTY_CHANGE),
+                )?;
+                conn.flush()?;
+                role_sender.send((window, read_role(window)))?;
+            }
+            Err(RecvTimeoutError::Timeout) => (),
+            Err(RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+
+        while let Some(event) = conn.poll_for_event()? {
+            if let Event::PropertyNotify(event) = event
+                && event.atom == wm_window_role
+            {
+                role_sender.send((event.window, read_role(event.window)))?;
+            }
+        }
+    }
 }
 
 impl XwmHandler for State {
@@ -57,8 +174,17 @@ impl XwmHandler for State {
         &mut self.pinnacle.xwayland_state.as_mut().unwrap().xwm
     }
 
-    fn new_window(&mut self, _xwm: XwmId, _window: X11Surface) {
-        trace!(class = _window.class(), "XwmHandler::new_window");
+    fn new_window(&mut self, _xwm: XwmId, window: X11Surface) {
+        trace!(class = window.class(), "XwmHandler::new_window");
+
+        if let Some(role_watcher) = self
+            .pinnacle
+            .xwayland_state
+            .as_ref()
+            .and_then(|xwayland_state| xwayland_state.role_watcher.as_ref())
+        {
+            role_watcher.watch(window.window_id());
+        }
     }
 
     fn new_override_redirect_window(&mut self, _xwm: XwmId, _window: X11Surface) {
@@ -101,6 +227,8 @@ impl XwmHandler for State {
             state.foreign_toplevel_list_handle = Some(handle);
         });
 
+        crate::api::tag::add_dynamic_for_window(self, &unmapped.window);
+
         if unmapped.window.output(&self.pinnacle).is_some() {
             self.pinnacle.request_window_rules(&mut unmapped);
         } else if let Some(output) = self.pinnacle.focused_output()
@@ -135,6 +263,7 @@ impl XwmHandler for State {
         if let Some(output) = self.pinnacle.focused_output() {
             window.set_tags_to_output(output);
         }
+        self.pinnacle.window_tags_changed = true;
 
         self.pinnacle.map_window_to(&window, loc);
         self.pinnacle.raise_window(window.clone());
@@ -164,6 +293,9 @@ impl XwmHandler for State {
 
     fn destroyed_window(&mut self, _xwm: XwmId, surface: X11Surface) {
         trace!(class = surface.class(), "XwmHandler::destroyed_window");
+        if let Some(xwayland_state) = self.pinnacle.xwayland_state.as_mut() {
+            xwayland_state.roles.remove(&surface.window_id());
+        }
         self.remove_xwayland_window(surface);
     }
 
@@ -664,12 +796,21 @@ impl Pinnacle {
                             std::env::set_var("DISPLAY", format!(":{display_number}"));
                         }
 
+                        let role_watcher =
+                            RoleWatcher::spawn(display_number, &state.pinnacle.loop_handle)
+                                .inspect_err(|err| {
+                                    warn!("Failed to watch xwayland window roles: {err}")
+                                })
+                                .ok();
+
                         state.pinnacle.xwayland_state = Some(XwaylandState {
                             xwm: wm,
                             display_num: display_number,
                             client: client.clone(),
                             should_clients_self_scale: false,
                             current_scale: None,
+                            role_watcher,
+                            roles: HashMap::new(),
                         });
 
                         state.pinnacle.update_xwayland_scale();
@@ -1,16 +1,28 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 pub mod bind;
+pub mod click;
+pub mod compose;
+pub mod gesture;
+pub mod inject;
 pub mod libinput;
+pub mod sticky;
 
-use std::{any::Any, time::Duration};
+use std::{any::Any, collections::HashMap, time::Duration};
 
 use crate::{
-    focus::pointer::{PointerContents, PointerFocusTarget},
+    focus::{
+        keyboard::KeyboardFocusTarget,
+        pointer::{PointerContents, PointerFocusTarget},
+    },
     state::{Pinnacle, WithState},
     window::WindowElement,
 };
-use bind::BindState;
+use bind::{BindState, ModMask};
+use click::ClickTracker;
+use compose::{Compose, ComposeOutcome};
+use gesture::GestureState;
+use inject::SpareKey;
 use libinput::LibinputState;
 use smithay::{
     backend::{
@@ -24,7 +36,7 @@ use smithay::{
     },
     desktop::{WindowSurfaceType, layer_map_for_output, space::SpaceElement},
     input::{
-        keyboard::{FilterResult, keysyms},
+        keyboard::{FilterResult, ModifiersState, keysyms},
         pointer::{
             AxisFrame, ButtonEvent, GestureHoldBeginEvent, GestureHoldEndEvent,
             GesturePinchBeginEvent, GesturePinchEndEvent, GesturePinchUpdateEvent,
@@ -33,31 +45,147 @@ use smithay::{
         },
         touch,
     },
+    output::{Output, WeakOutput},
+    reexports::calloop::{
+        RegistrationToken,
+        timer::{TimeoutAction, Timer},
+    },
     utils::{Logical, Point, Rectangle, SERIAL_COUNTER},
     wayland::{
         compositor::{self, RegionAttributes, SurfaceAttributes},
         keyboard_shortcuts_inhibit::KeyboardShortcutsInhibitorSeat,
         pointer_constraints::{PointerConstraint, with_pointer_constraint},
         seat::WaylandFocus,
+        selection::primary_selection::clear_primary_selection,
         shell::wlr_layer,
     },
 };
+use sticky::StickyKeys;
 use tracing::{error, info};
+use xkbcommon::xkb::{Keycode, Keysym};
 
 use crate::state::State;
 
+const BTN_MIDDLE: u32 = 0x112;
+
 #[derive(Default, Debug)]
 pub struct InputState {
     pub bind_state: BindState,
     pub libinput_state: LibinputState,
+    /// Mouse buttons that act as something else.
+    pub button_map: HashMap<u32, ButtonMapTarget>,
+    /// The keybinds currently repeating because their key is held.
+    pub bind_repeat: Option<BindRepeat>,
+    /// How relative pointer motion is adjusted before being sent to clients.
+    pub relative_motion: RelativeMotion,
+    /// Modifiers latched or locked by sticky keys.
+    pub sticky_keys: StickyKeys,
+    /// How far in from a window's edges, in pixels, an interactive resize picks that edge.
+    ///
+    /// If `None`, the window is split into thirds.
+    pub resize_edge_threshold: Option<u32>,
+    /// Whether to center the pointer on the focused output until it's first moved.
+    pub pointer_default_position: bool,
+    /// Whether the pointer has been moved by a pointer device.
+    pub pointer_moved: bool,
+    /// The key and modifiers that stop the focused window from grabbing all input.
+    pub grab_all_input_escape: Option<(Keysym, ModMask)>,
+    /// Touchpad swipes and pinches bound by the config.
+    pub gestures: GestureState,
+    /// The output pointer motion is kept inside of.
+    pub confined_output: Option<WeakOutput>,
+    /// Compose key sequences.
+    pub compose: Compose,
+    /// The key composed keysyms missing from the layout are typed with.
+    pub spare_key: Option<SpareKey>,
+    /// Whether the pointer is warped to an output when it becomes focused.
+    pub warp_on_output_focus: bool,
+    /// Button presses, to tell double clicks apart.
+    pub clicks: ClickTracker,
+}
+
+/// Keybinds that are re-fired on a timer while their key is held.
+#[derive(Debug)]
+pub struct BindRepeat {
+    /// The held key.
+    pub key: Keysym,
+    /// The repeat timer.
+    pub token: RegistrationToken,
 }
 
 impl InputState {
     pub fn clear(&mut self) {
         self.bind_state.clear();
+        self.button_map.clear();
+        self.relative_motion = RelativeMotion::default();
+        self.resize_edge_threshold = None;
+        self.pointer_default_position = false;
+        self.grab_all_input_escape = None;
+        self.gestures.clear();
+        self.confined_output = None;
+        self.compose.set_enabled(false);
+        self.warp_on_output_focus = false;
+        self.clicks = ClickTracker::default();
+    }
+}
+
+/// Adjustments made to relative pointer motion, which games read
+/// when they lock the pointer.
+///
+/// This doesn't affect how the cursor itself moves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelativeMotion {
+    /// What relative motion is multiplied by.
+    pub scale: f64,
+    /// Which delta is sent as the accelerated one.
+    pub policy: RelativeMotionPolicy,
+}
+
+impl Default for RelativeMotion {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            policy: RelativeMotionPolicy::default(),
+        }
+    }
+}
+
+/// How relative pointer motion is delivered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeMotionPolicy {
+    /// Send libinput's accelerated and unaccelerated deltas as they are.
+    #[default]
+    Accelerated,
+    /// Send the unaccelerated delta in place of the accelerated one
+    /// for clients that only read the latter.
+    Unaccelerated,
+}
+
+impl RelativeMotion {
+    /// Returns the accelerated and unaccelerated deltas to send to clients.
+    pub fn apply(
+        &self,
+        delta: Point<f64, Logical>,
+        delta_unaccel: Point<f64, Logical>,
+    ) -> (Point<f64, Logical>, Point<f64, Logical>) {
+        let delta = match self.policy {
+            RelativeMotionPolicy::Accelerated => delta,
+            RelativeMotionPolicy::Unaccelerated => delta_unaccel,
+        };
+
+        (delta.upscale(self.scale), delta_unaccel.upscale(self.scale))
     }
 }
 
+/// What a remapped mouse button does instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonMapTarget {
+    /// Act as a different button.
+    Button(u32),
+    /// Trigger keybinds as if this key was pressed with these modifiers.
+    Keybind { key: Keysym, mods: ModifiersState },
+}
+
 impl InputState {
     pub fn new() -> Self {
         Default::default()
@@ -74,9 +202,45 @@ enum KeyAction {
     ReloadConfig,
     /// Prevent the key from being sent to clients.
     Suppress,
+    /// Handle a key pressed while the overview is open.
+    Overview(Keysym),
+    /// Type the result of a compose sequence.
+    Compose(Vec<Keysym>),
 }
 
 impl Pinnacle {
+    /// Returns whether the keyboard focus has an active shortcuts inhibitor
+    /// or is a window grabbing all input.
+    pub fn keyboard_shortcuts_inhibited(&self) -> bool {
+        if self.input_grabbing_window().is_some() {
+            return true;
+        }
+
+        self.seat
+            .get_keyboard()
+            .and_then(|keyboard| keyboard.current_focus())
+            .and_then(|focus| {
+                focus
+                    .wl_surface()
+                    .and_then(|surf| self.seat.keyboard_shortcuts_inhibitor_for_surface(&surf))
+            })
+            .is_some_and(|inhibitor| inhibitor.is_active())
+    }
+
+    /// Returns the keyboard focus if it's a window grabbing all input.
+    pub fn input_grabbing_window(&self) -> Option<WindowElement> {
+        let focus = self.seat.get_keyboard()?.current_focus()?;
+
+        match focus {
+            KeyboardFocusTarget::Window(window)
+                if window.with_state(|state| state.grab_all_input) =>
+            {
+                Some(window)
+            }
+            _ => None,
+        }
+    }
+
     /// Get the [`PointerFocusTarget`] under `point` along with its origin in the global space.
     pub fn pointer_contents_under<P>(&self, point: P) -> PointerContents
     where
@@ -116,6 +280,18 @@ impl Pinnacle {
             };
         }
 
+        // Windows in the overview are picked by the compositor, not interacted with
+        if self
+            .overview
+            .as_ref()
+            .is_some_and(|overview| &overview.output == output)
+        {
+            return PointerContents {
+                focus_under: None,
+                output_under: Some(output.downgrade()),
+            };
+        }
+
         let mut fullscreen_and_up_split_at = 0;
 
         let windows = self
@@ -242,6 +418,8 @@ impl State {
         self.pinnacle
             .idle_notifier_state
             .notify_activity(&self.pinnacle.seat);
+        self.pinnacle.notify_idle_dim_activity();
+        self.notify_dpms_activity();
 
         match event {
             InputEvent::DeviceAdded { device } => self.on_device_added(device),
@@ -335,6 +513,103 @@ impl State {
         }
     }
 
+    /// Warps the pointer to the center of the focused output.
+    ///
+    /// Does nothing if there are no outputs.
+    pub fn reset_pointer_position(&mut self) {
+        let Some(output_geo) = self
+            .pinnacle
+            .focused_output()
+            .and_then(|output| self.pinnacle.space.output_geometry(output))
+        else {
+            return;
+        };
+
+        let center = output_geo.loc.to_f64()
+            + Point::from((
+                output_geo.size.w as f64 / 2.0,
+                output_geo.size.h as f64 / 2.0,
+            ));
+
+        self.warp_cursor_to_global_loc(center);
+    }
+
+    /// Warps the pointer to the center of `window` if it isn't already over it.
+    ///
+    /// Windows spanning multiple outputs are centered on the part on their own output.
+    pub fn center_pointer_on_window(&mut self, window: &WindowElement) {
+        let Some(window_geo) = self.pinnacle.space.element_geometry(window) else {
+            return;
+        };
+
+        let Some(pointer) = self.pinnacle.seat.get_pointer() else {
+            return;
+        };
+
+        if window_geo.to_f64().contains(pointer.current_location()) {
+            return;
+        }
+
+        let target_geo = window
+            .output(&self.pinnacle)
+            .and_then(|output| self.pinnacle.space.output_geometry(&output))
+            .and_then(|output_geo| output_geo.intersection(window_geo))
+            .unwrap_or(window_geo);
+
+        let center = target_geo.loc.to_f64()
+            + Point::from((
+                target_geo.size.w as f64 / 2.0,
+                target_geo.size.h as f64 / 2.0,
+            ));
+
+        self.warp_cursor_to_global_loc(center);
+    }
+
+    /// Keeps pointer motion inside `output`, or lets it move freely again if `None`.
+    ///
+    /// If the pointer is outside the output, it's warped to the output's center.
+    pub fn confine_pointer_to_output(&mut self, output: Option<&Output>) {
+        self.pinnacle.input_state.confined_output = output.map(|output| output.downgrade());
+
+        let Some(output_geo) = self.pinnacle.pointer_confinement_geo() else {
+            return;
+        };
+
+        let pointer_loc = self
+            .pinnacle
+            .seat
+            .get_pointer()
+            .map(|pointer| pointer.current_location());
+
+        if pointer_loc.is_some_and(|loc| output_geo.to_f64().contains(loc)) {
+            return;
+        }
+
+        let center = output_geo.loc.to_f64()
+            + Point::from((
+                output_geo.size.w as f64 / 2.0,
+                output_geo.size.h as f64 / 2.0,
+            ));
+
+        self.warp_cursor_to_global_loc(center);
+    }
+
+    /// Sets whether the pointer is centered on the focused output until it's first moved,
+    /// centering it now if it hasn't been moved yet.
+    pub fn set_pointer_default_position(&mut self, enabled: bool) {
+        self.pinnacle.input_state.pointer_default_position = enabled;
+        self.apply_pointer_default_position();
+    }
+
+    /// Centers the pointer on the focused output if the config asked for it
+    /// and the pointer hasn't been moved yet.
+    pub fn apply_pointer_default_position(&mut self) {
+        let input_state = &self.pinnacle.input_state;
+        if input_state.pointer_default_position && !input_state.pointer_moved {
+            self.reset_pointer_position();
+        }
+    }
+
     fn on_device_added(&mut self, device: impl Device) {
         if device.has_capability(DeviceCapability::Touch)
             && self.pinnacle.seat.get_touch().is_none()
@@ -358,30 +633,45 @@ impl State {
     }
 
     fn on_keyboard<I: InputBackend>(&mut self, event: I::KeyboardKeyEvent) {
-        let _span = tracy_client::span!("State::on_keyboard");
+        self.keyboard_key(event.key_code(), event.state(), event.time_msec());
+    }
+
+    /// Handles a key event, running keybinds before sending it to the focused client.
+    pub fn keyboard_key(&mut self, keycode: Keycode, press_state: KeyState, time: u32) {
+        let _span = tracy_client::span!("State::keyboard_key");
 
         let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
             return;
         };
 
         let serial = SERIAL_COUNTER.next_serial();
-        let time = event.time_msec();
-        let press_state = event.state();
 
-        let shortcuts_inhibited = keyboard
-            .current_focus()
-            .and_then(|focus| {
-                focus.wl_surface().and_then(|surf| {
-                    self.pinnacle
-                        .seat
-                        .keyboard_shortcuts_inhibitor_for_surface(&surf)
-                })
-            })
-            .is_some_and(|inhibitor| inhibitor.is_active());
+        let mut sticky_release = Vec::new();
+
+        if self.pinnacle.input_state.sticky_keys.enabled {
+            let is_modifier = self.is_sticky_modifier(keycode);
+            let outcome = self.pinnacle.input_state.sticky_keys.key(
+                keycode,
+                is_modifier,
+                press_state == KeyState::Pressed,
+            );
+
+            sticky_release = outcome.release;
+
+            if !outcome.process {
+                for keycode in sticky_release {
+                    self.inject_key(keycode, KeyState::Released);
+                }
+                return;
+            }
+        }
+
+        let shortcuts_inhibited = self.pinnacle.keyboard_shortcuts_inhibited();
+        let input_grabbing_window = self.pinnacle.input_grabbing_window();
 
         let action = keyboard.input(
             self,
-            event.key_code(),
+            keycode,
             press_state,
             serial,
             time,
@@ -398,6 +688,17 @@ impl State {
                     return FilterResult::Forward;
                 };
 
+                if press_state == KeyState::Pressed
+                    && let Some(window) = input_grabbing_window.as_ref()
+                    && let Some((escape_key, escape_mods)) =
+                        state.pinnacle.input_state.grab_all_input_escape
+                    && escape_key == raw_sym
+                    && escape_mods.matches(*modifiers)
+                {
+                    window.with_state_mut(|state| state.grab_all_input = false);
+                    return FilterResult::Intercept(KeyAction::Suppress);
+                }
+
                 let edge = match press_state {
                     KeyState::Released => bind::Edge::Release,
                     KeyState::Pressed => bind::Edge::Press,
@@ -407,13 +708,36 @@ impl State {
                     raw_sym,
                     *modifiers,
                     edge,
-                    state.pinnacle.input_state.bind_state.current_layer(),
+                    &state.pinnacle.input_state.bind_state.active_layers(),
                     shortcuts_inhibited,
                     !state.pinnacle.lock_state.is_unlocked(),
                 );
 
+                state.update_bind_repeat(raw_sym, edge);
+
+                let overview_open =
+                    state.pinnacle.overview.is_some() && state.pinnacle.lock_state.is_unlocked();
+
                 match bind_action {
-                    bind::BindAction::Forward => FilterResult::Forward,
+                    bind::BindAction::Forward if overview_open => match edge {
+                        bind::Edge::Press => FilterResult::Intercept(KeyAction::Overview(raw_sym)),
+                        bind::Edge::Release => FilterResult::Intercept(KeyAction::Suppress),
+                    },
+                    bind::BindAction::Forward => {
+                        match state.pinnacle.input_state.compose.key(
+                            keycode,
+                            keysym.modified_sym(),
+                            press_state == KeyState::Pressed,
+                        ) {
+                            ComposeOutcome::Forward => FilterResult::Forward,
+                            ComposeOutcome::Suppress => {
+                                FilterResult::Intercept(KeyAction::Suppress)
+                            }
+                            ComposeOutcome::Composed(keysyms) => {
+                                FilterResult::Intercept(KeyAction::Compose(keysyms))
+                            }
+                        }
+                    }
                     bind::BindAction::Suppress => FilterResult::Intercept(KeyAction::Suppress),
                     bind::BindAction::Quit => FilterResult::Intercept(KeyAction::Quit),
                     bind::BindAction::ReloadConfig => {
@@ -429,13 +753,11 @@ impl State {
                     self.pinnacle.shutdown();
                 }
                 KeyAction::SwitchVt(vt) => {
+                    self.stop_bind_repeat();
                     self.switch_vt(vt);
-                    self.pinnacle
-                        .input_state
-                        .bind_state
-                        .keybinds
-                        .last_pressed_triggered_binds
-                        .clear();
+                    let keybinds = &mut self.pinnacle.input_state.bind_state.keybinds;
+                    keybinds.last_pressed_triggered_binds.clear();
+                    keybinds.pressed_binds.clear();
                 }
                 KeyAction::ReloadConfig => {
                     info!("Reloading config");
@@ -444,13 +766,118 @@ impl State {
                         .expect("failed to restart config");
                 }
                 KeyAction::Suppress => (),
+                KeyAction::Overview(keysym) => self.overview_key(keysym),
+                KeyAction::Compose(keysyms) => {
+                    for keysym in keysyms {
+                        self.type_keysym(keysym);
+                    }
+                }
             }
         }
+
+        // Latched modifiers only apply to one key
+        for keycode in sticky_release {
+            self.inject_key(keycode, KeyState::Released);
+        }
+    }
+
+    /// Starts repeating keybinds that fired on a press of `key` and stops repeating
+    /// when the key is released or another key is pressed.
+    fn update_bind_repeat(&mut self, key: Keysym, edge: bind::Edge) {
+        match edge {
+            bind::Edge::Press => {
+                self.stop_bind_repeat();
+
+                let bind_ids = std::mem::take(
+                    &mut self
+                        .pinnacle
+                        .input_state
+                        .bind_state
+                        .keybinds
+                        .triggered_repeat_binds,
+                );
+
+                if !bind_ids.is_empty() {
+                    self.start_bind_repeat(key, bind_ids);
+                }
+            }
+            bind::Edge::Release => {
+                let is_repeating_key = self
+                    .pinnacle
+                    .input_state
+                    .bind_repeat
+                    .as_ref()
+                    .is_some_and(|repeat| repeat.key == key);
+
+                if is_repeating_key {
+                    self.stop_bind_repeat();
+                }
+            }
+        }
+    }
+
+    fn start_bind_repeat(&mut self, key: Keysym, bind_ids: Vec<u32>) {
+        let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
+            return;
+        };
+
+        let rate = keyboard.repeat_rate();
+        let delay = keyboard.repeat_delay();
+
+        if rate <= 0 {
+            return;
+        }
+
+        let interval = Duration::from_secs_f64(1.0 / rate as f64);
+        let timer = Timer::from_duration(Duration::from_millis(delay.max(0) as u64));
+
+        let token = self
+            .pinnacle
+            .loop_handle
+            .insert_source(timer, move |_, _, state| {
+                // Focus may have changed since the key was pressed, so check
+                // inhibitors and the lock against the current focus
+                let shortcuts_inhibited = state.pinnacle.keyboard_shortcuts_inhibited();
+                let is_locked = !state.pinnacle.lock_state.is_unlocked();
+                let active_layers = state.pinnacle.input_state.bind_state.active_layers();
+
+                let fired = state.pinnacle.input_state.bind_state.keybinds.repeat_press(
+                    &bind_ids,
+                    &active_layers,
+                    shortcuts_inhibited,
+                    is_locked,
+                );
+
+                if fired {
+                    TimeoutAction::ToDuration(interval)
+                } else {
+                    state.pinnacle.input_state.bind_repeat.take();
+                    TimeoutAction::Drop
+                }
+            });
+
+        match token {
+            Ok(token) => self.pinnacle.input_state.bind_repeat = Some(BindRepeat { key, token }),
+            Err(err) => error!("Failed to start keybind repeat: {err}"),
+        }
+    }
+
+    /// Stops repeating any held keybind.
+    pub fn stop_bind_repeat(&mut self) {
+        if let Some(repeat) = self.pinnacle.input_state.bind_repeat.take() {
+            self.pinnacle.loop_handle.remove(repeat.token);
+        }
     }
 
     fn on_pointer_button<I: InputBackend>(&mut self, event: I::PointerButtonEvent) {
         let _span = tracy_client::span!("State::on_pointer_button");
 
+        self.pointer_button(event.button_code(), event.state(), event.time_msec());
+    }
+
+    /// Handles a mouse button press or release, running binds and
+    /// sending the button to the client under the pointer.
+    pub fn pointer_button(&mut self, button: u32, button_state: ButtonState, time: u32) {
         let Some(pointer) = self.pinnacle.seat.get_pointer() else {
             return;
         };
@@ -460,10 +887,6 @@ impl State {
 
         let serial = SERIAL_COUNTER.next_serial();
 
-        let button = event.button_code();
-
-        let button_state = event.state();
-
         let pointer_loc = pointer.current_location();
 
         let mods = keyboard.modifier_state();
@@ -473,12 +896,59 @@ impl State {
             ButtonState::Pressed => bind::Edge::Press,
         };
 
-        let current_layer = self.pinnacle.input_state.bind_state.current_layer();
+        let active_layers = self.pinnacle.input_state.bind_state.active_layers();
+
+        let button = match self.pinnacle.input_state.button_map.get(&button).copied() {
+            None => button,
+            Some(ButtonMapTarget::Button(button)) => button,
+            Some(ButtonMapTarget::Keybind { key, mods }) => {
+                // The button never reaches clients or mousebinds
+                let bind_action = self.pinnacle.input_state.bind_state.keybinds.key(
+                    key,
+                    mods,
+                    edge,
+                    &active_layers,
+                    false,
+                    !self.pinnacle.lock_state.is_unlocked(),
+                );
+
+                self.update_bind_repeat(key, edge);
+
+                match bind_action {
+                    bind::BindAction::Forward | bind::BindAction::Suppress => (),
+                    bind::BindAction::Quit => self.pinnacle.shutdown(),
+                    bind::BindAction::ReloadConfig => {
+                        info!("Reloading config");
+                        self.pinnacle
+                            .start_config(false)
+                            .expect("failed to restart config");
+                    }
+                }
+
+                return;
+            }
+        };
+
+        if self.pinnacle.overview.is_some() && self.pinnacle.lock_state.is_unlocked() {
+            if button_state == ButtonState::Pressed {
+                self.overview_click(pointer_loc);
+            }
+            return;
+        }
+
+        let double_click = button_state == ButtonState::Pressed
+            && self
+                .pinnacle
+                .input_state
+                .clicks
+                .press(button, Duration::from_millis(time.into()));
+
         let bind_action = self.pinnacle.input_state.bind_state.mousebinds.btn(
             button,
             mods,
             edge,
-            current_layer,
+            double_click,
+            &active_layers,
             !self.pinnacle.lock_state.is_unlocked(),
         );
 
@@ -547,13 +1017,22 @@ impl State {
             }
         };
 
+        // Clients paste the primary selection themselves when middle clicked,
+        // so the only way to stop them is to have nothing for them to paste
+        if button == BTN_MIDDLE
+            && button_state == ButtonState::Pressed
+            && !self.pinnacle.config.middle_click_paste
+        {
+            clear_primary_selection(&self.pinnacle.display_handle, &self.pinnacle.seat);
+        }
+
         pointer.button(
             self,
             &ButtonEvent {
                 button,
                 state: button_state,
                 serial,
-                time: event.time_msec(),
+                time,
             },
         );
         pointer.frame(self);
@@ -615,6 +1094,9 @@ impl State {
     ) {
         let _span = tracy_client::span!("State::on_pointer_motion_absolute");
 
+        self.pinnacle.notify_cursor_motion();
+        self.pinnacle.input_state.pointer_moved = true;
+
         let Some(pointer) = self.pinnacle.seat.get_pointer() else {
             error!("Pointer motion absolute received with no pointer on seat");
             return;
@@ -628,7 +1110,10 @@ impl State {
             unreachable!("output should have a geometry as it was mapped");
         };
 
-        let pointer_loc = event.position_transformed(output_geo.size) + output_geo.loc.to_f64();
+        let pointer_loc = self.pinnacle.confine_pointer_loc(
+            event.position_transformed(output_geo.size) + output_geo.loc.to_f64(),
+        );
+
         let serial = SERIAL_COUNTER.next_serial();
 
         let new_contents = self.pinnacle.pointer_contents_under(pointer_loc);
@@ -659,7 +1144,23 @@ impl State {
     }
 
     fn on_pointer_motion<I: InputBackend>(&mut self, event: I::PointerMotionEvent) {
-        let _span = tracy_client::span!("State::on_pointer_motion");
+        self.pointer_motion(event.delta(), event.delta_unaccel(), event.time());
+    }
+
+    /// Handles relative pointer motion, moving the pointer unless it's locked and
+    /// sending relative motion to the client under it.
+    ///
+    /// `time` is in microseconds.
+    pub fn pointer_motion(
+        &mut self,
+        delta: Point<f64, Logical>,
+        delta_unaccel: Point<f64, Logical>,
+        time: u64,
+    ) {
+        let _span = tracy_client::span!("State::pointer_motion");
+
+        self.pinnacle.notify_cursor_motion();
+        self.pinnacle.input_state.pointer_moved = true;
 
         let Some(pointer) = self.pinnacle.seat.get_pointer() else {
             error!("Pointer motion received with no pointer on seat");
@@ -711,13 +1212,19 @@ impl State {
                 });
 
                 if pointer_locked {
+                    let (delta, delta_unaccel) = self
+                        .pinnacle
+                        .input_state
+                        .relative_motion
+                        .apply(delta, delta_unaccel);
+
                     pointer.relative_motion(
                         self,
                         Some((surface.clone(), surface_loc)),
                         &RelativeMotionEvent {
-                            delta: event.delta(),
-                            delta_unaccel: event.delta_unaccel(),
-                            utime: event.time(),
+                            delta,
+                            delta_unaccel,
+                            utime: time,
                         },
                     );
 
@@ -728,7 +1235,7 @@ impl State {
             }
         }
 
-        let mut new_pointer_loc = pointer_loc + event.delta();
+        let mut new_pointer_loc = pointer_loc + delta;
 
         if self
             .pinnacle
@@ -746,6 +1253,8 @@ impl State {
             new_pointer_loc = constrain_point_inside_rects(new_pointer_loc, output_locs);
         }
 
+        new_pointer_loc = self.pinnacle.confine_pointer_loc(new_pointer_loc);
+
         if let Some((focus, surf_loc, region)) = &pointer_confined_to {
             let region = region
                 .clone()
@@ -820,17 +1329,23 @@ impl State {
             &MotionEvent {
                 location: new_pointer_loc,
                 serial: SERIAL_COUNTER.next_serial(),
-                time: event.time_msec(),
+                time: (time / 1000) as u32,
             },
         );
 
+        let (delta, delta_unaccel) = self
+            .pinnacle
+            .input_state
+            .relative_motion
+            .apply(delta, delta_unaccel);
+
         pointer.relative_motion(
             self,
             new_contents.focus_under,
             &RelativeMotionEvent {
-                delta: event.delta(),
-                delta_unaccel: event.delta_unaccel(),
-                utime: event.time(),
+                delta,
+                delta_unaccel,
+                utime: time,
             },
         );
 
@@ -838,6 +1353,45 @@ impl State {
     }
 
     fn on_gesture_swipe_begin<I: InputBackend>(&mut self, event: I::GestureSwipeBeginEvent) {
+        self.gesture_swipe_begin(event.fingers(), event.time_msec());
+    }
+
+    fn on_gesture_swipe_update<I: InputBackend>(&mut self, event: I::GestureSwipeUpdateEvent) {
+        use smithay::backend::input::GestureSwipeUpdateEvent as _;
+
+        self.gesture_swipe_update(event.delta(), event.time_msec());
+    }
+
+    fn on_gesture_swipe_end<I: InputBackend>(&mut self, event: I::GestureSwipeEndEvent) {
+        self.gesture_swipe_end(event.cancelled(), event.time_msec());
+    }
+
+    fn on_gesture_pinch_begin<I: InputBackend>(&mut self, event: I::GesturePinchBeginEvent) {
+        self.gesture_pinch_begin(event.fingers(), event.time_msec());
+    }
+
+    fn on_gesture_pinch_update<I: InputBackend>(&mut self, event: I::GesturePinchUpdateEvent) {
+        use smithay::backend::input::GesturePinchUpdateEvent as _;
+
+        self.gesture_pinch_update(
+            event.delta(),
+            event.scale(),
+            event.rotation(),
+            event.time_msec(),
+        );
+    }
+
+    fn on_gesture_pinch_end<I: InputBackend>(&mut self, event: I::GesturePinchEndEvent) {
+        self.gesture_pinch_end(event.cancelled(), event.time_msec());
+    }
+
+    /// Handles the start of a swipe, sending it to the client under the pointer
+    /// unless it's bound.
+    pub fn gesture_swipe_begin(&mut self, fingers: u32, time: u32) {
+        if self.pinnacle.input_state.gestures.swipe_begin(fingers) {
+            return;
+        }
+
         let Some(pointer) = self.pinnacle.seat.get_pointer() else {
             return;
         };
@@ -846,29 +1400,31 @@ impl State {
             self,
             &GestureSwipeBeginEvent {
                 serial: SERIAL_COUNTER.next_serial(),
-                time: event.time_msec(),
-                fingers: event.fingers(),
+                time,
+                fingers,
             },
         );
     }
 
-    fn on_gesture_swipe_update<I: InputBackend>(&mut self, event: I::GestureSwipeUpdateEvent) {
+    /// Handles a swipe moving by `delta`.
+    pub fn gesture_swipe_update(&mut self, delta: Point<f64, Logical>, time: u32) {
+        if self.pinnacle.input_state.gestures.swipe_update(delta) {
+            return;
+        }
+
         let Some(pointer) = self.pinnacle.seat.get_pointer() else {
             return;
         };
 
-        use smithay::backend::input::GestureSwipeUpdateEvent as _;
-
-        pointer.gesture_swipe_update(
-            self,
-            &GestureSwipeUpdateEvent {
-                time: event.time_msec(),
-                delta: event.delta(),
-            },
-        );
+        pointer.gesture_swipe_update(self, &GestureSwipeUpdateEvent { time, delta });
     }
 
-    fn on_gesture_swipe_end<I: InputBackend>(&mut self, event: I::GestureSwipeEndEvent) {
+    /// Handles the end of a swipe, running its binds if it's bound and wasn't cancelled.
+    pub fn gesture_swipe_end(&mut self, cancelled: bool, time: u32) {
+        if self.pinnacle.input_state.gestures.swipe_end(cancelled) {
+            return;
+        }
+
         let Some(pointer) = self.pinnacle.seat.get_pointer() else {
             return;
         };
@@ -877,13 +1433,19 @@ impl State {
             self,
             &GestureSwipeEndEvent {
                 serial: SERIAL_COUNTER.next_serial(),
-                time: event.time_msec(),
-                cancelled: event.cancelled(),
+                time,
+                cancelled,
             },
         );
     }
 
-    fn on_gesture_pinch_begin<I: InputBackend>(&mut self, event: I::GesturePinchBeginEvent) {
+    /// Handles the start of a pinch, sending it to the client under the pointer
+    /// unless it's bound.
+    pub fn gesture_pinch_begin(&mut self, fingers: u32, time: u32) {
+        if self.pinnacle.input_state.gestures.pinch_begin(fingers) {
+            return;
+        }
+
         let Some(pointer) = self.pinnacle.seat.get_pointer() else {
             return;
         };
@@ -892,31 +1454,47 @@ impl State {
             self,
             &GesturePinchBeginEvent {
                 serial: SERIAL_COUNTER.next_serial(),
-                time: event.time_msec(),
-                fingers: event.fingers(),
+                time,
+                fingers,
             },
         );
     }
 
-    fn on_gesture_pinch_update<I: InputBackend>(&mut self, event: I::GesturePinchUpdateEvent) {
+    /// Handles a pinch moving, scaling, or rotating.
+    ///
+    /// `scale` is relative to where the fingers started.
+    pub fn gesture_pinch_update(
+        &mut self,
+        delta: Point<f64, Logical>,
+        scale: f64,
+        rotation: f64,
+        time: u32,
+    ) {
+        if self.pinnacle.input_state.gestures.pinch_update(scale) {
+            return;
+        }
+
         let Some(pointer) = self.pinnacle.seat.get_pointer() else {
             return;
         };
 
-        use smithay::backend::input::GesturePinchUpdateEvent as _;
-
         pointer.gesture_pinch_update(
             self,
             &GesturePinchUpdateEvent {
-                time: event.time_msec(),
-                delta: event.delta(),
-                scale: event.scale(),
-                rotation: event.rotation(),
+                time,
+                delta,
+                scale,
+                rotation,
             },
         );
     }
 
-    fn on_gesture_pinch_end<I: InputBackend>(&mut self, event: I::GesturePinchEndEvent) {
+    /// Handles the end of a pinch, running its binds if it's bound and wasn't cancelled.
+    pub fn gesture_pinch_end(&mut self, cancelled: bool, time: u32) {
+        if self.pinnacle.input_state.gestures.pinch_end(cancelled) {
+            return;
+        }
+
         let Some(pointer) = self.pinnacle.seat.get_pointer() else {
             return;
         };
@@ -925,8 +1503,8 @@ impl State {
             self,
             &GesturePinchEndEvent {
                 serial: SERIAL_COUNTER.next_serial(),
-                time: event.time_msec(),
-                cancelled: event.cancelled(),
+                time,
+                cancelled,
             },
         );
     }
@@ -1095,6 +1673,33 @@ impl State {
 /// Clamp the given point within the given rects.
 ///
 /// This returns the nearest point inside the rects.
+impl Pinnacle {
+    /// Moves `loc` inside the output the pointer is confined to, if any.
+    pub fn confine_pointer_loc(&mut self, loc: Point<f64, Logical>) -> Point<f64, Logical> {
+        match self.pointer_confinement_geo() {
+            Some(output_geo) => constrain_point_inside_rects(loc, [output_geo]),
+            None => loc,
+        }
+    }
+
+    /// Returns the geometry of the output the pointer is confined to, if any.
+    ///
+    /// Confinement is released if that output was disconnected or disabled.
+    fn pointer_confinement_geo(&mut self) -> Option<Rectangle<i32, Logical>> {
+        let confined_output = self.input_state.confined_output.as_ref()?;
+
+        let output_geo = confined_output
+            .upgrade()
+            .and_then(|output| self.space.output_geometry(&output));
+
+        if output_geo.is_none() {
+            self.input_state.confined_output = None;
+        }
+
+        output_geo
+    }
+}
+
 fn constrain_point_inside_rects(
     pos: Point<f64, Logical>,
     rects: impl IntoIterator<Item = Rectangle<i32, Logical>>,
@@ -1132,6 +1737,36 @@ fn constrain_point_inside_rects(
 mod tests {
     use super::*;
 
+    #[test]
+    fn relative_motion_default_passes_deltas_through() {
+        let (delta, delta_unaccel) =
+            RelativeMotion::default().apply((3.0, -2.0).into(), (1.5, -1.0).into());
+        assert_eq!(delta, (3.0, -2.0).into());
+        assert_eq!(delta_unaccel, (1.5, -1.0).into());
+    }
+
+    #[test]
+    fn relative_motion_scales_deltas() {
+        let relative_motion = RelativeMotion {
+            scale: 0.5,
+            policy: RelativeMotionPolicy::Accelerated,
+        };
+        let (delta, delta_unaccel) = relative_motion.apply((4.0, -2.0).into(), (2.0, -1.0).into());
+        assert_eq!(delta, (2.0, -1.0).into());
+        assert_eq!(delta_unaccel, (1.0, -0.5).into());
+    }
+
+    #[test]
+    fn relative_motion_unaccelerated_policy_replaces_accelerated_delta() {
+        let relative_motion = RelativeMotion {
+            scale: 2.0,
+            policy: RelativeMotionPolicy::Unaccelerated,
+        };
+        let (delta, delta_unaccel) = relative_motion.apply((4.0, -2.0).into(), (2.0, -1.0).into());
+        assert_eq!(delta, (4.0, -2.0).into());
+        assert_eq!(delta_unaccel, (4.0, -2.0).into());
+    }
+
     fn rect(loc: (i32, i32), size: (i32, i32)) -> Rectangle<i32, Logical> {
         Rectangle::new(loc.into(), size.into())
     }
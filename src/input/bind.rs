@@ -1,6 +1,6 @@
 use std::{
     cell::RefCell,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     rc::{Rc, Weak},
     sync::atomic::{AtomicU32, Ordering},
 };
@@ -15,6 +15,8 @@ static BIND_ID_COUNTER: AtomicU32 = AtomicU32::new(0);
 #[derive(Debug, Default)]
 pub struct BindState {
     pub layer_stack: Vec<String>,
+    /// Layers that let binds on the layers below them stay in effect.
+    pub passthrough_layers: HashSet<String>,
     pub keybinds: Keybinds,
     pub mousebinds: Mousebinds,
 }
@@ -25,6 +27,7 @@ impl BindState {
         self.keybinds.keysym_map.clear();
         self.mousebinds.id_map.clear();
         self.mousebinds.button_map.clear();
+        self.passthrough_layers.clear();
     }
 
     pub fn enter_layer(&mut self, layer: Option<String>) {
@@ -37,14 +40,36 @@ impl BindState {
         }
     }
 
-    pub fn current_layer(&self) -> Option<String> {
-        self.layer_stack.last().cloned()
+    /// Returns the layers whose binds are in effect, from highest to lowest priority.
+    ///
+    /// The topmost layer is always in effect. Layers below it, down to the default layer,
+    /// are in effect as long as every layer above them is a passthrough layer.
+    pub fn active_layers(&self) -> Vec<Option<String>> {
+        let mut layers = Vec::new();
+
+        for layer in self.layer_stack.iter().rev() {
+            layers.push(Some(layer.clone()));
+            if !self.passthrough_layers.contains(layer) {
+                return layers;
+            }
+        }
+
+        layers.push(None);
+        layers
     }
 
     pub fn enter_previous_layer(&mut self) {
         self.layer_stack.pop();
     }
 
+    pub fn set_layer_passthrough(&mut self, layer: String, passthrough: bool) {
+        if passthrough {
+            self.passthrough_layers.insert(layer);
+        } else {
+            self.passthrough_layers.remove(&layer);
+        }
+    }
+
     pub fn set_bind_group(&self, bind_id: u32, group: String) {
         if let Some(bind) = self.keybinds.id_map.get(&bind_id) {
             bind.borrow_mut().bind_data.group = group;
@@ -77,6 +102,13 @@ impl BindState {
         }
     }
 
+    pub fn set_repeat(&self, bind_id: u32, repeat: bool) {
+        // Only keys repeat
+        if let Some(bind) = self.keybinds.id_map.get(&bind_id) {
+            bind.borrow_mut().repeat = repeat;
+        }
+    }
+
     pub fn set_allow_when_locked(&self, bind_id: u32, allow_when_locked: bool) {
         if let Some(bind) = self.keybinds.id_map.get(&bind_id) {
             bind.borrow_mut().bind_data.allow_when_locked = allow_when_locked;
@@ -86,6 +118,20 @@ impl BindState {
     }
 }
 
+/// Returns the highest priority active layer out of the layers of matching binds.
+///
+/// Only binds on this layer fire so binds on a layer shadow the same binds
+/// on passed-through layers below it.
+fn highest_matching_layer(
+    layers: impl Iterator<Item = Option<String>>,
+    active_layers: &[Option<String>],
+) -> Option<Option<String>> {
+    layers
+        .filter_map(|layer| active_layers.iter().position(|active| *active == layer))
+        .min()
+        .map(|idx| active_layers[idx].clone())
+}
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum Edge {
     Press,
@@ -159,6 +205,8 @@ pub struct Keybind {
     sender: UnboundedSender<Edge>,
     pub recv: Option<UnboundedReceiver<Edge>>,
     pub has_on_press: bool,
+    /// Whether presses are re-sent while the key is held.
+    pub repeat: bool,
 }
 
 #[derive(Debug, Default)]
@@ -167,6 +215,13 @@ pub struct Keybinds {
     keysym_map: IndexMap<Keysym, Vec<Weak<RefCell<Keybind>>>>,
 
     pub last_pressed_triggered_binds: HashMap<Keysym, Vec<u32>>,
+    /// Binds whose on-press callbacks fired and that haven't been released yet.
+    ///
+    /// These always get their release, even if shortcuts became inhibited
+    /// or the session was locked while the key was held.
+    pub pressed_binds: HashSet<u32>,
+    /// Repeating binds whose on-press callbacks fired on the last key press.
+    pub triggered_repeat_binds: Vec<u32>,
 }
 
 impl Keybinds {
@@ -178,7 +233,7 @@ impl Keybinds {
         key: Keysym,
         mods: ModifiersState,
         edge: Edge,
-        current_layer: Option<String>,
+        active_layers: &[Option<String>],
         shortcuts_inhibited: bool,
         is_locked: bool,
     ) -> BindAction {
@@ -195,6 +250,14 @@ impl Keybinds {
                     let Entry::Occupied(kb_entry) = keybind else {
                         continue;
                     };
+                    if self.pressed_binds.remove(&bind_id) {
+                        bind_action = BindAction::Suppress;
+                        let sent = kb_entry.get().borrow().sender.send(Edge::Release).is_ok();
+                        if !sent {
+                            kb_entry.shift_remove();
+                        }
+                        continue;
+                    }
                     if kb_entry.get().borrow().bind_data.is_quit_bind {
                         return BindAction::Quit;
                     }
@@ -225,6 +288,20 @@ impl Keybinds {
 
         let mut should_clear_releases = false;
 
+        self.triggered_repeat_binds.clear();
+
+        let firing_layer = highest_matching_layer(
+            keybinds
+                .iter()
+                .filter_map(Weak::upgrade)
+                .filter_map(|keybind| {
+                    let keybind = keybind.borrow();
+                    (keybind.has_on_press && keybind.bind_data.mods.matches(mods))
+                        .then(|| keybind.bind_data.layer.clone())
+                }),
+            active_layers,
+        );
+
         keybinds.retain(|keybind| {
             let Some(keybind) = keybind.upgrade() else {
                 return false;
@@ -232,7 +309,7 @@ impl Keybinds {
 
             let keybind = keybind.borrow();
 
-            let same_layer = current_layer == keybind.bind_data.layer;
+            let same_layer = firing_layer.as_ref() == Some(&keybind.bind_data.layer);
 
             if let BindAction::Quit | BindAction::ReloadConfig = bind_action {
                 return true;
@@ -265,6 +342,14 @@ impl Keybinds {
                 {
                     retain = keybind.sender.send(edge).is_ok();
                     bind_action = BindAction::Suppress;
+
+                    if retain {
+                        self.pressed_binds.insert(keybind.bind_data.id);
+                    }
+
+                    if retain && keybind.repeat {
+                        self.triggered_repeat_binds.push(keybind.bind_data.id);
+                    }
                 };
 
                 retain
@@ -274,13 +359,52 @@ impl Keybinds {
         });
 
         if should_clear_releases {
+            // Binds that are still held keep their pending release
+            let pressed_binds = &self.pressed_binds;
             self.last_pressed_triggered_binds
-                .retain(|keysym, _| *keysym == key);
+                .retain(|keysym, bind_ids| {
+                    bind_ids.retain(|bind_id| *keysym == key || pressed_binds.contains(bind_id));
+                    !bind_ids.is_empty()
+                });
         }
 
         bind_action
     }
 
+    /// Re-sends a press to the given binds while their key is held.
+    ///
+    /// Returns whether any of them fired.
+    pub fn repeat_press(
+        &mut self,
+        bind_ids: &[u32],
+        active_layers: &[Option<String>],
+        shortcuts_inhibited: bool,
+        is_locked: bool,
+    ) -> bool {
+        if shortcuts_inhibited {
+            return false;
+        }
+
+        let mut fired = false;
+
+        for bind_id in bind_ids {
+            let Some(keybind) = self.id_map.get(bind_id) else {
+                continue;
+            };
+            let keybind = keybind.borrow();
+
+            if !active_layers.contains(&keybind.bind_data.layer)
+                || (is_locked && !keybind.bind_data.allow_when_locked)
+            {
+                continue;
+            }
+
+            fired |= keybind.sender.send(Edge::Press).is_ok();
+        }
+
+        fired
+    }
+
     pub fn add_keybind(
         &mut self,
         key: Keysym,
@@ -311,6 +435,7 @@ impl Keybinds {
             sender,
             recv: Some(recv),
             has_on_press: false,
+            repeat: false,
         }));
 
         assert!(
@@ -340,12 +465,20 @@ impl Keybinds {
 
 // Mousebinds
 
+/// A mousebind press or release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MouseEdge {
+    pub edge: Edge,
+    /// Whether this is a press that completed a double click.
+    pub double_click: bool,
+}
+
 #[derive(Debug)]
 pub struct Mousebind {
     pub bind_data: BindData,
     pub button: u32,
-    sender: UnboundedSender<Edge>,
-    pub recv: Option<UnboundedReceiver<Edge>>,
+    sender: UnboundedSender<MouseEdge>,
+    pub recv: Option<UnboundedReceiver<MouseEdge>>,
     pub has_on_press: bool,
 }
 
@@ -363,13 +496,16 @@ impl Mousebinds {
     ///
     /// Returns whether the button should be suppressed (not sent to the client).
     ///
+    /// `double_click` is whether a press completed a double click.
+    ///
     /// Named `btn` and not `button` because Rust Analyzer does some weird things in `input.rs`
     pub fn btn(
         &mut self,
         button: u32,
         mods: ModifiersState,
         edge: Edge,
-        current_layer: Option<String>,
+        double_click: bool,
+        active_layers: &[Option<String>],
         is_locked: bool,
     ) -> BindAction {
         let Some(mousebinds) = self.button_map.get_mut(&button) else {
@@ -397,7 +533,15 @@ impl Mousebinds {
                     if mb_entry.get().borrow().has_on_press {
                         bind_action = BindAction::Suppress;
                     }
-                    let sent = mb_entry.get().borrow().sender.send(Edge::Release).is_ok();
+                    let sent = mb_entry
+                        .get()
+                        .borrow()
+                        .sender
+                        .send(MouseEdge {
+                            edge: Edge::Release,
+                            double_click: false,
+                        })
+                        .is_ok();
                     if !sent {
                         mb_entry.shift_remove();
                     }
@@ -413,6 +557,18 @@ impl Mousebinds {
 
         let mut should_clear_releases = false;
 
+        let firing_layer = highest_matching_layer(
+            mousebinds
+                .iter()
+                .filter_map(Weak::upgrade)
+                .filter_map(|mousebind| {
+                    let mousebind = mousebind.borrow();
+                    (mousebind.has_on_press && mousebind.bind_data.mods.matches(mods))
+                        .then(|| mousebind.bind_data.layer.clone())
+                }),
+            active_layers,
+        );
+
         mousebinds.retain(|mousebind| {
             let Some(mousebind) = mousebind.upgrade() else {
                 return false;
@@ -420,7 +576,7 @@ impl Mousebinds {
 
             let mousebind = mousebind.borrow();
 
-            let same_layer = current_layer == mousebind.bind_data.layer;
+            let same_layer = firing_layer.as_ref() == Some(&mousebind.bind_data.layer);
 
             if let BindAction::Quit | BindAction::ReloadConfig = bind_action {
                 return true;
@@ -451,7 +607,10 @@ impl Mousebinds {
                     && same_layer
                     && (!is_locked || mousebind.bind_data.allow_when_locked)
                 {
-                    retain = mousebind.sender.send(edge).is_ok();
+                    retain = mousebind
+                        .sender
+                        .send(MouseEdge { edge, double_click })
+                        .is_ok();
                     bind_action = BindAction::Suppress;
                 };
 
@@ -482,7 +641,7 @@ impl Mousebinds {
     ) -> u32 {
         let id = BIND_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
 
-        let (sender, recv) = tokio::sync::mpsc::unbounded_channel::<Edge>();
+        let (sender, recv) = tokio::sync::mpsc::unbounded_channel::<MouseEdge>();
 
         let mousebind = Rc::new(RefCell::new(Mousebind {
             bind_data: BindData {
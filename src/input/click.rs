@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Double click detection for mousebinds.
+//!
+//! A press is a double click if it comes soon enough after a press of the same button.
+//! The press after a double click starts over, so triple clicks don't fire twice.
+
+use std::time::Duration;
+
+/// The shortest double click time that can be set.
+pub const MIN_DOUBLE_CLICK_TIME: Duration = Duration::from_millis(50);
+/// The longest double click time that can be set.
+pub const MAX_DOUBLE_CLICK_TIME: Duration = Duration::from_secs(2);
+
+#[derive(Debug)]
+pub struct ClickTracker {
+    /// How soon after a press another press of the same button has to come
+    /// to be a double click.
+    double_click_time: Duration,
+    /// The button and time of the last press that could start a double click.
+    last_press: Option<(u32, Duration)>,
+}
+
+impl Default for ClickTracker {
+    fn default() -> Self {
+        Self {
+            double_click_time: Duration::from_millis(400),
+            last_press: None,
+        }
+    }
+}
+
+impl ClickTracker {
+    /// Returns how soon presses have to follow each other to be a double click.
+    pub fn double_click_time(&self) -> Duration {
+        self.double_click_time
+    }
+
+    /// Sets how soon presses have to follow each other to be a double click.
+    ///
+    /// The time is clamped between [`MIN_DOUBLE_CLICK_TIME`] and [`MAX_DOUBLE_CLICK_TIME`].
+    pub fn set_double_click_time(&mut self, time: Duration) {
+        self.double_click_time = time.clamp(MIN_DOUBLE_CLICK_TIME, MAX_DOUBLE_CLICK_TIME);
+        self.last_press = None;
+    }
+
+    /// Records a button press at `time` and returns whether it's a double click.
+    pub fn press(&mut self, button: u32, time: Duration) -> bool {
+        let double_click = self.last_press.is_some_and(|(last_button, last_time)| {
+            last_button == button
+                && time
+                    .checked_sub(last_time)
+                    .is_some_and(|elapsed| elapsed <= self.double_click_time)
+        });
+
+        self.last_press = (!double_click).then_some((button, time));
+
+        double_click
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BTN_LEFT: u32 = 0x110;
+    const BTN_RIGHT: u32 = 0x111;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn quick_presses_are_double_click() {
+        let mut clicks = ClickTracker::default();
+
+        assert!(!clicks.press(BTN_LEFT, ms(1000)));
+        assert!(clicks.press(BTN_LEFT, ms(1200)));
+    }
+
+    #[test]
+    fn slow_presses_are_not_double_click() {
+        let mut clicks = ClickTracker::default();
+
+        assert!(!clicks.press(BTN_LEFT, ms(1000)));
+        assert!(!clicks.press(BTN_LEFT, ms(1500)));
+        assert!(clicks.press(BTN_LEFT, ms(1600)));
+    }
+
+    #[test]
+    fn different_buttons_are_not_double_click() {
+        let mut clicks = ClickTracker::default();
+
+        assert!(!clicks.press(BTN_LEFT, ms(1000)));
+        assert!(!clicks.press(BTN_RIGHT, ms(1100)));
+    }
+
+    #[test]
+    fn third_press_starts_over() {
+        let mut clicks = ClickTracker::default();
+
+        assert!(!clicks.press(BTN_LEFT, ms(1000)));
+        assert!(clicks.press(BTN_LEFT, ms(1100)));
+        assert!(!clicks.press(BTN_LEFT, ms(1200)));
+        assert!(clicks.press(BTN_LEFT, ms(1300)));
+    }
+
+    #[test]
+    fn double_click_time_is_clamped() {
+        let mut clicks = ClickTracker::default();
+
+        clicks.set_double_click_time(Duration::ZERO);
+        assert_eq!(clicks.double_click_time(), MIN_DOUBLE_CLICK_TIME);
+
+        clicks.set_double_click_time(Duration::from_secs(60));
+        assert_eq!(clicks.double_click_time(), MAX_DOUBLE_CLICK_TIME);
+    }
+}
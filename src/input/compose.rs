@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Compose key support, which types characters from sequences of keys.
+//!
+//! Pressing the compose key (`Multi_key`) followed by, for example, `'` and `e` types `é`.
+//! Sequences come from the compose table for the current locale.
+//!
+//! Keys that are part of a sequence are kept from clients, and the composed character
+//! is typed in their place.
+
+use std::ffi::OsString;
+
+use tracing::warn;
+use xkbcommon::xkb::{
+    self, Keycode, Keysym,
+    compose::{FeedResult, Status},
+};
+
+#[derive(Default)]
+pub struct Compose {
+    /// Whether compose sequences are handled.
+    enabled: bool,
+    /// The compose state, if a compose table was loaded.
+    state: Option<xkb::compose::State>,
+    /// Keys whose next release is dropped because their press was.
+    swallowed: Vec<Keycode>,
+}
+
+impl std::fmt::Debug for Compose {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Compose")
+            .field("enabled", &self.enabled)
+            .field("has_table", &self.state.is_some())
+            .field("swallowed", &self.swallowed)
+            .finish()
+    }
+}
+
+/// What happens to a key event with compose on.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ComposeOutcome {
+    /// The key isn't part of a compose sequence and goes through.
+    Forward,
+    /// The key started or continued a sequence, or cancelled one, and is dropped.
+    Suppress,
+    /// The key finished a sequence, which typed these keysyms.
+    Composed(Vec<Keysym>),
+}
+
+/// Returns the locale compose tables are looked up with, following the usual
+/// `LC_ALL`, `LC_CTYPE`, `LANG` precedence.
+fn compose_locale() -> OsString {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .filter_map(std::env::var_os)
+        .find(|locale| !locale.is_empty())
+        .unwrap_or_else(|| "C".into())
+}
+
+impl Compose {
+    /// Returns whether compose sequences are handled.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Turns compose on or off, cancelling any sequence in progress.
+    ///
+    /// The compose table for the current locale is loaded the first time compose is turned on.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+
+        if let Some(state) = self.state.as_mut() {
+            state.reset();
+        }
+
+        if enabled && self.state.is_none() {
+            let context = xkb::Context::new(xkb::CONTEXT_NO_FLAGS);
+            let locale = compose_locale();
+
+            match xkb::compose::Table::new_from_locale(
+                &context,
+                &locale,
+                xkb::compose::COMPILE_NO_FLAGS,
+            ) {
+                Ok(table) => self.set_table(&table),
+                Err(()) => warn!("Failed to load compose table for locale {locale:?}"),
+            }
+        }
+    }
+
+    /// Uses `table` for compose sequences.
+    pub fn set_table(&mut self, table: &xkb::compose::Table) {
+        self.state = Some(xkb::compose::State::new(
+            table,
+            xkb::compose::STATE_NO_FLAGS,
+        ));
+    }
+
+    /// Updates the compose state with a key event.
+    ///
+    /// `keysym` is the keysym the key produces with the current modifiers.
+    pub fn key(&mut self, keycode: Keycode, keysym: Keysym, pressed: bool) -> ComposeOutcome {
+        if !pressed {
+            if let Some(index) = self.swallowed.iter().position(|&key| key == keycode) {
+                self.swallowed.remove(index);
+                return ComposeOutcome::Suppress;
+            }
+            return ComposeOutcome::Forward;
+        }
+
+        if !self.enabled {
+            return ComposeOutcome::Forward;
+        }
+
+        let Some(state) = self.state.as_mut() else {
+            return ComposeOutcome::Forward;
+        };
+
+        // Modifiers and other keys that can't be in a sequence are ignored
+        if matches!(state.feed(keysym), FeedResult::Ignored) {
+            return ComposeOutcome::Forward;
+        }
+
+        let outcome = match state.status() {
+            Status::Nothing => return ComposeOutcome::Forward,
+            Status::Composing => ComposeOutcome::Suppress,
+            Status::Composed => {
+                let keysyms = match state.keysym() {
+                    Some(keysym) => vec![keysym],
+                    None => state
+                        .utf8()
+                        .unwrap_or_default()
+                        .chars()
+                        .map(|ch| xkb::utf32_to_keysym(ch as u32))
+                        .collect(),
+                };
+                state.reset();
+                ComposeOutcome::Composed(keysyms)
+            }
+            Status::Cancelled => {
+                state.reset();
+                ComposeOutcome::Suppress
+            }
+        };
+
+        self.swallowed.push(keycode);
+        outcome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MULTI: Keycode = Keycode::new(108);
+    const APOSTROPHE: Keycode = Keycode::new(48);
+    const E: Keycode = Keycode::new(26);
+    const X: Keycode = Keycode::new(53);
+
+    fn compose() -> Compose {
+        let context = xkb::Context::new(xkb::CONTEXT_NO_FLAGS);
+        let table = xkb::compose::Table::new_from_buffer(
+            &context,
+            "<Multi_key> <apostrophe> <e> : \"é\" eacute\n".as_bytes(),
+            std::ffi::OsStr::new("C"),
+            xkb::compose::FORMAT_TEXT_V1,
+            xkb::compose::COMPILE_NO_FLAGS,
+        )
+        .unwrap();
+
+        let mut compose = Compose {
+            enabled: true,
+            ..Default::default()
+        };
+        compose.set_table(&table);
+        compose
+    }
+
+    #[test]
+    fn sequence_composes_keysym() {
+        let mut compose = compose();
+
+        assert_eq!(
+            compose.key(MULTI, Keysym::Multi_key, true),
+            ComposeOutcome::Suppress
+        );
+        assert_eq!(
+            compose.key(MULTI, Keysym::Multi_key, false),
+            ComposeOutcome::Suppress
+        );
+        assert_eq!(
+            compose.key(APOSTROPHE, Keysym::apostrophe, true),
+            ComposeOutcome::Suppress
+        );
+        assert_eq!(
+            compose.key(E, Keysym::e, true),
+            ComposeOutcome::Composed(vec![Keysym::eacute])
+        );
+        assert_eq!(
+            compose.key(APOSTROPHE, Keysym::apostrophe, false),
+            ComposeOutcome::Suppress
+        );
+        assert_eq!(compose.key(E, Keysym::e, false), ComposeOutcome::Suppress);
+
+        // The sequence is over
+        assert_eq!(compose.key(E, Keysym::e, true), ComposeOutcome::Forward);
+    }
+
+    #[test]
+    fn unknown_sequence_cancels() {
+        let mut compose = compose();
+
+        compose.key(MULTI, Keysym::Multi_key, true);
+        assert_eq!(compose.key(X, Keysym::x, true), ComposeOutcome::Suppress);
+        assert_eq!(compose.key(X, Keysym::x, false), ComposeOutcome::Suppress);
+
+        assert_eq!(compose.key(X, Keysym::x, true), ComposeOutcome::Forward);
+    }
+
+    #[test]
+    fn disabled_compose_forwards_everything() {
+        let mut compose = compose();
+        compose.set_enabled(false);
+
+        assert_eq!(
+            compose.key(MULTI, Keysym::Multi_key, true),
+            ComposeOutcome::Forward
+        );
+        assert_eq!(compose.key(E, Keysym::e, true), ComposeOutcome::Forward);
+    }
+}
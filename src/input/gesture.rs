@@ -0,0 +1,255 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Touchpad gesture binds.
+//!
+//! Configs bind swipes in a direction and pinches by their finger count. A gesture with
+//! a bind for its kind and finger count isn't sent to clients. Its binds fire once it ends,
+//! unless it was cancelled.
+
+use smithay::utils::{Logical, Point};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+
+/// How far, in logical pixels, a swipe has to move for it to have a direction.
+pub const SWIPE_THRESHOLD: f64 = 50.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl SwipeDirection {
+    /// Returns the direction a swipe that moved by `delta` went in, or `None`
+    /// if it didn't move far enough.
+    ///
+    /// The axis that moved the most wins.
+    pub fn from_delta(delta: Point<f64, Logical>) -> Option<Self> {
+        if delta.x.abs().max(delta.y.abs()) < SWIPE_THRESHOLD {
+            return None;
+        }
+
+        Some(match delta.x.abs() > delta.y.abs() {
+            true if delta.x < 0.0 => Self::Left,
+            true => Self::Right,
+            false if delta.y < 0.0 => Self::Up,
+            false => Self::Down,
+        })
+    }
+}
+
+/// The kind of gesture a bind fires on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GestureKind {
+    Swipe(SwipeDirection),
+    Pinch,
+}
+
+/// What is sent to a bind when it fires.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GestureEvent {
+    Swipe,
+    /// A pinch with the scale the fingers ended at relative to where they started.
+    Pinch {
+        scale: f64,
+    },
+}
+
+#[derive(Debug)]
+struct GestureBind {
+    fingers: u32,
+    kind: GestureKind,
+    sender: UnboundedSender<GestureEvent>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ActiveGesture {
+    Swipe {
+        fingers: u32,
+        delta: Point<f64, Logical>,
+    },
+    Pinch {
+        fingers: u32,
+        scale: f64,
+    },
+}
+
+#[derive(Debug, Default)]
+pub struct GestureState {
+    binds: Vec<GestureBind>,
+    /// The bound gesture in progress, which clients aren't being sent.
+    active: Option<ActiveGesture>,
+}
+
+impl GestureState {
+    /// Binds a gesture, returning the receiver it fires into.
+    pub fn add_bind(&mut self, fingers: u32, kind: GestureKind) -> UnboundedReceiver<GestureEvent> {
+        let (sender, recv) = tokio::sync::mpsc::unbounded_channel();
+
+        self.binds.push(GestureBind {
+            fingers,
+            kind,
+            sender,
+        });
+
+        recv
+    }
+
+    /// Removes every bind.
+    ///
+    /// A gesture in progress stays bound so its remaining events don't leak to clients.
+    pub fn clear(&mut self) {
+        self.binds.clear();
+    }
+
+    /// Starts a swipe.
+    ///
+    /// Returns whether it's bound and shouldn't be sent to clients.
+    pub fn swipe_begin(&mut self, fingers: u32) -> bool {
+        let bound = self
+            .binds
+            .iter()
+            .any(|bind| bind.fingers == fingers && matches!(bind.kind, GestureKind::Swipe(_)));
+
+        self.active = bound.then_some(ActiveGesture::Swipe {
+            fingers,
+            delta: Point::default(),
+        });
+
+        bound
+    }
+
+    /// Moves the current swipe.
+    ///
+    /// Returns whether it's bound and shouldn't be sent to clients.
+    pub fn swipe_update(&mut self, delta: Point<f64, Logical>) -> bool {
+        match &mut self.active {
+            Some(ActiveGesture::Swipe {
+                delta: total_delta, ..
+            }) => {
+                *total_delta += delta;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Ends the current swipe, firing binds for its direction if it wasn't cancelled.
+    ///
+    /// Returns whether it's bound and shouldn't be sent to clients.
+    pub fn swipe_end(&mut self, cancelled: bool) -> bool {
+        let Some(ActiveGesture::Swipe { fingers, delta }) = self.active else {
+            return false;
+        };
+        self.active = None;
+
+        if !cancelled && let Some(direction) = SwipeDirection::from_delta(delta) {
+            self.fire(fingers, GestureKind::Swipe(direction), GestureEvent::Swipe);
+        }
+
+        true
+    }
+
+    /// Starts a pinch.
+    ///
+    /// Returns whether it's bound and shouldn't be sent to clients.
+    pub fn pinch_begin(&mut self, fingers: u32) -> bool {
+        let bound = self
+            .binds
+            .iter()
+            .any(|bind| bind.fingers == fingers && bind.kind == GestureKind::Pinch);
+
+        self.active = bound.then_some(ActiveGesture::Pinch {
+            fingers,
+            scale: 1.0,
+        });
+
+        bound
+    }
+
+    /// Updates the scale of the current pinch.
+    ///
+    /// Returns whether it's bound and shouldn't be sent to clients.
+    pub fn pinch_update(&mut self, scale: f64) -> bool {
+        match &mut self.active {
+            Some(ActiveGesture::Pinch {
+                scale: current_scale,
+                ..
+            }) => {
+                *current_scale = scale;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Ends the current pinch, firing binds with its scale if it wasn't cancelled.
+    ///
+    /// Returns whether it's bound and shouldn't be sent to clients.
+    pub fn pinch_end(&mut self, cancelled: bool) -> bool {
+        let Some(ActiveGesture::Pinch { fingers, scale }) = self.active else {
+            return false;
+        };
+        self.active = None;
+
+        if !cancelled {
+            self.fire(fingers, GestureKind::Pinch, GestureEvent::Pinch { scale });
+        }
+
+        true
+    }
+
+    fn fire(&mut self, fingers: u32, kind: GestureKind, event: GestureEvent) {
+        // Binds whose config went away are dropped
+        self.binds.retain(|bind| {
+            bind.fingers != fingers || bind.kind != kind || bind.sender.send(event).is_ok()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swipe_direction_follows_dominant_axis() {
+        assert_eq!(
+            SwipeDirection::from_delta((-80.0, 20.0).into()),
+            Some(SwipeDirection::Left)
+        );
+        assert_eq!(
+            SwipeDirection::from_delta((10.0, 60.0).into()),
+            Some(SwipeDirection::Down)
+        );
+        assert_eq!(SwipeDirection::from_delta((30.0, -30.0).into()), None);
+    }
+
+    #[test]
+    fn cancelled_swipe_does_not_fire() {
+        let mut gestures = GestureState::default();
+        let mut recv = gestures.add_bind(3, GestureKind::Swipe(SwipeDirection::Up));
+
+        assert!(gestures.swipe_begin(3));
+        assert!(gestures.swipe_update((0.0, -100.0).into()));
+        assert!(gestures.swipe_end(true));
+        assert!(recv.try_recv().is_err());
+
+        assert!(gestures.swipe_begin(3));
+        assert!(gestures.swipe_update((0.0, -100.0).into()));
+        assert!(gestures.swipe_end(false));
+        assert_eq!(recv.try_recv(), Ok(GestureEvent::Swipe));
+    }
+
+    #[test]
+    fn unbound_gesture_is_not_consumed() {
+        let mut gestures = GestureState::default();
+        let _recv = gestures.add_bind(3, GestureKind::Pinch);
+
+        assert!(!gestures.pinch_begin(2));
+        assert!(!gestures.pinch_update(0.5));
+        assert!(!gestures.pinch_end(false));
+
+        assert!(!gestures.swipe_begin(3));
+    }
+}
@@ -0,0 +1,474 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Synthetic keyboard input for configs.
+
+use std::time::Duration;
+
+use smithay::{
+    backend::input::KeyState,
+    input::keyboard::{FilterResult, KeyboardTarget, Layout, ModifiersState, SerializedMods},
+    utils::SERIAL_COUNTER,
+};
+use tracing::warn;
+use xkbcommon::xkb::{self, Keycode, Keysym};
+
+use crate::state::State;
+
+/// A key with no keysyms in the user's keymap, reserved to type keysyms their layout
+/// doesn't have.
+#[derive(Debug)]
+pub struct SpareKey {
+    keycode: Keycode,
+    name: String,
+    /// The keymap the key was reserved from.
+    base_keymap: String,
+    /// The keysym currently put on the key.
+    keysym: Option<Keysym>,
+    /// The address of the keymap the key is in, to notice when it's replaced.
+    keymap: usize,
+}
+
+/// A key that produces some keysym.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct InjectedKey {
+    keycode: Keycode,
+    /// Whether Shift needs to be held for the key to produce the keysym.
+    shift: bool,
+}
+
+/// Finds the key in `layout` that produces `keysym` without modifiers or with Shift.
+///
+/// Keysyms on higher levels (e.g. those behind AltGr) are not found.
+fn find_key(keymap: &xkb::Keymap, layout: Layout, keysym: Keysym) -> Option<InjectedKey> {
+    let min = keymap.min_keycode().raw();
+    let max = keymap.max_keycode().raw();
+
+    (0..2).find_map(|level| {
+        (min..=max).map(Keycode::new).find_map(|keycode| {
+            keymap
+                .key_get_syms_by_level(keycode, layout.0, level)
+                .contains(&keysym)
+                .then_some(InjectedKey {
+                    keycode,
+                    shift: level == 1,
+                })
+        })
+    })
+}
+
+/// Finds a key in `keymap` that has no keysyms, returning it along with its name.
+fn free_key(keymap: &xkb::Keymap) -> Option<(Keycode, String)> {
+    let min = keymap.min_keycode().raw();
+    let max = keymap.max_keycode().raw();
+
+    (min..=max).map(Keycode::new).find_map(|keycode| {
+        (keymap.num_layouts_for_key(keycode) == 0)
+            .then(|| keymap.key_get_name(keycode))
+            .flatten()
+            .map(|name| (keycode, name.to_string()))
+    })
+}
+
+/// Returns `keymap_text` with `keysym` put on the key named `key_name`.
+///
+/// The key should have no keysyms in `keymap_text`.
+fn keymap_with_keysym(keymap_text: &str, key_name: &str, keysym: Keysym) -> Option<String> {
+    let mut text = keymap_text.to_string();
+
+    // Find the end of the symbols section to add the key to
+    let start = text.find("xkb_symbols")?;
+    let open = start + text[start..].find('{')?;
+    let mut depth = 0;
+    let close = text[open..].char_indices().find_map(|(i, ch)| {
+        match ch {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            _ => (),
+        }
+        (depth == 0).then_some(open + i)
+    })?;
+
+    text.insert_str(
+        close,
+        &format!(
+            "    key <{key_name}> {{ [ {} ] }};\n",
+            xkb::keysym_get_name(keysym)
+        ),
+    );
+
+    Some(text)
+}
+
+/// Converts a character to the keysym that types it.
+fn keysym_for_char(ch: char) -> Keysym {
+    match ch {
+        // `utf32_to_keysym` gives Linefeed, which layouts don't usually have
+        '\n' => Keysym::Return,
+        ch => xkb::utf32_to_keysym(ch as u32),
+    }
+}
+
+impl State {
+    /// Types `text` into the client with keyboard focus.
+    ///
+    /// Characters that can't be typed with the current layout are skipped.
+    pub fn type_string(&mut self, text: &str) {
+        self.without_held_modifiers(|state| {
+            let shift = state.key_for_keysym(Keysym::Shift_L);
+
+            for ch in text.chars() {
+                let Some(key) = state.key_for_keysym(keysym_for_char(ch)) else {
+                    warn!("Cannot type {ch:?}, it is not in the current keyboard layout");
+                    continue;
+                };
+
+                state.press_keys(&[key], shift);
+                state.release_keys(&[key], shift);
+            }
+        });
+    }
+
+    /// Presses `keysyms` in order, then releases them in reverse order.
+    ///
+    /// Nothing is sent if any keysym can't be typed with the current layout.
+    pub fn send_combo(&mut self, keysyms: &[Keysym]) {
+        let shift = self.key_for_keysym(Keysym::Shift_L);
+        let mut keys = Vec::with_capacity(keysyms.len());
+
+        for keysym in keysyms.iter().copied() {
+            let Some(key) = self.key_for_keysym(keysym) else {
+                warn!(
+                    "Cannot send combo, {} is not in the current keyboard layout",
+                    xkb::keysym_get_name(keysym)
+                );
+                return;
+            };
+            keys.push(key);
+        }
+
+        self.without_held_modifiers(|state| {
+            state.press_keys(&keys, shift);
+            state.release_keys(&keys, shift);
+        });
+    }
+
+    /// Types `keysym` into the client with keyboard focus.
+    ///
+    /// If the current layout doesn't have `keysym`, it is put on the reserved
+    /// [`SpareKey`] first.
+    pub fn type_keysym(&mut self, keysym: Keysym) {
+        if let Some(key) = self.key_for_keysym(keysym) {
+            let shift = self.key_for_keysym(Keysym::Shift_L);
+            self.without_held_modifiers(|state| {
+                state.press_keys(&[key], shift);
+                state.release_keys(&[key], shift);
+            });
+            return;
+        }
+
+        let Some(keycode) = self.spare_key_for_keysym(keysym) else {
+            warn!(
+                "Cannot type {}, there is no free key to put it on",
+                xkb::keysym_get_name(keysym)
+            );
+            return;
+        };
+
+        self.without_held_modifiers(|state| {
+            state.inject_key(keycode, KeyState::Pressed);
+            state.inject_key(keycode, KeyState::Released);
+        });
+    }
+
+    /// Runs `inject` with depressed and latched modifiers cleared, then puts them back.
+    ///
+    /// Without this, modifiers the user is holding, like the ones of the keybind
+    /// that typed the text, would apply to the injected keys. Locked modifiers
+    /// are left alone.
+    fn without_held_modifiers(&mut self, inject: impl FnOnce(&mut Self)) {
+        let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
+            return;
+        };
+
+        let held = keyboard.modifier_state();
+
+        let cleared = ModifiersState {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            logo: false,
+            iso_level3_shift: false,
+            iso_level5_shift: false,
+            serialized: SerializedMods {
+                depressed: 0,
+                latched: 0,
+                ..held.serialized
+            },
+            ..held
+        };
+        keyboard.set_modifier_state(cleared);
+        self.send_modifiers();
+
+        inject(self);
+
+        // Locks may have been toggled while injecting, so only restore what was held
+        let locked = keyboard.modifier_state();
+        keyboard.set_modifier_state(ModifiersState {
+            caps_lock: locked.caps_lock,
+            num_lock: locked.num_lock,
+            serialized: SerializedMods {
+                locked: locked.serialized.locked,
+                ..held.serialized
+            },
+            ..held
+        });
+        self.send_modifiers();
+    }
+
+    /// Sends the keyboard's current modifiers to the focused client.
+    fn send_modifiers(&mut self) {
+        let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
+            return;
+        };
+        let Some(focus) = keyboard.current_focus() else {
+            return;
+        };
+
+        let seat = self.pinnacle.seat.clone();
+        let modifiers = keyboard.modifier_state();
+        focus.modifiers(&seat, self, modifiers, SERIAL_COUNTER.next_serial());
+    }
+
+    /// Reserves a key with no keysyms in the current keymap to type keysyms the layout
+    /// doesn't have.
+    ///
+    /// The key is reserved again if the keymap is replaced.
+    pub fn reserve_spare_key(&mut self) {
+        let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
+            return;
+        };
+
+        let spare_key = keyboard.with_xkb_state(self, |context| {
+            let xkb = context.xkb().lock().unwrap();
+            // SAFETY: The keymap is only read from
+            let keymap = unsafe { xkb.keymap() };
+            let (keycode, name) = free_key(keymap)?;
+            Some(SpareKey {
+                keycode,
+                name,
+                base_keymap: keymap.get_as_string(xkb::KEYMAP_FORMAT_TEXT_V1),
+                keysym: None,
+                keymap: keymap.get_raw_ptr() as usize,
+            })
+        });
+
+        self.pinnacle.input_state.spare_key = spare_key;
+    }
+
+    /// Puts `keysym` on the spare key if it isn't already there and returns the key.
+    ///
+    /// The keymap is only replaced when the key's keysym changes. The active layout
+    /// and locked modifiers are carried over to the new keymap.
+    fn spare_key_for_keysym(&mut self, keysym: Keysym) -> Option<Keycode> {
+        let keyboard = self.pinnacle.seat.get_keyboard()?;
+
+        let current_keymap = keyboard.with_xkb_state(self, |context| {
+            // SAFETY: The keymap is only read from
+            unsafe { context.xkb().lock().unwrap().keymap() }.get_raw_ptr() as usize
+        });
+
+        if self
+            .pinnacle
+            .input_state
+            .spare_key
+            .as_ref()
+            .is_none_or(|spare_key| spare_key.keymap != current_keymap)
+        {
+            self.reserve_spare_key();
+        }
+
+        let spare_key = self.pinnacle.input_state.spare_key.as_ref()?;
+        let keycode = spare_key.keycode;
+
+        if spare_key.keysym == Some(keysym) {
+            return Some(keycode);
+        }
+
+        let keymap = keymap_with_keysym(&spare_key.base_keymap, &spare_key.name, keysym)?;
+
+        let layout = keyboard.with_xkb_state(self, |context| {
+            context.xkb().lock().unwrap().active_layout()
+        });
+        let modifiers = keyboard.modifier_state();
+
+        if let Err(err) = keyboard.set_keymap_from_string(self, keymap) {
+            warn!("Failed to set keymap: {err}");
+            return None;
+        }
+
+        keyboard.with_xkb_state(self, |mut context| context.set_layout(layout));
+        self.set_lock(Keysym::Caps_Lock, modifiers.caps_lock);
+        self.set_numlock(modifiers.num_lock);
+
+        let new_keymap = keyboard.with_xkb_state(self, |context| {
+            // SAFETY: The keymap is only read from
+            unsafe { context.xkb().lock().unwrap().keymap() }.get_raw_ptr() as usize
+        });
+
+        if let Some(spare_key) = self.pinnacle.input_state.spare_key.as_mut() {
+            spare_key.keysym = Some(keysym);
+            spare_key.keymap = new_keymap;
+        }
+
+        Some(keycode)
+    }
+
+    fn key_for_keysym(&mut self, keysym: Keysym) -> Option<InjectedKey> {
+        let keyboard = self.pinnacle.seat.get_keyboard()?;
+
+        keyboard.with_xkb_state(self, |context| {
+            let xkb = context.xkb().lock().unwrap();
+            // SAFETY: The keymap is only read from
+            let keymap = unsafe { xkb.keymap() };
+            let key = find_key(keymap, xkb.active_layout(), keysym)?;
+            // A shifted key can't be typed if there's no Shift to press with it
+            if key.shift && find_key(keymap, xkb.active_layout(), Keysym::Shift_L).is_none() {
+                return None;
+            }
+            Some(key)
+        })
+    }
+
+    fn press_keys(&mut self, keys: &[InjectedKey], shift: Option<InjectedKey>) {
+        for key in keys {
+            if key.shift
+                && let Some(shift) = shift
+            {
+                self.inject_key(shift.keycode, KeyState::Pressed);
+            }
+            self.inject_key(key.keycode, KeyState::Pressed);
+        }
+    }
+
+    fn release_keys(&mut self, keys: &[InjectedKey], shift: Option<InjectedKey>) {
+        for key in keys.iter().rev() {
+            self.inject_key(key.keycode, KeyState::Released);
+            if key.shift
+                && let Some(shift) = shift
+            {
+                self.inject_key(shift.keycode, KeyState::Released);
+            }
+        }
+    }
+
+    /// Turns NumLock on or off by tapping the NumLock key if it isn't already in that state.
+    pub fn set_numlock(&mut self, enabled: bool) {
+        self.set_lock(Keysym::Num_Lock, enabled);
+    }
+
+    /// Turns a lock modifier on or off by tapping `lock_keysym`'s key if it isn't
+    /// already in that state.
+    fn set_lock(&mut self, lock_keysym: Keysym, enabled: bool) {
+        let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
+            return;
+        };
+
+        let modifiers = keyboard.modifier_state();
+        let locked = match lock_keysym {
+            Keysym::Caps_Lock => modifiers.caps_lock,
+            Keysym::Num_Lock => modifiers.num_lock,
+            _ => return,
+        };
+
+        if locked == enabled {
+            return;
+        }
+
+        let Some(key) = self.key_for_keysym(lock_keysym) else {
+            warn!(
+                "Cannot set {}, it is not in the current keyboard layout",
+                xkb::keysym_get_name(lock_keysym)
+            );
+            return;
+        };
+
+        self.inject_key(key.keycode, KeyState::Pressed);
+        self.inject_key(key.keycode, KeyState::Released);
+    }
+
+    /// Sends a key event straight to the focused client, bypassing keybinds.
+    pub(super) fn inject_key(&mut self, keycode: Keycode, key_state: KeyState) {
+        let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
+            return;
+        };
+
+        let serial = SERIAL_COUNTER.next_serial();
+        let time = Duration::from(self.pinnacle.clock.now()).as_millis() as u32;
+
+        keyboard.input::<(), _>(self, keycode, key_state, serial, time, |_, _, _| {
+            FilterResult::Forward
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn us_keymap() -> xkb::Keymap {
+        let context = xkb::Context::new(xkb::CONTEXT_NO_FLAGS);
+        xkb::Keymap::new_from_names(&context, "", "", "us", "", None, xkb::COMPILE_NO_FLAGS)
+            .unwrap()
+    }
+
+    #[test]
+    fn find_key_finds_base_and_shift_levels() {
+        let keymap = us_keymap();
+
+        let a = find_key(&keymap, Layout(0), Keysym::a).unwrap();
+        assert!(!a.shift);
+
+        let upper_a = find_key(&keymap, Layout(0), Keysym::A).unwrap();
+        assert!(upper_a.shift);
+        assert_eq!(a.keycode, upper_a.keycode);
+    }
+
+    #[test]
+    fn find_key_does_not_find_keysyms_missing_from_layout() {
+        let keymap = us_keymap();
+
+        assert_eq!(find_key(&keymap, Layout(0), keysym_for_char('ß')), None);
+    }
+
+    #[test]
+    fn keymap_with_keysym_adds_missing_keysym() {
+        let keymap = us_keymap();
+
+        let (keycode, name) = free_key(&keymap).unwrap();
+        let text = keymap_with_keysym(
+            &keymap.get_as_string(xkb::KEYMAP_FORMAT_TEXT_V1),
+            &name,
+            Keysym::eacute,
+        )
+        .unwrap();
+
+        let context = xkb::Context::new(xkb::CONTEXT_NO_FLAGS);
+        let keymap = xkb::Keymap::new_from_string(
+            &context,
+            text,
+            xkb::KEYMAP_FORMAT_TEXT_V1,
+            xkb::COMPILE_NO_FLAGS,
+        )
+        .unwrap();
+
+        assert_eq!(
+            find_key(&keymap, Layout(0), Keysym::eacute),
+            Some(InjectedKey {
+                keycode,
+                shift: false,
+            })
+        );
+        // Everything else is left alone
+        assert!(find_key(&keymap, Layout(0), Keysym::a).is_some());
+    }
+}
@@ -0,0 +1,248 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Sticky keys, which let modifiers be pressed one after another instead of held.
+//!
+//! Tapping a modifier latches it, keeping it held for the next key. Tapping it again
+//! while latched locks it, keeping it held until it's tapped a third time.
+//!
+//! Modifiers stay held by not letting their releases through to the keyboard,
+//! so keybinds and clients see the combined modifier state as if the keys were held.
+
+use smithay::backend::input::KeyState;
+use xkbcommon::xkb::{Keycode, Keysym};
+
+use crate::state::State;
+
+#[derive(Debug, Default)]
+pub struct StickyKeys {
+    /// Whether sticky keys are enabled.
+    pub enabled: bool,
+    /// Modifiers that were tapped and are held until the next key is pressed.
+    latched: Vec<Keycode>,
+    /// Modifiers that were tapped twice and are held until tapped again.
+    locked: Vec<Keycode>,
+    /// The modifier being held, if no other key has been pressed since.
+    tapping: Option<Keycode>,
+    /// Keys whose next release is dropped because their press was.
+    swallowed: Vec<Keycode>,
+}
+
+/// What happens to a key event with sticky keys on.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct StickyOutcome {
+    /// Whether the key event goes through.
+    pub process: bool,
+    /// Held modifiers to release after the key event.
+    pub release: Vec<Keycode>,
+}
+
+impl StickyKeys {
+    /// Updates the sticky state with a key event.
+    pub fn key(&mut self, keycode: Keycode, is_modifier: bool, pressed: bool) -> StickyOutcome {
+        if !pressed {
+            if let Some(index) = self.swallowed.iter().position(|&key| key == keycode) {
+                self.swallowed.remove(index);
+                return StickyOutcome::default();
+            }
+
+            if is_modifier && self.tapping == Some(keycode) {
+                self.tapping = None;
+                self.latched.push(keycode);
+                return StickyOutcome::default();
+            }
+
+            return StickyOutcome {
+                process: true,
+                release: Vec::new(),
+            };
+        }
+
+        if !is_modifier {
+            self.tapping = None;
+            return StickyOutcome {
+                process: true,
+                release: std::mem::take(&mut self.latched),
+            };
+        }
+
+        self.tapping = None;
+
+        if let Some(index) = self.locked.iter().position(|&key| key == keycode) {
+            self.locked.remove(index);
+            self.swallowed.push(keycode);
+            return StickyOutcome {
+                process: false,
+                release: vec![keycode],
+            };
+        }
+
+        if let Some(index) = self.latched.iter().position(|&key| key == keycode) {
+            self.latched.remove(index);
+            self.locked.push(keycode);
+            self.swallowed.push(keycode);
+            return StickyOutcome::default();
+        }
+
+        self.tapping = Some(keycode);
+
+        StickyOutcome {
+            process: true,
+            release: Vec::new(),
+        }
+    }
+
+    /// Clears the sticky state, returning the modifiers that were being held.
+    pub fn clear(&mut self) -> Vec<Keycode> {
+        self.tapping = None;
+        self.swallowed.clear();
+        let mut held = std::mem::take(&mut self.latched);
+        held.append(&mut self.locked);
+        held
+    }
+}
+
+impl State {
+    /// Turns sticky keys on or off, releasing any modifiers they were holding.
+    pub fn set_sticky_keys(&mut self, enabled: bool) {
+        let sticky_keys = &mut self.pinnacle.input_state.sticky_keys;
+        sticky_keys.enabled = enabled;
+        let held = sticky_keys.clear();
+
+        for keycode in held {
+            self.inject_key(keycode, KeyState::Released);
+        }
+    }
+
+    /// Returns whether `keycode` is a modifier that sticky keys applies to.
+    ///
+    /// Lock keys already stay on by themselves, so they aren't included.
+    pub(super) fn is_sticky_modifier(&mut self, keycode: Keycode) -> bool {
+        let Some(keyboard) = self.pinnacle.seat.get_keyboard() else {
+            return false;
+        };
+
+        keyboard.with_xkb_state(self, |context| {
+            let xkb = context.xkb().lock().unwrap();
+            // SAFETY: The keymap is only read from
+            let keymap = unsafe { xkb.keymap() };
+            keymap
+                .key_get_syms_by_level(keycode, xkb.active_layout().0, 0)
+                .iter()
+                .any(|keysym| {
+                    keysym.is_modifier_key()
+                        && !matches!(
+                            *keysym,
+                            Keysym::Caps_Lock | Keysym::Shift_Lock | Keysym::Num_Lock
+                        )
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CTRL: Keycode = Keycode::new(37);
+    const SHIFT: Keycode = Keycode::new(50);
+    const A: Keycode = Keycode::new(38);
+
+    fn tap(sticky: &mut StickyKeys, keycode: Keycode, is_modifier: bool) -> Vec<StickyOutcome> {
+        vec![
+            sticky.key(keycode, is_modifier, true),
+            sticky.key(keycode, is_modifier, false),
+        ]
+    }
+
+    fn processed() -> StickyOutcome {
+        StickyOutcome {
+            process: true,
+            release: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn tapped_modifier_latches_for_next_key() {
+        let mut sticky = StickyKeys::default();
+
+        assert_eq!(
+            tap(&mut sticky, CTRL, true),
+            [processed(), StickyOutcome::default()]
+        );
+
+        assert_eq!(
+            sticky.key(A, false, true),
+            StickyOutcome {
+                process: true,
+                release: vec![CTRL],
+            }
+        );
+        assert_eq!(sticky.key(A, false, false), processed());
+
+        // The latch only applied to one key
+        assert_eq!(sticky.key(A, false, true), processed());
+    }
+
+    #[test]
+    fn modifiers_latch_together() {
+        let mut sticky = StickyKeys::default();
+
+        tap(&mut sticky, CTRL, true);
+        tap(&mut sticky, SHIFT, true);
+
+        assert_eq!(sticky.key(A, false, true).release, [CTRL, SHIFT]);
+    }
+
+    #[test]
+    fn held_modifier_does_not_latch() {
+        let mut sticky = StickyKeys::default();
+
+        assert_eq!(sticky.key(CTRL, true, true), processed());
+        assert_eq!(sticky.key(A, false, true), processed());
+        assert_eq!(sticky.key(A, false, false), processed());
+        assert_eq!(sticky.key(CTRL, true, false), processed());
+
+        assert_eq!(sticky.key(A, false, true), processed());
+    }
+
+    #[test]
+    fn double_tapped_modifier_locks_until_tapped_again() {
+        let mut sticky = StickyKeys::default();
+
+        tap(&mut sticky, CTRL, true);
+        assert_eq!(
+            tap(&mut sticky, CTRL, true),
+            [StickyOutcome::default(), StickyOutcome::default()]
+        );
+
+        assert_eq!(sticky.key(A, false, true), processed());
+        assert_eq!(sticky.key(A, false, false), processed());
+        assert_eq!(sticky.key(A, false, true), processed());
+        assert_eq!(sticky.key(A, false, false), processed());
+
+        assert_eq!(
+            tap(&mut sticky, CTRL, true),
+            [
+                StickyOutcome {
+                    process: false,
+                    release: vec![CTRL],
+                },
+                StickyOutcome::default()
+            ]
+        );
+
+        assert_eq!(sticky.key(A, false, true), processed());
+    }
+
+    #[test]
+    fn clear_returns_held_modifiers() {
+        let mut sticky = StickyKeys::default();
+
+        tap(&mut sticky, CTRL, true);
+        tap(&mut sticky, CTRL, true);
+        tap(&mut sticky, SHIFT, true);
+
+        assert_eq!(sticky.clear(), [SHIFT, CTRL]);
+        assert_eq!(sticky.key(A, false, true), processed());
+    }
+}
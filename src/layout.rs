@@ -9,7 +9,6 @@ use std::{
 
 use indexmap::IndexSet;
 use smithay::{
-    desktop::layer_map_for_output,
     output::{Output, WeakOutput},
     reexports::wayland_protocols::xdg::shell::server::xdg_toplevel,
     utils::{Logical, Rectangle, Size},
@@ -20,9 +19,9 @@ use tree::{LayoutNode, LayoutTree, ResizeDir};
 
 use crate::{
     backend::Backend,
-    output::OutputName,
+    output::{OutputLayoutMode, OutputName},
     state::{Pinnacle, State, WithState},
-    tag::TagId,
+    tag::{Tag, TagId},
     util::transaction::{Location, PendingTransaction, TransactionBuilder},
     window::{UnmappingWindow, WindowElement},
 };
@@ -34,21 +33,24 @@ impl Pinnacle {
         backend: &mut Backend,
         is_resize: bool,
     ) {
-        let Some(tree) = self.layout_state.current_tree_for_output(output) else {
-            warn!("no layout tree for output");
-            return;
-        };
-
-        let (output_width, output_height) = {
-            let map = layer_map_for_output(output);
-            let zone = map.non_exclusive_zone();
-            (zone.size.w, zone.size.h)
-        };
-
-        let (geometries, nodes): (Vec<_>, Vec<_>) = tree
-            .compute_geos(output_width as u32, output_height as u32)
-            .into_iter()
-            .unzip();
+        let (geometries, nodes): (Vec<_>, Vec<_>) =
+            if output.with_state(|state| state.layout_mode == OutputLayoutMode::Floating) {
+                Default::default()
+            } else {
+                let Some(tree) = self.layout_state.current_tree_for_output(output) else {
+                    warn!("no layout tree for output");
+                    return;
+                };
+
+                let (output_width, output_height) = {
+                    let zone = self.non_exclusive_zone(output);
+                    (zone.size.w, zone.size.h)
+                };
+
+                tree.compute_geos(output_width as u32, output_height as u32)
+                    .into_iter()
+                    .unzip()
+            };
 
         let (windows_on_foc_tags, to_unmap) = output.with_state(|state| {
             let focused_tags = state.focused_tags().cloned().collect::<IndexSet<_>>();
@@ -57,7 +59,10 @@ impl Pinnacle {
                 .filter(|win| win.output(self).as_ref() == Some(output))
                 .cloned()
                 .partition::<Vec<_>, _>(|win| {
-                    win.with_state(|state| state.tags.intersection(&focused_tags).next().is_some())
+                    // Minimized windows are unmapped like windows on unfocused tags
+                    win.with_state(|state| {
+                        !state.minimized && state.tags.intersection(&focused_tags).next().is_some()
+                    })
                 })
         });
 
@@ -99,7 +104,7 @@ impl Pinnacle {
             return;
         };
 
-        let non_exclusive_geo = layer_map_for_output(output).non_exclusive_zone();
+        let non_exclusive_geo = self.non_exclusive_zone(output);
 
         let spilled_windows = tiled_windows
             .clone()
@@ -133,14 +138,29 @@ impl Pinnacle {
             .layout_state
             .pending_window_updates
             .take_next_for_output(output)
-            .unwrap_or_default()
-            .into_iter()
-            .map(|(win, geo)| (win, geo, false));
+            .unwrap_or_default();
+
+        // Maximized windows fill the non-exclusive zone, which changes when
+        // layer surfaces reserve or release space
+        let maximized_windows = windows_on_foc_tags
+            .iter()
+            .filter(|win| win.with_state(|state| state.layout_mode.is_maximized()))
+            .filter(|win| !wins_and_geos_other.iter().any(|(other, _)| other == *win))
+            .filter_map(|win| {
+                let geo = self.compute_window_geometry(win, output_geo, non_exclusive_geo)?;
+                Some((win.clone(), geo, false))
+            })
+            .collect::<Vec<_>>();
 
         let wins_and_geos = wins_and_geos_tiled
             .into_iter()
             .chain(spilled_windows)
-            .chain(wins_and_geos_other)
+            .chain(maximized_windows)
+            .chain(
+                wins_and_geos_other
+                    .into_iter()
+                    .map(|(win, geo)| (win, geo, false)),
+            )
             .collect::<Vec<_>>();
 
         let mut transaction_builder = TransactionBuilder::new();
@@ -187,6 +207,67 @@ impl Pinnacle {
             self.windows.swap(first, second);
         }
     }
+
+    /// Moves `window` in the layout order relative to the other windows on `tag`.
+    ///
+    /// Does nothing if no other window is on `tag`.
+    pub fn insert_window_on_tag(
+        &mut self,
+        window: &WindowElement,
+        tag: &Tag,
+        position: InsertPosition,
+    ) {
+        let is_on_tag = |win: &WindowElement| {
+            win != window
+                && !win.is_x11_override_redirect()
+                && win.with_state(|state| state.tags.contains(tag))
+        };
+
+        let anchor = match position {
+            InsertPosition::Start => None,
+            InsertPosition::End => self.windows.iter().rev().find(|win| is_on_tag(win)),
+            InsertPosition::AfterFocused => self
+                .keyboard_focus_stack
+                .windows()
+                .rev()
+                .find(|win| is_on_tag(win))
+                .or_else(|| self.windows.iter().rev().find(|win| is_on_tag(win))),
+        }
+        .cloned();
+
+        let Some(first) = self.windows.iter().find(|win| is_on_tag(win)).cloned() else {
+            return;
+        };
+
+        let Some(index) = self.windows.iter().position(|win| win == window) else {
+            return;
+        };
+        let window = self.windows.remove(index);
+
+        let insert_index = match anchor {
+            Some(anchor) => self
+                .windows
+                .iter()
+                .position(|win| win == anchor)
+                .map(|i| i + 1),
+            None => self.windows.iter().position(|win| win == first),
+        }
+        .unwrap_or(self.windows.len());
+
+        self.windows.insert(insert_index, window);
+    }
+}
+
+/// Where a window goes in the layout order of a tag it's moved to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertPosition {
+    /// Before all other windows on the tag.
+    Start,
+    /// After all other windows on the tag.
+    End,
+    /// After the most recently focused window on the tag,
+    /// or after all of them if none have been focused.
+    AfterFocused,
 }
 
 /// A monotonically increasing identifier for layout requests.
@@ -447,9 +528,14 @@ impl State {
         // Update and map unmapped non-tiled windows
         // Probably a better way to do this
         for win in self.pinnacle.windows.iter() {
-            let is_tiled = win.with_state(|state| state.layout_mode.is_tiled());
+            let (is_tiled, is_minimized) =
+                win.with_state(|state| (state.layout_mode.is_tiled(), state.minimized));
             let is_on_active_tag = win.is_on_active_tag();
-            if !is_tiled && is_on_active_tag && !self.pinnacle.space.elements().any(|w| w == win) {
+            if !is_tiled
+                && !is_minimized
+                && is_on_active_tag
+                && !self.pinnacle.space.elements().any(|w| w == win)
+            {
                 wins_to_update.push(win.clone());
             }
         }
@@ -466,6 +552,22 @@ impl Pinnacle {
             return;
         }
 
+        // Floating outputs don't get layouts, but windows still need to be
+        // mapped and unmapped as tags change
+        if output.with_state(|state| state.layout_mode == OutputLayoutMode::Floating) {
+            self.float_tiled_windows(output);
+
+            let output = output.clone();
+            self.loop_handle.insert_idle(move |state| {
+                state
+                    .pinnacle
+                    .update_windows_from_tree(&output, &mut state.backend, false);
+                state.schedule_render(&output);
+            });
+
+            return;
+        }
+
         let id = self.layout_state.next_id();
         let Some(sender) = self.layout_state.layout_request_sender.as_ref() else {
             warn!("Layout requested but no client has connected to the layout service");
@@ -478,7 +580,9 @@ impl Pinnacle {
                 .iter()
                 .filter(|win| !win.is_x11_override_redirect())
                 .filter(|win| {
-                    win.with_state(|state| state.tags.intersection(&focused_tags).next().is_some())
+                    win.with_state(|state| {
+                        !state.minimized && state.tags.intersection(&focused_tags).next().is_some()
+                    })
                 })
                 .cloned()
                 .collect::<Vec<_>>()
@@ -502,6 +606,59 @@ impl Pinnacle {
             tag_ids,
         });
     }
+
+    /// Floats all tiled and spilled windows on the given output in place.
+    ///
+    /// Their new geometries are applied with the next call to `update_windows_from_tree`.
+    fn float_tiled_windows(&mut self, output: &Output) {
+        let Some(output_geo) = self.space.output_geometry(output) else {
+            return;
+        };
+        let non_exclusive_zone = self.non_exclusive_zone(output);
+
+        let tiled_windows = self
+            .windows
+            .iter()
+            .filter(|win| !win.is_x11_override_redirect())
+            .filter(|win| win.output(self).as_ref() == Some(output))
+            .filter(|win| {
+                win.with_state(|state| {
+                    state.layout_mode.is_tiled() || state.layout_mode.is_spilled()
+                })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut updates = Vec::new();
+
+        for win in tiled_windows {
+            let loc = self.space.element_location(&win);
+            let size = win.geometry().size;
+
+            win.with_state_mut(|state| {
+                if let Some(loc) = loc {
+                    state.set_floating_loc(loc);
+                }
+                if !size.is_empty() {
+                    state.floating_size = size;
+                }
+                state.layout_mode.set_floating(true);
+                state.floated_by_output = true;
+            });
+
+            win.configure_states();
+
+            if let Some(geo) = self.compute_window_geometry(&win, output_geo, non_exclusive_zone) {
+                updates.push((win, geo));
+            }
+        }
+
+        if !updates.is_empty() {
+            self.layout_state
+                .pending_window_updates
+                .add_for_output(output, updates);
+        }
+    }
 }
 
 impl State {
@@ -11,10 +11,14 @@ pub mod handlers;
 pub mod hook;
 pub mod input;
 pub mod layout;
+pub mod logind;
+pub mod metrics;
 pub mod output;
+pub mod overview;
 pub mod process;
 pub mod protocol;
 pub mod render;
+pub mod screensaver;
 pub mod session;
 pub mod state;
 pub mod tag;
@@ -0,0 +1,165 @@
+//! Integration with systemd-logind.
+//!
+//! Pinnacle holds a delay inhibitor lock on sleep so configs get a chance to run
+//! their own hooks through [`PinnaclePrepareForSleep`] before the system suspends.
+//! The lock is released once every connected config has handled the signal.
+//!
+//! If logind isn't available, none of this happens and sleep is never delayed.
+//!
+//! [`PinnaclePrepareForSleep`]: crate::api::signal::PinnaclePrepareForSleep
+
+use std::os::fd::OwnedFd;
+
+use smithay::reexports::calloop::{self, channel::Event};
+use tracing::{debug, info, warn};
+
+use crate::{
+    api::signal::Signal,
+    state::{Pinnacle, State},
+};
+
+const LOGIND_DEST: &str = "org.freedesktop.login1";
+const LOGIND_PATH: &str = "/org/freedesktop/login1";
+const LOGIND_MANAGER_IFACE: &str = "org.freedesktop.login1.Manager";
+
+/// An event coming from logind.
+#[derive(Debug)]
+pub enum LogindEvent {
+    /// A new sleep delay inhibitor lock was taken.
+    ///
+    /// Closing the file descriptor releases the lock.
+    InhibitorTaken(OwnedFd),
+    /// The system is about to sleep (`true`) or has just resumed (`false`).
+    PrepareForSleep(bool),
+}
+
+#[derive(Debug, Default)]
+pub struct LogindState {
+    /// The currently held sleep delay inhibitor lock.
+    inhibitor: Option<OwnedFd>,
+    /// Whether the system is going to sleep and we're waiting on configs.
+    preparing_for_sleep: bool,
+}
+
+impl LogindState {
+    /// Returns whether a sleep inhibitor lock is currently held.
+    pub fn holds_inhibitor(&self) -> bool {
+        self.inhibitor.is_some()
+    }
+}
+
+impl Pinnacle {
+    /// Connects to logind on a separate thread and forwards its events to the event loop.
+    ///
+    /// If logind is unavailable, this logs and does nothing else.
+    pub fn insert_logind_source(&self) -> anyhow::Result<()> {
+        let (sender, receiver) = calloop::channel::channel::<LogindEvent>();
+
+        self.loop_handle
+            .insert_source(receiver, |event, _, state| match event {
+                Event::Msg(event) => state.on_logind_event(event),
+                Event::Closed => (),
+            })
+            .map_err(|err| anyhow::anyhow!("failed to insert logind source: {err}"))?;
+
+        std::thread::Builder::new()
+            .name("logind".into())
+            .spawn(move || {
+                if let Err(err) = run_logind(sender) {
+                    info!("logind integration disabled: {err}");
+                }
+            })?;
+
+        Ok(())
+    }
+
+    /// Releases the sleep inhibitor lock once every config has handled the sleep signal.
+    pub fn release_sleep_inhibitor_if_handled(&mut self) {
+        if !self.logind_state.preparing_for_sleep
+            || !self.signal_state.pinnacle_prepare_for_sleep.is_handled()
+        {
+            return;
+        }
+
+        self.logind_state.preparing_for_sleep = false;
+
+        if self.logind_state.inhibitor.take().is_some() {
+            debug!("Released sleep inhibitor lock");
+        }
+    }
+}
+
+impl State {
+    pub fn on_logind_event(&mut self, event: LogindEvent) {
+        match event {
+            LogindEvent::InhibitorTaken(fd) => {
+                debug!("Took sleep inhibitor lock");
+                self.pinnacle.logind_state.inhibitor.replace(fd);
+            }
+            LogindEvent::PrepareForSleep(sleeping) => {
+                self.pinnacle.logind_state.preparing_for_sleep = sleeping;
+                self.pinnacle
+                    .signal_state
+                    .pinnacle_prepare_for_sleep
+                    .signal(sleeping);
+            }
+        }
+    }
+}
+
+fn run_logind(sender: calloop::channel::Sender<LogindEvent>) -> zbus::Result<()> {
+    let connection = zbus::blocking::Connection::system()?;
+    let manager =
+        zbus::blocking::Proxy::new(&connection, LOGIND_DEST, LOGIND_PATH, LOGIND_MANAGER_IFACE)?;
+
+    // Subscribe before taking the lock so no signal is missed in between
+    let prepare_for_sleep = manager.receive_signal("PrepareForSleep")?;
+
+    let take_inhibitor = || -> zbus::Result<OwnedFd> {
+        let fd: zbus::zvariant::OwnedFd = manager.call(
+            "Inhibit",
+            &(
+                "sleep",
+                "Pinnacle",
+                "Letting the config run pre-sleep hooks",
+                "delay",
+            ),
+        )?;
+        Ok(fd.into())
+    };
+
+    if sender
+        .send(LogindEvent::InhibitorTaken(take_inhibitor()?))
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    for message in prepare_for_sleep {
+        let sleeping: bool = match message.body().deserialize() {
+            Ok(sleeping) => sleeping,
+            Err(err) => {
+                warn!("Received malformed PrepareForSleep signal: {err}");
+                continue;
+            }
+        };
+
+        // Re-take the lock on resume so the next sleep is delayed as well
+        if !sleeping {
+            match take_inhibitor() {
+                Ok(fd) => {
+                    if sender.send(LogindEvent::InhibitorTaken(fd)).is_err() {
+                        break;
+                    }
+                }
+                Err(err) => warn!("Failed to take sleep inhibitor lock: {err}"),
+            }
+        }
+
+        if sender.send(LogindEvent::PrepareForSleep(sleeping)).is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
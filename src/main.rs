@@ -17,7 +17,7 @@ use pinnacle::{
         self, Cli, CliSubcommand, ConfigSubcommand, DebugSubcommand, generate_config,
         start_lua_repl,
     },
-    config::{StartupConfig, get_config_dir, parse_startup_config},
+    config::{LogFormat, StartupConfig, get_config_dir, parse_startup_config},
     process::{REMOVE_RUST_BACKTRACE, REMOVE_RUST_LIB_BACKTRACE},
     session::{import_environment, notify_fd},
     state::State,
@@ -29,7 +29,7 @@ use smithay::reexports::{
 };
 use tracing::{error, info, warn};
 use tracing_appender::rolling::Rotation;
-use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt, util::SubscriberInitExt};
+use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt, reload, util::SubscriberInitExt};
 use xdg::BaseDirectories;
 
 #[cfg(feature = "tracy-alloc")]
@@ -72,11 +72,11 @@ async fn main() -> anyhow::Result<()> {
         "debug,h2=warn,hyper=warn,smithay::xwayland::xwm=warn,wgpu_hal=warn,naga=warn,wgpu_core=warn,cosmic_text=warn,iced_wgpu=warn,sctk=error",
     );
 
-    let file_log_layer = tracing_subscriber::fmt::layer()
-        .compact()
-        .with_ansi(false)
-        .with_writer(appender)
-        .with_filter(file_log_env_filter);
+    // The file log format comes from the startup config, which is parsed after logging
+    // is set up, so the format layer is swapped out once it's known
+    let (file_log_layer, file_log_reload_handle) =
+        reload::Layer::new(LogFormat::default().file_log_layer(appender.clone()));
+    let file_log_layer = file_log_layer.with_filter(file_log_env_filter);
 
     let stdout_env_filter =
         env_filter.unwrap_or_else(|_| EnvFilter::new("warn,pinnacle=info,snowcap=info,sctk=error"));
@@ -207,6 +207,13 @@ async fn main() -> anyhow::Result<()> {
 
     let startup_config = startup_config.merge_and_resolve(Some(&cli), &config_dir)?;
 
+    if startup_config.log_format != LogFormat::default()
+        && let Err(err) =
+            file_log_reload_handle.reload(startup_config.log_format.file_log_layer(appender))
+    {
+        warn!("Failed to change the log file format: {err}");
+    }
+
     let mut event_loop: EventLoop<State> = EventLoop::try_new()?;
 
     let mut state = State::new(
@@ -228,6 +235,14 @@ async fn main() -> anyhow::Result<()> {
         env::set_var("WAYLAND_DISPLAY", &state.pinnacle.socket_name);
     }
 
+    state.pinnacle.config.allow_input_injection = startup_config.allow_input_injection;
+    state.pinnacle.config.shortcut_inhibit_allowlist =
+        startup_config.shortcut_inhibit_allowlist.clone();
+
+    if startup_config.numlock_on_start {
+        state.set_numlock(true);
+    }
+
     state
         .pinnacle
         .start_grpc_server(&startup_config.socket_dir.clone())?;
@@ -280,6 +295,14 @@ async fn main() -> anyhow::Result<()> {
         import_environment();
     }
 
+    if let Err(err) = state.pinnacle.insert_logind_source() {
+        warn!("Failed to start logind integration: {err}");
+    }
+
+    if let Err(err) = state.pinnacle.insert_screensaver_source() {
+        warn!("Failed to start org.freedesktop.ScreenSaver integration: {err}");
+    }
+
     if let Err(err) = sd_notify::notify(true, &[sd_notify::NotifyState::Ready]) {
         warn!("Error notifying systemd: {err}");
     }
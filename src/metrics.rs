@@ -0,0 +1,91 @@
+//! Internal counters for diagnosing leaks and load.
+//!
+//! Counts that can be derived from existing state, like the number of open windows,
+//! aren't stored here and are instead computed when metrics are requested.
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicU32, Ordering},
+};
+
+use smithay::output::Output;
+
+use crate::{
+    backend::RenderResult,
+    state::{Pinnacle, WithState},
+};
+
+#[derive(Debug, Default)]
+pub struct Metrics {
+    /// The number of frames rendered and submitted across all outputs.
+    pub frames_rendered: u64,
+    /// The number of render attempts across all outputs that didn't happen or failed.
+    pub frames_skipped: u64,
+    /// The number of times the config was started after the first one.
+    pub config_restarts: u64,
+    config_started: bool,
+    /// Shared with the guards of open streams, which may be dropped off the main thread.
+    active_grpc_streams: Arc<AtomicU32>,
+}
+
+impl Metrics {
+    /// Records the start of a config, counting it as a restart if a config was started before.
+    pub fn record_config_start(&mut self) {
+        if self.config_started {
+            self.config_restarts += 1;
+        }
+        self.config_started = true;
+    }
+
+    /// Returns the number of gRPC streams that are currently open.
+    pub fn active_grpc_streams(&self) -> u32 {
+        self.active_grpc_streams.load(Ordering::Relaxed)
+    }
+
+    /// Counts a newly opened gRPC stream until the returned guard is dropped.
+    pub fn track_grpc_stream(&self) -> GrpcStreamGuard {
+        self.active_grpc_streams.fetch_add(1, Ordering::Relaxed);
+        GrpcStreamGuard(self.active_grpc_streams.clone())
+    }
+}
+
+/// Keeps a gRPC stream counted as active while alive.
+#[derive(Debug)]
+pub struct GrpcStreamGuard(Arc<AtomicU32>);
+
+impl Drop for GrpcStreamGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl Pinnacle {
+    /// Sets the result of the latest render attempt on `output` and counts it.
+    pub fn set_render_result(&mut self, output: &Output, result: RenderResult) {
+        output.with_state_mut(|state| state.last_render_result = Some(result));
+
+        match result {
+            RenderResult::Submitted => self.metrics.frames_rendered += 1,
+            RenderResult::Skipped => self.metrics.frames_skipped += 1,
+            RenderResult::NoDamage => (),
+        }
+    }
+}
+
+/// Returns the resident memory usage of this process in bytes.
+///
+/// Returns `None` if it couldn't be read from `/proc`.
+pub fn memory_usage() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+
+    let kib = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+
+    Some(kib * 1024)
+}
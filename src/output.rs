@@ -1,26 +1,48 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use std::cell::RefCell;
+use std::{
+    cell::RefCell,
+    time::{Duration, Instant},
+};
 
 use indexmap::IndexSet;
 use smithay::{
-    backend::renderer::damage::OutputDamageTracker,
+    backend::renderer::{damage::OutputDamageTracker, element::solid::SolidColorBuffer},
     desktop::layer_map_for_output,
     output::{Mode, Output, Scale},
-    reexports::{drm, wayland_server::backend::GlobalId},
-    utils::{Logical, Point, Size, Transform},
-    wayland::session_lock::LockSurface,
+    reexports::{
+        calloop::{
+            RegistrationToken,
+            timer::{TimeoutAction, Timer},
+        },
+        drm,
+        wayland_server::backend::GlobalId,
+    },
+    utils::{Logical, Physical, Point, Raw, Rectangle, Size, Transform},
+    wayland::{
+        session_lock::LockSurface,
+        shell::wlr_layer::{Anchor, ExclusiveZone},
+    },
 };
-use tracing::debug;
+use tracing::{debug, info};
 
 use crate::{
     api::signal::Signal,
-    backend::BackendData,
+    backend::{BackendData, RenderResult},
     config::ConnectorSavedState,
-    protocol::screencopy::Screencopy,
+    protocol::{output_management::OutputConfiguration, screencopy::Screencopy},
+    render::{
+        color_filter::ColorFilter,
+        offscreen::OffscreenBuffer,
+        util::{screenshot::CachedThumbnail, snapshot::OutputSnapshot},
+    },
     state::{Pinnacle, State, WithState},
     tag::Tag,
-    util::centered_loc,
+    util::{
+        centered_loc,
+        rect::{Direction, resolve_overlaps},
+    },
+    window::WindowElement,
 };
 
 /// A unique identifier for an output.
@@ -43,7 +65,34 @@ impl OutputName {
     }
 }
 
-/// State of an output's blanking status for session lock.
+/// The make, model, and serial number a display identifies itself with.
+#[derive(Debug, Default, Hash, Clone, PartialEq, Eq)]
+pub struct OutputIdentity {
+    pub make: String,
+    pub model: String,
+    pub serial: String,
+}
+
+impl OutputIdentity {
+    /// Returns whether the display reported anything useful to identify it with.
+    pub fn is_known(&self) -> bool {
+        [&self.make, &self.model, &self.serial]
+            .into_iter()
+            .any(|field| !field.is_empty() && field != "Unknown")
+    }
+}
+
+/// Replacements for parts of the identity a misreporting display reports.
+///
+/// Fields that are `None` keep what the display reports.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OutputIdentityOverride {
+    pub make: Option<String>,
+    pub model: Option<String>,
+    pub serial: Option<String>,
+}
+
+/// State of an output's blanking status for session lock or config-requested blanking.
 #[derive(Debug, Default, Copy, Clone)]
 pub enum BlankingState {
     /// The output is not blanked and is displaying normal content.
@@ -67,15 +116,152 @@ pub struct OutputState {
     pub modes: Vec<Mode>,
     pub lock_surface: Option<LockSurface>,
     pub blanking_state: BlankingState,
+    /// Whether the config has blanked this output, e.g. for a screensaver.
+    ///
+    /// An active session lock takes precedence over this.
+    pub blanked: bool,
     /// Whether the monitor is powered.
     ///
     /// Unpowered monitors aren't drawn to but their tags and windows
     /// still exist and can be interacted with.
     pub powered: bool,
+    /// How long to wait without activity before powering off this output,
+    /// overriding the global timeout.
+    pub dpms_timeout: Option<Duration>,
+    /// Whether this output was powered off from inactivity.
+    pub dpms_powered_off: bool,
     /// Damage tracker for debugging damage.
     pub debug_damage_tracker: OutputDamageTracker,
+    /// Buffer for the overlay drawn when outputs are dimmed from inactivity.
+    pub idle_dim_buffer: SolidColorBuffer,
+    /// Buffer for the border drawn around the selected window in the overview.
+    pub overview_selection_buffer: SolidColorBuffer,
     pub is_vrr_on: bool,
     pub is_vrr_on_demand: bool,
+    /// Whether windows on this output are laid out or all float.
+    pub layout_mode: OutputLayoutMode,
+    /// The filter everything on this output is drawn through.
+    pub color_filter: ColorFilter,
+    /// The texture this output is rendered into before its color filter is applied.
+    pub color_filter_buffer: OffscreenBuffer,
+    /// How many times larger than its mode this output is rendered before being downscaled.
+    ///
+    /// 1.0 disables supersampling.
+    pub supersample_factor: f32,
+    /// The texture this output is rendered into before being downscaled.
+    pub supersample_buffer: OffscreenBuffer,
+    /// Whether this output's scale was set explicitly, restored from a saved state,
+    /// or already guessed by auto scale.
+    ///
+    /// Auto scale leaves these outputs alone.
+    pub scale_settled: bool,
+    /// The result of the last attempt to render this output.
+    pub last_render_result: Option<RenderResult>,
+    /// The last thumbnail rendered of this output.
+    pub thumbnail_cache: Option<CachedThumbnail>,
+    /// The crossfade from this output's old contents after a mode or scale change.
+    pub transition: Option<OutputTransition>,
+    /// When the last frame for a compositor-driven animation on this output was let through.
+    pub last_animation_frame: Option<Instant>,
+    /// Whether a throttled animation frame is waiting on a timer.
+    pub animation_frame_pending: bool,
+    /// A mode change that is undone unless it's confirmed in time.
+    pub pending_mode_revert: Option<PendingModeRevert>,
+    /// The settings game mode overrode on this output, or `None` if game mode is off.
+    pub game_mode: Option<GameModeRestore>,
+}
+
+/// A mode change made with [`State::try_output_mode`] that hasn't been confirmed.
+#[derive(Debug)]
+pub struct PendingModeRevert {
+    /// The mode to go back to.
+    pub previous_mode: Mode,
+    /// The timer that goes back to the previous mode.
+    pub token: RegistrationToken,
+}
+
+/// The settings an output had before game mode was turned on.
+#[derive(Debug, Clone, Copy)]
+pub struct GameModeRestore {
+    pub is_vrr_on: bool,
+    pub is_vrr_on_demand: bool,
+}
+
+/// A crossfade from an output's contents before a mode or scale change.
+#[derive(Debug)]
+pub struct OutputTransition {
+    /// The output's contents before the change.
+    ///
+    /// This is `None` if the backend has no renderer to capture it with.
+    pub snapshot: Option<OutputSnapshot>,
+    /// The physical size of the output when the snapshot was captured.
+    pub old_size: Size<i32, Physical>,
+    /// When the transition started, on the compositor clock.
+    pub start: Duration,
+    /// How many frames have been rendered during the transition.
+    pub frames: u32,
+}
+
+impl OutputTransition {
+    /// How long the crossfade lasts.
+    pub const DURATION: Duration = Duration::from_millis(250);
+
+    /// Returns how far along the transition is at `now`, from 0.0 to 1.0.
+    pub fn progress(&self, now: Duration) -> f32 {
+        let elapsed = now.saturating_sub(self.start);
+        (elapsed.as_secs_f32() / Self::DURATION.as_secs_f32()).min(1.0)
+    }
+}
+
+/// How windows on an output are arranged.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputLayoutMode {
+    /// Windows tile according to their own layout mode.
+    #[default]
+    Tiling,
+    /// Every window floats and no layout is requested.
+    Floating,
+}
+
+/// How the integer `wl_output` scale is chosen for fractionally scaled outputs.
+///
+/// Clients that don't support fractional scaling render at this scale and
+/// are scaled to the fractional scale by the compositor.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LegacyScaleMode {
+    /// Round up, making legacy clients render larger and get downscaled.
+    #[default]
+    Ceil,
+    /// Round down, making legacy clients render smaller and get upscaled.
+    Floor,
+    /// Round to the nearest integer.
+    Round,
+    /// Always advertise this scale.
+    Prefer(i32),
+}
+
+impl LegacyScaleMode {
+    /// Returns the integer scale to advertise for the given fractional scale.
+    pub fn integer_scale(self, fractional: f64) -> i32 {
+        let scale = match self {
+            LegacyScaleMode::Ceil => fractional.ceil() as i32,
+            LegacyScaleMode::Floor => fractional.floor() as i32,
+            LegacyScaleMode::Round => fractional.round() as i32,
+            LegacyScaleMode::Prefer(scale) => scale,
+        };
+
+        scale.max(1)
+    }
+
+    /// Returns `scale` with its advertised integer scale chosen by this mode.
+    pub fn apply(self, scale: Scale) -> Scale {
+        let fractional = scale.fractional_scale();
+
+        Scale::Custom {
+            advertised_integer: self.integer_scale(fractional),
+            fractional,
+        }
+    }
 }
 
 impl Default for OutputState {
@@ -87,14 +273,32 @@ impl Default for OutputState {
             modes: Default::default(),
             lock_surface: Default::default(),
             blanking_state: Default::default(),
+            blanked: false,
             powered: true,
+            dpms_timeout: None,
+            dpms_powered_off: false,
             debug_damage_tracker: OutputDamageTracker::new(
                 Size::default(),
                 1.0,
                 Default::default(),
             ),
+            idle_dim_buffer: SolidColorBuffer::new((0.0, 0.0), [0.0, 0.0, 0.0, 0.0]),
+            overview_selection_buffer: SolidColorBuffer::new((0.0, 0.0), [0.0, 0.0, 0.0, 0.0]),
             is_vrr_on: false,
             is_vrr_on_demand: false,
+            layout_mode: OutputLayoutMode::default(),
+            color_filter: ColorFilter::default(),
+            color_filter_buffer: OffscreenBuffer::default(),
+            supersample_factor: 1.0,
+            supersample_buffer: OffscreenBuffer::default(),
+            scale_settled: false,
+            last_render_result: None,
+            thumbnail_cache: None,
+            transition: None,
+            last_animation_frame: None,
+            animation_frame_pending: false,
+            pending_mode_revert: None,
+            game_mode: None,
         }
     }
 }
@@ -168,6 +372,112 @@ impl From<OutputMode> for Mode {
 }
 
 impl Pinnacle {
+    /// Returns the area of `output` windows are laid out in, relative to the output.
+    ///
+    /// This is the output minus the space layer surfaces reserve with exclusive zones,
+    /// except for ones whose exclusive zones are ignored by the config.
+    pub fn non_exclusive_zone(&self, output: &Output) -> Rectangle<i32, Logical> {
+        let map = layer_map_for_output(output);
+
+        let respect = self.config.respect_exclusive_zones;
+        let ignored_namespaces = &self.config.exclusive_zone_ignored_namespaces;
+        if respect && ignored_namespaces.is_empty() {
+            return map.non_exclusive_zone();
+        }
+
+        let Some(mut zone) = self
+            .space
+            .output_geometry(output)
+            .map(|geo| Rectangle::from_size(geo.size))
+        else {
+            return map.non_exclusive_zone();
+        };
+
+        // Mirrors how the layer map reserves space, skipping ignored layers
+        for layer in map.layers() {
+            if !respect
+                || ignored_namespaces
+                    .iter()
+                    .any(|namespace| namespace == layer.namespace())
+            {
+                continue;
+            }
+
+            let state = layer.cached_state();
+            let ExclusiveZone::Exclusive(amount) = state.exclusive_zone else {
+                continue;
+            };
+            let amount = amount as i32;
+            let anchor = state.anchor;
+            let margin = state.margin;
+
+            if anchor.contains(Anchor::LEFT) && !anchor.contains(Anchor::RIGHT) {
+                zone.loc.x += amount + margin.left;
+                zone.size.w -= amount + margin.left;
+            } else if anchor.contains(Anchor::TOP) && !anchor.contains(Anchor::BOTTOM) {
+                zone.loc.y += amount + margin.top;
+                zone.size.h -= amount + margin.top;
+            } else if anchor.contains(Anchor::RIGHT) && !anchor.contains(Anchor::LEFT) {
+                zone.size.w -= amount + margin.right;
+            } else if anchor.contains(Anchor::BOTTOM) && !anchor.contains(Anchor::TOP) {
+                zone.size.h -= amount + margin.bottom;
+            }
+        }
+
+        zone.size.w = zone.size.w.max(0);
+        zone.size.h = zone.size.h.max(0);
+
+        zone
+    }
+
+    /// Moves outputs that overlap others so that none do, keeping `output` where it is.
+    ///
+    /// Outputs that don't overlap anything stay put, so gaps between them are kept.
+    pub fn resolve_output_overlaps(&mut self, output: &Output) {
+        let _span = tracy_client::span!("Pinnacle::resolve_output_overlaps");
+
+        let (outputs, mut geos): (Vec<_>, Vec<_>) = self
+            .space
+            .outputs()
+            .filter_map(|op| Some((op.clone(), self.space.output_geometry(op)?)))
+            .unzip();
+
+        let Some(fixed) = outputs.iter().position(|op| op == output) else {
+            return;
+        };
+
+        let old_locs = geos.iter().map(|geo| geo.loc).collect::<Vec<_>>();
+
+        resolve_overlaps(&mut geos, fixed);
+
+        for ((op, geo), old_loc) in outputs.iter().zip(geos).zip(old_locs) {
+            if geo.loc == old_loc {
+                continue;
+            }
+
+            debug!(
+                "Moving output {} to {:?} to resolve an overlap",
+                op.name(),
+                geo.loc
+            );
+
+            op.change_current_state(None, None, None, Some(geo.loc));
+            self.space.map_output(op, geo.loc);
+            self.signal_state.output_move.signal(op);
+            self.output_layout_changed = true;
+
+            if let Some(saved_state) = self
+                .config
+                .connector_saved_states
+                .get_mut(&OutputName(op.name()))
+            {
+                saved_state.loc = geo.loc;
+            }
+
+            self.request_layout(op);
+        }
+    }
+
     pub fn change_output_state(
         &mut self,
         backend: &mut impl BackendData,
@@ -179,6 +489,8 @@ impl Pinnacle {
     ) {
         let _span = tracy_client::span!("Pinnacle::change_output_state");
 
+        let scale = scale.map(|scale| self.config.legacy_scale_mode.apply(scale));
+
         // Calculate the ratio that the pointer location was over the output's size
         // so we can warp it if the output moves
         let pointer_loc_ratio = self.seat.get_pointer().and_then(|ptr| {
@@ -195,6 +507,18 @@ impl Pinnacle {
         });
 
         let old_scale = output.current_scale().fractional_scale();
+        let old_output_geo = self.space.output_geometry(output);
+
+        let mode_changed = mode.is_some_and(|mode| Some(Mode::from(mode)) != output.current_mode());
+        let scale_changed = scale.is_some_and(|scale| scale.fractional_scale() != old_scale);
+
+        let transition = (self.config.transition_animation
+            && self.config.animation_fps_cap != Some(0)
+            && output.with_state(|state| state.game_mode.is_none())
+            && (mode_changed || scale_changed)
+            && old_output_geo.is_some()
+            && output.with_state(|state| state.powered))
+        .then(|| self.start_output_transition(backend, output));
 
         output.change_current_state(None, transform, scale, location);
 
@@ -203,10 +527,33 @@ impl Pinnacle {
             self.signal_state.output_move.signal(output);
         }
 
+        if location.is_some() || mode.is_some() || transform.is_some() || scale.is_some() {
+            self.output_layout_changed = true;
+        }
+
         if let Some(mode) = mode {
             backend.set_output_mode(output, mode);
         }
 
+        if scale_changed
+            && self
+                .pointer_contents
+                .output_under
+                .as_ref()
+                .and_then(|op| op.upgrade())
+                .as_ref()
+                == Some(output)
+        {
+            self.cursor_state
+                .set_output_scale(output.current_scale().fractional_scale());
+        }
+
+        if self.config.auto_resolve_output_overlaps
+            && (location.is_some() || mode.is_some() || transform.is_some() || scale.is_some())
+        {
+            self.resolve_output_overlaps(output);
+        }
+
         let new_output_geo = self.space.output_geometry(output);
 
         if let (Some(pointer_loc_ratio), Some(new_output_geo)) = (pointer_loc_ratio, new_output_geo)
@@ -227,15 +574,12 @@ impl Pinnacle {
             });
         }
 
+        if let Some(transition) = transition {
+            output.with_state_mut(|state| state.transition = Some(transition));
+        }
+
         if mode.is_some() || transform.is_some() || scale.is_some() {
             layer_map_for_output(output).arrange();
-            if let Some(output_geo) = new_output_geo {
-                self.signal_state.output_resize.signal((
-                    output,
-                    output_geo.size.w.try_into().unwrap_or_default(),
-                    output_geo.size.h.try_into().unwrap_or_default(),
-                ));
-            }
         }
 
         if let Some(scale) = scale {
@@ -291,9 +635,151 @@ impl Pinnacle {
 
             lock_surface.send_configure();
         }
+
+        // Signal last so configs see the output with everything above applied
+        if (mode.is_some() || transform.is_some() || scale.is_some())
+            && let Some(output_geo) = new_output_geo
+        {
+            self.signal_state.output_resize.signal((
+                output,
+                output_geo.size.w.try_into().unwrap_or_default(),
+                output_geo.size.h.try_into().unwrap_or_default(),
+            ));
+        }
+    }
+
+    /// Captures an output's current contents to crossfade from after a mode or scale change.
+    ///
+    /// If the output is already transitioning, the snapshot from the first change is kept
+    /// and the fade restarts, so rapid changes don't capture a half-faded frame.
+    fn start_output_transition(
+        &mut self,
+        backend: &mut impl BackendData,
+        output: &Output,
+    ) -> OutputTransition {
+        if let Some(mut transition) = output.with_state_mut(|state| state.transition.take()) {
+            transition.start = self.clock.now().into();
+            transition.frames = 0;
+            return transition;
+        }
+
+        let old_size = output
+            .current_mode()
+            .map(|mode| output.current_transform().transform_size(mode.size))
+            .unwrap_or_default();
+        let scale = output.current_scale().fractional_scale();
+
+        let snapshot = backend.with_gles_renderer(|renderer| {
+            let elements = crate::render::output_render_elements(
+                output,
+                renderer,
+                &self.space,
+                &self.z_index_stack,
+                self.overview.as_ref(),
+                self.occluding_window(output).as_ref(),
+            );
+            OutputSnapshot::new(elements, scale.into())
+        });
+
+        OutputTransition {
+            snapshot,
+            old_size,
+            start: self.clock.now().into(),
+            frames: 0,
+        }
+    }
+
+    /// Requests another frame for the compositor-driven animations on an output,
+    /// throttled to the animation fps cap.
+    ///
+    /// Returns whether a render should be scheduled right away. If not, one is scheduled
+    /// once the cap allows it, or never if animations are disabled.
+    pub fn request_animation_frame(&mut self, output: &Output) -> bool {
+        if output.with_state(|state| state.game_mode.is_some()) {
+            return false;
+        }
+
+        let interval = match self.config.animation_fps_cap {
+            None => return true,
+            Some(0) => return false,
+            Some(cap) => Duration::from_secs(1) / cap,
+        };
+
+        let now = Instant::now();
+
+        let delay = output.with_state(|state| {
+            state
+                .last_animation_frame
+                .map(|last| interval.saturating_sub(now.duration_since(last)))
+                .unwrap_or_default()
+        });
+
+        if delay.is_zero() {
+            output.with_state_mut(|state| state.last_animation_frame = Some(now));
+            return true;
+        }
+
+        let already_pending = output
+            .with_state_mut(|state| std::mem::replace(&mut state.animation_frame_pending, true));
+
+        if !already_pending {
+            let output = output.downgrade();
+            let _ =
+                self.loop_handle
+                    .insert_source(Timer::from_duration(delay), move |_, _, state| {
+                        if let Some(output) = output.upgrade() {
+                            output.with_state_mut(|state| {
+                                state.animation_frame_pending = false;
+                                state.last_animation_frame = Some(Instant::now());
+                            });
+                            state.schedule_render(&output);
+                        }
+                        TimeoutAction::Drop
+                    });
+        }
+
+        false
+    }
+
+    /// Counts a rendered frame for an output's transition, ending it once it's done.
+    ///
+    /// Returns whether the output is still transitioning and needs another frame.
+    pub fn advance_output_transition(&mut self, output: &Output) -> bool {
+        let now = self.clock.now().into();
+
+        output.with_state_mut(|state| {
+            let Some(transition) = state.transition.as_mut() else {
+                return false;
+            };
+
+            transition.frames += 1;
+
+            if transition.progress(now) >= 1.0 {
+                debug!(
+                    "Output {} transitioned over {} frames",
+                    output.name(),
+                    transition.frames
+                );
+                state.transition = None;
+                return false;
+            }
+
+            true
+        })
+    }
+
+    /// Signals that the output layout changed if it did since the last call.
+    pub fn signal_output_layout_changed(&mut self) {
+        if std::mem::take(&mut self.output_layout_changed) {
+            self.signal_state.pinnacle_output_layout_changed.signal(());
+        }
     }
 
     pub fn set_output_enabled(&mut self, output: &Output, enabled: bool) {
+        if enabled != self.space.output_geometry(output).is_some() {
+            self.output_layout_changed = true;
+        }
+
         if enabled {
             let mut should_signal = false;
 
@@ -334,6 +820,7 @@ impl Pinnacle {
                     loc: output.current_location(),
                     tags: output.with_state(|state| state.tags.clone()),
                     scale: Some(output.current_scale()),
+                    identity: Some(self.output_identity(output)),
                 },
             );
 
@@ -343,6 +830,55 @@ impl Pinnacle {
         }
     }
 
+    /// Returns the identity of the display on `output`, with its identity override applied.
+    pub fn output_identity(&self, output: &Output) -> OutputIdentity {
+        let props = output.physical_properties();
+        let identity_override = self
+            .config
+            .output_identity_overrides
+            .get(&OutputName(output.name()))
+            .cloned()
+            .unwrap_or_default();
+
+        OutputIdentity {
+            make: identity_override.make.unwrap_or(props.make),
+            model: identity_override.model.unwrap_or(props.model),
+            serial: identity_override.serial.unwrap_or(props.serial_number),
+        }
+    }
+
+    /// Finds the state saved for a newly connected output.
+    ///
+    /// State saved for the same display, as identified by [`Pinnacle::output_identity`],
+    /// is preferred so displays keep their state when plugged into another connector.
+    /// Otherwise, state saved for the same connector is used.
+    pub fn saved_output_state(&self, output: &Output) -> Option<&ConnectorSavedState> {
+        let saved_states = &self.config.connector_saved_states;
+        let output_name = OutputName(output.name());
+        let identity = self.output_identity(output);
+
+        let same_connector = saved_states.get(&output_name);
+
+        if same_connector.is_some_and(|saved| {
+            saved
+                .identity
+                .as_ref()
+                .is_none_or(|saved_identity| saved_identity == &identity)
+        }) {
+            return same_connector;
+        }
+
+        identity
+            .is_known()
+            .then(|| {
+                saved_states
+                    .values()
+                    .find(|saved| saved.identity.as_ref() == Some(&identity))
+            })
+            .flatten()
+            .or(same_connector)
+    }
+
     /// Completely remove an output, for example when a monitor is unplugged
     pub fn remove_output(&mut self, output: &Output) {
         let _span = tracy_client::span!("Pinnacle::remove_output");
@@ -360,6 +896,15 @@ impl Pinnacle {
         }
 
         self.space.unmap_output(output);
+        self.output_layout_changed = true;
+
+        if self
+            .overview
+            .as_ref()
+            .is_some_and(|overview| &overview.output == output)
+        {
+            self.overview = None;
+        }
 
         self.output_focus_stack.remove(output);
         if let Some(new_focused_output) = self.output_focus_stack.current_focus() {
@@ -381,10 +926,295 @@ impl Pinnacle {
                 loc: output.current_location(),
                 tags: output.with_state(|state| state.tags.clone()),
                 scale: Some(output.current_scale()),
+                identity: Some(self.output_identity(output)),
             },
         );
 
         self.layout_state.remove_output(output);
+
+        self.cancel_mode_revert(output);
+    }
+
+    /// Keeps `output`'s current mode if it was changed with [`State::try_output_mode`]
+    /// and hasn't been reverted yet.
+    ///
+    /// Anything that sets the mode directly should call this so the pending revert
+    /// doesn't undo it.
+    pub fn cancel_mode_revert(&mut self, output: &Output) {
+        if let Some(revert) = output.with_state_mut(|state| state.pending_mode_revert.take()) {
+            self.loop_handle.remove(revert.token);
+        }
+    }
+
+    /// Returns whether any visible window on the given output demands vrr.
+    ///
+    /// `is_visible` determines whether a window counts as visible on the output.
+    pub fn output_demands_vrr(
+        &self,
+        output: &Output,
+        is_visible: impl Fn(&WindowElement) -> bool,
+    ) -> bool {
+        self.space.elements_for_output(output).any(|win| {
+            let Some(demand) = self.vrr_demand_for_window(win) else {
+                return false;
+            };
+
+            is_visible(win)
+                // FIXME: We probably want to check the actual fullscreen state,
+                // not the layout mode, but this isn't a *super* huge deal
+                && (!demand.fullscreen || win.with_state(|state| state.layout_mode.is_fullscreen()))
+        })
+    }
+
+    /// Returns whether the given output should display a blank frame.
+    ///
+    /// While the session is locked, outputs with a lock surface show it even if
+    /// the config blanked them.
+    pub fn should_blank_output(&self, output: &Output) -> bool {
+        if self.lock_state.is_locking() {
+            return true;
+        }
+
+        if self.lock_state.is_locked() {
+            return output.with_state(|state| state.lock_surface.is_none());
+        }
+
+        output.with_state(|state| state.blanked)
+    }
+
+    /// Returns all mapped outputs in `dir` from `output`, sorted closest to farthest.
+    pub fn outputs_in_dir(&self, output: &Output, dir: Direction) -> Vec<Output> {
+        let Some(op_rect) = self.space.output_geometry(output) else {
+            return Vec::new();
+        };
+
+        let candidates = self.space.outputs().collect::<Vec<_>>();
+        let rects = candidates
+            .iter()
+            .map(|op| self.space.output_geometry(op).expect("mapped"))
+            .collect::<Vec<_>>();
+
+        crate::util::rect::closest_in_dir(op_rect, &rects, dir)
+            .into_iter()
+            .map(|idx| candidates[idx].clone())
+            .collect()
+    }
+}
+
+impl State {
+    /// Blanks or unblanks the given output at the config's request.
+    pub fn set_output_blanked(&mut self, output: &Output, blanked: bool) {
+        let is_unlocked =
+            !self.pinnacle.lock_state.is_locking() && !self.pinnacle.lock_state.is_locked();
+
+        output.with_state_mut(|state| {
+            state.blanked = blanked;
+            // The session lock resets this itself on unlock
+            if !blanked && is_unlocked {
+                state.blanking_state = BlankingState::NotBlanked;
+            }
+        });
+
+        self.schedule_render(output);
+    }
+
+    /// Sets whether windows on the given output are laid out or all float.
+    ///
+    /// Switching to floating frees every tiled window in place, and switching
+    /// back to tiling tiles those windows again. Windows that were already floating
+    /// stay floating.
+    pub fn set_output_layout_mode(&mut self, output: &Output, layout_mode: OutputLayoutMode) {
+        if output.with_state(|state| state.layout_mode) == layout_mode {
+            return;
+        }
+
+        output.with_state_mut(|state| state.layout_mode = layout_mode);
+
+        if layout_mode == OutputLayoutMode::Tiling {
+            for win in self.pinnacle.windows.iter() {
+                if win.output(&self.pinnacle).as_ref() != Some(output)
+                    || !win.with_state_mut(|state| std::mem::take(&mut state.floated_by_output))
+                {
+                    continue;
+                }
+
+                win.with_state_mut(|state| state.layout_mode.set_floating(false));
+                win.configure_states();
+            }
+        }
+
+        self.pinnacle.request_layout(output);
+        self.schedule_render(output);
+    }
+
+    /// Turns game mode on the given output on or off.
+    ///
+    /// Game mode forces vrr on if the backend supports it, stops compositor-driven
+    /// animations, and culls windows covered by an opaque fullscreen window even if
+    /// occlusion culling is off. Turning it off restores the output's previous vrr setting.
+    ///
+    /// Tearing isn't supported, so game mode doesn't enable it.
+    pub fn set_output_game_mode(&mut self, output: &Output, enabled: bool) {
+        if output.with_state(|state| state.game_mode.is_some()) == enabled {
+            return;
+        }
+
+        if enabled {
+            output.with_state_mut(|state| {
+                state.game_mode = Some(GameModeRestore {
+                    is_vrr_on: state.is_vrr_on,
+                    is_vrr_on_demand: state.is_vrr_on_demand,
+                });
+                state.is_vrr_on_demand = false;
+                state.transition.take();
+            });
+            self.backend.set_output_vrr(output, true);
+        } else {
+            let Some(restore) = output.with_state_mut(|state| state.game_mode.take()) else {
+                return;
+            };
+
+            output.with_state_mut(|state| state.is_vrr_on_demand = restore.is_vrr_on_demand);
+            // On-demand vrr is updated on the next render
+            if !restore.is_vrr_on_demand {
+                self.backend.set_output_vrr(output, restore.is_vrr_on);
+            }
+        }
+
+        self.schedule_render(output);
+    }
+
+    /// Changes the given output's mode, going back to its current mode after
+    /// `revert_after` unless the change is confirmed with [`State::confirm_output_modes`].
+    ///
+    /// Trying another mode before confirming keeps the mode from before the first try
+    /// as the one to go back to.
+    pub fn try_output_mode(&mut self, output: &Output, mode: Mode, revert_after: Duration) {
+        let Some(current_mode) = output.current_mode() else {
+            return;
+        };
+
+        let previous_mode = match output.with_state_mut(|state| state.pending_mode_revert.take()) {
+            Some(revert) => {
+                self.pinnacle.loop_handle.remove(revert.token);
+                revert.previous_mode
+            }
+            None => current_mode,
+        };
+
+        self.set_output_mode(output, mode);
+
+        let weak_output = output.downgrade();
+        let token = self
+            .pinnacle
+            .loop_handle
+            .insert_source(Timer::from_duration(revert_after), move |_, _, state| {
+                // The output may have been unplugged in the meantime
+                if let Some(output) = weak_output.upgrade()
+                    && let Some(revert) =
+                        output.with_state_mut(|state| state.pending_mode_revert.take())
+                {
+                    info!(
+                        "Mode change on {} was not confirmed, reverting",
+                        output.name()
+                    );
+                    state.set_output_mode(&output, revert.previous_mode);
+                }
+                TimeoutAction::Drop
+            })
+            .expect("failed to insert mode revert timer");
+
+        output.with_state_mut(|state| {
+            state.pending_mode_revert = Some(PendingModeRevert {
+                previous_mode,
+                token,
+            });
+        });
+    }
+
+    /// Keeps all mode changes made with [`State::try_output_mode`] that haven't
+    /// been reverted yet.
+    pub fn confirm_output_modes(&mut self) {
+        for output in self.pinnacle.outputs.clone() {
+            self.pinnacle.cancel_mode_revert(&output);
+        }
+    }
+
+    /// Returns whether `output` can be set to `config`.
+    ///
+    /// Modes have to be ones the output advertises that pass a test commit,
+    /// and scales have to be positive.
+    pub fn test_output_configuration(
+        &mut self,
+        output: &Output,
+        config: &OutputConfiguration,
+    ) -> bool {
+        let OutputConfiguration::Enabled { mode, scale, .. } = config else {
+            return true;
+        };
+
+        if scale.is_some_and(|scale| scale <= 0.0) {
+            return false;
+        }
+
+        let Some((size, refresh)) = mode else {
+            return true;
+        };
+
+        let Some(mode) = try_pick_mode(
+            output,
+            size.w as u32,
+            size.h as u32,
+            refresh.map(|refresh| refresh.get()),
+        ) else {
+            return false;
+        };
+
+        match self
+            .backend
+            .test_output_mode(output, OutputMode::Smithay(mode))
+        {
+            Ok(()) => true,
+            Err(err) => {
+                debug!("Mode test for {} failed: {err:#}", output.name());
+                false
+            }
+        }
+    }
+
+    fn set_output_mode(&mut self, output: &Output, mode: Mode) {
+        self.pinnacle.change_output_state(
+            &mut self.backend,
+            output,
+            Some(OutputMode::Smithay(mode)),
+            None,
+            None,
+            None,
+        );
+        self.pinnacle.request_layout(output);
+        self.pinnacle
+            .output_management_manager_state
+            .update::<State>();
+    }
+
+    /// Sets how the integer scale advertised to clients without fractional scaling
+    /// is chosen and reapplies it to every output.
+    pub fn set_legacy_scale_mode(&mut self, mode: LegacyScaleMode) {
+        self.pinnacle.config.legacy_scale_mode = mode;
+
+        let outputs = self.pinnacle.space.outputs().cloned().collect::<Vec<_>>();
+
+        for output in outputs {
+            let scale = output.current_scale();
+            self.pinnacle.change_output_state(
+                &mut self.backend,
+                &output,
+                None,
+                None,
+                Some(scale),
+                None,
+            );
+        }
     }
 }
 
@@ -407,3 +1237,62 @@ pub fn try_pick_mode(
             .copied()
     })
 }
+
+/// The pixel density, in pixels per inch, that a scale of 1 is meant for.
+const BASE_DPI: f64 = 96.0;
+
+/// Guesses a sensible scale for `output` from its physical size and current mode.
+///
+/// Returns 1.0 if the output doesn't report a physical size.
+pub fn auto_scale(output: &Output) -> f64 {
+    let Some(mode) = output.current_mode() else {
+        return 1.0;
+    };
+
+    scale_for_density(output.physical_properties().size, mode.size)
+}
+
+/// Picks a scale, in quarter steps, that brings the pixel density
+/// of a display closest to [`BASE_DPI`].
+fn scale_for_density(physical_size_mm: Size<i32, Raw>, mode_size: Size<i32, Physical>) -> f64 {
+    if physical_size_mm.w <= 0 || physical_size_mm.h <= 0 || mode_size.w <= 0 || mode_size.h <= 0 {
+        return 1.0;
+    }
+
+    let diagonal_px = f64::hypot(mode_size.w as f64, mode_size.h as f64);
+    let diagonal_in = f64::hypot(physical_size_mm.w as f64, physical_size_mm.h as f64) / 25.4;
+    let dpi = diagonal_px / diagonal_in;
+
+    let scale = (dpi / BASE_DPI * 4.0).round() / 4.0;
+
+    scale.clamp(1.0, 3.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_for_density_picks_scale_from_dpi() {
+        // 24" 1080p
+        assert_eq!(
+            scale_for_density((531, 299).into(), (1920, 1080).into()),
+            1.0
+        );
+        // 27" 4k
+        assert_eq!(
+            scale_for_density((597, 336).into(), (3840, 2160).into()),
+            1.75
+        );
+        // 13" 2560x1600 laptop
+        assert_eq!(
+            scale_for_density((286, 179).into(), (2560, 1600).into()),
+            2.25
+        );
+    }
+
+    #[test]
+    fn scale_for_density_defaults_without_physical_size() {
+        assert_eq!(scale_for_density((0, 0).into(), (3840, 2160).into()), 1.0);
+    }
+}
@@ -0,0 +1,329 @@
+//! An overview of every window on an output, scaled down onto a grid so one can be picked.
+
+use smithay::{
+    desktop::layer_map_for_output,
+    input::keyboard::Keysym,
+    output::Output,
+    utils::{Logical, Point, Rectangle, Size},
+};
+
+use crate::{
+    focus::keyboard::KeyboardFocusTarget,
+    state::{Pinnacle, State, WithState},
+    window::WindowElement,
+};
+
+/// The gap between grid cells and around the edges of the grid.
+const GAP: i32 = 16;
+
+/// An active overview.
+#[derive(Debug)]
+pub struct Overview {
+    /// The output the overview is on.
+    pub output: Output,
+    /// The windows in the overview, in grid order.
+    pub windows: Vec<WindowElement>,
+    /// The index of the selected window in `windows`.
+    pub selected: usize,
+}
+
+/// A direction to move the overview selection in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Overview {
+    /// Creates an overview of the windows on all of `output`'s tags,
+    /// selecting the focused window if it's one of them.
+    pub fn new(pinnacle: &Pinnacle, output: Output) -> Self {
+        let windows = pinnacle
+            .windows
+            .iter()
+            .filter(|win| {
+                !win.is_x11_override_redirect() && win.output(pinnacle).as_ref() == Some(&output)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let focused = pinnacle
+            .seat
+            .get_keyboard()
+            .and_then(|keyboard| keyboard.current_focus());
+
+        let selected = windows
+            .iter()
+            .position(|win| matches!(&focused, Some(KeyboardFocusTarget::Window(focused)) if focused == win))
+            .unwrap_or_default();
+
+        Self {
+            output,
+            windows,
+            selected,
+        }
+    }
+
+    /// Returns the grid cells of the windows, relative to the output.
+    pub fn cells(&self) -> Vec<Rectangle<i32, Logical>> {
+        let area = layer_map_for_output(&self.output).non_exclusive_zone();
+        grid(area, self.windows.len())
+    }
+
+    /// Returns the index of the window whose cell contains `point`, in the global space.
+    pub fn window_at(&self, point: Point<f64, Logical>) -> Option<usize> {
+        let point = point - self.output.current_location().to_f64();
+        self.cells()
+            .iter()
+            .position(|cell| cell.to_f64().contains(point))
+    }
+
+    /// Moves the selection to the neighboring cell in `direction`, if there is one.
+    pub fn move_selection(&mut self, direction: Direction) {
+        let count = self.windows.len();
+        if count == 0 {
+            return;
+        }
+
+        let columns = columns(count);
+        let selected = self.selected;
+
+        self.selected = match direction {
+            Direction::Left if selected % columns > 0 => selected - 1,
+            Direction::Right if selected % columns < columns - 1 && selected + 1 < count => {
+                selected + 1
+            }
+            Direction::Up if selected >= columns => selected - columns,
+            Direction::Down if selected + columns < count => selected + columns,
+            _ => selected,
+        };
+    }
+
+    /// Removes a window from the overview, keeping the selection in bounds.
+    pub fn remove_window(&mut self, window: &WindowElement) {
+        let Some(index) = self.windows.iter().position(|win| win == window) else {
+            return;
+        };
+
+        self.windows.remove(index);
+
+        if index < self.selected || self.selected >= self.windows.len() {
+            self.selected = self.selected.saturating_sub(1);
+        }
+    }
+}
+
+/// Returns the number of columns a grid of `count` cells has.
+fn columns(count: usize) -> usize {
+    (count as f64).sqrt().ceil().max(1.0) as usize
+}
+
+/// Splits `area` into a grid of `count` equally sized cells,
+/// ordered left to right, then top to bottom.
+pub fn grid(area: Rectangle<i32, Logical>, count: usize) -> Vec<Rectangle<i32, Logical>> {
+    if count == 0 {
+        return Vec::new();
+    }
+
+    let columns = columns(count) as i32;
+    let rows = (count as i32 + columns - 1) / columns;
+
+    let cell_width = ((area.size.w - GAP * (columns + 1)) / columns).max(1);
+    let cell_height = ((area.size.h - GAP * (rows + 1)) / rows).max(1);
+
+    (0..count as i32)
+        .map(|i| {
+            let (column, row) = (i % columns, i / columns);
+            Rectangle::new(
+                (
+                    area.loc.x + GAP + column * (cell_width + GAP),
+                    area.loc.y + GAP + row * (cell_height + GAP),
+                )
+                    .into(),
+                (cell_width, cell_height).into(),
+            )
+        })
+        .collect()
+}
+
+/// Scales `size` down to fit in `cell` and centers it.
+///
+/// Returns the resulting geometry and the scale applied. Windows are never scaled up.
+pub fn fit_in_cell(
+    size: Size<i32, Logical>,
+    cell: Rectangle<i32, Logical>,
+) -> (Rectangle<i32, Logical>, f64) {
+    let scale = if size.w <= 0 || size.h <= 0 {
+        1.0
+    } else {
+        (cell.size.w as f64 / size.w as f64)
+            .min(cell.size.h as f64 / size.h as f64)
+            .min(1.0)
+    };
+
+    let fitted_size = Size::<i32, Logical>::from((
+        (size.w as f64 * scale).round() as i32,
+        (size.h as f64 * scale).round() as i32,
+    ));
+
+    let loc = cell.loc
+        + Point::from((
+            (cell.size.w - fitted_size.w) / 2,
+            (cell.size.h - fitted_size.h) / 2,
+        ));
+
+    (Rectangle::new(loc, fitted_size), scale)
+}
+
+impl State {
+    /// Opens the overview on the focused output, or closes it if it's open.
+    pub fn toggle_overview(&mut self) {
+        if self.pinnacle.overview.is_some() {
+            self.close_overview(false);
+        } else if let Some(output) = self.pinnacle.focused_output().cloned() {
+            self.open_overview(output);
+        }
+    }
+
+    /// Opens the overview on `output`, closing any open overview first.
+    pub fn open_overview(&mut self, output: Output) {
+        if self.pinnacle.overview.is_some() {
+            self.close_overview(false);
+        }
+
+        self.pinnacle.overview = Some(Overview::new(&self.pinnacle, output.clone()));
+
+        self.update_pointer_focus();
+        self.schedule_render(&output);
+    }
+
+    /// Closes the overview.
+    ///
+    /// If `select` is true, the selected window is focused,
+    /// switching to its tag if it's not on an active one.
+    pub fn close_overview(&mut self, select: bool) {
+        let Some(overview) = self.pinnacle.overview.take() else {
+            return;
+        };
+
+        if select && let Some(window) = overview.windows.get(overview.selected).cloned() {
+            if !window.is_on_active_tag()
+                && let Some(tag) = window.with_state(|state| state.tags.first().cloned())
+            {
+                crate::api::tag::switch_to(self, &tag);
+            }
+
+            crate::api::window::set_minimized(self, &window, false);
+
+            self.pinnacle.raise_window(window.clone());
+            self.pinnacle.keyboard_focus_stack.set_focus(window);
+            self.pinnacle.on_demand_layer_focus = None;
+            self.pinnacle.focus_output(&overview.output);
+        }
+
+        self.update_pointer_focus();
+        self.schedule_render(&overview.output);
+    }
+
+    /// Selects the window under `point` and closes the overview,
+    /// or just closes it if there is no window there.
+    pub fn overview_click(&mut self, point: Point<f64, Logical>) {
+        let Some(overview) = self.pinnacle.overview.as_mut() else {
+            return;
+        };
+
+        match overview.window_at(point) {
+            Some(index) => {
+                overview.selected = index;
+                self.close_overview(true);
+            }
+            None => self.close_overview(false),
+        }
+    }
+
+    /// Handles a key pressed while the overview is open.
+    ///
+    /// Arrow keys move the selection, Return and space focus the selected window,
+    /// and Escape closes the overview.
+    pub fn overview_key(&mut self, keysym: Keysym) {
+        let Some(overview) = self.pinnacle.overview.as_mut() else {
+            return;
+        };
+
+        let direction = match keysym {
+            Keysym::Left | Keysym::h => Direction::Left,
+            Keysym::Right | Keysym::l => Direction::Right,
+            Keysym::Up | Keysym::k => Direction::Up,
+            Keysym::Down | Keysym::j => Direction::Down,
+            Keysym::Return | Keysym::KP_Enter | Keysym::space => {
+                self.close_overview(true);
+                return;
+            }
+            Keysym::Escape => {
+                self.close_overview(false);
+                return;
+            }
+            _ => return,
+        };
+
+        overview.move_selection(direction);
+
+        let output = overview.output.clone();
+        self.schedule_render(&output);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_is_empty_without_windows() {
+        assert!(grid(Rectangle::from_size((1920, 1080).into()), 0).is_empty());
+    }
+
+    #[test]
+    fn grid_lays_out_cells_left_to_right_then_top_to_bottom() {
+        let cells = grid(Rectangle::from_size((1000, 1000).into()), 3);
+
+        assert_eq!(cells.len(), 3);
+
+        // 3 cells make a 2x2 grid
+        let size = Size::from(((1000 - GAP * 3) / 2, (1000 - GAP * 3) / 2));
+        assert_eq!(cells[0], Rectangle::new((GAP, GAP).into(), size));
+        assert_eq!(
+            cells[1],
+            Rectangle::new((GAP * 2 + size.w, GAP).into(), size)
+        );
+        assert_eq!(
+            cells[2],
+            Rectangle::new((GAP, GAP * 2 + size.h).into(), size)
+        );
+    }
+
+    #[test]
+    fn grid_is_offset_by_area_location() {
+        let cells = grid(Rectangle::new((100, 50).into(), (500, 500).into()), 1);
+        assert_eq!(cells[0].loc, Point::from((100 + GAP, 50 + GAP)));
+    }
+
+    #[test]
+    fn fit_in_cell_scales_down_and_centers() {
+        let cell = Rectangle::new((0, 0).into(), (400, 400).into());
+        let (geo, scale) = fit_in_cell((800, 400).into(), cell);
+
+        assert_eq!(scale, 0.5);
+        assert_eq!(geo, Rectangle::new((0, 100).into(), (400, 200).into()));
+    }
+
+    #[test]
+    fn fit_in_cell_does_not_scale_up() {
+        let cell = Rectangle::new((0, 0).into(), (400, 400).into());
+        let (geo, scale) = fit_in_cell((200, 100).into(), cell);
+
+        assert_eq!(scale, 1.0);
+        assert_eq!(geo, Rectangle::new((100, 150).into(), (200, 100).into()));
+    }
+}
@@ -8,7 +8,7 @@ use std::{
 
 use passfd::FdPassingExt;
 use sysinfo::{ProcessRefreshKind, ProcessesToUpdate};
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, watch};
 use tracing::warn;
 use xdg::BaseDirectories;
 
@@ -30,8 +30,16 @@ pub struct ExitInfo {
 #[derive(Debug)]
 pub struct ProcessState {
     pub system_processes: sysinfo::System,
-    // FIXME: If we reload the config then this doesn't get cleared
-    spawned: HashMap<u32, tokio::sync::oneshot::Receiver<ExitInfo>>,
+    /// Spawned processes, holding their exit info once they've exited.
+    ///
+    /// These outlive config reloads so a process can be waited on by any number of configs.
+    /// Exited processes are kept until their exit info is waited on or they're reaped with
+    /// [`ProcessState::reap`].
+    ///
+    /// Each process is waited on as soon as it's spawned, so it's reaped by the time its
+    /// exit info is set and never lingers as a zombie. Processes that daemonize by
+    /// double-forking exit here once they fork; their grandchildren are reparented to init.
+    spawned: HashMap<u32, watch::Receiver<Option<ExitInfo>>>,
     spawned_already: HashSet<String>,
 }
 
@@ -197,7 +205,7 @@ impl ProcessState {
             let _ = std::fs::remove_file(socket_path);
         });
 
-        let (oneshot_send, oneshot_recv) = oneshot::channel();
+        let (exit_send, exit_recv) = watch::channel(None);
 
         tokio::spawn(async move {
             let exit_status = child.wait().await;
@@ -207,27 +215,66 @@ impl ProcessState {
                     exit_msg: Some(status.to_string()),
                 })
                 .unwrap_or_default();
-            if oneshot_send.send(exit_info).is_err() {
-                warn!("Failed to send exit info to config");
-            }
+            // Receivers may have all been dropped, that's fine
+            exit_send.send_replace(Some(exit_info));
         });
 
-        self.spawned.insert(pid, oneshot_recv);
+        self.spawned.insert(pid, exit_recv);
         self.spawned_already.insert(arg0.clone());
 
         Some(data)
     }
 
-    pub fn wait_on_spawn(
-        &mut self,
-        pid: u32,
-    ) -> Option<tokio::sync::oneshot::Receiver<Option<ExitInfo>>> {
-        let recv = self.spawned.remove(&pid)?;
-        let (oneshot_tx, oneshot_rx) = tokio::sync::oneshot::channel();
+    /// Stops tracking spawned processes that have exited, returning their pids.
+    ///
+    /// Their exit info can't be waited on afterwards.
+    pub fn reap(&mut self) -> Vec<u32> {
+        let exited = self
+            .spawned
+            .iter()
+            .filter(|(_, exit)| exit.borrow().is_some())
+            .map(|(pid, _)| *pid)
+            .collect::<Vec<_>>();
+
+        for pid in exited.iter() {
+            self.spawned.remove(pid);
+        }
+
+        exited
+    }
+
+    /// Stops tracking the spawned process with `pid` once its exit info has been waited on.
+    pub fn remove_waited(&mut self, pid: u32) {
+        if self
+            .spawned
+            .get(&pid)
+            .is_some_and(|exit| exit.borrow().is_some())
+        {
+            self.spawned.remove(&pid);
+        }
+    }
+
+    /// Returns the pids of tracked spawned processes and whether each is still alive.
+    pub fn children(&self) -> impl Iterator<Item = (u32, bool)> + '_ {
+        self.spawned
+            .iter()
+            .map(|(pid, exit)| (*pid, exit.borrow().is_none()))
+    }
+
+    /// Returns a receiver for the exit info of the spawned process with `pid`.
+    ///
+    /// This can be called any number of times for the same process.
+    pub fn wait_on_spawn(&self, pid: u32) -> Option<oneshot::Receiver<Option<ExitInfo>>> {
+        let mut recv = self.spawned.get(&pid)?.clone();
+        let (oneshot_tx, oneshot_rx) = oneshot::channel();
 
         tokio::spawn(async move {
-            let exit_status = recv.await.ok();
-            oneshot_tx.send(exit_status).unwrap();
+            let exit_status = recv
+                .wait_for(|exit| exit.is_some())
+                .await
+                .ok()
+                .and_then(|exit| exit.clone());
+            let _ = oneshot_tx.send(exit_status);
         });
 
         Some(oneshot_rx)
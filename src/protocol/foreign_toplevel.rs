@@ -168,6 +168,9 @@ fn pending_toplevel_data_for(
 
     let focused = focused_win == Some(win);
 
+    // Includes any app id override, so it has to be read outside of `with_states`
+    let app_id = win.class();
+
     compositor::with_states(&surface, |states| match win.underlying_surface() {
         WindowSurface::Wayland(_toplevel) => {
             let role = states
@@ -178,7 +181,7 @@ fn pending_toplevel_data_for(
 
             Some(PendingToplevelData {
                 title: role.title.clone(),
-                app_id: role.app_id.clone(),
+                app_id,
                 maximized: role.current.states.contains(xdg_toplevel::State::Maximized),
                 minimized: win.with_state(|state| state.minimized),
                 fullscreen: role
@@ -192,7 +195,7 @@ fn pending_toplevel_data_for(
         }
         WindowSurface::X11(x11_surface) => Some(PendingToplevelData {
             title: Some(x11_surface.title()),
-            app_id: Some(x11_surface.class()),
+            app_id,
             maximized: x11_surface.is_maximized(),
             minimized: x11_surface.is_minimized(),
             fullscreen: x11_surface.is_fullscreen(),
@@ -1,10 +1,16 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+pub mod color_filter;
+pub mod color_lut;
+pub mod offscreen;
 pub mod pointer;
 pub mod render_elements;
+pub mod supersample;
 pub mod texture;
 pub mod util;
 
+use std::time::Duration;
+
 use smithay::{
     backend::renderer::{
         ImportAll, ImportMem, Renderer, RendererSuper, Texture,
@@ -12,6 +18,7 @@ use smithay::{
             self, AsRenderElements, RenderElementStates,
             solid::SolidColorRenderElement,
             surface::{WaylandSurfaceRenderElement, render_elements_from_surface_tree},
+            utils::RescaleRenderElement,
         },
         gles::GlesRenderer,
     },
@@ -24,20 +31,23 @@ use smithay::{
     },
     output::Output,
     reexports::wayland_server::protocol::wl_surface::WlSurface,
-    utils::{Logical, Physical, Point, Scale},
+    utils::{Logical, Physical, Point, Rectangle, Scale, Size},
     wayland::shell::wlr_layer,
 };
 use util::{snapshot::SnapshotRenderElement, surface::WlSurfaceTextureRenderElement};
 
 use crate::{
     backend::{Backend, udev::UdevRenderer},
+    overview::Overview,
     pinnacle_render_elements,
-    state::{State, WithState},
+    state::{Pinnacle, State, WithState},
     window::{WindowElement, ZIndexElement},
 };
 
 use self::{
-    pointer::PointerRenderElement, util::surface::texture_render_elements_from_surface_tree,
+    color_filter::ColorFilterRenderElement, pointer::PointerRenderElement,
+    supersample::SupersampleRenderElement,
+    util::surface::texture_render_elements_from_surface_tree,
 };
 
 pub const CLEAR_COLOR: [f32; 4] = [0.6, 0.6, 0.6, 1.0];
@@ -47,9 +57,12 @@ pinnacle_render_elements! {
     #[derive(Debug)]
     pub enum OutputRenderElement<R> {
         Surface = WaylandSurfaceRenderElement<R>,
+        RescaledSurface = RescaleRenderElement<WaylandSurfaceRenderElement<R>>,
         Pointer = PointerRenderElement<R>,
         Snapshot = SnapshotRenderElement,
         SolidColor = SolidColorRenderElement,
+        ColorFilter = ColorFilterRenderElement,
+        Supersample = SupersampleRenderElement,
     }
 }
 
@@ -477,6 +490,7 @@ fn window_render_elements<R: PRenderer + AsGlesRenderer>(
     renderer: &mut R,
     scale: Scale<f64>,
     z_index_stack: &[ZIndexElement],
+    occluder: Option<&WindowElement>,
 ) -> WindowRenderElements<R> {
     let _span = tracy_client::span!("window_render_elements");
 
@@ -509,6 +523,15 @@ fn window_render_elements<R: PRenderer + AsGlesRenderer>(
         ZIndexElement::Unmapping(weak) => weak.upgrade().map(itertools::Either::Right),
     }));
 
+    // Everything below the occluding window is hidden behind it
+    if let Some(index) = occluder.and_then(|occluder| {
+        renderables.iter().position(
+            |renderable| matches!(renderable, itertools::Either::Left(win) if *win == occluder),
+        )
+    }) {
+        renderables.drain(..index);
+    }
+
     let mut popups = Vec::new();
 
     let mut fullscreen_and_up = renderables
@@ -524,10 +547,18 @@ fn window_render_elements<R: PRenderer + AsGlesRenderer>(
                 let loc =
                     space.element_location(win).unwrap_or_default() - output.current_location();
 
+                let alpha = win.with_state(|state| {
+                    if state.layout_mode.is_fullscreen() {
+                        1.0
+                    } else {
+                        state.opacity
+                    }
+                });
+
                 let SplitRenderElements {
                     surface_elements,
                     popup_elements,
-                } = win.render_elements(renderer, loc, scale, 1.0);
+                } = win.render_elements(renderer, loc, scale, alpha);
 
                 popups.extend(popup_elements.into_iter().map(OutputRenderElement::from));
 
@@ -563,6 +594,8 @@ pub fn output_render_elements<R: PRenderer + AsGlesRenderer>(
     renderer: &mut R,
     space: &Space<WindowElement>,
     z_index_stack: &[ZIndexElement],
+    overview: Option<&Overview>,
+    occluder: Option<&WindowElement>,
 ) -> Vec<OutputRenderElement<R>> {
     let _span = tracy_client::span!("output_render_elements");
 
@@ -582,7 +615,16 @@ pub fn output_render_elements<R: PRenderer + AsGlesRenderer>(
         popups: window_popups,
         fullscreen_and_up: fullscreen_and_up_elements,
         rest: rest_of_window_elements,
-    } = window_render_elements::<R>(output, space, renderer, scale, z_index_stack);
+    } = match overview.filter(|overview| &overview.output == output) {
+        Some(overview) => WindowRenderElements {
+            popups: Vec::new(),
+            fullscreen_and_up: Vec::new(),
+            rest: overview_render_elements(overview, renderer, scale),
+        },
+        None => {
+            window_render_elements::<R>(output, space, renderer, scale, z_index_stack, occluder)
+        }
+    };
 
     // Elements render from top to bottom
 
@@ -598,6 +640,126 @@ pub fn output_render_elements<R: PRenderer + AsGlesRenderer>(
     output_render_elements
 }
 
+/// Border width around the selected window in the overview.
+const OVERVIEW_SELECTION_WIDTH: i32 = 4;
+/// Color of the border around the selected window in the overview.
+const OVERVIEW_SELECTION_COLOR: [f32; 4] = [0.5, 0.7, 1.0, 1.0];
+
+/// Renders every window in the overview scaled down into its grid cell,
+/// with a border around the selected one.
+fn overview_render_elements<R: PRenderer + AsGlesRenderer>(
+    overview: &Overview,
+    renderer: &mut R,
+    scale: Scale<f64>,
+) -> Vec<OutputRenderElement<R>> {
+    let _span = tracy_client::span!("overview_render_elements");
+
+    let mut elements = Vec::new();
+
+    for (i, (win, cell)) in overview.windows.iter().zip(overview.cells()).enumerate() {
+        let (geo, factor) = crate::overview::fit_in_cell(win.geometry().size, cell);
+
+        let origin = geo.loc.to_physical_precise_round(scale);
+
+        let SplitRenderElements {
+            surface_elements, ..
+        } = win.render_elements(renderer, geo.loc, scale, 1.0);
+
+        elements.extend(surface_elements.into_iter().map(|elem| {
+            OutputRenderElement::from(RescaleRenderElement::from_element(
+                elem,
+                origin,
+                Scale::from(factor),
+            ))
+        }));
+
+        if i == overview.selected {
+            let border = Rectangle::new(
+                geo.loc - Point::from((OVERVIEW_SELECTION_WIDTH, OVERVIEW_SELECTION_WIDTH)),
+                geo.size + Size::from((OVERVIEW_SELECTION_WIDTH * 2, OVERVIEW_SELECTION_WIDTH * 2)),
+            );
+
+            let elem = overview.output.with_state_mut(|state| {
+                state
+                    .overview_selection_buffer
+                    .update(border.size.to_f64(), OVERVIEW_SELECTION_COLOR);
+                SolidColorRenderElement::from_buffer(
+                    &state.overview_selection_buffer,
+                    border.loc.to_physical_precise_round(scale),
+                    scale,
+                    1.0,
+                    element::Kind::Unspecified,
+                )
+            });
+
+            elements.push(OutputRenderElement::from(elem));
+        }
+    }
+
+    elements
+}
+
+/// Returns an overlay that darkens the given output while outputs are dimmed from inactivity.
+pub fn idle_dim_render_element(
+    pinnacle: &Pinnacle,
+    output: &Output,
+) -> Option<SolidColorRenderElement> {
+    let config = pinnacle
+        .idle_dim
+        .config
+        .filter(|_| pinnacle.idle_dim.dimmed)?;
+    let size = pinnacle.space.output_geometry(output)?.size;
+    let scale = output.current_scale().fractional_scale();
+
+    let elem = output.with_state_mut(|state| {
+        state
+            .idle_dim_buffer
+            .update(size.to_f64(), [0.0, 0.0, 0.0, config.dim_level]);
+        SolidColorRenderElement::from_buffer(
+            &state.idle_dim_buffer,
+            (0, 0),
+            scale,
+            1.0,
+            element::Kind::Unspecified,
+        )
+    });
+
+    Some(elem)
+}
+
+/// Returns the fading snapshot of an output's old contents while it transitions
+/// after a mode or scale change.
+///
+/// `now` is the time on the compositor clock.
+pub fn output_transition_render_element<R: PRenderer + AsGlesRenderer>(
+    output: &Output,
+    renderer: &mut R,
+    now: Duration,
+) -> Option<SnapshotRenderElement> {
+    let scale = output.current_scale().fractional_scale();
+    let size = output
+        .current_mode()
+        .map(|mode| output.current_transform().transform_size(mode.size))?;
+
+    output.with_state(|state| {
+        let transition = state.transition.as_ref()?;
+        let snapshot = transition.snapshot.as_ref()?;
+
+        // Stretch the old contents over the output if its size changed
+        let scale = Scale::from((
+            scale * transition.old_size.w as f64 / size.w as f64,
+            scale * transition.old_size.h as f64 / size.h as f64,
+        ));
+
+        snapshot.render_elements(
+            renderer,
+            (0, 0).into(),
+            scale,
+            1.0 - transition.progress(now),
+        )
+    })
+}
+
 // TODO: docs
 pub fn take_presentation_feedback(
     output: &Output,
@@ -634,6 +796,33 @@ pub fn take_presentation_feedback(
     output_presentation_feedback
 }
 
+impl Pinnacle {
+    /// Returns the topmost opaque fullscreen window on `output` if occlusion culling
+    /// or game mode is on.
+    ///
+    /// Windows below it are fully covered, so they aren't rendered.
+    pub fn occluding_window(&self, output: &Output) -> Option<WindowElement> {
+        if !self.config.occlusion_culling && output.with_state(|state| state.game_mode.is_none()) {
+            return None;
+        }
+
+        let output_geo = self.space.output_geometry(output)?;
+
+        self.space
+            .elements_for_output(output)
+            .rev()
+            .find(|win| {
+                win.with_state(|state| state.layout_mode.is_fullscreen())
+                    && self
+                        .space
+                        .element_geometry(win)
+                        .is_some_and(|geo| geo.contains_rect(output_geo))
+                    && win.is_opaque()
+            })
+            .cloned()
+    }
+}
+
 impl State {
     /// Schedule a new render.
     pub fn schedule_render(&mut self, output: &Output) {
@@ -647,7 +836,9 @@ impl State {
                 winit.schedule_render();
             }
             #[cfg(feature = "testing")]
-            Backend::Dummy(_) => (),
+            Backend::Dummy(dummy) => {
+                dummy.schedule_render(output);
+            }
         }
     }
 }
@@ -0,0 +1,528 @@
+//! Whole-output color filters.
+//!
+//! When an output has a [`ColorFilter`] set, its elements are first rendered into an
+//! offscreen texture which is then drawn with a shader that multiplies every pixel by
+//! the filter's color matrix. Because this happens before the frame is submitted,
+//! screencopies of the output include the filter.
+
+use smithay::{
+    backend::renderer::{
+        Bind, Offscreen, RendererSuper, Texture,
+        element::{self, Element, Id, RenderElement},
+        gles::{
+            GlesError, GlesRenderer, GlesTexProgram, GlesTexture, Uniform, UniformName,
+            UniformType, UniformValue,
+        },
+        utils::{CommitCounter, DamageSet, OpaqueRegions},
+    },
+    output::Output,
+    utils::{Buffer, Physical, Rectangle, Scale, Size, Transform},
+};
+use tracing::warn;
+
+use crate::{backend::udev::UdevRenderer, state::WithState};
+
+use super::{AsGlesRenderer, OutputRenderElement, PRenderer, offscreen::OffscreenTexture};
+
+const SHADER: &str = include_str!("shaders/color_filter.frag");
+
+/// A filter applied to everything drawn on an output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorFilter {
+    /// No filter.
+    #[default]
+    None,
+    /// Desaturates everything.
+    Grayscale,
+    /// Shifts reds that are hard to tell apart for people with protanopia.
+    Protanopia,
+    /// Shifts greens that are hard to tell apart for people with deuteranopia.
+    Deuteranopia,
+    /// Shifts blues that are hard to tell apart for people with tritanopia.
+    Tritanopia,
+    /// Inverts all colors.
+    Invert,
+}
+
+/// A 3x3 color matrix in row-major order along with an offset scaled by alpha.
+type ColorMatrix = ([[f32; 3]; 3], [f32; 3]);
+
+const IDENTITY: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+// Simulation matrices from Machado, Oliveira, and Fernandes (2009) at full severity.
+const PROTANOPIA_SIM: [[f32; 3]; 3] = [
+    [0.152286, 1.052583, -0.204868],
+    [0.114503, 0.786281, 0.099216],
+    [-0.003882, -0.048116, 1.051998],
+];
+const DEUTERANOPIA_SIM: [[f32; 3]; 3] = [
+    [0.367322, 0.860646, -0.227968],
+    [0.280085, 0.672501, 0.047413],
+    [-0.011820, 0.042940, 0.968881],
+];
+const TRITANOPIA_SIM: [[f32; 3]; 3] = [
+    [1.255528, -0.076749, -0.178779],
+    [-0.078411, 0.930809, 0.147602],
+    [0.004733, 0.691367, 0.303900],
+];
+
+// How the color information lost to a deficiency is redistributed to the channels
+// that can still be seen.
+const RED_GREEN_SHIFT: [[f32; 3]; 3] = [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]];
+const BLUE_YELLOW_SHIFT: [[f32; 3]; 3] = [[1.0, 0.0, 0.7], [0.0, 1.0, 0.7], [0.0, 0.0, 0.0]];
+
+impl ColorFilter {
+    /// Returns the matrix this filter multiplies premultiplied colors by,
+    /// or `None` if this filter does nothing.
+    fn color_matrix(&self) -> Option<ColorMatrix> {
+        let matrix = match self {
+            ColorFilter::None => return None,
+            ColorFilter::Grayscale => {
+                // Rec. 709 luma
+                let luma = [0.2126, 0.7152, 0.0722];
+                ([luma, luma, luma], [0.0; 3])
+            }
+            ColorFilter::Protanopia => (daltonize(PROTANOPIA_SIM, RED_GREEN_SHIFT), [0.0; 3]),
+            ColorFilter::Deuteranopia => (daltonize(DEUTERANOPIA_SIM, RED_GREEN_SHIFT), [0.0; 3]),
+            ColorFilter::Tritanopia => (daltonize(TRITANOPIA_SIM, BLUE_YELLOW_SHIFT), [0.0; 3]),
+            ColorFilter::Invert => {
+                let negate = IDENTITY.map(|row| row.map(|val| -val));
+                (negate, [1.0; 3])
+            }
+        };
+
+        Some(matrix)
+    }
+
+    /// Applies this filter to a premultiplied RGBA color.
+    pub fn apply(&self, rgba: [f32; 4]) -> [f32; 4] {
+        let Some((matrix, offset)) = self.color_matrix() else {
+            return rgba;
+        };
+
+        let [r, g, b, a] = rgba;
+        let row = |i: usize| {
+            let [x, y, z] = matrix[i];
+            (x * r + y * g + z * b + offset[i] * a).clamp(0.0, a)
+        };
+
+        [row(0), row(1), row(2), a]
+    }
+
+    /// Returns this filter's color matrix as a column-major 4x4 matrix for the shader.
+    fn uniform_matrix(&self) -> Option<[[f32; 4]; 4]> {
+        let (matrix, offset) = self.color_matrix()?;
+
+        let column = |i: usize| [matrix[0][i], matrix[1][i], matrix[2][i], 0.0];
+
+        Some([
+            column(0),
+            column(1),
+            column(2),
+            [offset[0], offset[1], offset[2], 1.0],
+        ])
+    }
+}
+
+/// Builds a matrix that moves the color information lost according to `sim`
+/// into the channels given by `shift`.
+///
+/// This is `I + shift * (I - sim)`.
+fn daltonize(sim: [[f32; 3]; 3], shift: [[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    let mut ret = IDENTITY;
+
+    for (row, ret_row) in ret.iter_mut().enumerate() {
+        for (col, val) in ret_row.iter_mut().enumerate() {
+            *val += (0..3)
+                .map(|k| shift[row][k] * (IDENTITY[k][col] - sim[k][col]))
+                .sum::<f32>();
+        }
+    }
+
+    ret
+}
+
+/// The compiled color filter shader, stored in the renderer's EGL context.
+///
+/// `None` if compilation failed so it isn't retried every frame.
+struct ColorFilterProgram(Option<GlesTexProgram>);
+
+fn color_filter_program(renderer: &mut GlesRenderer) -> Option<GlesTexProgram> {
+    if let Some(ColorFilterProgram(program)) = renderer.egl_context().user_data().get() {
+        return program.clone();
+    }
+
+    let program = renderer
+        .compile_custom_texture_shader(
+            SHADER,
+            &[UniformName::new("color_matrix", UniformType::Matrix4x4)],
+        )
+        .inspect_err(|err| warn!("Failed to compile color filter shader: {err}"))
+        .ok();
+
+    renderer
+        .egl_context()
+        .user_data()
+        .insert_if_missing(|| ColorFilterProgram(program.clone()));
+
+    program
+}
+
+/// Replaces `elements` with a single element that draws them through `output`'s
+/// color filter.
+///
+/// Does nothing if the output has no filter.
+pub fn apply_output_color_filter<R>(
+    renderer: &mut R,
+    output: &Output,
+    elements: &mut Vec<OutputRenderElement<R>>,
+    clear_color: [f32; 4],
+) where
+    R: PRenderer + Offscreen<GlesTexture> + Bind<GlesTexture> + AsGlesRenderer,
+    OutputRenderElement<R>: RenderElement<R>,
+{
+    let filter = output.with_state(|state| state.color_filter);
+
+    if let Some(elem) =
+        ColorFilterRenderElement::new(renderer, output, filter, elements, clear_color)
+    {
+        *elements = vec![OutputRenderElement::ColorFilter(elem)];
+    }
+}
+
+/// An output's contents rendered to a texture and drawn through a [`ColorFilter`].
+#[derive(Debug)]
+pub struct ColorFilterRenderElement {
+    texture: OffscreenTexture,
+    size: Size<i32, Physical>,
+    program: GlesTexProgram,
+    matrix: [[f32; 4]; 4],
+}
+
+impl ColorFilterRenderElement {
+    /// Renders `elements` for `output` into the output's color filter texture to be drawn
+    /// through the filter.
+    ///
+    /// Returns `None` if the output has no filter or rendering failed, in which case
+    /// the elements should be drawn as-is.
+    pub fn new<R>(
+        renderer: &mut R,
+        output: &Output,
+        filter: ColorFilter,
+        elements: &[OutputRenderElement<R>],
+        clear_color: [f32; 4],
+    ) -> Option<Self>
+    where
+        R: PRenderer + Offscreen<GlesTexture> + Bind<GlesTexture> + AsGlesRenderer,
+        OutputRenderElement<R>: RenderElement<R>,
+    {
+        let _span = tracy_client::span!("ColorFilterRenderElement::new");
+
+        let matrix = filter.uniform_matrix()?;
+        let program = color_filter_program(renderer.as_gles_renderer())?;
+
+        let size = output
+            .current_transform()
+            .transform_size(output.current_mode()?.size);
+        let scale = Scale::from(output.current_scale().fractional_scale());
+
+        // The filtered texture replaces the whole frame, so the clear color is drawn underneath
+        let texture = output
+            .with_state(|state| state.color_filter_buffer.clone())
+            .render(
+                renderer,
+                elements,
+                size,
+                scale,
+                Scale::from(1.0),
+                clear_color,
+            )
+            .inspect_err(|err| warn!("Failed to render output for color filter: {err}"))
+            .ok()?;
+
+        Some(Self {
+            texture,
+            size,
+            program,
+            matrix,
+        })
+    }
+}
+
+impl Element for ColorFilterRenderElement {
+    fn id(&self) -> &Id {
+        &self.texture.id
+    }
+
+    fn current_commit(&self) -> CommitCounter {
+        self.texture.commit
+    }
+
+    fn src(&self) -> Rectangle<f64, Buffer> {
+        Rectangle::from_size(self.texture.texture.size()).to_f64()
+    }
+
+    fn geometry(&self, _scale: Scale<f64>) -> Rectangle<i32, Physical> {
+        Rectangle::from_size(self.size)
+    }
+
+    fn damage_since(
+        &self,
+        scale: Scale<f64>,
+        commit: Option<CommitCounter>,
+    ) -> DamageSet<i32, Physical> {
+        self.texture.damage_since(self.geometry(scale), commit)
+    }
+
+    fn opaque_regions(&self, _scale: Scale<f64>) -> OpaqueRegions<i32, Physical> {
+        // The clear color was drawn underneath, so every pixel is opaque
+        OpaqueRegions::from_slice(&[Rectangle::from_size(self.size)])
+    }
+
+    fn kind(&self) -> element::Kind {
+        element::Kind::Unspecified
+    }
+}
+
+impl RenderElement<GlesRenderer> for ColorFilterRenderElement {
+    fn draw(
+        &self,
+        frame: &mut <GlesRenderer as RendererSuper>::Frame<'_, '_>,
+        src: Rectangle<f64, Buffer>,
+        dst: Rectangle<i32, Physical>,
+        damage: &[Rectangle<i32, Physical>],
+        opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), GlesError> {
+        frame.render_texture_from_to(
+            &self.texture.texture,
+            src,
+            dst,
+            damage,
+            opaque_regions,
+            Transform::Normal,
+            1.0,
+            Some(&self.program),
+            &[Uniform::new(
+                "color_matrix",
+                UniformValue::Matrix4x4 {
+                    matrices: vec![self.matrix],
+                    transpose: false,
+                },
+            )],
+        )
+    }
+
+    fn underlying_storage(
+        &self,
+        renderer: &mut GlesRenderer,
+    ) -> Option<element::UnderlyingStorage<'_>> {
+        let _ = renderer;
+        None
+    }
+}
+
+impl<'a> RenderElement<UdevRenderer<'a>> for ColorFilterRenderElement {
+    fn draw(
+        &self,
+        frame: &mut <UdevRenderer<'a> as RendererSuper>::Frame<'_, '_>,
+        src: Rectangle<f64, Buffer>,
+        dst: Rectangle<i32, Physical>,
+        damage: &[Rectangle<i32, Physical>],
+        opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <UdevRenderer<'a> as RendererSuper>::Error> {
+        RenderElement::<GlesRenderer>::draw(
+            self,
+            frame.as_mut(),
+            src,
+            dst,
+            damage,
+            opaque_regions,
+        )?;
+        Ok(())
+    }
+
+    fn underlying_storage(
+        &self,
+        renderer: &mut UdevRenderer<'a>,
+    ) -> Option<element::UnderlyingStorage<'_>> {
+        let _ = renderer;
+        None
+    }
+}
+
+#[cfg(feature = "testing")]
+impl RenderElement<smithay::backend::renderer::test::DummyRenderer> for ColorFilterRenderElement {
+    fn draw(
+        &self,
+        _frame: &mut <smithay::backend::renderer::test::DummyRenderer as RendererSuper>::Frame<
+            'static,
+            'static,
+        >,
+        _src: Rectangle<f64, Buffer>,
+        _dst: Rectangle<i32, Physical>,
+        _damage: &[Rectangle<i32, Physical>],
+        _opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <smithay::backend::renderer::test::DummyRenderer as RendererSuper>::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smithay::{
+        backend::{
+            allocator::Fourcc,
+            renderer::{ExportMem, element::solid::SolidColorRenderElement},
+        },
+        output::{Mode, PhysicalProperties, Subpixel},
+    };
+
+    use super::*;
+    use crate::render::{offscreen::test_renderer, util::render_to_texture};
+
+    fn assert_close(actual: [f32; 4], expected: [f32; 4]) {
+        for (a, e) in actual.into_iter().zip(expected) {
+            assert!(
+                (a - e).abs() < 0.001,
+                "expected {expected:?}, got {actual:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn none_filter_has_no_matrix() {
+        assert_eq!(ColorFilter::None.uniform_matrix(), None);
+        assert_eq!(
+            ColorFilter::None.apply([0.2, 0.4, 0.6, 1.0]),
+            [0.2, 0.4, 0.6, 1.0]
+        );
+    }
+
+    #[test]
+    fn non_none_filters_have_matrix() {
+        for filter in [
+            ColorFilter::Grayscale,
+            ColorFilter::Protanopia,
+            ColorFilter::Deuteranopia,
+            ColorFilter::Tritanopia,
+            ColorFilter::Invert,
+        ] {
+            assert!(
+                filter.uniform_matrix().is_some(),
+                "{filter:?} has no matrix"
+            );
+        }
+    }
+
+    #[test]
+    fn invert_filter_inverts_premultiplied_colors() {
+        assert_close(
+            ColorFilter::Invert.apply([1.0, 0.0, 0.0, 1.0]),
+            [0.0, 1.0, 1.0, 1.0],
+        );
+        assert_close(
+            ColorFilter::Invert.apply([0.5, 0.0, 0.0, 0.5]),
+            [0.0, 0.5, 0.5, 0.5],
+        );
+    }
+
+    #[test]
+    fn grayscale_filter_uses_luma() {
+        let gray = ColorFilter::Grayscale.apply([0.0, 1.0, 0.0, 1.0]);
+        assert_close(gray, [0.7152, 0.7152, 0.7152, 1.0]);
+        assert_close(ColorFilter::Grayscale.apply([1.0; 4]), [1.0; 4]);
+    }
+
+    #[test]
+    fn daltonize_filters_preserve_gray() {
+        for filter in [
+            ColorFilter::Protanopia,
+            ColorFilter::Deuteranopia,
+            ColorFilter::Tritanopia,
+        ] {
+            assert_close(filter.apply([0.5, 0.5, 0.5, 1.0]), [0.5, 0.5, 0.5, 1.0]);
+        }
+    }
+
+    #[test]
+    fn uniform_matrix_matches_apply() {
+        let color = [0.3, 0.6, 0.9, 1.0];
+
+        for filter in [ColorFilter::Protanopia, ColorFilter::Invert] {
+            let m = filter.uniform_matrix().unwrap();
+            let shader =
+                std::array::from_fn(|row| (0..4).map(|col| m[col][row] * color[col]).sum::<f32>());
+            let shader = shader.map(|c: f32| c.clamp(0.0, 1.0));
+
+            assert_close(shader, filter.apply(color));
+        }
+    }
+
+    #[test]
+    fn invert_filter_is_applied_to_rendered_output() {
+        let mut renderer = test_renderer();
+
+        let output = Output::new(
+            "color-filter-test".to_string(),
+            PhysicalProperties {
+                size: (0, 0).into(),
+                subpixel: Subpixel::Unknown,
+                make: String::new(),
+                model: String::new(),
+                serial_number: String::new(),
+            },
+        );
+        output.change_current_state(
+            Some(Mode {
+                size: (4, 4).into(),
+                refresh: 60000,
+            }),
+            Some(Transform::Normal),
+            None,
+            None,
+        );
+
+        let red = OutputRenderElement::<GlesRenderer>::SolidColor(SolidColorRenderElement::new(
+            Id::new(),
+            Rectangle::from_size((4, 4).into()),
+            CommitCounter::default(),
+            [1.0, 0.0, 0.0, 1.0],
+            element::Kind::Unspecified,
+        ));
+
+        let filtered = ColorFilterRenderElement::new(
+            &mut renderer,
+            &output,
+            ColorFilter::Invert,
+            &[red],
+            [0.0, 0.0, 0.0, 1.0],
+        )
+        .unwrap();
+
+        let (mut texture, _) = render_to_texture(
+            &mut renderer,
+            [&filtered],
+            (4, 4).into(),
+            Scale::from(1.0),
+            Transform::Normal,
+            Fourcc::Abgr8888,
+        )
+        .unwrap();
+
+        let framebuffer = renderer.bind(&mut texture).unwrap();
+        let mapping = renderer
+            .copy_framebuffer(
+                &framebuffer,
+                Rectangle::from_size((4, 4).into()),
+                Fourcc::Abgr8888,
+            )
+            .unwrap();
+        drop(framebuffer);
+        let pixels = renderer.map_texture(&mapping).unwrap();
+
+        // Red inverts to cyan
+        for pixel in pixels.chunks_exact(4) {
+            assert_eq!(pixel, [0, 255, 255, 255]);
+        }
+    }
+}
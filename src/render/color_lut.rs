@@ -0,0 +1,339 @@
+//! Calibration LUTs applied as output gamma ramps.
+//!
+//! Two kinds of files are understood:
+//! - 1D LUTs in the `.cube` format, or the same thing without any header: one `r g b`
+//!   row per line with values from 0.0 to 1.0.
+//! - ICC profiles with a `vcgt` (video card gamma table) tag, either as a table or as a
+//!   per-channel formula.
+//!
+//! The parsed curves are resampled to the output's gamma size and set through DRM.
+
+use std::path::Path;
+
+use anyhow::{Context, bail, ensure};
+use smithay::output::Output;
+
+use crate::backend::Backend;
+
+/// The number of entries a `vcgt` formula is sampled at.
+const FORMULA_SAMPLES: usize = 256;
+
+/// A per-channel color lookup table with values from 0.0 to 1.0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorLut {
+    channels: [Vec<f64>; 3],
+}
+
+impl ColorLut {
+    /// Parses a `.cube` 1D LUT, a headerless `r g b` LUT, or an ICC profile's `vcgt` tag.
+    pub fn parse(data: &[u8]) -> anyhow::Result<Self> {
+        if data.get(36..40) == Some(&b"acsp"[..]) {
+            return Self::parse_icc(data);
+        }
+
+        let text =
+            std::str::from_utf8(data).context("file is neither an ICC profile nor a text LUT")?;
+        Self::parse_cube(text)
+    }
+
+    fn parse_cube(text: &str) -> anyhow::Result<Self> {
+        let mut size = None;
+        let mut channels = [Vec::new(), Vec::new(), Vec::new()];
+
+        for (line_number, line) in text.lines().enumerate() {
+            let line_number = line_number + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            let first = words.next().expect("line isn't empty");
+
+            match first {
+                "TITLE" | "DOMAIN_MIN" | "DOMAIN_MAX" => continue,
+                "LUT_1D_SIZE" => {
+                    let lut_size = words
+                        .next()
+                        .and_then(|size| size.parse::<usize>().ok())
+                        .with_context(|| format!("line {line_number}: invalid LUT_1D_SIZE"))?;
+                    size = Some(lut_size);
+                    continue;
+                }
+                "LUT_3D_SIZE" => bail!("3D LUTs are not supported"),
+                _ => (),
+            }
+
+            let row = std::iter::once(first)
+                .chain(words)
+                .map(|value| value.parse::<f64>())
+                .collect::<Result<Vec<_>, _>>()
+                .with_context(|| format!("line {line_number}: expected numbers"))?;
+
+            let [r, g, b] = row[..] else {
+                bail!("line {line_number}: expected 3 values, got {}", row.len());
+            };
+
+            for (channel, value) in channels.iter_mut().zip([r, g, b]) {
+                channel.push(value.clamp(0.0, 1.0));
+            }
+        }
+
+        if let Some(size) = size {
+            ensure!(
+                channels[0].len() == size,
+                "LUT_1D_SIZE is {size} but the file has {} entries",
+                channels[0].len()
+            );
+        }
+
+        Self::new(channels)
+    }
+
+    fn parse_icc(data: &[u8]) -> anyhow::Result<Self> {
+        let tag_count = read_u32(data, 128).context("ICC profile has no tag table")? as usize;
+
+        let vcgt = (0..tag_count)
+            .map(|i| 132 + i * 12)
+            .find(|&entry| data.get(entry..entry + 4) == Some(&b"vcgt"[..]))
+            .context("ICC profile has no vcgt tag")?;
+
+        let offset = read_u32(data, vcgt + 4).context("truncated ICC tag table")? as usize;
+        let tag_type = read_u32(data, offset + 8).context("truncated vcgt tag")?;
+
+        match tag_type {
+            // Table
+            0 => {
+                let channel_count = read_u16(data, offset + 12).context("truncated vcgt tag")?;
+                let entry_count =
+                    read_u16(data, offset + 14).context("truncated vcgt tag")? as usize;
+                let entry_size = read_u16(data, offset + 16).context("truncated vcgt tag")?;
+
+                ensure!(
+                    channel_count == 3,
+                    "vcgt tables with {channel_count} channels are not supported"
+                );
+
+                let max = match entry_size {
+                    1 => u8::MAX as f64,
+                    2 => u16::MAX as f64,
+                    _ => bail!("vcgt entries of {entry_size} bytes are not supported"),
+                };
+
+                let mut channels = [Vec::new(), Vec::new(), Vec::new()];
+                let mut pos = offset + 18;
+                for channel in channels.iter_mut() {
+                    for _ in 0..entry_count {
+                        let value = match entry_size {
+                            1 => data.get(pos).copied().map(u16::from),
+                            _ => read_u16(data, pos),
+                        }
+                        .context("truncated vcgt table")?;
+                        channel.push(value as f64 / max);
+                        pos += entry_size as usize;
+                    }
+                }
+
+                Self::new(channels)
+            }
+            // Formula
+            1 => {
+                let mut channels = [Vec::new(), Vec::new(), Vec::new()];
+                for (i, channel) in channels.iter_mut().enumerate() {
+                    let params = offset + 12 + i * 12;
+                    let [gamma, min, max] = [0, 4, 8].map(|field| {
+                        read_u32(data, params + field).map(|fixed| fixed as f64 / 65536.0)
+                    });
+                    let (Some(gamma), Some(min), Some(max)) = (gamma, min, max) else {
+                        bail!("truncated vcgt formula");
+                    };
+
+                    *channel = (0..FORMULA_SAMPLES)
+                        .map(|i| {
+                            let x = i as f64 / (FORMULA_SAMPLES - 1) as f64;
+                            (min + (max - min) * x.powf(gamma)).clamp(0.0, 1.0)
+                        })
+                        .collect();
+                }
+
+                Self::new(channels)
+            }
+            _ => bail!("unknown vcgt type {tag_type}"),
+        }
+    }
+
+    fn new(channels: [Vec<f64>; 3]) -> anyhow::Result<Self> {
+        ensure!(
+            channels[0].len() >= 2,
+            "LUT needs at least 2 entries, got {}",
+            channels[0].len()
+        );
+
+        Ok(Self { channels })
+    }
+
+    /// Resamples this LUT into red, green, and blue gamma ramps with `gamma_size` entries.
+    pub fn ramps(&self, gamma_size: usize) -> [Vec<u16>; 3] {
+        self.channels
+            .each_ref()
+            .map(|channel| resample(channel, gamma_size))
+    }
+}
+
+/// Linearly interpolates `values` into `size` entries scaled to the full `u16` range.
+fn resample(values: &[f64], size: usize) -> Vec<u16> {
+    let last = (values.len() - 1) as f64;
+
+    (0..size)
+        .map(|i| {
+            let pos = match size {
+                1 => 0.0,
+                _ => i as f64 / (size - 1) as f64 * last,
+            };
+            let lower = pos.floor() as usize;
+            let upper = (lower + 1).min(values.len() - 1);
+            let t = pos - lower as f64;
+            let value = values[lower] + (values[upper] - values[lower]) * t;
+            (value * u16::MAX as f64).round() as u16
+        })
+        .collect()
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+/// Loads the LUT or ICC profile at `path` and sets it as the gamma ramps of `output`.
+///
+/// This fails if the file can't be parsed or the output doesn't support setting gamma.
+pub fn load_color_lut(backend: &mut Backend, output: &Output, path: &Path) -> anyhow::Result<()> {
+    let Backend::Udev(udev) = backend else {
+        bail!("setting gamma is only supported on the udev backend");
+    };
+
+    let gamma_size = udev.gamma_size(output)? as usize;
+    ensure!(
+        gamma_size != 0,
+        "output {} does not support setting gamma",
+        output.name()
+    );
+
+    let data = std::fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let lut =
+        ColorLut::parse(&data).with_context(|| format!("failed to parse {}", path.display()))?;
+
+    let [red, green, blue] = lut.ramps(gamma_size);
+    udev.set_gamma(output, Some([&red, &green, &blue]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cube_lut_resamples_to_gamma_size() {
+        let lut = ColorLut::parse(
+            b"# calibration\n\
+              TITLE \"test\"\n\
+              LUT_1D_SIZE 3\n\
+              0.0 0.0 0.0\n\
+              0.5 0.25 0.5\n\
+              1.0 0.5 1.0\n",
+        )
+        .unwrap();
+
+        let [red, green, blue] = lut.ramps(256);
+        assert_eq!(red.len(), 256);
+        assert_eq!(green.len(), 256);
+        assert_eq!(blue.len(), 256);
+
+        assert_eq!(red[0], 0);
+        assert_eq!(red[255], u16::MAX);
+        assert_eq!(green[255], 32768);
+        assert_eq!(red, blue);
+    }
+
+    #[test]
+    fn headerless_lut_parses() {
+        let lut = ColorLut::parse(b"0 0 0\n1 1 1\n").unwrap();
+        let [red, _, _] = lut.ramps(3);
+        assert_eq!(red, [0, 32768, u16::MAX]);
+    }
+
+    #[test]
+    fn mismatched_lut_size_is_an_error() {
+        assert!(ColorLut::parse(b"LUT_1D_SIZE 4\n0 0 0\n1 1 1\n").is_err());
+    }
+
+    #[test]
+    fn unsupported_files_are_errors() {
+        assert!(ColorLut::parse(b"LUT_3D_SIZE 2\n").is_err());
+        assert!(ColorLut::parse(b"0 0\n1 1\n").is_err());
+        assert!(ColorLut::parse(&[0xff, 0xfe, 0x00]).is_err());
+        assert!(ColorLut::parse(b"0 0 0\n").is_err());
+    }
+
+    fn icc_with_vcgt(vcgt: &[u8]) -> Vec<u8> {
+        let mut data = vec![0u8; 128];
+        data[36..40].copy_from_slice(b"acsp");
+        data.extend(1u32.to_be_bytes());
+        data.extend(b"vcgt");
+        data.extend(144u32.to_be_bytes());
+        data.extend((vcgt.len() as u32).to_be_bytes());
+        data.extend(vcgt);
+        data
+    }
+
+    #[test]
+    fn icc_vcgt_table_parses() {
+        let mut vcgt = b"vcgt".to_vec();
+        vcgt.extend([0; 4]);
+        vcgt.extend(0u32.to_be_bytes());
+        vcgt.extend(3u16.to_be_bytes());
+        vcgt.extend(2u16.to_be_bytes());
+        vcgt.extend(2u16.to_be_bytes());
+        for _ in 0..3 {
+            vcgt.extend(0u16.to_be_bytes());
+            vcgt.extend(u16::MAX.to_be_bytes());
+        }
+
+        let lut = ColorLut::parse(&icc_with_vcgt(&vcgt)).unwrap();
+        let [red, green, blue] = lut.ramps(1024);
+        assert_eq!(red.len(), 1024);
+        assert_eq!(red, green);
+        assert_eq!(green, blue);
+        assert_eq!(red[1023], u16::MAX);
+    }
+
+    #[test]
+    fn icc_vcgt_formula_parses() {
+        let mut vcgt = b"vcgt".to_vec();
+        vcgt.extend([0; 4]);
+        vcgt.extend(1u32.to_be_bytes());
+        for _ in 0..3 {
+            vcgt.extend(65536u32.to_be_bytes()); // gamma 1.0
+            vcgt.extend(0u32.to_be_bytes());
+            vcgt.extend(32768u32.to_be_bytes()); // max 0.5
+        }
+
+        let lut = ColorLut::parse(&icc_with_vcgt(&vcgt)).unwrap();
+        let [red, _, _] = lut.ramps(2);
+        assert_eq!(red, [0, 32768]);
+    }
+
+    #[test]
+    fn icc_without_vcgt_is_an_error() {
+        let mut data = vec![0u8; 128];
+        data[36..40].copy_from_slice(b"acsp");
+        data.extend(0u32.to_be_bytes());
+        assert!(ColorLut::parse(&data).is_err());
+    }
+}
@@ -0,0 +1,290 @@
+//! Offscreen textures kept between frames.
+//!
+//! Whole-output effects like supersampling and color filters render the output's
+//! elements into a texture first. Reallocating that texture every frame is expensive
+//! and makes the resulting element look fully damaged every frame, so an
+//! [`OffscreenBuffer`] keeps the texture around and only redraws what changed.
+
+use std::{cell::RefCell, rc::Rc};
+
+use anyhow::anyhow;
+use smithay::{
+    backend::{
+        allocator::Fourcc,
+        renderer::{
+            Bind, ContextId, Offscreen, Renderer,
+            damage::OutputDamageTracker,
+            element::{Id, RenderElement},
+            gles::GlesTexture,
+            utils::{CommitCounter, DamageBag, DamageSet, DamageSnapshot},
+        },
+    },
+    utils::{Physical, Rectangle, Scale, Size, Transform},
+};
+
+use super::AsGlesRenderer;
+
+/// How many frames of damage are kept for elements that are a few commits behind.
+const DAMAGE_HISTORY: usize = 4;
+
+/// A texture that elements are rendered into, kept while its size stays the same.
+///
+/// Clones share the same texture.
+#[derive(Debug, Clone)]
+pub struct OffscreenBuffer {
+    id: Id,
+    inner: Rc<RefCell<Option<Inner>>>,
+}
+
+impl Default for OffscreenBuffer {
+    fn default() -> Self {
+        Self {
+            id: Id::new(),
+            inner: Default::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    texture: GlesTexture,
+    context_id: ContextId<GlesTexture>,
+    size: Size<i32, Physical>,
+    scale: Scale<f64>,
+    damage_tracker: OutputDamageTracker,
+    damage: DamageBag<i32, Physical>,
+}
+
+/// The result of rendering into an [`OffscreenBuffer`].
+#[derive(Debug)]
+pub struct OffscreenTexture {
+    pub id: Id,
+    pub texture: GlesTexture,
+    pub commit: CommitCounter,
+    pub damage: DamageSnapshot<i32, Physical>,
+}
+
+impl OffscreenTexture {
+    /// Returns the damage since `commit`, falling back to all of `geometry`.
+    pub fn damage_since(
+        &self,
+        geometry: Rectangle<i32, Physical>,
+        commit: Option<CommitCounter>,
+    ) -> DamageSet<i32, Physical> {
+        self.damage
+            .damage_since(commit)
+            .unwrap_or_else(|| DamageSet::from_slice(&[geometry]))
+    }
+}
+
+impl OffscreenBuffer {
+    /// Renders `elements` into this buffer's texture, recreating it if `size`
+    /// or the renderer changed.
+    ///
+    /// Damage is reported downscaled by `damage_scale`, for textures drawn
+    /// smaller than they were rendered.
+    pub fn render<R, E>(
+        &self,
+        renderer: &mut R,
+        elements: &[E],
+        size: Size<i32, Physical>,
+        scale: Scale<f64>,
+        damage_scale: Scale<f64>,
+        clear_color: [f32; 4],
+    ) -> anyhow::Result<OffscreenTexture>
+    where
+        R: Renderer + Offscreen<GlesTexture> + Bind<GlesTexture> + AsGlesRenderer,
+        E: RenderElement<R>,
+    {
+        let _span = tracy_client::span!("OffscreenBuffer::render");
+
+        let context_id = renderer.as_gles_renderer().context_id();
+        let mut inner = self.inner.borrow_mut();
+
+        // The texture still holds the last frame unless it's recreated
+        let mut age = 1;
+
+        if inner
+            .as_ref()
+            .is_none_or(|inner| inner.context_id != context_id || inner.size != size)
+        {
+            let buffer_size = size.to_logical(1).to_buffer(1, Transform::Normal);
+            let texture = renderer
+                .create_buffer(Fourcc::Abgr8888, buffer_size)
+                .map_err(|err| anyhow!("failed to create texture: {err}"))?;
+
+            let damage = inner
+                .take()
+                .map(|inner| inner.damage)
+                .unwrap_or_else(|| DamageBag::new(DAMAGE_HISTORY));
+
+            *inner = Some(Inner {
+                texture,
+                context_id,
+                size,
+                scale,
+                damage_tracker: OutputDamageTracker::new(size, scale, Transform::Normal),
+                damage,
+            });
+            age = 0;
+        }
+
+        let inner = inner.as_mut().expect("inner was just set");
+
+        if inner.scale != scale {
+            inner.scale = scale;
+            inner.damage_tracker = OutputDamageTracker::new(size, scale, Transform::Normal);
+            age = 0;
+        }
+
+        {
+            let mut framebuffer = renderer
+                .bind(&mut inner.texture)
+                .map_err(|err| anyhow!("failed to bind texture: {err}"))?;
+
+            let result = inner
+                .damage_tracker
+                .render_output(renderer, &mut framebuffer, age, elements, clear_color)
+                .map_err(|err| anyhow!("failed to render to texture: {err:?}"))?;
+
+            if let Some(damage) = result.damage {
+                inner.damage.add(
+                    damage
+                        .iter()
+                        .map(|rect| rect.to_f64().downscale(damage_scale).to_i32_up()),
+                );
+            }
+        }
+
+        Ok(OffscreenTexture {
+            id: self.id.clone(),
+            texture: inner.texture.clone(),
+            commit: inner.damage.current_commit(),
+            damage: inner.damage.snapshot(),
+        })
+    }
+}
+
+/// Creates a surfaceless renderer on the first EGL device for tests that need real rendering.
+#[cfg(test)]
+pub(super) fn test_renderer() -> smithay::backend::renderer::gles::GlesRenderer {
+    use smithay::backend::{
+        egl::{EGLContext, EGLDevice, EGLDisplay},
+        renderer::gles::GlesRenderer,
+    };
+
+    let device = EGLDevice::enumerate()
+        .expect("failed to enumerate EGL devices")
+        .next()
+        .expect("no EGL devices");
+    let display = unsafe { EGLDisplay::new(device) }.expect("failed to create EGL display");
+    let context = EGLContext::new(&display).expect("failed to create EGL context");
+    unsafe { GlesRenderer::new(context) }.expect("failed to create renderer")
+}
+
+#[cfg(test)]
+mod tests {
+    use smithay::backend::renderer::{
+        element::{Kind, solid::SolidColorRenderElement},
+        gles::GlesRenderer,
+    };
+
+    use super::*;
+
+    fn render(
+        buffer: &OffscreenBuffer,
+        renderer: &mut GlesRenderer,
+        elements: &[SolidColorRenderElement],
+    ) -> OffscreenTexture {
+        buffer
+            .render(
+                renderer,
+                elements,
+                (64, 64).into(),
+                Scale::from(1.0),
+                Scale::from(1.0),
+                [0.0, 0.0, 0.0, 1.0],
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn unchanged_elements_reuse_texture_without_damage() {
+        let mut renderer = test_renderer();
+        let buffer = OffscreenBuffer::default();
+
+        let id = Id::new();
+        let mut commit = CommitCounter::default();
+        let square = |commit| {
+            SolidColorRenderElement::new(
+                id.clone(),
+                Rectangle::new((8, 8).into(), (16, 16).into()),
+                commit,
+                [1.0, 0.0, 0.0, 1.0],
+                Kind::Unspecified,
+            )
+        };
+
+        let first = render(&buffer, &mut renderer, &[square(commit)]);
+        let second = render(&buffer, &mut renderer, &[square(commit)]);
+
+        assert_eq!(first.id, second.id);
+        assert_eq!(first.texture.tex_id(), second.texture.tex_id());
+        assert_eq!(first.commit, second.commit);
+        assert!(
+            second
+                .damage_since(Rectangle::default(), Some(first.commit))
+                .is_empty()
+        );
+
+        commit.increment();
+        let third = render(&buffer, &mut renderer, &[square(commit)]);
+
+        assert_eq!(first.id, third.id);
+        assert_ne!(second.commit, third.commit);
+        assert_eq!(
+            &*third.damage_since(Rectangle::default(), Some(second.commit)),
+            &[Rectangle::new((8, 8).into(), (16, 16).into())]
+        );
+    }
+
+    #[test]
+    fn damage_is_downscaled() {
+        let mut renderer = test_renderer();
+        let buffer = OffscreenBuffer::default();
+
+        let square = SolidColorRenderElement::new(
+            Id::new(),
+            Rectangle::new((8, 8).into(), (16, 16).into()),
+            CommitCounter::default(),
+            [1.0, 0.0, 0.0, 1.0],
+            Kind::Unspecified,
+        );
+
+        let first = buffer
+            .render(
+                &mut renderer,
+                &[] as &[SolidColorRenderElement],
+                (64, 64).into(),
+                Scale::from(1.0),
+                Scale::from(2.0),
+                [0.0, 0.0, 0.0, 1.0],
+            )
+            .unwrap();
+        let second = buffer
+            .render(
+                &mut renderer,
+                &[square],
+                (64, 64).into(),
+                Scale::from(1.0),
+                Scale::from(2.0),
+                [0.0, 0.0, 0.0, 1.0],
+            )
+            .unwrap();
+
+        assert_eq!(
+            &*second.damage_since(Rectangle::default(), Some(first.commit)),
+            &[Rectangle::new((4, 4).into(), (8, 8).into())]
+        );
+    }
+}
@@ -16,7 +16,7 @@ use smithay::{
     output::Output,
     reexports::wayland_server::protocol::wl_surface::WlSurface,
     render_elements,
-    utils::{Clock, Logical, Monotonic, Point, Scale},
+    utils::{Clock, Logical, Monotonic, Point, Scale, Size},
     wayland::compositor,
 };
 
@@ -29,8 +29,17 @@ use super::PRenderer;
 
 pub enum PointerElement {
     Hidden,
-    Named { cursor: Rc<XCursor>, size: u32 },
-    Surface { surface: WlSurface },
+    /// A themed cursor.
+    ///
+    /// `size` is the size to load images at and `scale` is the scale that size is for.
+    Named {
+        cursor: Rc<XCursor>,
+        size: u32,
+        scale: f64,
+    },
+    Surface {
+        surface: WlSurface,
+    },
 }
 
 render_elements! {
@@ -60,7 +69,6 @@ pub fn pointer_render_elements<R: PRenderer>(
     };
 
     let scale = Scale::from(output.current_scale().fractional_scale());
-    let integer_scale = output.current_scale().integer_scale();
 
     let pointer_elem = cursor_state.pointer_element();
 
@@ -69,18 +77,31 @@ pub fn pointer_render_elements<R: PRenderer>(
 
         let mut elements = match &pointer_elem {
             PointerElement::Hidden => vec![],
-            PointerElement::Named { cursor, size } => {
-                let image = cursor.image(clock.now().into(), *size * integer_scale as u32);
-                let hotspot = (image.xhot as i32, image.yhot as i32);
-                let buffer = cursor_state.buffer_for_image(image, integer_scale);
+            PointerElement::Named {
+                cursor,
+                size,
+                scale: cursor_scale,
+            } => {
+                let image = cursor.image(clock.now().into(), *size);
+                // The image was loaded for the output the pointer is on, so size it
+                // the same logically on every output
+                let logical_size = Size::<f64, Logical>::from((
+                    image.width as f64 / cursor_scale,
+                    image.height as f64 / cursor_scale,
+                ))
+                .to_i32_round();
+                let hotspot = Point::<f64, Logical>::from((
+                    image.xhot as f64 / cursor_scale,
+                    image.yhot as f64 / cursor_scale,
+                ));
+                let buffer = cursor_state.buffer_for_image(image);
                 let elem = MemoryRenderBufferRenderElement::from_buffer(
                     renderer,
-                    (cursor_pos - Point::from(hotspot).downscale(integer_scale).to_f64())
-                        .to_physical_precise_round(scale),
+                    (cursor_pos - hotspot).to_physical_precise_round(scale),
                     &buffer,
                     None,
                     None,
-                    None,
+                    Some(logical_size),
                     element::Kind::Cursor,
                 );
 
@@ -0,0 +1,237 @@
+//! Whole-output supersampling.
+//!
+//! When an output has a supersample factor above 1, its elements are rendered into an
+//! offscreen texture that many times larger than the output's mode, which is then drawn
+//! downscaled onto the output. This smooths out fractional scales and rotations at the cost
+//! of rendering many more pixels.
+
+use smithay::{
+    backend::renderer::{
+        Bind, Offscreen, RendererSuper, Texture,
+        element::{self, Element, Id, RenderElement, utils::RescaleRenderElement},
+        gles::{GlesError, GlesRenderer, GlesTexture},
+        utils::{CommitCounter, DamageSet, OpaqueRegions},
+    },
+    output::Output,
+    utils::{Buffer, Physical, Point, Rectangle, Scale, Size, Transform},
+};
+use tracing::warn;
+
+use crate::{backend::udev::UdevRenderer, state::WithState};
+
+use super::{AsGlesRenderer, OutputRenderElement, PRenderer, offscreen::OffscreenTexture};
+
+/// The largest supersample factor an output can have.
+pub const MAX_SUPERSAMPLE_FACTOR: f32 = 4.0;
+
+/// The largest width or height of the offscreen texture.
+///
+/// Factors that would make the texture larger are lowered to fit.
+const MAX_TARGET_DIMENSION: i32 = 16384;
+
+/// Returns the size of the texture `output` is rendered into before being downscaled,
+/// or `None` if it isn't supersampled.
+pub fn supersample_target_size(output: &Output) -> Option<Size<i32, Physical>> {
+    let factor = output.with_state(|state| state.supersample_factor);
+    let size = output
+        .current_transform()
+        .transform_size(output.current_mode()?.size);
+
+    target_size(size, factor)
+}
+
+fn target_size(size: Size<i32, Physical>, factor: f32) -> Option<Size<i32, Physical>> {
+    if size.is_empty() {
+        return None;
+    }
+
+    let max_factor = MAX_TARGET_DIMENSION as f64 / size.w.max(size.h) as f64;
+    let factor = (factor as f64).min(max_factor);
+
+    if factor <= 1.0 {
+        return None;
+    }
+
+    Some(size.to_f64().upscale(factor).to_i32_round())
+}
+
+/// Replaces `elements` with a single element that draws them supersampled.
+///
+/// Does nothing if the output isn't supersampled.
+pub fn apply_output_supersampling<R>(
+    renderer: &mut R,
+    output: &Output,
+    elements: &mut Vec<OutputRenderElement<R>>,
+    clear_color: [f32; 4],
+) where
+    R: PRenderer + Offscreen<GlesTexture> + Bind<GlesTexture> + AsGlesRenderer,
+    OutputRenderElement<R>: RenderElement<R>,
+{
+    if let Some(elem) = SupersampleRenderElement::new(renderer, output, elements, clear_color) {
+        *elements = vec![OutputRenderElement::Supersample(elem)];
+    }
+}
+
+/// An output's contents rendered to a larger texture that is drawn downscaled.
+#[derive(Debug)]
+pub struct SupersampleRenderElement {
+    texture: OffscreenTexture,
+    size: Size<i32, Physical>,
+}
+
+impl SupersampleRenderElement {
+    /// Renders `elements` for `output` into the output's supersample texture, which is
+    /// the size of [`supersample_target_size`].
+    ///
+    /// Returns `None` if the output isn't supersampled or rendering failed, in which case
+    /// the elements should be drawn as-is.
+    pub fn new<R>(
+        renderer: &mut R,
+        output: &Output,
+        elements: &[OutputRenderElement<R>],
+        clear_color: [f32; 4],
+    ) -> Option<Self>
+    where
+        R: PRenderer + Offscreen<GlesTexture> + Bind<GlesTexture> + AsGlesRenderer,
+        OutputRenderElement<R>: RenderElement<R>,
+    {
+        let _span = tracy_client::span!("SupersampleRenderElement::new");
+
+        let target_size = supersample_target_size(output)?;
+
+        let size = output
+            .current_transform()
+            .transform_size(output.current_mode()?.size);
+        let scale = Scale::from(output.current_scale().fractional_scale());
+        let factor = Scale {
+            x: target_size.w as f64 / size.w as f64,
+            y: target_size.h as f64 / size.h as f64,
+        };
+
+        let elements = elements
+            .iter()
+            .map(|elem| RescaleRenderElement::from_element(elem, Point::default(), factor))
+            .collect::<Vec<_>>();
+
+        // The texture replaces the whole frame, so the clear color is drawn underneath
+        let texture = output
+            .with_state(|state| state.supersample_buffer.clone())
+            .render(renderer, &elements, target_size, scale, factor, clear_color)
+            .inspect_err(|err| warn!("Failed to render output for supersampling: {err}"))
+            .ok()?;
+
+        Some(Self { texture, size })
+    }
+}
+
+impl Element for SupersampleRenderElement {
+    fn id(&self) -> &Id {
+        &self.texture.id
+    }
+
+    fn current_commit(&self) -> CommitCounter {
+        self.texture.commit
+    }
+
+    fn src(&self) -> Rectangle<f64, Buffer> {
+        Rectangle::from_size(self.texture.texture.size()).to_f64()
+    }
+
+    fn geometry(&self, _scale: Scale<f64>) -> Rectangle<i32, Physical> {
+        Rectangle::from_size(self.size)
+    }
+
+    fn damage_since(
+        &self,
+        scale: Scale<f64>,
+        commit: Option<CommitCounter>,
+    ) -> DamageSet<i32, Physical> {
+        self.texture.damage_since(self.geometry(scale), commit)
+    }
+
+    fn opaque_regions(&self, _scale: Scale<f64>) -> OpaqueRegions<i32, Physical> {
+        // The clear color was drawn underneath, so every pixel is opaque
+        OpaqueRegions::from_slice(&[Rectangle::from_size(self.size)])
+    }
+
+    fn kind(&self) -> element::Kind {
+        element::Kind::Unspecified
+    }
+}
+
+impl RenderElement<GlesRenderer> for SupersampleRenderElement {
+    fn draw(
+        &self,
+        frame: &mut <GlesRenderer as RendererSuper>::Frame<'_, '_>,
+        src: Rectangle<f64, Buffer>,
+        dst: Rectangle<i32, Physical>,
+        damage: &[Rectangle<i32, Physical>],
+        opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), GlesError> {
+        frame.render_texture_from_to(
+            &self.texture.texture,
+            src,
+            dst,
+            damage,
+            opaque_regions,
+            Transform::Normal,
+            1.0,
+            None,
+            &[],
+        )
+    }
+
+    fn underlying_storage(
+        &self,
+        renderer: &mut GlesRenderer,
+    ) -> Option<element::UnderlyingStorage<'_>> {
+        let _ = renderer;
+        None
+    }
+}
+
+impl<'a> RenderElement<UdevRenderer<'a>> for SupersampleRenderElement {
+    fn draw(
+        &self,
+        frame: &mut <UdevRenderer<'a> as RendererSuper>::Frame<'_, '_>,
+        src: Rectangle<f64, Buffer>,
+        dst: Rectangle<i32, Physical>,
+        damage: &[Rectangle<i32, Physical>],
+        opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <UdevRenderer<'a> as RendererSuper>::Error> {
+        RenderElement::<GlesRenderer>::draw(
+            self,
+            frame.as_mut(),
+            src,
+            dst,
+            damage,
+            opaque_regions,
+        )?;
+        Ok(())
+    }
+
+    fn underlying_storage(
+        &self,
+        renderer: &mut UdevRenderer<'a>,
+    ) -> Option<element::UnderlyingStorage<'_>> {
+        let _ = renderer;
+        None
+    }
+}
+
+#[cfg(feature = "testing")]
+impl RenderElement<smithay::backend::renderer::test::DummyRenderer> for SupersampleRenderElement {
+    fn draw(
+        &self,
+        _frame: &mut <smithay::backend::renderer::test::DummyRenderer as RendererSuper>::Frame<
+            'static,
+            'static,
+        >,
+        _src: Rectangle<f64, Buffer>,
+        _dst: Rectangle<i32, Physical>,
+        _damage: &[Rectangle<i32, Physical>],
+        _opaque_regions: &[Rectangle<i32, Physical>],
+    ) -> Result<(), <smithay::backend::renderer::test::DummyRenderer as RendererSuper>::Error> {
+        Ok(())
+    }
+}
@@ -1,16 +1,17 @@
 //! Render utilities.
 
+pub mod screenshot;
 pub mod snapshot;
 pub mod surface;
 
-use anyhow::{Context, bail};
+use anyhow::{Context, anyhow, bail};
 use smithay::backend::allocator::Fourcc;
 use smithay::backend::renderer::damage::OutputDamageTracker;
 use smithay::backend::renderer::element::solid::SolidColorRenderElement;
 use smithay::backend::renderer::element::utils::{Relocate, RelocateRenderElement};
 use smithay::backend::renderer::element::{self, Element, Id};
 use smithay::backend::renderer::utils::CommitCounter;
-use smithay::backend::renderer::{Bind, Color32F, Frame, Offscreen, Renderer, RendererSuper};
+use smithay::backend::renderer::{Bind, Color32F, Frame, Offscreen, Renderer};
 use smithay::utils::{Point, Rectangle};
 use smithay::{
     backend::renderer::{
@@ -92,14 +93,17 @@ pub fn render_to_encompassing_texture<E: RenderElement<GlesRenderer>>(
 /// Elements outside of the rectangle will be clipped.
 ///
 /// From https://github.com/YaLTeR/niri/blob/efb39e466b5248eb894745e899de33661493511d/src/render_helpers/mod.rs#L180
-pub fn render_to_texture(
-    renderer: &mut GlesRenderer,
-    elements: impl IntoIterator<Item = impl RenderElement<GlesRenderer>>,
+pub fn render_to_texture<R>(
+    renderer: &mut R,
+    elements: impl IntoIterator<Item = impl RenderElement<R>>,
     size: Size<i32, Physical>,
     scale: Scale<f64>,
     transform: Transform,
     fourcc: Fourcc,
-) -> anyhow::Result<(GlesTexture, SyncPoint)> {
+) -> anyhow::Result<(GlesTexture, SyncPoint)>
+where
+    R: Renderer + Offscreen<GlesTexture> + Bind<GlesTexture>,
+{
     if size.is_empty() {
         // Causes GL_INVALID_VALUE when binding
         bail!("size was empty");
@@ -108,12 +112,12 @@ pub fn render_to_texture(
     let buffer_size = size.to_logical(1).to_buffer(1, Transform::Normal);
     let mut texture: GlesTexture = renderer
         .create_buffer(fourcc, buffer_size)
-        .context("failed to create texture")?;
+        .map_err(|err| anyhow!("failed to create texture: {err}"))?;
 
     let sync_point = {
         let mut framebuffer = renderer
             .bind(&mut texture)
-            .context("failed to bind texture")?;
+            .map_err(|err| anyhow!("failed to bind texture: {err}"))?;
 
         render_elements_to_framebuffer(
             renderer,
@@ -134,10 +138,10 @@ pub fn render_to_texture(
 /// to a texture with a rectangle of loc (0, 0) and size `size`.
 ///
 /// From https://github.com/YaLTeR/niri/blob/efb39e466b5248eb894745e899de33661493511d/src/render_helpers/mod.rs#L295
-fn render_elements_to_framebuffer(
-    renderer: &mut GlesRenderer,
-    framebuffer: &mut <GlesRenderer as RendererSuper>::Framebuffer<'_>,
-    elements: impl IntoIterator<Item = impl RenderElement<GlesRenderer>>,
+fn render_elements_to_framebuffer<R: Renderer>(
+    renderer: &mut R,
+    framebuffer: &mut R::Framebuffer<'_>,
+    elements: impl IntoIterator<Item = impl RenderElement<R>>,
     size: Size<i32, Physical>,
     scale: Scale<f64>,
     transform: Transform,
@@ -147,11 +151,11 @@ fn render_elements_to_framebuffer(
 
     let mut frame = renderer
         .render(framebuffer, size, transform)
-        .context("failed to start render")?;
+        .map_err(|err| anyhow!("failed to start render: {err}"))?;
 
     frame
         .clear([0.0, 0.0, 0.0, 0.0].into(), &[dst_rect])
-        .context("failed to clear frame")?;
+        .map_err(|err| anyhow!("failed to clear frame: {err}"))?;
 
     for elem in elements {
         let src = elem.src();
@@ -160,11 +164,13 @@ fn render_elements_to_framebuffer(
         if let Some(mut damage) = dst_rect.intersection(dst) {
             damage.loc -= dst.loc;
             elem.draw(&mut frame, src, dst, &[damage], &[])
-                .context("failed to draw element")?;
+                .map_err(|err| anyhow!("failed to draw element: {err}"))?;
         }
     }
 
-    frame.finish().context("failed to finish frame")
+    frame
+        .finish()
+        .map_err(|err| anyhow!("failed to finish frame: {err}"))
 }
 
 /// Renders damage rectangles for the given elements.
@@ -203,6 +209,36 @@ pub fn render_damage(
         .collect()
 }
 
+/// Removes elements that are completely covered by the opaque regions of elements above them.
+///
+/// `elements` must be ordered from top to bottom. Elements that don't declare an opaque region
+/// are treated as fully transparent, so they never hide anything.
+pub fn cull_opaque_occluded_elements<E: Element>(elements: &mut Vec<E>, scale: Scale<f64>) {
+    let _span = tracy_client::span!("cull_opaque_occluded_elements");
+
+    let mut opaque = Vec::<Rectangle<i32, Physical>>::new();
+
+    elements.retain(|elem| {
+        let geo = elem.geometry(scale);
+
+        if geo.is_empty() {
+            return true;
+        }
+
+        if !opaque.is_empty() && geo.subtract_rects(opaque.iter().copied()).is_empty() {
+            return false;
+        }
+
+        opaque.extend(elem.opaque_regions(scale).iter().map(|rect| {
+            let mut rect = *rect;
+            rect.loc += geo.loc;
+            rect
+        }));
+
+        true
+    });
+}
+
 /// Renders opaque region rectangles on top of each element.
 ///
 /// https://github.com/YaLTeR/niri/blob/b351f6ff220560d96a260d8dd3ad794000923481/src/render_helpers/debug.rs#L10
@@ -251,3 +287,45 @@ pub fn render_opaque_regions<R: PRenderer>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(geo: Rectangle<i32, Physical>, alpha: f32) -> SolidColorRenderElement {
+        SolidColorRenderElement::new(
+            Id::new(),
+            geo,
+            CommitCounter::default(),
+            [0.0, 0.0, 0.0, alpha],
+            element::Kind::Unspecified,
+        )
+    }
+
+    #[test]
+    fn cull_opaque_occluded_elements_culls_elements_under_opaque_ones() {
+        let opaque = solid(Rectangle::new((0, 0).into(), (100, 100).into()), 1.0);
+        let covered = solid(Rectangle::new((10, 10).into(), (50, 50).into()), 1.0);
+        let peeking = solid(Rectangle::new((50, 50).into(), (100, 100).into()), 1.0);
+
+        let mut elements = vec![opaque.clone(), covered, peeking.clone()];
+        cull_opaque_occluded_elements(&mut elements, Scale::from(1.0));
+
+        let ids = elements
+            .iter()
+            .map(|elem| elem.id().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![opaque.id().clone(), peeking.id().clone()]);
+    }
+
+    #[test]
+    fn cull_opaque_occluded_elements_keeps_elements_under_translucent_ones() {
+        let translucent = solid(Rectangle::new((0, 0).into(), (100, 100).into()), 0.5);
+        let below = solid(Rectangle::new((10, 10).into(), (50, 50).into()), 1.0);
+
+        let mut elements = vec![translucent, below];
+        cull_opaque_occluded_elements(&mut elements, Scale::from(1.0));
+
+        assert_eq!(elements.len(), 2);
+    }
+}
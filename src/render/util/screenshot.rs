@@ -0,0 +1,271 @@
+//! Utilities for capturing screenshots of windows and thumbnails of outputs.
+
+use std::{fs::File, io::BufWriter, path::Path, time::Duration};
+
+use anyhow::{Context, bail};
+use smithay::{
+    backend::allocator::Fourcc,
+    backend::renderer::{
+        ExportMem, Texture, TextureMapping,
+        element::utils::RescaleRenderElement,
+        gles::{GlesRenderer, GlesTexture},
+    },
+    output::Output,
+    utils::{Physical, Point, Rectangle, Scale, Size, Transform},
+};
+
+use crate::{
+    state::{Pinnacle, State, WithState},
+    window::WindowElement,
+};
+
+use super::{EncompassingTexture, render_to_encompassing_texture, render_to_texture};
+
+/// How long an output thumbnail is reused for before it's rendered again.
+pub const THUMBNAIL_CACHE_DURATION: Duration = Duration::from_millis(250);
+
+/// A captured image in 8-bit RGBA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Screenshot {
+    /// The size of the image.
+    pub size: Size<i32, Physical>,
+    /// Pixel data in RGBA order, row by row from the top.
+    pub rgba: Vec<u8>,
+}
+
+impl Screenshot {
+    /// Creates a fully transparent screenshot of the given size.
+    pub fn transparent(size: Size<i32, Physical>) -> Self {
+        let len = size.w.max(0) as usize * size.h.max(0) as usize * 4;
+        Self {
+            size,
+            rgba: vec![0; len],
+        }
+    }
+
+    /// Encodes this screenshot as a PNG and writes it to `path`.
+    pub fn save_png(&self, path: &Path) -> anyhow::Result<()> {
+        let file =
+            File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+
+        let mut encoder =
+            png::Encoder::new(BufWriter::new(file), self.size.w as u32, self.size.h as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder
+            .write_header()
+            .context("failed to write png header")?;
+        writer
+            .write_image_data(&self.rgba)
+            .context("failed to write png data")?;
+
+        Ok(())
+    }
+}
+
+impl WindowElement {
+    /// Renders this window, including decorations but not popups, into a [`Screenshot`]
+    /// cropped to its bounding box.
+    pub fn screenshot(
+        &self,
+        renderer: &mut GlesRenderer,
+        scale: Scale<f64>,
+    ) -> anyhow::Result<Screenshot> {
+        let elements = self.texture_render_elements(renderer, (0, 0).into(), scale, 1.0);
+
+        let EncompassingTexture { texture, .. } = render_to_encompassing_texture(
+            renderer,
+            elements.surface_elements,
+            scale,
+            Transform::Normal,
+            Fourcc::Abgr8888,
+        )?;
+
+        download_texture(renderer, &texture)
+    }
+}
+
+/// Copies an Abgr8888 texture into a [`Screenshot`].
+fn download_texture(
+    renderer: &mut GlesRenderer,
+    texture: &GlesTexture,
+) -> anyhow::Result<Screenshot> {
+    let size = texture.size();
+
+    // Abgr8888 is laid out as RGBA in memory
+    let mapping = renderer
+        .copy_texture(texture, Rectangle::from_size(size), Fourcc::Abgr8888)
+        .context("failed to copy texture")?;
+    let flipped = mapping.flipped();
+    let bytes = renderer
+        .map_texture(&mapping)
+        .context("failed to map texture")?;
+
+    let size = Size::<i32, Physical>::from((size.w, size.h));
+    let stride = size.w as usize * 4;
+
+    let rgba = if flipped {
+        bytes
+            .chunks_exact(stride)
+            .rev()
+            .flatten()
+            .copied()
+            .collect()
+    } else {
+        bytes.to_vec()
+    };
+
+    Ok(Screenshot { size, rgba })
+}
+
+/// Returns the largest size with the aspect ratio of `size` that fits within `max_size`.
+///
+/// Sizes that already fit are returned as-is.
+pub fn thumbnail_size(
+    size: Size<i32, Physical>,
+    max_size: Size<i32, Physical>,
+) -> Size<i32, Physical> {
+    if size.is_empty() || (size.w <= max_size.w && size.h <= max_size.h) {
+        return size;
+    }
+
+    let factor = f64::min(
+        max_size.w as f64 / size.w as f64,
+        max_size.h as f64 / size.h as f64,
+    );
+
+    Size::from((
+        ((size.w as f64 * factor).floor() as i32).max(1),
+        ((size.h as f64 * factor).floor() as i32).max(1),
+    ))
+}
+
+/// A recently rendered output thumbnail.
+#[derive(Debug, Clone)]
+pub struct CachedThumbnail {
+    /// When the thumbnail was rendered.
+    pub time: Duration,
+    /// The size the thumbnail was requested to fit within.
+    pub max_size: Size<i32, Physical>,
+    pub thumbnail: Screenshot,
+}
+
+/// Renders the contents of `output`, without the cursor, scaled down to fit within `max_size`.
+fn render_output_thumbnail(
+    renderer: &mut GlesRenderer,
+    pinnacle: &Pinnacle,
+    output: &Output,
+    max_size: Size<i32, Physical>,
+) -> anyhow::Result<Screenshot> {
+    let _span = tracy_client::span!("render_output_thumbnail");
+
+    let Some(output_geo) = pinnacle.space.output_geometry(output) else {
+        bail!("output is not mapped");
+    };
+
+    let scale = Scale::from(output.current_scale().fractional_scale());
+    let size = output_geo.size.to_physical_precise_round(scale);
+    let thumbnail_size = thumbnail_size(size, max_size);
+    let factor = Scale {
+        x: thumbnail_size.w as f64 / size.w as f64,
+        y: thumbnail_size.h as f64 / size.h as f64,
+    };
+
+    let elements = crate::render::output_render_elements(
+        output,
+        renderer,
+        &pinnacle.space,
+        &pinnacle.z_index_stack,
+        pinnacle.overview.as_ref(),
+        pinnacle.occluding_window(output).as_ref(),
+    );
+
+    // Output elements are ordered front to back but are drawn back to front
+    let elements = elements
+        .iter()
+        .rev()
+        .map(|elem| RescaleRenderElement::from_element(elem, Point::default(), factor));
+
+    let (texture, _sync_point) = render_to_texture(
+        renderer,
+        elements,
+        thumbnail_size,
+        scale,
+        Transform::Normal,
+        Fourcc::Abgr8888,
+    )?;
+
+    download_texture(renderer, &texture)
+}
+
+impl State {
+    /// Renders a thumbnail of `output` that fits within `max_size`.
+    ///
+    /// Thumbnails are reused for [`THUMBNAIL_CACHE_DURATION`] so repeated requests,
+    /// like from an output switcher, don't render the output every time.
+    pub fn output_thumbnail(
+        &mut self,
+        output: &Output,
+        max_size: Size<i32, Physical>,
+    ) -> anyhow::Result<Screenshot> {
+        if !self.pinnacle.lock_state.is_unlocked() {
+            bail!("the session is locked");
+        }
+
+        let now = Duration::from(self.pinnacle.clock.now());
+
+        let cached = output.with_state(|state| {
+            state
+                .thumbnail_cache
+                .as_ref()
+                .filter(|cached| {
+                    cached.max_size == max_size
+                        && now.saturating_sub(cached.time) < THUMBNAIL_CACHE_DURATION
+                })
+                .map(|cached| cached.thumbnail.clone())
+        });
+
+        if let Some(thumbnail) = cached {
+            return Ok(thumbnail);
+        }
+
+        let thumbnail = self
+            .backend
+            .with_renderer(|renderer| {
+                render_output_thumbnail(renderer, &self.pinnacle, output, max_size)
+            })
+            .context("no renderer available")??;
+
+        output.with_state_mut(|state| {
+            state.thumbnail_cache = Some(CachedThumbnail {
+                time: now,
+                max_size,
+                thumbnail: thumbnail.clone(),
+            });
+        });
+
+        Ok(thumbnail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumbnail_size_keeps_aspect_ratio() {
+        assert_eq!(
+            thumbnail_size((1920, 1080).into(), (320, 320).into()),
+            (320, 180).into()
+        );
+        assert_eq!(
+            thumbnail_size((1080, 1920).into(), (320, 320).into()),
+            (180, 320).into()
+        );
+        assert_eq!(
+            thumbnail_size((200, 100).into(), (320, 320).into()),
+            (200, 100).into()
+        );
+    }
+}
@@ -17,7 +17,7 @@ use smithay::{
 use tracing::debug;
 
 use crate::render::texture::CommonTextureRenderElement;
-use crate::render::{AsGlesRenderer, PRenderer};
+use crate::render::{AsGlesRenderer, OutputRenderElement, PRenderer};
 use crate::state::WithState;
 use crate::window::WindowElement;
 
@@ -27,6 +27,9 @@ use super::{EncompassingTexture, render_to_encompassing_texture};
 /// Type for window snapshots.
 pub type WindowSnapshot = RenderSnapshot<WlSurfaceTextureRenderElement>;
 
+/// Type for snapshots of an output's contents.
+pub type OutputSnapshot = RenderSnapshot<OutputRenderElement<GlesRenderer>>;
+
 pub type SnapshotRenderElement = RescaleRenderElement<WlSurfaceTextureRenderElement>;
 
 /// A snapshot of given elements that can be rendered at some point in the future.
@@ -119,8 +122,6 @@ impl<E: RenderElement<GlesRenderer>> RenderSnapshot<E> {
         let common = CommonTextureRenderElement::new(elem);
 
         // Scale in the opposite direction from the original scale to have it be the same size
-        // INFO: This scale is currently unused as we aren't using snapshots
-        // when changing output scale.
         let scale = Scale::from((1.0 / scale.x, 1.0 / scale.y));
 
         Some(RescaleRenderElement::from_element(
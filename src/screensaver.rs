@@ -0,0 +1,192 @@
+//! Implementation of the `org.freedesktop.ScreenSaver` DBus interface.
+//!
+//! Many apps, like browsers and video players, prevent idle through this interface
+//! instead of the idle-inhibit Wayland protocol. Inhibitors taken through it keep
+//! Pinnacle idle-inhibited until they are released or their owner disconnects from the bus.
+//!
+//! If the session bus isn't available or another process already owns the
+//! `org.freedesktop.ScreenSaver` name, none of this happens.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+};
+
+use smithay::reexports::calloop::{self, channel::Event};
+use tracing::{debug, info};
+use zbus::message::Header;
+
+use crate::state::{Pinnacle, State};
+
+const SCREENSAVER_NAME: &str = "org.freedesktop.ScreenSaver";
+/// Apps call the interface on either of these paths.
+const SCREENSAVER_PATHS: [&str; 2] = ["/org/freedesktop/ScreenSaver", "/ScreenSaver"];
+
+/// An event coming from a DBus client of the screensaver interface.
+#[derive(Debug)]
+pub enum ScreenSaverEvent {
+    /// A client inhibited idle.
+    Inhibit {
+        /// The cookie the client releases the inhibitor with.
+        cookie: u32,
+        /// The unique bus name of the client.
+        owner: String,
+        /// The name of the application that inhibited idle.
+        app_name: String,
+    },
+    /// A client released the inhibitor with the given cookie.
+    UnInhibit(u32),
+    /// The client with the given unique bus name disconnected.
+    OwnerGone(String),
+}
+
+#[derive(Debug)]
+struct Inhibitor {
+    /// The unique bus name of the client holding this inhibitor.
+    owner: String,
+    app_name: String,
+}
+
+#[derive(Debug, Default)]
+pub struct ScreenSaverState {
+    /// Inhibitors keyed by their cookie.
+    inhibitors: HashMap<u32, Inhibitor>,
+}
+
+impl ScreenSaverState {
+    /// Returns whether a DBus client is inhibiting idle.
+    pub fn is_inhibited(&self) -> bool {
+        !self.inhibitors.is_empty()
+    }
+}
+
+impl Pinnacle {
+    /// Serves the screensaver interface on a separate thread and forwards its events
+    /// to the event loop.
+    ///
+    /// If the interface can't be served, this logs and does nothing else.
+    pub fn insert_screensaver_source(&self) -> anyhow::Result<()> {
+        let (sender, receiver) = calloop::channel::channel::<ScreenSaverEvent>();
+
+        self.loop_handle
+            .insert_source(receiver, |event, _, state| match event {
+                Event::Msg(event) => state.on_screensaver_event(event),
+                Event::Closed => (),
+            })
+            .map_err(|err| anyhow::anyhow!("failed to insert screensaver source: {err}"))?;
+
+        std::thread::Builder::new()
+            .name("screensaver".into())
+            .spawn(move || {
+                if let Err(err) = run_screensaver(sender) {
+                    info!("org.freedesktop.ScreenSaver integration disabled: {err}");
+                }
+            })?;
+
+        Ok(())
+    }
+}
+
+impl State {
+    pub fn on_screensaver_event(&mut self, event: ScreenSaverEvent) {
+        let inhibitors = &mut self.pinnacle.screensaver_state.inhibitors;
+
+        match event {
+            ScreenSaverEvent::Inhibit {
+                cookie,
+                owner,
+                app_name,
+            } => {
+                debug!(cookie, "{app_name} inhibited idle through DBus");
+                inhibitors.insert(cookie, Inhibitor { owner, app_name });
+            }
+            ScreenSaverEvent::UnInhibit(cookie) => {
+                if let Some(inhibitor) = inhibitors.remove(&cookie) {
+                    debug!(cookie, "{} released its idle inhibitor", inhibitor.app_name);
+                }
+            }
+            ScreenSaverEvent::OwnerGone(owner) => {
+                inhibitors.retain(|_, inhibitor| inhibitor.owner != owner);
+            }
+        }
+
+        self.pinnacle.refresh_idle_inhibit();
+    }
+}
+
+struct ScreenSaver {
+    sender: calloop::channel::Sender<ScreenSaverEvent>,
+    /// Shared between every path the interface is served at.
+    next_cookie: Arc<AtomicU32>,
+}
+
+#[zbus::interface(name = "org.freedesktop.ScreenSaver")]
+impl ScreenSaver {
+    fn inhibit(
+        &self,
+        #[zbus(header)] header: Header<'_>,
+        application_name: String,
+        _reason_for_inhibit: String,
+    ) -> u32 {
+        let cookie = self.next_cookie.fetch_add(1, Ordering::Relaxed);
+        let owner = header
+            .sender()
+            .map(|sender| sender.to_string())
+            .unwrap_or_default();
+
+        let _ = self.sender.send(ScreenSaverEvent::Inhibit {
+            cookie,
+            owner,
+            app_name: application_name,
+        });
+
+        cookie
+    }
+
+    #[zbus(name = "UnInhibit")]
+    fn un_inhibit(&self, cookie: u32) {
+        let _ = self.sender.send(ScreenSaverEvent::UnInhibit(cookie));
+    }
+}
+
+fn run_screensaver(sender: calloop::channel::Sender<ScreenSaverEvent>) -> zbus::Result<()> {
+    // Some apps treat a cookie of 0 as a failure
+    let next_cookie = Arc::new(AtomicU32::new(1));
+
+    let mut builder = zbus::blocking::connection::Builder::session()?.name(SCREENSAVER_NAME)?;
+    for path in SCREENSAVER_PATHS {
+        builder = builder.serve_at(
+            path,
+            ScreenSaver {
+                sender: sender.clone(),
+                next_cookie: next_cookie.clone(),
+            },
+        )?;
+    }
+    let connection = builder.build()?;
+
+    let dbus = zbus::blocking::fdo::DBusProxy::new(&connection)?;
+
+    // Clean up after clients that disconnect without releasing their inhibitors
+    for signal in dbus.receive_name_owner_changed()? {
+        let Ok(args) = signal.args() else {
+            continue;
+        };
+
+        let name = args.name().to_string();
+
+        // Only unique names (like `:1.42`) belong to a single client connection
+        if !name.starts_with(':') || args.new_owner().is_some() {
+            continue;
+        }
+
+        if sender.send(ScreenSaverEvent::OwnerGone(name)).is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
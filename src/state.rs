@@ -14,10 +14,15 @@ use crate::{
     cursor::CursorState,
     focus::{OutputFocusStack, WindowKeyboardFocusStack, pointer::PointerContents},
     handlers::{
-        session_lock::LockState, xdg_activation::XDG_ACTIVATION_TOKEN_TIMEOUT,
+        idle::{IdleDim, IdleDpms},
+        session_lock::LockState,
+        xdg_activation::XDG_ACTIVATION_TOKEN_TIMEOUT,
         xwayland::XwaylandState,
     },
     layout::LayoutState,
+    logind::LogindState,
+    metrics::Metrics,
+    overview::Overview,
     process::ProcessState,
     protocol::{
         drm::WlDrmState,
@@ -27,6 +32,7 @@ use crate::{
         output_power_management::OutputPowerManagementState,
         screencopy::ScreencopyManagerState,
     },
+    screensaver::ScreenSaverState,
     window::{Unmapped, WindowElement, ZIndexElement, rules::WindowRuleState},
 };
 use smithay::{
@@ -62,6 +68,7 @@ use smithay::{
             self, CompositorClientState, CompositorHandler, CompositorState, SurfaceData,
             with_surface_tree_downward,
         },
+        content_type::ContentTypeState,
         cursor_shape::CursorShapeManagerState,
         dmabuf::DmabufState,
         foreign_toplevel_list::ForeignToplevelListState,
@@ -171,9 +178,12 @@ pub struct Pinnacle {
     pub pointer_gestures_state: PointerGesturesState,
     pub single_pixel_buffer_state: SinglePixelBufferState,
     pub foreign_toplevel_list_state: ForeignToplevelListState,
+    pub content_type_state: ContentTypeState,
     #[cfg(feature = "snowcap")]
     pub snowcap_decoration_state: SnowcapDecorationState,
     pub wl_drm_state: WlDrmState,
+    /// Why hardware acceleration failed to initialize, if it did.
+    pub hardware_accel_error: Option<String>,
 
     pub lock_state: LockState,
 
@@ -211,6 +221,12 @@ pub struct Pinnacle {
 
     pub signal_state: SignalState,
 
+    pub logind_state: LogindState,
+
+    pub screensaver_state: ScreenSaverState,
+
+    pub metrics: Metrics,
+
     pub layout_state: LayoutState,
 
     pub window_rule_state: WindowRuleState,
@@ -220,6 +236,25 @@ pub struct Pinnacle {
 
     /// WlSurfaces with an attached idle inhibitor.
     pub idle_inhibiting_surfaces: HashSet<WlSurface>,
+    /// Dimming of outputs after inactivity.
+    pub idle_dim: IdleDim,
+    /// Powering off of outputs after inactivity.
+    pub idle_dpms: IdleDpms,
+
+    /// Windows hidden in named scratchpads.
+    pub scratchpads: HashMap<String, WindowElement>,
+    /// Named groups of windows, in the order they were added.
+    pub window_groups: HashMap<String, Vec<WindowElement>>,
+    /// The overview, if one is open.
+    pub overview: Option<Overview>,
+    /// Whether the output layout changed since the last event loop cycle.
+    ///
+    /// Batches output layout changes so they're signaled once.
+    pub output_layout_changed: bool,
+    /// Whether windows were added, removed, or retagged since the last event loop cycle.
+    ///
+    /// Batches tag window count changes so they're only counted and signaled once.
+    pub window_tags_changed: bool,
 
     #[cfg(feature = "snowcap")]
     pub snowcap_handle: Option<snowcap::SnowcapHandle>,
@@ -257,11 +292,21 @@ impl State {
         self.pinnacle.fixup_z_layering();
         self.pinnacle.space.refresh();
         self.pinnacle.update_window_tags();
+        self.pinnacle.signal_layout_mode_changes();
+        self.pinnacle.signal_geometry_changes();
+        self.pinnacle.signal_tag_window_count_changes();
+        if self.pinnacle.output_layout_changed {
+            // Outputs were just set up or moved, so keep the pointer on one
+            self.apply_pointer_default_position();
+            self.pinnacle.constrain_floating_windows();
+        }
+        self.pinnacle.signal_output_layout_changed();
         self.pinnacle.cursor_state.cleanup();
         self.pinnacle.popup_manager.cleanup();
         self.update_pointer_focus();
         foreign_toplevel::refresh(self);
         self.pinnacle.refresh_idle_inhibit();
+        self.pinnacle.release_sleep_inhibitor_if_handled();
 
         self.backend.render_scheduled_outputs(&mut self.pinnacle);
 
@@ -382,6 +427,18 @@ impl Pinnacle {
                 anyhow::anyhow!("failed to insert xdg activation token cleanup source: {err}")
             })?;
 
+        // Hidden windows still need frame callbacks while no output is rendering
+        loop_handle
+            .insert_source(Timer::immediate(), |_, _, state| {
+                state.pinnacle.send_hidden_frame_callbacks(None);
+                TimeoutAction::ToDuration(
+                    state.pinnacle.config.frame_callback_policy.timer_interval(),
+                )
+            })
+            .map_err(|err| {
+                anyhow::anyhow!("failed to insert hidden window frame callback source: {err}")
+            })?;
+
         let (blocker_cleared_tx, blocker_cleared_rx) = std::sync::mpsc::channel();
 
         let pinnacle = Pinnacle {
@@ -462,9 +519,11 @@ impl Pinnacle {
             pointer_gestures_state: PointerGesturesState::new::<State>(&display_handle),
             single_pixel_buffer_state: SinglePixelBufferState::new::<State>(&display_handle),
             foreign_toplevel_list_state: ForeignToplevelListState::new::<State>(&display_handle),
+            content_type_state: ContentTypeState::new::<State>(&display_handle),
             #[cfg(feature = "snowcap")]
             snowcap_decoration_state: SnowcapDecorationState::new::<State>(&display_handle),
             wl_drm_state: WlDrmState,
+            hardware_accel_error: None,
 
             lock_state: LockState::default(),
 
@@ -499,6 +558,12 @@ impl Pinnacle {
 
             signal_state: SignalState::default(),
 
+            logind_state: LogindState::default(),
+
+            screensaver_state: ScreenSaverState::default(),
+
+            metrics: Metrics::default(),
+
             layout_state: LayoutState::default(),
 
             window_rule_state: WindowRuleState::default(),
@@ -506,6 +571,14 @@ impl Pinnacle {
             root_surface_cache: HashMap::new(),
 
             idle_inhibiting_surfaces: HashSet::new(),
+            idle_dim: IdleDim::default(),
+            idle_dpms: IdleDpms::default(),
+
+            scratchpads: HashMap::new(),
+            window_groups: HashMap::new(),
+            overview: None,
+            output_layout_changed: false,
+            window_tags_changed: false,
 
             outputs: Default::default(),
 
@@ -609,6 +682,10 @@ impl Pinnacle {
         let now = self.clock.now();
 
         for window in self.space.elements_for_output(output) {
+            if !self.window_frame_callback_due(window, output, now.into()) {
+                continue;
+            }
+
             window.send_frame(output, now, FRAME_CALLBACK_THROTTLE, should_send);
 
             #[cfg(feature = "snowcap")]
@@ -640,6 +717,8 @@ impl Pinnacle {
         if let CursorImageStatus::Surface(surface) = self.cursor_state.cursor_image() {
             send_frames_surface_tree(surface, output, now, FRAME_CALLBACK_THROTTLE, should_send);
         }
+
+        self.send_hidden_frame_callbacks(Some(output));
     }
 
     /// Returns a custom primary scanout output comparison function that picks the output with
@@ -684,6 +763,8 @@ impl Pinnacle {
         let _span = tracy_client::span!("Pinnacle::update_primary_scanout_output");
 
         for window in self.space.elements() {
+            let preferred_scale = window.with_state(|state| state.preferred_scale);
+
             window.with_surfaces(|surface, states| {
                 let primary_scanout_output = update_surface_primary_scanout_output(
                     surface,
@@ -694,9 +775,10 @@ impl Pinnacle {
                 );
 
                 if let Some(output) = primary_scanout_output {
+                    let scale = preferred_scale
+                        .unwrap_or_else(|| output.current_scale().fractional_scale());
                     with_fractional_scale(states, |fraction_scale| {
-                        fraction_scale
-                            .set_preferred_scale(output.current_scale().fractional_scale());
+                        fraction_scale.set_preferred_scale(scale);
                     });
                 }
             });
@@ -71,6 +71,11 @@ struct TagInner {
     /// This tag is defunct as a result of a config reload
     /// and will be replaced by the next added tag.
     defunct: bool,
+    /// This tag was created automatically for a new window
+    /// and will be removed once it has no windows.
+    dynamic: bool,
+    /// The window count that was last signaled for this tag.
+    signaled_window_count: u32,
 }
 
 /// A marker for windows.
@@ -106,6 +111,8 @@ impl Tag {
                 name: name.clone(),
                 active: false,
                 defunct: false,
+                dynamic: false,
+                signaled_window_count: 0,
             })),
         }
     }
@@ -161,4 +168,21 @@ impl Tag {
     pub fn make_defunct(&self) {
         self.inner.lock().unwrap().defunct = true;
     }
+
+    /// Gets whether this tag was created automatically for a new window.
+    pub fn dynamic(&self) -> bool {
+        self.inner.lock().unwrap().dynamic
+    }
+
+    /// Make this tag dynamic, removing it once it has no windows.
+    pub fn make_dynamic(&self) {
+        self.inner.lock().unwrap().dynamic = true;
+    }
+
+    /// Sets the window count that was last signaled for this tag.
+    ///
+    /// Returns whether the new count is different from the old one.
+    pub fn set_signaled_window_count(&self, count: u32) -> bool {
+        std::mem::replace(&mut self.inner.lock().unwrap().signaled_window_count, count) != count
+    }
 }
@@ -128,6 +128,74 @@ pub fn closest_in_dir(
         .collect()
 }
 
+/// Moves rectangles in `rects` so that none of them overlap, keeping the one at `fixed` in place.
+///
+/// Rectangles are placed in order of their distance from the fixed one. Each is pushed out
+/// of the placed rectangles it overlaps along the axis that needs the smaller move, away from
+/// the rectangle it overlapped so the arrangement is kept. Rectangles that don't overlap
+/// anything don't move, so gaps between them are kept too.
+pub fn resolve_overlaps(rects: &mut [Rectangle<i32, Logical>], fixed: usize) {
+    let fixed_loc = rects[fixed].loc;
+
+    let mut order = (0..rects.len())
+        .filter(|&idx| idx != fixed)
+        .collect::<Vec<_>>();
+    order.sort_by(|&a, &b| {
+        distance(fixed_loc, rects[a].loc).total_cmp(&distance(fixed_loc, rects[b].loc))
+    });
+
+    let mut placed = vec![fixed];
+
+    for idx in order {
+        // Pushing a rectangle out of one can push it into another, so this gives up
+        // after a while and puts it to the right of everything placed so far.
+        for _ in 0..placed.len() * 4 {
+            let Some(&other) = placed
+                .iter()
+                .find(|&&other| rects[idx].overlaps(rects[other]))
+            else {
+                break;
+            };
+
+            rects[idx].loc += push_out(rects[idx], rects[other]);
+        }
+
+        if placed
+            .iter()
+            .any(|&other| rects[idx].overlaps(rects[other]))
+        {
+            rects[idx].loc.x = placed
+                .iter()
+                .map(|&other| rects[other].right())
+                .max()
+                .unwrap_or_default();
+        }
+
+        placed.push(idx);
+    }
+}
+
+/// Returns the smallest offset that moves `rect` out of `other`
+/// while keeping it on the same side of `other`'s center.
+fn push_out(rect: Rectangle<i32, Logical>, other: Rectangle<i32, Logical>) -> Point<i32, Logical> {
+    let x = if rect.center().x >= other.center().x {
+        other.right() - rect.left()
+    } else {
+        other.left() - rect.right()
+    };
+    let y = if rect.center().y >= other.center().y {
+        other.bottom() - rect.top()
+    } else {
+        other.top() - rect.bottom()
+    };
+
+    if x.abs() <= y.abs() {
+        Point::new(x, 0)
+    } else {
+        Point::new(0, y)
+    }
+}
+
 /// Returns whether the `other` rectangle's center is more left/rightward or up/downward
 /// from `rect`'s center based on a 45 degree cross centered on `rect`.
 ///
@@ -207,6 +275,54 @@ mod tests {
         Rectangle::new((x, y).into(), (w, h).into())
     }
 
+    #[test]
+    fn resolve_overlaps_pushes_out_overlapping_rect() {
+        let mut rects = [rect(0, 0, 1920, 1080), rect(1000, 100, 1920, 1080)];
+
+        resolve_overlaps(&mut rects, 0);
+
+        assert_eq!(rects, [rect(0, 0, 1920, 1080), rect(1920, 100, 1920, 1080)]);
+    }
+
+    #[test]
+    fn resolve_overlaps_keeps_fixed_rect_and_gaps() {
+        let mut rects = [
+            rect(500, 0, 1000, 1000),
+            rect(0, 0, 1000, 1000),
+            rect(3000, 0, 1000, 1000),
+        ];
+
+        resolve_overlaps(&mut rects, 0);
+
+        assert_eq!(
+            rects,
+            [
+                rect(500, 0, 1000, 1000),
+                rect(-500, 0, 1000, 1000),
+                rect(3000, 0, 1000, 1000),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_overlaps_leaves_no_overlaps() {
+        let mut rects = [
+            rect(0, 0, 1000, 1000),
+            rect(0, 0, 1000, 1000),
+            rect(0, 0, 1000, 1000),
+            rect(500, 500, 1000, 1000),
+        ];
+
+        resolve_overlaps(&mut rects, 0);
+
+        assert_eq!(rects[0], rect(0, 0, 1000, 1000));
+        for (i, a) in rects.iter().enumerate() {
+            for b in rects.iter().skip(i + 1) {
+                assert!(!a.overlaps(*b), "{a:?} overlaps {b:?}");
+            }
+        }
+    }
+
     /// ┌───┬───┐
     /// │ 0 │ 1 │
     /// ├───┼───┤
@@ -3,19 +3,23 @@
 pub mod layout;
 pub mod rules;
 
-use std::{cell::RefCell, collections::HashMap, ops::Deref, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, ops::Deref, rc::Rc, time::Duration};
 
 use indexmap::IndexSet;
 use rules::{ClientRequests, WindowRules};
 use smithay::{
+    backend::renderer::utils::RendererSurfaceStateUserData,
     desktop::{Window, WindowSurface, WindowSurfaceType, space::SpaceElement},
     output::{Output, WeakOutput},
     reexports::{
-        wayland_protocols::xdg::{
-            decoration::zv1::server::zxdg_toplevel_decoration_v1,
-            shell::server::{
-                xdg_positioner::{Anchor, ConstraintAdjustment, Gravity},
-                xdg_toplevel,
+        wayland_protocols::{
+            wp::content_type::v1::server::wp_content_type_v1,
+            xdg::{
+                decoration::zv1::server::zxdg_toplevel_decoration_v1,
+                shell::server::{
+                    xdg_positioner::{Anchor, ConstraintAdjustment, Gravity},
+                    xdg_toplevel,
+                },
             },
         },
         wayland_server::protocol::wl_surface::WlSurface,
@@ -23,6 +27,8 @@ use smithay::{
     utils::{IsAlive, Logical, Point, Rectangle, Serial, Size},
     wayland::{
         compositor,
+        content_type::ContentTypeSurfaceCachedState,
+        fractional_scale::with_fractional_scale,
         seat::WaylandFocus,
         shell::xdg::{PositionerState, SurfaceCachedState, XdgToplevelSurfaceData},
         xdg_activation::XdgActivationTokenData,
@@ -33,13 +39,14 @@ use tracing::{error, warn};
 use window_state::LayoutModeKind;
 
 use crate::{
+    api::signal::Signal,
     render::util::snapshot::WindowSnapshot,
     state::{Pinnacle, State, WithState},
     tag::Tag,
     util::transaction::Transaction,
 };
 
-use self::window_state::WindowElementState;
+use self::window_state::{MatchedVrrDemand, VrrDemand, WindowElementState};
 
 pub mod window_state;
 
@@ -72,9 +79,17 @@ impl WindowElement {
     }
 
     /// Get this window's class (app id in Wayland but hey old habits die hard).
+    ///
+    /// If the config overrode this window's app id, the override is returned instead.
+    ///
+    /// RefCell Safety: This calls `with_state` on `self`.
     pub fn class(&self) -> Option<String> {
         let _span = tracy_client::span!("WindowElement::class");
 
+        if let Some(app_id) = self.with_state(|state| state.app_id_override.clone()) {
+            return Some(app_id);
+        }
+
         match self.0.underlying_surface() {
             WindowSurface::Wayland(toplevel) => {
                 compositor::with_states(toplevel.wl_surface(), |states| {
@@ -92,6 +107,16 @@ impl WindowElement {
         }
     }
 
+    /// Get this window's X11 WM_CLASS instance.
+    ///
+    /// Wayland windows don't have one.
+    pub fn instance(&self) -> Option<String> {
+        match self.0.underlying_surface() {
+            WindowSurface::Wayland(_) => None,
+            WindowSurface::X11(surface) => Some(surface.instance()),
+        }
+    }
+
     /// Get this window's title.
     pub fn title(&self) -> Option<String> {
         let _span = tracy_client::span!("WindowElement::title");
@@ -113,6 +138,21 @@ impl WindowElement {
         }
     }
 
+    /// Get the content type this window's client hinted at.
+    pub fn content_type(&self) -> wp_content_type_v1::Type {
+        let Some(surface) = self.wl_surface() else {
+            return wp_content_type_v1::Type::None;
+        };
+
+        compositor::with_states(&surface, |states| {
+            *states
+                .cached_state
+                .get::<ContentTypeSurfaceCachedState>()
+                .current()
+                .content_type()
+        })
+    }
+
     /// Send a close request to this window.
     pub fn close(&self) {
         let _span = tracy_client::span!("WindowElement::close");
@@ -149,6 +189,30 @@ impl WindowElement {
         self.with_state(|state| state.tags.iter().any(|tag| tag.active()))
     }
 
+    /// Returns whether this window's main surface is fully opaque over its geometry.
+    pub fn is_opaque(&self) -> bool {
+        let Some(surface) = self.wl_surface() else {
+            return false;
+        };
+
+        let geometry = self.geometry();
+
+        compositor::with_states(&surface, |states| {
+            let Some(data) = states.data_map.get::<RendererSurfaceStateUserData>() else {
+                return false;
+            };
+
+            let data = data.lock().unwrap();
+            let Some(opaque_regions) = data.opaque_regions() else {
+                return false;
+            };
+
+            geometry
+                .subtract_rects(opaque_regions.iter().copied())
+                .is_empty()
+        })
+    }
+
     pub fn is_x11_override_redirect(&self) -> bool {
         matches!(self.x11_surface(), Some(surface) if surface.is_override_redirect())
     }
@@ -184,14 +248,19 @@ impl WindowElement {
         let (mut size, loc) = {
             #[cfg(feature = "snowcap")]
             {
-                // Not `should_not_have_ssd`, we need the calculation done beforehand
-                if self.with_state(|state| {
-                    state.layout_mode.is_fullscreen()
-                        || state.decoration_mode
-                            == Some(zxdg_toplevel_decoration_v1::Mode::ClientSide)
-                }) || self
-                    .x11_surface()
-                    .is_some_and(|surface| surface.is_decorated())
+                // Not `should_not_have_ssd`, we need the calculation done beforehand.
+                // This checks the pending mode and not only the one set through the API,
+                // as the client may have negotiated it.
+                let pending_decoration_mode = self.toplevel().and_then(|toplevel| {
+                    toplevel.with_pending_state(|state| state.decoration_mode)
+                });
+
+                if self.with_state(|state| state.layout_mode.is_fullscreen())
+                    || pending_decoration_mode
+                        == Some(zxdg_toplevel_decoration_v1::Mode::ClientSide)
+                    || self
+                        .x11_surface()
+                        .is_some_and(|surface| surface.is_decorated())
                 {
                     (size, loc)
                 } else {
@@ -533,6 +602,92 @@ impl WithState for WindowElement {
     }
 }
 
+/// How windows that aren't displayed, like ones on inactive tags, get frame callbacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameCallbackPolicy {
+    /// Hidden windows get a frame callback once per interval.
+    ///
+    /// This keeps clients' animations and clocks from freezing without letting them
+    /// render at full speed.
+    Throttle(Duration),
+    /// Hidden windows get frame callbacks as often as displayed windows on their output.
+    Always,
+    /// Hidden windows get no frame callbacks.
+    Never,
+}
+
+impl Default for FrameCallbackPolicy {
+    fn default() -> Self {
+        Self::Throttle(Duration::from_secs(1))
+    }
+}
+
+impl FrameCallbackPolicy {
+    /// Returns how often hidden windows are checked for due frame callbacks
+    /// independently of their output rendering.
+    pub fn timer_interval(self) -> Duration {
+        match self {
+            FrameCallbackPolicy::Throttle(interval) => interval.max(Duration::from_millis(1)),
+            FrameCallbackPolicy::Always | FrameCallbackPolicy::Never => Duration::from_secs(1),
+        }
+    }
+}
+
+/// Whether floating windows can be moved outside the work area of their output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OffscreenPolicy {
+    /// Windows can be moved anywhere, including completely off-screen.
+    #[default]
+    Allow,
+    /// At least this many pixels of a window stay inside the work area on each axis.
+    KeepVisible(i32),
+    /// Windows stay completely inside the work area.
+    ///
+    /// Windows larger than the work area are pinned to its top-left corner.
+    Clamp,
+}
+
+impl OffscreenPolicy {
+    /// Returns where a window with the geometry `geo` should be placed to satisfy this policy
+    /// within `area`.
+    pub fn constrain(
+        self,
+        geo: Rectangle<i32, Logical>,
+        area: Rectangle<i32, Logical>,
+    ) -> Point<i32, Logical> {
+        let (min_visible_w, min_visible_h) = match self {
+            OffscreenPolicy::Allow => return geo.loc,
+            OffscreenPolicy::KeepVisible(pixels) => {
+                (pixels.min(geo.size.w), pixels.min(geo.size.h))
+            }
+            OffscreenPolicy::Clamp => (geo.size.w, geo.size.h),
+        };
+
+        let clamp_axis = |loc: i32, size: i32, min_visible: i32, area_loc: i32, area_size: i32| {
+            let min = area_loc - (size - min_visible);
+            let max = area_loc + area_size - min_visible;
+            loc.clamp(min, max.max(min))
+        };
+
+        Point::from((
+            clamp_axis(
+                geo.loc.x,
+                geo.size.w,
+                min_visible_w,
+                area.loc.x,
+                area.size.w,
+            ),
+            clamp_axis(
+                geo.loc.y,
+                geo.size.h,
+                min_visible_h,
+                area.loc.y,
+                area.size.h,
+            ),
+        ))
+    }
+}
+
 impl Pinnacle {
     /// Returns the [Window] associated with a given [WlSurface].
     pub fn window_for_surface(&self, surface: &WlSurface) -> Option<&WindowElement> {
@@ -614,6 +769,7 @@ impl Pinnacle {
         }
 
         self.windows.retain(|win| win != window);
+        self.window_tags_changed = true;
         self.unmapped_windows.retain(|win| win.window != window);
         if unmap {
             self.unmapped_windows.push(Unmapped {
@@ -632,6 +788,22 @@ impl Pinnacle {
         }
 
         self.keyboard_focus_stack.remove(window);
+        self.scratchpads.retain(|_, win| win != window);
+        for group in self.window_groups.values_mut() {
+            group.retain(|win| win != window);
+        }
+        if let Some(overview) = self.overview.as_mut() {
+            overview.remove_window(window);
+        }
+
+        let dynamic_tags = window.with_state(|state| {
+            state
+                .tags
+                .iter()
+                .filter(|tag| tag.dynamic())
+                .cloned()
+                .collect::<Vec<_>>()
+        });
 
         let to_schedule = self.space.outputs_for_element(window);
         self.space.unmap_elem(window);
@@ -639,6 +811,8 @@ impl Pinnacle {
             for output in to_schedule {
                 state.schedule_render(&output);
             }
+
+            crate::api::tag::remove_empty_dynamic(state, dynamic_tags);
         });
     }
 
@@ -661,13 +835,103 @@ impl Pinnacle {
         }
     }
 
+    /// Returns the vrr demand of the given window.
+    ///
+    /// An explicitly set demand takes precedence. Otherwise, the window is run
+    /// through the configured [`VrrDemandMatcher`][window_state::VrrDemandMatcher].
+    /// The result is cached and only recomputed when the window's app id or content type changes.
+    pub fn vrr_demand_for_window(&self, window: &WindowElement) -> Option<VrrDemand> {
+        if let Some(demand) = window.with_state(|state| state.vrr_demand) {
+            return Some(demand);
+        }
+
+        let matcher = self.config.vrr_demand_matcher.as_ref()?;
+
+        let app_id = window.class();
+        let content_type = window.content_type();
+
+        let cached = window.with_state(|state| {
+            state
+                .matched_vrr_demand
+                .as_ref()
+                .filter(|matched| matched.app_id == app_id && matched.content_type == content_type)
+                .map(|matched| matched.demand)
+        });
+
+        if let Some(demand) = cached {
+            return demand;
+        }
+
+        let demand = matcher.demand_for(app_id.as_deref(), content_type);
+
+        window.with_state_mut(|state| {
+            state.matched_vrr_demand = Some(MatchedVrrDemand {
+                app_id,
+                content_type,
+                demand,
+            });
+        });
+
+        demand
+    }
+
+    /// Marks a window as wanting attention or not, signaling the config if this changed.
+    pub fn set_window_urgent(&mut self, window: &WindowElement, urgent: bool) {
+        if window.with_state(|state| state.urgent) == urgent {
+            return;
+        }
+
+        window.with_state_mut(|state| state.urgent = urgent);
+
+        self.signal_state.window_urgent_changed.signal(window);
+    }
+
+    /// Overrides the app id reported for a window, or removes the override with `None`.
+    pub fn set_window_app_id_override(&self, window: &WindowElement, app_id: Option<String>) {
+        window.with_state_mut(|state| state.app_id_override = app_id);
+
+        let app_id = window.class().unwrap_or_default();
+        window.with_state(|state| {
+            if let Some(handle) = state.foreign_toplevel_list_handle.as_ref() {
+                handle.send_app_id(&app_id);
+                handle.send_done();
+            }
+        });
+    }
+
+    /// Overrides the fractional scale advertised to a window, or removes the override with `None`.
+    ///
+    /// The override applies no matter which outputs the window is on.
+    pub fn set_window_preferred_scale(&self, window: &WindowElement, scale: Option<f64>) {
+        window.with_state_mut(|state| state.preferred_scale = scale);
+
+        let scale = scale.or_else(|| {
+            self.space
+                .outputs_for_element(window)
+                .first()
+                .cloned()
+                .or_else(|| window.output(self))
+                .map(|output| output.current_scale().fractional_scale())
+        });
+
+        let Some(scale) = scale else {
+            return;
+        };
+
+        window.with_surfaces(|_, states| {
+            with_fractional_scale(states, |fractional_scale| {
+                fractional_scale.set_preferred_scale(scale);
+            });
+        });
+    }
+
     /// Updates the tags of windows that have moved to another output.
     ///
     /// A window "moves" to another output when it has more of its area over the new output
     /// than the old output.
     ///
     /// Needs to be called after `Space::refresh`.
-    pub fn update_window_tags(&self) {
+    pub fn update_window_tags(&mut self) {
         let _span = tracy_client::span!("Pinnacle::refresh_window_tags");
 
         for win in self.windows.iter() {
@@ -692,10 +956,235 @@ impl Pinnacle {
 
             if tag_output != overlapping_output {
                 win.set_tags_to_output(&overlapping_output);
+                self.window_tags_changed = true;
+            }
+        }
+    }
+
+    /// Signals layout mode changes for windows whose layout mode changed
+    /// since the last call.
+    pub fn signal_layout_mode_changes(&mut self) {
+        let _span = tracy_client::span!("Pinnacle::signal_layout_mode_changes");
+
+        for win in self.windows.iter() {
+            let changed = win.with_state_mut(|state| {
+                let current = state.layout_mode.current();
+                let last = state.signaled_layout_mode.replace(current);
+                last.is_some_and(|last| last != current)
+            });
+
+            if changed {
+                self.signal_state.window_layout_mode_changed.signal(win);
+            }
+        }
+    }
+
+    /// Signals geometry changes for windows whose geometry changed since the last call.
+    ///
+    /// Nothing is signaled while the pointer is grabbed so an interactive move or resize
+    /// only signals the final geometry once it ends.
+    pub fn signal_geometry_changes(&mut self) {
+        let _span = tracy_client::span!("Pinnacle::signal_geometry_changes");
+
+        if self
+            .seat
+            .get_pointer()
+            .is_some_and(|pointer| pointer.is_grabbed())
+        {
+            return;
+        }
+
+        for win in self.windows.iter() {
+            let Some(geo) = self.space.element_geometry(win) else {
+                continue;
+            };
+
+            let changed = win.with_state_mut(|state| {
+                let last = state.signaled_geometry.replace(geo);
+                last.is_some_and(|last| last != geo)
+            });
+
+            if changed {
+                self.signal_state.window_geometry_changed.signal((win, geo));
+            }
+        }
+    }
+
+    /// Signals window count changes for tags whose window count changed
+    /// since the last call.
+    ///
+    /// This is called once per event loop cycle so bursts of window changes
+    /// only signal the final count. Nothing is counted unless
+    /// [`Pinnacle::window_tags_changed`] was set.
+    pub fn signal_tag_window_count_changes(&mut self) {
+        let _span = tracy_client::span!("Pinnacle::signal_tag_window_count_changes");
+
+        if !std::mem::take(&mut self.window_tags_changed) {
+            return;
+        }
+
+        let mut counts = HashMap::<Tag, u32>::new();
+        for win in self.windows.iter() {
+            win.with_state(|state| {
+                for tag in state.tags.iter() {
+                    *counts.entry(tag.clone()).or_default() += 1;
+                }
+            });
+        }
+
+        let tags = self
+            .outputs
+            .iter()
+            .flat_map(|op| op.with_state(|state| state.tags.clone()))
+            .filter(|tag| !tag.defunct())
+            .collect::<Vec<_>>();
+
+        for tag in tags {
+            let count = counts.get(&tag).copied().unwrap_or_default();
+            if tag.set_signaled_window_count(count) {
+                self.signal_state
+                    .tag_window_count_changed
+                    .signal((&tag, count));
             }
         }
     }
 
+    /// Constrains `loc` for a floating `window` being placed on `output`
+    /// according to the offscreen policy.
+    pub fn constrain_floating_loc(
+        &self,
+        window: &WindowElement,
+        loc: Point<i32, Logical>,
+        output: &Output,
+    ) -> Point<i32, Logical> {
+        let Some(output_geo) = self.space.output_geometry(output) else {
+            return loc;
+        };
+
+        let mut work_area = self.non_exclusive_zone(output);
+        work_area.loc += output_geo.loc;
+
+        let mut size = window.with_state(|state| state.floating_size);
+        if size.is_empty() {
+            size = window.geometry().size;
+        }
+
+        self.config
+            .offscreen_policy
+            .constrain(Rectangle::new(loc, size), work_area)
+    }
+
+    /// Moves floating windows back into the work area of their output
+    /// if the offscreen policy requires it.
+    ///
+    /// This should be called after outputs are moved, resized, or removed.
+    pub fn constrain_floating_windows(&mut self) {
+        let _span = tracy_client::span!("Pinnacle::constrain_floating_windows");
+
+        if self.config.offscreen_policy == OffscreenPolicy::Allow {
+            return;
+        }
+
+        let floating_windows = self
+            .windows
+            .iter()
+            .filter(|win| win.with_state(|state| state.layout_mode.is_floating()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for win in floating_windows {
+            if win.output(self).is_some() {
+                self.update_window_geometry(&win, false);
+            }
+        }
+    }
+
+    /// Returns whether `window` is fully covered by an opaque window above it on `output`.
+    pub fn is_window_occluded(&self, window: &WindowElement, output: &Output) -> bool {
+        let Some(geo) = self.space.element_geometry(window) else {
+            return false;
+        };
+
+        self.space
+            .elements_for_output(output)
+            .skip_while(|win| *win != window)
+            .skip(1)
+            .any(|above| {
+                self.space
+                    .element_geometry(above)
+                    .is_some_and(|above_geo| above_geo.contains_rect(geo))
+                    && above.is_opaque()
+            })
+    }
+
+    /// Returns whether `window`, which isn't displayed, should get a frame callback at `now`
+    /// according to the frame callback policy.
+    pub fn hidden_window_frame_callback_due(&self, window: &WindowElement, now: Duration) -> bool {
+        let interval = match self.config.frame_callback_policy {
+            FrameCallbackPolicy::Throttle(interval) => interval,
+            FrameCallbackPolicy::Always => return true,
+            FrameCallbackPolicy::Never => return false,
+        };
+
+        throttled_frame_callback_due(window, interval, now)
+    }
+
+    /// Sends frame callbacks to windows that aren't displayed, like ones on inactive tags
+    /// or minimized ones, if they're due for one.
+    ///
+    /// If `output` is set, only windows on that output get them.
+    pub fn send_hidden_frame_callbacks(&self, output: Option<&Output>) {
+        let _span = tracy_client::span!("Pinnacle::send_hidden_frame_callbacks");
+
+        let now = self.clock.now();
+
+        for window in self.windows.iter() {
+            // Displayed windows get frame callbacks when their output renders
+            if self.space.element_location(window).is_some() {
+                continue;
+            }
+
+            let Some(window_output) = window.output(self) else {
+                continue;
+            };
+
+            if output.is_some_and(|output| *output != window_output)
+                || !self.hidden_window_frame_callback_due(window, now.into())
+            {
+                continue;
+            }
+
+            window.send_frame(&window_output, now, None, |_, _| {
+                Some(window_output.clone())
+            });
+        }
+    }
+
+    /// Returns whether `window` should get frame callbacks at `now`.
+    ///
+    /// With a background fps cap, occluded windows only get frame callbacks
+    /// once per cap interval. Windows that aren't occluded, including ones that
+    /// just stopped being occluded, always get them.
+    pub fn window_frame_callback_due(
+        &self,
+        window: &WindowElement,
+        output: &Output,
+        now: Duration,
+    ) -> bool {
+        let Some(fps_cap) = self.config.background_fps_cap else {
+            return true;
+        };
+
+        if !self.is_window_occluded(window, output) {
+            window.with_state_mut(|state| state.last_throttled_frame = None);
+            return true;
+        }
+
+        let interval = Duration::from_secs(1) / fps_cap.max(1);
+
+        throttled_frame_callback_due(window, interval, now)
+    }
+
     pub fn compute_window_geometry(
         &self,
         window: &WindowElement,
@@ -744,6 +1233,18 @@ impl Pinnacle {
                         positioner.get_unconstrained_geometry(non_exclusive_geo).loc
                     });
 
+                let constrained_loc = self
+                    .config
+                    .offscreen_policy
+                    .constrain(Rectangle::new(floating_loc, size), non_exclusive_geo);
+
+                let (set_x, set_y) = if constrained_loc != floating_loc {
+                    (Some(constrained_loc.x), Some(constrained_loc.y))
+                } else {
+                    (set_x, set_y)
+                };
+                let floating_loc = constrained_loc;
+
                 window.with_state_mut(|state| {
                     state.floating_x = Some(set_x.unwrap_or(floating_loc.x));
                     state.floating_y = Some(set_y.unwrap_or(floating_loc.y));
@@ -758,6 +1259,22 @@ impl Pinnacle {
     }
 }
 
+/// Returns whether at least `interval` has passed since `window` last got a throttled
+/// frame callback, recording `now` as the last one if so.
+fn throttled_frame_callback_due(window: &WindowElement, interval: Duration, now: Duration) -> bool {
+    window.with_state_mut(|state| {
+        let due = state
+            .last_throttled_frame
+            .is_none_or(|last| now.saturating_sub(last) >= interval);
+
+        if due {
+            state.last_throttled_frame = Some(now);
+        }
+
+        due
+    })
+}
+
 fn set_tags_to_output(tags: &mut IndexSet<Tag>, output: &Output) {
     *tags = output.with_state(|state| {
         let output_tags = state.focused_tags().cloned().collect::<IndexSet<_>>();
@@ -816,6 +1333,7 @@ impl State {
         };
 
         self.pinnacle.windows.push(window.clone());
+        self.pinnacle.window_tags_changed = true;
 
         self.pinnacle.raise_window(window.clone());
 
@@ -1,7 +1,7 @@
 use std::{rc::Rc, time::Duration};
 
 use smithay::{
-    desktop::{layer_map_for_output, utils::surface_primary_scanout_output},
+    desktop::utils::surface_primary_scanout_output,
     output::Output,
     utils::{Logical, Point, Rectangle},
 };
@@ -159,7 +159,7 @@ impl Pinnacle {
 
         let mode = window.with_state(|state| state.layout_mode);
 
-        let non_exclusive_zone = layer_map_for_output(&output).non_exclusive_zone();
+        let non_exclusive_zone = self.non_exclusive_zone(&output);
         let geo = self.compute_window_geometry(window, output_geo, non_exclusive_zone);
 
         if !window.is_on_active_tag() {
@@ -212,7 +212,11 @@ impl Pinnacle {
         let old_mode = window.with_state(|state| state.layout_mode);
         let mut new_mode = old_mode;
         update_layout(&mut new_mode);
-        window.with_state_mut(|state| state.layout_mode = new_mode);
+        window.with_state_mut(|state| {
+            state.layout_mode = new_mode;
+            // The layout mode was chosen explicitly, so keep it when the output is tiled again
+            state.floated_by_output = false;
+        });
 
         if old_mode != new_mode {
             let need_layout = old_mode.is_tiled() || new_mode.is_tiled() || new_mode.is_spilled();
@@ -249,11 +253,12 @@ impl Pinnacle {
         };
 
         window.set_tags_to_output(&target);
+        self.window_tags_changed = true;
 
         // Reset the floating loc since we're changing output.
         let output_loc = target.current_location();
 
-        let Rectangle { mut loc, size } = layer_map_for_output(&target).non_exclusive_zone();
+        let Rectangle { mut loc, size } = self.non_exclusive_zone(&target);
 
         // Slightly offset the location so the window is not jammed in a corner
         let offset = {
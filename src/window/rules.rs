@@ -1,13 +1,15 @@
 use indexmap::IndexSet;
+use regex::Regex;
 use smithay::{
     desktop::WindowSurface,
     reexports::wayland_protocols::xdg::decoration::zv1::server::zxdg_toplevel_decoration_v1,
-    utils::{Logical, Size},
+    utils::{Logical, Rectangle, Size},
 };
 use tracing::error;
 
 use crate::{
     api::Sender,
+    output::OutputLayoutMode,
     state::{Pinnacle, WithState},
     tag::Tag,
 };
@@ -43,6 +45,43 @@ pub struct WindowRules {
     pub tags: Option<IndexSet<Tag>>,
 }
 
+/// Matches windows by their app id and title.
+///
+/// All set conditions must match. An empty matcher matches every window.
+#[derive(Debug, Clone, Default)]
+pub struct WindowMatcher {
+    /// A regex the window's app id must match.
+    pub app_id: Option<Regex>,
+    /// A regex the window's title must match.
+    pub title: Option<Regex>,
+}
+
+impl WindowMatcher {
+    pub fn matches(&self, window: &WindowElement) -> bool {
+        if let Some(regex) = self.app_id.as_ref()
+            && !window.class().is_some_and(|app_id| regex.is_match(&app_id))
+        {
+            return false;
+        }
+
+        if let Some(regex) = self.title.as_ref()
+            && !window.title().is_some_and(|title| regex.is_match(&title))
+        {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// Gives matching windows that open floating an initial geometry.
+#[derive(Debug, Clone)]
+pub struct GeometryRule {
+    pub matcher: WindowMatcher,
+    /// The geometry, with its location relative to the window's output.
+    pub geometry: Rectangle<i32, Logical>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct ClientRequests {
     pub layout_mode: Option<FullscreenOrMaximized>,
@@ -175,6 +214,14 @@ impl Pinnacle {
 
         let attempt_float_on_map = layout_mode.is_none() && client_layout_mode.is_none();
 
+        // Window rules run after the floating app ids and titles, so a layout mode set by one wins
+        let opens_floating = layout_mode.is_none() && self.config.opens_floating(&unmapped.window);
+
+        let decoration_mode = self
+            .config
+            .decoration_policy
+            .resolve(*decoration_mode, *client_decoration_mode);
+
         let layout_mode = layout_mode
             .or_else(|| {
                 client_layout_mode.map(|mode| match mode {
@@ -182,24 +229,67 @@ impl Pinnacle {
                     FullscreenOrMaximized::Maximized => LayoutMode::new_maximized_external(),
                 })
             })
-            .unwrap_or(LayoutMode::new_tiled());
+            .unwrap_or_else(|| {
+                if opens_floating {
+                    LayoutMode::new_floating()
+                } else {
+                    LayoutMode::new_tiled()
+                }
+            });
 
         unmapped.window.with_state_mut(|state| {
             state.layout_mode = layout_mode;
             state.floating_x = *floating_x;
             state.floating_y = *floating_y;
             state.floating_size = floating_size.unwrap_or(state.floating_size);
-            state.decoration_mode = (*decoration_mode).or(*client_decoration_mode);
+            state.decoration_mode = decoration_mode;
             if let Some(tags) = tags {
                 state.tags = tags.clone();
             }
         });
 
+        // Windows opened on floating outputs float from the start
+        if layout_mode.is_tiled()
+            && unmapped.window.output(self).is_some_and(|output| {
+                output.with_state(|state| state.layout_mode == OutputLayoutMode::Floating)
+            })
+        {
+            unmapped
+                .window
+                .with_state_mut(|state| state.layout_mode.set_floating(true));
+        }
+
+        // Geometry rules only give floating windows a geometry, and window rules
+        // that set one take precedence
+        if unmapped
+            .window
+            .with_state(|state| state.layout_mode.is_floating())
+            && let Some(rule) = self
+                .config
+                .geometry_rules
+                .iter()
+                .find(|rule| rule.matcher.matches(&unmapped.window))
+        {
+            let output_loc = unmapped
+                .window
+                .output(self)
+                .and_then(|output| self.space.output_geometry(&output))
+                .map(|geo| geo.loc)
+                .unwrap_or_default();
+            let loc = output_loc + rule.geometry.loc;
+
+            unmapped.window.with_state_mut(|state| {
+                state.floating_x = floating_x.or(Some(loc.x));
+                state.floating_y = floating_y.or(Some(loc.y));
+                state.floating_size = floating_size.unwrap_or(rule.geometry.size);
+            });
+        }
+
         self.configure_window_if_nontiled(&unmapped.window);
 
         if let WindowSurface::Wayland(toplevel) = unmapped.window.underlying_surface() {
             toplevel.with_pending_state(|state| {
-                state.decoration_mode = (*decoration_mode).or(*client_decoration_mode);
+                state.decoration_mode = decoration_mode;
             });
             crate::handlers::decoration::update_kde_decoration_mode(
                 toplevel.wl_surface(),
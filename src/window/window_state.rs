@@ -1,14 +1,19 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
 
 use indexmap::IndexSet;
+use regex::Regex;
 use smithay::{
-    desktop::{WindowSurface, layer_map_for_output},
-    reexports::wayland_protocols::xdg::{
-        decoration::zv1::server::zxdg_toplevel_decoration_v1, shell::server::xdg_toplevel,
+    desktop::WindowSurface,
+    reexports::wayland_protocols::{
+        wp::content_type::v1::server::wp_content_type_v1,
+        xdg::{decoration::zv1::server::zxdg_toplevel_decoration_v1, shell::server::xdg_toplevel},
     },
-    utils::{Logical, Point, Serial, Size},
+    utils::{Logical, Point, Rectangle, Serial, Size},
     wayland::{compositor::HookId, foreign_toplevel_list::ForeignToplevelHandle},
 };
 use tracing::warn;
@@ -401,6 +406,62 @@ pub struct WindowElementState {
     pub decoration_surfaces: Vec<DecorationSurface>,
 
     pub vrr_demand: Option<VrrDemand>,
+    /// The last result of running this window through the [`VrrDemandMatcher`].
+    pub matched_vrr_demand: Option<MatchedVrrDemand>,
+
+    /// The opacity this window is rendered with.
+    ///
+    /// Fullscreen windows are always rendered fully opaque.
+    pub opacity: f32,
+
+    /// Whether this window wants attention. Cleared when the window is focused.
+    pub urgent: bool,
+
+    /// A fractional scale advertised to this window instead of its output's scale.
+    pub preferred_scale: Option<f64>,
+
+    /// The layout mode last sent through the layout mode changed signal.
+    pub signaled_layout_mode: Option<LayoutModeKind>,
+
+    /// The geometry last sent through the geometry changed signal.
+    pub signaled_geometry: Option<Rectangle<i32, Logical>>,
+
+    /// The width to height ratio this window keeps when resized while floating.
+    pub aspect_ratio: Option<(u32, u32)>,
+
+    /// What happens when this window is asked to close.
+    pub close_behavior: CloseBehavior,
+
+    /// Whether keybinds are bypassed and all keys are sent to this window while it's focused.
+    pub grab_all_input: bool,
+
+    /// Whether this window was floated because its output switched to floating layout mode.
+    ///
+    /// Only these windows are tiled again when the output switches back.
+    pub floated_by_output: bool,
+
+    /// When this window last got a throttled frame callback while occluded or not displayed.
+    pub last_throttled_frame: Option<Duration>,
+
+    /// An app id reported for this window in place of the one it set.
+    pub app_id_override: Option<String>,
+
+    /// Whether the pointer is moved onto this window when it gets keyboard focus.
+    pub warp_cursor_on_focus: bool,
+}
+
+/// What happens when a window is asked to close through the API or foreign toplevel management.
+///
+/// This doesn't stop clients from closing on their own.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum CloseBehavior {
+    /// The window is sent a close request.
+    #[default]
+    Close,
+    /// The window is minimized instead.
+    Minimize,
+    /// The window is hidden in the scratchpad with the given name instead.
+    Scratchpad(String),
 }
 
 impl WindowElement {
@@ -571,10 +632,7 @@ impl Pinnacle {
                 window.set_pending_geo(size, loc);
             }
             LayoutModeKind::Maximized => {
-                let mut non_exclusive_geo = {
-                    let map = layer_map_for_output(&output);
-                    map.non_exclusive_zone()
-                };
+                let mut non_exclusive_geo = self.non_exclusive_zone(&output);
                 non_exclusive_geo.loc += output_geo.loc;
 
                 window.set_pending_geo(non_exclusive_geo.size, Some(non_exclusive_geo.loc));
@@ -663,9 +721,46 @@ impl WindowElementState {
             #[cfg(feature = "snowcap")]
             decoration_surfaces: Vec::new(),
             vrr_demand: None,
+            matched_vrr_demand: None,
+            opacity: 1.0,
+            urgent: false,
+            preferred_scale: None,
+            signaled_layout_mode: None,
+            signaled_geometry: None,
+            aspect_ratio: None,
+            close_behavior: CloseBehavior::default(),
+            grab_all_input: false,
+            floated_by_output: false,
+            last_throttled_frame: None,
+            app_id_override: None,
+            warp_cursor_on_focus: false,
         }
     }
 
+    /// Adjusts `size` to this window's aspect ratio, if it has one.
+    ///
+    /// The height follows the width unless `from_height` is set,
+    /// in which case the width follows the height.
+    pub fn apply_aspect_ratio(
+        &self,
+        mut size: Size<i32, Logical>,
+        from_height: bool,
+    ) -> Size<i32, Logical> {
+        let Some((ratio_w, ratio_h)) = self.aspect_ratio else {
+            return size;
+        };
+
+        let ratio = ratio_w as f64 / ratio_h as f64;
+
+        if from_height {
+            size.w = (size.h as f64 * ratio).round() as i32;
+        } else {
+            size.h = (size.w as f64 / ratio).round() as i32;
+        }
+
+        size
+    }
+
     pub fn floating_loc(&self) -> Option<Point<i32, Logical>> {
         if let (Some(x), Some(y)) = (self.floating_x, self.floating_y) {
             Some(Point::from((x, y)))
@@ -701,10 +796,89 @@ pub struct VrrDemand {
     pub fullscreen: bool,
 }
 
+/// Gives windows without an explicit [`VrrDemand`] one if they match.
+#[derive(Clone, Debug)]
+pub struct VrrDemandMatcher {
+    /// A regex the window's app id must match.
+    pub app_id: Option<Regex>,
+    /// Content types the window must have one of. Empty matches any content type.
+    pub content_types: Vec<wp_content_type_v1::Type>,
+    /// Whether matched windows must be fullscreen to turn on vrr.
+    pub fullscreen: bool,
+}
+
+impl VrrDemandMatcher {
+    /// Returns the demand a window with the given app id and content type should have.
+    pub fn demand_for(
+        &self,
+        app_id: Option<&str>,
+        content_type: wp_content_type_v1::Type,
+    ) -> Option<VrrDemand> {
+        if let Some(regex) = self.app_id.as_ref()
+            && !app_id.is_some_and(|app_id| regex.is_match(app_id))
+        {
+            return None;
+        }
+
+        if !self.content_types.is_empty() && !self.content_types.contains(&content_type) {
+            return None;
+        }
+
+        Some(VrrDemand {
+            fullscreen: self.fullscreen,
+        })
+    }
+}
+
+/// A cached [`VrrDemandMatcher`] result, along with the window properties it was computed from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatchedVrrDemand {
+    pub app_id: Option<String>,
+    pub content_type: wp_content_type_v1::Type,
+    pub demand: Option<VrrDemand>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn vrr_demand_matcher_requires_all_conditions() {
+        let matcher = VrrDemandMatcher {
+            app_id: Some(Regex::new("^steam_app_").unwrap()),
+            content_types: vec![wp_content_type_v1::Type::Game],
+            fullscreen: true,
+        };
+
+        assert_eq!(
+            matcher.demand_for(Some("steam_app_1234"), wp_content_type_v1::Type::Game),
+            Some(VrrDemand { fullscreen: true })
+        );
+        assert_eq!(
+            matcher.demand_for(Some("steam_app_1234"), wp_content_type_v1::Type::Video),
+            None
+        );
+        assert_eq!(
+            matcher.demand_for(Some("firefox"), wp_content_type_v1::Type::Game),
+            None
+        );
+        assert_eq!(
+            matcher.demand_for(None, wp_content_type_v1::Type::Game),
+            None
+        );
+
+        let matcher = VrrDemandMatcher {
+            app_id: None,
+            content_types: Vec::new(),
+            fullscreen: false,
+        };
+
+        assert_eq!(
+            matcher.demand_for(None, wp_content_type_v1::Type::None),
+            Some(VrrDemand { fullscreen: false })
+        );
+    }
+
     #[test]
     fn layout_mode_changes_correctly_user_only() {
         let mut layout_mode = LayoutMode::new_tiled();
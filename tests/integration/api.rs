@@ -1,6 +1,13 @@
+//! Tests for the config APIs.
+//!
+//! Most tests run the same steps through both the Rust and Lua APIs. Lua test chunks don't
+//! run an event loop, so tests that wait on signal or bind callbacks only use the Rust API.
+
+mod debug;
 mod input;
 mod output;
 mod pinnacle;
 mod process;
+mod render;
 mod tag;
 mod window;
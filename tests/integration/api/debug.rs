@@ -0,0 +1,159 @@
+use pinnacle::{state::WithState, tag::Tag};
+use smithay::utils::Rectangle;
+
+use crate::{
+    common::{Lang, fixture::Fixture, for_each_api},
+    spawn_lua_blocking,
+};
+
+fn set_up() -> Fixture {
+    let fixture = Fixture::new();
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+    fixture
+}
+
+#[test_log::test]
+fn debug_set_direct_scanout() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        assert!(!fixture.pinnacle().config.debug.disable_direct_scanout);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::debug::set_direct_scanout(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.debug").set_direct_scanout(false)
+            },
+        }
+
+        assert!(fixture.pinnacle().config.debug.disable_direct_scanout);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::debug::toggle_direct_scanout();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.debug").toggle_direct_scanout()
+            },
+        }
+
+        assert!(!fixture.pinnacle().config.debug.disable_direct_scanout);
+    });
+}
+
+#[test_log::test]
+fn debug_set_opaque_region_culling() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        assert!(!fixture.pinnacle().config.debug.opaque_region_culling);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::debug::set_opaque_region_culling(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.debug").set_opaque_region_culling(true)
+            },
+        }
+
+        assert!(fixture.pinnacle().config.debug.opaque_region_culling);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::debug::toggle_opaque_region_culling();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.debug").toggle_opaque_region_culling()
+            },
+        }
+
+        assert!(!fixture.pinnacle().config.debug.opaque_region_culling);
+    });
+}
+
+#[test_log::test]
+fn debug_dump_state_contains_outputs_and_windows() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+        output.with_state_mut(|state| {
+            let tag = Tag::new("1".to_string());
+            tag.set_active(true);
+            state.add_tags([tag, Tag::new("2".to_string())]);
+        });
+        fixture.pinnacle().focus_output(&output);
+
+        let client_id = fixture.add_client();
+        fixture.spawn_floating_window_with(client_id, (500, 400), |win| {
+            win.set_app_id("mango");
+            win.set_title("a title");
+        });
+
+        let output_name = output.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let dump = pinnacle_api::debug::dump_state();
+
+                assert_eq!(dump.outputs.len(), 1);
+                let output = &dump.outputs[0];
+                assert_eq!(output.name, output_name);
+                assert_eq!(output.geometry.unwrap().size.w, 1920);
+                assert_eq!(
+                    output
+                        .tags
+                        .iter()
+                        .map(|tag| (tag.name.as_str(), tag.active))
+                        .collect::<Vec<_>>(),
+                    [("1", true), ("2", false)]
+                );
+                assert_eq!(dump.focused_output_name, Some(output_name));
+
+                assert_eq!(dump.windows.len(), 1);
+                let window = &dump.windows[0];
+                assert_eq!(window.app_id, "mango");
+                assert_eq!(window.title, "a title");
+                assert_eq!(window.tag_ids, [output.tags[0].id]);
+                assert_eq!(
+                    window.layout_mode,
+                    pinnacle_api::window::LayoutMode::Floating
+                );
+                assert_eq!(window.geometry.unwrap().size.w, 500);
+                assert_eq!(dump.focused_window_id, Some(window.id));
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local dump = require("pinnacle.debug").dump_state()
+
+                assert(#dump.outputs == 1)
+                local output = dump.outputs[1]
+                assert(output.name == $output_name)
+                assert(output.geometry.width == 1920)
+                assert(#output.tags == 2)
+                assert(output.tags[1].name == "1" and output.tags[1].active)
+                assert(output.tags[2].name == "2" and not output.tags[2].active)
+                assert(dump.focused_output_name == $output_name)
+
+                assert(#dump.windows == 1)
+                local window = dump.windows[1]
+                assert(window.app_id == "mango")
+                assert(window.title == "a title")
+                assert(window.tag_ids[1] == output.tags[1].id)
+                assert(window.layout_mode == "floating")
+                assert(window.geometry.width == 500)
+                assert(dump.focused_window_id == window.id)
+            },
+        }
+    });
+}
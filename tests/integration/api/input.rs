@@ -1,10 +1,37 @@
-use pinnacle_api::input::Bind as _;
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+use pinnacle::{
+    handlers::idle::IdleDimConfig, render::pointer::PointerElement, screensaver::ScreenSaverEvent,
+    state::WithState, tag::Tag,
+};
+use pinnacle_api::input::{Bind as _, ButtonAction, Keysym, Mod, MouseButton, SwipeDirection};
+use smithay::{
+    backend::input::{ButtonState, KeyState},
+    input::pointer::{CursorIcon, CursorImageStatus},
+    utils::{Point, Rectangle},
+    wayland::selection::primary_selection,
+};
+use xkbcommon::xkb::{self, Keycode};
 
 use crate::{
-    common::{Lang, fixture::Fixture, for_each_api},
+    common::{Lang, client::ClientId, fixture::Fixture, for_each_api},
     spawn_lua_blocking,
 };
 
+/// The xkb keycodes of left Ctrl and A.
+const CTRL_L: Keycode = Keycode::new(37);
+const KEY_A: Keycode = Keycode::new(38);
+const KEY_E: Keycode = Keycode::new(26);
+const KEY_APOSTROPHE: Keycode = Keycode::new(48);
+const ALT_R: Keycode = Keycode::new(108);
+const SHIFT_L: Keycode = Keycode::new(50);
+
 fn set_up() -> Fixture {
     let fixture = Fixture::new();
     fixture
@@ -14,6 +41,24 @@ fn set_up() -> Fixture {
     fixture
 }
 
+/// Sets up a fixture with a focused floating window to receive injected input.
+fn set_up_with_focused_window() -> (Fixture, ClientId) {
+    let mut fixture = set_up();
+
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag]);
+    });
+    fixture.pinnacle().focus_output(&output);
+
+    let client_id = fixture.add_client();
+    fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
+
+    (fixture, client_id)
+}
+
 #[test_log::test]
 fn input_set_xkb_config() {
     for_each_api(|lang| {
@@ -173,6 +218,595 @@ fn input_keybind() {
     });
 }
 
+#[test_log::test]
+fn input_keybind_repeat() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        // Need tokio here for the input stuff
+        let handle = fixture.runtime_handle();
+        let _guard = handle.enter();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::keybind(pinnacle_api::input::Mod::SUPER, 'c')
+                    .repeat()
+                    .on_press(|| {});
+                pinnacle_api::input::keybind(pinnacle_api::input::Mod::SUPER, 'v').on_press(|| {});
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.keybind({
+                    mods = { "super" },
+                    key = "c",
+                    repeating = true,
+                    on_press = function() end,
+                })
+                Input.keybind({
+                    mods = { "super" },
+                    key = "v",
+                    on_press = function() end,
+                })
+            },
+        }
+
+        let repeats = fixture
+            .pinnacle()
+            .input_state
+            .bind_state
+            .keybinds
+            .id_map
+            .values()
+            .map(|keybind| {
+                let keybind = keybind.borrow();
+                (keybind.key, keybind.repeat)
+            })
+            .collect::<Vec<_>>();
+
+        assert!(repeats.contains(&(pinnacle_api::Keysym::c, true)));
+        assert!(repeats.contains(&(pinnacle_api::Keysym::v, false)));
+    });
+}
+
+#[test_log::test]
+fn input_keybind_repeat_fires_while_held() {
+    let (mut fixture, _) = set_up_with_focused_window();
+
+    let presses = Arc::new(AtomicU32::new(0));
+
+    let presses_clone = presses.clone();
+    fixture.spawn_blocking(move || {
+        pinnacle_api::input::set_mouse_button_map([(
+            MouseButton::Side,
+            ButtonAction::Keybind(Mod::SUPER, Keysym::Up),
+        )]);
+        pinnacle_api::input::keybind(Mod::SUPER, Keysym::Up)
+            .repeat()
+            .on_press(move || {
+                presses_clone.fetch_add(1, Ordering::SeqCst);
+            });
+    });
+
+    fixture
+        .state()
+        .pointer_button(MouseButton::Side.into(), ButtonState::Pressed, 0);
+
+    fixture.dispatch_until(|_| presses.load(Ordering::SeqCst) >= 3);
+
+    fixture
+        .state()
+        .pointer_button(MouseButton::Side.into(), ButtonState::Released, 0);
+
+    // Let any in-flight presses arrive
+    fixture.dispatch_for(Duration::from_millis(100));
+    let presses_after_release = presses.load(Ordering::SeqCst);
+
+    fixture.dispatch_for(Duration::from_millis(300));
+    assert_eq!(presses.load(Ordering::SeqCst), presses_after_release);
+    assert!(fixture.pinnacle().input_state.bind_repeat.is_none());
+}
+
+#[test_log::test]
+fn input_bind_layer_suppresses_default_binds_unless_passthrough() {
+    let (mut fixture, _) = set_up_with_focused_window();
+
+    let default_presses = Arc::new(AtomicU32::new(0));
+    let resize_presses = Arc::new(AtomicU32::new(0));
+
+    let default_presses_clone = default_presses.clone();
+    let resize_presses_clone = resize_presses.clone();
+    fixture.spawn_blocking(move || {
+        pinnacle_api::input::set_mouse_button_map([
+            (
+                MouseButton::Side,
+                ButtonAction::Keybind(Mod::SUPER, Keysym::Up),
+            ),
+            (
+                MouseButton::Extra,
+                ButtonAction::Keybind(Mod::SUPER, Keysym::Left),
+            ),
+        ]);
+        pinnacle_api::input::keybind(Mod::SUPER, Keysym::Up).on_press(move || {
+            default_presses_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        pinnacle_api::input::BindLayer::get("resize")
+            .keybind(Mod::SUPER, Keysym::Left)
+            .on_press(move || {
+                resize_presses_clone.fetch_add(1, Ordering::SeqCst);
+            });
+        pinnacle_api::input::BindLayer::get("resize").enter();
+    });
+
+    let click = |fixture: &mut Fixture, button: MouseButton| {
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            fixture.state().pointer_button(button.into(), state, 0);
+        }
+        fixture.dispatch_for(Duration::from_millis(100));
+    };
+
+    click(&mut fixture, MouseButton::Extra);
+    click(&mut fixture, MouseButton::Side);
+    assert_eq!(resize_presses.load(Ordering::SeqCst), 1);
+    assert_eq!(default_presses.load(Ordering::SeqCst), 0);
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::input::BindLayer::get("resize").set_passthrough(true);
+    });
+
+    click(&mut fixture, MouseButton::Side);
+    assert_eq!(default_presses.load(Ordering::SeqCst), 1);
+
+    fixture.spawn_blocking(pinnacle_api::input::exit_bind_layer);
+    assert!(
+        fixture
+            .pinnacle()
+            .input_state
+            .bind_state
+            .layer_stack
+            .is_empty()
+    );
+
+    click(&mut fixture, MouseButton::Extra);
+    click(&mut fixture, MouseButton::Side);
+    assert_eq!(resize_presses.load(Ordering::SeqCst), 1);
+    assert_eq!(default_presses.load(Ordering::SeqCst), 2);
+}
+
+#[test_log::test]
+fn input_grab_all_input_bypasses_keybinds_until_escaped() {
+    let (mut fixture, _) = set_up_with_focused_window();
+
+    let presses = Arc::new(AtomicU32::new(0));
+
+    let presses_clone = presses.clone();
+    fixture.spawn_blocking(move || {
+        pinnacle_api::input::set_mouse_button_map([
+            (
+                MouseButton::Side,
+                ButtonAction::Keybind(Mod::SUPER, Keysym::Up),
+            ),
+            (
+                MouseButton::Extra,
+                ButtonAction::Keybind(Mod::SUPER, Keysym::Escape),
+            ),
+        ]);
+        pinnacle_api::input::keybind(Mod::SUPER, Keysym::Up).on_press(move || {
+            presses_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        pinnacle_api::input::set_grab_all_input_escape(Mod::SUPER, Keysym::Escape);
+        pinnacle_api::window::get_focused()
+            .unwrap()
+            .set_grab_all_input(true);
+    });
+
+    let click = |fixture: &mut Fixture, button: MouseButton| {
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            fixture.state().pointer_button(button.into(), state, 0);
+        }
+        fixture.dispatch_for(Duration::from_millis(100));
+    };
+
+    click(&mut fixture, MouseButton::Side);
+    assert_eq!(presses.load(Ordering::SeqCst), 0);
+
+    click(&mut fixture, MouseButton::Extra);
+    let window = fixture.pinnacle().windows[0].clone();
+    assert!(!window.with_state(|state| state.grab_all_input));
+
+    click(&mut fixture, MouseButton::Side);
+    assert_eq!(presses.load(Ordering::SeqCst), 1);
+}
+
+#[test_log::test]
+fn input_set_double_click_time_detects_quick_presses_only() {
+    let (mut fixture, _) = set_up_with_focused_window();
+
+    let presses = Arc::new(AtomicU32::new(0));
+    let double_clicks = Arc::new(AtomicU32::new(0));
+
+    fixture.spawn_blocking({
+        let presses = presses.clone();
+        let double_clicks = double_clicks.clone();
+        move || {
+            pinnacle_api::input::set_double_click_time(Duration::from_millis(200));
+            pinnacle_api::input::mousebind(Mod::empty(), MouseButton::Side)
+                .on_press(move || {
+                    presses.fetch_add(1, Ordering::SeqCst);
+                })
+                .on_double_click(move || {
+                    double_clicks.fetch_add(1, Ordering::SeqCst);
+                });
+        }
+    });
+
+    assert_eq!(
+        fixture.pinnacle().input_state.clicks.double_click_time(),
+        Duration::from_millis(200)
+    );
+
+    let click = |fixture: &mut Fixture, time: u32| {
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            fixture
+                .state()
+                .pointer_button(MouseButton::Side.into(), state, time);
+        }
+        fixture.dispatch_for(Duration::from_millis(100));
+    };
+
+    // Two quick clicks
+    click(&mut fixture, 1000);
+    click(&mut fixture, 1100);
+    assert_eq!(presses.load(Ordering::SeqCst), 2);
+    assert_eq!(double_clicks.load(Ordering::SeqCst), 1);
+
+    // Two slow clicks
+    click(&mut fixture, 5000);
+    click(&mut fixture, 5500);
+    assert_eq!(presses.load(Ordering::SeqCst), 4);
+    assert_eq!(double_clicks.load(Ordering::SeqCst), 1);
+}
+
+#[test_log::test]
+fn input_gesture_binds_fire_on_matching_gestures() {
+    let mut fixture = set_up();
+
+    let swipes = Arc::new(AtomicU32::new(0));
+    let pinch_scale = Arc::new(Mutex::new(None));
+
+    fixture.spawn_blocking({
+        let swipes = swipes.clone();
+        let pinch_scale = pinch_scale.clone();
+        move || {
+            pinnacle_api::input::on_swipe(3, SwipeDirection::Left, move || {
+                swipes.fetch_add(1, Ordering::SeqCst);
+            });
+            pinnacle_api::input::on_pinch(2, move |scale| {
+                pinch_scale.lock().unwrap().replace(scale);
+            });
+        }
+    });
+
+    let swipe = |fixture: &mut Fixture, fingers: u32, delta: (f64, f64), cancelled: bool| {
+        fixture.state().gesture_swipe_begin(fingers, 0);
+        fixture.state().gesture_swipe_update(delta.into(), 0);
+        fixture.state().gesture_swipe_end(cancelled, 0);
+        fixture.dispatch_for(Duration::from_millis(100));
+    };
+
+    swipe(&mut fixture, 3, (-100.0, 10.0), false);
+    assert_eq!(swipes.load(Ordering::SeqCst), 1);
+
+    // Cancelled, in the wrong direction, or with an unbound finger count
+    swipe(&mut fixture, 3, (-100.0, 10.0), true);
+    swipe(&mut fixture, 3, (100.0, 10.0), false);
+    swipe(&mut fixture, 4, (-100.0, 10.0), false);
+    assert_eq!(swipes.load(Ordering::SeqCst), 1);
+
+    fixture.state().gesture_pinch_begin(2, 0);
+    fixture
+        .state()
+        .gesture_pinch_update((0.0, 0.0).into(), 0.5, 0.0, 0);
+    fixture.state().gesture_pinch_end(false, 0);
+    fixture.dispatch_for(Duration::from_millis(100));
+
+    assert_eq!(*pinch_scale.lock().unwrap(), Some(0.5));
+}
+
+#[test_log::test]
+fn input_keybind_release_fires_after_press_across_focus_change() {
+    let (mut fixture, client_id) = set_up_with_focused_window();
+
+    let edges = Arc::new(Mutex::new(Vec::new()));
+
+    let edges_clone = edges.clone();
+    fixture.spawn_blocking(move || {
+        pinnacle_api::input::set_mouse_button_map([(
+            MouseButton::Side,
+            ButtonAction::Keybind(Mod::SUPER, Keysym::Down),
+        )]);
+        let on_press_edges = edges_clone.clone();
+        pinnacle_api::input::keybind(Mod::SUPER, Keysym::Down)
+            .on_press(move || on_press_edges.lock().unwrap().push("press"))
+            .on_release(move || edges_clone.lock().unwrap().push("release"));
+    });
+
+    fixture
+        .state()
+        .pointer_button(MouseButton::Side.into(), ButtonState::Pressed, 0);
+
+    fixture.dispatch_until(|_| !edges.lock().unwrap().is_empty());
+
+    // Focus moves to a new window while the key is held
+    fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
+
+    fixture
+        .state()
+        .pointer_button(MouseButton::Side.into(), ButtonState::Released, 0);
+
+    fixture.dispatch_until(|_| edges.lock().unwrap().len() >= 2);
+
+    assert_eq!(*edges.lock().unwrap(), ["press", "release"]);
+    assert!(
+        fixture
+            .pinnacle()
+            .input_state
+            .bind_state
+            .keybinds
+            .pressed_binds
+            .is_empty()
+    );
+}
+
+#[test_log::test]
+fn input_set_numlock() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up_with_focused_window();
+
+        let numlock_on = |fixture: &mut Fixture| {
+            fixture
+                .pinnacle()
+                .seat
+                .get_keyboard()
+                .unwrap()
+                .modifier_state()
+                .num_lock
+        };
+
+        assert!(!numlock_on(&mut fixture));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_numlock(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_numlock(true)
+            },
+        }
+
+        assert!(numlock_on(&mut fixture));
+
+        // Setting the same state again doesn't toggle it
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_numlock(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_numlock(true)
+            },
+        }
+
+        assert!(numlock_on(&mut fixture));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_numlock(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_numlock(false)
+            },
+        }
+
+        assert!(!numlock_on(&mut fixture));
+    });
+}
+
+#[test_log::test]
+fn input_numlock_on_start_survives_config_keymap_changes() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up_with_focused_window();
+
+        let numlock_on = |fixture: &mut Fixture| {
+            fixture
+                .pinnacle()
+                .seat
+                .get_keyboard()
+                .unwrap()
+                .modifier_state()
+                .num_lock
+        };
+
+        // What `numlock_on_start` does before the config starts
+        fixture.state().set_numlock(true);
+        assert!(numlock_on(&mut fixture));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_xkb_config(
+                    pinnacle_api::input::XkbConfig::new().with_layout("us"),
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_xkb_config({ layout = "us" })
+            },
+        }
+
+        assert!(numlock_on(&mut fixture));
+
+        let keymap = xkb::Keymap::new_from_names(
+            &xkb::Context::new(xkb::CONTEXT_NO_FLAGS),
+            "",
+            "",
+            "us",
+            "",
+            None,
+            xkb::COMPILE_NO_FLAGS,
+        )
+        .unwrap()
+        .get_as_string(xkb::KEYMAP_FORMAT_TEXT_V1);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::input::set_xkb_keymap(keymap);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_xkb_keymap($keymap)
+            },
+        }
+
+        assert!(numlock_on(&mut fixture));
+    });
+}
+
+#[test_log::test]
+fn input_set_sticky_keys_latches_modifier_for_next_key() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up_with_focused_window();
+
+        let presses = Arc::new(AtomicU32::new(0));
+
+        let presses_clone = presses.clone();
+        fixture.spawn_blocking(move || {
+            pinnacle_api::input::keybind(Mod::CTRL, 'a').on_press(move || {
+                presses_clone.fetch_add(1, Ordering::SeqCst);
+            });
+        });
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_sticky_keys(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_sticky_keys(true)
+            },
+        }
+
+        assert!(fixture.pinnacle().input_state.sticky_keys.enabled);
+
+        let tap = |fixture: &mut Fixture, keycode: Keycode| {
+            for state in [KeyState::Pressed, KeyState::Released] {
+                fixture.state().keyboard_key(keycode, state, 0);
+            }
+            fixture.dispatch_for(Duration::from_millis(100));
+        };
+
+        tap(&mut fixture, CTRL_L);
+        tap(&mut fixture, KEY_A);
+        assert_eq!(presses.load(Ordering::SeqCst), 1);
+
+        let ctrl_held = |fixture: &mut Fixture| {
+            fixture
+                .pinnacle()
+                .seat
+                .get_keyboard()
+                .unwrap()
+                .modifier_state()
+                .ctrl
+        };
+
+        // The latch is released after one key
+        assert!(!ctrl_held(&mut fixture));
+        tap(&mut fixture, KEY_A);
+        assert_eq!(presses.load(Ordering::SeqCst), 1);
+
+        // Tapping twice locks the modifier until it's tapped again
+        tap(&mut fixture, CTRL_L);
+        tap(&mut fixture, CTRL_L);
+        tap(&mut fixture, KEY_A);
+        tap(&mut fixture, KEY_A);
+        assert_eq!(presses.load(Ordering::SeqCst), 3);
+
+        tap(&mut fixture, CTRL_L);
+        assert!(!ctrl_held(&mut fixture));
+        tap(&mut fixture, KEY_A);
+        assert_eq!(presses.load(Ordering::SeqCst), 3);
+    });
+}
+
+#[test_log::test]
+fn input_set_resize_edge_threshold() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up_with_focused_window();
+
+        // The 500x500 window's top-left corner is at (710, 290).
+        // This point is 100 pixels from the left edge and 10 from the top.
+        let point = (810.0, 300.0);
+
+        let resize_cursor = |fixture: &mut Fixture| {
+            fixture.state().warp_cursor_to_global_loc(point);
+            fixture.spawn_blocking(|| {
+                pinnacle_api::window::begin_resize(MouseButton::Left);
+            });
+            let cursor = fixture.pinnacle().cursor_state.cursor_image().clone();
+            fixture
+                .state()
+                .pointer_button(MouseButton::Left.into(), ButtonState::Released, 0);
+            cursor
+        };
+
+        // By default the window is split into thirds
+        assert_eq!(
+            resize_cursor(&mut fixture),
+            CursorImageStatus::Named(CursorIcon::NwResize)
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_resize_edge_threshold(20);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_resize_edge_threshold(20)
+            },
+        }
+
+        assert_eq!(
+            resize_cursor(&mut fixture),
+            CursorImageStatus::Named(CursorIcon::NResize)
+        );
+
+        // Large thresholds are capped at a third of the window
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_resize_edge_threshold(1000);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_resize_edge_threshold(1000)
+            },
+        }
+
+        assert_eq!(
+            resize_cursor(&mut fixture),
+            CursorImageStatus::Named(CursorIcon::NwResize)
+        );
+
+        fixture.state().warp_cursor_to_global_loc((1200.0, 540.0));
+        fixture.spawn_blocking(|| {
+            pinnacle_api::window::begin_resize(MouseButton::Left);
+        });
+        assert_eq!(
+            *fixture.pinnacle().cursor_state.cursor_image(),
+            CursorImageStatus::Named(CursorIcon::EResize)
+        );
+    });
+}
+
 #[test_log::test]
 fn input_mousebind() {
     for_each_api(|lang| {
@@ -234,3 +868,923 @@ fn input_mousebind() {
         assert!(mousebind.has_on_press)
     });
 }
+
+#[test_log::test]
+fn input_type_string() {
+    for_each_api(|lang| {
+        let (mut fixture, client_id) = set_up_with_focused_window();
+        fixture.pinnacle().config.allow_input_injection = true;
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::type_string("Hello, World!");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.type_string("Hello, World!")
+            },
+        }
+
+        fixture.roundtrip(client_id);
+
+        assert_eq!(fixture.client(client_id).typed_text(), "Hello, World!");
+    });
+}
+
+#[test_log::test]
+fn input_send_combo() {
+    for_each_api(|lang| {
+        let (mut fixture, client_id) = set_up_with_focused_window();
+        fixture.pinnacle().config.allow_input_injection = true;
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::send_combo([
+                    pinnacle_api::Keysym::Shift_L,
+                    pinnacle_api::Keysym::a,
+                ]);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.send_combo({ "Shift_L", "a" })
+            },
+        }
+
+        fixture.roundtrip(client_id);
+
+        assert_eq!(fixture.client(client_id).typed_text(), "A");
+    });
+}
+
+#[test_log::test]
+fn input_type_string_ignores_held_modifiers() {
+    for_each_api(|lang| {
+        let (mut fixture, client_id) = set_up_with_focused_window();
+        fixture.pinnacle().config.allow_input_injection = true;
+
+        fixture.state().keyboard_key(SHIFT_L, KeyState::Pressed, 0);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::type_string("abc");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.type_string("abc")
+            },
+        }
+
+        fixture.roundtrip(client_id);
+
+        assert_eq!(fixture.client(client_id).typed_text(), "abc");
+
+        // Shift is still held after typing
+        let keyboard = fixture.pinnacle().seat.get_keyboard().unwrap();
+        assert!(keyboard.modifier_state().shift);
+
+        fixture.state().keyboard_key(SHIFT_L, KeyState::Released, 0);
+        fixture.state().keyboard_key(KEY_A, KeyState::Pressed, 0);
+        fixture.state().keyboard_key(KEY_A, KeyState::Released, 0);
+        fixture.roundtrip(client_id);
+
+        assert_eq!(fixture.client(client_id).typed_text(), "abca");
+    });
+}
+
+#[test_log::test]
+fn input_type_string_does_nothing_when_injection_is_not_allowed() {
+    for_each_api(|lang| {
+        let (mut fixture, client_id) = set_up_with_focused_window();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::type_string("nope");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.type_string("nope")
+            },
+        }
+
+        fixture.roundtrip(client_id);
+
+        assert_eq!(fixture.client(client_id).typed_text(), "");
+    });
+}
+
+#[test_log::test]
+fn input_set_compose_enabled_types_composed_character() {
+    for_each_api(|lang| {
+        let (mut fixture, client_id) = set_up_with_focused_window();
+
+        fixture.spawn_blocking(|| {
+            pinnacle_api::input::set_xkb_config(
+                pinnacle_api::input::XkbConfig::new()
+                    .with_layout("us")
+                    .with_options("compose:ralt"),
+            );
+        });
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_compose_enabled(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_compose_enabled(true)
+            },
+        }
+
+        assert!(fixture.pinnacle().input_state.compose.enabled());
+
+        // Use a known table so the test doesn't depend on the system locale
+        let context = xkb::Context::new(xkb::CONTEXT_NO_FLAGS);
+        let table = xkb::compose::Table::new_from_buffer(
+            &context,
+            "<Multi_key> <apostrophe> <e> : \"é\" eacute\n".as_bytes(),
+            std::ffi::OsStr::new("C"),
+            xkb::compose::FORMAT_TEXT_V1,
+            xkb::compose::COMPILE_NO_FLAGS,
+        )
+        .unwrap();
+        fixture.pinnacle().input_state.compose.set_table(&table);
+
+        for keycode in [ALT_R, KEY_APOSTROPHE, KEY_E] {
+            for state in [KeyState::Pressed, KeyState::Released] {
+                fixture.state().keyboard_key(keycode, state, 0);
+            }
+        }
+
+        fixture.roundtrip(client_id);
+
+        assert_eq!(fixture.client(client_id).typed_text(), "é");
+    });
+}
+
+#[test_log::test]
+fn input_composing_missing_keysym_keeps_keymap_and_locks() {
+    let (mut fixture, client_id) = set_up_with_focused_window();
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::input::set_xkb_config(
+            pinnacle_api::input::XkbConfig::new()
+                .with_layout("us")
+                .with_options("compose:ralt"),
+        );
+        pinnacle_api::input::set_compose_enabled(true);
+        pinnacle_api::input::set_numlock(true);
+    });
+
+    let context = xkb::Context::new(xkb::CONTEXT_NO_FLAGS);
+    let table = xkb::compose::Table::new_from_buffer(
+        &context,
+        "<Multi_key> <apostrophe> <e> : \"é\" eacute\n".as_bytes(),
+        std::ffi::OsStr::new("C"),
+        xkb::compose::FORMAT_TEXT_V1,
+        xkb::compose::COMPILE_NO_FLAGS,
+    )
+    .unwrap();
+    fixture.pinnacle().input_state.compose.set_table(&table);
+
+    let keymap_ptr = |fixture: &mut Fixture| {
+        let keyboard = fixture.pinnacle().seat.get_keyboard().unwrap();
+        keyboard.with_xkb_state(fixture.state(), |context| {
+            unsafe { context.xkb().lock().unwrap().keymap() }.get_raw_ptr() as usize
+        })
+    };
+
+    let compose_e_acute = |fixture: &mut Fixture| {
+        for keycode in [ALT_R, KEY_APOSTROPHE, KEY_E] {
+            for state in [KeyState::Pressed, KeyState::Released] {
+                fixture.state().keyboard_key(keycode, state, 0);
+            }
+        }
+    };
+
+    compose_e_acute(&mut fixture);
+    let keymap_after_first = keymap_ptr(&mut fixture);
+
+    // The keysym is already on the spare key, so the keymap isn't replaced again
+    compose_e_acute(&mut fixture);
+    assert_eq!(keymap_ptr(&mut fixture), keymap_after_first);
+
+    fixture.roundtrip(client_id);
+
+    assert_eq!(fixture.client(client_id).typed_text(), "éé");
+    assert!(
+        fixture
+            .pinnacle()
+            .seat
+            .get_keyboard()
+            .unwrap()
+            .modifier_state()
+            .num_lock
+    );
+}
+
+#[test_log::test]
+fn input_set_idle_dim() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_idle_dim(Duration::from_millis(100), 0.5).unwrap();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                assert(Input.set_idle_dim(100, 0.5))
+            },
+        }
+
+        fixture.dispatch_until(|fixture| fixture.pinnacle().idle_dim.dimmed);
+
+        assert!(pinnacle::render::idle_dim_render_element(fixture.pinnacle(), &output).is_some());
+
+        fixture.pinnacle().notify_idle_dim_activity();
+
+        assert!(!fixture.pinnacle().idle_dim.dimmed);
+        assert!(pinnacle::render::idle_dim_render_element(fixture.pinnacle(), &output).is_none());
+    });
+}
+
+#[test_log::test]
+fn input_set_idle_dim_rejects_out_of_range_level() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                assert!(
+                    pinnacle_api::input::set_idle_dim(Duration::from_millis(100), 1.5).is_err()
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                assert(not Input.set_idle_dim(100, 1.5))
+            },
+        }
+
+        assert_eq!(fixture.pinnacle().idle_dim.config, None);
+    });
+}
+
+#[test_log::test]
+fn input_idle_dim_waits_for_idle_inhibitor() {
+    let mut fixture = set_up();
+
+    fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+
+    fixture
+        .state()
+        .on_screensaver_event(ScreenSaverEvent::Inhibit {
+            cookie: 1,
+            owner: ":1.1".to_string(),
+            app_name: "mpv".to_string(),
+        });
+
+    fixture.pinnacle().set_idle_dim(Some(IdleDimConfig {
+        dim_after: Duration::from_millis(100),
+        dim_level: 0.5,
+    }));
+
+    fixture.dispatch_for(Duration::from_millis(300));
+    assert!(!fixture.pinnacle().idle_dim.dimmed);
+
+    fixture
+        .state()
+        .on_screensaver_event(ScreenSaverEvent::UnInhibit(1));
+
+    fixture.dispatch_until(|fixture| fixture.pinnacle().idle_dim.dimmed);
+}
+
+#[test_log::test]
+fn input_idle_inhibited_by_screensaver_clients() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+
+        let idle_inhibited = |fixture: &mut Fixture, expected: bool| match lang {
+            Lang::Rust => {
+                let inhibited = fixture.spawn_blocking(pinnacle_api::input::idle_inhibited);
+                assert_eq!(inhibited, expected);
+            }
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                assert(Input.idle_inhibited() == $expected)
+            },
+        };
+
+        idle_inhibited(&mut fixture, false);
+
+        // Stand in for two apps calling Inhibit over DBus
+        for (cookie, owner) in [(1, ":1.1"), (2, ":1.2")] {
+            fixture
+                .state()
+                .on_screensaver_event(ScreenSaverEvent::Inhibit {
+                    cookie,
+                    owner: owner.to_string(),
+                    app_name: "firefox".to_string(),
+                });
+        }
+
+        idle_inhibited(&mut fixture, true);
+
+        fixture
+            .state()
+            .on_screensaver_event(ScreenSaverEvent::UnInhibit(1));
+
+        // The second cookie is still held
+        idle_inhibited(&mut fixture, true);
+
+        // The second app disconnects without calling UnInhibit
+        fixture
+            .state()
+            .on_screensaver_event(ScreenSaverEvent::OwnerGone(":1.2".to_string()));
+
+        idle_inhibited(&mut fixture, false);
+    });
+}
+
+#[test_log::test]
+fn input_unset_idle_dim() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        fixture.pinnacle().set_idle_dim(Some(IdleDimConfig {
+            dim_after: Duration::from_secs(60),
+            dim_level: 0.5,
+        }));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::unset_idle_dim();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.unset_idle_dim()
+            },
+        }
+
+        assert_eq!(fixture.pinnacle().idle_dim.config, None);
+    });
+}
+
+#[test_log::test]
+fn input_cursor_size_follows_output_scale_under_pointer() {
+    let mut fixture = set_up();
+
+    fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    let hidpi = fixture.add_output(Rectangle::new((1920, 0).into(), (1920, 1080).into()));
+    let hidpi_name = hidpi.name();
+
+    fixture.spawn_blocking(move || {
+        pinnacle_api::output::get_by_name(&hidpi_name)
+            .unwrap()
+            .set_scale(2.0);
+    });
+
+    fixture.state().warp_cursor_to_global_loc((500.0, 500.0));
+    let lodpi_size = fixture.pinnacle().cursor_state.scaled_size();
+
+    fixture.state().warp_cursor_to_global_loc((2000.0, 200.0));
+    let hidpi_size = fixture.pinnacle().cursor_state.scaled_size();
+
+    assert_eq!(hidpi_size, lodpi_size * 2);
+
+    let PointerElement::Named { size, scale, .. } =
+        fixture.pinnacle().cursor_state.pointer_element()
+    else {
+        panic!("the default cursor should be a named cursor");
+    };
+    assert_eq!((size, scale), (hidpi_size, 2.0));
+
+    fixture.state().warp_cursor_to_global_loc((500.0, 500.0));
+    assert_eq!(fixture.pinnacle().cursor_state.scaled_size(), lodpi_size);
+}
+
+fn cursor_hidden(fixture: &mut Fixture) -> bool {
+    matches!(
+        fixture.pinnacle().cursor_state.pointer_element(),
+        PointerElement::Hidden
+    )
+}
+
+#[test_log::test]
+fn input_set_cursor_visible() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        assert!(!cursor_hidden(&mut fixture));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_cursor_visible(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_cursor_visible(false)
+            },
+        }
+
+        assert!(cursor_hidden(&mut fixture));
+
+        // Moving the pointer doesn't show a cursor hidden by the config
+        fixture.pinnacle().notify_cursor_motion();
+        assert!(cursor_hidden(&mut fixture));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_cursor_visible(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_cursor_visible(true)
+            },
+        }
+
+        assert!(!cursor_hidden(&mut fixture));
+    });
+}
+
+#[test_log::test]
+fn input_set_cursor_autohide() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_cursor_autohide(Duration::from_millis(100));
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_cursor_autohide(100)
+            },
+        }
+
+        fixture.dispatch_until(cursor_hidden);
+
+        fixture.pinnacle().notify_cursor_motion();
+        assert!(!cursor_hidden(&mut fixture));
+
+        fixture.dispatch_until(cursor_hidden);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::unset_cursor_autohide();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.unset_cursor_autohide()
+            },
+        }
+
+        assert!(!cursor_hidden(&mut fixture));
+    });
+}
+
+/// Clicks `button` in the middle of the first window.
+fn click_window(fixture: &mut Fixture, client_id: ClientId, button: u32) {
+    let window = fixture.pinnacle().windows[0].clone();
+    let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+    let center = geo.loc + geo.size.downscale(2).to_point();
+
+    fixture.state().warp_cursor_to_global_loc(center.to_f64());
+    fixture
+        .state()
+        .pointer_button(button, ButtonState::Pressed, 0);
+    fixture
+        .state()
+        .pointer_button(button, ButtonState::Released, 0);
+    fixture.roundtrip(client_id);
+}
+
+#[test_log::test]
+fn input_set_middle_click_paste_clears_primary_selection_on_middle_click() {
+    for_each_api(|lang| {
+        let (mut fixture, client_id) = set_up_with_focused_window();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_middle_click_paste(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_middle_click_paste(false)
+            },
+        }
+
+        assert!(!fixture.pinnacle().config.middle_click_paste);
+
+        let set_selection = |fixture: &mut Fixture| {
+            let pinnacle = fixture.pinnacle();
+            primary_selection::set_primary_selection(
+                &pinnacle.display_handle,
+                &pinnacle.seat,
+                vec!["text/plain;charset=utf-8".to_string()],
+                (),
+            );
+        };
+        let has_selection = |fixture: &mut Fixture| {
+            primary_selection::current_primary_selection_userdata(&fixture.pinnacle().seat)
+                .is_some()
+        };
+
+        set_selection(&mut fixture);
+        assert!(has_selection(&mut fixture));
+
+        // Other buttons leave the selection alone
+        click_window(&mut fixture, client_id, MouseButton::Left.into());
+        assert!(has_selection(&mut fixture));
+
+        click_window(&mut fixture, client_id, MouseButton::Middle.into());
+        assert!(!has_selection(&mut fixture));
+
+        // The click itself still goes through
+        assert_eq!(
+            fixture.client(client_id).pressed_buttons(),
+            [u32::from(MouseButton::Left), u32::from(MouseButton::Middle)]
+        );
+
+        // Nothing changes once paste is enabled again
+        fixture.pinnacle().config.middle_click_paste = true;
+        set_selection(&mut fixture);
+        click_window(&mut fixture, client_id, MouseButton::Middle.into());
+        assert!(has_selection(&mut fixture));
+    });
+}
+
+#[test_log::test]
+fn input_set_mouse_button_map_remaps_to_button() {
+    for_each_api(|lang| {
+        let (mut fixture, client_id) = set_up_with_focused_window();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_mouse_button_map([(
+                    MouseButton::Right,
+                    ButtonAction::Button(MouseButton::Middle),
+                )]);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_mouse_button_map({
+                    { from = "btn_right", button = "btn_middle" },
+                })
+            },
+        }
+
+        click_window(&mut fixture, client_id, MouseButton::Right.into());
+        click_window(&mut fixture, client_id, MouseButton::Left.into());
+
+        assert_eq!(
+            fixture.client(client_id).pressed_buttons(),
+            [u32::from(MouseButton::Middle), u32::from(MouseButton::Left)]
+        );
+    });
+}
+
+#[test_log::test]
+fn input_set_mouse_button_map_remaps_to_keybind() {
+    for_each_api(|lang| {
+        let (mut fixture, client_id) = set_up_with_focused_window();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_mouse_button_map([(
+                    MouseButton::Side,
+                    ButtonAction::Keybind(Mod::SUPER, Keysym::Tab),
+                )]);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_mouse_button_map({
+                    { from = "btn_side", keybind = { mods = { "super" }, key = "Tab" } },
+                })
+            },
+        }
+
+        click_window(&mut fixture, client_id, MouseButton::Side.into());
+
+        assert!(fixture.client(client_id).pressed_buttons().is_empty());
+    });
+}
+
+#[test_log::test]
+fn input_pointer_and_keyboard_focus() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up_with_focused_window();
+
+        let window = fixture.pinnacle().windows[0].clone();
+        let window_id = window.with_state(|state| state.id.0);
+        let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+        let center = geo.loc + geo.size.downscale(2).to_point();
+
+        fixture.state().warp_cursor_to_global_loc(center.to_f64());
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                assert_eq!(
+                    pinnacle_api::input::pointer_focus(),
+                    Some(pinnacle_api::input::PointerFocus::Window(
+                        pinnacle_api::window::WindowHandle::from_id(window_id)
+                    ))
+                );
+                assert_eq!(
+                    pinnacle_api::input::keyboard_focus(),
+                    Some(pinnacle_api::window::WindowHandle::from_id(window_id))
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local focus = Input.pointer_focus()
+                assert(focus and focus.window and focus.window.id == $window_id)
+                assert(Input.keyboard_focus().id == $window_id)
+            },
+        }
+
+        fixture
+            .state()
+            .warp_cursor_to_global_loc((1919.0, 1079.0).into());
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                assert_eq!(pinnacle_api::input::pointer_focus(), None);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                assert(Input.pointer_focus() == nil)
+            },
+        }
+    });
+}
+
+#[test_log::test]
+fn input_confine_pointer_to_output_clamps_motion() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        let left = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+        let right = fixture.add_output(Rectangle::new((1920, 0).into(), (1920, 1080).into()));
+        fixture.pinnacle().focus_output(&left);
+
+        fixture.state().warp_cursor_to_global_loc((3000.0, 500.0));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let output = pinnacle_api::output::get_focused().unwrap();
+                pinnacle_api::input::confine_pointer_to_output(Some(&output));
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.confine_pointer_to_output(Output.get_focused())
+            },
+        }
+
+        assert_eq!(
+            fixture
+                .pinnacle()
+                .input_state
+                .confined_output
+                .as_ref()
+                .and_then(|output| output.upgrade()),
+            Some(left.clone())
+        );
+
+        // The pointer was outside, so it was moved to the center
+        let pointer_loc = fixture
+            .pinnacle()
+            .seat
+            .get_pointer()
+            .unwrap()
+            .current_location();
+        assert_eq!(pointer_loc, (960.0, 540.0).into());
+
+        // Motion into the right output stops at the left one's edge
+        let loc = fixture
+            .pinnacle()
+            .confine_pointer_loc((2500.0, 500.0).into());
+        assert_eq!(loc, (1919.0, 500.0).into());
+        assert!(
+            !fixture
+                .pinnacle()
+                .space
+                .output_geometry(&right)
+                .unwrap()
+                .to_f64()
+                .contains(loc)
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::confine_pointer_to_output(None);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.confine_pointer_to_output(nil)
+            },
+        }
+
+        assert_eq!(
+            fixture
+                .pinnacle()
+                .confine_pointer_loc((2500.0, 500.0).into()),
+            (2500.0, 500.0).into()
+        );
+
+        // Removing the output releases confinement
+        fixture.state().confine_pointer_to_output(Some(&left));
+        fixture.pinnacle().remove_output(&left);
+        assert_eq!(
+            fixture
+                .pinnacle()
+                .confine_pointer_loc((2500.0, 500.0).into()),
+            (2500.0, 500.0).into()
+        );
+        assert!(fixture.pinnacle().input_state.confined_output.is_none());
+    });
+}
+
+#[test_log::test]
+fn input_set_relative_motion_scale_and_policy() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_relative_motion_scale(0.5);
+                pinnacle_api::input::set_relative_motion_policy(
+                    pinnacle_api::input::RelativeMotionPolicy::Unaccelerated,
+                );
+                // Ignored
+                pinnacle_api::input::set_relative_motion_scale(-1.0);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_relative_motion_scale(0.5)
+                Input.set_relative_motion_policy("unaccelerated")
+                Input.set_relative_motion_scale(-1.0)
+            },
+        }
+
+        let relative_motion = fixture.pinnacle().input_state.relative_motion;
+        assert_eq!(relative_motion.scale, 0.5);
+        assert_eq!(
+            relative_motion.policy,
+            pinnacle::input::RelativeMotionPolicy::Unaccelerated
+        );
+
+        let (delta, delta_unaccel) = relative_motion.apply((8.0, 4.0).into(), (4.0, 2.0).into());
+        assert_eq!(delta, (2.0, 1.0).into());
+        assert_eq!(delta_unaccel, (2.0, 1.0).into());
+    });
+}
+
+#[test_log::test]
+fn input_relative_motion_to_locked_pointer_is_scaled() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+        output.with_state_mut(|state| {
+            let tag = Tag::new("1".to_string());
+            tag.set_active(true);
+            state.add_tags([tag]);
+        });
+        fixture.pinnacle().focus_output(&output);
+
+        let client_id = fixture.add_client();
+        // The window's top-left corner is at (710, 290)
+        let surface = fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
+
+        // Move the pointer onto the window
+        let pointer_loc = fixture
+            .pinnacle()
+            .seat
+            .get_pointer()
+            .unwrap()
+            .current_location();
+        let to_window = Point::from((960.0, 540.0)) - pointer_loc;
+        fixture.state().pointer_motion(to_window, to_window, 0);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_relative_motion_scale(0.5);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_relative_motion_scale(0.5)
+            },
+        }
+
+        let locked = fixture.client(client_id).lock_pointer(&surface);
+        fixture.client(client_id).track_relative_motion();
+        fixture.roundtrip(client_id);
+        fixture.roundtrip(client_id);
+        assert!(locked.load(Ordering::Relaxed));
+
+        fixture
+            .state()
+            .pointer_motion((10.0, 6.0).into(), (8.0, 4.0).into(), 1000);
+        fixture.roundtrip(client_id);
+
+        assert_eq!(
+            fixture.client(client_id).relative_motions(),
+            &[((5.0, 3.0), (4.0, 2.0))]
+        );
+
+        // The pointer stays put while locked
+        let pointer = fixture.pinnacle().seat.get_pointer().unwrap();
+        assert_eq!(pointer.current_location(), (960.0, 540.0).into());
+    });
+}
+
+#[test_log::test]
+fn input_reset_pointer_position() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+        let output = fixture.add_output(Rectangle::new((1920, 0).into(), (1280, 720).into()));
+        fixture.pinnacle().focus_output(&output);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::reset_pointer_position();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.reset_pointer_position()
+            },
+        }
+
+        let pointer = fixture.pinnacle().seat.get_pointer().unwrap();
+        assert_eq!(pointer.current_location(), (2560.0, 360.0).into());
+    });
+}
+
+#[test_log::test]
+fn input_set_warp_on_output_focus_warps_pointer_to_focused_output() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        let left = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+        let right = fixture.add_output(Rectangle::new((1920, 0).into(), (1280, 720).into()));
+        fixture.pinnacle().focus_output(&left);
+        fixture.state().warp_cursor_to_global_loc((100.0, 100.0));
+
+        let right_name = right.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::input::set_warp_on_output_focus(true);
+                pinnacle_api::output::get_by_name(right_name)
+                    .unwrap()
+                    .focus();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_warp_on_output_focus(true)
+                Output.get_by_name($right_name):focus()
+            },
+        }
+
+        fixture.dispatch();
+
+        let pointer = fixture.pinnacle().seat.get_pointer().unwrap();
+        assert_eq!(pointer.current_location(), (2560.0, 360.0).into());
+
+        // Focusing the output the pointer is already on leaves it alone
+        fixture.state().warp_cursor_to_global_loc((100.0, 100.0));
+        fixture.pinnacle().focus_output(&left);
+        fixture.dispatch();
+
+        assert_eq!(pointer.current_location(), (100.0, 100.0).into());
+    });
+}
+
+#[test_log::test]
+fn input_set_pointer_default_position_centers_pointer_on_new_output() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::input::set_pointer_default_position(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Input.set_pointer_default_position(true)
+            },
+        }
+
+        fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+        fixture.dispatch_for(Duration::from_millis(100));
+
+        let pointer = fixture.pinnacle().seat.get_pointer().unwrap();
+        assert_eq!(pointer.current_location(), (960.0, 540.0).into());
+    });
+}
@@ -1,4 +1,23 @@
-use pinnacle::{state::WithState, tag::Tag};
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+use pinnacle::{
+    output::BlankingState,
+    render::{color_filter::ColorFilter, supersample::supersample_target_size},
+    state::WithState,
+    tag::Tag,
+};
+use pinnacle_api::{
+    layout::{LayoutGenerator as _, generators::MasterStack},
+    output::{CustomModeFlags, OutputLayoutMode},
+    signal::OutputSignal,
+    util::Direction,
+};
 use smithay::{output::Output, utils::Rectangle};
 
 use crate::{
@@ -226,6 +245,120 @@ fn output_handle_set_mode() {
     });
 }
 
+#[test_log::test]
+fn output_handle_try_mode_reverts_unless_confirmed() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        let old_mode = output.current_mode().unwrap();
+
+        let new_mode = smithay::output::Mode {
+            size: (800, 600).into(),
+            refresh: 75000,
+        };
+        output.add_mode(new_mode);
+        output.with_state_mut(|state| {
+            state.modes.push(new_mode);
+        });
+
+        let try_mode = |fixture: &mut Fixture| match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .try_mode(800, 600, 75000, Duration::from_millis(200))
+                    .unwrap();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                assert(Output.get_focused():try_mode(800, 600, 75000, 200))
+            },
+        };
+
+        try_mode(&mut fixture);
+        assert_eq!(output.current_mode().unwrap(), new_mode);
+
+        fixture.dispatch_for(Duration::from_millis(500));
+        assert_eq!(output.current_mode().unwrap(), old_mode);
+
+        try_mode(&mut fixture);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::confirm_mode();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.confirm_mode()
+            },
+        }
+
+        fixture.dispatch_for(Duration::from_millis(500));
+        assert_eq!(output.current_mode().unwrap(), new_mode);
+    });
+}
+
+#[test_log::test]
+fn output_handle_set_mode_during_try_mode_is_not_reverted() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        let tried_mode = smithay::output::Mode {
+            size: (800, 600).into(),
+            refresh: 75000,
+        };
+        let set_mode = smithay::output::Mode {
+            size: (1024, 768).into(),
+            refresh: 60000,
+        };
+        for mode in [tried_mode, set_mode] {
+            output.add_mode(mode);
+            output.with_state_mut(|state| {
+                state.modes.push(mode);
+            });
+        }
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let output = pinnacle_api::output::get_focused().unwrap();
+                output
+                    .try_mode(800, 600, 75000, Duration::from_millis(200))
+                    .unwrap();
+                output.set_mode(1024, 768, 60000);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local output = Output.get_focused()
+                assert(output:try_mode(800, 600, 75000, 200))
+                output:set_mode(1024, 768, 60000)
+            },
+        }
+
+        assert_eq!(output.current_mode().unwrap(), set_mode);
+
+        fixture.dispatch_for(Duration::from_millis(500));
+        assert_eq!(output.current_mode().unwrap(), set_mode);
+    });
+}
+
+#[test_log::test]
+fn output_handle_try_mode_rejects_unsupported_mode() {
+    let (mut fixture, output, _) = set_up();
+
+    let old_mode = output.current_mode().unwrap();
+
+    fixture.spawn_blocking(|| {
+        let result = pinnacle_api::output::get_focused().unwrap().try_mode(
+            123,
+            456,
+            None,
+            Duration::from_secs(1),
+        );
+        assert!(result.is_err());
+    });
+
+    assert_eq!(output.current_mode().unwrap(), old_mode);
+}
+
 #[test_log::test]
 fn output_handle_set_custom_mode() {
     for_each_api(|lang| {
@@ -273,6 +406,57 @@ fn output_handle_set_custom_mode() {
     });
 }
 
+#[test_log::test]
+fn output_handle_add_custom_mode() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        let new_mode = smithay::output::Mode {
+            size: (2560, 1080).into(),
+            refresh: 75000,
+        };
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let output = pinnacle_api::output::get_focused().unwrap();
+                output
+                    .add_custom_mode(2560, 1080, 75000, CustomModeFlags::REDUCED_BLANKING)
+                    .unwrap();
+                assert!(
+                    output
+                        .add_custom_mode(2560, 1080, 0, CustomModeFlags::empty())
+                        .is_err()
+                );
+                assert!(output.modes().any(|mode| mode.size.w == 2560
+                    && mode.size.h == 1080
+                    && mode.refresh_rate_mhz == 75000));
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                assert(Output.get_focused():add_custom_mode(2560, 1080, 75000, { reduced_blanking = true }))
+                assert(not Output.get_focused():add_custom_mode(2560, 1080, 0))
+            },
+        }
+
+        assert!(output.with_state(|state| state.modes.contains(&new_mode)));
+        assert_ne!(output.current_mode().unwrap(), new_mode);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .set_mode(2560, 1080, 75000);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_focused():set_mode(2560, 1080, 75000)
+            },
+        }
+
+        assert_eq!(output.current_mode().unwrap(), new_mode);
+    });
+}
+
 #[test_log::test]
 fn output_handle_set_modeline() {
     for_each_api(|lang| {
@@ -506,6 +690,53 @@ fn output_handle_set_vrr() {
     });
 }
 
+#[test_log::test]
+fn output_set_vrr_demand_matcher() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_window_with(client_id, |window| window.set_app_id("steam_app_1234"));
+
+        fixture.spawn_blocking(move || {
+            pinnacle_api::output::get_focused()
+                .unwrap()
+                .set_vrr(pinnacle_api::output::Vrr::OnDemand);
+        });
+
+        fixture.dispatch();
+        assert!(output.with_state(|state| !state.is_vrr_on));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::set_vrr_demand_matcher(
+                    pinnacle_api::output::VrrDemandMatcher::new().app_id("^steam_app_"),
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_vrr_demand_matcher({ app_id = "^steam_app_" })
+            },
+        }
+
+        fixture.dispatch_until(|_| output.with_state(|state| state.is_vrr_on));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::set_vrr_demand_matcher(
+                    pinnacle_api::output::VrrDemandMatcher::new().app_id("^firefox$"),
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_vrr_demand_matcher({ app_id = "^firefox$" })
+            },
+        }
+
+        fixture.dispatch_until(|_| output.with_state(|state| !state.is_vrr_on));
+    });
+}
+
 #[test_log::test]
 fn output_handle_make() {
     let (mut fixture, output, _) = set_up();
@@ -563,6 +794,59 @@ fn output_handle_serial() {
     }
 }
 
+#[test_log::test]
+fn output_handle_set_identity_override() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        let name = output.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let output = pinnacle_api::output::get_by_name(name).unwrap();
+                output.set_identity_override("Dell", "U2720Q", "ABC123");
+                assert_eq!(output.make(), "Dell");
+                assert_eq!(output.model(), "U2720Q");
+                assert_eq!(output.serial(), "ABC123");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local output = Output.get_by_name($name)
+                output:set_identity_override("Dell", "U2720Q", "ABC123")
+                assert(output:make() == "Dell")
+                assert(output:model() == "U2720Q")
+                assert(output:serial() == "ABC123")
+            },
+        }
+
+        let loc = output.current_location();
+        fixture.pinnacle().remove_output(&output);
+
+        let new_output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+        assert!(fixture.pinnacle().saved_output_state(&new_output).is_none());
+
+        let new_name = new_output.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_by_name(new_name)
+                    .unwrap()
+                    .set_identity_override("Dell", "U2720Q", "ABC123");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_by_name($new_name):set_identity_override("Dell", "U2720Q", "ABC123")
+            },
+        }
+
+        let saved_loc = fixture
+            .pinnacle()
+            .saved_output_state(&new_output)
+            .map(|saved| saved.loc);
+        assert_eq!(saved_loc, Some(loc));
+    });
+}
+
 #[test_log::test]
 fn output_handle_loc() {
     let (mut fixture, _, output) = set_up();
@@ -701,6 +985,25 @@ fn output_handle_current_mode() {
     }
 }
 
+#[test_log::test]
+fn output_handle_refresh_rate() {
+    let (mut fixture, _, _) = set_up();
+
+    fixture.spawn_blocking(|| {
+        let output = pinnacle_api::output::get_focused().unwrap();
+        assert_eq!(output.refresh_rate(), Some(60000));
+        // The dummy backend doesn't present frames
+        assert_eq!(output.time_since_last_present(), None);
+    });
+
+    spawn_lua_blocking! {
+        fixture,
+        local output = Output.get_focused()
+        assert(output:refresh_rate() == 60000)
+        assert(output:time_since_last_present() == nil)
+    }
+}
+
 #[test_log::test]
 fn output_handle_preferred_mode() {
     let (mut fixture, output, _) = set_up();
@@ -1093,7 +1396,851 @@ fn output_handle_focus() {
     });
 }
 
+#[test_log::test]
+fn output_handle_view_tags() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        let tag2 = Tag::new("2".to_string());
+        output.with_state_mut(|state| state.add_tags([tag2.clone()]));
+
+        let layout_count = Arc::new(AtomicU32::new(0));
+
+        fixture.spawn_blocking({
+            let layout_count = layout_count.clone();
+            move || {
+                pinnacle_api::layout::manage(move |args| {
+                    layout_count.fetch_add(1, Ordering::Relaxed);
+                    pinnacle_api::layout::LayoutResponse {
+                        root_node: MasterStack::default().layout(args.window_count),
+                        tree_id: 0,
+                    }
+                });
+            }
+        });
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(2, client_id);
+
+        fixture.pinnacle().windows[1].with_state_mut(|state| {
+            state.tags = [tag2.clone()].into_iter().collect();
+        });
+
+        let before = layout_count.load(Ordering::Relaxed);
+
+        let name = output.name();
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_by_name(name)
+                    .unwrap()
+                    .view_tags(["1", "2"]);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_by_name($name):view_tags({ "1", "2" })
+            },
+        }
+
+        fixture.dispatch_for(Duration::from_millis(500));
+
+        assert!(
+            fixture
+                .pinnacle()
+                .windows
+                .iter()
+                .all(|win| win.is_on_active_tag())
+        );
+        assert_eq!(layout_count.load(Ordering::Relaxed), before + 1);
+    });
+}
+
+#[test_log::test]
+fn output_handle_add_tag_to_view() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        output.with_state_mut(|state| state.add_tags([Tag::new("2".to_string())]));
+
+        let name = output.name();
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_by_name(name)
+                    .unwrap()
+                    .add_tag_to_view("2");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_by_name($name):add_tag_to_view("2")
+            },
+        }
+
+        output.with_state(|state| {
+            assert!(state.tags.iter().all(|tag| tag.active()));
+        });
+    });
+}
+
+#[test_log::test]
+fn output_focus_in_direction() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2) = set_up();
+
+        let state = fixture.state();
+        state.pinnacle.change_output_state(
+            &mut state.backend,
+            &output2,
+            None,
+            None,
+            None,
+            Some((1920, 0).into()),
+        );
+
+        let focus_in_direction = |fixture: &mut Fixture, dir: Direction| match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::focus_in_direction(dir);
+            }),
+            Lang::Lua => {
+                let dir = match dir {
+                    Direction::Left => "left",
+                    Direction::Right => "right",
+                    Direction::Up => "up",
+                    Direction::Down => "down",
+                };
+                spawn_lua_blocking! {
+                    fixture,
+                    Output.focus_in_direction($dir)
+                }
+            }
+        };
+
+        let client_id = fixture.add_client();
+
+        focus_in_direction(&mut fixture, Direction::Right);
+        assert_eq!(fixture.pinnacle().focused_output().unwrap(), &output2);
+
+        fixture.spawn_windows(1, client_id);
+        let window = fixture.pinnacle().windows.last().cloned().unwrap();
+        assert_eq!(window.output(fixture.pinnacle()).as_ref(), Some(&output2));
+
+        // Nothing further right
+        focus_in_direction(&mut fixture, Direction::Right);
+        assert_eq!(fixture.pinnacle().focused_output().unwrap(), &output2);
+
+        focus_in_direction(&mut fixture, Direction::Left);
+        assert_eq!(fixture.pinnacle().focused_output().unwrap(), &output1);
+
+        fixture.spawn_windows(1, client_id);
+        let window = fixture.pinnacle().windows.last().cloned().unwrap();
+        assert_eq!(window.output(fixture.pinnacle()).as_ref(), Some(&output1));
+    });
+}
+
 // TODO: for_each_output
-// TODO: connect_signal
-// TODO: keyboard_focus_stack
-// TODO: keyboard_focus_stack_visible
+
+#[test_log::test]
+fn output_connect_signal_resize_fires_with_new_size() {
+    let (mut fixture, output, _) = set_up();
+
+    let resized = Arc::new(Mutex::new(None));
+    let resized_clone = resized.clone();
+
+    fixture.spawn_blocking(move || {
+        pinnacle_api::output::connect_signal(OutputSignal::Resize(Box::new(
+            move |output, width, height| {
+                *resized_clone.lock().unwrap() = Some((output.name(), width, height));
+            },
+        )));
+    });
+
+    // Let the signal stream finish connecting
+    fixture.dispatch_for(Duration::from_millis(500));
+
+    fixture.spawn_blocking(move || {
+        pinnacle_api::output::get_focused().unwrap().set_scale(2.0);
+    });
+
+    fixture.dispatch_until(|_| resized.lock().unwrap().is_some());
+
+    let (name, width, height) = resized.lock().unwrap().take().unwrap();
+    assert_eq!(name, output.name());
+    assert_eq!((width, height), (960, 540));
+}
+
+#[test_log::test]
+fn output_handle_set_layout_mode_floating_stops_tiling() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        fixture.spawn_blocking(|| {
+            pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+                root_node: MasterStack::default().layout(args.window_count),
+                tree_id: 0,
+            });
+        });
+
+        let client_id = fixture.add_client();
+        let surfaces = fixture.spawn_windows(2, client_id);
+
+        let geometries = |fixture: &mut Fixture| {
+            let pinnacle = fixture.pinnacle();
+            pinnacle
+                .windows
+                .iter()
+                .map(|win| pinnacle.space.element_geometry(win).unwrap())
+                .collect::<Vec<_>>()
+        };
+        let all_floating = |fixture: &mut Fixture| {
+            fixture
+                .pinnacle()
+                .windows
+                .iter()
+                .all(|win| win.with_state(|state| state.layout_mode.is_floating()))
+        };
+
+        let tiled_geometries = geometries(&mut fixture);
+        assert!(!all_floating(&mut fixture));
+
+        let output_name = output.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_by_name(output_name)
+                    .unwrap()
+                    .set_layout_mode(OutputLayoutMode::Floating);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_by_name($output_name):set_layout_mode("floating")
+            },
+        }
+
+        fixture.flush();
+
+        assert!(all_floating(&mut fixture));
+        assert_eq!(geometries(&mut fixture), tiled_geometries);
+
+        // Closing a window would normally retile the rest
+        fixture.client(client_id).close_window(&surfaces[0]);
+        fixture.roundtrip(client_id);
+        fixture.flush();
+
+        assert_eq!(geometries(&mut fixture), [tiled_geometries[1]]);
+
+        let output_name = output.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_by_name(output_name)
+                    .unwrap()
+                    .set_layout_mode(OutputLayoutMode::Tiling);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_by_name($output_name):set_layout_mode("tiling")
+            },
+        }
+
+        // The remaining window retiles to fill the output
+        fixture
+            .dispatch_until(|fixture| geometries(fixture)[0].size.w > tiled_geometries[1].size.w);
+
+        assert!(!all_floating(&mut fixture));
+    });
+}
+
+#[test_log::test]
+fn output_handle_set_layout_mode_tiling_keeps_floating_windows_floating() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        fixture.spawn_blocking(|| {
+            pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+                root_node: MasterStack::default().layout(args.window_count),
+                tree_id: 0,
+            });
+        });
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(2, client_id);
+
+        let floating = |fixture: &mut Fixture| {
+            fixture
+                .pinnacle()
+                .windows
+                .iter()
+                .map(|win| win.with_state(|state| state.layout_mode.is_floating()))
+                .collect::<Vec<_>>()
+        };
+
+        let output_name = output.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::window::get_all()
+                    .next()
+                    .unwrap()
+                    .set_floating(true);
+                let output = pinnacle_api::output::get_by_name(output_name).unwrap();
+                output.set_layout_mode(OutputLayoutMode::Floating);
+                output.set_layout_mode(OutputLayoutMode::Tiling);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_all()[1]:set_floating(true)
+                local output = Output.get_by_name($output_name)
+                output:set_layout_mode("floating")
+                output:set_layout_mode("tiling")
+            },
+        }
+
+        fixture.flush();
+
+        // Only the window floated by the output is tiled again
+        assert_eq!(floating(&mut fixture), [true, false]);
+    });
+}
+
+// TODO: keyboard_focus_stack
+// TODO: keyboard_focus_stack_visible
+
+#[test_log::test]
+fn output_set_blanked() {
+    for_each_api(|lang| {
+        let (mut fixture, ..) = set_up();
+
+        let output = fixture.pinnacle().focused_output().cloned().unwrap();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .set_blanked(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_focused():set_blanked(true)
+            },
+        }
+
+        fixture.dispatch_until(|_| {
+            output.with_state(|state| matches!(state.blanking_state, BlankingState::Blanked))
+        });
+        assert!(output.with_state(|state| state.blanked));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .set_blanked(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_focused():set_blanked(false)
+            },
+        }
+
+        fixture.dispatch();
+        assert!(output.with_state(|state| {
+            !state.blanked && matches!(state.blanking_state, BlankingState::NotBlanked)
+        }));
+    });
+}
+
+#[test_log::test]
+fn output_handle_set_color_filter() {
+    for_each_api(|lang| {
+        let (mut fixture, ..) = set_up();
+
+        let output = fixture.pinnacle().focused_output().cloned().unwrap();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .set_color_filter(pinnacle_api::output::ColorFilter::Invert);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_focused():set_color_filter("invert")
+            },
+        }
+
+        fixture.dispatch();
+        assert_eq!(
+            output.with_state(|state| state.color_filter),
+            ColorFilter::Invert
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .set_color_filter(pinnacle_api::output::ColorFilter::None);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_focused():set_color_filter("none")
+            },
+        }
+
+        fixture.dispatch();
+        assert_eq!(
+            output.with_state(|state| state.color_filter),
+            ColorFilter::None
+        );
+    });
+}
+
+#[test_log::test]
+fn output_handle_set_supersample_factor() {
+    for_each_api(|lang| {
+        let (mut fixture, ..) = set_up();
+
+        let output = fixture.pinnacle().focused_output().cloned().unwrap();
+
+        let set_factor = |fixture: &mut Fixture, factor: f32| {
+            match lang {
+                Lang::Rust => fixture.spawn_blocking(move || {
+                    pinnacle_api::output::get_focused()
+                        .unwrap()
+                        .set_supersample_factor(factor);
+                }),
+                Lang::Lua => spawn_lua_blocking! {
+                    fixture,
+                    Output.get_focused():set_supersample_factor($factor)
+                },
+            }
+            fixture.dispatch();
+        };
+
+        set_factor(&mut fixture, 2.0);
+        assert_eq!(supersample_target_size(&output), Some((3840, 2160).into()));
+
+        // Clamped to the max factor
+        set_factor(&mut fixture, 10.0);
+        assert_eq!(supersample_target_size(&output), Some((7680, 4320).into()));
+
+        set_factor(&mut fixture, 1.0);
+        assert_eq!(supersample_target_size(&output), None);
+    });
+}
+
+#[test_log::test]
+fn output_set_auto_scale_scales_new_high_dpi_outputs() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, _) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::set_auto_scale(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_auto_scale(true)
+            },
+        }
+
+        assert!(fixture.pinnacle().config.auto_scale);
+
+        // No physical size, so this stays at 1.0
+        assert_eq!(output1.current_scale().fractional_scale(), 1.0);
+
+        // A 27" 4k monitor
+        let output = fixture.add_output_with_physical_size(
+            Rectangle::new((0, 0).into(), (3840, 2160).into()),
+            (597, 336).into(),
+        );
+
+        assert_eq!(output.current_scale().fractional_scale(), 1.75);
+    });
+}
+
+#[test_log::test]
+fn output_set_auto_scale_leaves_explicit_scales_alone() {
+    for_each_api(|lang| {
+        let (mut fixture, _, _) = set_up();
+
+        // Two 27" 4k monitors connected before the config enables auto scale
+        let explicit = fixture.add_output_with_physical_size(
+            Rectangle::new((0, 0).into(), (3840, 2160).into()),
+            (597, 336).into(),
+        );
+        let unset = fixture.add_output_with_physical_size(
+            Rectangle::new((0, 0).into(), (3840, 2160).into()),
+            (597, 336).into(),
+        );
+
+        let explicit_name = explicit.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_by_name(&explicit_name)
+                    .unwrap()
+                    .set_scale(1.25);
+                pinnacle_api::output::set_auto_scale(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_by_name($explicit_name):set_scale(1.25)
+                Output.set_auto_scale(true)
+            },
+        }
+
+        assert_eq!(explicit.current_scale().fractional_scale(), 1.25);
+        assert_eq!(unset.current_scale().fractional_scale(), 1.75);
+
+        let unset_name = unset.name();
+
+        // Enabling it again, like a reload does, doesn't touch guessed scales either
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_by_name(&unset_name)
+                    .unwrap()
+                    .set_scale(2.0);
+                pinnacle_api::output::set_auto_scale(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_by_name($unset_name):set_scale(2.0)
+                Output.set_auto_scale(true)
+            },
+        }
+
+        assert_eq!(unset.current_scale().fractional_scale(), 2.0);
+    });
+}
+
+#[test_log::test]
+fn output_set_legacy_scale_mode_sets_advertised_integer_scale() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, _) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                use pinnacle_api::output::LegacyScale;
+                pinnacle_api::output::set_legacy_scale_mode(LegacyScale::Ceil);
+                pinnacle_api::output::get_focused().unwrap().set_scale(1.5);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_legacy_scale_mode("ceil")
+                Output.get_focused():set_scale(1.5)
+            },
+        }
+
+        assert_eq!(output1.current_scale().fractional_scale(), 1.5);
+        assert_eq!(output1.current_scale().integer_scale(), 2);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                use pinnacle_api::output::LegacyScale;
+                pinnacle_api::output::set_legacy_scale_mode(LegacyScale::Floor);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_legacy_scale_mode("floor")
+            },
+        }
+
+        assert_eq!(output1.current_scale().fractional_scale(), 1.5);
+        assert_eq!(output1.current_scale().integer_scale(), 1);
+    });
+}
+
+#[test_log::test]
+fn output_set_dynamic_tags_creates_and_removes_tags() {
+    for_each_api(|lang| {
+        let mut fixture = Fixture::new();
+
+        let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+        fixture.pinnacle().focus_output(&output);
+
+        fixture
+            .runtime_handle()
+            .block_on(pinnacle_api::connect())
+            .unwrap();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::set_dynamic_tags(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_dynamic_tags(true)
+            },
+        }
+
+        assert!(fixture.pinnacle().config.dynamic_tags);
+
+        fixture.spawn_blocking(|| {
+            pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+                root_node: MasterStack::default().layout(args.window_count),
+                tree_id: 0,
+            });
+        });
+
+        let client_id = fixture.add_client();
+        let surface = fixture.spawn_windows(1, client_id).remove(0);
+
+        let tags = output.with_state(|state| state.tags.clone());
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name(), "1");
+        assert!(tags[0].dynamic());
+        assert!(tags[0].active());
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| state.tags.contains(&tags[0])));
+        assert!(fixture.pinnacle().space.element_geometry(&window).is_some());
+
+        fixture.client(client_id).close_window(&surface);
+        fixture.roundtrip(client_id);
+        fixture.dispatch_until(|fixture| output.with_state(|state| state.tags.is_empty()));
+
+        assert!(fixture.pinnacle().windows.is_empty());
+    });
+}
+
+#[test_log::test]
+fn output_set_dpms_timeout_powers_off_idle_outputs() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2) = set_up();
+
+        let output2_name = output2.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::set_dpms_timeout(Duration::from_millis(100));
+                pinnacle_api::output::get_by_name(output2_name)
+                    .unwrap()
+                    .set_dpms_timeout(Duration::from_secs(60));
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_dpms_timeout(100)
+                Output.get_by_name($output2_name):set_dpms_timeout(60000)
+            },
+        }
+
+        fixture.dispatch_until(|_| output1.with_state(|state| !state.powered));
+
+        assert!(output1.with_state(|state| state.dpms_powered_off));
+        assert!(output2.with_state(|state| state.powered));
+
+        fixture.state().notify_dpms_activity();
+
+        assert!(output1.with_state(|state| state.powered && !state.dpms_powered_off));
+        assert!(output2.with_state(|state| state.powered));
+    });
+}
+
+#[test_log::test]
+fn output_set_transition_animation_renders_frames_during_scale_change() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::set_transition_animation(true);
+                pinnacle_api::output::get_focused().unwrap().set_scale(2.0);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_transition_animation(true)
+                Output.get_focused():set_scale(2.0)
+            },
+        }
+
+        assert_eq!(output.current_scale().fractional_scale(), 2.0);
+
+        fixture.dispatch_until(|_| {
+            output.with_state(|state| {
+                state
+                    .transition
+                    .as_ref()
+                    .is_some_and(|transition| transition.frames >= 3)
+            })
+        });
+
+        fixture.dispatch_until(|_| output.with_state(|state| state.transition.is_none()));
+    });
+}
+
+#[test_log::test]
+fn render_set_animation_fps_cap_throttles_transition_frames() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::set_transition_animation(true);
+                pinnacle_api::render::set_animation_fps_cap(10);
+                pinnacle_api::output::get_focused().unwrap().set_scale(2.0);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_transition_animation(true)
+                require("pinnacle.render").set_animation_fps_cap(10)
+                Output.get_focused():set_scale(2.0)
+            },
+        }
+
+        assert_eq!(fixture.pinnacle().config.animation_fps_cap, Some(10));
+
+        let mut frames = 0;
+        fixture.dispatch_until(|_| {
+            output.with_state(|state| match state.transition.as_ref() {
+                Some(transition) => {
+                    frames = transition.frames;
+                    false
+                }
+                None => true,
+            })
+        });
+
+        // The 250ms fade gets a frame at most every 100ms, instead of one per dispatch
+        assert!(frames <= 5, "transition rendered {frames} frames");
+    });
+}
+
+#[test_log::test]
+fn render_set_animation_fps_cap_zero_disables_transitions() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::set_transition_animation(true);
+                pinnacle_api::render::set_animation_fps_cap(0);
+                pinnacle_api::output::get_focused().unwrap().set_scale(2.0);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_transition_animation(true)
+                require("pinnacle.render").set_animation_fps_cap(0)
+                Output.get_focused():set_scale(2.0)
+            },
+        }
+
+        assert_eq!(output.current_scale().fractional_scale(), 2.0);
+        assert!(output.with_state(|state| state.transition.is_none()));
+    });
+}
+
+#[test_log::test]
+fn output_handle_tag_gets_tag_on_that_output() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2) = set_up();
+
+        let output1_name = output1.name();
+        let output2_name = output2.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let op1 = pinnacle_api::output::get_by_name(&output1_name).unwrap();
+                let op2 = pinnacle_api::output::get_by_name(&output2_name).unwrap();
+
+                assert_eq!(op1.tag("1").unwrap().output(), op1);
+                assert_eq!(op2.tag("1").unwrap().output(), op2);
+                assert!(op1.tag("2").is_none());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local op1 = Output.get_by_name($output1_name)
+                local op2 = Output.get_by_name($output2_name)
+
+                assert(op1:tag("1"):output().name == op1.name)
+                assert(op2:tag("1"):output().name == op2.name)
+                assert(not op1:tag("2"))
+            },
+        }
+    });
+}
+
+#[test_log::test]
+fn output_set_auto_resolve_overlaps_separates_overlapping_outputs() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2) = set_up();
+
+        let output2_name = output2.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::set_auto_resolve_overlaps(true);
+                pinnacle_api::output::get_by_name(&output2_name)
+                    .unwrap()
+                    .set_loc(1000, 0);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_auto_resolve_overlaps(true)
+                Output.get_by_name($output2_name):set_loc(1000, 0)
+            },
+        }
+
+        // The moved output stays put and the other is pushed out of the way
+        assert_eq!(output2.current_location(), (1000, 0).into());
+        assert_eq!(output1.current_location(), (-920, 0).into());
+
+        let output2_name = output2.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::output::get_by_name(&output2_name)
+                    .unwrap()
+                    .set_loc(2500, 0);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_by_name($output2_name):set_loc(2500, 0)
+            },
+        }
+
+        // Gaps are left alone
+        assert_eq!(output2.current_location(), (2500, 0).into());
+        assert_eq!(output1.current_location(), (-920, 0).into());
+    });
+}
+
+#[test_log::test]
+fn output_handle_set_game_mode_applies_and_restores_settings() {
+    for_each_api(|lang| {
+        let (mut fixture, output, _) = set_up();
+
+        fixture.spawn_blocking(|| {
+            pinnacle_api::render::set_animation_fps_cap(30);
+        });
+
+        assert!(output.with_state(|state| !state.is_vrr_on));
+        assert!(fixture.pinnacle().request_animation_frame(&output));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .set_game_mode(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_focused():set_game_mode(true)
+            },
+        }
+
+        assert!(output.with_state(|state| state.is_vrr_on && state.game_mode.is_some()));
+        assert!(!fixture.pinnacle().request_animation_frame(&output));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::get_focused()
+                    .unwrap()
+                    .set_game_mode(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.get_focused():set_game_mode(false)
+            },
+        }
+
+        assert!(output.with_state(|state| !state.is_vrr_on && state.game_mode.is_none()));
+        assert_eq!(fixture.pinnacle().config.animation_fps_cap, Some(30));
+    });
+}
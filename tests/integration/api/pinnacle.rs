@@ -1,3 +1,27 @@
+use std::{
+    collections::HashMap,
+    io::Read,
+    os::unix::net::UnixStream,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        mpsc,
+    },
+    time::Duration,
+};
+
+use pinnacle::{
+    logind::LogindEvent,
+    protocol::output_management::{OutputConfiguration, OutputManagementHandler},
+    state::WithState,
+    tag::Tag,
+};
+use pinnacle_api::{
+    layout::{LayoutGenerator as _, generators::MasterStack},
+    signal::PinnacleSignal,
+};
+use smithay::utils::Rectangle;
+
 use crate::{
     common::{Lang, fixture::Fixture, for_each_api},
     spawn_lua_blocking,
@@ -67,3 +91,264 @@ fn pinnacle_take_last_error() {
         }
     });
 }
+
+#[test_log::test]
+fn pinnacle_renderer_info_is_stubbed_on_test_backend() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let info = pinnacle_api::pinnacle::renderer_info();
+                assert_eq!(info.renderer, "Dummy");
+                assert_eq!(info.vendor, "Pinnacle");
+                assert_eq!(info.gpu_path, None);
+                assert!(info.dmabuf_formats.is_empty());
+                assert_eq!(info.hardware_accel_error, None);
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    local info = Pinnacle.renderer_info()
+                    assert(info.renderer == "Dummy")
+                    assert(info.vendor == "Pinnacle")
+                    assert(info.gpu_path == nil)
+                    assert(#info.dmabuf_formats == 0)
+                    assert(info.hardware_accel_error == nil)
+                }
+            }
+        }
+    });
+}
+
+#[test_log::test]
+fn pinnacle_renderer_info_reports_hardware_accel_failure() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        fixture
+            .pinnacle()
+            .hardware_accel_failed(&anyhow::anyhow!("no render node"));
+
+        // Pinnacle keeps running and managing outputs without hardware acceleration
+        fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let info = pinnacle_api::pinnacle::renderer_info();
+                assert_eq!(info.hardware_accel_error.as_deref(), Some("no render node"));
+                assert_eq!(pinnacle_api::output::get_all().count(), 1);
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    local info = Pinnacle.renderer_info()
+                    assert(info.hardware_accel_error == "no render node")
+                    assert(#Output.get_all() == 1)
+                }
+            }
+        }
+    });
+}
+
+#[test_log::test]
+fn pinnacle_fall_back_from_primary_gpu_tries_other_gpus() {
+    let mut fixture = set_up();
+
+    let mut tried = Vec::new();
+
+    // The primary gpu 0 couldn't be added and gpu 1 fails, so gpu 2 is used
+    let fallback = pinnacle::backend::udev::fall_back_from_primary_gpu(
+        fixture.pinnacle(),
+        0,
+        vec![1, 2, 3],
+        |pinnacle, gpu| {
+            tried.push(gpu);
+            if gpu == 1 {
+                anyhow::bail!("gpu {gpu} has no render node");
+            }
+            pinnacle.hardware_accel_error = None;
+            Ok(())
+        },
+    );
+
+    assert_eq!(fallback, Some(2));
+    assert_eq!(tried, [1, 2]);
+
+    let info = fixture.spawn_blocking(pinnacle_api::pinnacle::renderer_info);
+    assert_eq!(info.hardware_accel_error, None);
+}
+
+#[test_log::test]
+fn pinnacle_fall_back_from_primary_gpu_stays_degraded_when_every_gpu_fails() {
+    let mut fixture = set_up();
+
+    let fallback = pinnacle::backend::udev::fall_back_from_primary_gpu(
+        fixture.pinnacle(),
+        0,
+        vec![1],
+        |_, gpu| anyhow::bail!("gpu {gpu} has no render node"),
+    );
+
+    assert_eq!(fallback, None);
+
+    // Pinnacle keeps running and managing outputs without hardware acceleration
+    fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+
+    let info = fixture.spawn_blocking(pinnacle_api::pinnacle::renderer_info);
+    assert_eq!(
+        info.hardware_accel_error.as_deref(),
+        Some("primary gpu 0 failed to initialize")
+    );
+    assert_eq!(
+        fixture.spawn_blocking(|| pinnacle_api::output::get_all().count()),
+        1
+    );
+}
+
+#[test_log::test]
+fn pinnacle_metrics_counts_windows() {
+    for_each_api(|lang| {
+        let mut fixture = set_up();
+
+        let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+        output.with_state_mut(|state| {
+            let tag = Tag::new("1".to_string());
+            tag.set_active(true);
+            state.add_tags([tag]);
+        });
+        fixture.pinnacle().focus_output(&output);
+
+        fixture.spawn_blocking(|| {
+            pinnacle_api::layout::manage(|args| pinnacle_api::layout::LayoutResponse {
+                root_node: MasterStack::default().layout(args.window_count),
+                tree_id: 0,
+            });
+        });
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(3, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let metrics = pinnacle_api::pinnacle::metrics();
+                assert_eq!(metrics.windows, 3);
+                assert_eq!(metrics.outputs, 1);
+                // The layout manager holds a stream open
+                assert!(metrics.active_grpc_streams >= 1);
+                assert_eq!(metrics.config_restarts, 0);
+            }),
+            Lang::Lua => {
+                spawn_lua_blocking! {
+                    fixture,
+                    local metrics = Pinnacle.metrics()
+                    assert(metrics.windows == 3)
+                    assert(metrics.outputs == 1)
+                    assert(metrics.active_grpc_streams >= 1)
+                    assert(metrics.config_restarts == 0)
+                }
+            }
+        }
+    });
+}
+
+#[test_log::test]
+fn pinnacle_prepare_for_sleep_releases_inhibitor_after_callbacks() {
+    let mut fixture = set_up();
+
+    // Stand in for logind: it sees the lock released when our end is closed
+    let (inhibitor, mut logind_end) = UnixStream::pair().unwrap();
+    fixture
+        .state()
+        .on_logind_event(LogindEvent::InhibitorTaken(inhibitor.into()));
+
+    let callback_started = Arc::new(AtomicBool::new(false));
+    let callback_started_clone = callback_started.clone();
+    let (finish_callback, callback_can_finish) = mpsc::channel::<()>();
+
+    fixture.spawn_blocking(move || {
+        pinnacle_api::pinnacle::connect_signal(PinnacleSignal::PrepareForSleep(Box::new(
+            move |sleeping| {
+                assert!(sleeping);
+                callback_started_clone.store(true, Ordering::SeqCst);
+                callback_can_finish.recv().unwrap();
+            },
+        )));
+    });
+
+    // Let the signal stream finish connecting
+    fixture.dispatch_for(Duration::from_millis(500));
+
+    fixture
+        .state()
+        .on_logind_event(LogindEvent::PrepareForSleep(true));
+
+    fixture.dispatch_until(|_| callback_started.load(Ordering::SeqCst));
+    fixture.dispatch_for(Duration::from_millis(200));
+    assert!(fixture.pinnacle().logind_state.holds_inhibitor());
+
+    finish_callback.send(()).unwrap();
+
+    fixture.dispatch_until(|fixture| !fixture.pinnacle().logind_state.holds_inhibitor());
+
+    let mut buf = [0u8; 1];
+    assert_eq!(logind_end.read(&mut buf).unwrap(), 0);
+}
+
+#[test_log::test]
+fn pinnacle_output_layout_changed_fires_once_per_batch() {
+    let mut fixture = set_up();
+
+    let output1 = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    let output2 = fixture.add_output(Rectangle::new((1920, 0).into(), (1920, 1080).into()));
+
+    let count = Arc::new(AtomicU32::new(0));
+    let count_clone = count.clone();
+
+    fixture.spawn_blocking(move || {
+        pinnacle_api::pinnacle::connect_signal(PinnacleSignal::OutputLayoutChanged(Box::new(
+            move || {
+                count_clone.fetch_add(1, Ordering::SeqCst);
+            },
+        )));
+    });
+
+    // Let the signal stream finish connecting
+    fixture.dispatch_for(Duration::from_millis(500));
+    count.store(0, Ordering::SeqCst);
+
+    let enabled_at = |x: i32| OutputConfiguration::Enabled {
+        mode: None,
+        position: Some((x, 0).into()),
+        transform: None,
+        scale: Some(2.0),
+        adaptive_sync: None,
+    };
+
+    fixture.state().apply_configuration(HashMap::from([
+        (output1.clone(), enabled_at(1920)),
+        (output2.clone(), enabled_at(0)),
+    ]));
+
+    fixture.dispatch_for(Duration::from_millis(500));
+
+    assert_eq!(output1.current_location(), (1920, 0).into());
+    assert_eq!(output2.current_location(), (0, 0).into());
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    // Nothing changed, so nothing fires
+    fixture.state().apply_configuration(HashMap::from([(
+        output1.clone(),
+        OutputConfiguration::Enabled {
+            mode: None,
+            position: None,
+            transform: None,
+            scale: None,
+            adaptive_sync: None,
+        },
+    )]));
+
+    fixture.dispatch_for(Duration::from_millis(500));
+
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
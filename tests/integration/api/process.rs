@@ -1,6 +1,7 @@
 use std::{
     fs::File,
     io::{Read, Write},
+    sync::{Arc, Mutex},
     time::Duration,
 };
 
@@ -337,3 +338,177 @@ fn process_set_env() {
         );
     });
 }
+
+#[test_log::test]
+fn process_on_exit_fires_with_exit_code() {
+    let (mut fixture, ..) = set_up();
+    let handle = fixture.runtime_handle();
+    let _guard = handle.enter();
+
+    let exit_code = Arc::new(Mutex::new(None));
+
+    let exit_code_clone = exit_code.clone();
+    fixture.spawn_blocking(move || {
+        let child = pinnacle_api::process::Command::new("sh")
+            .args(["-c", "exit 3"])
+            .spawn()
+            .unwrap();
+
+        pinnacle_api::process::on_exit(child.pid(), move |exit_info| {
+            *exit_code_clone.lock().unwrap() = Some(exit_info.exit_code);
+        });
+    });
+
+    fixture.dispatch_until(|_| exit_code.lock().unwrap().is_some());
+
+    assert_eq!(*exit_code.lock().unwrap(), Some(Some(3)));
+}
+
+#[test_log::test]
+fn process_exited_children_are_kept_until_waited_on() {
+    let (mut fixture, ..) = set_up();
+    let handle = fixture.runtime_handle();
+    let _guard = handle.enter();
+
+    let exited = fixture.spawn_blocking(|| {
+        pinnacle_api::process::Command::new("sh")
+            .args(["-c", "exit 5"])
+            .spawn()
+            .unwrap()
+            .pid()
+    });
+
+    fixture.dispatch_until(|fixture| {
+        fixture
+            .pinnacle()
+            .process_state
+            .children()
+            .any(|(pid, alive)| pid == exited && !alive)
+    });
+
+    // Spawning another process keeps the exited one's exit info around
+    let sleeping = fixture.spawn_blocking(|| {
+        pinnacle_api::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap()
+            .pid()
+    });
+
+    assert!(
+        fixture
+            .pinnacle()
+            .process_state
+            .children()
+            .any(|(pid, _)| pid == exited)
+    );
+
+    let exit_code = Arc::new(Mutex::new(None));
+
+    let exit_code_clone = exit_code.clone();
+    fixture.spawn_blocking(move || {
+        pinnacle_api::process::on_exit(exited, move |exit_info| {
+            *exit_code_clone.lock().unwrap() = Some(exit_info.exit_code);
+        });
+    });
+
+    fixture.dispatch_until(|_| exit_code.lock().unwrap().is_some());
+    assert_eq!(*exit_code.lock().unwrap(), Some(Some(5)));
+
+    fixture.dispatch_until(|fixture| {
+        fixture
+            .pinnacle()
+            .process_state
+            .children()
+            .all(|(pid, _)| pid != exited)
+    });
+    assert!(
+        fixture
+            .pinnacle()
+            .process_state
+            .children()
+            .any(|(pid, _)| pid == sleeping)
+    );
+
+    let _ = std::process::Command::new("kill")
+        .arg(sleeping.to_string())
+        .status();
+}
+
+fn is_zombie(pid: u32) -> bool {
+    std::fs::read_to_string(format!("/proc/{pid}/stat")).is_ok_and(|stat| {
+        // The state comes after the parenthesized command name
+        stat.rsplit_once(')')
+            .is_some_and(|(_, rest)| rest.trim_start().starts_with('Z'))
+    })
+}
+
+#[test_log::test]
+fn process_running_children_and_reap() {
+    let (mut fixture, ..) = set_up();
+    let handle = fixture.runtime_handle();
+    let _guard = handle.enter();
+
+    let (exiting, sleeping) = fixture.spawn_blocking(|| {
+        let exiting = (0..3)
+            .map(|_| {
+                pinnacle_api::process::Command::new("sh")
+                    .args(["-c", "exit 0"])
+                    .spawn()
+                    .unwrap()
+                    .pid()
+            })
+            .collect::<Vec<_>>();
+
+        let sleeping = pinnacle_api::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap()
+            .pid();
+
+        (exiting, sleeping)
+    });
+
+    fixture.dispatch_until(|fixture| {
+        fixture
+            .pinnacle()
+            .process_state
+            .children()
+            .filter(|(_, alive)| !alive)
+            .count()
+            == exiting.len()
+    });
+
+    let children = fixture.spawn_blocking(pinnacle_api::process::running_children);
+
+    for pid in exiting.iter() {
+        assert!(children.contains(&pinnacle_api::process::ChildStatus {
+            pid: *pid,
+            alive: false
+        }));
+        assert!(!is_zombie(*pid));
+    }
+    assert!(children.contains(&pinnacle_api::process::ChildStatus {
+        pid: sleeping,
+        alive: true
+    }));
+
+    let mut reaped = fixture.spawn_blocking(pinnacle_api::process::reap);
+    reaped.sort();
+    let mut expected = exiting.clone();
+    expected.sort();
+    assert_eq!(reaped, expected);
+
+    let children = fixture.spawn_blocking(pinnacle_api::process::running_children);
+    assert_eq!(
+        children,
+        [pinnacle_api::process::ChildStatus {
+            pid: sleeping,
+            alive: true
+        }]
+    );
+
+    let _ = std::process::Command::new("kill")
+        .arg(sleeping.to_string())
+        .status();
+}
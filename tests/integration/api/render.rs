@@ -0,0 +1,433 @@
+use pinnacle::{
+    focus::keyboard::KeyboardFocusTarget,
+    state::{Pinnacle, WithState},
+    tag::Tag,
+    window::WindowElement,
+};
+use smithay::{
+    backend::renderer::element::{Element, Id},
+    input::keyboard::Keysym,
+    output::Output,
+    reexports::wayland_server::protocol::wl_surface::WlSurface,
+    utils::{Point, Rectangle},
+};
+
+use crate::{
+    common::{Lang, client::ClientId, fixture::Fixture, for_each_api, gles_renderer},
+    spawn_lua_blocking,
+};
+
+/// Sets up a fixture with a single 500x400 floating window.
+fn set_up() -> (Fixture, ClientId, WlSurface) {
+    let mut fixture = Fixture::new();
+
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag]);
+    });
+    fixture.pinnacle().focus_output(&output);
+
+    fixture
+        .runtime_handle()
+        .block_on(pinnacle_api::connect())
+        .unwrap();
+
+    let client_id = fixture.add_client();
+    let surface = fixture.spawn_floating_window_with(client_id, (500, 400), |_| ());
+
+    (fixture, client_id, surface)
+}
+
+#[test_log::test]
+fn render_screenshot_window_rgba() {
+    for_each_api(|lang| {
+        let (mut fixture, ..) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let window = pinnacle_api::window::get_all().next().unwrap();
+                let screenshot = pinnacle_api::render::screenshot_window_rgba(&window).unwrap();
+                assert_eq!(screenshot.width, 500);
+                assert_eq!(screenshot.height, 400);
+                assert_eq!(screenshot.rgba.len(), 500 * 400 * 4);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window = Window.get_all()[1]
+                local screenshot = require("pinnacle.render").screenshot_window_rgba(window)
+                assert(screenshot)
+                assert(screenshot.width == 500)
+                assert(screenshot.height == 400)
+                assert(#screenshot.rgba == 500 * 400 * 4)
+            },
+        }
+    });
+}
+
+#[test_log::test]
+fn render_screenshot_window_saves_png() {
+    for_each_api(|lang| {
+        let (mut fixture, ..) = set_up();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("window.png");
+        let path_str = path.to_string_lossy().into_owned();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let window = pinnacle_api::window::get_all().next().unwrap();
+                pinnacle_api::render::screenshot_window(&window, path_str).unwrap();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window = Window.get_all()[1]
+                assert(require("pinnacle.render").screenshot_window(window, $path_str))
+            },
+        }
+
+        let decoder = png::Decoder::new(std::fs::File::open(&path).unwrap());
+        let reader = decoder.read_info().unwrap();
+        assert_eq!(reader.info().width, 500);
+        assert_eq!(reader.info().height, 400);
+    });
+}
+
+#[test_log::test]
+fn render_screenshot_window_renders_with_gles() {
+    let (mut fixture, ..) = set_up();
+
+    let window = fixture.pinnacle().windows[0].clone();
+    let mut renderer = gles_renderer();
+
+    let screenshot = window.screenshot(&mut renderer, 1.0.into()).unwrap();
+    assert_eq!(screenshot.size, (500, 400).into());
+
+    // The test client's windows are opaque black
+    assert!(
+        screenshot
+            .rgba
+            .chunks_exact(4)
+            .all(|pixel| pixel == [0, 0, 0, 255])
+    );
+
+    let screenshot = window.screenshot(&mut renderer, 2.0.into()).unwrap();
+    assert_eq!(screenshot.size, (1000, 800).into());
+}
+
+#[test_log::test]
+fn render_output_thumbnail_fails_on_dummy_backend() {
+    for_each_api(|lang| {
+        let (mut fixture, ..) = set_up();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let output = pinnacle_api::output::get_focused().unwrap();
+                assert!(pinnacle_api::render::output_thumbnail(&output, 320, 320).is_err());
+                assert!(output.snapshot(320, 320).is_err());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local output = Output.get_focused()
+                assert(require("pinnacle.render").output_thumbnail(output, 320, 320) == nil)
+                assert(output:snapshot(320, 320) == nil)
+            },
+        }
+    });
+}
+
+#[test_log::test]
+fn render_output_thumbnail_fits_within_max_size() {
+    use pinnacle::render::util::screenshot::thumbnail_size;
+
+    let size = thumbnail_size((1920, 1080).into(), (200, 200).into());
+    assert!(size.w <= 200 && size.h <= 200);
+    assert_eq!(size, (200, 112).into());
+
+    let size = thumbnail_size((1920, 1080).into(), (320, 90).into());
+    assert_eq!(size, (160, 90).into());
+}
+
+#[test_log::test]
+fn render_screenshot_window_fails_for_closed_window() {
+    let (mut fixture, client_id, surface) = set_up();
+
+    let window = fixture.spawn_blocking(|| pinnacle_api::window::get_all().next().unwrap());
+
+    fixture.client(client_id).close_window(&surface);
+    fixture.roundtrip(client_id);
+    assert_eq!(fixture.pinnacle().windows.len(), 0);
+
+    fixture.spawn_blocking(move || {
+        assert!(pinnacle_api::render::screenshot_window_rgba(&window).is_err());
+    });
+}
+
+#[test_log::test]
+fn render_render_state_reports_last_result() {
+    for_each_api(|lang| {
+        let (mut fixture, ..) = set_up();
+
+        let output = fixture.pinnacle().focused_output().cloned().unwrap();
+        fixture.state().schedule_render(&output);
+        fixture.dispatch();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let output = pinnacle_api::output::get_focused().unwrap();
+                let state = pinnacle_api::render::render_state(&output).unwrap();
+                assert!(matches!(
+                    state.last_result,
+                    Some(
+                        pinnacle_api::render::RenderResult::Submitted
+                            | pinnacle_api::render::RenderResult::NoDamage
+                    )
+                ));
+                assert!(!state.render_scheduled);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local state = require("pinnacle.render").render_state(Output.get_focused())
+                assert(state)
+                assert(state.last_result == "submitted" or state.last_result == "no_damage")
+                assert(not state.render_scheduled)
+            },
+        }
+    });
+}
+
+/// Adds two more windows to the fixture, moving the last one to an inactive tag.
+fn set_up_overview() -> (Fixture, Tag) {
+    let (mut fixture, client_id, _) = set_up();
+
+    fixture.spawn_floating_window_with(client_id, (300, 300), |_| ());
+    fixture.spawn_floating_window_with(client_id, (300, 300), |_| ());
+
+    let output = fixture.pinnacle().focused_output().cloned().unwrap();
+    let tag = Tag::new("2".to_string());
+    output.with_state_mut(|state| state.add_tags([tag.clone()]));
+
+    let window = fixture.pinnacle().windows[2].clone();
+    window.with_state_mut(|state| state.tags = [tag.clone()].into_iter().collect());
+    fixture.pinnacle().request_layout(&output);
+    fixture.dispatch();
+
+    (fixture, tag)
+}
+
+fn focused_window(fixture: &mut Fixture) -> Option<KeyboardFocusTarget> {
+    fixture.dispatch();
+    fixture
+        .pinnacle()
+        .seat
+        .get_keyboard()
+        .unwrap()
+        .current_focus()
+}
+
+#[test_log::test]
+fn render_set_overview_shows_windows_on_all_tags() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up_overview();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::render::set_overview(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.render").set_overview(true)
+            },
+        }
+
+        let windows = fixture.pinnacle().windows.clone();
+        let overview = fixture.pinnacle().overview.as_ref().unwrap();
+        assert_eq!(overview.windows, windows);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::render::toggle_overview();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.render").toggle_overview()
+            },
+        }
+
+        assert!(fixture.pinnacle().overview.is_none());
+    });
+}
+
+#[test_log::test]
+fn render_overview_keyboard_selection_focuses_window_and_switches_tag() {
+    let (mut fixture, tag) = set_up_overview();
+
+    fixture.spawn_blocking(pinnacle_api::render::toggle_overview);
+
+    // The grid is 2x2, so the third window is below the first
+    fixture.pinnacle().overview.as_mut().unwrap().selected = 0;
+    fixture.state().overview_key(Keysym::Down);
+    assert_eq!(fixture.pinnacle().overview.as_ref().unwrap().selected, 2);
+
+    fixture.state().overview_key(Keysym::Return);
+    assert!(fixture.pinnacle().overview.is_none());
+
+    let window = fixture.pinnacle().windows[2].clone();
+    assert_eq!(
+        focused_window(&mut fixture),
+        Some(KeyboardFocusTarget::Window(window))
+    );
+    assert!(tag.active());
+}
+
+#[test_log::test]
+fn render_overview_click_focuses_window() {
+    let (mut fixture, _) = set_up_overview();
+
+    fixture.spawn_blocking(pinnacle_api::render::toggle_overview);
+
+    let cell = fixture.pinnacle().overview.as_ref().unwrap().cells()[1];
+    let center = cell.loc + Point::from((cell.size.w / 2, cell.size.h / 2));
+    fixture.state().overview_click(center.to_f64());
+    assert!(fixture.pinnacle().overview.is_none());
+
+    let window = fixture.pinnacle().windows[1].clone();
+    assert_eq!(
+        focused_window(&mut fixture),
+        Some(KeyboardFocusTarget::Window(window))
+    );
+}
+
+#[test_log::test]
+fn render_overview_escape_keeps_focus() {
+    let (mut fixture, tag) = set_up_overview();
+
+    let focused = focused_window(&mut fixture);
+
+    fixture.spawn_blocking(pinnacle_api::render::toggle_overview);
+
+    fixture.state().overview_key(Keysym::Right);
+    fixture.state().overview_key(Keysym::Escape);
+    assert!(fixture.pinnacle().overview.is_none());
+
+    assert_eq!(focused_window(&mut fixture), focused);
+    assert!(!tag.active());
+}
+
+#[test_log::test]
+fn render_occlusion_culling_hides_windows_behind_fullscreen_window() {
+    for_each_api(|lang| {
+        let (mut fixture, client_id, _) = set_up();
+
+        let output = fixture.pinnacle().focused_output().cloned().unwrap();
+        fixture.spawn_floating_window_with(client_id, (1920, 1080), |_| ());
+        let occluded = fixture.pinnacle().windows[0].clone();
+        let fullscreen = fixture.pinnacle().windows[1].clone();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::set_occlusion_culling(true);
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_fullscreen(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_occlusion_culling(true)
+                Window.get_focused():set_fullscreen(true)
+            },
+        }
+
+        fixture.wait_client_configure(client_id);
+        fixture.client(client_id).ack_all_window();
+        fixture.roundtrip(client_id);
+        fixture.flush();
+
+        // The floating window behind the fullscreen one is culled
+        assert_eq!(
+            fixture.pinnacle().occluding_window(&output),
+            Some(fullscreen.clone())
+        );
+
+        let element_ids = output_element_ids(fixture.pinnacle(), &output);
+        let surface_id = |window: &WindowElement| {
+            Id::from_wayland_resource(&window.wl_surface().unwrap().into_owned())
+        };
+        assert!(element_ids.contains(&surface_id(&fullscreen)));
+        assert!(!element_ids.contains(&surface_id(&occluded)));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::output::set_occlusion_culling(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Output.set_occlusion_culling(false)
+            },
+        }
+
+        assert_eq!(fixture.pinnacle().occluding_window(&output), None);
+
+        let element_ids = output_element_ids(fixture.pinnacle(), &output);
+        assert!(element_ids.contains(&surface_id(&occluded)));
+    });
+}
+
+/// Gets the ids of the elements that would be rendered on `output`.
+fn output_element_ids(pinnacle: &Pinnacle, output: &Output) -> Vec<Id> {
+    let mut renderer = gles_renderer();
+    pinnacle::render::output_render_elements(
+        output,
+        &mut renderer,
+        &pinnacle.space,
+        &pinnacle.z_index_stack,
+        pinnacle.overview.as_ref(),
+        pinnacle.occluding_window(output).as_ref(),
+    )
+    .iter()
+    .map(|element| element.id().clone())
+    .collect()
+}
+
+#[test_log::test]
+fn render_set_cursor_mode_toggles_cursor_compositing() {
+    for_each_api(|lang| {
+        let (mut fixture, _, _) = set_up();
+
+        assert_eq!(
+            fixture.pinnacle().config.cursor_mode,
+            pinnacle::cursor::CursorMode::Plane
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::render::set_cursor_mode(pinnacle_api::render::CursorMode::Software);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.render").set_cursor_mode("software")
+            },
+        }
+
+        assert_eq!(
+            fixture.pinnacle().config.cursor_mode,
+            pinnacle::cursor::CursorMode::Software
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::render::set_cursor_mode(pinnacle_api::render::CursorMode::Plane);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.render").set_cursor_mode("plane")
+            },
+        }
+
+        assert_eq!(
+            fixture.pinnacle().config.cursor_mode,
+            pinnacle::cursor::CursorMode::Plane
+        );
+    });
+}
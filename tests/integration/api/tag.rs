@@ -1,5 +1,11 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
 use pinnacle::{state::WithState, tag::Tag};
-use pinnacle_api::layout::LayoutNode;
+use pinnacle_api::{layout::LayoutNode, signal::TagSignal};
 use smithay::{output::Output, utils::Rectangle};
 
 use crate::{
@@ -394,4 +400,193 @@ fn tag_get_does_not_return_tags_cleared_after_config_reload() {
     });
 }
 
-// TODO: tag connect_signal
+#[test_log::test]
+fn tag_handle_move_to_output() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2, tags1, tags2) = set_up();
+
+        let id = fixture.add_client();
+        fixture.spawn_floating_window_with(id, (500, 500), |_| ());
+        fixture.spawn_floating_window_with(id, (500, 500), |_| ());
+
+        let windows = fixture.pinnacle().windows.clone();
+        assert_eq!(windows.len(), 2);
+
+        // The second window also has a tag that stays on the old output
+        windows[1].with_state_mut(|state| {
+            state.tags.insert(tags1[1].clone());
+        });
+
+        let output2_name = output2.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let output = pinnacle_api::output::get_by_name(output2_name).unwrap();
+                pinnacle_api::tag::get("1").unwrap().move_to_output(&output);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Tag.get("1"):move_to_output(Output.get_by_name($output2_name))
+            },
+        }
+
+        assert_eq!(tags1[0].output(fixture.pinnacle()), Some(output2.clone()));
+        output1.with_state(|state| assert!(!state.tags.contains(&tags1[0])));
+        output2.with_state(|state| {
+            assert_eq!(state.tags.len(), tags2.len() + 1);
+        });
+
+        for window in windows.iter() {
+            assert_eq!(window.output(fixture.pinnacle()), Some(output2.clone()));
+            window.with_state(|state| {
+                assert_eq!(state.tags.iter().collect::<Vec<_>>(), vec![&tags1[0]]);
+                assert!(state.floating_loc().unwrap().x >= 1920);
+            });
+        }
+    });
+}
+
+#[test_log::test]
+fn tag_handle_set_output_relocates_tag_from_disconnected_output() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2, tags1, tags2) = set_up();
+
+        let id = fixture.add_client();
+        fixture.spawn_floating_window_with(id, (500, 500), |_| ());
+
+        let window = fixture.pinnacle().windows[0].clone();
+
+        let tag_id = tags1[0].id().to_inner();
+
+        fixture.pinnacle().remove_output(&output1);
+        assert_eq!(tags1[0].output(fixture.pinnacle()), None);
+
+        let output2_name = output2.name();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let output = pinnacle_api::output::get_by_name(output2_name).unwrap();
+                pinnacle_api::tag::TagHandle::from_id(tag_id).set_output(&output);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.tag").handle.new($tag_id):set_output(Output.get_by_name($output2_name))
+            },
+        }
+
+        fixture.dispatch();
+
+        assert_eq!(tags1[0].output(fixture.pinnacle()), Some(output2.clone()));
+        output2.with_state(|state| {
+            assert_eq!(state.tags.len(), tags2.len() + 1);
+        });
+
+        // The tag no longer comes back with the old output
+        assert!(
+            fixture
+                .pinnacle()
+                .config
+                .connector_saved_states
+                .values()
+                .all(|saved_state| !saved_state.tags.contains(&tags1[0]))
+        );
+
+        assert_eq!(window.output(fixture.pinnacle()), Some(output2.clone()));
+        assert!(
+            fixture
+                .pinnacle()
+                .space
+                .outputs_for_element(&window)
+                .contains(&output2)
+        );
+    });
+}
+
+#[test_log::test]
+fn tag_swap() {
+    for_each_api(|lang| {
+        let (mut fixture, output1, output2, tags1, tags2) = set_up();
+
+        let id = fixture.add_client();
+        fixture.spawn_floating_window_with(id, (500, 500), |_| ());
+        fixture.spawn_floating_window_with(id, (500, 500), |_| ());
+
+        let windows = fixture.pinnacle().windows.clone();
+        assert_eq!(windows.len(), 2);
+
+        windows[1].with_state_mut(|state| {
+            state.tags = [tags2[0].clone()].into_iter().collect();
+        });
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let tag1 = pinnacle_api::tag::get("1").unwrap();
+                let tag4 = pinnacle_api::tag::get("4").unwrap();
+                pinnacle_api::tag::swap(&tag1, &tag4);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Tag.swap(Tag.get("1"), Tag.get("4"))
+            },
+        }
+
+        assert_eq!(windows[0].output(fixture.pinnacle()), Some(output2.clone()));
+        windows[0].with_state(|state| {
+            assert_eq!(state.tags.iter().collect::<Vec<_>>(), vec![&tags2[0]]);
+            assert!(state.floating_loc().unwrap().x >= 1920);
+        });
+
+        assert_eq!(windows[1].output(fixture.pinnacle()), Some(output1.clone()));
+        windows[1].with_state(|state| {
+            assert_eq!(state.tags.iter().collect::<Vec<_>>(), vec![&tags1[0]]);
+        });
+    });
+}
+
+#[test_log::test]
+fn tag_window_count_changed_signal() {
+    let (mut fixture, _, _, tags1, _) = set_up();
+
+    let counts = Arc::new(Mutex::new(HashMap::<u32, u32>::new()));
+    let counts_clone = counts.clone();
+
+    fixture.spawn_blocking(move || {
+        pinnacle_api::tag::connect_signal(TagSignal::WindowCountChanged(Box::new(
+            move |tag, count| {
+                counts_clone.lock().unwrap().insert(tag.id(), count);
+            },
+        )));
+    });
+
+    // Let the signal stream finish connecting
+    fixture.dispatch_for(Duration::from_millis(500));
+
+    let tag1_id = tags1[0].id().to_inner();
+    let tag2_id = tags1[1].id().to_inner();
+
+    let client_id = fixture.add_client();
+    let surfaces = fixture.spawn_windows(2, client_id);
+    fixture.dispatch_for(Duration::from_millis(200));
+
+    assert_eq!(counts.lock().unwrap().get(&tag1_id), Some(&2));
+    assert_eq!(counts.lock().unwrap().get(&tag2_id), None);
+
+    // Windows on multiple tags count towards each of them
+    let windows = fixture.pinnacle().windows.clone();
+    windows[0].with_state_mut(|state| {
+        state.tags.insert(tags1[1].clone());
+    });
+    fixture.dispatch_for(Duration::from_millis(200));
+
+    assert_eq!(counts.lock().unwrap().get(&tag1_id), Some(&2));
+    assert_eq!(counts.lock().unwrap().get(&tag2_id), Some(&1));
+
+    for surface in surfaces.iter() {
+        fixture.client(client_id).close_window(surface);
+    }
+    fixture.roundtrip(client_id);
+    fixture.dispatch_for(Duration::from_millis(200));
+
+    assert_eq!(counts.lock().unwrap().get(&tag1_id), Some(&0));
+    assert_eq!(counts.lock().unwrap().get(&tag2_id), Some(&0));
+}
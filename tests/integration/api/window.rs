@@ -1,3 +1,9 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, atomic::Ordering},
+    time::Duration,
+};
+
 use crate::{
     common::{Lang, fixture::Fixture, for_each_api},
     spawn_lua_blocking,
@@ -6,11 +12,15 @@ use pinnacle::{focus::keyboard::KeyboardFocusTarget, state::WithState, tag::Tag}
 use pinnacle_api::{
     layout::{LayoutGenerator as _, generators::MasterStack},
     output::OutputHandle,
+    signal::WindowSignal,
 };
 use smithay::{
     output::Output,
-    reexports::wayland_protocols::xdg::decoration::zv1::server::zxdg_toplevel_decoration_v1,
-    utils::Rectangle,
+    reexports::wayland_protocols::xdg::decoration::zv1::{
+        client::zxdg_toplevel_decoration_v1::Mode as ClientDecorationMode,
+        server::zxdg_toplevel_decoration_v1,
+    },
+    utils::{Logical, Point, Rectangle},
 };
 
 fn set_up() -> (Fixture, Output) {
@@ -66,6 +76,87 @@ fn window_get_all() {
     }
 }
 
+#[test_log::test]
+fn window_get_all_with_props() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+
+    fixture.spawn_window_with(client_id, |win| {
+        win.set_app_id("alpha");
+        win.set_title("first");
+    });
+    fixture.spawn_window_with(client_id, |win| win.set_app_id("beta"));
+    fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
+
+    fixture.spawn_blocking(|| {
+        let props = pinnacle_api::window::get_all_with_props();
+        assert_eq!(props.len(), 3);
+
+        for props in props {
+            assert_eq!(props.title, props.window.title());
+            assert_eq!(props.app_id, props.window.app_id());
+            assert_eq!(props.tags, props.window.tags().collect::<Vec<_>>());
+            assert_eq!(props.loc, props.window.loc());
+            assert_eq!(props.size, props.window.size());
+            assert_eq!(props.layout_mode, props.window.layout_mode());
+        }
+    });
+
+    spawn_lua_blocking! {
+        fixture,
+        local all_props = Window.get_all_with_props()
+        assert(#all_props == 3)
+
+        for _, props in ipairs(all_props) do
+            assert(props.title == props.window:title())
+            assert(props.app_id == props.window:app_id())
+            assert(#props.tags == #props.window:tags())
+            assert(props.loc.x == props.window:loc().x)
+            assert(props.loc.y == props.window:loc().y)
+            assert(props.size.width == props.window:size().width)
+            assert(props.size.height == props.window:size().height)
+            assert((props.layout_mode == "floating") == props.window:floating())
+        end
+    }
+}
+
+#[test_log::test]
+fn window_get_all_on_output() {
+    let (mut fixture, output) = set_up();
+
+    let client_id = fixture.add_client();
+    fixture.spawn_windows(2, client_id);
+
+    let output2 = fixture.add_output(Rectangle::new((1920, 0).into(), (1920, 1080).into()));
+    output2.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag]);
+    });
+    fixture.pinnacle().focus_output(&output2);
+    fixture.spawn_windows(1, client_id);
+
+    let output_name = output.name();
+    let output2_name = output2.name();
+
+    fixture.spawn_blocking(move || {
+        let output = OutputHandle::from_name(output_name);
+        let output2 = OutputHandle::from_name(output2_name);
+        assert_eq!(pinnacle_api::window::get_all_on_output(&output).count(), 2);
+        assert_eq!(pinnacle_api::window::get_all_on_output(&output2).count(), 1);
+    });
+
+    let output_name = output.name();
+    let output2_name = output2.name();
+
+    spawn_lua_blocking! {
+        fixture,
+        assert(#Window.get_all_on_output(Output.handle.new($output_name)) == 2)
+        assert(#Window.get_all_on_output(Output.handle.new($output2_name)) == 1)
+    }
+}
+
 #[test_log::test]
 fn window_get_focused() {
     let (mut fixture, _) = set_up();
@@ -125,48 +216,52 @@ fn window_handle_close() {
 }
 
 #[test_log::test]
-fn window_handle_set_geometry_floating() {
+fn window_handle_close_with_minimize_close_behavior_minimizes() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        let _surface = fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
+        let surface = fixture.spawn_windows(1, client_id).remove(0);
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
-                    .unwrap()
-                    .set_geometry(200, 300, 1000, 1000);
+                let window = pinnacle_api::window::get_focused().unwrap();
+                window.set_close_behavior(pinnacle_api::window::CloseBehavior::Minimize);
+                window.close();
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_geometry({ x = 200, y = 300, width = 1000, height = 1000 })
+                local window = Window.get_focused()
+                window:set_close_behavior("minimize")
+                window:close()
             },
         }
+        fixture.roundtrip(client_id);
 
-        fixture.wait_client_configure(client_id);
-        fixture.flush();
+        let window = fixture.client(client_id).window_for_surface(&surface);
+        assert!(!window.close_requested);
 
+        assert_eq!(fixture.pinnacle().windows.len(), 1);
         let window = fixture.pinnacle().windows[0].clone();
-        let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+        assert!(window.with_state(|state| state.minimized));
 
-        assert_eq!(geo, Rectangle::new((200, 300).into(), (1000, 1000).into()));
+        // The app quitting on its own still removes the window
+        fixture.client(client_id).close_window(&surface);
+        fixture.roundtrip(client_id);
+
+        assert_eq!(fixture.pinnacle().windows.len(), 0);
     });
 }
 
 #[test_log::test]
-fn window_handle_set_geometry_tiled_does_not_change_geometry() {
+fn window_handle_set_geometry_floating() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        let _surface = fixture.spawn_windows(1, client_id).remove(0);
-
-        let window = fixture.pinnacle().windows[0].clone();
-
-        let old_geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+        let _surface = fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
@@ -180,262 +275,348 @@ fn window_handle_set_geometry_tiled_does_not_change_geometry() {
             },
         }
 
+        fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        let new_geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+        let window = fixture.pinnacle().windows[0].clone();
+        let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
 
-        assert_eq!(new_geo, old_geo);
+        assert_eq!(geo, Rectangle::new((200, 300).into(), (1000, 1000).into()));
     });
 }
 
 #[test_log::test]
-fn window_handle_set_fullscreen() {
+fn window_handle_float_with_geometry_floats_tiled_window_with_geometry() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        let surface = fixture.spawn_windows(1, client_id).remove(0);
+        let _surface = fixture.spawn_windows(1, client_id).remove(0);
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| state.layout_mode.is_tiled()));
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .set_fullscreen(true);
+                    .float_with_geometry(200, 300, 1000, 800);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_fullscreen(true)
+                Window.get_focused():float_with_geometry({ x = 200, y = 300, width = 1000, height = 800 })
             },
         }
 
         fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        assert!(
-            fixture
-                .client(client_id)
-                .window_for_surface(&surface)
-                .fullscreen
-        );
-
-        let window = fixture.pinnacle().windows[0].clone();
-        assert!(window.with_state(|state| state.layout_mode.is_fullscreen()));
+        assert!(window.with_state(|state| state.layout_mode.is_floating()));
+        let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+        assert_eq!(geo, Rectangle::new((200, 300).into(), (1000, 800).into()));
 
+        // Already floating windows are just moved and resized
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .set_fullscreen(false);
+                    .float_with_geometry(100, 100, 600, 400);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_fullscreen(false)
+                Window.get_focused():float_with_geometry({ x = 100, y = 100, width = 600, height = 400 })
             },
         }
 
         fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        assert!(
-            !fixture
-                .client(client_id)
-                .window_for_surface(&surface)
-                .fullscreen
+        assert!(window.with_state(|state| state.layout_mode.is_floating()));
+        let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+        assert_eq!(geo, Rectangle::new((100, 100).into(), (600, 400).into()));
+    });
+}
+
+/// Sets the offscreen policy, then tries to move a 500x500 floating window far off the top-left
+/// of the output and returns where it ended up.
+fn move_window_offscreen(
+    fixture: &mut Fixture,
+    lang: Lang,
+    policy: pinnacle_api::window::OffscreenPolicy,
+    lua_policy: &str,
+) -> Point<i32, Logical> {
+    let client_id = fixture.add_client();
+
+    let _surface = fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
+
+    match lang {
+        Lang::Rust => fixture.spawn_blocking(move || {
+            pinnacle_api::window::set_offscreen_policy(policy);
+            pinnacle_api::window::get_focused()
+                .unwrap()
+                .set_geometry(-5000, -5000, None, None);
+        }),
+        Lang::Lua => {
+            let lua_policy = lua_policy.to_string();
+            spawn_lua_blocking! {
+                fixture,
+                Window.set_offscreen_policy($lua_policy, 32)
+                Window.get_focused():set_geometry({ x = -5000, y = -5000 })
+            }
+        }
+    }
+
+    fixture.wait_client_configure(client_id);
+    fixture.flush();
+
+    let window = fixture.pinnacle().windows[0].clone();
+    fixture.pinnacle().space.element_location(&window).unwrap()
+}
+
+#[test_log::test]
+fn window_set_offscreen_policy_allow_lets_windows_go_offscreen() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let loc = move_window_offscreen(
+            &mut fixture,
+            lang,
+            pinnacle_api::window::OffscreenPolicy::Allow,
+            "allow",
         );
 
-        let window = fixture.pinnacle().windows[0].clone();
-        assert!(window.with_state(|state| !state.layout_mode.is_fullscreen()));
+        assert_eq!(loc, Point::from((-5000, -5000)));
     });
 }
 
 #[test_log::test]
-fn window_handle_toggle_fullscreen() {
+fn window_set_offscreen_policy_keep_visible_keeps_part_of_windows_onscreen() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
-        let client_id = fixture.add_client();
+        let loc = move_window_offscreen(
+            &mut fixture,
+            lang,
+            pinnacle_api::window::OffscreenPolicy::KeepVisible(32),
+            "keep_visible",
+        );
 
-        let surface = fixture.spawn_windows(1, client_id).remove(0);
+        assert_eq!(loc, Point::from((32 - 500, 32 - 500)));
+    });
+}
+
+#[test_log::test]
+fn window_set_offscreen_policy_clamp_keeps_windows_onscreen() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let loc = move_window_offscreen(
+            &mut fixture,
+            lang,
+            pinnacle_api::window::OffscreenPolicy::Clamp,
+            "clamp",
+        );
+
+        assert_eq!(loc, Point::from((0, 0)));
+    });
+}
+
+#[test_log::test]
+fn window_set_background_fps_cap_throttles_occluded_windows() {
+    for_each_api(|lang| {
+        let (mut fixture, output) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_floating_window_with(client_id, (500, 400), |_| ());
+        fixture.spawn_floating_window_with(client_id, (1920, 1080), |_| ());
+        let occluded = fixture.pinnacle().windows[0].clone();
+        let visible = fixture.pinnacle().windows[1].clone();
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::set_background_fps_cap(10);
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .toggle_fullscreen();
+                    .set_fullscreen(true);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():toggle_fullscreen()
+                Window.set_background_fps_cap(10)
+                Window.get_focused():set_fullscreen(true)
             },
         }
 
         fixture.wait_client_configure(client_id);
+        fixture.client(client_id).ack_all_window();
+        fixture.roundtrip(client_id);
         fixture.flush();
 
-        assert!(
-            fixture
-                .client(client_id)
-                .window_for_surface(&surface)
-                .fullscreen
-        );
+        assert!(fixture.pinnacle().is_window_occluded(&occluded, &output));
 
-        let window = fixture.pinnacle().windows[0].clone();
-        assert!(window.with_state(|state| state.layout_mode.is_fullscreen()));
+        // Simulate a second of frames on a 60hz output
+        let mut occluded_frames = 0;
+        let mut visible_frames = 0;
+        for frame in 0..60 {
+            let now = Duration::from_secs(1) * frame / 60;
+            let pinnacle = fixture.pinnacle();
+            if pinnacle.window_frame_callback_due(&occluded, &output, now) {
+                occluded_frames += 1;
+            }
+            if pinnacle.window_frame_callback_due(&visible, &output, now) {
+                visible_frames += 1;
+            }
+        }
+
+        assert_eq!(visible_frames, 60);
+        assert!(occluded_frames <= 10, "got {occluded_frames} frames");
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .toggle_fullscreen();
+                    .set_fullscreen(false);
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_geometry(1500, 0, None, None);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():toggle_fullscreen()
+                Window.get_focused():set_fullscreen(false)
+                Window.get_focused():set_geometry({ x = 1500, y = 0 })
             },
         }
 
         fixture.wait_client_configure(client_id);
+        fixture.client(client_id).ack_all_window();
+        fixture.roundtrip(client_id);
         fixture.flush();
 
+        // Uncovered windows get frame callbacks right away
+        assert!(!fixture.pinnacle().is_window_occluded(&occluded, &output));
+        let now = Duration::from_secs(1);
         assert!(
-            !fixture
-                .client(client_id)
-                .window_for_surface(&surface)
-                .fullscreen
+            fixture
+                .pinnacle()
+                .window_frame_callback_due(&occluded, &output, now)
         );
-
-        let window = fixture.pinnacle().windows[0].clone();
-        assert!(window.with_state(|state| !state.layout_mode.is_fullscreen()));
     });
 }
 
 #[test_log::test]
-fn window_handle_set_maximized() {
+fn window_handle_set_aspect_ratio_keeps_ratio_on_resize() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        let surface = fixture.spawn_windows(1, client_id).remove(0);
+        let _surface = fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
-                    .unwrap()
-                    .set_maximized(true);
+                let window = pinnacle_api::window::get_focused().unwrap();
+                window.set_aspect_ratio((16, 9));
+                window.set_geometry(None, None, 1600, None);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_maximized(true)
+                local window = Window.get_focused()
+                window:set_aspect_ratio(16, 9)
+                window:set_geometry({ width = 1600 })
             },
         }
 
         fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        assert!(
-            fixture
-                .client(client_id)
-                .window_for_surface(&surface)
-                .maximized
-        );
-
         let window = fixture.pinnacle().windows[0].clone();
-        assert!(window.with_state(|state| state.layout_mode.is_maximized()));
+        let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+        assert_eq!(geo.size, (1600, 900).into());
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
-                    .unwrap()
-                    .set_maximized(false);
+                let window = pinnacle_api::window::get_focused().unwrap();
+                window.set_aspect_ratio(None);
+                window.set_geometry(None, None, 1000, None);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_maximized(false)
+                local window = Window.get_focused()
+                window:set_aspect_ratio(nil)
+                window:set_geometry({ width = 1000 })
             },
         }
 
         fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        assert!(
-            !fixture
-                .client(client_id)
-                .window_for_surface(&surface)
-                .maximized
-        );
-
-        let window = fixture.pinnacle().windows[0].clone();
-        assert!(window.with_state(|state| !state.layout_mode.is_maximized()));
+        let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+        assert_eq!(geo.size, (1000, 900).into());
     });
 }
 
 #[test_log::test]
-fn window_handle_toggle_maximized() {
+fn window_layout_is_preserved_across_config_reload() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        let surface = fixture.spawn_windows(1, client_id).remove(0);
+        let _surface = fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
-                    .unwrap()
-                    .toggle_maximized();
+                pinnacle_api::pinnacle::set_preserve_layout_on_reload(true);
+                let window = pinnacle_api::window::get_focused().unwrap();
+                window.set_geometry(100, 200, None, None);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():toggle_maximized()
+                Pinnacle.set_preserve_layout_on_reload(true)
+                local window = Window.get_focused()
+                window:set_geometry({ x = 100, y = 200 })
             },
         }
 
         fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        assert!(
-            fixture
-                .client(client_id)
-                .window_for_surface(&surface)
-                .maximized
-        );
-
-        let window = fixture.pinnacle().windows[0].clone();
-        assert!(window.with_state(|state| state.layout_mode.is_maximized()));
-
+        // The new tags are added in a different order so the window's old tag
+        // isn't replaced by one with the same name
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
-                    .unwrap()
-                    .toggle_maximized();
+                pinnacle_api::pinnacle::reload_config();
+                let output = pinnacle_api::output::get_focused().unwrap();
+                let tags = pinnacle_api::tag::add(&output, ["3", "1"]).collect::<Vec<_>>();
+                tags[1].set_active(true);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():toggle_maximized()
+                Pinnacle.reload_config()
+                local tags = Tag.add(Output.get_focused(), "3", "1")
+                tags[2]:set_active(true)
             },
         }
 
-        fixture.wait_client_configure(client_id);
-        fixture.flush();
-
-        assert!(
-            !fixture
-                .client(client_id)
-                .window_for_surface(&surface)
-                .maximized
-        );
+        fixture.dispatch_for(Duration::from_millis(200));
 
         let window = fixture.pinnacle().windows[0].clone();
-        assert!(window.with_state(|state| !state.layout_mode.is_maximized()));
+        let tag_names =
+            window.with_state(|state| state.tags.iter().map(|tag| tag.name()).collect::<Vec<_>>());
+        assert_eq!(tag_names, ["1"]);
+
+        let geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+        assert_eq!(geo.loc, (100, 200).into());
+        assert_eq!(geo.size, (500, 500).into());
     });
 }
 
 #[test_log::test]
-fn window_handle_set_floating() {
+fn window_layout_is_preserved_across_config_reload_with_tags_added_separately() {
     for_each_api(|lang| {
-        let (mut fixture, _) = set_up();
+        let (mut fixture, output) = set_up();
+
+        output.with_state_mut(|state| state.add_tags([Tag::new("2".to_string())]));
 
         let client_id = fixture.add_client();
 
@@ -443,46 +624,53 @@ fn window_handle_set_floating() {
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
-                    .unwrap()
-                    .set_floating(true);
+                pinnacle_api::pinnacle::set_preserve_layout_on_reload(true);
+                let window = pinnacle_api::window::get_focused().unwrap();
+                let output = pinnacle_api::output::get_focused().unwrap();
+                window.set_tag(&output.tags().nth(1).unwrap(), true);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_floating(true)
+                Pinnacle.set_preserve_layout_on_reload(true)
+                local window = Window.get_focused()
+                window:set_tag(Output.get_focused():tags()[2], true)
             },
         }
 
-        fixture.wait_client_configure(client_id);
-        fixture.flush();
-
-        let window = fixture.pinnacle().windows[0].clone();
-        assert!(window.with_state(|state| state.layout_mode.is_floating()));
-
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
+                pinnacle_api::pinnacle::reload_config();
+                let output = pinnacle_api::output::get_focused().unwrap();
+                pinnacle_api::tag::add(&output, ["1"])
+                    .next()
                     .unwrap()
-                    .set_floating(false);
+                    .set_active(true);
+                pinnacle_api::tag::add(&output, ["2"]).for_each(drop);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_floating(false)
+                Pinnacle.reload_config()
+                Tag.add(Output.get_focused(), "1")[1]:set_active(true)
+                Tag.add(Output.get_focused(), "2")
             },
         }
 
-        fixture.wait_client_configure(client_id);
-        fixture.flush();
+        fixture.dispatch_for(Duration::from_millis(200));
 
         let window = fixture.pinnacle().windows[0].clone();
-        assert!(window.with_state(|state| !state.layout_mode.is_floating()));
+        let tag_names =
+            window.with_state(|state| state.tags.iter().map(|tag| tag.name()).collect::<Vec<_>>());
+        assert_eq!(tag_names, ["1", "2"]);
+        assert!(fixture.pinnacle().config.saved_window_layouts.is_empty());
     });
 }
 
 #[test_log::test]
-fn window_handle_toggle_floating() {
+fn saved_window_layouts_missing_tags_are_given_up_on() {
     for_each_api(|lang| {
-        let (mut fixture, _) = set_up();
+        let (mut fixture, output) = set_up();
+
+        output.with_state_mut(|state| state.add_tags([Tag::new("2".to_string())]));
 
         let client_id = fixture.add_client();
 
@@ -490,787 +678,1829 @@ fn window_handle_toggle_floating() {
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
-                    .unwrap()
-                    .toggle_floating();
+                pinnacle_api::pinnacle::set_preserve_layout_on_reload(true);
+                let window = pinnacle_api::window::get_focused().unwrap();
+                let output = pinnacle_api::output::get_focused().unwrap();
+                window.set_tag(&output.tags().nth(1).unwrap(), true);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():toggle_floating()
+                Pinnacle.set_preserve_layout_on_reload(true)
+                local window = Window.get_focused()
+                window:set_tag(Output.get_focused():tags()[2], true)
             },
         }
 
-        fixture.wait_client_configure(client_id);
-        fixture.flush();
-
-        let window = fixture.pinnacle().windows[0].clone();
-        assert!(window.with_state(|state| state.layout_mode.is_floating()));
-
+        // Tag "2" never comes back
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
+                pinnacle_api::pinnacle::reload_config();
+                let output = pinnacle_api::output::get_focused().unwrap();
+                pinnacle_api::tag::add(&output, ["1"])
+                    .next()
                     .unwrap()
-                    .toggle_floating();
+                    .set_active(true);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():toggle_floating()
+                Pinnacle.reload_config()
+                Tag.add(Output.get_focused(), "1")[1]:set_active(true)
             },
         }
 
-        fixture.wait_client_configure(client_id);
-        fixture.flush();
+        fixture.dispatch_for(Duration::from_millis(200));
+        assert_eq!(fixture.pinnacle().config.saved_window_layouts.len(), 1);
+
+        fixture.dispatch_until(|fixture| fixture.pinnacle().config.saved_window_layouts.is_empty());
 
         let window = fixture.pinnacle().windows[0].clone();
-        assert!(window.with_state(|state| !state.layout_mode.is_floating()));
+        let tag_names =
+            window.with_state(|state| state.tags.iter().map(|tag| tag.name()).collect::<Vec<_>>());
+        assert_eq!(tag_names, ["1"]);
     });
 }
 
 #[test_log::test]
-fn window_handle_set_focused() {
+fn window_handle_set_geometry_tiled_does_not_change_geometry() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        let _surfaces = fixture.spawn_windows(2, client_id);
+        let _surface = fixture.spawn_windows(1, client_id).remove(0);
 
-        let keyboard = fixture.pinnacle().seat.get_keyboard().unwrap();
+        let window = fixture.pinnacle().windows[0].clone();
+
+        let old_geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .set_focused(false);
+                    .set_geometry(200, 300, 1000, 1000);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_focused(false)
+                Window.get_focused():set_geometry({ x = 200, y = 300, width = 1000, height = 1000 })
             },
         }
 
-        fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        assert_eq!(keyboard.current_focus(), None);
+        let new_geo = fixture.pinnacle().space.element_geometry(&window).unwrap();
+
+        assert_eq!(new_geo, old_geo);
+    });
+}
+
+#[test_log::test]
+fn window_handle_set_fullscreen() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        let surface = fixture.spawn_windows(1, client_id).remove(0);
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_all()
-                    .next()
+                pinnacle_api::window::get_focused()
                     .unwrap()
-                    .set_focused(true);
+                    .set_fullscreen(true);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_all()[1]:set_focused(true)
+                Window.get_focused():set_fullscreen(true)
             },
         }
 
         fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        let first_window = fixture.pinnacle().windows[0].clone();
-        assert_eq!(
-            keyboard.current_focus(),
-            Some(KeyboardFocusTarget::Window(first_window))
+        assert!(
+            fixture
+                .client(client_id)
+                .window_for_surface(&surface)
+                .fullscreen
         );
 
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| state.layout_mode.is_fullscreen()));
+
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_all()
-                    .nth(1)
+                pinnacle_api::window::get_focused()
                     .unwrap()
-                    .set_focused(true);
+                    .set_fullscreen(false);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_all()[2]:set_focused(true)
+                Window.get_focused():set_fullscreen(false)
             },
         }
 
         fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        let second_window = fixture.pinnacle().windows[1].clone();
-        assert_eq!(
-            keyboard.current_focus(),
-            Some(KeyboardFocusTarget::Window(second_window))
+        assert!(
+            !fixture
+                .client(client_id)
+                .window_for_surface(&surface)
+                .fullscreen
         );
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| !state.layout_mode.is_fullscreen()));
     });
 }
 
 #[test_log::test]
-fn window_handle_toggle_focused() {
+fn window_handle_toggle_fullscreen() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        let _surfaces = fixture.spawn_windows(2, client_id);
-
-        let keyboard = fixture.pinnacle().seat.get_keyboard().unwrap();
+        let surface = fixture.spawn_windows(1, client_id).remove(0);
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .toggle_focused();
-            }),
-            Lang::Lua => spawn_lua_blocking! {
-                fixture,
-                Window.get_focused():toggle_focused()
-            },
-        }
-
-        fixture.wait_client_configure(client_id);
-        fixture.flush();
-
-        assert_eq!(keyboard.current_focus(), None);
-
-        match lang {
-            Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_all()
-                    .next()
-                    .unwrap()
-                    .toggle_focused();
+                    .toggle_fullscreen();
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_all()[1]:toggle_focused()
+                Window.get_focused():toggle_fullscreen()
             },
         }
 
         fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        let first_window = fixture.pinnacle().windows[0].clone();
-        assert_eq!(
-            keyboard.current_focus(),
-            Some(KeyboardFocusTarget::Window(first_window))
+        assert!(
+            fixture
+                .client(client_id)
+                .window_for_surface(&surface)
+                .fullscreen
         );
 
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| state.layout_mode.is_fullscreen()));
+
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_all()
-                    .nth(1)
+                pinnacle_api::window::get_focused()
                     .unwrap()
-                    .toggle_focused();
+                    .toggle_fullscreen();
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_all()[2]:toggle_focused()
+                Window.get_focused():toggle_fullscreen()
             },
         }
 
         fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        let second_window = fixture.pinnacle().windows[1].clone();
-        assert_eq!(
-            keyboard.current_focus(),
-            Some(KeyboardFocusTarget::Window(second_window))
+        assert!(
+            !fixture
+                .client(client_id)
+                .window_for_surface(&surface)
+                .fullscreen
         );
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| !state.layout_mode.is_fullscreen()));
     });
 }
 
 #[test_log::test]
-fn window_handle_set_decoration_mode() {
+fn window_handle_set_maximized() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        let _surface = fixture.spawn_windows(1, client_id).remove(0);
-        let window = fixture.pinnacle().windows[0].clone();
-
-        assert_eq!(window.with_state(|state| state.decoration_mode), None);
+        let surface = fixture.spawn_windows(1, client_id).remove(0);
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .set_decoration_mode(pinnacle_api::window::DecorationMode::ClientSide);
+                    .set_maximized(true);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_decoration_mode("client_side")
+                Window.get_focused():set_maximized(true)
             },
         }
 
         fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        assert_eq!(
-            window.with_state(|state| state.decoration_mode),
-            Some(zxdg_toplevel_decoration_v1::Mode::ClientSide)
+        assert!(
+            fixture
+                .client(client_id)
+                .window_for_surface(&surface)
+                .maximized
         );
 
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| state.layout_mode.is_maximized()));
+
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .set_decoration_mode(pinnacle_api::window::DecorationMode::ServerSide);
+                    .set_maximized(false);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_decoration_mode("server_side")
+                Window.get_focused():set_maximized(false)
             },
         }
 
         fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        assert_eq!(
-            window.with_state(|state| state.decoration_mode),
-            Some(zxdg_toplevel_decoration_v1::Mode::ServerSide)
+        assert!(
+            !fixture
+                .client(client_id)
+                .window_for_surface(&surface)
+                .maximized
         );
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| !state.layout_mode.is_maximized()));
     });
 }
 
 #[test_log::test]
-fn window_handle_set_vrr_demand() {
+fn window_handle_toggle_maximized() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        let _surface = fixture.spawn_windows(1, client_id).remove(0);
-        let window = fixture.pinnacle().windows[0].clone();
+        let surface = fixture.spawn_windows(1, client_id).remove(0);
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .set_vrr_demand(pinnacle_api::window::VrrDemand::when_visible());
+                    .toggle_maximized();
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_vrr_demand("visible")
+                Window.get_focused():toggle_maximized()
             },
         }
 
-        assert_eq!(
-            window.with_state(|state| state.vrr_demand),
-            Some(pinnacle::window::window_state::VrrDemand { fullscreen: false })
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        assert!(
+            fixture
+                .client(client_id)
+                .window_for_surface(&surface)
+                .maximized
         );
 
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| state.layout_mode.is_maximized()));
+
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .set_vrr_demand(pinnacle_api::window::VrrDemand::when_fullscreen());
+                    .toggle_maximized();
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_vrr_demand("fullscreen")
+                Window.get_focused():toggle_maximized()
             },
         }
 
-        assert_eq!(
-            window.with_state(|state| state.vrr_demand),
-            Some(pinnacle::window::window_state::VrrDemand { fullscreen: true })
-        );
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
 
-        match lang {
-            Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
-                    .unwrap()
-                    .set_vrr_demand(None);
-            }),
-            Lang::Lua => spawn_lua_blocking! {
-                fixture,
-                Window.get_focused():set_vrr_demand(nil)
-            },
-        }
+        assert!(
+            !fixture
+                .client(client_id)
+                .window_for_surface(&surface)
+                .maximized
+        );
 
-        assert_eq!(window.with_state(|state| state.vrr_demand), None);
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| !state.layout_mode.is_maximized()));
     });
 }
 
 #[test_log::test]
-fn window_handle_move_to_tag() {
+fn window_handle_set_floating() {
     for_each_api(|lang| {
-        let (mut fixture, output) = set_up();
-        output.with_state_mut(|state| {
-            let tag2 = Tag::new("2".to_string());
-            let tag3 = Tag::new("3".to_string());
-            state.add_tags([tag2, tag3]);
-        });
+        let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        fixture.spawn_windows(1, client_id);
+        let _surface = fixture.spawn_windows(1, client_id).remove(0);
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .move_to_tag(&pinnacle_api::tag::get("2").unwrap());
+                    .set_floating(true);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():move_to_tag(Tag.get('2'))
+                Window.get_focused():set_floating(true)
             },
         }
 
-        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
 
-        assert_eq!(tags.len(), 1);
-        assert_eq!(tags[0].name(), "2");
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| state.layout_mode.is_floating()));
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_floating(false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_floating(false)
+            },
+        }
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| !state.layout_mode.is_floating()));
     });
 }
 
 #[test_log::test]
-fn window_handle_set_tag() {
+fn window_handle_toggle_floating() {
     for_each_api(|lang| {
-        let (mut fixture, output) = set_up();
-        output.with_state_mut(|state| {
-            let tag2 = Tag::new("2".to_string());
-            let tag3 = Tag::new("3".to_string());
-            state.add_tags([tag2, tag3]);
-        });
+        let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        fixture.spawn_windows(1, client_id);
+        let _surface = fixture.spawn_windows(1, client_id).remove(0);
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .set_tag(&pinnacle_api::tag::get("2").unwrap(), true);
+                    .toggle_floating();
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_tag(Tag.get('2'), true)
+                Window.get_focused():toggle_floating()
             },
         }
 
-        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
 
-        assert_eq!(tags.len(), 2);
-        assert_eq!(tags[0].name(), "1");
-        assert_eq!(tags[1].name(), "2");
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| state.layout_mode.is_floating()));
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .set_tag(&pinnacle_api::tag::get("1").unwrap(), false);
+                    .toggle_floating();
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_tag(Tag.get('1'), false)
+                Window.get_focused():toggle_floating()
             },
         }
 
-        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
 
-        assert_eq!(tags.len(), 1);
-        assert_eq!(tags[0].name(), "2");
+        let window = fixture.pinnacle().windows[0].clone();
+        assert!(window.with_state(|state| !state.layout_mode.is_floating()));
     });
 }
 
 #[test_log::test]
-fn window_handle_toggle_tag() {
+fn window_handle_set_focused() {
     for_each_api(|lang| {
-        let (mut fixture, output) = set_up();
-        output.with_state_mut(|state| {
-            let tag2 = Tag::new("2".to_string());
-            let tag3 = Tag::new("3".to_string());
-            state.add_tags([tag2, tag3]);
-        });
+        let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        fixture.spawn_windows(1, client_id);
+        let _surfaces = fixture.spawn_windows(2, client_id);
+
+        let keyboard = fixture.pinnacle().seat.get_keyboard().unwrap();
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .toggle_tag(&pinnacle_api::tag::get("2").unwrap());
+                    .set_focused(false);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():toggle_tag(Tag.get('2'))
+                Window.get_focused():set_focused(false)
             },
         }
 
-        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
 
-        assert_eq!(tags.len(), 2);
-        assert_eq!(tags[0].name(), "1");
-        assert_eq!(tags[1].name(), "2");
+        assert_eq!(keyboard.current_focus(), None);
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
+                pinnacle_api::window::get_all()
+                    .next()
                     .unwrap()
-                    .toggle_tag(&pinnacle_api::tag::get("1").unwrap());
+                    .set_focused(true);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():toggle_tag(Tag.get('1'))
+                Window.get_all()[1]:set_focused(true)
             },
         }
 
-        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
 
-        assert_eq!(tags.len(), 1);
-        assert_eq!(tags[0].name(), "2");
+        let first_window = fixture.pinnacle().windows[0].clone();
+        assert_eq!(
+            keyboard.current_focus(),
+            Some(KeyboardFocusTarget::Window(first_window))
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_all()
+                    .nth(1)
+                    .unwrap()
+                    .set_focused(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_all()[2]:set_focused(true)
+            },
+        }
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        let second_window = fixture.pinnacle().windows[1].clone();
+        assert_eq!(
+            keyboard.current_focus(),
+            Some(KeyboardFocusTarget::Window(second_window))
+        );
     });
 }
 
 #[test_log::test]
-fn window_handle_set_tags() {
+fn window_handle_toggle_focused() {
     for_each_api(|lang| {
-        let (mut fixture, output) = set_up();
-        output.with_state_mut(|state| {
-            let tag2 = Tag::new("2".to_string());
-            let tag3 = Tag::new("3".to_string());
-            state.add_tags([tag2, tag3]);
-        });
+        let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        fixture.spawn_windows(1, client_id);
+        let _surfaces = fixture.spawn_windows(2, client_id);
+
+        let keyboard = fixture.pinnacle().seat.get_keyboard().unwrap();
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused().unwrap().set_tags([
-                    pinnacle_api::tag::get("2").unwrap(),
-                    pinnacle_api::tag::get("3").unwrap(),
-                ]);
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .toggle_focused();
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_tags({ Tag.get("2"), Tag.get("3") })
+                Window.get_focused():toggle_focused()
             },
         }
 
-        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
 
-        assert_eq!(tags.len(), 2);
-        assert_eq!(tags[0].name(), "2");
-        assert_eq!(tags[1].name(), "3");
+        assert_eq!(keyboard.current_focus(), None);
 
-        // Setting tags to empty shouldn't do anything
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_all()
+                    .next()
+                    .unwrap()
+                    .toggle_focused();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_all()[1]:toggle_focused()
+            },
+        }
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        let first_window = fixture.pinnacle().windows[0].clone();
+        assert_eq!(
+            keyboard.current_focus(),
+            Some(KeyboardFocusTarget::Window(first_window))
+        );
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_focused()
+                pinnacle_api::window::get_all()
+                    .nth(1)
                     .unwrap()
-                    .set_tags::<pinnacle_api::tag::TagHandle>([]);
+                    .toggle_focused();
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_focused():set_tags({})
+                Window.get_all()[2]:toggle_focused()
             },
         }
 
-        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
 
-        assert_eq!(tags.len(), 2);
-        assert_eq!(tags[0].name(), "2");
-        assert_eq!(tags[1].name(), "3");
+        let second_window = fixture.pinnacle().windows[1].clone();
+        assert_eq!(
+            keyboard.current_focus(),
+            Some(KeyboardFocusTarget::Window(second_window))
+        );
     });
 }
 
 #[test_log::test]
-fn window_handle_raise() {
+fn window_handle_set_decoration_mode() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        fixture.spawn_windows(2, client_id);
+        let _surface = fixture.spawn_windows(1, client_id).remove(0);
+        let window = fixture.pinnacle().windows[0].clone();
 
-        let top = fixture
-            .pinnacle()
-            .z_index_stack
-            .last()
-            .unwrap()
-            .window()
-            .unwrap()
-            .clone();
-        let second = fixture.pinnacle().windows[1].clone();
-        assert_eq!(top, second);
+        assert_eq!(window.with_state(|state| state.decoration_mode), None);
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_all().next().unwrap().raise();
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_decoration_mode(pinnacle_api::window::DecorationMode::ClientSide);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_all()[1]:raise()
+                Window.get_focused():set_decoration_mode("client_side")
             },
         }
 
-        let top = fixture
-            .pinnacle()
-            .z_index_stack
-            .last()
-            .unwrap()
-            .window()
-            .unwrap()
-            .clone();
-        let first = fixture.pinnacle().windows[0].clone();
-        assert_eq!(top, first);
-    });
-}
-
-#[test_log::test]
-fn window_handle_lower() {
-    for_each_api(|lang| {
-        let (mut fixture, _) = set_up();
-
-        let client_id = fixture.add_client();
-
-        fixture.spawn_windows(2, client_id);
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
 
-        let top = fixture
-            .pinnacle()
-            .z_index_stack
-            .last()
-            .unwrap()
-            .window()
-            .unwrap()
-            .clone();
-        let second = fixture.pinnacle().windows[1].clone();
-        assert_eq!(top, second);
+        assert_eq!(
+            window.with_state(|state| state.decoration_mode),
+            Some(zxdg_toplevel_decoration_v1::Mode::ClientSide)
+        );
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                pinnacle_api::window::get_all().nth(1).unwrap().lower();
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_decoration_mode(pinnacle_api::window::DecorationMode::ServerSide);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                Window.get_all()[2]:lower()
+                Window.get_focused():set_decoration_mode("server_side")
             },
         }
 
-        let top = fixture
-            .pinnacle()
-            .z_index_stack
-            .last()
-            .unwrap()
-            .window()
-            .unwrap()
-            .clone();
-        let first = fixture.pinnacle().windows[0].clone();
-        assert_eq!(top, first);
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        assert_eq!(
+            window.with_state(|state| state.decoration_mode),
+            Some(zxdg_toplevel_decoration_v1::Mode::ServerSide)
+        );
     });
 }
 
 #[test_log::test]
-fn window_handle_is_on_active_tag() {
+fn window_decoration_mode_persists_across_toggle_floating() {
     for_each_api(|lang| {
-        let (mut fixture, output) = set_up();
+        let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
 
-        fixture.spawn_windows(1, client_id);
+        let mut configured_mode = None;
+        fixture.spawn_window_with(client_id, |window| {
+            configured_mode =
+                Some(window.request_decoration_mode(ClientDecorationMode::ClientSide));
+        });
+        let configured_mode = configured_mode.unwrap();
+
+        assert_eq!(
+            configured_mode.load(Ordering::Relaxed),
+            u32::from(ClientDecorationMode::ClientSide)
+        );
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                assert!(
-                    pinnacle_api::window::get_all()
-                        .next()
-                        .unwrap()
-                        .is_on_active_tag()
-                );
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_decoration_mode(pinnacle_api::window::DecorationMode::ServerSide);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                assert(Window.get_all()[1]:is_on_active_tag())
+                Window.get_focused():set_decoration_mode("server_side")
             },
         }
 
-        output.with_state(|state| {
-            state.tags[0].set_active(false);
-        });
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        let window = fixture.pinnacle().windows[0].clone();
+
+        for _ in 0..2 {
+            match lang {
+                Lang::Rust => fixture.spawn_blocking(|| {
+                    pinnacle_api::window::get_focused()
+                        .unwrap()
+                        .toggle_floating();
+                }),
+                Lang::Lua => spawn_lua_blocking! {
+                    fixture,
+                    Window.get_focused():toggle_floating()
+                },
+            }
+
+            fixture.wait_client_configure(client_id);
+            fixture.flush();
+
+            assert_eq!(
+                window.with_state(|state| state.decoration_mode),
+                Some(zxdg_toplevel_decoration_v1::Mode::ServerSide)
+            );
+            assert_eq!(
+                configured_mode.load(Ordering::Relaxed),
+                u32::from(ClientDecorationMode::ServerSide)
+            );
+            assert!(!window.should_not_have_ssd());
+        }
+
+        assert!(window.with_state(|state| state.layout_mode.is_tiled()));
+    });
+}
+
+#[test_log::test]
+fn window_set_decoration_policy_force_server_side_overrides_client_request() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
 
         match lang {
             Lang::Rust => fixture.spawn_blocking(|| {
-                assert!(
-                    !pinnacle_api::window::get_all()
-                        .next()
-                        .unwrap()
-                        .is_on_active_tag()
+                pinnacle_api::window::set_decoration_policy(
+                    pinnacle_api::window::DecorationPolicy::ForceServerSide,
                 );
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                assert(not Window.get_all()[1]:is_on_active_tag())
+                Window.set_decoration_policy("force_server_side")
             },
         }
+
+        let client_id = fixture.add_client();
+
+        let mut configured_mode = None;
+        fixture.spawn_floating_window_with(client_id, (500, 500), |window| {
+            configured_mode =
+                Some(window.request_decoration_mode(ClientDecorationMode::ClientSide));
+        });
+        let configured_mode = configured_mode.unwrap();
+
+        assert_eq!(
+            configured_mode.load(Ordering::Relaxed),
+            u32::from(ClientDecorationMode::ServerSide)
+        );
+
+        let window = fixture.pinnacle().windows[0].clone();
+        assert_eq!(
+            window.with_state(|state| state.decoration_mode),
+            Some(zxdg_toplevel_decoration_v1::Mode::ServerSide)
+        );
     });
 }
 
 #[test_log::test]
-fn window_handle_loc() {
+fn window_set_floating_app_ids_opens_matching_windows_floating() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::set_floating_app_ids(["pavucontrol"]);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_floating_app_ids({ "pavucontrol" })
+            },
+        }
+
         let client_id = fixture.add_client();
 
-        fixture.spawn_windows(1, client_id);
+        fixture.spawn_window_with(client_id, |win| win.set_app_id("alacritty"));
 
-        let window = fixture.pinnacle().windows[0].clone();
-        let loc = fixture.pinnacle().space.element_location(&window).unwrap();
+        let window = fixture.client(client_id).create_window();
+        window.set_app_id("pavucontrol");
+        window.commit();
+        let surface = window.surface();
+        fixture.roundtrip(client_id);
+        fixture.wait_client_configure(client_id);
 
-        match lang {
-            Lang::Rust => fixture.spawn_blocking(move || {
-                let point = pinnacle_api::window::get_focused().unwrap().loc().unwrap();
-                assert_eq!(point.x, loc.x);
-                assert_eq!(point.y, loc.y);
-            }),
-            Lang::Lua => {
-                let x = loc.x;
-                let y = loc.y;
-                spawn_lua_blocking! {
-                    fixture,
-                    local point = Window.get_focused():loc()
-                    assert(point.x == $x)
-                    assert(point.y == $y)
-                };
-            }
-        }
+        let window = fixture.client(client_id).window_for_surface(&surface);
+        window.attach_buffer();
+        window.ack_and_commit();
+        fixture.roundtrip(client_id);
+
+        fixture.dispatch_until(|fixture| fixture.pinnacle().windows.len() == 2);
+
+        let windows = fixture.pinnacle().windows.clone();
+        assert!(windows[0].with_state(|state| state.layout_mode.is_tiled()));
+        assert!(windows[1].with_state(|state| state.layout_mode.is_floating()));
     });
 }
 
 #[test_log::test]
-fn window_handle_size() {
+fn window_on_inactive_tag_gets_throttled_frame_callbacks() {
     for_each_api(|lang| {
         let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
-
-        fixture.spawn_windows(1, client_id);
-
+        let surface = fixture.spawn_windows(1, client_id).remove(0);
         let window = fixture.pinnacle().windows[0].clone();
-        let actual_size = fixture
-            .pinnacle()
-            .space
-            .element_geometry(&window)
-            .unwrap()
-            .size;
 
         match lang {
-            Lang::Rust => fixture.spawn_blocking(move || {
-                let api_size = pinnacle_api::window::get_focused().unwrap().size().unwrap();
-                assert_eq!(api_size.w, actual_size.w as u32);
-                assert_eq!(api_size.h, actual_size.h as u32);
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::tag::get("1").unwrap().set_active(false);
             }),
-            Lang::Lua => {
-                let w = actual_size.w;
-                let h = actual_size.h;
-                spawn_lua_blocking! {
-                    fixture,
-                    local size = Window.get_focused():size()
-                    assert(size.width == $w)
-                    assert(size.height == $h)
-                };
-            }
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Tag.get("1"):set_active(false)
+            },
         }
-    });
-}
-
-#[test_log::test]
-fn window_handle_app_id() {
-    let (mut fixture, _) = set_up();
 
-    let client_id = fixture.add_client();
+        fixture
+            .dispatch_until(|fixture| fixture.pinnacle().space.element_location(&window).is_none());
 
-    let app_id = "mango";
+        // Hidden windows get a frame callback about once a second by default
+        let done = fixture.client(client_id).request_frame(&surface);
+        fixture.dispatch_for(Duration::from_millis(1200));
+        fixture.roundtrip(client_id);
+        assert!(done.load(Ordering::Relaxed));
 
-    fixture.spawn_window_with(client_id, |win| {
-        win.set_app_id(app_id);
-    });
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::render::set_frame_callback_policy(
+                    pinnacle_api::render::FrameCallbackPolicy::Never,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                require("pinnacle.render").set_frame_callback_policy("never")
+            },
+        }
 
-    fixture.spawn_blocking(move || {
         assert_eq!(
-            pinnacle_api::window::get_focused().unwrap().app_id(),
-            app_id
+            fixture.pinnacle().config.frame_callback_policy,
+            pinnacle::window::FrameCallbackPolicy::Never
         );
+
+        let done = fixture.client(client_id).request_frame(&surface);
+        fixture.dispatch_for(Duration::from_millis(1200));
+        fixture.roundtrip(client_id);
+        assert!(!done.load(Ordering::Relaxed));
     });
-    spawn_lua_blocking! {
-        fixture,
-        assert(Window.get_focused():app_id() == $app_id)
-    };
 }
 
 #[test_log::test]
-fn window_handle_title() {
-    let (mut fixture, _) = set_up();
+fn window_add_geometry_rule_sets_initial_floating_geometry() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
 
-    let client_id = fixture.add_client();
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                use pinnacle_api::{
+                    util::{Point, Rect, Size},
+                    window::WindowMatcher,
+                };
 
-    let title = "a title";
+                pinnacle_api::window::set_floating_app_ids(["calculator"]);
+                for app_id in ["^calculator$", "^alacritty$"] {
+                    pinnacle_api::window::add_geometry_rule(
+                        WindowMatcher::new().app_id(app_id),
+                        Rect {
+                            loc: Point { x: 1500, y: 20 },
+                            size: Size { w: 400, h: 500 },
+                        },
+                    );
+                }
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_floating_app_ids({ "calculator" })
+                for _, app_id in ipairs({ "^calculator$", "^alacritty$" }) do
+                    Window.add_geometry_rule(
+                        { app_id = app_id },
+                        { x = 1500, y = 20, width = 400, height = 500 }
+                    )
+                end
+            },
+        }
 
-    fixture.spawn_window_with(client_id, |win| {
-        win.set_title(title);
-    });
+        assert_eq!(fixture.pinnacle().config.geometry_rules.len(), 2);
 
-    fixture.spawn_blocking(move || {
-        assert_eq!(pinnacle_api::window::get_focused().unwrap().title(), title);
-    });
-    spawn_lua_blocking! {
-        fixture,
-        assert(Window.get_focused():title() == $title)
-    };
-}
+        let client_id = fixture.add_client();
 
-#[test_log::test]
-fn window_handle_focused() {
-    let (mut fixture, _) = set_up();
+        // Tiled windows ignore geometry rules
+        fixture.spawn_window_with(client_id, |win| win.set_app_id("alacritty"));
 
-    let client_id = fixture.add_client();
+        let window = fixture.client(client_id).create_window();
+        window.set_app_id("calculator");
+        window.commit();
+        let surface = window.surface();
+        fixture.roundtrip(client_id);
+        fixture.wait_client_configure(client_id);
 
-    fixture.spawn_windows(2, client_id);
+        let window = fixture.client(client_id).window_for_surface(&surface);
+        window.attach_buffer();
+        window.ack_and_commit();
+        fixture.roundtrip(client_id);
 
-    fixture.spawn_blocking(move || {
-        let mut windows = pinnacle_api::window::get_all();
-        assert!(!windows.next().unwrap().focused());
-        assert!(windows.next().unwrap().focused());
+        fixture.dispatch_until(|fixture| fixture.pinnacle().windows.len() == 2);
+
+        let windows = fixture.pinnacle().windows.clone();
+        assert!(windows[0].with_state(|state| state.layout_mode.is_tiled()));
+        assert!(windows[1].with_state(|state| state.layout_mode.is_floating()));
+
+        let tiled_geo = fixture.pinnacle().space.element_geometry(&windows[0]);
+        assert_ne!(
+            tiled_geo,
+            Some(Rectangle::new((1500, 20).into(), (400, 500).into()))
+        );
+
+        let geo = fixture
+            .pinnacle()
+            .space
+            .element_geometry(&windows[1])
+            .unwrap();
+        assert_eq!(geo, Rectangle::new((1500, 20).into(), (400, 500).into()));
     });
-    spawn_lua_blocking! {
-        fixture,
-        local windows = Window.get_all()
-        assert(not windows[1]:focused())
-        assert(windows[2]:focused())
-    };
 }
 
 #[test_log::test]
-fn window_handle_output() {
-    let (mut fixture, output) = set_up();
+fn window_handle_set_vrr_demand() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
 
-    let client_id = fixture.add_client();
+        let client_id = fixture.add_client();
 
-    fixture.spawn_windows(1, client_id);
+        let _surface = fixture.spawn_windows(1, client_id).remove(0);
+        let window = fixture.pinnacle().windows[0].clone();
 
-    fixture.spawn_blocking({
-        let output_name = output.name();
-        move || {
-            assert_eq!(
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
                 pinnacle_api::window::get_focused()
                     .unwrap()
-                    .output()
-                    .unwrap()
-                    .name(),
-                output_name
-            );
+                    .set_vrr_demand(pinnacle_api::window::VrrDemand::when_visible());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_vrr_demand("visible")
+            },
         }
-    });
 
-    let output_name = output.name();
-    spawn_lua_blocking! {
-        fixture,
-        assert(Window.get_focused():output().name == $output_name)
-    };
+        assert_eq!(
+            window.with_state(|state| state.vrr_demand),
+            Some(pinnacle::window::window_state::VrrDemand { fullscreen: false })
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_vrr_demand(pinnacle_api::window::VrrDemand::when_fullscreen());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_vrr_demand("fullscreen")
+            },
+        }
+
+        assert_eq!(
+            window.with_state(|state| state.vrr_demand),
+            Some(pinnacle::window::window_state::VrrDemand { fullscreen: true })
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_vrr_demand(None);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_vrr_demand(nil)
+            },
+        }
+
+        assert_eq!(window.with_state(|state| state.vrr_demand), None);
+    });
+}
+
+#[test_log::test]
+fn window_handle_move_to_tag() {
+    for_each_api(|lang| {
+        let (mut fixture, output) = set_up();
+        output.with_state_mut(|state| {
+            let tag2 = Tag::new("2".to_string());
+            let tag3 = Tag::new("3".to_string());
+            state.add_tags([tag2, tag3]);
+        });
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(1, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .move_to_tag(&pinnacle_api::tag::get("2").unwrap());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():move_to_tag(Tag.get('2'))
+            },
+        }
+
+        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name(), "2");
+    });
+}
+
+#[test_log::test]
+fn window_handle_move_to_tag_at_start() {
+    for_each_api(|lang| {
+        let (mut fixture, output) = set_up();
+        let tag2 = Tag::new("2".to_string());
+        output.with_state_mut(|state| {
+            state.add_tags([tag2.clone()]);
+        });
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(3, client_id);
+
+        let windows = fixture.pinnacle().windows.clone();
+        assert!(matches!(
+            fixture.pinnacle().seat.get_keyboard().unwrap().current_focus(),
+            Some(KeyboardFocusTarget::Window(win)) if win == windows[2]
+        ));
+
+        for window in &windows[..2] {
+            pinnacle::api::window::move_to_tag(fixture.state(), window, &tag2, None);
+        }
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused().unwrap().move_to_tag_at(
+                    &pinnacle_api::tag::get("2").unwrap(),
+                    pinnacle_api::window::InsertPosition::Start,
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():move_to_tag(Tag.get('2'), "start")
+            },
+        }
+
+        assert_eq!(
+            fixture.pinnacle().windows,
+            [windows[2].clone(), windows[0].clone(), windows[1].clone()]
+        );
+        assert!(
+            fixture
+                .pinnacle()
+                .windows
+                .iter()
+                .all(|win| win.with_state(|state| state.tags.contains(&tag2)))
+        );
+    });
+}
+
+#[test_log::test]
+fn window_handle_set_tag() {
+    for_each_api(|lang| {
+        let (mut fixture, output) = set_up();
+        output.with_state_mut(|state| {
+            let tag2 = Tag::new("2".to_string());
+            let tag3 = Tag::new("3".to_string());
+            state.add_tags([tag2, tag3]);
+        });
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(1, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_tag(&pinnacle_api::tag::get("2").unwrap(), true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_tag(Tag.get('2'), true)
+            },
+        }
+
+        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].name(), "1");
+        assert_eq!(tags[1].name(), "2");
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_tag(&pinnacle_api::tag::get("1").unwrap(), false);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_tag(Tag.get('1'), false)
+            },
+        }
+
+        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name(), "2");
+    });
+}
+
+#[test_log::test]
+fn window_handle_toggle_tag() {
+    for_each_api(|lang| {
+        let (mut fixture, output) = set_up();
+        output.with_state_mut(|state| {
+            let tag2 = Tag::new("2".to_string());
+            let tag3 = Tag::new("3".to_string());
+            state.add_tags([tag2, tag3]);
+        });
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(1, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .toggle_tag(&pinnacle_api::tag::get("2").unwrap());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():toggle_tag(Tag.get('2'))
+            },
+        }
+
+        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].name(), "1");
+        assert_eq!(tags[1].name(), "2");
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .toggle_tag(&pinnacle_api::tag::get("1").unwrap());
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():toggle_tag(Tag.get('1'))
+            },
+        }
+
+        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name(), "2");
+    });
+}
+
+#[test_log::test]
+fn window_handle_set_tags() {
+    for_each_api(|lang| {
+        let (mut fixture, output) = set_up();
+        output.with_state_mut(|state| {
+            let tag2 = Tag::new("2".to_string());
+            let tag3 = Tag::new("3".to_string());
+            state.add_tags([tag2, tag3]);
+        });
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(1, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused().unwrap().set_tags([
+                    pinnacle_api::tag::get("2").unwrap(),
+                    pinnacle_api::tag::get("3").unwrap(),
+                ]);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_tags({ Tag.get("2"), Tag.get("3") })
+            },
+        }
+
+        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].name(), "2");
+        assert_eq!(tags[1].name(), "3");
+
+        // Setting tags to empty shouldn't do anything
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_tags::<pinnacle_api::tag::TagHandle>([]);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_tags({})
+            },
+        }
+
+        let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
+
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].name(), "2");
+        assert_eq!(tags[1].name(), "3");
+    });
+}
+
+#[test_log::test]
+fn window_set_tags_bulk_lays_out_each_output_once() {
+    for_each_api(|lang| {
+        let mut fixture = Fixture::new();
+
+        let outputs = [0, 1920].map(|x| {
+            let output = fixture.add_output(Rectangle::new((x, 0).into(), (1920, 1080).into()));
+            output.with_state_mut(|state| {
+                let tag1 = Tag::new("1".to_string());
+                let tag2 = Tag::new("2".to_string());
+                tag1.set_active(true);
+                tag2.set_active(true);
+                state.add_tags([tag1, tag2]);
+            });
+            output
+        });
+
+        fixture
+            .runtime_handle()
+            .block_on(pinnacle_api::connect())
+            .unwrap();
+
+        let layout_counts = Arc::new(Mutex::new(HashMap::<String, u32>::new()));
+
+        fixture.spawn_blocking({
+            let layout_counts = layout_counts.clone();
+            move || {
+                pinnacle_api::layout::manage(move |args| {
+                    *layout_counts
+                        .lock()
+                        .unwrap()
+                        .entry(args.output.name())
+                        .or_default() += 1;
+                    pinnacle_api::layout::LayoutResponse {
+                        root_node: MasterStack::default().layout(args.window_count),
+                        tree_id: 0,
+                    }
+                });
+            }
+        });
+
+        let client_id = fixture.add_client();
+        for output in outputs.iter() {
+            fixture.pinnacle().focus_output(output);
+            fixture.spawn_windows(2, client_id);
+        }
+
+        fixture.dispatch_for(Duration::from_millis(500));
+        let before = layout_counts.lock().unwrap().clone();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::set_tags_bulk(pinnacle_api::window::get_all().map(|win| {
+                    let tag = pinnacle_api::tag::get_on_output("2", &win.output().unwrap());
+                    (win, vec![tag.unwrap()])
+                }));
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local changes = {}
+                for _, win in ipairs(Window.get_all()) do
+                    table.insert(changes, { window = win, tags = { Tag.get("2", win:output()) } })
+                end
+                Window.set_tags_bulk(changes)
+            },
+        }
+
+        fixture.dispatch_for(Duration::from_millis(500));
+        let after = layout_counts.lock().unwrap().clone();
+
+        for output in outputs.iter() {
+            let name = output.name();
+            assert_eq!(after[&name] - before[&name], 1);
+        }
+
+        for window in fixture.pinnacle().windows.clone() {
+            let tags = window.with_state(|state| state.tags.clone());
+            assert_eq!(tags.len(), 1);
+            assert_eq!(tags[0].name(), "2");
+            assert_eq!(
+                tags[0].output(fixture.pinnacle()),
+                window.output(fixture.pinnacle())
+            );
+        }
+        assert_eq!(fixture.pinnacle().windows.len(), 4);
+    });
+}
+
+#[test_log::test]
+fn window_handle_raise() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(2, client_id);
+
+        let top = fixture
+            .pinnacle()
+            .z_index_stack
+            .last()
+            .unwrap()
+            .window()
+            .unwrap()
+            .clone();
+        let second = fixture.pinnacle().windows[1].clone();
+        assert_eq!(top, second);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_all().next().unwrap().raise();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_all()[1]:raise()
+            },
+        }
+
+        let top = fixture
+            .pinnacle()
+            .z_index_stack
+            .last()
+            .unwrap()
+            .window()
+            .unwrap()
+            .clone();
+        let first = fixture.pinnacle().windows[0].clone();
+        assert_eq!(top, first);
+    });
+}
+
+#[test_log::test]
+fn window_handle_lower() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(2, client_id);
+
+        let top = fixture
+            .pinnacle()
+            .z_index_stack
+            .last()
+            .unwrap()
+            .window()
+            .unwrap()
+            .clone();
+        let second = fixture.pinnacle().windows[1].clone();
+        assert_eq!(top, second);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_all().nth(1).unwrap().lower();
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_all()[2]:lower()
+            },
+        }
+
+        let top = fixture
+            .pinnacle()
+            .z_index_stack
+            .last()
+            .unwrap()
+            .window()
+            .unwrap()
+            .clone();
+        let first = fixture.pinnacle().windows[0].clone();
+        assert_eq!(top, first);
+    });
+}
+
+#[test_log::test]
+fn window_handle_is_on_active_tag() {
+    for_each_api(|lang| {
+        let (mut fixture, output) = set_up();
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(1, client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                assert!(
+                    pinnacle_api::window::get_all()
+                        .next()
+                        .unwrap()
+                        .is_on_active_tag()
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                assert(Window.get_all()[1]:is_on_active_tag())
+            },
+        }
+
+        output.with_state(|state| {
+            state.tags[0].set_active(false);
+        });
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                assert!(
+                    !pinnacle_api::window::get_all()
+                        .next()
+                        .unwrap()
+                        .is_on_active_tag()
+                );
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                assert(not Window.get_all()[1]:is_on_active_tag())
+            },
+        }
+    });
+}
+
+#[test_log::test]
+fn window_handle_loc() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(1, client_id);
+
+        let window = fixture.pinnacle().windows[0].clone();
+        let loc = fixture.pinnacle().space.element_location(&window).unwrap();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let point = pinnacle_api::window::get_focused().unwrap().loc().unwrap();
+                assert_eq!(point.x, loc.x);
+                assert_eq!(point.y, loc.y);
+            }),
+            Lang::Lua => {
+                let x = loc.x;
+                let y = loc.y;
+                spawn_lua_blocking! {
+                    fixture,
+                    local point = Window.get_focused():loc()
+                    assert(point.x == $x)
+                    assert(point.y == $y)
+                };
+            }
+        }
+    });
+}
+
+#[test_log::test]
+fn window_handle_size() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_windows(1, client_id);
+
+        let window = fixture.pinnacle().windows[0].clone();
+        let actual_size = fixture
+            .pinnacle()
+            .space
+            .element_geometry(&window)
+            .unwrap()
+            .size;
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let api_size = pinnacle_api::window::get_focused().unwrap().size().unwrap();
+                assert_eq!(api_size.w, actual_size.w as u32);
+                assert_eq!(api_size.h, actual_size.h as u32);
+            }),
+            Lang::Lua => {
+                let w = actual_size.w;
+                let h = actual_size.h;
+                spawn_lua_blocking! {
+                    fixture,
+                    local size = Window.get_focused():size()
+                    assert(size.width == $w)
+                    assert(size.height == $h)
+                };
+            }
+        }
+    });
+}
+
+#[test_log::test]
+fn window_handle_app_id() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+
+    let app_id = "mango";
+
+    fixture.spawn_window_with(client_id, |win| {
+        win.set_app_id(app_id);
+    });
+
+    fixture.spawn_blocking(move || {
+        assert_eq!(
+            pinnacle_api::window::get_focused().unwrap().app_id(),
+            app_id
+        );
+    });
+    spawn_lua_blocking! {
+        fixture,
+        assert(Window.get_focused():app_id() == $app_id)
+    };
+}
+
+#[test_log::test]
+fn window_handle_set_app_id_override_reports_override() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        fixture.spawn_window_with(client_id, |win| {
+            win.set_app_id("alpha");
+        });
+
+        fixture.double_roundtrip(client_id);
+        assert_eq!(
+            fixture.client(client_id).foreign_toplevel_app_ids(),
+            [Some("alpha")]
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let focused = pinnacle_api::window::get_focused().unwrap();
+                focused.set_app_id_override(Some("beta"));
+                assert_eq!(focused.app_id(), "beta");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local focused = Window.get_focused()
+                focused:set_app_id_override("beta")
+                assert(focused:app_id() == "beta")
+            },
+        }
+
+        fixture.double_roundtrip(client_id);
+        assert_eq!(
+            fixture.client(client_id).foreign_toplevel_app_ids(),
+            [Some("beta")]
+        );
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                let focused = pinnacle_api::window::get_focused().unwrap();
+                focused.set_app_id_override(None);
+                assert_eq!(focused.app_id(), "alpha");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local focused = Window.get_focused()
+                focused:set_app_id_override(nil)
+                assert(focused:app_id() == "alpha")
+            },
+        }
+
+        fixture.double_roundtrip(client_id);
+        assert_eq!(
+            fixture.client(client_id).foreign_toplevel_app_ids(),
+            [Some("alpha")]
+        );
+    });
+}
+
+#[test_log::test]
+fn window_handle_set_warp_cursor_on_focus_warps_only_to_flagged_window() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+
+        let _surfaces = fixture.spawn_windows(2, client_id);
+
+        let pointer = fixture.pinnacle().seat.get_pointer().unwrap();
+        let first_window = fixture.pinnacle().windows[0].clone();
+        let second_window = fixture.pinnacle().windows[1].clone();
+
+        let first_geo = fixture
+            .pinnacle()
+            .space
+            .element_geometry(&first_window)
+            .unwrap();
+        let second_geo = fixture
+            .pinnacle()
+            .space
+            .element_geometry(&second_window)
+            .unwrap();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_all()
+                    .next()
+                    .unwrap()
+                    .set_warp_cursor_on_focus(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_all()[1]:set_warp_cursor_on_focus(true)
+            },
+        }
+
+        assert!(first_window.with_state(|state| state.warp_cursor_on_focus));
+
+        // Focusing the flagged window warps the pointer to its center
+        let outside = second_geo.loc.to_f64() + Point::from((10.0, 10.0));
+        fixture.state().warp_cursor_to_global_loc(outside);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_all()
+                    .next()
+                    .unwrap()
+                    .set_focused(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_all()[1]:set_focused(true)
+            },
+        }
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        let center = first_geo.loc.to_f64()
+            + Point::from((first_geo.size.w as f64 / 2.0, first_geo.size.h as f64 / 2.0));
+        assert_eq!(pointer.current_location(), center);
+
+        // Focusing the other window leaves the pointer alone
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_all()
+                    .nth(1)
+                    .unwrap()
+                    .set_focused(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_all()[2]:set_focused(true)
+            },
+        }
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        assert_eq!(pointer.current_location(), center);
+    });
+}
+
+#[test_log::test]
+fn window_handle_title() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+
+    let title = "a title";
+
+    fixture.spawn_window_with(client_id, |win| {
+        win.set_title(title);
+    });
+
+    fixture.spawn_blocking(move || {
+        assert_eq!(pinnacle_api::window::get_focused().unwrap().title(), title);
+    });
+    spawn_lua_blocking! {
+        fixture,
+        assert(Window.get_focused():title() == $title)
+    };
+}
+
+#[test_log::test]
+fn window_handle_instance_and_role_are_empty_for_wayland_windows() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+
+    fixture.spawn_window_with(client_id, |win| {
+        win.set_app_id("mango");
+    });
+
+    fixture.spawn_blocking(move || {
+        let focused = pinnacle_api::window::get_focused().unwrap();
+        assert_eq!(focused.app_id(), "mango");
+        assert_eq!(focused.instance(), "");
+        assert_eq!(focused.role(), "");
+    });
+    spawn_lua_blocking! {
+        fixture,
+        local focused = Window.get_focused()
+        assert(focused:instance() == "")
+        assert(focused:role() == "")
+    };
+}
+
+#[test_log::test]
+fn window_handle_instance_and_role_come_from_x11_properties() {
+    use smithay::reexports::x11rb::{
+        self,
+        connection::Connection as _,
+        protocol::xproto::{AtomEnum, ConnectionExt as _, CreateWindowAux, PropMode, WindowClass},
+        wrapper::ConnectionExt as _,
+    };
+
+    let (mut fixture, _) = set_up();
+
+    let display = fixture.start_xwayland();
+
+    // Xwayland needs the event loop dispatched, so the X11 client lives on its own thread
+    let x11_client = std::thread::spawn(move || {
+        let (conn, screen_num) = x11rb::connect(Some(&display)).unwrap();
+        let screen = &conn.setup().roots[screen_num];
+        let window = conn.generate_id().unwrap();
+        conn.create_window(
+            x11rb::COPY_DEPTH_FROM_PARENT,
+            window,
+            screen.root,
+            0,
+            0,
+            200,
+            200,
+            0,
+            WindowClass::INPUT_OUTPUT,
+            0,
+            &CreateWindowAux::new(),
+        )
+        .unwrap();
+        conn.change_property8(
+            PropMode::REPLACE,
+            window,
+            AtomEnum::WM_CLASS,
+            AtomEnum::STRING,
+            b"mango\0Mango\0",
+        )
+        .unwrap();
+        let wm_window_role = conn
+            .intern_atom(false, b"WM_WINDOW_ROLE")
+            .unwrap()
+            .reply()
+            .unwrap()
+            .atom;
+        conn.change_property8(
+            PropMode::REPLACE,
+            window,
+            wm_window_role,
+            AtomEnum::STRING,
+            b"browser",
+        )
+        .unwrap();
+        conn.map_window(window).unwrap();
+        conn.sync().unwrap();
+        conn
+    });
+
+    fixture.dispatch_until(|_| x11_client.is_finished());
+    let _conn = x11_client.join().unwrap();
+
+    fixture.dispatch_until(|fixture| {
+        let pinnacle = fixture.pinnacle();
+        let Some(xwayland_state) = pinnacle.xwayland_state.as_ref() else {
+            return false;
+        };
+        pinnacle
+            .windows
+            .iter()
+            .filter_map(|win| win.x11_surface())
+            .any(|surface| xwayland_state.window_role(surface).is_some())
+    });
+
+    fixture.spawn_blocking(move || {
+        let window = pinnacle_api::window::get_all().next().unwrap();
+        assert_eq!(window.class(), "Mango");
+        assert_eq!(window.instance(), "mango");
+        assert_eq!(window.role(), "browser");
+    });
+    spawn_lua_blocking! {
+        fixture,
+        local window = Window.get_all()[1]
+        assert(window:instance() == "mango")
+        assert(window:role() == "browser")
+    };
+}
+
+#[test_log::test]
+fn window_handle_focused() {
+    let (mut fixture, _) = set_up();
+
+    let client_id = fixture.add_client();
+
+    fixture.spawn_windows(2, client_id);
+
+    fixture.spawn_blocking(move || {
+        let mut windows = pinnacle_api::window::get_all();
+        assert!(!windows.next().unwrap().focused());
+        assert!(windows.next().unwrap().focused());
+    });
+    spawn_lua_blocking! {
+        fixture,
+        local windows = Window.get_all()
+        assert(not windows[1]:focused())
+        assert(windows[2]:focused())
+    };
+}
+
+#[test_log::test]
+fn window_handle_output() {
+    let (mut fixture, output) = set_up();
+
+    let client_id = fixture.add_client();
+
+    fixture.spawn_windows(1, client_id);
+
+    fixture.spawn_blocking({
+        let output_name = output.name();
+        move || {
+            assert_eq!(
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .output()
+                    .unwrap()
+                    .name(),
+                output_name
+            );
+        }
+    });
+
+    let output_name = output.name();
+    spawn_lua_blocking! {
+        fixture,
+        assert(Window.get_focused():output().name == $output_name)
+    };
 }
 
 #[test_log::test]
@@ -1495,23 +2725,264 @@ fn window_handle_move_to_output() {
 }
 
 #[test_log::test]
-fn window_handle_swap_simple() {
+fn window_handle_swap_simple() {
+    for_each_api(|lang| {
+        let (mut fixture, _output1) = set_up();
+
+        let client_id = fixture.add_client();
+
+        let _surfaces = fixture.spawn_windows(4, client_id);
+
+        let window = fixture.pinnacle().windows[0].clone();
+        let target = fixture.pinnacle().windows[2].clone();
+
+        let window_id = window.with_state(|s| s.id.0);
+        let target_id = target.with_state(|s| s.id.0);
+
+        let window_geo = fixture.pinnacle().space.element_geometry(&window);
+        let target_geo = fixture.pinnacle().space.element_geometry(&target);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let window_handle = pinnacle_api::window::WindowHandle::from_id(window_id);
+                let target_handle = pinnacle_api::window::WindowHandle::from_id(target_id);
+
+                window_handle.swap(&target_handle);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window_handle = Window.handle.new($window_id)
+                local target_handle = Window.handle.new($target_id)
+
+                window_handle:swap(target_handle)
+            },
+        };
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        assert_eq!(
+            window_geo,
+            fixture.pinnacle().space.element_geometry(&target)
+        );
+        assert_eq!(
+            target_geo,
+            fixture.pinnacle().space.element_geometry(&window)
+        );
+    })
+}
+
+#[test_log::test]
+fn window_handle_swap_multi_output() {
+    for_each_api(|lang| {
+        let (mut fixture, _output1) = set_up();
+
+        let client_id = fixture.add_client();
+
+        let mut surfaces = fixture.spawn_windows(1, client_id);
+
+        let output2 = fixture.add_output(Rectangle::new((1920, 0).into(), (1920, 1080).into()));
+        output2.with_state_mut(|state| {
+            let tag = Tag::new("2".to_string());
+            tag.set_active(true);
+            state.add_tags([tag]);
+        });
+
+        fixture.pinnacle().focus_output(&output2);
+
+        surfaces.append(&mut fixture.spawn_windows(1, client_id));
+
+        let window = fixture.pinnacle().windows[0].clone();
+        let target = fixture.pinnacle().windows[1].clone();
+
+        let window_id = window.with_state(|s| s.id.0);
+        let target_id = target.with_state(|s| s.id.0);
+
+        let window_geo = fixture.pinnacle().space.element_geometry(&window);
+        let target_geo = fixture.pinnacle().space.element_geometry(&target);
+
+        let window_tags = window.with_state(|state| state.tags.clone());
+        let target_tags = target.with_state(|state| state.tags.clone());
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let window_handle = pinnacle_api::window::WindowHandle::from_id(window_id);
+                let target_handle = pinnacle_api::window::WindowHandle::from_id(target_id);
+
+                window_handle.swap(&target_handle);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window_handle = Window.handle.new($window_id)
+                local target_handle = Window.handle.new($target_id)
+
+                window_handle:swap(target_handle)
+            },
+        };
+
+        fixture.dispatch_until(|fixture| !fixture.pinnacle().layout_state.pending_swap);
+
+        assert_eq!(
+            window_geo,
+            fixture.pinnacle().space.element_geometry(&target)
+        );
+        assert_eq!(
+            target_geo,
+            fixture.pinnacle().space.element_geometry(&window)
+        );
+
+        assert_eq!(window_tags, target.with_state(|state| state.tags.clone()));
+        assert_eq!(target_tags, window.with_state(|state| state.tags.clone()));
+    })
+}
+
+#[test_log::test]
+fn window_handle_swap_non_tiled() {
+    for_each_api(|lang| {
+        let (mut fixture, _output1) = set_up();
+
+        let client_id = fixture.add_client();
+        let mut surfaces = fixture.spawn_windows(1, client_id);
+
+        surfaces.push(fixture.spawn_floating_window_with(client_id, (500, 500), |_| ()));
+
+        let window = fixture.pinnacle().windows[0].clone();
+        let target = fixture.pinnacle().windows[1].clone();
+
+        let window_id = window.with_state(|s| s.id.0);
+        let target_id = target.with_state(|s| s.id.0);
+
+        let window_geo = fixture.pinnacle().space.element_geometry(&window);
+        let target_geo = fixture.pinnacle().space.element_geometry(&target);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let window_handle = pinnacle_api::window::WindowHandle::from_id(window_id);
+                let target_handle = pinnacle_api::window::WindowHandle::from_id(target_id);
+
+                window_handle.swap(&target_handle);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window_handle = Window.handle.new($window_id)
+                local target_handle = Window.handle.new($target_id)
+
+                window_handle:swap(target_handle)
+            },
+        };
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        fixture.dispatch_until(|fixture| !fixture.pinnacle().layout_state.pending_swap);
+
+        assert_eq!(
+            window_geo,
+            fixture.pinnacle().space.element_geometry(&target)
+        );
+        assert_eq!(
+            target_geo,
+            fixture.pinnacle().space.element_geometry(&window)
+        );
+
+        assert!(window.with_state(|state| state.layout_mode.is_floating()));
+        assert!(target.with_state(|state| state.layout_mode.is_tiled()));
+    })
+}
+
+#[test_log::test]
+fn window_handle_swap_non_tiled_multi_output() {
+    for_each_api(|lang| {
+        let (mut fixture, _output1) = set_up();
+
+        let client_id = fixture.add_client();
+        let mut surfaces = fixture.spawn_windows(1, client_id);
+
+        let output2 = fixture.add_output(Rectangle::new((1920, 0).into(), (1920, 1080).into()));
+        output2.with_state_mut(|state| {
+            let tag = Tag::new("2".to_string());
+            tag.set_active(true);
+            state.add_tags([tag]);
+        });
+        fixture.pinnacle().focus_output(&output2);
+
+        surfaces.push(fixture.spawn_floating_window_with(client_id, (500, 500), |_| ()));
+
+        let window = fixture.pinnacle().windows[0].clone();
+        let target = fixture.pinnacle().windows[1].clone();
+
+        let window_id = window.with_state(|s| s.id.0);
+        let target_id = target.with_state(|s| s.id.0);
+
+        let window_geo = fixture.pinnacle().space.element_geometry(&window);
+        let target_geo = fixture.pinnacle().space.element_geometry(&target);
+
+        let window_tags = window.with_state(|state| state.tags.clone());
+        let target_tags = target.with_state(|state| state.tags.clone());
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let window_handle = pinnacle_api::window::WindowHandle::from_id(window_id);
+                let target_handle = pinnacle_api::window::WindowHandle::from_id(target_id);
+
+                window_handle.swap(&target_handle);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local window_handle = Window.handle.new($window_id)
+                local target_handle = Window.handle.new($target_id)
+
+                window_handle:swap(target_handle)
+            },
+        };
+
+        fixture.wait_client_configure(client_id);
+        fixture.flush();
+
+        fixture.dispatch_until(|fixture| !fixture.pinnacle().layout_state.pending_swap);
+
+        assert_eq!(
+            window_geo,
+            fixture.pinnacle().space.element_geometry(&target)
+        );
+        assert_eq!(
+            target_geo,
+            fixture.pinnacle().space.element_geometry(&window)
+        );
+
+        assert_eq!(window_tags, target.with_state(|state| state.tags.clone()));
+        assert_eq!(target_tags, window.with_state(|state| state.tags.clone()));
+
+        assert!(window.with_state(|state| state.layout_mode.is_floating()));
+        assert!(target.with_state(|state| state.layout_mode.is_tiled()));
+    })
+}
+
+#[test_log::test]
+fn window_handle_swap_swap_tag() {
     for_each_api(|lang| {
-        let (mut fixture, _output1) = set_up();
+        let (mut fixture, output) = set_up();
 
-        let client_id = fixture.add_client();
+        let tag = Tag::new("2".to_string());
+        tag.set_active(true);
+        output.with_state_mut(|state| {
+            state.add_tags([tag.clone()]);
+        });
 
-        let _surfaces = fixture.spawn_windows(4, client_id);
+        let id = fixture.add_client();
+
+        let _surfaces = fixture.spawn_windows(4, id);
 
         let window = fixture.pinnacle().windows[0].clone();
         let target = fixture.pinnacle().windows[2].clone();
+        target.with_state_mut(|state| state.tags = std::iter::once(tag.clone()).collect());
+
+        let window_tags = window.with_state(|state| state.tags.clone());
+        let target_tags = target.with_state(|state| state.tags.clone());
 
         let window_id = window.with_state(|s| s.id.0);
         let target_id = target.with_state(|s| s.id.0);
 
-        let window_geo = fixture.pinnacle().space.element_geometry(&window);
-        let target_geo = fixture.pinnacle().space.element_geometry(&target);
-
         match lang {
             Lang::Rust => fixture.spawn_blocking(move || {
                 let window_handle = pinnacle_api::window::WindowHandle::from_id(window_id);
@@ -1524,26 +2995,21 @@ fn window_handle_swap_simple() {
                 local window_handle = Window.handle.new($window_id)
                 local target_handle = Window.handle.new($target_id)
 
-                window_handle:swap(target_handle)
+                window_handle:swap(target_handle);
             },
         };
 
-        fixture.wait_client_configure(client_id);
+        fixture.wait_client_configure(id);
         fixture.flush();
 
-        assert_eq!(
-            window_geo,
-            fixture.pinnacle().space.element_geometry(&target)
-        );
-        assert_eq!(
-            target_geo,
-            fixture.pinnacle().space.element_geometry(&window)
-        );
+        assert_eq!(window_tags, target.with_state(|state| state.tags.clone()));
+
+        assert_eq!(target_tags, window.with_state(|state| state.tags.clone()));
     })
 }
 
 #[test_log::test]
-fn window_handle_swap_multi_output() {
+fn window_handle_swap_swap_tag_multi_output() {
     for_each_api(|lang| {
         let (mut fixture, _output1) = set_up();
 
@@ -1555,8 +3021,9 @@ fn window_handle_swap_multi_output() {
         output2.with_state_mut(|state| {
             let tag = Tag::new("2".to_string());
             tag.set_active(true);
-            state.add_tags([tag]);
+            state.add_tags([tag, Tag::new("3".to_string())]);
         });
+        let tags = output2.with_state(|state| state.tags.clone());
 
         fixture.pinnacle().focus_output(&output2);
 
@@ -1564,6 +3031,7 @@ fn window_handle_swap_multi_output() {
 
         let window = fixture.pinnacle().windows[0].clone();
         let target = fixture.pinnacle().windows[1].clone();
+        target.with_state_mut(|state| state.tags = tags);
 
         let window_id = window.with_state(|s| s.id.0);
         let target_id = target.with_state(|s| s.id.0);
@@ -1607,24 +3075,30 @@ fn window_handle_swap_multi_output() {
 }
 
 #[test_log::test]
-fn window_handle_swap_non_tiled() {
+fn window_handle_swap_swap_inactive_tag() {
     for_each_api(|lang| {
-        let (mut fixture, _output1) = set_up();
+        let (mut fixture, output) = set_up();
 
-        let client_id = fixture.add_client();
-        let mut surfaces = fixture.spawn_windows(1, client_id);
+        let tag = Tag::new("2".to_string());
+        tag.set_active(false);
+        output.with_state_mut(|state| {
+            state.add_tags([tag.clone()]);
+        });
 
-        surfaces.push(fixture.spawn_floating_window_with(client_id, (500, 500), |_| ()));
+        let id = fixture.add_client();
+
+        let _surfaces = fixture.spawn_windows(4, id);
 
         let window = fixture.pinnacle().windows[0].clone();
-        let target = fixture.pinnacle().windows[1].clone();
+        let target = fixture.pinnacle().windows[2].clone();
+        target.with_state_mut(|state| state.tags = std::iter::once(tag.clone()).collect());
+
+        let window_tags = window.with_state(|state| state.tags.clone());
+        let target_tags = target.with_state(|state| state.tags.clone());
 
         let window_id = window.with_state(|s| s.id.0);
         let target_id = target.with_state(|s| s.id.0);
 
-        let window_geo = fixture.pinnacle().space.element_geometry(&window);
-        let target_geo = fixture.pinnacle().space.element_geometry(&target);
-
         match lang {
             Lang::Rust => fixture.spawn_blocking(move || {
                 let window_handle = pinnacle_api::window::WindowHandle::from_id(window_id);
@@ -1637,56 +3111,47 @@ fn window_handle_swap_non_tiled() {
                 local window_handle = Window.handle.new($window_id)
                 local target_handle = Window.handle.new($target_id)
 
-                window_handle:swap(target_handle)
+                window_handle:swap(target_handle);
             },
         };
 
-        fixture.wait_client_configure(client_id);
+        fixture.wait_client_configure(id);
         fixture.flush();
 
-        fixture.dispatch_until(|fixture| !fixture.pinnacle().layout_state.pending_swap);
-
-        assert_eq!(
-            window_geo,
-            fixture.pinnacle().space.element_geometry(&target)
-        );
-        assert_eq!(
-            target_geo,
-            fixture.pinnacle().space.element_geometry(&window)
-        );
+        assert_eq!(window_tags, target.with_state(|state| state.tags.clone()));
 
-        assert!(window.with_state(|state| state.layout_mode.is_floating()));
-        assert!(target.with_state(|state| state.layout_mode.is_tiled()));
+        assert_eq!(target_tags, window.with_state(|state| state.tags.clone()));
     })
 }
 
 #[test_log::test]
-fn window_handle_swap_non_tiled_multi_output() {
+fn window_handle_swap_swap_inactive_tag_multi_output() {
     for_each_api(|lang| {
         let (mut fixture, _output1) = set_up();
 
         let client_id = fixture.add_client();
+
         let mut surfaces = fixture.spawn_windows(1, client_id);
 
         let output2 = fixture.add_output(Rectangle::new((1920, 0).into(), (1920, 1080).into()));
+        let inactive_tag = Tag::new("3".to_string());
         output2.with_state_mut(|state| {
             let tag = Tag::new("2".to_string());
             tag.set_active(true);
-            state.add_tags([tag]);
+            state.add_tags([tag, inactive_tag.clone()]);
         });
+
         fixture.pinnacle().focus_output(&output2);
 
-        surfaces.push(fixture.spawn_floating_window_with(client_id, (500, 500), |_| ()));
+        surfaces.append(&mut fixture.spawn_windows(1, client_id));
 
         let window = fixture.pinnacle().windows[0].clone();
         let target = fixture.pinnacle().windows[1].clone();
+        target.with_state_mut(|state| state.tags = std::iter::once(inactive_tag.clone()).collect());
 
         let window_id = window.with_state(|s| s.id.0);
         let target_id = target.with_state(|s| s.id.0);
 
-        let window_geo = fixture.pinnacle().space.element_geometry(&window);
-        let target_geo = fixture.pinnacle().space.element_geometry(&target);
-
         let window_tags = window.with_state(|state| state.tags.clone());
         let target_tags = target.with_state(|state| state.tags.clone());
 
@@ -1706,249 +3171,530 @@ fn window_handle_swap_non_tiled_multi_output() {
             },
         };
 
-        fixture.wait_client_configure(client_id);
-        fixture.flush();
-
         fixture.dispatch_until(|fixture| !fixture.pinnacle().layout_state.pending_swap);
 
+        assert_eq!(window_tags, target.with_state(|state| state.tags.clone()));
+        assert_eq!(target_tags, window.with_state(|state| state.tags.clone()));
+    })
+}
+
+#[test_log::test]
+fn window_groups() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        let surfaces = fixture.spawn_windows(3, client_id);
+
+        let ids = fixture
+            .pinnacle()
+            .windows
+            .iter()
+            .map(|win| win.with_state(|state| state.id.0))
+            .collect::<Vec<_>>();
+        let (id0, id2) = (ids[0], ids[2]);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::window::create_group("group");
+                assert_eq!(pinnacle_api::window::group_windows("group").count(), 0);
+
+                pinnacle_api::window::WindowHandle::from_id(id2).add_to_group("group");
+                pinnacle_api::window::WindowHandle::from_id(id0).add_to_group("group");
+                // Adding a window twice does nothing
+                pinnacle_api::window::WindowHandle::from_id(id0).add_to_group("group");
+
+                let group = pinnacle_api::window::group_windows("group")
+                    .map(|win| win.id())
+                    .collect::<Vec<_>>();
+                assert_eq!(group, [id2, id0]);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.create_group("group")
+                assert(#Window.group_windows("group") == 0)
+
+                Window.handle.new($id2):add_to_group("group")
+                Window.handle.new($id0):add_to_group("group")
+                Window.handle.new($id0):add_to_group("group")
+
+                local group = Window.group_windows("group")
+                assert(#group == 2)
+                assert(group[1].id == $id2)
+                assert(group[2].id == $id0)
+            },
+        }
+
+        // Closed windows leave their groups
+        fixture.client(client_id).close_window(&surfaces[2]);
+        fixture.roundtrip(client_id);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                let group = pinnacle_api::window::group_windows("group")
+                    .map(|win| win.id())
+                    .collect::<Vec<_>>();
+                assert_eq!(group, [id0]);
+
+                pinnacle_api::window::WindowHandle::from_id(id0).remove_from_group("group");
+                assert_eq!(pinnacle_api::window::group_windows("group").count(), 0);
+
+                pinnacle_api::window::remove_group("group");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                local group = Window.group_windows("group")
+                assert(#group == 1)
+                assert(group[1].id == $id0)
+
+                Window.handle.new($id0):remove_from_group("group")
+                assert(#Window.group_windows("group") == 0)
+
+                Window.remove_group("group")
+            },
+        }
+
+        assert!(fixture.pinnacle().window_groups.is_empty());
+    })
+}
+
+#[test_log::test]
+fn window_focus_in_group_cycles_through_group() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(3, client_id);
+
+        let windows = fixture.pinnacle().windows.clone();
+        let ids = windows
+            .iter()
+            .map(|win| win.with_state(|state| state.id.0))
+            .collect::<Vec<_>>();
+        let (id0, id1) = (ids[0], ids[1]);
+
+        // The last spawned window is focused, and joins the group through the first one
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(move || {
+                pinnacle_api::window::WindowHandle::from_id(id0).add_to_group("tabs");
+                pinnacle_api::window::WindowHandle::from_id(id1).add_to_group("tabs");
+                pinnacle_api::window::add_focused_to_group_of(
+                    &pinnacle_api::window::WindowHandle::from_id(id0),
+                );
+                pinnacle_api::window::WindowHandle::from_id(id0).set_focused(true);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.handle.new($id0):add_to_group("tabs")
+                Window.handle.new($id1):add_to_group("tabs")
+                Window.add_focused_to_group_of(Window.handle.new($id0))
+                Window.handle.new($id0):set_focused(true)
+            },
+        }
+
+        assert_eq!(fixture.pinnacle().window_groups["tabs"], windows);
+
+        let assert_shown = |fixture: &mut Fixture, idx: usize| {
+            fixture.wait_client_configure(client_id);
+            fixture.flush();
+
+            let keyboard = fixture.pinnacle().seat.get_keyboard().unwrap();
+            assert_eq!(
+                keyboard.current_focus(),
+                Some(KeyboardFocusTarget::Window(windows[idx].clone()))
+            );
+            let top = fixture
+                .pinnacle()
+                .z_index_stack
+                .last()
+                .unwrap()
+                .window()
+                .unwrap()
+                .clone();
+            assert_eq!(top, windows[idx]);
+        };
+
+        for expected in [1, 2, 0] {
+            match lang {
+                Lang::Rust => fixture.spawn_blocking(pinnacle_api::window::focus_next_in_group),
+                Lang::Lua => spawn_lua_blocking! {
+                    fixture,
+                    Window.focus_next_in_group()
+                },
+            }
+            assert_shown(&mut fixture, expected);
+        }
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(pinnacle_api::window::focus_prev_in_group),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.focus_prev_in_group()
+            },
+        }
+        assert_shown(&mut fixture, 2);
+    })
+}
+
+#[test_log::test]
+fn window_scratchpad_toggle_on_other_output() {
+    for_each_api(|lang| {
+        let (mut fixture, output1) = set_up();
+
+        let output2 = fixture.add_output(Rectangle::new((1920, 0).into(), (1920, 1080).into()));
+        output2.with_state_mut(|state| {
+            let tag = Tag::new("1".to_string());
+            tag.set_active(true);
+            state.add_tags([tag]);
+        });
+        fixture.pinnacle().focus_output(&output1);
+
+        let client_id = fixture.add_client();
+        fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
+
+        let window = fixture.pinnacle().windows[0].clone();
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .to_scratchpad("term");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():to_scratchpad("term")
+            },
+        }
+
+        fixture
+            .dispatch_until(|fixture| fixture.pinnacle().space.element_geometry(&window).is_none());
+
+        assert!(window.with_state(|state| state.tags.is_empty()));
         assert_eq!(
-            window_geo,
-            fixture.pinnacle().space.element_geometry(&target)
+            fixture.pinnacle().keyboard_focus_stack.current_focus(),
+            None
+        );
+
+        fixture.pinnacle().focus_output(&output2);
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::toggle_scratchpad("term");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.toggle_scratchpad("term")
+            },
+        }
+
+        fixture
+            .dispatch_until(|fixture| fixture.pinnacle().space.element_geometry(&window).is_some());
+
+        assert_eq!(
+            window.with_state(|state| state.tags.clone()),
+            output2.with_state(|state| state.tags.clone())
+        );
+        assert!(window.with_state(|state| state.layout_mode.is_floating()));
+        assert_eq!(
+            fixture.pinnacle().space.element_geometry(&window),
+            Some(Rectangle::new((1920 + 710, 290).into(), (500, 500).into()))
         );
         assert_eq!(
-            target_geo,
-            fixture.pinnacle().space.element_geometry(&window)
+            fixture.pinnacle().keyboard_focus_stack.current_focus(),
+            Some(&window)
         );
 
-        assert_eq!(window_tags, target.with_state(|state| state.tags.clone()));
-        assert_eq!(target_tags, window.with_state(|state| state.tags.clone()));
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::toggle_scratchpad("term");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.toggle_scratchpad("term")
+            },
+        }
+
+        fixture
+            .dispatch_until(|fixture| fixture.pinnacle().space.element_geometry(&window).is_none());
+
+        assert!(window.with_state(|state| state.tags.is_empty()));
+    });
+}
+
+#[test_log::test]
+fn window_scratchpad_forgets_closed_window() {
+    for_each_api(|lang| {
+        let (mut fixture, _) = set_up();
+
+        let client_id = fixture.add_client();
+        let surface = fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
+
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .to_scratchpad("term");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():to_scratchpad("term")
+            },
+        }
+
+        assert_eq!(fixture.pinnacle().scratchpads.len(), 1);
+
+        fixture.client(client_id).close_window(&surface);
+        fixture.roundtrip(client_id);
+
+        assert_eq!(fixture.pinnacle().windows.len(), 0);
+        assert!(fixture.pinnacle().scratchpads.is_empty());
 
-        assert!(window.with_state(|state| state.layout_mode.is_floating()));
-        assert!(target.with_state(|state| state.layout_mode.is_tiled()));
-    })
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::toggle_scratchpad("term");
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.toggle_scratchpad("term")
+            },
+        }
+
+        assert_eq!(fixture.pinnacle().windows.len(), 0);
+    });
 }
 
 #[test_log::test]
-fn window_handle_swap_swap_tag() {
+fn window_set_inactive_opacity_follows_focus() {
     for_each_api(|lang| {
-        let (mut fixture, output) = set_up();
-
-        let tag = Tag::new("2".to_string());
-        tag.set_active(true);
-        output.with_state_mut(|state| {
-            state.add_tags([tag.clone()]);
-        });
-
-        let id = fixture.add_client();
+        let (mut fixture, _) = set_up();
 
-        let _surfaces = fixture.spawn_windows(4, id);
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::set_inactive_opacity(0.5);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.set_inactive_opacity(0.5)
+            },
+        }
 
-        let window = fixture.pinnacle().windows[0].clone();
-        let target = fixture.pinnacle().windows[2].clone();
-        target.with_state_mut(|state| state.tags = std::iter::once(tag.clone()).collect());
+        let client_id = fixture.add_client();
+        fixture.spawn_windows(2, client_id);
 
-        let window_tags = window.with_state(|state| state.tags.clone());
-        let target_tags = target.with_state(|state| state.tags.clone());
+        let opacities = |fixture: &mut Fixture| {
+            fixture
+                .pinnacle()
+                .windows
+                .iter()
+                .map(|win| win.with_state(|state| state.opacity))
+                .collect::<Vec<_>>()
+        };
 
-        let window_id = window.with_state(|s| s.id.0);
-        let target_id = target.with_state(|s| s.id.0);
+        assert_eq!(opacities(&mut fixture), [0.5, 1.0]);
 
         match lang {
-            Lang::Rust => fixture.spawn_blocking(move || {
-                let window_handle = pinnacle_api::window::WindowHandle::from_id(window_id);
-                let target_handle = pinnacle_api::window::WindowHandle::from_id(target_id);
-
-                window_handle.swap(&target_handle);
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_all()
+                    .next()
+                    .unwrap()
+                    .set_focused(true);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                local window_handle = Window.handle.new($window_id)
-                local target_handle = Window.handle.new($target_id)
-
-                window_handle:swap(target_handle);
+                Window.get_all()[1]:set_focused(true)
             },
-        };
+        }
 
-        fixture.wait_client_configure(id);
+        fixture.wait_client_configure(client_id);
         fixture.flush();
 
-        assert_eq!(window_tags, target.with_state(|state| state.tags.clone()));
-
-        assert_eq!(target_tags, window.with_state(|state| state.tags.clone()));
-    })
+        assert_eq!(opacities(&mut fixture), [1.0, 0.5]);
+    });
 }
 
 #[test_log::test]
-fn window_handle_swap_swap_tag_multi_output() {
-    for_each_api(|lang| {
-        let (mut fixture, _output1) = set_up();
+fn window_handle_set_urgent_signals_and_clears_on_focus() {
+    let (mut fixture, _) = set_up();
 
-        let client_id = fixture.add_client();
+    let client_id = fixture.add_client();
+    fixture.spawn_windows(2, client_id);
 
-        let mut surfaces = fixture.spawn_windows(1, client_id);
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_clone = changes.clone();
 
-        let output2 = fixture.add_output(Rectangle::new((1920, 0).into(), (1920, 1080).into()));
-        output2.with_state_mut(|state| {
-            let tag = Tag::new("2".to_string());
-            tag.set_active(true);
-            state.add_tags([tag, Tag::new("3".to_string())]);
-        });
-        let tags = output2.with_state(|state| state.tags.clone());
+    fixture.spawn_blocking(move || {
+        pinnacle_api::window::connect_signal(WindowSignal::UrgentChanged(Box::new(
+            move |window, urgent| {
+                changes_clone.lock().unwrap().push((window.id(), urgent));
+            },
+        )));
+    });
 
-        fixture.pinnacle().focus_output(&output2);
+    // Let the signal stream finish connecting
+    fixture.dispatch_for(Duration::from_millis(500));
 
-        surfaces.append(&mut fixture.spawn_windows(1, client_id));
+    let first_id = fixture.pinnacle().windows[0].with_state(|state| state.id.0);
 
-        let window = fixture.pinnacle().windows[0].clone();
-        let target = fixture.pinnacle().windows[1].clone();
-        target.with_state_mut(|state| state.tags = tags);
+    fixture.spawn_blocking(|| {
+        let window = pinnacle_api::window::get_all().next().unwrap();
+        window.set_urgent(true);
+        assert!(window.urgent());
+    });
 
-        let window_id = window.with_state(|s| s.id.0);
-        let target_id = target.with_state(|s| s.id.0);
+    fixture.dispatch_until(|_| !changes.lock().unwrap().is_empty());
+    assert_eq!(*changes.lock().unwrap(), [(first_id, true)]);
 
-        let window_geo = fixture.pinnacle().space.element_geometry(&window);
-        let target_geo = fixture.pinnacle().space.element_geometry(&target);
+    fixture.spawn_blocking(|| {
+        pinnacle_api::window::get_all()
+            .next()
+            .unwrap()
+            .set_focused(true);
+    });
 
-        let window_tags = window.with_state(|state| state.tags.clone());
-        let target_tags = target.with_state(|state| state.tags.clone());
+    fixture.dispatch_until(|_| changes.lock().unwrap().len() == 2);
+    assert_eq!(
+        *changes.lock().unwrap(),
+        [(first_id, true), (first_id, false)]
+    );
 
-        match lang {
-            Lang::Rust => fixture.spawn_blocking(move || {
-                let window_handle = pinnacle_api::window::WindowHandle::from_id(window_id);
-                let target_handle = pinnacle_api::window::WindowHandle::from_id(target_id);
+    fixture.spawn_blocking(|| {
+        assert!(!pinnacle_api::window::get_all().next().unwrap().urgent());
+    });
+}
 
-                window_handle.swap(&target_handle);
-            }),
-            Lang::Lua => spawn_lua_blocking! {
-                fixture,
-                local window_handle = Window.handle.new($window_id)
-                local target_handle = Window.handle.new($target_id)
+#[test_log::test]
+fn window_signal_layout_mode_changed_fires_once_per_change() {
+    let (mut fixture, _) = set_up();
 
-                window_handle:swap(target_handle)
-            },
-        };
+    let client_id = fixture.add_client();
+    fixture.spawn_windows(1, client_id);
 
-        fixture.dispatch_until(|fixture| !fixture.pinnacle().layout_state.pending_swap);
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_clone = changes.clone();
 
-        assert_eq!(
-            window_geo,
-            fixture.pinnacle().space.element_geometry(&target)
-        );
-        assert_eq!(
-            target_geo,
-            fixture.pinnacle().space.element_geometry(&window)
-        );
+    fixture.spawn_blocking(move || {
+        pinnacle_api::window::connect_signal(WindowSignal::LayoutModeChanged(Box::new(
+            move |window, layout_mode| {
+                changes_clone
+                    .lock()
+                    .unwrap()
+                    .push((window.id(), layout_mode));
+            },
+        )));
+    });
 
-        assert_eq!(window_tags, target.with_state(|state| state.tags.clone()));
-        assert_eq!(target_tags, window.with_state(|state| state.tags.clone()));
-    })
-}
+    // Let the signal stream finish connecting
+    fixture.dispatch_for(Duration::from_millis(500));
 
-#[test_log::test]
-fn window_handle_swap_swap_inactive_tag() {
-    for_each_api(|lang| {
-        let (mut fixture, output) = set_up();
+    let id = fixture.pinnacle().windows[0].with_state(|state| state.id.0);
 
-        let tag = Tag::new("2".to_string());
-        tag.set_active(false);
-        output.with_state_mut(|state| {
-            state.add_tags([tag.clone()]);
-        });
+    fixture.spawn_blocking(|| {
+        let window = pinnacle_api::window::get_all().next().unwrap();
+        window.set_floating(true);
+        // Setting the same mode again is a no-op
+        window.set_floating(true);
+    });
 
-        let id = fixture.add_client();
+    fixture.dispatch_until(|_| !changes.lock().unwrap().is_empty());
+    fixture.dispatch_for(Duration::from_millis(200));
 
-        let _surfaces = fixture.spawn_windows(4, id);
+    assert_eq!(
+        *changes.lock().unwrap(),
+        [(id, pinnacle_api::window::LayoutMode::Floating)]
+    );
+}
 
-        let window = fixture.pinnacle().windows[0].clone();
-        let target = fixture.pinnacle().windows[2].clone();
-        target.with_state_mut(|state| state.tags = std::iter::once(tag.clone()).collect());
+#[test_log::test]
+fn window_signal_geometry_changed_carries_new_geometry() {
+    let (mut fixture, _) = set_up();
 
-        let window_tags = window.with_state(|state| state.tags.clone());
-        let target_tags = target.with_state(|state| state.tags.clone());
+    let client_id = fixture.add_client();
+    fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
 
-        let window_id = window.with_state(|s| s.id.0);
-        let target_id = target.with_state(|s| s.id.0);
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_clone = changes.clone();
 
-        match lang {
-            Lang::Rust => fixture.spawn_blocking(move || {
-                let window_handle = pinnacle_api::window::WindowHandle::from_id(window_id);
-                let target_handle = pinnacle_api::window::WindowHandle::from_id(target_id);
+    fixture.spawn_blocking(move || {
+        pinnacle_api::window::connect_signal(WindowSignal::GeometryChanged(Box::new(
+            move |window, geometry| {
+                changes_clone.lock().unwrap().push((window.id(), geometry));
+            },
+        )));
+    });
 
-                window_handle.swap(&target_handle);
-            }),
-            Lang::Lua => spawn_lua_blocking! {
-                fixture,
-                local window_handle = Window.handle.new($window_id)
-                local target_handle = Window.handle.new($target_id)
+    // Let the signal stream finish connecting
+    fixture.dispatch_for(Duration::from_millis(500));
 
-                window_handle:swap(target_handle);
-            },
-        };
+    let id = fixture.pinnacle().windows[0].with_state(|state| state.id.0);
 
-        fixture.wait_client_configure(id);
-        fixture.flush();
+    fixture.spawn_blocking(|| {
+        pinnacle_api::window::get_focused()
+            .unwrap()
+            .set_geometry(200, 300, 1000, 800);
+    });
 
-        assert_eq!(window_tags, target.with_state(|state| state.tags.clone()));
+    fixture.wait_client_configure(client_id);
+    fixture.flush();
 
-        assert_eq!(target_tags, window.with_state(|state| state.tags.clone()));
-    })
+    fixture.dispatch_until(|_| !changes.lock().unwrap().is_empty());
+
+    assert_eq!(
+        changes.lock().unwrap().last().copied(),
+        Some((
+            id,
+            pinnacle_api::util::Rect {
+                loc: pinnacle_api::util::Point { x: 200, y: 300 },
+                size: pinnacle_api::util::Size { w: 1000, h: 800 },
+            }
+        ))
+    );
 }
 
+// TODO: window_begin_move
+// TODO: window_begin_resize
+// TODO: window_add_window_rule
+
 #[test_log::test]
-fn window_handle_swap_swap_inactive_tag_multi_output() {
+fn window_handle_set_preferred_scale() {
     for_each_api(|lang| {
-        let (mut fixture, _output1) = set_up();
+        let (mut fixture, _) = set_up();
 
         let client_id = fixture.add_client();
+        let surface = fixture.spawn_floating_window_with(client_id, (500, 500), |_| ());
 
-        let mut surfaces = fixture.spawn_windows(1, client_id);
-
-        let output2 = fixture.add_output(Rectangle::new((1920, 0).into(), (1920, 1080).into()));
-        let inactive_tag = Tag::new("3".to_string());
-        output2.with_state_mut(|state| {
-            let tag = Tag::new("2".to_string());
-            tag.set_active(true);
-            state.add_tags([tag, inactive_tag.clone()]);
-        });
-
-        fixture.pinnacle().focus_output(&output2);
-
-        surfaces.append(&mut fixture.spawn_windows(1, client_id));
+        let scale = fixture.client(client_id).fractional_scale(&surface);
+        fixture.roundtrip(client_id);
 
-        let window = fixture.pinnacle().windows[0].clone();
-        let target = fixture.pinnacle().windows[1].clone();
-        target.with_state_mut(|state| state.tags = std::iter::once(inactive_tag.clone()).collect());
+        assert_eq!(scale.load(Ordering::Relaxed), 120);
 
-        let window_id = window.with_state(|s| s.id.0);
-        let target_id = target.with_state(|s| s.id.0);
+        match lang {
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_preferred_scale(2.0);
+            }),
+            Lang::Lua => spawn_lua_blocking! {
+                fixture,
+                Window.get_focused():set_preferred_scale(2.0)
+            },
+        }
 
-        let window_tags = window.with_state(|state| state.tags.clone());
-        let target_tags = target.with_state(|state| state.tags.clone());
+        fixture.roundtrip(client_id);
+        assert_eq!(scale.load(Ordering::Relaxed), 240);
 
         match lang {
-            Lang::Rust => fixture.spawn_blocking(move || {
-                let window_handle = pinnacle_api::window::WindowHandle::from_id(window_id);
-                let target_handle = pinnacle_api::window::WindowHandle::from_id(target_id);
-
-                window_handle.swap(&target_handle);
+            Lang::Rust => fixture.spawn_blocking(|| {
+                pinnacle_api::window::get_focused()
+                    .unwrap()
+                    .set_preferred_scale(None);
             }),
             Lang::Lua => spawn_lua_blocking! {
                 fixture,
-                local window_handle = Window.handle.new($window_id)
-                local target_handle = Window.handle.new($target_id)
-
-                window_handle:swap(target_handle)
+                Window.get_focused():set_preferred_scale(nil)
             },
-        };
-
-        fixture.dispatch_until(|fixture| !fixture.pinnacle().layout_state.pending_swap);
+        }
 
-        assert_eq!(window_tags, target.with_state(|state| state.tags.clone()));
-        assert_eq!(target_tags, window.with_state(|state| state.tags.clone()));
-    })
+        fixture.roundtrip(client_id);
+        assert_eq!(scale.load(Ordering::Relaxed), 120);
+    });
 }
-
-// TODO: window_begin_move
-// TODO: window_begin_resize
-// TODO: window_connect_signal
-// TODO: window_add_window_rule
@@ -17,30 +17,66 @@ use smithay::reexports::{
     calloop::EventLoop,
     wayland_protocols::{
         wp::{
+            fractional_scale::v1::client::{
+                wp_fractional_scale_manager_v1::WpFractionalScaleManagerV1,
+                wp_fractional_scale_v1::{self, WpFractionalScaleV1},
+            },
+            keyboard_shortcuts_inhibit::zv1::client::{
+                zwp_keyboard_shortcuts_inhibit_manager_v1::ZwpKeyboardShortcutsInhibitManagerV1,
+                zwp_keyboard_shortcuts_inhibitor_v1::{self, ZwpKeyboardShortcutsInhibitorV1},
+            },
+            pointer_constraints::zv1::client::{
+                zwp_locked_pointer_v1::{self, ZwpLockedPointerV1},
+                zwp_pointer_constraints_v1::{self, ZwpPointerConstraintsV1},
+            },
+            relative_pointer::zv1::client::{
+                zwp_relative_pointer_manager_v1::ZwpRelativePointerManagerV1,
+                zwp_relative_pointer_v1::{self, ZwpRelativePointerV1},
+            },
             single_pixel_buffer::v1::client::wp_single_pixel_buffer_manager_v1::WpSinglePixelBufferManagerV1,
             viewporter::client::{wp_viewport::WpViewport, wp_viewporter::WpViewporter},
         },
-        xdg::shell::client::{
-            xdg_surface::{self, XdgSurface},
-            xdg_toplevel::{self, XdgToplevel},
-            xdg_wm_base::{self, XdgWmBase},
+        xdg::{
+            decoration::zv1::client::{
+                zxdg_decoration_manager_v1::ZxdgDecorationManagerV1,
+                zxdg_toplevel_decoration_v1::{self, ZxdgToplevelDecorationV1},
+            },
+            shell::client::{
+                xdg_surface::{self, XdgSurface},
+                xdg_toplevel::{self, XdgToplevel},
+                xdg_wm_base::{self, XdgWmBase},
+            },
+        },
+    },
+    wayland_protocols_wlr::{
+        foreign_toplevel::v1::client::{
+            zwlr_foreign_toplevel_handle_v1::{self, ZwlrForeignToplevelHandleV1},
+            zwlr_foreign_toplevel_manager_v1::{self, ZwlrForeignToplevelManagerV1},
+        },
+        layer_shell::v1::client::{
+            zwlr_layer_shell_v1::{self, ZwlrLayerShellV1},
+            zwlr_layer_surface_v1::{self, ZwlrLayerSurfaceV1},
         },
     },
 };
 use tracing::debug;
 use wayland_client::{
-    Connection, Dispatch, Proxy, QueueHandle, delegate_noop,
+    Connection, Dispatch, Proxy, QueueHandle, WEnum, delegate_noop,
     globals::GlobalListContents,
     protocol::{
         wl_buffer::WlBuffer,
         wl_callback::{self, WlCallback},
         wl_compositor::WlCompositor,
         wl_display::WlDisplay,
+        wl_keyboard::{self, WlKeyboard},
         wl_output::WlOutput,
+        wl_pointer::{self, WlPointer},
         wl_registry::{self, WlRegistry},
+        wl_seat::{self, WlSeat},
         wl_surface::WlSurface,
     },
 };
+use xkbcommon::xkb;
 
 pub struct Client {
     id: ClientId,
@@ -67,8 +103,28 @@ struct State {
     xdg_wm_base: Option<XdgWmBase>,
     single_pixel_buffer: Option<WpSinglePixelBufferManagerV1>,
     viewporter: Option<WpViewporter>,
+    shortcuts_inhibit_manager: Option<ZwpKeyboardShortcutsInhibitManagerV1>,
+    pointer_constraints: Option<ZwpPointerConstraintsV1>,
+    relative_pointer_manager: Option<ZwpRelativePointerManagerV1>,
+    fractional_scale_manager: Option<WpFractionalScaleManagerV1>,
+    decoration_manager: Option<ZxdgDecorationManagerV1>,
+    layer_shell: Option<ZwlrLayerShellV1>,
+    foreign_toplevel_manager: Option<ZwlrForeignToplevelManagerV1>,
+    /// App ids last reported for each foreign toplevel handle.
+    foreign_toplevels: Vec<(ZwlrForeignToplevelHandleV1, Option<String>)>,
+    seat: Option<WlSeat>,
     windows: Vec<Window>,
+    layers: Vec<LayerSurface>,
     outputs: Vec<WlOutput>,
+    keyboard: Option<WlKeyboard>,
+    xkb_state: Option<xkb::State>,
+    /// Text received through key presses.
+    typed_text: String,
+    pointer: Option<WlPointer>,
+    /// Buttons received through pointer button presses.
+    pressed_buttons: Vec<u32>,
+    /// Accelerated and unaccelerated deltas received through relative pointer motion.
+    relative_motions: Vec<((f64, f64), (f64, f64))>,
 }
 
 pub struct Window {
@@ -78,6 +134,8 @@ pub struct Window {
     toplevel: XdgToplevel,
     viewport: WpViewport,
     single_pixel_buffer: WpSinglePixelBufferManagerV1,
+    decoration_manager: Option<ZxdgDecorationManagerV1>,
+    decoration: Option<ZxdgToplevelDecorationV1>,
 
     current_configure_serial: Option<u32>,
     pending_configure: PendingConfigure,
@@ -88,6 +146,9 @@ pub struct Window {
 
 impl Drop for Window {
     fn drop(&mut self) {
+        if let Some(decoration) = self.decoration.take() {
+            decoration.destroy();
+        }
         self.toplevel.destroy();
         self.xdg_surface.destroy();
         self.viewport.destroy();
@@ -95,6 +156,22 @@ impl Drop for Window {
     }
 }
 
+pub struct LayerSurface {
+    qh: QueueHandle<State>,
+    wl_surface: WlSurface,
+    layer_surface: ZwlrLayerSurfaceV1,
+    single_pixel_buffer: WpSinglePixelBufferManagerV1,
+
+    current_configure_serial: Option<u32>,
+}
+
+impl Drop for LayerSurface {
+    fn drop(&mut self) {
+        self.layer_surface.destroy();
+        self.wl_surface.destroy();
+    }
+}
+
 #[derive(Default, Debug)]
 struct PendingConfigure {
     size: Option<(i32, i32)>,
@@ -128,8 +205,24 @@ impl Client {
             xdg_wm_base: None,
             single_pixel_buffer: None,
             viewporter: None,
+            shortcuts_inhibit_manager: None,
+            pointer_constraints: None,
+            relative_pointer_manager: None,
+            fractional_scale_manager: None,
+            decoration_manager: None,
+            layer_shell: None,
+            foreign_toplevel_manager: None,
+            foreign_toplevels: Vec::new(),
+            seat: None,
             windows: Vec::new(),
+            layers: Vec::new(),
             outputs: Vec::new(),
+            keyboard: None,
+            xkb_state: None,
+            typed_text: String::new(),
+            pointer: None,
+            pressed_buttons: Vec::new(),
+            relative_motions: Vec::new(),
         };
 
         Self {
@@ -195,9 +288,130 @@ impl Client {
         self.state.windows.retain(|win| &win.surface() != surface);
     }
 
+    /// Creates a layer surface on the focused output with the given keyboard interactivity.
+    pub fn create_layer(
+        &mut self,
+        layer: zwlr_layer_shell_v1::Layer,
+        keyboard_interactivity: zwlr_layer_surface_v1::KeyboardInteractivity,
+    ) -> &mut LayerSurface {
+        self.state.create_layer(layer, keyboard_interactivity)
+    }
+
+    pub fn layer_for_surface(&mut self, surface: &WlSurface) -> &mut LayerSurface {
+        self.state
+            .layers
+            .iter_mut()
+            .find(|layer| &layer.wl_surface == surface)
+            .unwrap()
+    }
+
+    pub fn close_layer(&mut self, surface: &WlSurface) {
+        self.state
+            .layers
+            .retain(|layer| &layer.surface() != surface);
+    }
+
     pub fn wl_outputs(&self) -> &Vec<WlOutput> {
         &self.state.outputs
     }
+
+    /// Gets the text this client has received through key presses.
+    pub fn typed_text(&self) -> &str {
+        &self.state.typed_text
+    }
+
+    /// Gets the mouse buttons this client has received presses for, in order.
+    pub fn pressed_buttons(&self) -> &[u32] {
+        &self.state.pressed_buttons
+    }
+
+    /// Gets the app ids reported through wlr-foreign-toplevel-management
+    /// for every toplevel this client knows about.
+    pub fn foreign_toplevel_app_ids(&self) -> Vec<Option<&str>> {
+        self.state
+            .foreign_toplevels
+            .iter()
+            .map(|(_, app_id)| app_id.as_deref())
+            .collect()
+    }
+
+    /// Asks the compositor to inhibit keyboard shortcuts for the given surface.
+    ///
+    /// Returns a flag that is set while the inhibitor is active.
+    pub fn inhibit_shortcuts(&mut self, surface: &WlSurface) -> Arc<AtomicBool> {
+        let active = Arc::new(AtomicBool::new(false));
+        self.state
+            .shortcuts_inhibit_manager
+            .as_ref()
+            .unwrap()
+            .inhibit_shortcuts(
+                surface,
+                self.state.seat.as_ref().unwrap(),
+                &self.state.qh,
+                active.clone(),
+            );
+        active
+    }
+
+    /// Locks the pointer to the given surface.
+    ///
+    /// Returns a flag that is set while the lock is active.
+    pub fn lock_pointer(&mut self, surface: &WlSurface) -> Arc<AtomicBool> {
+        let locked = Arc::new(AtomicBool::new(false));
+        self.state
+            .pointer_constraints
+            .as_ref()
+            .unwrap()
+            .lock_pointer(
+                surface,
+                self.state.pointer.as_ref().unwrap(),
+                None,
+                zwp_pointer_constraints_v1::Lifetime::Persistent,
+                &self.state.qh,
+                locked.clone(),
+            );
+        surface.commit();
+        locked
+    }
+
+    /// Starts recording relative pointer motion, which is returned by [`Self::relative_motions`].
+    pub fn track_relative_motion(&mut self) {
+        self.state
+            .relative_pointer_manager
+            .as_ref()
+            .unwrap()
+            .get_relative_pointer(self.state.pointer.as_ref().unwrap(), &self.state.qh, ());
+    }
+
+    /// Gets the accelerated and unaccelerated deltas of relative pointer motion
+    /// received since [`Self::track_relative_motion`].
+    pub fn relative_motions(&self) -> &[((f64, f64), (f64, f64))] {
+        &self.state.relative_motions
+    }
+
+    /// Requests a frame callback for the given surface and commits it.
+    ///
+    /// Returns a flag that is set once the callback is done.
+    pub fn request_frame(&mut self, surface: &WlSurface) -> Arc<AtomicBool> {
+        let done = Arc::new(AtomicBool::new(false));
+        surface.frame(&self.state.qh, done.clone());
+        surface.commit();
+        self.state.conn.flush().unwrap();
+        done
+    }
+
+    /// Gets a fractional scale object for the given surface.
+    ///
+    /// Returns the preferred scale the compositor sends, in 120ths. This is 0 until one is sent.
+    pub fn fractional_scale(&mut self, surface: &WlSurface) -> Arc<AtomicU32> {
+        let scale = Arc::new(AtomicU32::new(0));
+        self.state
+            .fractional_scale_manager
+            .as_ref()
+            .unwrap()
+            .get_fractional_scale(surface, &self.state.qh, scale.clone());
+        scale
+    }
 }
 
 impl State {
@@ -222,6 +436,8 @@ impl State {
         let window = Window {
             qh: self.qh.clone(),
             single_pixel_buffer: self.single_pixel_buffer.clone().unwrap(),
+            decoration_manager: self.decoration_manager.clone(),
+            decoration: None,
             wl_surface,
             xdg_surface,
             toplevel,
@@ -237,6 +453,39 @@ impl State {
         self.windows.last_mut().unwrap()
     }
 
+    fn create_layer(
+        &mut self,
+        layer: zwlr_layer_shell_v1::Layer,
+        keyboard_interactivity: zwlr_layer_surface_v1::KeyboardInteractivity,
+    ) -> &mut LayerSurface {
+        let wl_surface = self
+            .compositor
+            .as_ref()
+            .unwrap()
+            .create_surface(&self.qh, ());
+        let layer_surface = self.layer_shell.as_ref().unwrap().get_layer_surface(
+            &wl_surface,
+            None,
+            layer,
+            "test".to_string(),
+            &self.qh,
+            (),
+        );
+        layer_surface.set_size(100, 100);
+        layer_surface.set_keyboard_interactivity(keyboard_interactivity);
+
+        let layer = LayerSurface {
+            qh: self.qh.clone(),
+            single_pixel_buffer: self.single_pixel_buffer.clone().unwrap(),
+            wl_surface,
+            layer_surface,
+            current_configure_serial: None,
+        };
+
+        self.layers.push(layer);
+        self.layers.last_mut().unwrap()
+    }
+
     fn send_sync(&self) -> Arc<AtomicBool> {
         let wait = Arc::new(AtomicBool::new(false));
         self.display.sync(&self.qh, wait.clone());
@@ -310,6 +559,67 @@ impl Window {
     pub fn unset_maximized(&self) {
         self.toplevel.unset_maximized();
     }
+
+    /// Asks the compositor to use the given decoration mode for this window.
+    ///
+    /// Returns the decoration mode the compositor configures. This is 0 until one is sent.
+    pub fn request_decoration_mode(
+        &mut self,
+        mode: zxdg_toplevel_decoration_v1::Mode,
+    ) -> Arc<AtomicU32> {
+        let configured_mode = Arc::new(AtomicU32::new(0));
+        let decoration = self
+            .decoration_manager
+            .as_ref()
+            .unwrap()
+            .get_toplevel_decoration(&self.toplevel, &self.qh, configured_mode.clone());
+        decoration.set_mode(mode);
+        self.decoration = Some(decoration);
+        configured_mode
+    }
+}
+
+impl LayerSurface {
+    pub fn surface(&self) -> WlSurface {
+        self.wl_surface.clone()
+    }
+
+    pub fn commit(&self) {
+        self.wl_surface.commit();
+    }
+
+    pub fn ack_and_commit(&mut self) {
+        if let Some(current_configure_serial) = self.current_configure_serial.take() {
+            self.layer_surface.ack_configure(current_configure_serial);
+        }
+
+        self.commit();
+    }
+
+    pub fn attach_buffer(&self) {
+        let buffer =
+            self.single_pixel_buffer
+                .create_u32_rgba_buffer(0, 0, 0, u32::MAX, &self.qh, ());
+        self.wl_surface.attach(Some(&buffer), 0, 0);
+    }
+
+    /// Attaches a null buffer, unmapping this layer surface on the next commit.
+    pub fn detach_buffer(&self) {
+        self.wl_surface.attach(None, 0, 0);
+    }
+
+    /// Anchors this layer surface to the given edges and reserves `exclusive_zone`
+    /// pixels from the anchored edge.
+    pub fn set_exclusive_zone(
+        &self,
+        anchor: zwlr_layer_surface_v1::Anchor,
+        size: (u32, u32),
+        exclusive_zone: i32,
+    ) {
+        self.layer_surface.set_anchor(anchor);
+        self.layer_surface.set_size(size.0, size.1);
+        self.layer_surface.set_exclusive_zone(exclusive_zone);
+    }
 }
 
 impl Dispatch<WlRegistry, ()> for State {
@@ -340,6 +650,40 @@ impl Dispatch<WlRegistry, ()> for State {
                 } else if interface == WpViewporter::interface().name {
                     let version = u32::min(version, WpViewporter::interface().version);
                     state.viewporter = Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == ZwpKeyboardShortcutsInhibitManagerV1::interface().name {
+                    let version = u32::min(
+                        version,
+                        ZwpKeyboardShortcutsInhibitManagerV1::interface().version,
+                    );
+                    state.shortcuts_inhibit_manager =
+                        Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == ZwpPointerConstraintsV1::interface().name {
+                    let version = u32::min(version, ZwpPointerConstraintsV1::interface().version);
+                    state.pointer_constraints = Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == ZwpRelativePointerManagerV1::interface().name {
+                    let version =
+                        u32::min(version, ZwpRelativePointerManagerV1::interface().version);
+                    state.relative_pointer_manager =
+                        Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == WpFractionalScaleManagerV1::interface().name {
+                    let version =
+                        u32::min(version, WpFractionalScaleManagerV1::interface().version);
+                    state.fractional_scale_manager =
+                        Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == ZxdgDecorationManagerV1::interface().name {
+                    let version = u32::min(version, ZxdgDecorationManagerV1::interface().version);
+                    state.decoration_manager = Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == ZwlrLayerShellV1::interface().name {
+                    let version = u32::min(version, ZwlrLayerShellV1::interface().version);
+                    state.layer_shell = Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == ZwlrForeignToplevelManagerV1::interface().name {
+                    let version =
+                        u32::min(version, ZwlrForeignToplevelManagerV1::interface().version);
+                    state.foreign_toplevel_manager =
+                        Some(registry.bind(name, version, qhandle, ()));
+                } else if interface == WlSeat::interface().name {
+                    let version = u32::min(version, WlSeat::interface().version);
+                    state.seat = Some(registry.bind(name, version, qhandle, ()));
                 } else if interface == WlOutput::interface().name {
                     let version = u32::min(version, WlOutput::interface().version);
                     state
@@ -499,7 +843,282 @@ impl Dispatch<XdgToplevel, ()> for State {
     }
 }
 
+impl Dispatch<ZwlrLayerSurfaceV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        proxy: &ZwlrLayerSurfaceV1,
+        event: <ZwlrLayerSurfaceV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let Some(layer) = state
+            .layers
+            .iter_mut()
+            .find(|layer| &layer.layer_surface == proxy)
+        else {
+            return;
+        };
+
+        match event {
+            zwlr_layer_surface_v1::Event::Configure { serial, .. } => {
+                layer.current_configure_serial = Some(serial);
+            }
+            zwlr_layer_surface_v1::Event::Closed => (),
+            _ => panic!(),
+        }
+    }
+}
+
+impl Dispatch<WlSeat, ()> for State {
+    fn event(
+        state: &mut Self,
+        proxy: &WlSeat,
+        event: <WlSeat as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        qhandle: &QueueHandle<Self>,
+    ) {
+        if let wl_seat::Event::Capabilities {
+            capabilities: WEnum::Value(capabilities),
+        } = event
+        {
+            if capabilities.contains(wl_seat::Capability::Keyboard) && state.keyboard.is_none() {
+                state.keyboard = Some(proxy.get_keyboard(qhandle, ()));
+            }
+            if capabilities.contains(wl_seat::Capability::Pointer) && state.pointer.is_none() {
+                state.pointer = Some(proxy.get_pointer(qhandle, ()));
+            }
+        }
+    }
+}
+
+impl Dispatch<WlPointer, ()> for State {
+    fn event(
+        state: &mut Self,
+        _proxy: &WlPointer,
+        event: <WlPointer as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        if let wl_pointer::Event::Button {
+            button,
+            state: WEnum::Value(wl_pointer::ButtonState::Pressed),
+            ..
+        } = event
+        {
+            state.pressed_buttons.push(button);
+        }
+    }
+}
+
+impl Dispatch<WlKeyboard, ()> for State {
+    fn event(
+        state: &mut Self,
+        _proxy: &WlKeyboard,
+        event: <WlKeyboard as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        match event {
+            wl_keyboard::Event::Keymap {
+                format: _,
+                fd,
+                size,
+            } => {
+                let context = xkb::Context::new(xkb::CONTEXT_NO_FLAGS);
+                // SAFETY: The compositor sends a valid keymap fd of the given size
+                let keymap = unsafe {
+                    xkb::Keymap::new_from_fd(
+                        &context,
+                        fd,
+                        size as usize,
+                        xkb::KEYMAP_FORMAT_TEXT_V1,
+                        xkb::COMPILE_NO_FLAGS,
+                    )
+                };
+                state.xkb_state = keymap.ok().flatten().map(|keymap| xkb::State::new(&keymap));
+            }
+            wl_keyboard::Event::Key {
+                key,
+                state: WEnum::Value(wl_keyboard::KeyState::Pressed),
+                ..
+            } => {
+                if let Some(xkb_state) = state.xkb_state.as_ref() {
+                    // Wayland key codes are evdev codes, xkb's are offset by 8
+                    let text = xkb_state.key_get_utf8(xkb::Keycode::new(key + 8));
+                    state.typed_text.push_str(&text);
+                }
+            }
+            wl_keyboard::Event::Modifiers {
+                mods_depressed,
+                mods_latched,
+                mods_locked,
+                group,
+                ..
+            } => {
+                if let Some(xkb_state) = state.xkb_state.as_mut() {
+                    xkb_state.update_mask(mods_depressed, mods_latched, mods_locked, 0, 0, group);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+impl Dispatch<ZwpKeyboardShortcutsInhibitorV1, Arc<AtomicBool>> for State {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZwpKeyboardShortcutsInhibitorV1,
+        event: <ZwpKeyboardShortcutsInhibitorV1 as wayland_client::Proxy>::Event,
+        data: &Arc<AtomicBool>,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        match event {
+            zwp_keyboard_shortcuts_inhibitor_v1::Event::Active => {
+                data.store(true, Ordering::Relaxed)
+            }
+            zwp_keyboard_shortcuts_inhibitor_v1::Event::Inactive => {
+                data.store(false, Ordering::Relaxed)
+            }
+            _ => (),
+        }
+    }
+}
+
+impl Dispatch<ZwpLockedPointerV1, Arc<AtomicBool>> for State {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZwpLockedPointerV1,
+        event: <ZwpLockedPointerV1 as wayland_client::Proxy>::Event,
+        data: &Arc<AtomicBool>,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        match event {
+            zwp_locked_pointer_v1::Event::Locked => data.store(true, Ordering::Relaxed),
+            zwp_locked_pointer_v1::Event::Unlocked => data.store(false, Ordering::Relaxed),
+            _ => (),
+        }
+    }
+}
+
+impl Dispatch<ZwpRelativePointerV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        _proxy: &ZwpRelativePointerV1,
+        event: <ZwpRelativePointerV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        if let zwp_relative_pointer_v1::Event::RelativeMotion {
+            dx,
+            dy,
+            dx_unaccel,
+            dy_unaccel,
+            ..
+        } = event
+        {
+            state
+                .relative_motions
+                .push(((dx, dy), (dx_unaccel, dy_unaccel)));
+        }
+    }
+}
+
+impl Dispatch<WpFractionalScaleV1, Arc<AtomicU32>> for State {
+    fn event(
+        _state: &mut Self,
+        _proxy: &WpFractionalScaleV1,
+        event: <WpFractionalScaleV1 as wayland_client::Proxy>::Event,
+        data: &Arc<AtomicU32>,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        if let wp_fractional_scale_v1::Event::PreferredScale { scale } = event {
+            data.store(scale, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Dispatch<ZxdgToplevelDecorationV1, Arc<AtomicU32>> for State {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZxdgToplevelDecorationV1,
+        event: <ZxdgToplevelDecorationV1 as wayland_client::Proxy>::Event,
+        data: &Arc<AtomicU32>,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        if let zxdg_toplevel_decoration_v1::Event::Configure {
+            mode: WEnum::Value(mode),
+        } = event
+        {
+            data.store(mode.into(), Ordering::Relaxed);
+        }
+    }
+}
+
+impl Dispatch<ZwlrForeignToplevelManagerV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        _proxy: &ZwlrForeignToplevelManagerV1,
+        event: <ZwlrForeignToplevelManagerV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        if let zwlr_foreign_toplevel_manager_v1::Event::Toplevel { toplevel } = event {
+            state.foreign_toplevels.push((toplevel, None));
+        }
+    }
+
+    wayland_client::event_created_child!(State, ZwlrForeignToplevelManagerV1, [
+        zwlr_foreign_toplevel_manager_v1::EVT_TOPLEVEL_OPCODE => (ZwlrForeignToplevelHandleV1, ()),
+    ]);
+}
+
+impl Dispatch<ZwlrForeignToplevelHandleV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        proxy: &ZwlrForeignToplevelHandleV1,
+        event: <ZwlrForeignToplevelHandleV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        match event {
+            zwlr_foreign_toplevel_handle_v1::Event::AppId { app_id } => {
+                if let Some((_, id)) = state
+                    .foreign_toplevels
+                    .iter_mut()
+                    .find(|(handle, _)| handle == proxy)
+                {
+                    *id = Some(app_id);
+                }
+            }
+            zwlr_foreign_toplevel_handle_v1::Event::Closed => {
+                state
+                    .foreign_toplevels
+                    .retain(|(handle, _)| handle != proxy);
+                proxy.destroy();
+            }
+            _ => (),
+        }
+    }
+}
+
 delegate_noop!(State: WlCompositor);
+delegate_noop!(State: ZxdgDecorationManagerV1);
+delegate_noop!(State: WpFractionalScaleManagerV1);
+delegate_noop!(State: ZwlrLayerShellV1);
+delegate_noop!(State: ZwpKeyboardShortcutsInhibitManagerV1);
+delegate_noop!(State: ZwpPointerConstraintsV1);
+delegate_noop!(State: ZwpRelativePointerManagerV1);
 delegate_noop!(State: WpSinglePixelBufferManagerV1);
 delegate_noop!(State: WpViewporter);
 delegate_noop!(State: WpViewport);
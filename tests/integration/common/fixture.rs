@@ -2,7 +2,7 @@ use std::{
     os::fd::AsFd,
     sync::{
         Arc, Mutex, MutexGuard,
-        atomic::{AtomicU32, Ordering},
+        atomic::{AtomicBool, AtomicU32, Ordering},
     },
     time::Duration,
 };
@@ -11,7 +11,7 @@ use pinnacle::state::{ClientState, Pinnacle};
 use smithay::{
     output::Output,
     reexports::calloop::{EventLoop, Interest, Mode, PostAction, generic::Generic},
-    utils::{Logical, Rectangle, Transform},
+    utils::{Logical, Raw, Rectangle, Size, Transform},
 };
 use tracing::debug;
 use wayland_client::protocol::wl_surface::WlSurface;
@@ -115,6 +115,15 @@ impl Fixture {
     }
 
     pub fn add_output(&mut self, geo: Rectangle<i32, Logical>) -> Output {
+        self.add_output_with_physical_size(geo, (0, 0).into())
+    }
+
+    /// Adds an output that reports the given physical size in millimeters.
+    pub fn add_output_with_physical_size(
+        &mut self,
+        geo: Rectangle<i32, Logical>,
+        physical_size: Size<i32, Raw>,
+    ) -> Output {
         let name = format!(
             "pinnacle-{}",
             OUTPUT_COUNTER.fetch_add(1, Ordering::Relaxed)
@@ -125,12 +134,24 @@ impl Fixture {
             "",
             geo.loc,
             geo.size.to_physical(1),
+            physical_size,
             60000,
             1.0,
             Transform::Normal,
         )
     }
 
+    /// Starts Xwayland and dispatches until its wm is running.
+    ///
+    /// Returns the X11 display name.
+    pub fn start_xwayland(&mut self) -> String {
+        self.pinnacle()
+            .insert_xwayland_source(Arc::new(AtomicBool::new(false)))
+            .unwrap();
+        self.dispatch_until(|fixture| fixture.pinnacle().xwayland_state.is_some());
+        std::env::var("DISPLAY").unwrap()
+    }
+
     pub fn state(&mut self) -> &mut pinnacle::state::State {
         &mut self.state.server.state
     }
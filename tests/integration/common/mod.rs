@@ -1,4 +1,8 @@
 use mlua::{Lua, Variadic};
+use smithay::backend::{
+    egl::{EGLContext, EGLDevice, EGLDisplay},
+    renderer::gles::GlesRenderer,
+};
 
 pub mod client;
 pub mod fixture;
@@ -15,6 +19,17 @@ pub fn for_each_api(mut test: impl FnMut(Lang)) {
     test(Lang::Lua);
 }
 
+/// Creates a surfaceless renderer on the first EGL device for tests that need real rendering.
+pub fn gles_renderer() -> GlesRenderer {
+    let device = EGLDevice::enumerate()
+        .expect("failed to enumerate EGL devices")
+        .next()
+        .expect("no EGL devices");
+    let display = unsafe { EGLDisplay::new(device) }.expect("failed to create EGL display");
+    let context = EGLContext::new(&display).expect("failed to create EGL context");
+    unsafe { GlesRenderer::new(context) }.expect("failed to create renderer")
+}
+
 pub fn new_lua() -> Lua {
     let lua = unsafe { Lua::unsafe_new() };
 
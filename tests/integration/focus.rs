@@ -1,7 +1,14 @@
 use crate::common::fixture::Fixture;
 use pinnacle::{focus::keyboard::KeyboardFocusTarget, state::WithState, tag::Tag};
 use pinnacle_api::layout::{LayoutGenerator, generators::MasterStack};
-use smithay::{output::Output, utils::Rectangle};
+use smithay::{
+    desktop::layer_map_for_output,
+    output::Output,
+    reexports::wayland_protocols_wlr::layer_shell::v1::client::{
+        zwlr_layer_shell_v1::Layer, zwlr_layer_surface_v1::KeyboardInteractivity,
+    },
+    utils::Rectangle,
+};
 use test_log::test;
 
 fn set_up() -> (Fixture, Output, Output) {
@@ -117,3 +124,84 @@ fn keyboard_focus() {
         ))
     );
 }
+
+#[test]
+fn keyboard_focus_returns_to_window_after_layer_closes() {
+    let (mut fixture, op1, _) = set_up();
+
+    let client_id = fixture.add_client();
+    fixture.spawn_floating_window_with(client_id, (50, 50), |_| ());
+    let window = fixture.pinnacle().windows[0].clone();
+
+    let keyboard = fixture.pinnacle().seat.get_keyboard().unwrap();
+    assert_eq!(
+        keyboard.current_focus(),
+        Some(KeyboardFocusTarget::Window(window.clone()))
+    );
+
+    // An exclusive layer grabs the keyboard while it exists
+    let layer = fixture
+        .client(client_id)
+        .create_layer(Layer::Overlay, KeyboardInteractivity::Exclusive);
+    let exclusive_surface = layer.surface();
+    layer.commit();
+    fixture.roundtrip(client_id);
+
+    let layer = fixture
+        .client(client_id)
+        .layer_for_surface(&exclusive_surface);
+    layer.attach_buffer();
+    layer.ack_and_commit();
+    fixture.roundtrip(client_id);
+
+    fixture.dispatch_until(|_| {
+        matches!(
+            keyboard.current_focus(),
+            Some(KeyboardFocusTarget::LayerSurface(_))
+        )
+    });
+
+    fixture.client(client_id).close_layer(&exclusive_surface);
+    fixture.roundtrip(client_id);
+
+    fixture.dispatch_until(|_| {
+        keyboard.current_focus() == Some(KeyboardFocusTarget::Window(window.clone()))
+    });
+
+    // An on-demand layer keeps focus after being clicked until it unmaps
+    let layer = fixture
+        .client(client_id)
+        .create_layer(Layer::Top, KeyboardInteractivity::OnDemand);
+    let on_demand_surface = layer.surface();
+    layer.commit();
+    fixture.roundtrip(client_id);
+
+    let layer = fixture
+        .client(client_id)
+        .layer_for_surface(&on_demand_surface);
+    layer.attach_buffer();
+    layer.ack_and_commit();
+    fixture.roundtrip(client_id);
+
+    let desktop_layer = layer_map_for_output(&op1).layers().next().cloned().unwrap();
+    fixture.pinnacle().on_demand_layer_focus = Some(desktop_layer);
+
+    fixture.dispatch_until(|_| {
+        matches!(
+            keyboard.current_focus(),
+            Some(KeyboardFocusTarget::LayerSurface(_))
+        )
+    });
+
+    let layer = fixture
+        .client(client_id)
+        .layer_for_surface(&on_demand_surface);
+    layer.detach_buffer();
+    layer.commit();
+    fixture.roundtrip(client_id);
+
+    fixture.dispatch_until(|_| {
+        keyboard.current_focus() == Some(KeyboardFocusTarget::Window(window.clone()))
+    });
+    assert!(fixture.pinnacle().on_demand_layer_focus.is_none());
+}
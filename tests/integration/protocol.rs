@@ -1 +1,2 @@
+mod keyboard_shortcuts_inhibit;
 mod xdg_shell;
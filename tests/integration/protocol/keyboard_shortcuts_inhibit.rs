@@ -0,0 +1,67 @@
+//! keyboard_shortcuts_inhibit test suite
+//!
+//! Checks that the `[shortcut_inhibit]` allowlist from the startup config decides
+//! which apps get their inhibitors activated.
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use pinnacle::{state::WithState, tag::Tag};
+use smithay::utils::Rectangle;
+
+use crate::common::fixture::Fixture;
+
+/// Spawns a window with the given app id and creates a shortcuts inhibitor for it.
+///
+/// Returns the fixture and the inhibitor's active flag.
+fn spawn_inhibiting_window(
+    allowlist: Option<Vec<String>>,
+    app_id: &str,
+) -> (Fixture, Arc<AtomicBool>) {
+    let mut fixture = Fixture::new();
+
+    let output = fixture.add_output(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    output.with_state_mut(|state| {
+        let tag = Tag::new("1".to_string());
+        tag.set_active(true);
+        state.add_tags([tag]);
+    });
+    fixture.pinnacle().focus_output(&output);
+
+    fixture.pinnacle().config.shortcut_inhibit_allowlist = allowlist;
+
+    let client_id = fixture.add_client();
+    let surface = fixture.spawn_floating_window_with(client_id, (500, 500), |window| {
+        window.set_app_id(app_id);
+    });
+
+    let active = fixture.client(client_id).inhibit_shortcuts(&surface);
+    fixture.roundtrip(client_id);
+
+    (fixture, active)
+}
+
+#[test_log::test]
+fn inhibitor_is_activated_without_allowlist() {
+    let (_fixture, active) = spawn_inhibiting_window(None, "some-app");
+
+    assert!(active.load(Ordering::Relaxed));
+}
+
+#[test_log::test]
+fn allowlisted_app_inhibitor_is_activated() {
+    let (_fixture, active) =
+        spawn_inhibiting_window(Some(vec!["virt-manager".to_string()]), "virt-manager");
+
+    assert!(active.load(Ordering::Relaxed));
+}
+
+#[test_log::test]
+fn app_not_in_allowlist_inhibitor_is_not_activated() {
+    let (_fixture, active) =
+        spawn_inhibiting_window(Some(vec!["virt-manager".to_string()]), "some-app");
+
+    assert!(!active.load(Ordering::Relaxed));
+}
@@ -3,9 +3,16 @@ use pinnacle_api::{
     layout::{LayoutGenerator as _, generators::MasterStack},
     output::OutputHandle,
 };
-use smithay::{output::Output, utils::Rectangle};
+use smithay::{
+    output::Output,
+    reexports::wayland_protocols_wlr::layer_shell::v1::client::{
+        zwlr_layer_shell_v1::Layer,
+        zwlr_layer_surface_v1::{Anchor, KeyboardInteractivity},
+    },
+    utils::Rectangle,
+};
 
-use crate::common::fixture::Fixture;
+use crate::common::{client::ClientId, fixture::Fixture};
 
 fn set_up() -> (Fixture, Output) {
     let mut fixture = Fixture::new();
@@ -120,6 +127,47 @@ fn window_spawned_without_tags_gets_tags_after_add() {
             .with_state(|state| !state.tags.is_empty())
     );
 }
+
+#[test_log::test]
+fn window_committing_buffer_without_tags_stays_unmapped() {
+    let (mut fixture, output) = set_up();
+
+    output.with_state_mut(|state| state.tags.clear());
+
+    let id = fixture.add_client();
+
+    // Add a window
+    let window = fixture.client(id).create_window();
+    window.commit();
+    let surface = window.surface();
+    fixture.roundtrip(id);
+
+    // Commit a buffer without having received the initial configure
+    let window = fixture.client(id).window_for_surface(&surface);
+    assert!(window.current_serial().is_none());
+    window.attach_buffer();
+    window.commit();
+    fixture.roundtrip(id);
+
+    assert!(fixture.pinnacle().windows.is_empty());
+    assert_eq!(fixture.pinnacle().unmapped_windows.len(), 1);
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::tag::add(&pinnacle_api::output::get_focused().unwrap(), ["1"])
+            .next()
+            .unwrap()
+            .set_active(true);
+    });
+
+    fixture.wait_client_configure(id);
+    fixture.client(id).ack_all_window();
+    fixture.roundtrip(id);
+    fixture.flush();
+
+    assert!(fixture.pinnacle().unmapped_windows.is_empty());
+    assert_eq!(fixture.pinnacle().windows.len(), 1);
+}
+
 #[test_log::test]
 fn window_tags_update_after_set_geometry() {
     let (mut fixture, output1) = set_up();
@@ -206,3 +254,238 @@ fn window_move_to_output() {
     let tags = fixture.pinnacle().windows[0].with_state(|state| state.tags.clone());
     assert_eq!(tags, output2.with_state(|state| state.tags.clone()));
 }
+
+#[test_log::test]
+fn minimized_window_does_not_occupy_tile() {
+    let (mut fixture, output) = set_up();
+
+    let id = fixture.add_client();
+    fixture.spawn_windows(2, id);
+
+    let minimized = fixture.pinnacle().windows[0].clone();
+    let other = fixture.pinnacle().windows[1].clone();
+
+    let minimized_geo = fixture.pinnacle().space.element_geometry(&minimized);
+    let other_geo = fixture.pinnacle().space.element_geometry(&other);
+
+    pinnacle::api::window::set_minimized(fixture.state(), &minimized, true);
+
+    fixture.wait_client_configure(id);
+    fixture.flush();
+
+    assert!(
+        !fixture
+            .pinnacle()
+            .space
+            .elements()
+            .any(|win| win == &minimized)
+    );
+    assert_eq!(
+        fixture.pinnacle().space.element_geometry(&other),
+        fixture.pinnacle().space.output_geometry(&output)
+    );
+
+    pinnacle::api::window::set_minimized(fixture.state(), &minimized, false);
+
+    fixture.wait_client_configure(id);
+    fixture.flush();
+
+    assert_eq!(
+        fixture.pinnacle().space.element_geometry(&minimized),
+        minimized_geo
+    );
+    assert_eq!(fixture.pinnacle().space.element_geometry(&other), other_geo);
+}
+
+#[test_log::test]
+fn minimized_floating_window_restores_to_its_location() {
+    let (mut fixture, _) = set_up();
+
+    let id = fixture.add_client();
+    fixture.spawn_floating_window_with(id, (500, 500), |_| ());
+
+    let window = fixture.pinnacle().windows[0].clone();
+    let geo = fixture.pinnacle().space.element_geometry(&window);
+    assert!(geo.is_some());
+
+    pinnacle::api::window::set_minimized(fixture.state(), &window, true);
+    fixture.dispatch_until(|fixture| fixture.pinnacle().space.element_geometry(&window).is_none());
+
+    assert_eq!(
+        fixture.pinnacle().keyboard_focus_stack.current_focus(),
+        None
+    );
+
+    pinnacle::api::window::set_minimized(fixture.state(), &window, false);
+    fixture.dispatch_until(|fixture| fixture.pinnacle().space.element_geometry(&window).is_some());
+
+    assert_eq!(fixture.pinnacle().space.element_geometry(&window), geo);
+}
+
+#[test_log::test]
+fn minimized_window_stays_minimized_across_tag_switch_by_default() {
+    let (mut fixture, output) = set_up();
+
+    let tag2 = Tag::new("2".to_string());
+    output.with_state_mut(|state| state.add_tags([tag2.clone()]));
+    let tag1 = output.with_state(|state| state.tags[0].clone());
+
+    let id = fixture.add_client();
+    fixture.spawn_windows(1, id);
+
+    let window = fixture.pinnacle().windows[0].clone();
+
+    pinnacle::api::window::set_minimized(fixture.state(), &window, true);
+    fixture.flush();
+
+    pinnacle::api::tag::switch_to(fixture.state(), &tag2);
+    pinnacle::api::tag::switch_to(fixture.state(), &tag1);
+    fixture.flush();
+
+    assert!(window.with_state(|state| state.minimized));
+
+    fixture.pinnacle().config.minimize_restore_on_tag = true;
+
+    pinnacle::api::tag::switch_to(fixture.state(), &tag2);
+    pinnacle::api::tag::switch_to(fixture.state(), &tag1);
+
+    fixture.wait_client_configure(id);
+    fixture.flush();
+
+    assert!(!window.with_state(|state| state.minimized));
+    assert!(
+        fixture
+            .pinnacle()
+            .space
+            .elements()
+            .any(|win| win == &window)
+    );
+}
+
+/// Maps a bar that reserves `height` pixels along `edge`.
+fn add_bar(fixture: &mut Fixture, id: ClientId, edge: Anchor, height: i32) {
+    let layer = fixture
+        .client(id)
+        .create_layer(Layer::Top, KeyboardInteractivity::None);
+    layer.set_exclusive_zone(
+        edge | Anchor::Left | Anchor::Right,
+        (0, height as u32),
+        height,
+    );
+    let surface = layer.surface();
+    layer.commit();
+    fixture.roundtrip(id);
+
+    let layer = fixture.client(id).layer_for_surface(&surface);
+    layer.attach_buffer();
+    layer.ack_and_commit();
+    fixture.roundtrip(id);
+}
+
+#[test_log::test]
+fn maximized_window_avoids_exclusive_zones() {
+    let (mut fixture, _) = set_up();
+
+    let id = fixture.add_client();
+    fixture.spawn_windows(1, id);
+    let window = fixture.pinnacle().windows[0].clone();
+
+    add_bar(&mut fixture, id, Anchor::Top, 30);
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::window::get_focused()
+            .unwrap()
+            .set_maximized(true);
+    });
+    fixture.wait_client_configure(id);
+    fixture.flush();
+
+    assert_eq!(
+        fixture.pinnacle().space.element_geometry(&window),
+        Some(Rectangle::new((0, 30).into(), (1920, 1050).into()))
+    );
+
+    // Bars added after maximizing shrink the window too
+    add_bar(&mut fixture, id, Anchor::Bottom, 20);
+    fixture.wait_client_configure(id);
+    fixture.flush();
+
+    assert_eq!(
+        fixture.pinnacle().space.element_geometry(&window),
+        Some(Rectangle::new((0, 30).into(), (1920, 1030).into()))
+    );
+}
+
+#[test_log::test]
+fn maximized_window_ignores_exclusive_zones_when_configured() {
+    let (mut fixture, _) = set_up();
+
+    let id = fixture.add_client();
+    fixture.spawn_windows(1, id);
+    let window = fixture.pinnacle().windows[0].clone();
+
+    add_bar(&mut fixture, id, Anchor::Top, 30);
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::window::get_focused()
+            .unwrap()
+            .set_maximized(true);
+    });
+    fixture.wait_client_configure(id);
+    fixture.flush();
+
+    let full = Some(Rectangle::new((0, 0).into(), (1920, 1080).into()));
+    let below_bar = Some(Rectangle::new((0, 30).into(), (1920, 1050).into()));
+
+    assert_eq!(
+        fixture.pinnacle().space.element_geometry(&window),
+        below_bar
+    );
+
+    // The test client's layers have the "test" namespace
+    fixture.spawn_blocking(|| {
+        pinnacle_api::output::set_exclusive_zone_ignored_namespaces(["test"]);
+    });
+    fixture.wait_client_configure(id);
+    fixture.flush();
+
+    assert_eq!(fixture.pinnacle().space.element_geometry(&window), full);
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::output::set_exclusive_zone_ignored_namespaces(Vec::<String>::new());
+    });
+    fixture.wait_client_configure(id);
+    fixture.flush();
+
+    assert_eq!(
+        fixture.pinnacle().space.element_geometry(&window),
+        below_bar
+    );
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::output::set_respect_exclusive_zones(false);
+    });
+    fixture.wait_client_configure(id);
+    fixture.flush();
+
+    assert_eq!(fixture.pinnacle().space.element_geometry(&window), full);
+
+    // Bars mapped while zones are ignored don't reserve space either
+    add_bar(&mut fixture, id, Anchor::Bottom, 20);
+    fixture.client(id).ack_all_window();
+    fixture.roundtrip(id);
+    fixture.flush();
+
+    assert_eq!(fixture.pinnacle().space.element_geometry(&window), full);
+
+    fixture.spawn_blocking(|| {
+        pinnacle_api::output::set_respect_exclusive_zones(true);
+    });
+    fixture.wait_client_configure(id);
+    fixture.flush();
+
+    assert_eq!(
+        fixture.pinnacle().space.element_geometry(&window),
+        Some(Rectangle::new((0, 30).into(), (1920, 1030).into()))
+    );
+}
@@ -42,6 +42,7 @@ pub(crate) fn run(channel: Channel<WlcsEvent>) {
         "Dummy Output",
         (0, 0).into(),
         (1920, 1080).into(),
+        (0, 0).into(),
         60000,
         1.0,
         smithay::utils::Transform::Normal,